@@ -0,0 +1,16 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RateLimitWindow } from "./RateLimitWindow";
+import type { RateLimitWindowKind } from "./RateLimitWindowKind";
+
+/**
+ * Rate-limit usage reached a configured `[limits] warn_at` threshold.
+ *
+ * Sent once per threshold and window; the threshold re-arms after usage drops back below it.
+ */
+export type AccountRateLimitWarningNotification = { threadId: string, limitId: string | null, limitName: string | null, windowKind: RateLimitWindowKind, thresholdPercent: number, 
+/**
+ * Window that triggered the warning; `resetsAt` is the projected reset time.
+ */
+window: RateLimitWindow, };
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Whose usage a rate limit counts. Accounts with organization or project
+ * budgets report a limit per scope alongside their personal one.
+ */
+export type RateLimitScope = "personal" | "organization" | "project";
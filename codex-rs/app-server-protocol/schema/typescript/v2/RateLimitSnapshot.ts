@@ -3,11 +3,21 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { PlanType } from "../PlanType";
 import type { CreditsSnapshot } from "./CreditsSnapshot";
+import type { ExtraRateLimitWindow } from "./ExtraRateLimitWindow";
 import type { RateLimitReachedType } from "./RateLimitReachedType";
+import type { RateLimitScope } from "./RateLimitScope";
 import type { RateLimitWindow } from "./RateLimitWindow";
 import type { SpendControlLimitSnapshot } from "./SpendControlLimitSnapshot";
 
-export type RateLimitSnapshot = { limitId: string | null, limitName: string | null, primary: RateLimitWindow | null, secondary: RateLimitWindow | null, credits: CreditsSnapshot | null, individualLimit: SpendControlLimitSnapshot | null,
+export type RateLimitSnapshot = { limitId: string | null, limitName: string | null,
+/**
+ * Whose usage this limit counts, when the backend says.
+ */
+scope: RateLimitScope | null, primary: RateLimitWindow | null, secondary: RateLimitWindow | null,
+/**
+ * Additional windows reported for this limit, such as per-model or burst limits.
+ */
+extra: Array<ExtraRateLimitWindow>, credits: CreditsSnapshot | null, individualLimit: SpendControlLimitSnapshot | null,
 /**
  * Backend-reported spend-control state. `None` is unavailable, not a sparse-update recovery.
  */
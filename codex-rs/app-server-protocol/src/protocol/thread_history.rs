@@ -402,6 +402,7 @@ impl ThreadHistoryBuilder {
             RolloutItem::InterAgentCommunication(_)
             | RolloutItem::InterAgentCommunicationMetadata { .. }
             | RolloutItem::TurnContext(_)
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_)
             | RolloutItem::SessionMeta(_) => {}
         }
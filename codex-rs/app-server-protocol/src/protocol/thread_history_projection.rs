@@ -79,6 +79,7 @@ pub fn project_rollout_line(line: &RolloutLine) -> ThreadHistoryChangeSet {
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::Compacted(_)
         | RolloutItem::TurnContext(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_)
         | RolloutItem::EventMsg(_) => ThreadHistoryChangeSet::default(),
     }
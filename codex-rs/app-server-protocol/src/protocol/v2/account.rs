@@ -3,9 +3,12 @@ use codex_experimental_api_macros::ExperimentalApi;
 use codex_protocol::account::PlanType;
 use codex_protocol::account::ProviderAccount;
 use codex_protocol::protocol::CreditsSnapshot as CoreCreditsSnapshot;
+use codex_protocol::protocol::ExtraRateLimitWindow as CoreExtraRateLimitWindow;
 use codex_protocol::protocol::RateLimitReachedType as CoreRateLimitReachedType;
+use codex_protocol::protocol::RateLimitScope as CoreRateLimitScope;
 use codex_protocol::protocol::RateLimitSnapshot as CoreRateLimitSnapshot;
 use codex_protocol::protocol::RateLimitWindow as CoreRateLimitWindow;
+use codex_protocol::protocol::RateLimitWindowKind as CoreRateLimitWindowKind;
 use codex_protocol::protocol::SpendControlLimitSnapshot as CoreSpendControlLimitSnapshot;
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -519,14 +522,52 @@ pub struct AccountRateLimitsUpdatedNotification {
     pub rate_limits: RateLimitSnapshot,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+/// Rate-limit usage reached a configured `[limits] warn_at` threshold.
+///
+/// Sent once per threshold and window; the threshold re-arms after usage drops back below it.
+pub struct AccountRateLimitWarningNotification {
+    pub thread_id: String,
+    pub limit_id: Option<String>,
+    pub limit_name: Option<String>,
+    pub window_kind: RateLimitWindowKind,
+    pub threshold_percent: i32,
+    /// Window that triggered the warning; `resetsAt` is the projected reset time.
+    pub window: RateLimitWindow,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export_to = "v2/", rename_all = "snake_case")]
+pub enum RateLimitWindowKind {
+    Primary,
+    Secondary,
+}
+
+impl From<CoreRateLimitWindowKind> for RateLimitWindowKind {
+    fn from(value: CoreRateLimitWindowKind) -> Self {
+        match value {
+            CoreRateLimitWindowKind::Primary => Self::Primary,
+            CoreRateLimitWindowKind::Secondary => Self::Secondary,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
 pub struct RateLimitSnapshot {
     pub limit_id: Option<String>,
     pub limit_name: Option<String>,
+    /// Whose usage this limit counts, when the backend says.
+    pub scope: Option<RateLimitScope>,
     pub primary: Option<RateLimitWindow>,
     pub secondary: Option<RateLimitWindow>,
+    /// Additional windows reported for this limit, such as per-model or burst limits.
+    #[serde(default)]
+    pub extra: Vec<ExtraRateLimitWindow>,
     pub credits: Option<CreditsSnapshot>,
     pub individual_limit: Option<SpendControlLimitSnapshot>,
     /// Backend-reported spend-control state. `None` is unavailable, not a sparse-update recovery.
@@ -540,8 +581,14 @@ impl From<CoreRateLimitSnapshot> for RateLimitSnapshot {
         Self {
             limit_id: value.limit_id,
             limit_name: value.limit_name,
+            scope: value.scope.map(RateLimitScope::from),
             primary: value.primary.map(RateLimitWindow::from),
             secondary: value.secondary.map(RateLimitWindow::from),
+            extra: value
+                .extra
+                .into_iter()
+                .map(ExtraRateLimitWindow::from)
+                .collect(),
             credits: value.credits.map(CreditsSnapshot::from),
             individual_limit: value.individual_limit.map(SpendControlLimitSnapshot::from),
             spend_control_reached: value.spend_control_reached,
@@ -553,6 +600,27 @@ impl From<CoreRateLimitSnapshot> for RateLimitSnapshot {
     }
 }
 
+/// Whose usage a rate limit counts. Accounts with organization or project
+/// budgets report a limit per scope alongside their personal one.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export_to = "v2/", rename_all = "snake_case")]
+pub enum RateLimitScope {
+    Personal,
+    Organization,
+    Project,
+}
+
+impl From<CoreRateLimitScope> for RateLimitScope {
+    fn from(value: CoreRateLimitScope) -> Self {
+        match value {
+            CoreRateLimitScope::Personal => Self::Personal,
+            CoreRateLimitScope::Organization => Self::Organization,
+            CoreRateLimitScope::Project => Self::Project,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
 #[serde(rename_all = "snake_case")]
 #[ts(export_to = "v2/", rename_all = "snake_case")]
@@ -625,6 +693,29 @@ impl From<CoreRateLimitWindow> for RateLimitWindow {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ExtraRateLimitWindow {
+    pub label: String,
+    pub used_percent: i32,
+    #[ts(type = "number | null")]
+    pub window_duration_mins: Option<i64>,
+    #[ts(type = "number | null")]
+    pub resets_at: Option<i64>,
+}
+
+impl From<CoreExtraRateLimitWindow> for ExtraRateLimitWindow {
+    fn from(value: CoreExtraRateLimitWindow) -> Self {
+        Self {
+            label: value.label,
+            used_percent: value.used_percent.round() as i32,
+            window_duration_mins: value.window_minutes,
+            resets_at: value.resets_at,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
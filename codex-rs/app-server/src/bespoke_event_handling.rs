@@ -11,6 +11,7 @@ use crate::thread_state::TurnSummary;
 use crate::thread_state::resolve_server_request_on_thread_listener;
 use crate::thread_status::ThreadWatchActiveGuard;
 use crate::thread_status::ThreadWatchManager;
+use codex_app_server_protocol::AccountRateLimitWarningNotification;
 use codex_app_server_protocol::AccountRateLimitsUpdatedNotification;
 use codex_app_server_protocol::AdditionalPermissionProfile as V2AdditionalPermissionProfile;
 use codex_app_server_protocol::CodexErrorInfo as V2CodexErrorInfo;
@@ -264,6 +265,19 @@ pub(crate) async fn apply_bespoke_event_handling(
                 .send_server_notification(ServerNotification::GuardianWarning(notification))
                 .await;
         }
+        EventMsg::RateLimitWarning(warning_event) => {
+            let notification = AccountRateLimitWarningNotification {
+                thread_id: conversation_id.to_string(),
+                limit_id: warning_event.limit_id,
+                limit_name: warning_event.limit_name,
+                window_kind: warning_event.window_kind.into(),
+                threshold_percent: i32::from(warning_event.threshold_percent),
+                window: warning_event.window.into(),
+            };
+            outgoing
+                .send_server_notification(ServerNotification::AccountRateLimitWarning(notification))
+                .await;
+        }
         EventMsg::GuardianAssessment(assessment) => {
             let pending_command_execution = match build_item_from_guardian_event(
                 &assessment,
@@ -3728,12 +3742,14 @@ mod tests {
         let rate_limits = RateLimitSnapshot {
             limit_id: Some("codex".to_string()),
             limit_name: None,
+            scope: None,
             primary: Some(RateLimitWindow {
                 used_percent: 42.5,
                 window_minutes: Some(15),
                 resets_at: Some(1700000000),
             }),
             secondary: None,
+            extra: Vec::new(),
             credits: Some(CreditsSnapshot {
                 has_credits: true,
                 unlimited: false,
@@ -9,6 +9,7 @@ use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
 use codex_exec_server::LOCAL_FS;
 use codex_features::feature_for_key;
+use codex_http_client::set_http_network_settings;
 use codex_login::AuthManager;
 use codex_login::default_client::set_default_client_residency_requirement;
 use codex_utils_absolute_path::AbsolutePathBuf;
@@ -127,6 +128,7 @@ impl ConfigManager {
         match self.load_latest_config(/*fallback_cwd*/ None).await {
             Ok(config) => {
                 set_default_client_residency_requirement(config.enforce_residency.value());
+                set_http_network_settings(config.http_network.clone());
             }
             Err(err) => warn!(
                 error = %err,
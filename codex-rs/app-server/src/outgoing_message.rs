@@ -741,6 +741,7 @@ mod tests {
     use std::time::Duration;
 
     use codex_app_server_protocol::AccountLoginCompletedNotification;
+    use codex_app_server_protocol::AccountRateLimitWarningNotification;
     use codex_app_server_protocol::AccountRateLimitsUpdatedNotification;
     use codex_app_server_protocol::AccountUpdatedNotification;
     use codex_app_server_protocol::ApplyPatchApprovalParams;
@@ -757,6 +758,7 @@ mod tests {
     use codex_app_server_protocol::ModelVerificationNotification;
     use codex_app_server_protocol::RateLimitSnapshot;
     use codex_app_server_protocol::RateLimitWindow;
+    use codex_app_server_protocol::RateLimitWindowKind;
     use codex_app_server_protocol::ServerResponse;
     use codex_app_server_protocol::ToolRequestUserInputParams;
     use codex_app_server_protocol::TurnModerationMetadataNotification;
@@ -830,12 +832,14 @@ mod tests {
                 rate_limits: RateLimitSnapshot {
                     limit_id: Some("codex".to_string()),
                     limit_name: None,
+                    scope: None,
                     primary: Some(RateLimitWindow {
                         used_percent: 25,
                         window_duration_mins: Some(15),
                         resets_at: Some(123),
                     }),
                     secondary: None,
+                    extra: Vec::new(),
                     credits: None,
                     individual_limit: None,
                     spend_control_reached: None,
@@ -851,12 +855,14 @@ mod tests {
                         "rateLimits": {
                         "limitId": "codex",
                         "limitName": null,
+                        "scope": null,
                         "primary": {
                             "usedPercent": 25,
                             "windowDurationMins": 15,
                             "resetsAt": 123
                         },
                         "secondary": null,
+                        "extra": [],
                         "credits": null,
                         "individualLimit": null,
                         "spendControlReached": null,
@@ -936,6 +942,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_account_rate_limit_warning_notification_serialization() {
+        let notification =
+            ServerNotification::AccountRateLimitWarning(AccountRateLimitWarningNotification {
+                thread_id: "thread-1".to_string(),
+                limit_id: Some("codex".to_string()),
+                limit_name: None,
+                window_kind: RateLimitWindowKind::Primary,
+                threshold_percent: 90,
+                window: RateLimitWindow {
+                    used_percent: 92,
+                    window_duration_mins: Some(300),
+                    resets_at: Some(1_700_000_000),
+                },
+            });
+
+        assert_eq!(
+            json!({
+                "method": "account/rateLimits/warning",
+                "params": {
+                    "threadId": "thread-1",
+                    "limitId": "codex",
+                    "limitName": null,
+                    "windowKind": "primary",
+                    "thresholdPercent": 90,
+                    "window": {
+                        "usedPercent": 92,
+                        "windowDurationMins": 300,
+                        "resetsAt": 1_700_000_000,
+                    },
+                },
+            }),
+            serde_json::to_value(notification)
+                .expect("ensure the notification serializes correctly"),
+            "ensure the notification serializes correctly"
+        );
+    }
+
     #[test]
     fn verify_model_rerouted_notification_serialization() {
         let notification = ServerNotification::ModelRerouted(ModelReroutedNotification {
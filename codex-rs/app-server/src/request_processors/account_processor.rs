@@ -350,7 +350,7 @@ impl AccountRequestProcessor {
         }
 
         match login_with_api_key(
-            &self.config.codex_home,
+            &self.config.auth_home(),
             &params.api_key,
             self.config.cli_auth_credentials_store_mode,
             self.config.auth_keyring_backend_kind(),
@@ -416,7 +416,7 @@ impl AccountRequestProcessor {
 
             set_user_model_provider_to_bedrock(&self.config_manager).await?;
             login_with_bedrock_api_key(
-                &self.config.codex_home,
+                &self.config.auth_home(),
                 api_key,
                 region,
                 self.config.cli_auth_credentials_store_mode,
@@ -459,7 +459,7 @@ impl AccountRequestProcessor {
             codex_streamlined_login,
             login_success_page,
             ..LoginServerOptions::new(
-                config.codex_home.to_path_buf(),
+                config.auth_home(),
                 oauth_client_id(),
                 config.forced_chatgpt_workspace_id.clone(),
                 config.cli_auth_credentials_store_mode,
@@ -1028,8 +1028,11 @@ impl AccountRequestProcessor {
             ));
         }
 
-        let client = BackendClient::from_auth(self.config.chatgpt_base_url.clone(), &auth)
+        let mut client = BackendClient::from_auth(self.config.chatgpt_base_url.clone(), &auth)
             .map_err(|err| internal_error(format!("failed to construct backend client: {err}")))?;
+        if let Some(timeout) = self.config.rate_limits_timeout {
+            client = client.with_rate_limits_timeout(timeout);
+        }
 
         let (response, detailed_rate_limit_reset_credits) = tokio::join!(
             client.get_rate_limits_with_reset_credits(),
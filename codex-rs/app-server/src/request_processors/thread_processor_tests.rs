@@ -676,6 +676,7 @@ mod thread_processor_behavior_tests {
             experimental_bearer_token: None,
             auth: None,
             aws: None,
+            azure: None,
             wire_api: WireApi::Responses,
             query_params: None,
             http_headers: None,
@@ -243,6 +243,7 @@ async fn get_account_rate_limits_returns_snapshot() -> Result<()> {
         rate_limits: RateLimitSnapshot {
             limit_id: Some("codex".to_string()),
             limit_name: None,
+            scope: None,
             primary: Some(RateLimitWindow {
                 used_percent: 42,
                 window_duration_mins: Some(60),
@@ -253,6 +254,7 @@ async fn get_account_rate_limits_returns_snapshot() -> Result<()> {
                 window_duration_mins: Some(1440),
                 resets_at: Some(secondary_reset_timestamp),
             }),
+            extra: Vec::new(),
             credits: None,
             individual_limit: Some(SpendControlLimitSnapshot {
                 limit: "25000".to_string(),
@@ -271,6 +273,7 @@ async fn get_account_rate_limits_returns_snapshot() -> Result<()> {
                     RateLimitSnapshot {
                         limit_id: Some("codex".to_string()),
                         limit_name: None,
+                        scope: None,
                         primary: Some(RateLimitWindow {
                             used_percent: 42,
                             window_duration_mins: Some(60),
@@ -281,6 +284,7 @@ async fn get_account_rate_limits_returns_snapshot() -> Result<()> {
                             window_duration_mins: Some(1440),
                             resets_at: Some(secondary_reset_timestamp),
                         }),
+                        extra: Vec::new(),
                         credits: None,
                         individual_limit: Some(SpendControlLimitSnapshot {
                             limit: "25000".to_string(),
@@ -300,12 +304,14 @@ async fn get_account_rate_limits_returns_snapshot() -> Result<()> {
                     RateLimitSnapshot {
                         limit_id: Some("codex_other".to_string()),
                         limit_name: Some("codex_other".to_string()),
+                        scope: None,
                         primary: Some(RateLimitWindow {
                             used_percent: 88,
                             window_duration_mins: Some(30),
                             resets_at: Some(1735693200),
                         }),
                         secondary: None,
+                        extra: Vec::new(),
                         credits: None,
                         individual_limit: None,
                         spend_control_reached: None,
@@ -678,6 +678,7 @@ sqlite = true
         /*cwd_filters*/ None,
         "mock_provider",
         /*search_term*/ None,
+        codex_state::ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(repaired_page.items.len(), 3);
@@ -1816,6 +1817,7 @@ async fn thread_list_sort_recency_at_uses_state_db_order_with_provider_filter()
         /*cwd_filters*/ None,
         "mock_provider",
         /*search_term*/ None,
+        codex_state::ThreadTimeBounds::default(),
     )
     .await?;
     state_db
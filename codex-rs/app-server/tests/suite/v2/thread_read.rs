@@ -510,6 +510,7 @@ async fn thread_search_occurrences_reads_paginated_projection() -> Result<()> {
             codex_home: codex_home.path().to_path_buf(),
             sqlite_home: codex_home.path().to_path_buf(),
             default_model_provider_id: "mock_provider".to_string(),
+            encrypt_history: false,
         },
         Some(state_db),
     );
@@ -1527,6 +1528,7 @@ async fn paginated_history_lists_use_projected_turns_and_items() -> Result<()> {
             codex_home: codex_home.path().to_path_buf(),
             sqlite_home: codex_home.path().to_path_buf(),
             default_model_provider_id: "mock_provider".to_string(),
+            encrypt_history: false,
         },
         Some(state_db),
     );
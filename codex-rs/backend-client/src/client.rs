@@ -9,7 +9,9 @@ use crate::types::TokenUsageProfile;
 use crate::types::TurnAttemptsSiblingTurnsResponse;
 use anyhow::Result;
 use codex_api::SharedAuthProvider;
+use codex_client::RetryPolicy;
 use codex_http_client::build_reqwest_client_with_custom_ca;
+use codex_http_client::http_network_settings;
 use codex_http_client::with_chatgpt_cloudflare_cookie_store;
 use codex_login::CodexAuth;
 use codex_login::default_client::get_codex_user_agent;
@@ -29,9 +31,92 @@ use reqwest::header::USER_AGENT;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
 mod rate_limit_resets;
+mod retry;
 
+/// Upper bound on each rate-limit status request, so callers on a hung
+/// network (captive portals, stalled proxies) fail fast instead of waiting.
+pub const DEFAULT_RATE_LIMITS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default time allowed to establish a connection to the backend.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default upper bound on each backend request, from connecting until the
+/// response body has been read.
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Upper bound on cloud task requests, whose responses can carry large diffs.
+pub const DEFAULT_TASK_REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Connect and overall request timeouts for every request a [`Client`] sends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClientTimeouts {
+    pub connect: Duration,
+    pub request: Duration,
+}
+
+impl Default for ClientTimeouts {
+    fn default() -> Self {
+        Self {
+            connect: DEFAULT_CONNECT_TIMEOUT,
+            request: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+}
+
+impl ClientTimeouts {
+    /// Timeouts from the installed `[network]` settings, falling back to the
+    /// built-in defaults for any that are unset.
+    pub fn from_network_settings() -> Self {
+        let settings = http_network_settings();
+        Self {
+            connect: settings
+                .backend_connect_timeout()
+                .unwrap_or(DEFAULT_CONNECT_TIMEOUT),
+            request: settings
+                .backend_request_timeout()
+                .unwrap_or(DEFAULT_REQUEST_TIMEOUT),
+        }
+    }
+}
+
+/// Why a request could not connect to the backend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectErrorKind {
+    /// The host was resolved but refused the connection.
+    Refused,
+    /// The host name could not be resolved.
+    Dns,
+    /// Any other connect failure, such as a TLS handshake error.
+    Other,
+}
+
+impl ConnectErrorKind {
+    fn classify(err: &reqwest::Error) -> Self {
+        let mut source = std::error::Error::source(err);
+        while let Some(err) = source {
+            if let Some(io_err) = err.downcast_ref::<std::io::Error>()
+                && io_err.kind() == std::io::ErrorKind::ConnectionRefused
+            {
+                return Self::Refused;
+            }
+            // hyper-util does not expose its resolver error type, only this
+            // message wrapping the underlying lookup failure.
+            if err.to_string().starts_with("dns error") {
+                return Self::Dns;
+            }
+            source = err.source();
+        }
+        Self::Other
+    }
+}
+
+/// Error returned by backend requests. Calls that return [`anyhow::Error`]
+/// carry this as the error value, so callers can recover it with
+/// `downcast_ref::<RequestError>()`.
 #[derive(Debug)]
 pub enum RequestError {
     UnexpectedStatus {
@@ -41,20 +126,74 @@ pub enum RequestError {
         content_type: String,
         body: String,
     },
+    /// The request, including connecting, did not finish within its timeout.
+    Timeout {
+        method: String,
+        url: String,
+        source: reqwest::Error,
+    },
+    /// No connection to the backend could be established.
+    Connect {
+        method: String,
+        url: String,
+        kind: ConnectErrorKind,
+        source: reqwest::Error,
+    },
     Other(anyhow::Error),
+    /// A failure from a coalesced request, shared by every caller that waited
+    /// on it. Accessors and formatting look through to the shared error.
+    Shared(Arc<RequestError>),
 }
 
 impl RequestError {
     pub fn status(&self) -> Option<StatusCode> {
         match self {
             Self::UnexpectedStatus { status, .. } => Some(*status),
-            Self::Other(_) => None,
+            Self::Timeout { .. } | Self::Connect { .. } | Self::Other(_) => None,
+            Self::Shared(err) => err.status(),
         }
     }
 
     pub fn is_unauthorized(&self) -> bool {
         self.status() == Some(StatusCode::UNAUTHORIZED)
     }
+
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Timeout { .. } => true,
+            Self::UnexpectedStatus { .. } | Self::Connect { .. } | Self::Other(_) => false,
+            Self::Shared(err) => err.is_timeout(),
+        }
+    }
+
+    pub fn connect_error_kind(&self) -> Option<ConnectErrorKind> {
+        match self {
+            Self::Connect { kind, .. } => Some(*kind),
+            Self::UnexpectedStatus { .. } | Self::Timeout { .. } | Self::Other(_) => None,
+            Self::Shared(err) => err.connect_error_kind(),
+        }
+    }
+
+    /// Classifies a failure to send a request. Connect timeouts count as
+    /// timeouts rather than connect errors.
+    fn from_send_error(method: &str, url: &str, err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            Self::Timeout {
+                method: method.to_string(),
+                url: url.to_string(),
+                source: err,
+            }
+        } else if err.is_connect() {
+            Self::Connect {
+                method: method.to_string(),
+                url: url.to_string(),
+                kind: ConnectErrorKind::classify(&err),
+                source: err,
+            }
+        } else {
+            Self::Other(err.into())
+        }
+    }
 }
 
 impl fmt::Display for RequestError {
@@ -70,7 +209,22 @@ impl fmt::Display for RequestError {
                 f,
                 "{method} {url} failed: {status}; content-type={content_type}; body={body}"
             ),
+            Self::Timeout { method, url, .. } => write!(f, "{method} {url} timed out"),
+            Self::Connect {
+                method,
+                url,
+                kind,
+                source,
+            } => {
+                let reason = match kind {
+                    ConnectErrorKind::Refused => "connection refused",
+                    ConnectErrorKind::Dns => "DNS lookup failed",
+                    ConnectErrorKind::Other => "could not connect",
+                };
+                write!(f, "{method} {url} failed: {reason}: {source}")
+            }
             Self::Other(err) => write!(f, "{err}"),
+            Self::Shared(err) => write!(f, "{err}"),
         }
     }
 }
@@ -79,14 +233,17 @@ impl std::error::Error for RequestError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::UnexpectedStatus { .. } => None,
+            Self::Timeout { source, .. } | Self::Connect { source, .. } => Some(source),
             Self::Other(err) => Some(err.as_ref()),
+            Self::Shared(err) => err.source(),
         }
     }
 }
 
+/// Recovers the [`RequestError`] an [`anyhow::Error`] carries, if any.
 impl From<anyhow::Error> for RequestError {
     fn from(err: anyhow::Error) -> Self {
-        Self::Other(err)
+        err.downcast().unwrap_or_else(Self::Other)
     }
 }
 
@@ -129,6 +286,11 @@ pub struct Client {
     chatgpt_account_id: Option<String>,
     chatgpt_account_is_fedramp: bool,
     path_style: PathStyle,
+    timeouts: ClientTimeouts,
+    rate_limits_timeout: Duration,
+    task_request_timeout: Duration,
+    retry_policy: RetryPolicy,
+    retry_non_idempotent: bool,
 }
 
 impl fmt::Debug for Client {
@@ -143,12 +305,26 @@ impl fmt::Debug for Client {
                 &self.chatgpt_account_is_fedramp,
             )
             .field("path_style", &self.path_style)
+            .field("timeouts", &self.timeouts)
+            .field("rate_limits_timeout", &self.rate_limits_timeout)
+            .field("task_request_timeout", &self.task_request_timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
             .finish_non_exhaustive()
     }
 }
 
 impl Client {
+    /// Creates a client using the timeouts from the installed `[network]`
+    /// settings.
     pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        Self::new_with_timeouts(base_url, ClientTimeouts::from_network_settings())
+    }
+
+    pub fn new_with_timeouts(
+        base_url: impl Into<String>,
+        timeouts: ClientTimeouts,
+    ) -> Result<Self> {
         let mut base_url = base_url.into();
         // Normalize common ChatGPT hostnames to include /backend-api so we hit the WHAM paths.
         // Also trim trailing slashes for consistent URL building.
@@ -162,7 +338,9 @@ impl Client {
             base_url = format!("{base_url}/backend-api");
         }
         let http = build_reqwest_client_with_custom_ca(with_chatgpt_cloudflare_cookie_store(
-            reqwest::Client::builder(),
+            reqwest::Client::builder()
+                .connect_timeout(timeouts.connect)
+                .timeout(timeouts.request),
         ))?;
         let path_style = PathStyle::from_base_url(&base_url);
         Ok(Self {
@@ -173,6 +351,11 @@ impl Client {
             chatgpt_account_id: None,
             chatgpt_account_is_fedramp: false,
             path_style,
+            timeouts,
+            rate_limits_timeout: DEFAULT_RATE_LIMITS_TIMEOUT,
+            task_request_timeout: DEFAULT_TASK_REQUEST_TIMEOUT,
+            retry_policy: retry::default_retry_policy(),
+            retry_non_idempotent: false,
         })
     }
 
@@ -209,6 +392,34 @@ impl Client {
         self
     }
 
+    /// Overrides [`DEFAULT_RATE_LIMITS_TIMEOUT`] for rate-limit status requests.
+    pub fn with_rate_limits_timeout(mut self, timeout: Duration) -> Self {
+        self.rate_limits_timeout = timeout;
+        self
+    }
+
+    /// Overrides [`DEFAULT_TASK_REQUEST_TIMEOUT`] for creating cloud tasks and
+    /// fetching their details.
+    pub fn with_task_request_timeout(mut self, timeout: Duration) -> Self {
+        self.task_request_timeout = timeout;
+        self
+    }
+
+    /// Replaces the policy used to retry requests that fail to connect or
+    /// return 429 or a 5xx status. By default a request is tried up to three
+    /// times.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Also retries non-idempotent requests such as task creation. Only safe
+    /// against endpoints that deduplicate repeated requests.
+    pub fn with_non_idempotent_retries(mut self) -> Self {
+        self.retry_non_idempotent = true;
+        self
+    }
+
     fn headers(&self) -> HeaderMap {
         let mut h = HeaderMap::new();
         if let Some(ua) = &self.user_agent {
@@ -237,7 +448,10 @@ impl Client {
         method: &str,
         url: &str,
     ) -> Result<(String, String)> {
-        let res = req.send().await?;
+        let res = self
+            .send_with_retry(req)
+            .await
+            .map_err(|err| RequestError::from_send_error(method, url, err))?;
         let status = res.status();
         let ct = res
             .headers()
@@ -258,7 +472,10 @@ impl Client {
         method: &str,
         url: &str,
     ) -> std::result::Result<(String, String), RequestError> {
-        let res = req.send().await.map_err(anyhow::Error::from)?;
+        let res = self
+            .send_with_retry(req)
+            .await
+            .map_err(|err| RequestError::from_send_error(method, url, err))?;
         let status = res.status();
         let content_type = res
             .headers()
@@ -389,7 +606,11 @@ impl Client {
             PathStyle::CodexApi => format!("{}/api/codex/tasks/{}", self.base_url, task_id),
             PathStyle::ChatGptApi => format!("{}/wham/tasks/{}", self.base_url, task_id),
         };
-        let req = self.http.get(&url).headers(self.headers());
+        let req = self
+            .http
+            .get(&url)
+            .headers(self.headers())
+            .timeout(self.task_request_timeout);
         let (body, ct) = self.exec_request(req, "GET", &url).await?;
         let parsed: CodeTaskDetailsResponse = self.decode_json(&url, &ct, &body)?;
         Ok((parsed, body, ct))
@@ -458,7 +679,8 @@ impl Client {
             .post(&url)
             .headers(self.headers())
             .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
-            .json(&request_body);
+            .json(&request_body)
+            .timeout(self.task_request_timeout);
         let (body, ct) = self.exec_request(req, "POST", &url).await?;
         // Extract id from JSON: prefer `task.id`; fallback to top-level `id` when present.
         match serde_json::from_str::<serde_json::Value>(&body) {
@@ -538,8 +760,10 @@ impl Client {
         RateLimitSnapshot {
             limit_id,
             limit_name,
+            scope: None,
             primary,
             secondary,
+            extra: Vec::new(),
             credits: Self::map_credits(credits),
             individual_limit,
             spend_control_reached,
@@ -848,12 +1072,14 @@ mod tests {
             RateLimitSnapshot {
                 limit_id: Some("codex_other".to_string()),
                 limit_name: Some("codex_other".to_string()),
+                scope: None,
                 primary: Some(RateLimitWindow {
                     used_percent: 90.0,
                     window_minutes: Some(60),
                     resets_at: Some(1),
                 }),
                 secondary: None,
+                extra: Vec::new(),
                 credits: None,
                 individual_limit: None,
                 spend_control_reached: None,
@@ -863,12 +1089,14 @@ mod tests {
             RateLimitSnapshot {
                 limit_id: Some("codex".to_string()),
                 limit_name: Some("codex".to_string()),
+                scope: None,
                 primary: Some(RateLimitWindow {
                     used_percent: 10.0,
                     window_minutes: Some(60),
                     resets_at: Some(2),
                 }),
                 secondary: None,
+                extra: Vec::new(),
                 credits: None,
                 individual_limit: None,
                 spend_control_reached: None,
@@ -1010,6 +1238,11 @@ mod tests {
             chatgpt_account_id: None,
             chatgpt_account_is_fedramp: false,
             path_style,
+            timeouts: ClientTimeouts::default(),
+            rate_limits_timeout: DEFAULT_RATE_LIMITS_TIMEOUT,
+            task_request_timeout: DEFAULT_TASK_REQUEST_TIMEOUT,
+            retry_policy: retry::default_retry_policy(),
+            retry_non_idempotent: false,
         }
     }
 }
@@ -2,14 +2,33 @@
 
 use super::Client;
 use super::PathStyle;
+use super::RequestError;
+use crate::single_flight::SingleFlight;
 use crate::types::ConsumeRateLimitResetCreditResponse;
 use crate::types::RateLimitResetCreditsDetails;
 use crate::types::RateLimitStatusWithResetCredits;
+use crate::types::RateLimitWindowSnapshot;
 use crate::types::RateLimitsWithResetCredits;
+use crate::types::RawRateLimitPayload;
 use anyhow::Result;
+use codex_protocol::protocol::ExtraRateLimitWindow;
+use codex_protocol::protocol::RateLimitSnapshot;
 use reqwest::header::CONTENT_TYPE;
 use reqwest::header::HeaderValue;
 use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// How long a fetched rate-limit status answers later callers for the same
+/// account before the backend is asked again.
+const RATE_LIMIT_STATUS_HOLD: Duration = Duration::from_secs(3);
+
+/// Rate-limit status fetches in this process, keyed by usage URL and account.
+static RATE_LIMIT_STATUS_FLIGHTS: LazyLock<
+    SingleFlight<(String, Option<String>), RateLimitsWithResetCredits, RequestError>,
+> = LazyLock::new(|| SingleFlight::new(RATE_LIMIT_STATUS_HOLD));
 
 #[derive(Serialize)]
 struct ConsumeRateLimitResetCreditRequest<'a> {
@@ -19,19 +38,98 @@ struct ConsumeRateLimitResetCreditRequest<'a> {
 }
 
 impl Client {
+    /// Concurrent calls for the same account share one request, and its
+    /// result answers further calls for a few seconds. A failure reaches
+    /// every waiting caller as a [`RequestError::Shared`].
     pub async fn get_rate_limits_with_reset_credits(&self) -> Result<RateLimitsWithResetCredits> {
-        let payload = self.get_rate_limit_status().await?;
+        RATE_LIMIT_STATUS_FLIGHTS
+            .run(self.rate_limit_status_key(), || async {
+                self.fetch_rate_limits_with_reset_credits()
+                    .await
+                    .map_err(RequestError::from)
+            })
+            .await
+            .map_err(|err| RequestError::Shared(err).into())
+    }
+
+    async fn fetch_rate_limits_with_reset_credits(&self) -> Result<RateLimitsWithResetCredits> {
+        let (payload, raw_rate_limits) = self.get_rate_limit_status().await?;
+        let mut rate_limits = Self::rate_limit_snapshots_from_payload(payload.rate_limits);
+        Self::attach_raw_rate_limit_fields(&mut rate_limits, raw_rate_limits);
         Ok(RateLimitsWithResetCredits {
-            rate_limits: Self::rate_limit_snapshots_from_payload(payload.rate_limits),
+            rate_limits,
             rate_limit_reset_credits: payload.rate_limit_reset_credits,
         })
     }
 
-    pub(super) async fn get_rate_limit_status(&self) -> Result<RateLimitStatusWithResetCredits> {
+    /// Each attempt is bounded by the client's rate-limits timeout. Requests
+    /// that could not connect or hit a transient status are retried by the
+    /// client's retry policy; timeouts are not, so a hung network costs at
+    /// most one timeout.
+    pub(super) async fn get_rate_limit_status(
+        &self,
+    ) -> Result<(RateLimitStatusWithResetCredits, RawRateLimitPayload)> {
         let url = self.rate_limit_status_url();
-        let req = self.http.get(&url).headers(self.headers());
+        let req = self
+            .http
+            .get(&url)
+            .headers(self.headers())
+            .timeout(self.rate_limits_timeout);
         let (body, ct) = self.exec_request(req, "GET", &url).await?;
-        self.decode_json(&url, &ct, &body)
+        let status = self.decode_json(&url, &ct, &body)?;
+        let raw_rate_limits = self.decode_json(&url, &ct, &body)?;
+        Ok((status, raw_rate_limits))
+    }
+
+    /// Copies what the generated models drop from each `rate_limit` object onto
+    /// the snapshot of the limit that reported it: every window other than the
+    /// primary and secondary one, and the `scope` (`personal`, `organization`,
+    /// or `project`) when present. Unknown scopes are ignored.
+    fn attach_raw_rate_limit_fields(
+        snapshots: &mut [RateLimitSnapshot],
+        payload: RawRateLimitPayload,
+    ) {
+        let additional = payload
+            .additional_rate_limits
+            .unwrap_or_default()
+            .into_iter()
+            .map(|details| (details.metered_feature, details.rate_limit));
+        let windows_by_limit =
+            std::iter::once(("codex".to_string(), payload.rate_limit)).chain(additional);
+        for (limit_id, fields) in windows_by_limit {
+            let Some(fields) = fields else {
+                continue;
+            };
+            if let Some(snapshot) = snapshots
+                .iter_mut()
+                .find(|snapshot| snapshot.limit_id.as_deref() == Some(limit_id.as_str()))
+            {
+                snapshot.scope = fields
+                    .get("scope")
+                    .and_then(Value::as_str)
+                    .and_then(|scope| scope.trim().to_ascii_lowercase().parse().ok());
+                snapshot.extra = Self::map_extra_rate_limit_windows(fields);
+            }
+        }
+    }
+
+    fn map_extra_rate_limit_windows(fields: BTreeMap<String, Value>) -> Vec<ExtraRateLimitWindow> {
+        fields
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let label = key.strip_suffix("_window")?;
+                if matches!(label, "primary" | "secondary") {
+                    return None;
+                }
+                let window: RateLimitWindowSnapshot = serde_json::from_value(value).ok()?;
+                Some(ExtraRateLimitWindow {
+                    label: label.to_string(),
+                    used_percent: f64::from(window.used_percent),
+                    window_minutes: Self::window_minutes_from_seconds(window.limit_window_seconds),
+                    resets_at: Some(i64::from(window.reset_at)),
+                })
+            })
+            .collect()
     }
 
     pub async fn list_rate_limit_reset_credits(&self) -> Result<RateLimitResetCreditsDetails> {
@@ -73,10 +171,20 @@ impl Client {
                 redeem_request_id,
                 credit_id,
             });
-        let (body, ct) = self.exec_request(req, "POST", &url).await?;
+        let result = self.exec_request(req, "POST", &url).await;
+        // Whether or not the consume went through, a held status may now be stale.
+        RATE_LIMIT_STATUS_FLIGHTS.forget(&self.rate_limit_status_key());
+        let (body, ct) = result?;
         self.decode_json(&url, &ct, &body)
     }
 
+    fn rate_limit_status_key(&self) -> (String, Option<String>) {
+        (
+            self.rate_limit_status_url(),
+            self.chatgpt_account_id.clone(),
+        )
+    }
+
     fn rate_limit_status_url(&self) -> String {
         match self.path_style {
             PathStyle::CodexApi => format!("{}/api/codex/usage", self.base_url),
@@ -3,6 +3,7 @@ use crate::types::ConsumeRateLimitResetCreditCode;
 use crate::types::RateLimitResetCreditDetails;
 use crate::types::RateLimitResetCreditsDetails;
 use crate::types::RateLimitResetCreditsSummary;
+use codex_protocol::protocol::RateLimitScope;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -135,6 +136,148 @@ fn rate_limit_reset_contract_uses_expected_paths_and_payloads() {
     );
 }
 
+#[test]
+fn usage_payload_extra_windows_attach_to_their_limit() -> anyhow::Result<()> {
+    let window = |used_percent: i32, seconds: i32, reset_at: i32| {
+        serde_json::json!({
+            "used_percent": used_percent,
+            "limit_window_seconds": seconds,
+            "reset_after_seconds": 0,
+            "reset_at": reset_at,
+        })
+    };
+    let body = serde_json::json!({
+        "plan_type": "plus",
+        "rate_limit": {
+            "allowed": true,
+            "limit_reached": false,
+            "primary_window": window(10, 18_000, 100),
+            "secondary_window": window(20, 604_800, 200),
+            "burst_window": window(55, 60, 300),
+            "note": "not a window",
+        },
+        "additional_rate_limits": [
+            {
+                "limit_name": "GPT-5.3-Codex-Spark",
+                "metered_feature": "codex_bengalfox",
+                "rate_limit": {
+                    "allowed": true,
+                    "limit_reached": false,
+                    "primary_window": window(30, 18_000, 400),
+                    "daily_window": window(5, 86_400, 500),
+                },
+            }
+        ],
+    });
+    let status: RateLimitStatusWithResetCredits = serde_json::from_value(body.clone())?;
+    let raw_rate_limits: RawRateLimitPayload = serde_json::from_value(body)?;
+
+    let mut snapshots = Client::rate_limit_snapshots_from_payload(status.rate_limits);
+    Client::attach_raw_rate_limit_fields(&mut snapshots, raw_rate_limits);
+
+    let extras: Vec<_> = snapshots
+        .into_iter()
+        .map(|snapshot| (snapshot.limit_id, snapshot.extra))
+        .collect();
+    assert_eq!(
+        extras,
+        vec![
+            (
+                Some("codex".to_string()),
+                vec![ExtraRateLimitWindow {
+                    label: "burst".to_string(),
+                    used_percent: 55.0,
+                    window_minutes: Some(1),
+                    resets_at: Some(300),
+                }],
+            ),
+            (
+                Some("codex_bengalfox".to_string()),
+                vec![ExtraRateLimitWindow {
+                    label: "daily".to_string(),
+                    used_percent: 5.0,
+                    window_minutes: Some(1440),
+                    resets_at: Some(500),
+                }],
+            ),
+        ]
+    );
+    Ok(())
+}
+
+fn usage_payload_scopes(
+    body: serde_json::Value,
+) -> anyhow::Result<Vec<(String, Option<RateLimitScope>)>> {
+    let status: RateLimitStatusWithResetCredits = serde_json::from_value(body.clone())?;
+    let raw_rate_limits: RawRateLimitPayload = serde_json::from_value(body)?;
+    let mut snapshots = Client::rate_limit_snapshots_from_payload(status.rate_limits);
+    Client::attach_raw_rate_limit_fields(&mut snapshots, raw_rate_limits);
+    Ok(snapshots
+        .into_iter()
+        .map(|snapshot| (snapshot.limit_id.unwrap_or_default(), snapshot.scope))
+        .collect())
+}
+
+#[test]
+fn usage_payload_scopes_attach_to_their_limit() -> anyhow::Result<()> {
+    let rate_limit = |scope: Option<&str>| {
+        let mut rate_limit = serde_json::json!({
+            "allowed": true,
+            "limit_reached": false,
+            "primary_window": {
+                "used_percent": 10,
+                "limit_window_seconds": 18_000,
+                "reset_after_seconds": 0,
+                "reset_at": 100,
+            },
+        });
+        if let Some(scope) = scope {
+            rate_limit["scope"] = scope.into();
+        }
+        rate_limit
+    };
+    let additional = |metered_feature: &str, scope: Option<&str>| {
+        serde_json::json!({
+            "limit_name": metered_feature,
+            "metered_feature": metered_feature,
+            "rate_limit": rate_limit(scope),
+        })
+    };
+
+    let scoped = serde_json::json!({
+        "plan_type": "enterprise",
+        "rate_limit": rate_limit(Some("personal")),
+        "additional_rate_limits": [
+            additional("codex_org", Some("Organization")),
+            additional("codex_project", Some("project")),
+            additional("codex_team", Some("team")),
+        ],
+    });
+    assert_eq!(
+        usage_payload_scopes(scoped)?,
+        vec![
+            ("codex".to_string(), Some(RateLimitScope::Personal)),
+            ("codex_org".to_string(), Some(RateLimitScope::Organization)),
+            ("codex_project".to_string(), Some(RateLimitScope::Project)),
+            ("codex_team".to_string(), None),
+        ]
+    );
+
+    let unscoped = serde_json::json!({
+        "plan_type": "plus",
+        "rate_limit": rate_limit(/*scope*/ None),
+        "additional_rate_limits": [additional("codex_bengalfox", /*scope*/ None)],
+    });
+    assert_eq!(
+        usage_payload_scopes(unscoped)?,
+        vec![
+            ("codex".to_string(), None),
+            ("codex_bengalfox".to_string(), None),
+        ]
+    );
+    Ok(())
+}
+
 fn test_client(base_url: &str, path_style: PathStyle) -> Client {
     Client {
         base_url: base_url.to_string(),
@@ -144,5 +287,10 @@ fn test_client(base_url: &str, path_style: PathStyle) -> Client {
         chatgpt_account_id: None,
         chatgpt_account_is_fedramp: false,
         path_style,
+        timeouts: crate::ClientTimeouts::default(),
+        rate_limits_timeout: crate::DEFAULT_RATE_LIMITS_TIMEOUT,
+        task_request_timeout: crate::DEFAULT_TASK_REQUEST_TIMEOUT,
+        retry_policy: super::super::retry::default_retry_policy(),
+        retry_non_idempotent: false,
     }
 }
@@ -0,0 +1,146 @@
+//! Retries for transient backend failures.
+//!
+//! Requests that fail to connect, or that come back with a 429 or a 5xx
+//! status, are retried with exponential backoff and jitter according to the
+//! client's [`RetryPolicy`]. A 429 with a `Retry-After` of some seconds waits
+//! that long instead. Only idempotent methods are retried unless the client
+//! opted in with [`Client::with_non_idempotent_retries`]. Timeouts are never
+//! retried, since the attempt already waited as long as the caller allowed.
+
+use super::Client;
+use codex_client::RetryOn;
+use codex_client::RetryPolicy;
+use codex_client::backoff;
+use codex_http_client::TraceOptions;
+use codex_http_client::execute_traced;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use reqwest::header::RETRY_AFTER;
+use std::time::Duration;
+use tracing::Instrument;
+
+/// Longest `Retry-After` the client waits out. A 429 asking for a longer
+/// wait is returned to the caller rather than stalling it.
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(10);
+
+pub(super) fn default_retry_policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(200),
+        retry_on: RetryOn {
+            retry_429: true,
+            retry_5xx: true,
+            retry_transport: true,
+        },
+    }
+}
+
+/// Backend responses are small JSON documents, so their bodies can be traced.
+fn trace_options(attempt: u64) -> TraceOptions {
+    TraceOptions {
+        attempt: Some(attempt),
+        buffer_response_body: true,
+        omit_bodies: false,
+    }
+}
+
+impl Client {
+    /// Sends `req`, retrying transient failures. The final response is
+    /// returned even when its status is an error so callers can report it.
+    pub(super) async fn send_with_retry(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> reqwest::Result<reqwest::Response> {
+        let (http, request) = req.build_split();
+        let request = request?;
+        let retryable = request.method().is_idempotent() || self.retry_non_idempotent;
+        let span = tracing::debug_span!(
+            "backend_request",
+            method = %request.method(),
+            url = %request.url(),
+            attempts = tracing::field::Empty,
+        );
+
+        async move {
+            let mut attempt: u64 = 1;
+            loop {
+                // Requests with streaming bodies cannot be replayed, so they
+                // get a single attempt.
+                let Some(current) = request.try_clone() else {
+                    tracing::Span::current().record("attempts", attempt);
+                    return execute_traced(&http, request, trace_options(attempt)).await;
+                };
+                let outcome = execute_traced(&http, current, trace_options(attempt)).await;
+                let delay = if retryable && attempt < self.retry_policy.max_attempts {
+                    self.retry_delay(&outcome, attempt)
+                } else {
+                    None
+                };
+                let Some(delay) = delay else {
+                    tracing::Span::current().record("attempts", attempt);
+                    return outcome;
+                };
+                match &outcome {
+                    Ok(response) => tracing::debug!(
+                        attempt,
+                        status = %response.status(),
+                        ?delay,
+                        "retrying backend request"
+                    ),
+                    Err(err) => tracing::debug!(
+                        attempt,
+                        error = %err,
+                        ?delay,
+                        "retrying backend request"
+                    ),
+                }
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// How long to wait before retrying after `outcome`, or `None` if it
+    /// should be returned as is.
+    fn retry_delay(
+        &self,
+        outcome: &reqwest::Result<reqwest::Response>,
+        attempt: u64,
+    ) -> Option<Duration> {
+        let RetryPolicy {
+            base_delay,
+            retry_on,
+            ..
+        } = &self.retry_policy;
+        match outcome {
+            Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                if !retry_on.retry_429 {
+                    return None;
+                }
+                match retry_after(response.headers()) {
+                    Some(delay) => (delay <= MAX_RETRY_AFTER).then_some(delay),
+                    None => Some(backoff(*base_delay, attempt)),
+                }
+            }
+            Ok(response) => (retry_on.retry_5xx && response.status().is_server_error())
+                .then(|| backoff(*base_delay, attempt)),
+            Err(err) => (retry_on.retry_transport && err.is_connect() && !err.is_timeout())
+                .then(|| backoff(*base_delay, attempt)),
+        }
+    }
+}
+
+/// Reads a `Retry-After` given in seconds. The HTTP-date form is ignored and
+/// falls back to the policy's backoff.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
@@ -1,9 +1,19 @@
 mod client;
+mod single_flight;
 pub(crate) mod types;
 
 pub use client::AddCreditsNudgeCreditType;
 pub use client::Client;
+pub use client::ClientTimeouts;
+pub use client::ConnectErrorKind;
+pub use client::DEFAULT_CONNECT_TIMEOUT;
+pub use client::DEFAULT_RATE_LIMITS_TIMEOUT;
+pub use client::DEFAULT_REQUEST_TIMEOUT;
+pub use client::DEFAULT_TASK_REQUEST_TIMEOUT;
 pub use client::RequestError;
+pub use codex_client::RetryOn;
+pub use codex_client::RetryPolicy;
+pub use single_flight::SingleFlight;
 pub use types::AccountEntry;
 pub use types::AccountsCheckResponse;
 pub use types::CodeTaskDetailsResponse;
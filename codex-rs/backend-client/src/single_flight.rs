@@ -0,0 +1,108 @@
+//! Coalescing for read-only backend calls.
+//!
+//! Frontends that poll the same endpoint at once (the TUI, the app server, the
+//! memories startup guard) would otherwise each send their own request. A
+//! [`SingleFlight`] lets concurrent callers with the same key await one
+//! in-flight request and share its result. Successful results are then held
+//! for a short window so a burst of follow-up calls is answered without
+//! another round trip; failures are only shared with the callers that were
+//! already waiting, so the next caller retries.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+
+use tokio::sync::OnceCell;
+
+pub struct SingleFlight<K, T, E> {
+    hold_for: Duration,
+    flights: Mutex<HashMap<K, Arc<Flight<T, E>>>>,
+}
+
+struct Flight<T, E> {
+    result: OnceCell<Result<T, Arc<E>>>,
+    finished_at: OnceLock<Instant>,
+}
+
+impl<K, T, E> SingleFlight<K, T, E>
+where
+    K: Eq + Hash,
+    T: Clone,
+{
+    /// Creates a group that holds successful results for `hold_for` after
+    /// they arrive. A zero duration only coalesces concurrent callers.
+    pub fn new(hold_for: Duration) -> Self {
+        Self {
+            hold_for,
+            flights: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Runs `fetch` unless a request for `key` is already in flight or
+    /// finished successfully within the hold window, in which case that
+    /// result is returned instead.
+    ///
+    /// If the caller driving the request is dropped, a waiting caller takes
+    /// over with its own `fetch`.
+    pub async fn run<F, Fut>(&self, key: K, fetch: F) -> Result<T, Arc<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let flight = self.join(key);
+        let result = flight
+            .result
+            .get_or_init(|| async { fetch().await.map_err(Arc::new) })
+            .await
+            .clone();
+        flight.finished_at.get_or_init(Instant::now);
+        result
+    }
+
+    /// Drops any held result for `key`, so the next caller fetches afresh.
+    /// Use this after a write that changes what the call would return.
+    pub fn forget(&self, key: &K) {
+        if let Ok(mut flights) = self.flights.lock() {
+            flights.remove(key);
+        }
+    }
+
+    fn join(&self, key: K) -> Arc<Flight<T, E>> {
+        let Ok(mut flights) = self.flights.lock() else {
+            return Arc::new(Flight::new());
+        };
+        let now = Instant::now();
+        flights.retain(|_, flight| flight.is_reusable(now, self.hold_for));
+        Arc::clone(
+            flights
+                .entry(key)
+                .or_insert_with(|| Arc::new(Flight::new())),
+        )
+    }
+}
+
+impl<T, E> Flight<T, E> {
+    fn new() -> Self {
+        Self {
+            result: OnceCell::new(),
+            finished_at: OnceLock::new(),
+        }
+    }
+
+    fn is_reusable(&self, now: Instant, hold_for: Duration) -> bool {
+        let Some(finished_at) = self.finished_at.get() else {
+            return true;
+        };
+        self.result.get().is_some_and(Result::is_ok)
+            && now.saturating_duration_since(*finished_at) < hold_for
+    }
+}
+
+#[cfg(test)]
+#[path = "single_flight_tests.rs"]
+mod tests;
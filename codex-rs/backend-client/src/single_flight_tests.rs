@@ -0,0 +1,46 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+async fn counted_run(
+    group: &SingleFlight<&'static str, usize, String>,
+    calls: &AtomicUsize,
+    fail: bool,
+) -> Result<usize, String> {
+    group
+        .run("usage", move || async move {
+            let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            if fail {
+                Err(format!("call {call} failed"))
+            } else {
+                Ok(call)
+            }
+        })
+        .await
+        .map_err(|err| err.to_string())
+}
+
+#[tokio::test]
+async fn successes_are_held_until_forgotten() {
+    let group = SingleFlight::new(Duration::from_secs(60));
+    let calls = AtomicUsize::new(0);
+
+    let first = counted_run(&group, &calls, /*fail*/ false).await;
+    let held = counted_run(&group, &calls, /*fail*/ false).await;
+    group.forget(&"usage");
+    let refetched = counted_run(&group, &calls, /*fail*/ false).await;
+
+    assert_eq!((first, held, refetched), (Ok(1), Ok(1), Ok(2)));
+}
+
+#[tokio::test]
+async fn failures_are_not_held() {
+    let group = SingleFlight::new(Duration::from_secs(60));
+    let calls = AtomicUsize::new(0);
+
+    let failed = counted_run(&group, &calls, /*fail*/ true).await;
+    let retried = counted_run(&group, &calls, /*fail*/ false).await;
+
+    assert_eq!((failed, retried), (Err("call 1 failed".to_string()), Ok(2)));
+}
@@ -17,6 +17,7 @@ use codex_protocol::protocol::RateLimitSnapshot;
 use serde::Deserialize;
 use serde::de::Deserializer;
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
@@ -54,6 +55,24 @@ pub(crate) struct RateLimitStatusWithResetCredits {
     pub rate_limit_reset_credits: Option<RateLimitResetCreditsSummary>,
 }
 
+/// The raw `rate_limit` objects of the usage payload. The generated models only
+/// keep `primary_window` and `secondary_window`, so any other `*_window` entry
+/// and the limit's `scope` are read from here.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub(crate) struct RawRateLimitPayload {
+    #[serde(default)]
+    pub rate_limit: Option<BTreeMap<String, Value>>,
+    #[serde(default)]
+    pub additional_rate_limits: Option<Vec<RawAdditionalRateLimit>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub(crate) struct RawAdditionalRateLimit {
+    pub metered_feature: String,
+    #[serde(default)]
+    pub rate_limit: Option<BTreeMap<String, Value>>,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub struct CodexWorkspaceMessagesResponse {
     #[serde(default)]
@@ -0,0 +1,30 @@
+use codex_backend_client::Client;
+use codex_login::CodexAuth;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[tokio::test]
+async fn get_rate_limits_from_auth_sends_chatgpt_account_id() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .and(header("authorization", "Bearer Access Token"))
+        .and(header("chatgpt-account-id", "account_id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"plan_type": "plus"})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let auth = CodexAuth::create_dummy_chatgpt_auth_for_testing();
+    let result = Client::from_auth(server.uri(), &auth)?
+        .get_rate_limits()
+        .await;
+
+    assert!(result.is_ok(), "{result:?}");
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use std::io::Write;
+
+use codex_backend_client::Client;
+use codex_protocol::account::PlanType;
+use codex_protocol::protocol::RateLimitSnapshot;
+use codex_protocol::protocol::RateLimitWindow;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::header_regex;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[tokio::test]
+async fn get_rate_limits_decodes_gzip_response() -> anyhow::Result<()> {
+    let payload = json!({
+        "plan_type": "plus",
+        "rate_limit": {
+            "primary_window": {
+                "used_percent": 42,
+                "limit_window_seconds": 300,
+                "reset_after_seconds": 0,
+                "reset_at": 123,
+            },
+        },
+    });
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(payload.to_string().as_bytes())?;
+    let body = encoder.finish()?;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .and(header_regex("accept-encoding", "gzip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "application/json")
+                .insert_header("content-encoding", "gzip")
+                .set_body_bytes(body),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let snapshot = Client::new(server.uri())?.get_rate_limits().await?;
+
+    assert_eq!(
+        snapshot,
+        RateLimitSnapshot {
+            limit_id: Some("codex".to_string()),
+            limit_name: None,
+            scope: None,
+            primary: Some(RateLimitWindow {
+                used_percent: 42.0,
+                window_minutes: Some(5),
+                resets_at: Some(123),
+            }),
+            secondary: None,
+            extra: Vec::new(),
+            credits: None,
+            individual_limit: None,
+            spend_control_reached: None,
+            plan_type: Some(PlanType::Plus),
+            rate_limit_reached_type: None,
+        }
+    );
+    Ok(())
+}
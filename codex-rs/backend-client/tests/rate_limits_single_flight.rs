@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use codex_backend_client::Client;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tokio::task::JoinSet;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+const CALLERS: usize = 16;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_rate_limit_fetches_share_one_request() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({
+                    "plan_type": "plus",
+                    "rate_limit": {
+                        "primary_window": {
+                            "used_percent": 42,
+                            "limit_window_seconds": 300,
+                            "reset_after_seconds": 0,
+                            "reset_at": 123,
+                        },
+                    },
+                }))
+                .set_delay(Duration::from_millis(200)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut callers = JoinSet::new();
+    for _ in 0..CALLERS {
+        let client = Client::new(server.uri())?;
+        callers.spawn(async move { client.get_rate_limits().await });
+    }
+    let mut snapshots = Vec::with_capacity(CALLERS);
+    while let Some(result) = callers.join_next().await {
+        snapshots.push(result??);
+    }
+    // A caller arriving just after the burst is answered from the held result.
+    snapshots.push(Client::new(server.uri())?.get_rate_limits().await?);
+
+    let expected = snapshots[0].clone();
+    assert_eq!(snapshots, vec![expected; CALLERS + 1]);
+    assert_eq!(server.received_requests().await.map(|r| r.len()), Some(1));
+    Ok(())
+}
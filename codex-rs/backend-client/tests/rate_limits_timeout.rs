@@ -0,0 +1,35 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_backend_client::Client;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[tokio::test]
+async fn get_rate_limits_times_out_without_retrying() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({"plan_type": "plus"}))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let result = Client::new(server.uri())?
+        .with_rate_limits_timeout(Duration::from_millis(200))
+        .get_rate_limits()
+        .await;
+
+    assert!(result.is_err(), "{result:?}");
+    assert!(started.elapsed() < Duration::from_secs(5));
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_backend_client::Client;
+use codex_backend_client::RequestError;
+use codex_backend_client::RetryOn;
+use codex_backend_client::RetryPolicy;
+use pretty_assertions::assert_eq;
+use reqwest::StatusCode;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+fn fast_retries(max_attempts: u64) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        base_delay: Duration::from_millis(1),
+        retry_on: RetryOn {
+            retry_429: true,
+            retry_5xx: true,
+            retry_transport: true,
+        },
+    }
+}
+
+#[tokio::test]
+async fn rate_limited_request_waits_for_retry_after_then_succeeds() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"plan_type": "plus"})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let snapshot = Client::new(server.uri())?
+        .with_retry_policy(fast_retries(/*max_attempts*/ 3))
+        .get_rate_limits()
+        .await?;
+
+    assert_eq!(snapshot.limit_id.as_deref(), Some("codex"));
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    Ok(())
+}
+
+#[tokio::test]
+async fn repeated_server_errors_exhaust_the_attempts() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/config/bundle"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("unavailable"))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let result = Client::new(server.uri())?
+        .with_retry_policy(fast_retries(/*max_attempts*/ 3))
+        .get_config_bundle()
+        .await;
+
+    assert_eq!(
+        result.as_ref().err().and_then(RequestError::status),
+        Some(StatusCode::INTERNAL_SERVER_ERROR)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_idempotent_requests_are_only_retried_when_asked() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/codex/tasks"))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = Client::new(server.uri())?
+        .with_retry_policy(fast_retries(/*max_attempts*/ 3))
+        .create_task(json!({}))
+        .await;
+    assert!(result.is_err(), "{result:?}");
+    server.verify().await;
+    server.reset().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/codex/tasks"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/codex/tasks"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"task": {"id": "task_1"}})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let task_id = Client::new(server.uri())?
+        .with_retry_policy(fast_retries(/*max_attempts*/ 3))
+        .with_non_idempotent_retries()
+        .create_task(json!({}))
+        .await?;
+
+    assert_eq!(task_id, "task_1");
+    Ok(())
+}
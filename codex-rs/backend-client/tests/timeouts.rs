@@ -0,0 +1,160 @@
+use std::net::TcpListener;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_backend_client::Client;
+use codex_backend_client::ClientTimeouts;
+use codex_backend_client::ConnectErrorKind;
+use codex_backend_client::RequestError;
+use codex_backend_client::RetryOn;
+use codex_backend_client::RetryPolicy;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+const SHORT_TIMEOUT: Duration = Duration::from_millis(300);
+const SERVER_DELAY: Duration = Duration::from_secs(10);
+/// Slack for scheduling on busy CI machines.
+const TOLERANCE: Duration = Duration::from_secs(2);
+
+fn no_retries() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 1,
+        base_delay: Duration::from_millis(1),
+        retry_on: RetryOn {
+            retry_429: false,
+            retry_5xx: false,
+            retry_transport: false,
+        },
+    }
+}
+
+#[tokio::test]
+async fn request_timeout_aborts_slow_response() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/accounts/check"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({}))
+                .set_delay(SERVER_DELAY),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let result = Client::new_with_timeouts(
+        server.uri(),
+        ClientTimeouts {
+            connect: Duration::from_secs(5),
+            request: SHORT_TIMEOUT,
+        },
+    )?
+    .get_accounts_check()
+    .await;
+    let elapsed = started.elapsed();
+
+    let err = result.expect_err("slow response should time out");
+    let err = err
+        .downcast_ref::<RequestError>()
+        .expect("timeout should surface as a RequestError");
+    assert!(err.is_timeout(), "{err:?}");
+    assert_eq!(err.connect_error_kind(), None);
+    assert!(elapsed >= SHORT_TIMEOUT, "{elapsed:?}");
+    assert!(elapsed < SHORT_TIMEOUT + TOLERANCE, "{elapsed:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn task_request_timeout_overrides_client_timeout() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/tasks/task_1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({}))
+                .set_delay(SERVER_DELAY),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let result = Client::new_with_timeouts(
+        server.uri(),
+        ClientTimeouts {
+            connect: Duration::from_secs(5),
+            request: Duration::from_secs(60),
+        },
+    )?
+    .with_task_request_timeout(SHORT_TIMEOUT)
+    .get_task_details("task_1")
+    .await;
+    let elapsed = started.elapsed();
+
+    let err = result.expect_err("slow task details should time out");
+    let err = err
+        .downcast_ref::<RequestError>()
+        .expect("timeout should surface as a RequestError");
+    assert!(err.is_timeout(), "{err:?}");
+    assert!(elapsed < SHORT_TIMEOUT + TOLERANCE, "{elapsed:?}");
+    Ok(())
+}
+
+#[tokio::test]
+async fn refused_connection_is_not_reported_as_timeout() -> anyhow::Result<()> {
+    // Reserve a free port, then close it so connecting is refused.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    drop(listener);
+
+    let result = Client::new_with_timeouts(format!("http://{addr}"), ClientTimeouts::default())?
+        .with_retry_policy(no_retries())
+        .get_accounts_check()
+        .await;
+
+    let err = result.expect_err("closed port should refuse the connection");
+    let err = err
+        .downcast_ref::<RequestError>()
+        .expect("connect failure should surface as a RequestError");
+    assert!(!err.is_timeout(), "{err:?}");
+    assert_eq!(err.connect_error_kind(), Some(ConnectErrorKind::Refused));
+    Ok(())
+}
+
+#[tokio::test]
+async fn coalesced_rate_limit_timeout_surfaces_as_request_error() -> anyhow::Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/codex/usage"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({}))
+                .set_delay(SERVER_DELAY),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Client::new_with_timeouts(server.uri(), ClientTimeouts::default())?
+        .with_rate_limits_timeout(SHORT_TIMEOUT);
+    let (first, second) = tokio::join!(
+        client.get_rate_limits_with_reset_credits(),
+        client.get_rate_limits_with_reset_credits(),
+    );
+
+    for result in [first, second] {
+        let err = result.expect_err("slow rate-limit status should time out");
+        let err = err
+            .downcast_ref::<RequestError>()
+            .expect("coalesced timeout should surface as a RequestError");
+        assert!(err.is_timeout(), "{err:?}");
+        assert_eq!(err.connect_error_kind(), None);
+    }
+    Ok(())
+}
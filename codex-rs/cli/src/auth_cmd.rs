@@ -0,0 +1,240 @@
+use anyhow::Context;
+use anyhow::Result;
+use codex_core::config::Config;
+use codex_core::config::ConfigBuilder;
+use codex_core::config::edit::ConfigEditsBuilder;
+use codex_login::AuthCredentialsStoreMode;
+use codex_login::AuthDotJson;
+use codex_login::DEFAULT_AUTH_PROFILE;
+use codex_login::auth_profile_home;
+use codex_login::list_auth_profiles;
+use codex_login::load_auth_dot_json;
+use codex_login::migrate_auth_storage;
+use codex_login::set_active_auth_profile;
+use codex_login::validate_auth_profile_name;
+use codex_protocol::auth::AuthMode;
+use codex_utils_cli::CliConfigOverrides;
+
+use self::status::StatusArgs;
+use crate::doctor::stored_auth_mode_value;
+
+mod status;
+
+/// Subcommands:
+/// - `use` — switch the active auth profile
+/// - `list` — show the auth profiles and which one is in use
+/// - `migrate` — move stored credentials between `auth.json` and the OS keyring
+/// - `status` (alias `whoami`) — show which credentials are in use
+#[derive(Debug, clap::Parser)]
+pub struct AuthCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: AuthSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum AuthSubcommand {
+    /// Use a profile's credentials wherever `--auth-profile` or the
+    /// `auth_profile` config key does not pin another one.
+    Use(UseArgs),
+
+    /// List auth profiles, marking the one used in this directory with `*`.
+    List,
+
+    /// Move every profile's stored credentials to another store and set
+    /// `cli_auth_credentials_store` to match.
+    Migrate(MigrateArgs),
+
+    /// Show which credentials this directory uses, when the access token
+    /// expires, and where the credentials are stored. Exits non-zero when not
+    /// logged in.
+    #[clap(visible_alias = "whoami")]
+    Status(StatusArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct UseArgs {
+    /// Profile to switch to. `default` selects the credentials stored
+    /// directly under `$CODEX_HOME`.
+    #[arg(value_name = "NAME")]
+    pub name: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct MigrateArgs {
+    /// Store to move the credentials into.
+    #[arg(long = "to", value_enum, value_name = "STORE")]
+    pub to: CredentialsStore,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CredentialsStore {
+    /// `auth.json` under `$CODEX_HOME` (or the profile's directory).
+    File,
+    /// The OS keyring: Keychain on macOS, Secret Service on Linux, and
+    /// Credential Manager on Windows.
+    Keyring,
+}
+
+impl AuthCli {
+    pub async fn run(self) -> Result<()> {
+        let AuthCli {
+            config_overrides,
+            subcommand,
+        } = self;
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = ConfigBuilder::default()
+            .cli_overrides(overrides)
+            .build()
+            .await
+            .context("failed to load configuration")?;
+
+        match subcommand {
+            AuthSubcommand::Use(args) => run_use(&config, &args.name),
+            AuthSubcommand::List => run_list(&config),
+            AuthSubcommand::Migrate(args) => run_migrate(&config, args.to).await,
+            AuthSubcommand::Status(args) => status::run_status(&config, args).await,
+        }
+    }
+}
+
+fn run_use(config: &Config, name: &str) -> Result<()> {
+    validate_auth_profile_name(name)?;
+    if name != DEFAULT_AUTH_PROFILE && stored_credentials(config, name)?.is_none() {
+        anyhow::bail!(
+            "Auth profile `{name}` has no stored credentials. Run `codex login --profile {name}` first."
+        );
+    }
+    set_active_auth_profile(&config.codex_home, name)?;
+    println!("Switched to auth profile `{name}`.");
+
+    let pinned = config
+        .config_layer_stack
+        .effective_config()
+        .get("auth_profile")
+        .and_then(toml::Value::as_str)
+        .map(str::to_string);
+    if let Some(pinned) = pinned
+        && pinned != name
+    {
+        println!("Auth profile `{pinned}` is pinned here by config and still takes precedence.");
+    }
+    Ok(())
+}
+
+fn run_list(config: &Config) -> Result<()> {
+    for profile in list_auth_profiles(&config.codex_home)? {
+        let marker = if profile == config.auth_profile {
+            '*'
+        } else {
+            ' '
+        };
+        let credentials = match stored_credentials(config, &profile)? {
+            Some(auth) => describe_credentials(&auth),
+            None => "not logged in".to_string(),
+        };
+        println!("{marker} {profile}\t{credentials}");
+    }
+    Ok(())
+}
+
+async fn run_migrate(config: &Config, store: CredentialsStore) -> Result<()> {
+    let (from, to, store_name) = match store {
+        CredentialsStore::File => (
+            AuthCredentialsStoreMode::Keyring,
+            AuthCredentialsStoreMode::File,
+            "file",
+        ),
+        CredentialsStore::Keyring => (
+            AuthCredentialsStoreMode::File,
+            AuthCredentialsStoreMode::Keyring,
+            "keyring",
+        ),
+    };
+    let keyring_backend_kind = config.auth_keyring_backend_kind();
+    // Check the setting can be saved before moving anything, so credentials never end up in a
+    // store the config does not point at.
+    let config_edits =
+        ConfigEditsBuilder::for_config(config).set_cli_auth_credentials_store(store_name);
+    config_edits.ensure_editable()?;
+
+    let mut moved = Vec::new();
+    for profile in list_auth_profiles(&config.codex_home)? {
+        let auth_home = auth_profile_home(&config.codex_home, &profile);
+        match migrate_auth_storage(&auth_home, from, to, keyring_backend_kind) {
+            Ok(true) => moved.push((profile, auth_home)),
+            Ok(false) => {}
+            Err(err) => {
+                // Put already-moved profiles back so every profile stays readable with the
+                // unchanged `cli_auth_credentials_store` setting.
+                for (moved_profile, moved_home) in &moved {
+                    if let Err(err) =
+                        migrate_auth_storage(moved_home, to, from, keyring_backend_kind)
+                    {
+                        eprintln!(
+                            "Warning: failed to move auth profile `{moved_profile}` back: {err}"
+                        );
+                    }
+                }
+                anyhow::bail!(
+                    "Could not move auth profile `{profile}` to the {store_name} store: {err}\nStored credentials were left where they were."
+                );
+            }
+        }
+    }
+
+    config_edits
+        .apply()
+        .await
+        .context("failed to update cli_auth_credentials_store in the user config file")?;
+    if moved.is_empty() {
+        println!("No stored credentials to move. New logins will use the {store_name} store.");
+    } else {
+        let profiles = moved
+            .iter()
+            .map(|(profile, _)| profile.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("Moved credentials for {profiles} to the {store_name} store.");
+    }
+    Ok(())
+}
+
+fn stored_credentials(config: &Config, profile: &str) -> Result<Option<AuthDotJson>> {
+    let auth_home = auth_profile_home(&config.codex_home, profile);
+    // A named profile's directory is created before anything is stored for it, so a missing
+    // directory means there is nothing to load (and no keyring entry keyed by its path).
+    if !auth_home.is_dir() {
+        return Ok(None);
+    }
+    load_auth_dot_json(
+        &auth_home,
+        config.cli_auth_credentials_store_mode,
+        config.auth_keyring_backend_kind(),
+    )
+    .with_context(|| format!("failed to read credentials for auth profile `{profile}`"))
+}
+
+fn describe_credentials(auth: &AuthDotJson) -> String {
+    match stored_auth_mode_value(auth) {
+        AuthMode::Chatgpt | AuthMode::ChatgptAuthTokens => {
+            match auth
+                .tokens
+                .as_ref()
+                .and_then(|tokens| tokens.id_token.email.as_deref())
+            {
+                Some(email) => format!("ChatGPT ({email})"),
+                None => "ChatGPT".to_string(),
+            }
+        }
+        AuthMode::ApiKey => "API key".to_string(),
+        AuthMode::Headers => "headers".to_string(),
+        AuthMode::AgentIdentity => "access token".to_string(),
+        AuthMode::PersonalAccessToken => "personal access token".to_string(),
+        AuthMode::BedrockApiKey => "Amazon Bedrock API key".to_string(),
+    }
+}
@@ -0,0 +1,354 @@
+//! `codex auth status` (alias `whoami`).
+//!
+//! The report is built from the raw stored payload, so it works offline and
+//! never refreshes tokens as a side effect. `--check` adds one authenticated
+//! models request through the doctor's provider probe, which resolves the
+//! provider and its auth exactly like model requests do.
+
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+use codex_cli::safe_format_key;
+use codex_core::config::Config;
+use codex_login::AuthCredentialsStoreMode;
+use codex_login::AuthDotJson;
+use codex_login::load_auth_dot_json;
+use codex_login::token_data::parse_jwt_expiration;
+use codex_protocol::auth::AuthMode;
+use serde::Serialize;
+
+use crate::doctor::probe_provider_credentials;
+use crate::doctor::stored_auth_mode_value;
+
+#[derive(Debug, clap::Parser)]
+pub struct StatusArgs {
+    /// Send an authenticated request to the model provider to confirm the
+    /// credentials are accepted. Exits non-zero if they are not.
+    #[arg(long)]
+    pub check: bool,
+
+    /// Print the status as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct AuthStatus {
+    profile: String,
+    credentials_store: &'static str,
+    logged_in: bool,
+    auth_mode: Option<AuthMode>,
+    /// Masked key or account email, whichever identifies the credentials.
+    account: Option<String>,
+    token_expires_at: Option<DateTime<Utc>>,
+    token_expired: Option<bool>,
+    refreshes_automatically: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check: Option<CredentialCheck>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct CredentialCheck {
+    ok: bool,
+    summary: String,
+}
+
+pub(super) async fn run_status(config: &Config, args: StatusArgs) -> Result<()> {
+    let stored = load_auth_dot_json(
+        &config.auth_home(),
+        config.cli_auth_credentials_store_mode,
+        config.auth_keyring_backend_kind(),
+    )?;
+    let mut status = auth_status(
+        &config.auth_profile,
+        config.cli_auth_credentials_store_mode,
+        stored.as_ref(),
+        Utc::now(),
+    );
+    if args.check && status.logged_in {
+        let (ok, summary) = probe_provider_credentials(config).await;
+        status.check = Some(CredentialCheck { ok, summary });
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        for line in render_status(&status) {
+            println!("{line}");
+        }
+    }
+    let healthy = status.logged_in && status.check.as_ref().is_none_or(|check| check.ok);
+    if !healthy {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn auth_status(
+    profile: &str,
+    store_mode: AuthCredentialsStoreMode,
+    stored: Option<&AuthDotJson>,
+    now: DateTime<Utc>,
+) -> AuthStatus {
+    let credentials_store = match store_mode {
+        AuthCredentialsStoreMode::File => "file",
+        AuthCredentialsStoreMode::Keyring => "keyring",
+        AuthCredentialsStoreMode::Auto => "auto",
+        AuthCredentialsStoreMode::Ephemeral => "ephemeral",
+    };
+    let Some(auth) = stored else {
+        return AuthStatus {
+            profile: profile.to_string(),
+            credentials_store,
+            logged_in: false,
+            auth_mode: None,
+            account: None,
+            token_expires_at: None,
+            token_expired: None,
+            refreshes_automatically: false,
+            check: None,
+        };
+    };
+
+    let mode = stored_auth_mode_value(auth);
+    let email = auth
+        .tokens
+        .as_ref()
+        .and_then(|tokens| tokens.id_token.email.clone());
+    let account = match mode {
+        AuthMode::ApiKey => auth.openai_api_key.as_deref().map(safe_format_key),
+        AuthMode::PersonalAccessToken => auth.personal_access_token.as_deref().map(safe_format_key),
+        AuthMode::BedrockApiKey => auth
+            .bedrock_api_key
+            .as_ref()
+            .map(|bedrock| safe_format_key(&bedrock.api_key)),
+        AuthMode::Chatgpt | AuthMode::ChatgptAuthTokens | AuthMode::AgentIdentity => email,
+        AuthMode::Headers => None,
+    };
+    let token_expires_at = match mode {
+        AuthMode::Chatgpt | AuthMode::ChatgptAuthTokens => auth
+            .tokens
+            .as_ref()
+            .and_then(|tokens| parse_jwt_expiration(&tokens.access_token).ok().flatten()),
+        AuthMode::ApiKey
+        | AuthMode::Headers
+        | AuthMode::AgentIdentity
+        | AuthMode::PersonalAccessToken
+        | AuthMode::BedrockApiKey => None,
+    };
+    // Only a managed ChatGPT login holds a refresh token Codex can use; tokens
+    // supplied by a host app are refreshed by that app.
+    let refreshes_automatically = mode == AuthMode::Chatgpt
+        && auth
+            .tokens
+            .as_ref()
+            .is_some_and(|tokens| !tokens.refresh_token.is_empty());
+
+    AuthStatus {
+        profile: profile.to_string(),
+        credentials_store,
+        logged_in: true,
+        auth_mode: Some(mode),
+        account,
+        token_expires_at,
+        token_expired: token_expires_at.map(|expires_at| expires_at <= now),
+        refreshes_automatically,
+        check: None,
+    }
+}
+
+fn render_status(status: &AuthStatus) -> Vec<String> {
+    let mut lines = vec![
+        format!("Auth profile: {}", status.profile),
+        format!("Credentials store: {}", status.credentials_store),
+    ];
+    if !status.logged_in {
+        lines.push("Not logged in".to_string());
+        return lines;
+    }
+
+    let mode = status
+        .auth_mode
+        .map(auth_mode_description)
+        .unwrap_or_default();
+    lines.push(match status.account.as_deref() {
+        Some(account) => format!("Logged in using {mode} - {account}"),
+        None => format!("Logged in using {mode}"),
+    });
+    if let Some(expires_at) = status.token_expires_at {
+        let expires_at = expires_at.format("%Y-%m-%d %H:%M UTC");
+        let state = if status.token_expired == Some(true) {
+            format!("Access token expired at {expires_at}")
+        } else {
+            format!("Access token expires at {expires_at}")
+        };
+        lines.push(if status.refreshes_automatically {
+            format!("{state} (refreshing automatically)")
+        } else {
+            state
+        });
+    }
+    if let Some(check) = status.check.as_ref() {
+        let outcome = if check.ok { "ok" } else { "failed" };
+        lines.push(format!("Credential check: {outcome} - {}", check.summary));
+    }
+    lines
+}
+
+fn auth_mode_description(mode: AuthMode) -> &'static str {
+    match mode {
+        AuthMode::ApiKey => "an API key",
+        AuthMode::Chatgpt | AuthMode::ChatgptAuthTokens => "ChatGPT",
+        AuthMode::Headers => "request headers",
+        AuthMode::AgentIdentity => "an access token",
+        AuthMode::PersonalAccessToken => "a personal access token",
+        AuthMode::BedrockApiKey => "an Amazon Bedrock API key",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use codex_login::TokenData;
+    use codex_login::token_data::IdTokenInfo;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    const EXPIRES_AT: i64 = 1_800_000_000;
+    /// Unsigned JWT whose payload is `{"exp":1800000000}`.
+    const ACCESS_TOKEN: &str = "eyJhbGciOiJub25lIn0.eyJleHAiOjE4MDAwMDAwMDB9.sig";
+
+    fn timestamp(secs: i64) -> anyhow::Result<DateTime<Utc>> {
+        DateTime::from_timestamp(secs, /*nsecs*/ 0).context("timestamp out of range")
+    }
+
+    fn chatgpt_auth(auth_mode: AuthMode) -> AuthDotJson {
+        AuthDotJson {
+            auth_mode: Some(auth_mode),
+            openai_api_key: None,
+            tokens: Some(TokenData {
+                id_token: IdTokenInfo {
+                    email: Some("user@example.com".to_string()),
+                    ..Default::default()
+                },
+                access_token: ACCESS_TOKEN.to_string(),
+                refresh_token: "refresh-token".to_string(),
+                account_id: None,
+            }),
+            last_refresh: None,
+            agent_identity: None,
+            personal_access_token: None,
+            bedrock_api_key: None,
+        }
+    }
+
+    fn expected_chatgpt_status(
+        auth_mode: AuthMode,
+        expired: bool,
+        refreshes_automatically: bool,
+    ) -> anyhow::Result<AuthStatus> {
+        Ok(AuthStatus {
+            profile: "work".to_string(),
+            credentials_store: "keyring",
+            logged_in: true,
+            auth_mode: Some(auth_mode),
+            account: Some("user@example.com".to_string()),
+            token_expires_at: Some(timestamp(EXPIRES_AT)?),
+            token_expired: Some(expired),
+            refreshes_automatically,
+            check: None,
+        })
+    }
+
+    #[test]
+    fn valid_chatgpt_token_reports_its_expiry() -> anyhow::Result<()> {
+        let now = timestamp(EXPIRES_AT - 3_600)?;
+        let status = auth_status(
+            "work",
+            AuthCredentialsStoreMode::Keyring,
+            Some(&chatgpt_auth(AuthMode::Chatgpt)),
+            now,
+        );
+
+        assert_eq!(
+            status,
+            expected_chatgpt_status(
+                AuthMode::Chatgpt,
+                /*expired*/ false,
+                /*refreshes_automatically*/ true,
+            )?
+        );
+        assert_eq!(
+            render_status(&status),
+            vec![
+                "Auth profile: work".to_string(),
+                "Credentials store: keyring".to_string(),
+                "Logged in using ChatGPT - user@example.com".to_string(),
+                "Access token expires at 2027-01-15 08:00 UTC (refreshing automatically)"
+                    .to_string(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expired_external_token_is_not_refreshed_by_codex() -> anyhow::Result<()> {
+        let now = timestamp(EXPIRES_AT + 60)?;
+        let status = auth_status(
+            "work",
+            AuthCredentialsStoreMode::Keyring,
+            Some(&chatgpt_auth(AuthMode::ChatgptAuthTokens)),
+            now,
+        );
+
+        assert_eq!(
+            status,
+            expected_chatgpt_status(
+                AuthMode::ChatgptAuthTokens,
+                /*expired*/ true,
+                /*refreshes_automatically*/ false,
+            )?
+        );
+        assert_eq!(
+            render_status(&status).last().map(String::as_str),
+            Some("Access token expired at 2027-01-15 08:00 UTC")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn api_key_status_masks_the_key() -> anyhow::Result<()> {
+        let auth = AuthDotJson {
+            auth_mode: Some(AuthMode::ApiKey),
+            openai_api_key: Some("sk-proj-1234567890ABCDE".to_string()),
+            tokens: None,
+            last_refresh: None,
+            agent_identity: None,
+            personal_access_token: None,
+            bedrock_api_key: None,
+        };
+        let status = auth_status(
+            "default",
+            AuthCredentialsStoreMode::File,
+            Some(&auth),
+            Utc::now(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&status)?,
+            json!({
+                "profile": "default",
+                "credentials_store": "file",
+                "logged_in": true,
+                "auth_mode": "apikey",
+                "account": "sk-proj-***ABCDE",
+                "token_expires_at": null,
+                "token_expired": null,
+                "refreshes_automatically": false,
+            })
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_config::config_toml::ConfigToml;
+use codex_config::merge_toml_values;
+use codex_core::config::Config;
+use codex_core::config::ConfigBuilder;
+use codex_protocol::config_types::SandboxMode;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_utils_cli::CliConfigOverrides;
+use serde::Serialize;
+use toml::Value as TomlValue;
+
+/// Subcommands:
+/// - `diff` — show config keys whose value differs from the built-in defaults
+#[derive(Debug, clap::Parser)]
+pub struct ConfigCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: ConfigSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ConfigSubcommand {
+    /// Show config keys whose effective value differs from the built-in defaults.
+    Diff(DiffArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DiffArgs {
+    /// Config file to inspect instead of the effective config (merged layers
+    /// plus `-c` overrides).
+    #[arg(value_name = "FILE")]
+    pub file: Option<PathBuf>,
+
+    /// Compare against this config file instead of the built-in defaults.
+    #[arg(long, value_name = "FILE")]
+    pub against: Option<PathBuf>,
+
+    /// Output the differences as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// A single dotted config key whose value differs between the two sides.
+///
+/// `None` means the key is unset on that side.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct ConfigDiffEntry {
+    pub key: String,
+    pub base: Option<TomlValue>,
+    pub effective: Option<TomlValue>,
+}
+
+impl ConfigCli {
+    pub async fn run(self) -> Result<()> {
+        let ConfigCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        match subcommand {
+            ConfigSubcommand::Diff(args) => run_diff(&config_overrides, args).await?,
+        }
+
+        Ok(())
+    }
+}
+
+async fn run_diff(config_overrides: &CliConfigOverrides, args: DiffArgs) -> Result<()> {
+    let DiffArgs {
+        file,
+        against,
+        json,
+    } = args;
+
+    let effective = match file {
+        Some(path) => read_config_file(&path)?,
+        None => {
+            let overrides = config_overrides
+                .parse_overrides()
+                .map_err(anyhow::Error::msg)?;
+            let config = ConfigBuilder::default()
+                .cli_overrides(overrides)
+                .build()
+                .await
+                .context("failed to load configuration")?;
+            config.config_layer_stack.effective_config()
+        }
+    };
+    let defaults = Config::load_default_with_cli_overrides(Vec::new())
+        .await
+        .context("failed to load default configuration")?;
+    let defaults = resolved_defaults(&defaults)?;
+    let base = match against {
+        Some(path) => read_config_file(&path)?,
+        None => defaults.clone(),
+    };
+
+    let entries = diff_configs(
+        &normalize_config(with_defaults(&defaults, base), "base config")?,
+        &normalize_config(with_defaults(&defaults, effective), "effective config")?,
+    );
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No differences.");
+        return Ok(());
+    }
+    for entry in entries {
+        println!(
+            "{}: {} -> {}",
+            entry.key,
+            format_value(entry.base.as_ref()),
+            format_value(entry.effective.as_ref())
+        );
+    }
+
+    Ok(())
+}
+
+fn read_config_file(path: &Path) -> Result<TomlValue> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    toml::from_str(&contents).with_context(|| format!("failed to parse {}", path.display()))
+}
+
+/// Built-in values for the top-level settings Codex fills in when they are
+/// unset, taken from a config loaded without any config files.
+fn resolved_defaults(config: &Config) -> Result<TomlValue> {
+    let sandbox_mode = match config.legacy_sandbox_policy() {
+        SandboxPolicy::DangerFullAccess => Some(SandboxMode::DangerFullAccess),
+        SandboxPolicy::ReadOnly { .. } => Some(SandboxMode::ReadOnly),
+        SandboxPolicy::WorkspaceWrite { .. } => Some(SandboxMode::WorkspaceWrite),
+        SandboxPolicy::ExternalSandbox { .. } => None,
+    };
+    let defaults = ConfigToml {
+        model_provider: Some(config.model_provider_id.clone()),
+        approval_policy: Some(config.permissions.approval_policy.value()),
+        sandbox_mode,
+        cli_auth_credentials_store: Some(config.cli_auth_credentials_store_mode),
+        mcp_oauth_credentials_store: Some(config.mcp_oauth_credentials_store_mode),
+        project_doc_max_bytes: Some(config.project_doc_max_bytes),
+        file_opener: Some(config.file_opener),
+        hide_agent_reasoning: Some(config.hide_agent_reasoning),
+        show_raw_agent_reasoning: Some(config.show_raw_agent_reasoning),
+        ..ConfigToml::default()
+    };
+    TomlValue::try_from(&defaults).context("failed to serialize default configuration")
+}
+
+/// Layer a config over the resolved defaults so settings it leaves unset
+/// compare as their default value rather than as unset.
+fn with_defaults(defaults: &TomlValue, config: TomlValue) -> TomlValue {
+    let mut merged = defaults.clone();
+    merge_toml_values(&mut merged, &config);
+    merged
+}
+
+/// Round-trip a raw config table through [`ConfigToml`] so serde defaults are
+/// filled in and equivalent spellings compare equal, then flatten it into
+/// dotted keys.
+fn normalize_config(value: TomlValue, label: &str) -> Result<BTreeMap<String, TomlValue>> {
+    let config: ConfigToml = value
+        .try_into()
+        .with_context(|| format!("failed to deserialize {label}"))?;
+    let value =
+        TomlValue::try_from(&config).with_context(|| format!("failed to serialize {label}"))?;
+    let mut flattened = BTreeMap::new();
+    flatten_value("", &value, &mut flattened);
+    Ok(flattened)
+}
+
+fn flatten_value(prefix: &str, value: &TomlValue, out: &mut BTreeMap<String, TomlValue>) {
+    match value {
+        TomlValue::Table(table) => {
+            for (key, child) in table {
+                let key = format_key_segment(key);
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_value(&path, child, out);
+            }
+        }
+        _ => {
+            out.insert(prefix.to_string(), value.clone());
+        }
+    }
+}
+
+/// Quote key segments that are not bare TOML keys, such as project paths.
+fn format_key_segment(key: &str) -> String {
+    let is_bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if is_bare {
+        key.to_string()
+    } else {
+        format!("{key:?}")
+    }
+}
+
+pub(crate) fn diff_configs(
+    base: &BTreeMap<String, TomlValue>,
+    effective: &BTreeMap<String, TomlValue>,
+) -> Vec<ConfigDiffEntry> {
+    let keys: BTreeSet<&String> = base.keys().chain(effective.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let base = base.get(key);
+            let effective = effective.get(key);
+            (base != effective).then(|| ConfigDiffEntry {
+                key: key.clone(),
+                base: base.cloned(),
+                effective: effective.cloned(),
+            })
+        })
+        .collect()
+}
+
+fn format_value(value: Option<&TomlValue>) -> String {
+    match value {
+        Some(TomlValue::String(value)) => value.clone(),
+        Some(value) => value.to_string(),
+        None => "<unset>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn normalized(contents: &str) -> BTreeMap<String, TomlValue> {
+        normalize_config(toml::from_str(contents).expect("parse toml"), "test config")
+            .expect("normalize config")
+    }
+
+    #[test]
+    fn diff_reports_only_changed_keys() {
+        let base = normalized(
+            r#"
+model = "gpt-5"
+sandbox_mode = "read-only"
+"#,
+        );
+        let effective = normalized(
+            r#"
+model = "gpt-5"
+sandbox_mode = "workspace-write"
+
+[projects."/tmp/project"]
+trust_level = "trusted"
+"#,
+        );
+
+        assert_eq!(
+            diff_configs(&base, &effective),
+            vec![
+                ConfigDiffEntry {
+                    key: r#"projects."/tmp/project".trust_level"#.to_string(),
+                    base: None,
+                    effective: Some(TomlValue::String("trusted".to_string())),
+                },
+                ConfigDiffEntry {
+                    key: "sandbox_mode".to_string(),
+                    base: Some(TomlValue::String("read-only".to_string())),
+                    effective: Some(TomlValue::String("workspace-write".to_string())),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unset_keys_compare_as_their_default() {
+        let defaults: TomlValue = toml::from_str(
+            r#"
+approval_policy = "on-request"
+sandbox_mode = "read-only"
+"#,
+        )
+        .expect("parse defaults");
+        let effective: TomlValue =
+            toml::from_str(r#"sandbox_mode = "workspace-write""#).expect("parse effective");
+
+        let base = normalize_config(with_defaults(&defaults, defaults.clone()), "defaults")
+            .expect("normalize defaults");
+        let effective = normalize_config(with_defaults(&defaults, effective), "effective")
+            .expect("normalize effective");
+
+        assert_eq!(
+            diff_configs(&base, &effective),
+            vec![ConfigDiffEntry {
+                key: "sandbox_mode".to_string(),
+                base: Some(TomlValue::String("read-only".to_string())),
+                effective: Some(TomlValue::String("workspace-write".to_string())),
+            }]
+        );
+    }
+
+    #[test]
+    fn defaults_do_not_differ_from_themselves() {
+        let defaults = normalized("");
+        assert_eq!(diff_configs(&defaults, &defaults), Vec::new());
+    }
+}
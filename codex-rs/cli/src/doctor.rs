@@ -70,6 +70,7 @@ mod background;
 mod git;
 mod output;
 mod progress;
+mod provider_probe;
 mod runtime;
 mod system;
 mod thread_inventory;
@@ -83,6 +84,7 @@ use output::redact_detail;
 use output::render_human_report;
 use progress::DoctorProgress;
 use progress::doctor_progress;
+use provider_probe::provider_probe_check;
 use runtime::runtime_check;
 use runtime::search_check;
 use system::system_check;
@@ -368,6 +370,7 @@ async fn build_report(
                 thread_inventory_check,
                 background_server_check,
                 reachability_check,
+                provider_probe_check,
             ) = tokio::join!(
                 async { run_sync_check("config", progress.clone(), || config_check(config)) },
                 async { run_sync_check("auth", progress.clone(), || auth_check(config)) },
@@ -376,7 +379,7 @@ async fn build_report(
                 run_async_check(
                     "websocket",
                     progress.clone(),
-                    websocket_reachability_check(config, Some(auth_manager)),
+                    websocket_reachability_check(config, Some(auth_manager.clone())),
                 ),
                 run_async_check("MCP", progress.clone(), mcp_check(config)),
                 async {
@@ -411,6 +414,11 @@ async fn build_report(
                     progress.clone(),
                     provider_reachability_check(reachability_plan),
                 ),
+                run_async_check(
+                    "provider probe",
+                    progress.clone(),
+                    provider_probe_check(config, Some(auth_manager)),
+                ),
             );
             checks.extend([
                 config_check,
@@ -427,6 +435,7 @@ async fn build_report(
                 thread_inventory_check,
                 background_server_check,
                 reachability_check,
+                provider_probe_check,
             ]);
         }
         Err(err) => {
@@ -1174,7 +1183,9 @@ fn config_toml_details(config: &Config, details: &mut Vec<String>) {
 
 fn auth_check(config: &Config) -> DoctorCheck {
     let mut details = Vec::new();
-    let auth_path = config.codex_home.join("auth.json");
+    let auth_home = config.auth_home();
+    let auth_path = auth_home.join("auth.json");
+    details.push(format!("auth profile: {}", config.auth_profile));
     details.push(format!(
         "auth storage mode: {:?}",
         config.cli_auth_credentials_store_mode
@@ -1206,7 +1217,7 @@ fn auth_check(config: &Config) -> DoctorCheck {
     }
 
     match load_auth_dot_json(
-        &config.codex_home,
+        &auth_home,
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
     ) {
@@ -1342,7 +1353,16 @@ fn stored_auth_mode(auth: &codex_login::AuthDotJson) -> &'static str {
     }
 }
 
-fn stored_auth_mode_value(auth: &AuthDotJson) -> AuthMode {
+/// Runs the authenticated provider probe on its own and reports whether the
+/// provider accepted the credentials, with the probe's summary.
+pub(crate) async fn probe_provider_credentials(config: &Config) -> (bool, String) {
+    let auth_manager =
+        AuthManager::shared_from_config(config, /*enable_codex_api_key_env*/ true).await;
+    let check = provider_probe_check(config, Some(auth_manager)).await;
+    (check.status == CheckStatus::Ok, check.summary)
+}
+
+pub(crate) fn stored_auth_mode_value(auth: &AuthDotJson) -> AuthMode {
     if let Some(mode) = auth.auth_mode {
         return mode;
     }
@@ -1613,6 +1633,29 @@ async fn mcp_check_from_servers(servers: &HashMap<String, McpServerConfig>) -> D
                     }
                 }
             }
+            McpServerTransportConfig::WebSocket {
+                bearer_token_env_var,
+                env_http_headers,
+                ..
+            } => {
+                *transport_counts.entry("websocket").or_default() += 1;
+                if disabled_server {
+                    continue;
+                }
+                if let Some(env_var) = bearer_token_env_var
+                    && !env_var_present(env_var)
+                {
+                    missing_env.push(format!("{name}: bearer token env var {env_var} is not set"));
+                }
+                if let Some(headers) = env_http_headers {
+                    for env_var in headers.values() {
+                        if !env_var_present(env_var) {
+                            missing_env
+                                .push(format!("{name}: header env var {env_var} is not set"));
+                        }
+                    }
+                }
+            }
         }
     }
 
@@ -2162,6 +2205,7 @@ fn non_empty_trimmed(value: String) -> Option<String> {
 async fn state_check(config: &Config) -> DoctorCheck {
     let mut details = Vec::new();
     path_readiness(&mut details, "CODEX_HOME", &config.codex_home);
+    let codex_home_writable = codex_home_write_probe(&mut details, &config.codex_home);
     path_readiness(&mut details, "log dir", &config.log_dir);
     path_readiness(&mut details, "sqlite home", &config.sqlite_home);
     let mut integrity_failures = Vec::new();
@@ -2172,6 +2216,19 @@ async fn state_check(config: &Config) -> DoctorCheck {
     rollout_stats_details(&mut details, &config.codex_home);
     standalone_release_cache_details(&mut details);
 
+    if !codex_home_writable {
+        return DoctorCheck::new(
+            "state.paths",
+            "state",
+            CheckStatus::Fail,
+            "CODEX_HOME is not writable",
+        )
+        .details(details)
+        .remediation(
+            "Fix the ownership or permissions of CODEX_HOME, or point CODEX_HOME at a writable directory.",
+        );
+    }
+
     let status = if integrity_failures.is_empty() {
         CheckStatus::Ok
     } else {
@@ -2191,6 +2248,29 @@ async fn state_check(config: &Config) -> DoctorCheck {
     check
 }
 
+/// Creates and removes a scratch file in CODEX_HOME. A missing CODEX_HOME is
+/// not an error because Codex creates it on first use.
+fn codex_home_write_probe(details: &mut Vec<String>, codex_home: &Path) -> bool {
+    if !codex_home.exists() {
+        details.push("CODEX_HOME writable: skipped (missing)".to_string());
+        return true;
+    }
+
+    match tempfile::Builder::new()
+        .prefix(".codex-doctor-")
+        .tempfile_in(codex_home)
+    {
+        Ok(_) => {
+            details.push("CODEX_HOME writable: yes".to_string());
+            true
+        }
+        Err(err) => {
+            details.push(format!("CODEX_HOME writable: no ({err})"));
+            false
+        }
+    }
+}
+
 async fn sqlite_integrity_detail(
     details: &mut Vec<String>,
     integrity_failures: &mut Vec<String>,
@@ -2567,7 +2647,7 @@ impl ProviderAuthReachabilityMode {
 
 fn provider_reachability_plan(config: &Config) -> ReachabilityPlan {
     let stored_auth = load_auth_dot_json(
-        &config.codex_home,
+        &config.auth_home(),
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
     )
@@ -3811,6 +3891,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn codex_home_write_probe_reports_writable_and_missing_homes() {
+        let temp = tempfile::tempdir().expect("create temp dir");
+        let mut details = Vec::new();
+
+        let writable = codex_home_write_probe(&mut details, temp.path());
+        let missing = codex_home_write_probe(&mut details, &temp.path().join("missing"));
+
+        assert_eq!((writable, missing), (true, true));
+        assert_eq!(
+            details,
+            vec![
+                "CODEX_HOME writable: yes".to_string(),
+                "CODEX_HOME writable: skipped (missing)".to_string(),
+            ]
+        );
+        assert_eq!(
+            std::fs::read_dir(temp.path())
+                .expect("read temp dir")
+                .count(),
+            0
+        );
+    }
+
     #[test]
     fn collect_rollout_stats_counts_nested_rollout_files() {
         let temp = tempfile::tempdir().expect("create temp dir");
@@ -40,7 +40,7 @@ const GROUPS: &[OutputGroup] = &[
     },
     OutputGroup {
         title: "Connectivity",
-        keys: &["network", "websocket", "reachability"],
+        keys: &["network", "websocket", "reachability", "provider"],
     },
     OutputGroup {
         title: "Background Server",
@@ -629,6 +629,7 @@ fn display_summary(check: &DoctorCheck, options: HumanOutputOptions) -> String {
         "sandbox" => sandbox_summary(check),
         "network" => network_summary(check),
         "websocket" => websocket_summary(check),
+        "provider" => provider_summary(check),
         "app-server" => app_server_summary(check),
         _ => check.summary.clone(),
     }
@@ -773,6 +774,18 @@ fn websocket_summary(check: &DoctorCheck) -> String {
     }
 }
 
+fn provider_summary(check: &DoctorCheck) -> String {
+    match detail::detail_value(check, "latency") {
+        Some(latency) if check.status == CheckStatus::Ok => {
+            format!(
+                "authenticated request ok · {}",
+                latency.replace(" ms", "ms")
+            )
+        }
+        _ => check.summary.clone(),
+    }
+}
+
 fn app_server_summary(check: &DoctorCheck) -> String {
     let status = detail::detail_value(check, "status");
     let mode = detail::detail_value(check, "mode");
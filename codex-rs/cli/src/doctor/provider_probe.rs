@@ -0,0 +1,236 @@
+//! Probes the active model provider with an authenticated models-list request.
+//!
+//! The reachability check only proves that provider URLs answer unauthenticated
+//! requests. This probe resolves the provider and its auth exactly like model
+//! requests do, so the request carries the same headers (including
+//! `ChatGPT-Account-ID` for ChatGPT auth) and catches rejected credentials as
+//! well as broken routes. The row reports the HTTP outcome and round-trip
+//! latency.
+
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_api::ApiError;
+use codex_api::ModelsClient;
+use codex_api::ReqwestTransport;
+use codex_api::TransportError;
+use codex_core::config::Config;
+use codex_login::AuthManager;
+use codex_login::CodexAuth;
+use codex_login::default_client::build_reqwest_client;
+use codex_model_provider::create_model_provider;
+use http::HeaderMap;
+use http::StatusCode;
+
+use super::CheckStatus;
+use super::DoctorCheck;
+use super::auth_mode_name;
+use super::should_probe_models_route;
+
+const CHECK_ID: &str = "network.provider_probe";
+const CATEGORY: &str = "provider";
+const CHATGPT_ACCOUNT_ID_HEADER: &str = "chatgpt-account-id";
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Builds the authenticated provider probe row.
+///
+/// Providers without a models route (Azure Responses and Amazon Bedrock) and
+/// providers that need OpenAI credentials when none are configured are
+/// skipped; the reachability and auth rows already cover those cases.
+pub(super) async fn provider_probe_check(
+    config: &Config,
+    auth_manager: Option<Arc<AuthManager>>,
+) -> DoctorCheck {
+    let provider = &config.model_provider;
+    let mut details = vec![format!("model provider: {}", config.model_provider_id)];
+
+    if !should_probe_models_route(
+        &provider.name,
+        provider.base_url.as_deref().unwrap_or_default(),
+        provider.is_amazon_bedrock(),
+    ) {
+        return DoctorCheck::new(
+            CHECK_ID,
+            CATEGORY,
+            CheckStatus::Ok,
+            "active provider has no models endpoint to probe",
+        )
+        .details(details);
+    }
+
+    let runtime_provider = create_model_provider(provider.clone(), auth_manager);
+    let auth = runtime_provider.auth().await;
+    details.push(format!(
+        "auth mode: {}",
+        auth.as_ref().map(auth_mode_name).unwrap_or("none")
+    ));
+    if provider.requires_openai_auth && auth.is_none() {
+        return DoctorCheck::new(
+            CHECK_ID,
+            CATEGORY,
+            CheckStatus::Ok,
+            "provider probe skipped because no credentials are configured",
+        )
+        .details(details);
+    }
+
+    let api_provider = match runtime_provider.api_provider().await {
+        Ok(api_provider) => api_provider,
+        Err(err) => {
+            details.push(format!("provider setup failed: {err}"));
+            return probe_warning("provider probe could not be set up", details);
+        }
+    };
+    let api_auth = match runtime_provider.api_auth().await {
+        Ok(api_auth) => api_auth,
+        Err(err) => {
+            details.push(format!("auth resolution failed: {err}"));
+            return probe_warning("provider probe auth could not be resolved", details);
+        }
+    };
+
+    let request_url =
+        ModelsClient::<ReqwestTransport>::request_url(&api_provider, env!("CARGO_PKG_VERSION"));
+    details.push(format!("endpoint: {request_url}"));
+    if auth.as_ref().is_some_and(CodexAuth::uses_codex_backend) {
+        let attached = api_auth
+            .to_auth_headers()
+            .contains_key(CHATGPT_ACCOUNT_ID_HEADER);
+        details.push(format!("ChatGPT-Account-ID header attached: {attached}"));
+    }
+
+    let client = ModelsClient::new(
+        ReqwestTransport::new(build_reqwest_client()),
+        api_provider,
+        api_auth,
+    );
+    let started = Instant::now();
+    let result = tokio::time::timeout(
+        PROBE_TIMEOUT,
+        client.list_models(request_url, HeaderMap::new()),
+    )
+    .await;
+    details.push(format!("latency: {} ms", started.elapsed().as_millis()));
+
+    match result {
+        Ok(Ok((models, _etag))) => {
+            details.push(format!("models listed: {}", models.len()));
+            DoctorCheck::new(
+                CHECK_ID,
+                CATEGORY,
+                CheckStatus::Ok,
+                "provider accepted an authenticated models request",
+            )
+            .details(details)
+        }
+        // The models client only reports stream errors after a successful
+        // response whose body is not a Codex models list, which is common for
+        // third-party providers and still proves the route and auth work.
+        Ok(Err(ApiError::Stream(_))) => {
+            details.push("response: not a Codex models list".to_string());
+            DoctorCheck::new(
+                CHECK_ID,
+                CATEGORY,
+                CheckStatus::Ok,
+                "provider accepted an authenticated models request",
+            )
+            .details(details)
+        }
+        Ok(Err(err)) => {
+            let status = error_status(&err);
+            details.push(match status {
+                Some(status) => format!("result: HTTP {}", status.as_u16()),
+                None => format!("result: {err}"),
+            });
+            let (status, summary) = failed_probe_outcome(status);
+            let mut check = DoctorCheck::new(CHECK_ID, CATEGORY, status, summary).details(details);
+            if status == CheckStatus::Fail {
+                check = check.remediation(
+                    "Run codex login again, or check the API key and account configured for this provider.",
+                );
+            }
+            check
+        }
+        Err(_) => {
+            details.push(format!(
+                "result: no response within {} s",
+                PROBE_TIMEOUT.as_secs()
+            ));
+            probe_warning("provider models request timed out", details)
+        }
+    }
+}
+
+fn error_status(err: &ApiError) -> Option<StatusCode> {
+    match err {
+        ApiError::Transport(TransportError::Http { status, .. }) | ApiError::Api { status, .. } => {
+            Some(*status)
+        }
+        _ => None,
+    }
+}
+
+/// Rejected credentials fail the row; other HTTP and transport errors only
+/// warn because the reachability row already reports connectivity problems.
+fn failed_probe_outcome(status: Option<StatusCode>) -> (CheckStatus, &'static str) {
+    match status {
+        Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => (
+            CheckStatus::Fail,
+            "provider rejected the configured credentials",
+        ),
+        Some(_) => (
+            CheckStatus::Warning,
+            "provider models request returned an HTTP error",
+        ),
+        None => (CheckStatus::Warning, "provider models request failed"),
+    }
+}
+
+fn probe_warning(summary: &'static str, details: Vec<String>) -> DoctorCheck {
+    DoctorCheck::new(CHECK_ID, CATEGORY, CheckStatus::Warning, summary)
+        .details(details)
+        .remediation("Check proxy, VPN, firewall, DNS, and custom CA configuration.")
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use pretty_assertions::assert_eq;
+
+    use super::CheckStatus;
+    use super::failed_probe_outcome;
+
+    #[test]
+    fn rejected_credentials_fail_and_other_errors_warn() {
+        assert_eq!(
+            [
+                Some(StatusCode::UNAUTHORIZED),
+                Some(StatusCode::FORBIDDEN),
+                Some(StatusCode::NOT_FOUND),
+                Some(StatusCode::INTERNAL_SERVER_ERROR),
+                None,
+            ]
+            .map(failed_probe_outcome),
+            [
+                (
+                    CheckStatus::Fail,
+                    "provider rejected the configured credentials"
+                ),
+                (
+                    CheckStatus::Fail,
+                    "provider rejected the configured credentials"
+                ),
+                (
+                    CheckStatus::Warning,
+                    "provider models request returned an HTTP error"
+                ),
+                (
+                    CheckStatus::Warning,
+                    "provider models request returned an HTTP error"
+                ),
+                (CheckStatus::Warning, "provider models request failed"),
+            ]
+        );
+    }
+}
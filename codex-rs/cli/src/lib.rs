@@ -21,6 +21,7 @@ pub use login::run_login_with_chatgpt;
 pub use login::run_login_with_device_code;
 pub use login::run_login_with_device_code_fallback_to_browser;
 pub use login::run_logout;
+pub use login::safe_format_key;
 
 #[derive(Debug, Default, Args)]
 pub struct SandboxStateArgs {
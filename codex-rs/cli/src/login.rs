@@ -176,7 +176,7 @@ pub async fn run_login_with_chatgpt(cli_config_overrides: CliConfigOverrides) ->
 
     let forced_chatgpt_workspace_id = config.forced_chatgpt_workspace_id.clone();
     match login_with_chatgpt(
-        config.codex_home.to_path_buf(),
+        config.auth_home(),
         forced_chatgpt_workspace_id,
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
@@ -209,7 +209,7 @@ pub async fn run_login_with_api_key(
     }
 
     match login_with_api_key(
-        &config.codex_home,
+        &config.auth_home(),
         &api_key,
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
@@ -240,7 +240,7 @@ pub async fn run_login_with_access_token(
 
     let auth_route_config = config.auth_route_config();
     match login_with_access_token(
-        &config.codex_home,
+        &config.auth_home(),
         &access_token,
         config.cli_auth_credentials_store_mode,
         config.forced_chatgpt_workspace_id.as_deref(),
@@ -317,7 +317,7 @@ pub async fn run_login_with_device_code(
     }
     let auth_route_config = config.auth_route_config();
     clear_existing_auth_before_login(
-        &config.codex_home,
+        &config.auth_home(),
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
         auth_route_config.as_ref(),
@@ -325,7 +325,7 @@ pub async fn run_login_with_device_code(
     .await;
     let forced_chatgpt_workspace_id = config.forced_chatgpt_workspace_id.clone();
     let mut opts = ServerOptions::new(
-        config.codex_home.to_path_buf(),
+        config.auth_home(),
         client_id.unwrap_or(CLIENT_ID.to_string()),
         forced_chatgpt_workspace_id,
         config.cli_auth_credentials_store_mode,
@@ -365,7 +365,7 @@ pub async fn run_login_with_device_code_fallback_to_browser(
     }
     let auth_route_config = config.auth_route_config();
     clear_existing_auth_before_login(
-        &config.codex_home,
+        &config.auth_home(),
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
         auth_route_config.as_ref(),
@@ -374,7 +374,7 @@ pub async fn run_login_with_device_code_fallback_to_browser(
 
     let forced_chatgpt_workspace_id = config.forced_chatgpt_workspace_id.clone();
     let mut opts = ServerOptions::new(
-        config.codex_home.to_path_buf(),
+        config.auth_home(),
         client_id.unwrap_or(CLIENT_ID.to_string()),
         forced_chatgpt_workspace_id,
         config.cli_auth_credentials_store_mode,
@@ -426,7 +426,7 @@ pub async fn run_login_status(cli_config_overrides: CliConfigOverrides) -> ! {
     let auth_route_config = config.auth_route_config();
 
     match CodexAuth::from_auth_storage(
-        &config.codex_home,
+        &config.auth_home(),
         config.cli_auth_credentials_store_mode,
         Some(&config.chatgpt_base_url),
         config.auth_keyring_backend_kind(),
@@ -481,7 +481,7 @@ pub async fn run_logout(cli_config_overrides: CliConfigOverrides) -> ! {
     let auth_route_config = config.auth_route_config();
 
     match logout_with_revoke(
-        &config.codex_home,
+        &config.auth_home(),
         config.cli_auth_credentials_store_mode,
         config.auth_keyring_backend_kind(),
         auth_route_config.as_ref(),
@@ -521,7 +521,8 @@ async fn load_config_or_exit(cli_config_overrides: CliConfigOverrides) -> Config
     }
 }
 
-fn safe_format_key(key: &str) -> String {
+/// Masks all but the start and end of an API key for display.
+pub fn safe_format_key(key: &str) -> String {
     if key.len() <= 13 {
         return "***".to_string();
     }
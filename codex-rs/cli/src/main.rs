@@ -23,6 +23,8 @@ use codex_exec::Cli as ExecCli;
 use codex_exec::Command as ExecCommand;
 use codex_exec::ReviewArgs;
 use codex_execpolicy::ExecPolicyCheckCommand;
+use codex_http_client::HttpTraceLevel;
+use codex_http_client::install_http_trace;
 use codex_responses_api_proxy::Args as ResponsesApiProxyArgs;
 use codex_rollout_trace::REDUCED_STATE_FILE_NAME;
 use codex_rollout_trace::replay_bundle;
@@ -46,6 +48,8 @@ use supports_color::Stream;
 
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod app_cmd;
+mod auth_cmd;
+mod config_cmd;
 #[cfg(any(target_os = "macos", target_os = "windows"))]
 mod desktop_app;
 mod doctor;
@@ -56,14 +60,20 @@ mod plugin_cmd;
 mod remote_control_cmd;
 #[cfg(target_os = "windows")]
 mod sandbox_setup;
+mod sessions_cmd;
 mod state_db_recovery;
+mod tools_cmd;
 #[cfg(not(windows))]
 mod wsl_paths;
 
+use crate::auth_cmd::AuthCli;
+use crate::config_cmd::ConfigCli;
 use crate::mcp_cmd::McpCli;
 use crate::plugin_cmd::PluginCli;
 use crate::plugin_cmd::PluginSubcommand;
 use crate::remote_control_cmd::RemoteControlCommand;
+use crate::sessions_cmd::SessionsCli;
+use crate::tools_cmd::ToolsCli;
 use doctor::DoctorCommand;
 use state_db_recovery as local_state_db;
 
@@ -81,6 +91,7 @@ use codex_home::CodexHomeUserInstructionsProvider;
 use codex_login::AuthManager;
 use codex_login::CodexAuth;
 use codex_login::read_codex_access_token_from_env;
+use codex_login::validate_auth_profile_name;
 use codex_memories_write::clear_memory_roots_contents;
 use codex_models_manager::bundled_models_response;
 use codex_models_manager::manager::RefreshStrategy;
@@ -113,6 +124,25 @@ struct MultitoolCli {
     #[clap(flatten)]
     remote: InteractiveRemoteOptions,
 
+    /// Record HTTP requests to the ChatGPT backend, model providers, and tool
+    /// servers under `$CODEX_HOME/log/http/`, with credentials redacted. Use
+    /// `--trace-http=bodies` to also record truncated bodies. Overrides
+    /// `CODEX_HTTP_TRACE`.
+    #[arg(
+        long = "trace-http",
+        value_name = "LEVEL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "requests",
+        global = true
+    )]
+    trace_http: Option<HttpTraceLevel>,
+
+    /// Use the credentials of this auth profile for this invocation, overriding
+    /// `codex auth use` and the `auth_profile` config key.
+    #[arg(long = "auth-profile", value_name = "NAME", global = true)]
+    auth_profile: Option<String>,
+
     #[clap(flatten)]
     interactive: TuiCli,
 
@@ -135,6 +165,9 @@ enum Subcommand {
     /// Remove stored authentication credentials.
     Logout(LogoutCommand),
 
+    /// Switch between and list named auth profiles.
+    Auth(AuthCli),
+
     /// Manage external MCP servers for Codex.
     Mcp(McpCli),
 
@@ -209,6 +242,16 @@ enum Subcommand {
 
     /// Inspect feature flags.
     Features(FeaturesCli),
+
+    /// Inspect the resolved configuration.
+    Config(ConfigCli),
+
+    /// Inspect the tools available to the model.
+    Tools(ToolsCli),
+
+    /// List and manage saved sessions.
+    #[clap(visible_alias = "threads")]
+    Sessions(SessionsCli),
 }
 
 #[derive(Debug, Parser)]
@@ -483,9 +526,15 @@ struct LoginCommand {
     )]
     api_key: Option<String>,
 
-    #[arg(long = "device-auth")]
+    /// Sign in with a one-time code entered on another device; use this over SSH or on a
+    /// machine without a browser.
+    #[arg(long = "device-auth", visible_alias = "device")]
     use_device_code: bool,
 
+    /// Store the credentials in this auth profile instead of the active one.
+    #[arg(long = "profile", value_name = "NAME")]
+    profile: Option<String>,
+
     /// EXPERIMENTAL: Use custom OAuth issuer base URL (advanced)
     /// Override the OAuth issuer base URL (advanced)
     #[arg(long = "experimental_issuer", value_name = "URL", hide = true)]
@@ -943,16 +992,6 @@ struct FeatureSetArgs {
     feature: String,
 }
 
-fn stage_str(stage: Stage) -> &'static str {
-    match stage {
-        Stage::UnderDevelopment => "under development",
-        Stage::Experimental { .. } => "experimental",
-        Stage::Stable => "stable",
-        Stage::Deprecated => "deprecated",
-        Stage::Removed => "removed",
-    }
-}
-
 fn main() -> anyhow::Result<()> {
     let remote_control_disabled = codex_app_server::take_remote_control_disabled_env();
     arg0_dispatch_or_else(move |arg0_paths: Arg0DispatchPaths| async move {
@@ -961,6 +1000,18 @@ fn main() -> anyhow::Result<()> {
     })
 }
 
+/// Starts HTTP tracing when `--trace-http` or `CODEX_HTTP_TRACE` asks for it.
+fn enable_http_trace(level: Option<HttpTraceLevel>) {
+    let Some(level) = level.or_else(HttpTraceLevel::from_env) else {
+        return;
+    };
+    if let Err(err) =
+        find_codex_home().and_then(|codex_home| install_http_trace(level, codex_home.as_path()))
+    {
+        eprintln!("WARNING: failed to enable HTTP tracing: {err}");
+    }
+}
+
 async fn cli_main(
     arg0_paths: Arg0DispatchPaths,
     remote_control_disabled: bool,
@@ -969,13 +1020,21 @@ async fn cli_main(
         config_overrides: mut root_config_overrides,
         feature_toggles,
         remote,
+        trace_http,
+        auth_profile,
         mut interactive,
         subcommand,
     } = MultitoolCli::parse();
+    enable_http_trace(trace_http);
 
     // Fold --enable/--disable into config overrides so they flow to all subcommands.
     let toggle_overrides = feature_toggles.to_overrides()?;
     root_config_overrides.raw_overrides.extend(toggle_overrides);
+    if let Some(auth_profile) = auth_profile {
+        root_config_overrides
+            .raw_overrides
+            .push(auth_profile_override(&auth_profile)?);
+    }
     let root_remote = remote.remote;
     let root_remote_auth_token_env = remote.remote_auth_token_env;
     let root_strict_config = interactive.strict_config;
@@ -1346,6 +1405,12 @@ async fn cli_main(
                 &mut login_cli.config_overrides,
                 root_config_overrides.clone(),
             );
+            if let Some(profile) = login_cli.profile.as_deref() {
+                login_cli
+                    .config_overrides
+                    .raw_overrides
+                    .push(auth_profile_override(profile)?);
+            }
             match login_cli.action {
                 Some(LoginSubcommand::Status) => {
                     run_login_status(login_cli.config_overrides).await;
@@ -1392,6 +1457,18 @@ async fn cli_main(
             );
             run_logout(logout_cli.config_overrides).await;
         }
+        Some(Subcommand::Auth(mut auth_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "auth",
+            )?;
+            prepend_config_flags(
+                &mut auth_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            auth_cli.run().await?;
+        }
         Some(Subcommand::Completion(completion_cli)) => {
             reject_remote_mode_for_subcommand(
                 root_remote.as_deref(),
@@ -1613,16 +1690,18 @@ async fn cli_main(
                 let mut stage_width = 0;
                 for def in FEATURES {
                     let name = def.key;
-                    let stage = stage_str(def.stage);
+                    let stage = def.stage.label();
                     let enabled = config.features.enabled(def.id);
                     name_width = name_width.max(name.len());
                     stage_width = stage_width.max(stage.len());
-                    rows.push((name, stage, enabled));
+                    rows.push((name, stage, enabled, def.description));
                 }
-                rows.sort_unstable_by_key(|(name, _, _)| *name);
+                rows.sort_unstable_by_key(|(name, _, _, _)| *name);
 
-                for (name, stage, enabled) in rows {
-                    println!("{name:<name_width$}  {stage:<stage_width$}  {enabled}");
+                for (name, stage, enabled, description) in rows {
+                    println!(
+                        "{name:<name_width$}  {stage:<stage_width$}  {enabled:<5}  {description}"
+                    );
                 }
             }
             FeaturesSubcommand::Enable(FeatureSetArgs { feature }) => {
@@ -1642,6 +1721,42 @@ async fn cli_main(
                 disable_feature_in_config(&feature).await?;
             }
         },
+        Some(Subcommand::Config(mut config_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "config",
+            )?;
+            prepend_config_flags(
+                &mut config_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            config_cli.run().await?;
+        }
+        Some(Subcommand::Tools(mut tools_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "tools",
+            )?;
+            prepend_config_flags(
+                &mut tools_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            tools_cli.run(&arg0_paths).await?;
+        }
+        Some(Subcommand::Sessions(mut sessions_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "sessions",
+            )?;
+            prepend_config_flags(
+                &mut sessions_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            sessions_cli.run().await?;
+        }
     }
 
     Ok(())
@@ -2049,6 +2164,12 @@ async fn run_debug_clear_memories_command(
 
 /// Prepend root-level overrides so they have lower precedence than
 /// CLI-specific ones specified after the subcommand (if any).
+/// Builds the `-c` override that pins `profile` as the auth profile.
+fn auth_profile_override(profile: &str) -> anyhow::Result<String> {
+    validate_auth_profile_name(profile)?;
+    Ok(format!("auth_profile=\"{profile}\""))
+}
+
 fn prepend_config_flags(
     subcommand_config_overrides: &mut CliConfigOverrides,
     cli_config_overrides: CliConfigOverrides,
@@ -2128,6 +2249,7 @@ fn unsupported_subcommand_name_for_strict_config(
         Some(Subcommand::App(_)) => Some("app"),
         Some(Subcommand::Login(_)) => Some("login"),
         Some(Subcommand::Logout(_)) => Some("logout"),
+        Some(Subcommand::Auth(_)) => Some("auth"),
         Some(Subcommand::Completion(_)) => Some("completion"),
         Some(Subcommand::Update) => Some("update"),
         Some(Subcommand::Cloud(_)) => Some("cloud"),
@@ -2138,6 +2260,9 @@ fn unsupported_subcommand_name_for_strict_config(
         Some(Subcommand::ResponsesApiProxy(_)) => Some("responses-api-proxy"),
         Some(Subcommand::StdioToUds(_)) => Some("stdio-to-uds"),
         Some(Subcommand::Features(_)) => Some("features"),
+        Some(Subcommand::Config(_)) => Some("config"),
+        Some(Subcommand::Tools(_)) => Some("tools"),
+        Some(Subcommand::Sessions(_)) => Some("sessions"),
     }
 }
 
@@ -2460,6 +2585,7 @@ fn merge_interactive_cli_flags(interactive: &mut TuiCli, subcommand_cli: TuiCli)
         strict_config,
         approval_policy,
         web_search,
+        ascii,
         prompt,
         config_overrides,
         ..
@@ -2473,6 +2599,9 @@ fn merge_interactive_cli_flags(interactive: &mut TuiCli, subcommand_cli: TuiCli)
     if web_search {
         interactive.web_search = true;
     }
+    if ascii {
+        interactive.ascii = true;
+    }
     if strict_config {
         interactive.strict_config = true;
     }
@@ -2501,6 +2630,33 @@ mod tests {
     use codex_tui::TokenUsage;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn trace_http_flag_does_not_swallow_the_prompt() {
+        let parse = |args: &[&str]| {
+            let cli = MultitoolCli::try_parse_from(args).expect("parse");
+            (cli.trace_http, cli.interactive.prompt)
+        };
+
+        assert_eq!(
+            [
+                parse(&["codex", "fix the bug"]),
+                parse(&["codex", "--trace-http", "fix the bug"]),
+                parse(&["codex", "--trace-http=bodies", "fix the bug"]),
+            ],
+            [
+                (None, Some("fix the bug".to_string())),
+                (
+                    Some(HttpTraceLevel::Requests),
+                    Some("fix the bug".to_string())
+                ),
+                (
+                    Some(HttpTraceLevel::Bodies),
+                    Some("fix the bug".to_string())
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn exec_server_remote_auth_accepts_api_key_auth() {
         let auth = CodexAuth::from_api_key("sk-test");
@@ -2558,6 +2714,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn auth_profile_flags_pin_the_auth_profile() {
+        let cli = MultitoolCli::try_parse_from([
+            "codex",
+            "login",
+            "--profile",
+            "work",
+            "--auth-profile",
+            "personal",
+        ])
+        .expect("parse");
+        let Some(Subcommand::Login(login_cli)) = cli.subcommand else {
+            panic!("expected login subcommand");
+        };
+
+        assert_eq!(
+            (
+                cli.auth_profile,
+                login_cli.profile,
+                auth_profile_override("work").map_err(|err| err.to_string()),
+            ),
+            (
+                Some("personal".to_string()),
+                Some("work".to_string()),
+                Ok("auth_profile=\"work\"".to_string()),
+            )
+        );
+        assert!(auth_profile_override("../work").is_err());
+    }
+
     fn finalize_resume_from_args(args: &[&str]) -> TuiCli {
         let cli = MultitoolCli::try_parse_from(args).expect("parse");
         let MultitoolCli {
@@ -2566,6 +2752,8 @@ mod tests {
             subcommand,
             feature_toggles: _,
             remote: _,
+            trace_http: _,
+            auth_profile: _,
         } = cli;
 
         let Subcommand::Resume(ResumeCommand {
@@ -2600,6 +2788,8 @@ mod tests {
             subcommand,
             feature_toggles: _,
             remote: _,
+            trace_http: _,
+            auth_profile: _,
         } = cli;
 
         let Subcommand::Fork(ForkCommand {
@@ -2625,6 +2815,8 @@ mod tests {
             subcommand,
             feature_toggles: _,
             remote: _,
+            trace_http: _,
+            auth_profile: _,
         } = cli;
 
         let Subcommand::Archive(SessionArchiveCommand {
@@ -353,6 +353,8 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -604,6 +606,20 @@ async fn run_list(config_overrides: &CliConfigOverrides, list_args: ListArgs) ->
                             "env_http_headers": env_http_headers,
                         })
                     }
+                    McpServerTransportConfig::WebSocket {
+                        ws_url,
+                        bearer_token_env_var,
+                        http_headers,
+                        env_http_headers,
+                    } => {
+                        serde_json::json!({
+                            "type": "websocket",
+                            "ws_url": ws_url,
+                            "bearer_token_env_var": bearer_token_env_var,
+                            "http_headers": http_headers,
+                            "env_http_headers": env_http_headers,
+                        })
+                    }
                 };
 
                 serde_json::json!({
@@ -674,6 +690,11 @@ async fn run_list(config_overrides: &CliConfigOverrides, list_args: ListArgs) ->
                 url,
                 bearer_token_env_var,
                 ..
+            }
+            | McpServerTransportConfig::WebSocket {
+                ws_url: url,
+                bearer_token_env_var,
+                ..
             } => {
                 let status = format_mcp_status(cfg);
                 let auth_status = auth_statuses
@@ -845,6 +866,18 @@ async fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Re
                 "http_headers": http_headers,
                 "env_http_headers": env_http_headers,
             }),
+            McpServerTransportConfig::WebSocket {
+                ws_url,
+                bearer_token_env_var,
+                http_headers,
+                env_http_headers,
+            } => serde_json::json!({
+                "type": "websocket",
+                "ws_url": ws_url,
+                "bearer_token_env_var": bearer_token_env_var,
+                "http_headers": http_headers,
+                "env_http_headers": env_http_headers,
+            }),
         };
         let output = serde_json::to_string_pretty(&serde_json::json!({
             "name": get_args.name,
@@ -853,6 +886,8 @@ async fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Re
             "transport": transport,
             "enabled_tools": server.enabled_tools.clone(),
             "disabled_tools": server.disabled_tools.clone(),
+            "requires_approval": server.requires_approval,
+            "description": server.description.clone(),
             "startup_timeout_sec": server
                 .startup_timeout_sec
                 .map(|timeout| timeout.as_secs_f64()),
@@ -920,9 +955,19 @@ async fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Re
             bearer_token_env_var,
             http_headers,
             env_http_headers,
+        }
+        | McpServerTransportConfig::WebSocket {
+            ws_url: url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
         } => {
-            println!("  transport: streamable_http");
-            println!("  url: {url}");
+            let (transport, url_key) = match &server.transport {
+                McpServerTransportConfig::WebSocket { .. } => ("websocket", "ws_url"),
+                _ => ("streamable_http", "url"),
+            };
+            println!("  transport: {transport}");
+            println!("  {url_key}: {url}");
             let bearer_token_display = bearer_token_env_var.as_deref().unwrap_or("-");
             println!("  bearer_token_env_var: {bearer_token_display}");
             let headers_display = match http_headers {
@@ -968,6 +1013,12 @@ async fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Re
         };
         println!("  default_tools_approval_mode: {approval_mode}");
     }
+    if server.requires_approval {
+        println!("  requires_approval: true");
+    }
+    if let Some(description) = &server.description {
+        println!("  description: {description}");
+    }
     println!("  remove: codex mcp remove {}", get_args.name);
 
     Ok(())
@@ -606,7 +606,7 @@ pub(crate) async fn load_cli_auth_mode(config: &Config) -> Option<AuthMode> {
 
     let auth_route_config = config.auth_route_config();
     CodexAuth::from_auth_storage(
-        &config.codex_home,
+        &config.auth_home(),
         config.cli_auth_credentials_store_mode,
         Some(&config.chatgpt_base_url),
         config.auth_keyring_backend_kind(),
@@ -0,0 +1,1446 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use anyhow::Context;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+use codex_core::config::ConfigBuilder;
+use codex_core::configure_rollout_key_store;
+use codex_protocol::ThreadId;
+use codex_rollout::ForkedRollout;
+use codex_rollout::INTERACTIVE_SESSION_SOURCES;
+use codex_rollout::RolloutCheck;
+use codex_rollout::RolloutCompaction;
+use codex_rollout::RolloutCompactionStats;
+use codex_rollout::RolloutCompression;
+use codex_rollout::RolloutCompressionStats;
+use codex_rollout::RolloutPrunePolicy;
+use codex_rollout::RolloutPruneReport;
+use codex_rollout::RolloutRecorder;
+use codex_rollout::SessionImportStats;
+use codex_rollout::SessionSearchHit;
+use codex_rollout::SessionSearchOptions;
+use codex_rollout::SessionSearchQuery;
+use codex_rollout::SortDirection;
+use codex_rollout::ThreadItem;
+use codex_rollout::ThreadSortKey;
+use codex_rollout::append_thread_name;
+use codex_rollout::archive_sessions;
+use codex_rollout::check_rollouts;
+use codex_rollout::compact_rollouts;
+use codex_rollout::compress_rollouts_older_than;
+use codex_rollout::decrypt_rollouts;
+use codex_rollout::encrypt_rollouts;
+use codex_rollout::find_archived_thread_path_by_id_str;
+use codex_rollout::find_thread_ids_by_name;
+use codex_rollout::find_thread_name_by_id;
+use codex_rollout::find_thread_names_by_ids;
+use codex_rollout::find_thread_path_by_id_str;
+use codex_rollout::fork_rollout;
+use codex_rollout::import_sessions;
+use codex_rollout::parse_cursor;
+use codex_rollout::prune_rollouts;
+use codex_rollout::read_session_meta_line;
+use codex_rollout::reindex_sessions;
+use codex_rollout::repair_rollout;
+use codex_rollout::search_sessions;
+use codex_rollout::single_line_preview;
+use codex_rollout::state_db::get_state_db;
+use codex_rollout::state_db::reconcile_rollout;
+use codex_rollout::usage;
+use codex_rollout::usage::UsageFilter;
+use codex_state::ThreadTimeBounds;
+use codex_utils_cli::CliConfigOverrides;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use supports_color::Stream;
+
+use self::export::ExportFormat;
+use self::stats::StatsFormat;
+
+mod delete;
+mod encrypt;
+mod export;
+mod fsck;
+mod stats;
+mod tag;
+
+const PREVIEW_MAX_CHARS: usize = 60;
+
+/// Subcommands:
+/// - `list` — list saved sessions, newest first, one page at a time
+/// - `search` — find user and assistant messages across saved sessions
+/// - `compress` — gzip session rollouts that have not been touched recently
+/// - `prune` — delete old session rollouts by age, count, or total size
+/// - `export` — write a session transcript as Markdown, HTML, or JSON
+/// - `stats` — summarize sessions, token usage, and time spent over a window
+/// - `compact` — drop superseded token usage records from old rollouts
+/// - `reindex` — rebuild the session index from the rollout files
+/// - `fsck` — check rollouts for corrupt lines and repair interrupted writes
+/// - `tag` — give a session a unique name usable in place of its id
+/// - `untag` — remove a session's name
+/// - `encrypt` — encrypt saved rollouts with the history encryption key
+/// - `decrypt` — rewrite encrypted rollouts as plain JSONL
+/// - `import` — merge sessions from another Codex home or an archive
+/// - `archive` — bundle every session into a portable `.tar.zst`
+/// - `delete` — permanently remove individual sessions
+/// - `fork` — copy a session up to a chosen record into a new session
+#[derive(Debug, clap::Parser)]
+pub struct SessionsCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: SessionsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SessionsSubcommand {
+    /// List saved sessions, newest first.
+    List(ListArgs),
+
+    /// Search user and assistant messages across saved sessions, newest first.
+    Search(SearchArgs),
+
+    /// Gzip session rollouts that have not been modified recently. Compressed
+    /// sessions can still be listed, searched, and resumed.
+    Compress(CompressArgs),
+
+    /// Delete old session rollouts. Sessions modified in the last day are
+    /// never removed, and archived sessions are left alone.
+    Prune(PruneArgs),
+
+    /// Write a saved session as a Markdown, HTML, or JSON transcript.
+    Export(ExportArgs),
+
+    /// Summarize sessions, token usage, time spent in turns, busiest days, and
+    /// top models over a recent window.
+    Stats(StatsArgs),
+
+    /// Drop superseded token usage records from session rollouts that have
+    /// not been modified in the last day. Conversation history is unchanged.
+    Compact(CompactArgs),
+
+    /// Rebuild the session index used for fast listing by rescanning every
+    /// rollout. Rollout files are only read.
+    Reindex,
+
+    /// Check every session rollout for lines that are not valid records,
+    /// including a partial final line left by a crash.
+    Fsck(FsckArgs),
+
+    /// Name a session so it can be referred to by name instead of id. A name
+    /// already held by another session moves to this one.
+    Tag(TagArgs),
+
+    /// Remove a session's name.
+    Untag(UntagArgs),
+
+    /// Encrypt saved session rollouts, including archived ones, with the key
+    /// kept in `cli_auth_credentials_store`. Sessions modified in the last day
+    /// are skipped.
+    Encrypt,
+
+    /// Rewrite encrypted session rollouts as plain JSONL. Sessions modified in
+    /// the last day are skipped.
+    Decrypt,
+
+    /// Copy sessions in from another Codex home or from an archive written by
+    /// `codex sessions archive`. Sessions whose id is already used by a
+    /// different local session get a new id. Safe to run again after an
+    /// interruption.
+    Import(ImportArgs),
+
+    /// Bundle every saved session, including archived ones, and their names
+    /// into a zstd-compressed tarball for `codex sessions import`.
+    Archive(ArchiveArgs),
+
+    /// Permanently delete sessions, including their names and index entries.
+    /// Sessions in use by a running Codex process are refused.
+    Delete(DeleteArgs),
+
+    /// Copy a session up to a chosen record into a new session that can be
+    /// resumed on its own. The original session is left unchanged.
+    Fork(ForkArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ListArgs {
+    /// Maximum number of sessions to print.
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    pub limit: u32,
+
+    /// Resume after the session where a previous page ended.
+    #[arg(long, value_name = "CURSOR")]
+    pub cursor: Option<String>,
+
+    /// Timestamp to order sessions by.
+    #[arg(long, value_enum, default_value_t = SessionSort::Updated)]
+    pub sort: SessionSort,
+
+    /// Only list sessions whose `--sort` timestamp is at or after this time:
+    /// an RFC3339 timestamp or an age such as `12h` or `7d`.
+    #[arg(long, value_name = "TIME", value_parser = parse_time_bound)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// Only list sessions whose `--sort` timestamp is before this time, in the
+    /// same format as `--since`.
+    #[arg(long, value_name = "TIME", value_parser = parse_time_bound)]
+    pub until: Option<DateTime<Utc>>,
+
+    /// Only list sessions that used this model provider. May be repeated.
+    #[arg(long = "provider", value_name = "PROVIDER")]
+    pub providers: Vec<String>,
+
+    /// Only list sessions started in this directory. May be repeated.
+    #[arg(long = "cwd", value_name = "DIR")]
+    pub cwds: Vec<PathBuf>,
+
+    /// Include sessions from every source, not just interactive ones.
+    #[arg(long)]
+    pub all_sources: bool,
+
+    /// Show which session each forked session was forked from.
+    #[arg(long)]
+    pub lineage: bool,
+
+    /// Output the page as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct SearchArgs {
+    /// Text to look for. Matched case-insensitively unless `--regex` is set.
+    #[arg(value_name = "QUERY")]
+    pub query: String,
+
+    /// Treat QUERY as a regular expression.
+    #[arg(long)]
+    pub regex: bool,
+
+    /// Only include messages from this far back, e.g. `12h`, `7d`, or `2w`.
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    pub since: Option<Duration>,
+
+    /// Maximum number of matches to print.
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    pub limit: u32,
+
+    /// Search archived sessions instead of active ones.
+    #[arg(long)]
+    pub archived: bool,
+
+    /// Output the matches as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct CompressArgs {
+    /// Only compress rollouts last modified at least this long ago, e.g. `30d`.
+    /// Must be at least one day.
+    #[arg(long, value_name = "AGE", default_value = "30d", value_parser = parse_age)]
+    pub older_than: Duration,
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(group(
+    clap::ArgGroup::new("limits")
+        .required(true)
+        .multiple(true)
+        .args(["older_than", "keep_last", "max_total_size"])
+))]
+pub struct PruneArgs {
+    /// Delete sessions last modified longer ago than this, e.g. `90d`.
+    #[arg(long, value_name = "AGE", value_parser = parse_age)]
+    pub older_than: Option<Duration>,
+
+    /// Always keep the newest N sessions. On its own, deletes all others.
+    #[arg(long, value_name = "N")]
+    pub keep_last: Option<usize>,
+
+    /// Delete the oldest sessions until the rest fit in this many bytes.
+    #[arg(long, value_name = "BYTES")]
+    pub max_total_size: Option<u64>,
+
+    /// Print what would be deleted without deleting anything.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ExportArgs {
+    /// Id or name of the session to export.
+    #[arg(value_name = "SESSION")]
+    pub session_id: String,
+
+    /// Transcript format.
+    #[arg(long, value_enum, default_value_t = ExportFormat::Markdown)]
+    pub format: ExportFormat,
+
+    /// Include the model's reasoning summaries.
+    #[arg(long)]
+    pub include_reasoning: bool,
+
+    /// Write the transcript to this file instead of stdout.
+    #[arg(long, value_name = "FILE")]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct StatsArgs {
+    /// Start of the window: an RFC3339 timestamp or an age such as `7d`.
+    #[arg(long, value_name = "TIME", default_value = "30d", value_parser = parse_time_bound)]
+    pub since: DateTime<Utc>,
+
+    /// Include sessions from every source, not just interactive ones.
+    #[arg(long)]
+    pub all_sources: bool,
+
+    /// Output format. `csv` prints one row per session instead of a summary.
+    #[arg(long, value_enum, default_value_t = StatsFormat::Text)]
+    pub format: StatsFormat,
+
+    /// Output the summary as JSON. Shorthand for `--format json`.
+    #[arg(long, conflicts_with = "format")]
+    pub json: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+#[command(group(
+    clap::ArgGroup::new("target")
+        .required(true)
+        .args(["session_id", "all"])
+))]
+pub struct CompactArgs {
+    /// Id or name of the session to compact.
+    #[arg(value_name = "SESSION")]
+    pub session_id: Option<String>,
+
+    /// Compact every saved session, including archived ones.
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct FsckArgs {
+    /// Remove a partial final line from each affected rollout, keeping the
+    /// original next to it with a `.bak` suffix.
+    #[arg(long)]
+    pub repair: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct TagArgs {
+    /// Id or current name of the session to name.
+    #[arg(value_name = "SESSION")]
+    pub session: String,
+
+    /// New name: letters, digits, `.`, `_`, and `-`, at most 64 characters.
+    #[arg(value_name = "NAME", value_parser = parse_session_name)]
+    pub name: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct UntagArgs {
+    /// Id or name of the session whose name to remove.
+    #[arg(value_name = "SESSION")]
+    pub session: String,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ImportArgs {
+    /// Codex home directory or `.tar.zst` archive to import from.
+    #[arg(value_name = "PATH")]
+    pub source: PathBuf,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ArchiveArgs {
+    /// File to write the archive to. Must not already exist.
+    #[arg(long, value_name = "FILE")]
+    pub out: PathBuf,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DeleteArgs {
+    /// Ids or names of the sessions to delete.
+    #[arg(value_name = "SESSION", required = true)]
+    pub sessions: Vec<String>,
+
+    /// Delete without asking for confirmation.
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+
+    /// Overwrite each rollout file with zeros before removing it.
+    #[arg(long)]
+    pub wipe: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ForkArgs {
+    /// Id or name of the session to fork.
+    #[arg(value_name = "SESSION")]
+    pub session: String,
+
+    /// Keep rollout records up to and including this 1-based index, as
+    /// reported in the `line` field of `codex sessions search --json`.
+    /// Defaults to the end of the session.
+    #[arg(long, value_name = "INDEX")]
+    pub at: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SessionSort {
+    Updated,
+    Created,
+}
+
+impl From<SessionSort> for ThreadSortKey {
+    fn from(sort: SessionSort) -> Self {
+        match sort {
+            SessionSort::Updated => ThreadSortKey::UpdatedAt,
+            SessionSort::Created => ThreadSortKey::CreatedAt,
+        }
+    }
+}
+
+/// One row of `codex sessions list`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct SessionRow {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub updated_at: Option<String>,
+    pub cwd: Option<String>,
+    pub model_provider: Option<String>,
+    pub preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forked_from: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SessionsPage {
+    sessions: Vec<SessionRow>,
+    next_cursor: Option<String>,
+}
+
+/// One match printed by `codex sessions search --json`.
+#[derive(Debug, Serialize)]
+struct SearchMatch {
+    id: String,
+    timestamp: String,
+    path: String,
+    line: usize,
+    snippet: String,
+    highlight: Range<usize>,
+}
+
+impl SessionsCli {
+    pub async fn run(self) -> Result<()> {
+        let SessionsCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        // Any subcommand may read or write encrypted rollouts, so the key has
+        // to come from the configured `cli_auth_credentials_store`.
+        let overrides = config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = ConfigBuilder::default()
+            .cli_overrides(overrides)
+            .build()
+            .await
+            .context("failed to load configuration")?;
+        configure_rollout_key_store(&config);
+
+        match subcommand {
+            SessionsSubcommand::List(args) => run_list(&config_overrides, args).await?,
+            SessionsSubcommand::Search(args) => run_search(&config_overrides, args).await?,
+            SessionsSubcommand::Compress(args) => run_compress(&config_overrides, args).await?,
+            SessionsSubcommand::Prune(args) => run_prune(&config_overrides, args).await?,
+            SessionsSubcommand::Export(args) => run_export(&config_overrides, args).await?,
+            SessionsSubcommand::Stats(args) => run_stats(&config_overrides, args).await?,
+            SessionsSubcommand::Compact(args) => run_compact(&config_overrides, args).await?,
+            SessionsSubcommand::Reindex => run_reindex(&config_overrides).await?,
+            SessionsSubcommand::Fsck(args) => run_fsck(&config_overrides, args).await?,
+            SessionsSubcommand::Tag(args) => run_tag(&config_overrides, args).await?,
+            SessionsSubcommand::Untag(args) => run_untag(&config_overrides, args).await?,
+            SessionsSubcommand::Encrypt => {
+                run_encrypt(&config_overrides, /*encrypt*/ true).await?
+            }
+            SessionsSubcommand::Decrypt => {
+                run_encrypt(&config_overrides, /*encrypt*/ false).await?
+            }
+            SessionsSubcommand::Import(args) => run_import(&config_overrides, args).await?,
+            SessionsSubcommand::Archive(args) => run_archive(&config_overrides, args).await?,
+            SessionsSubcommand::Delete(args) => run_delete(&config_overrides, args).await?,
+            SessionsSubcommand::Fork(args) => run_fork(&config_overrides, args).await?,
+        }
+
+        Ok(())
+    }
+}
+
+async fn run_list(config_overrides: &CliConfigOverrides, args: ListArgs) -> Result<()> {
+    let ListArgs {
+        limit,
+        cursor,
+        sort,
+        since,
+        until,
+        providers,
+        cwds,
+        all_sources,
+        lineage,
+        json,
+    } = args;
+
+    let cursor = cursor
+        .map(|token| parse_cursor(&token).with_context(|| format!("invalid cursor: {token}")))
+        .transpose()?;
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let allowed_sources = if all_sources {
+        &[][..]
+    } else {
+        INTERACTIVE_SESSION_SOURCES.as_slice()
+    };
+    let model_providers = (!providers.is_empty()).then_some(providers.as_slice());
+    let cwd_filters = cwds
+        .iter()
+        .map(|cwd| {
+            std::path::absolute(cwd).with_context(|| format!("invalid --cwd: {}", cwd.display()))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let cwd_filters = (!cwd_filters.is_empty()).then_some(cwd_filters.as_slice());
+    let time_bounds = match sort {
+        SessionSort::Updated => ThreadTimeBounds {
+            updated_after: since,
+            updated_before: until,
+            ..Default::default()
+        },
+        SessionSort::Created => ThreadTimeBounds {
+            created_after: since,
+            created_before: until,
+            ..Default::default()
+        },
+    };
+
+    let page = RolloutRecorder::list_threads(
+        get_state_db(&config).await,
+        &config,
+        limit as usize,
+        cursor.as_ref(),
+        sort.into(),
+        SortDirection::Desc,
+        allowed_sources,
+        model_providers,
+        cwd_filters,
+        config.model_provider_id.as_str(),
+        /*search_term*/ None,
+        time_bounds,
+    )
+    .await
+    .context("failed to list sessions")?;
+
+    let next_cursor = page
+        .next_cursor
+        .as_ref()
+        .map(serde_json::to_value)
+        .transpose()?
+        .and_then(|value| value.as_str().map(str::to_string));
+    let thread_ids = page
+        .items
+        .iter()
+        .filter_map(|item| item.thread_id)
+        .collect::<HashSet<_>>();
+    let names = find_thread_names_by_ids(&config.codex_home, &thread_ids)
+        .await
+        .context("failed to read session names")?;
+    let mut sessions = page
+        .items
+        .iter()
+        .map(|item| session_row(item, &names))
+        .collect::<Vec<_>>();
+    if lineage {
+        for (row, item) in sessions.iter_mut().zip(&page.items) {
+            // A rollout whose meta cannot be read is listed without lineage.
+            row.forked_from = read_session_meta_line(&item.path)
+                .await
+                .ok()
+                .and_then(|session_meta| session_meta.meta.forked_from_id)
+                .map(|thread_id| thread_id.to_string());
+        }
+    }
+
+    if json {
+        let page = SessionsPage {
+            sessions,
+            next_cursor,
+        };
+        println!("{}", serde_json::to_string_pretty(&page)?);
+        return Ok(());
+    }
+
+    if sessions.is_empty() {
+        println!("No sessions found.");
+    }
+    for row in &sessions {
+        println!("{}", format_row(row));
+    }
+    if let Some(next_cursor) = next_cursor {
+        println!();
+        println!("Next page: --cursor {next_cursor}");
+    }
+
+    Ok(())
+}
+
+async fn run_search(config_overrides: &CliConfigOverrides, args: SearchArgs) -> Result<()> {
+    let SearchArgs {
+        query,
+        regex,
+        since,
+        limit,
+        archived,
+        json,
+    } = args;
+
+    let query = if regex {
+        SessionSearchQuery::regex(&query).with_context(|| format!("invalid regex: {query}"))?
+    } else {
+        SessionSearchQuery::literal(&query)?
+    };
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let since = since
+        .map(|age| {
+            SystemTime::now()
+                .checked_sub(age)
+                .context("--since reaches before the Unix epoch")
+        })
+        .transpose()?;
+
+    let hits = search_sessions(
+        &config.codex_home,
+        &SessionSearchOptions {
+            query,
+            archived,
+            since,
+            limit: limit as usize,
+        },
+    )
+    .await
+    .context("failed to search sessions")?;
+
+    if json {
+        let matches = hits
+            .into_iter()
+            .map(|hit| SearchMatch {
+                id: hit.thread_id.to_string(),
+                timestamp: hit.timestamp,
+                path: hit.path.display().to_string(),
+                line: hit.line,
+                snippet: hit.snippet,
+                highlight: hit.highlight,
+            })
+            .collect::<Vec<_>>();
+        println!("{}", serde_json::to_string_pretty(&matches)?);
+        return Ok(());
+    }
+
+    if hits.is_empty() {
+        println!("No matches found.");
+        return Ok(());
+    }
+    let color_enabled = supports_color::on(Stream::Stdout).is_some();
+    for hit in &hits {
+        println!("{}  {}", hit.timestamp, hit.thread_id);
+        println!("    {}", highlight_snippet(hit, color_enabled));
+    }
+
+    Ok(())
+}
+
+async fn run_compress(config_overrides: &CliConfigOverrides, args: CompressArgs) -> Result<()> {
+    let CompressArgs { older_than } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let stats =
+        compress_rollouts_older_than(&config.codex_home, older_than, RolloutCompression::Gzip)
+            .await
+            .context("failed to compress sessions")?;
+
+    let RolloutCompressionStats {
+        scanned,
+        compressed,
+        skipped,
+        failed,
+    } = stats;
+    println!(
+        "Compressed {compressed} of {scanned} uncompressed sessions ({skipped} skipped, {failed} failed)."
+    );
+    if failed > 0 {
+        anyhow::bail!("failed to compress {failed} sessions");
+    }
+
+    Ok(())
+}
+
+async fn run_prune(config_overrides: &CliConfigOverrides, args: PruneArgs) -> Result<()> {
+    let PruneArgs {
+        older_than,
+        keep_last,
+        max_total_size,
+        dry_run,
+    } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let policy = RolloutPrunePolicy {
+        older_than,
+        keep_last,
+        max_total_bytes: max_total_size,
+    };
+    let report = prune_rollouts(&config, &policy, dry_run)
+        .await
+        .context("failed to prune sessions")?;
+
+    let RolloutPruneReport {
+        scanned,
+        removed,
+        failed,
+        in_use,
+    } = report;
+    for rollout in &removed {
+        println!(
+            "{}  {}",
+            format_pruned_rollout(rollout.modified, rollout.size),
+            rollout.path.display()
+        );
+    }
+    for rollout in &in_use {
+        eprintln!(
+            "skipped {}: in use by another Codex process",
+            rollout.path.display()
+        );
+    }
+    for (rollout, err) in &failed {
+        eprintln!("failed to delete {}: {err}", rollout.path.display());
+    }
+    let removed_bytes = removed.iter().map(|rollout| rollout.size).sum::<u64>();
+    let verb = if dry_run { "Would delete" } else { "Deleted" };
+    println!(
+        "{verb} {} of {scanned} sessions ({removed_bytes} bytes, {} failed).",
+        removed.len(),
+        failed.len()
+    );
+    if !failed.is_empty() {
+        anyhow::bail!("failed to delete {} sessions", failed.len());
+    }
+
+    Ok(())
+}
+
+async fn run_export(config_overrides: &CliConfigOverrides, args: ExportArgs) -> Result<()> {
+    let ExportArgs {
+        session_id,
+        format,
+        include_reasoning,
+        out,
+    } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let path = find_session_rollout(&config.codex_home, &session_id).await?;
+    let (items, thread_id, _parse_errors) = RolloutRecorder::load_rollout_items(&path)
+        .await
+        .with_context(|| format!("failed to read {}", path.display()))?;
+
+    let id = thread_id.map_or(session_id, |thread_id| thread_id.to_string());
+    let transcript = export::transcript_from_rollout_items(id, &items, include_reasoning);
+    let rendered = export::render_transcript(&transcript, format)?;
+    match out {
+        Some(out) => std::fs::write(&out, rendered)
+            .with_context(|| format!("failed to write {}", out.display()))?,
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
+
+async fn run_stats(config_overrides: &CliConfigOverrides, args: StatsArgs) -> Result<()> {
+    let StatsArgs {
+        since,
+        all_sources,
+        format,
+        json,
+    } = args;
+    let format = if json { StatsFormat::Json } else { format };
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let allowed_sources = if all_sources {
+        Vec::new()
+    } else {
+        INTERACTIVE_SESSION_SOURCES.clone()
+    };
+    let filter = UsageFilter {
+        since: Some(since),
+        allowed_sources,
+        ..Default::default()
+    };
+
+    if format == StatsFormat::Csv {
+        let mut stdout = std::io::stdout().lock();
+        stats::write_csv_header(&mut stdout)?;
+        usage::for_each_session(get_state_db(&config).await, &config, &filter, |session| {
+            stats::write_csv_row(&mut stdout, &session)
+        })
+        .await
+        .context("failed to read session usage")?;
+        return Ok(());
+    }
+
+    let report = usage::aggregate(get_state_db(&config).await, &config, &filter)
+        .await
+        .context("failed to aggregate session usage")?;
+
+    let stats = stats::SessionStats::from_report(since, report);
+    match format {
+        StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+        StatsFormat::Text | StatsFormat::Csv => print!("{}", stats::render_stats(&stats)),
+    }
+
+    Ok(())
+}
+
+async fn run_compact(config_overrides: &CliConfigOverrides, args: CompactArgs) -> Result<()> {
+    let CompactArgs { session_id, all: _ } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+
+    if let Some(session_id) = session_id {
+        let path = find_session_rollout(&config.codex_home, &session_id).await?;
+        let RolloutCompaction {
+            lines_before,
+            lines_after,
+            bytes_before,
+            bytes_after,
+        } = RolloutRecorder::compact(&path)
+            .await
+            .with_context(|| format!("failed to compact {}", path.display()))?;
+        if lines_after == lines_before {
+            println!("Nothing to compact in {}.", path.display());
+        } else {
+            println!(
+                "Compacted {} from {bytes_before} to {bytes_after} bytes ({} records dropped).",
+                path.display(),
+                lines_before - lines_after
+            );
+        }
+        return Ok(());
+    }
+
+    let RolloutCompactionStats {
+        scanned,
+        compacted,
+        skipped,
+        failed,
+        bytes_saved,
+    } = compact_rollouts(&config.codex_home)
+        .await
+        .context("failed to compact sessions")?;
+    println!(
+        "Compacted {compacted} of {scanned} sessions ({bytes_saved} bytes saved, {skipped} skipped, {failed} failed)."
+    );
+    if failed > 0 {
+        anyhow::bail!("failed to compact {failed} sessions");
+    }
+
+    Ok(())
+}
+
+async fn run_encrypt(config_overrides: &CliConfigOverrides, encrypt: bool) -> Result<()> {
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let stats = if encrypt {
+        encrypt_rollouts(&config.codex_home)
+            .await
+            .context("failed to encrypt sessions")?
+    } else {
+        decrypt_rollouts(&config.codex_home)
+            .await
+            .context("failed to decrypt sessions")?
+    };
+    encrypt::report(encrypt, stats)
+}
+
+async fn run_import(config_overrides: &CliConfigOverrides, args: ImportArgs) -> Result<()> {
+    let ImportArgs { source } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let SessionImportStats {
+        scanned,
+        imported,
+        skipped,
+        renamed,
+        failed,
+    } = import_sessions(&config.codex_home, &source)
+        .await
+        .with_context(|| format!("failed to import sessions from {}", source.display()))?;
+    reindex_sessions(&config)
+        .await
+        .context("failed to rebuild the session index")?;
+    println!(
+        "Imported {imported} of {scanned} sessions ({renamed} renamed, {skipped} skipped, {failed} failed)."
+    );
+    if failed > 0 {
+        anyhow::bail!("failed to import {failed} sessions; run the import again to retry them");
+    }
+
+    Ok(())
+}
+
+async fn run_archive(config_overrides: &CliConfigOverrides, args: ArchiveArgs) -> Result<()> {
+    let ArchiveArgs { out } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let archived = archive_sessions(&config.codex_home, &out)
+        .await
+        .with_context(|| format!("failed to write {}", out.display()))?;
+    println!("Archived {archived} sessions to {}.", out.display());
+
+    Ok(())
+}
+
+async fn run_delete(config_overrides: &CliConfigOverrides, args: DeleteArgs) -> Result<()> {
+    let DeleteArgs {
+        sessions,
+        yes,
+        wipe,
+    } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+
+    let total = sessions.len();
+    let mut failed = 0;
+    let mut targets = Vec::new();
+    for session in sessions {
+        let resolved = async {
+            let path = find_session_rollout(&config.codex_home, &session).await?;
+            let session_meta = read_session_meta_line(&path)
+                .await
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            anyhow::Ok((session_meta.meta.id, path))
+        }
+        .await;
+        match resolved {
+            Ok((thread_id, path)) => targets.push(delete::DeleteTarget {
+                session,
+                thread_id,
+                path,
+            }),
+            Err(err) => {
+                eprintln!("Failed to delete {session}: {err:#}");
+                failed += 1;
+            }
+        }
+    }
+
+    if !targets.is_empty() && !yes && !delete::confirm(&targets, wipe)? {
+        println!("Delete cancelled.");
+        return Ok(());
+    }
+    for target in &targets {
+        match delete::delete_session(&config, target, wipe).await {
+            Ok(()) => println!("Deleted session {}.", delete::describe(target)),
+            Err(err) => {
+                eprintln!("Failed to delete {}: {err:#}", target.session);
+                failed += 1;
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("failed to delete {failed} of {total} sessions");
+    }
+
+    Ok(())
+}
+
+async fn run_fork(config_overrides: &CliConfigOverrides, args: ForkArgs) -> Result<()> {
+    let ForkArgs { session, at } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let source = find_session_rollout(&config.codex_home, &session).await?;
+    let ForkedRollout {
+        thread_id,
+        forked_from_id,
+        path,
+        lines,
+    } = fork_rollout(&config.codex_home, &source, at)
+        .await
+        .with_context(|| format!("failed to fork {session}"))?;
+    reconcile_rollout(
+        get_state_db(&config).await.as_deref(),
+        &path,
+        config.model_provider_id.as_str(),
+        /*builder*/ None,
+        &[],
+        /*archived_only*/ None,
+        /*new_thread_memory_mode*/ None,
+    )
+    .await;
+    println!("Forked session {forked_from_id} into {thread_id} ({lines} records).");
+    println!("Resume it with: codex resume {thread_id}");
+
+    Ok(())
+}
+
+async fn run_reindex(config_overrides: &CliConfigOverrides) -> Result<()> {
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let stats = reindex_sessions(&config)
+        .await
+        .context("failed to rebuild the session index")?;
+
+    let codex_state::BackfillStats {
+        scanned,
+        upserted,
+        failed,
+    } = stats;
+    println!("Reindexed {upserted} of {scanned} sessions ({failed} failed).");
+    if failed > 0 {
+        anyhow::bail!("failed to reindex {failed} sessions");
+    }
+
+    Ok(())
+}
+
+async fn run_fsck(config_overrides: &CliConfigOverrides, args: FsckArgs) -> Result<()> {
+    let FsckArgs { repair } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let checks = check_rollouts(&config.codex_home)
+        .await
+        .context("failed to check sessions")?;
+
+    let checked = checks.len();
+    let mut warnings = 0;
+    let mut repaired = 0;
+    let mut failed = 0;
+    for RolloutCheck { path, report } in checks {
+        let report = match report {
+            Ok(report) => report,
+            Err(err) => {
+                eprintln!("failed to check {}: {err}", path.display());
+                failed += 1;
+                continue;
+            }
+        };
+        if report.is_clean() {
+            continue;
+        }
+        warnings += 1;
+        for problem in fsck::describe_problems(&path, &report) {
+            println!("{problem}");
+        }
+        if !report.truncated_tail {
+            continue;
+        }
+        if !repair {
+            println!(
+                "{}: ends in a partial line; rerun with --repair to remove it",
+                path.display()
+            );
+            continue;
+        }
+        match repair_rollout(&path).await {
+            Ok(Some(backup)) => {
+                println!(
+                    "{}: removed partial final line (original saved to {})",
+                    path.display(),
+                    backup.display()
+                );
+                repaired += 1;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                eprintln!("failed to repair {}: {err}", path.display());
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "Checked {checked} sessions: {warnings} with warnings, {repaired} repaired, {failed} failed."
+    );
+    if failed > 0 {
+        anyhow::bail!("failed to check or repair {failed} sessions");
+    }
+
+    Ok(())
+}
+
+async fn run_tag(config_overrides: &CliConfigOverrides, args: TagArgs) -> Result<()> {
+    let TagArgs { session, name } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let thread_id = find_session_id(&config.codex_home, &session).await?;
+    let moved_from = tag::tag_session(&config.codex_home, thread_id, &name)
+        .await
+        .context("failed to update session names")?;
+
+    for previous in &moved_from {
+        println!("Removed name {name} from session {previous}.");
+    }
+    println!("Named session {thread_id} {name}.");
+
+    Ok(())
+}
+
+async fn run_untag(config_overrides: &CliConfigOverrides, args: UntagArgs) -> Result<()> {
+    let UntagArgs { session } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let thread_id = find_session_id(&config.codex_home, &session).await?;
+    let name = find_thread_name_by_id(&config.codex_home, &thread_id)
+        .await
+        .context("failed to read session names")?
+        .filter(|name| !name.trim().is_empty());
+    let Some(name) = name else {
+        println!("Session {thread_id} has no name.");
+        return Ok(());
+    };
+    append_thread_name(&config.codex_home, thread_id, /*name*/ "")
+        .await
+        .context("failed to update session names")?;
+    println!("Removed name {name} from session {thread_id}.");
+
+    Ok(())
+}
+
+/// Finds the rollout for a session id or name, looking in active sessions
+/// first and then in archived ones.
+///
+/// Names can never parse as a session id, so an argument that does is only
+/// looked up as an id.
+async fn find_session_rollout(codex_home: &Path, session: &str) -> Result<PathBuf> {
+    if ThreadId::from_string(session).is_ok() {
+        return find_rollout_by_id(codex_home, session)
+            .await?
+            .with_context(|| format!("no saved session with id {session}"));
+    }
+    // Names are unique once set through `tag`, but `/rename` does not enforce
+    // that, so prefer the most recently named session.
+    for thread_id in find_thread_ids_by_name(codex_home, session)
+        .await?
+        .into_iter()
+        .rev()
+    {
+        if let Some(path) = find_rollout_by_id(codex_home, &thread_id.to_string()).await? {
+            return Ok(path);
+        }
+    }
+    anyhow::bail!("no saved session with id or name {session}")
+}
+
+async fn find_rollout_by_id(codex_home: &Path, session_id: &str) -> Result<Option<PathBuf>> {
+    if let Some(path) =
+        find_thread_path_by_id_str(codex_home, session_id, /*state_db_ctx*/ None).await?
+    {
+        return Ok(Some(path));
+    }
+    Ok(find_archived_thread_path_by_id_str(codex_home, session_id, /*state_db_ctx*/ None).await?)
+}
+
+/// Resolves a session id or name to the id recorded in its rollout.
+async fn find_session_id(codex_home: &Path, session: &str) -> Result<ThreadId> {
+    let path = find_session_rollout(codex_home, session).await?;
+    let session_meta = read_session_meta_line(&path)
+        .await
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(session_meta.meta.id)
+}
+
+fn format_pruned_rollout(modified: SystemTime, size: u64) -> String {
+    let modified = chrono::DateTime::<chrono::Utc>::from(modified).format("%Y-%m-%d %H:%M");
+    format!("{modified}  {size:>10} bytes")
+}
+
+fn highlight_snippet(hit: &SessionSearchHit, color_enabled: bool) -> String {
+    let Some(matched) = hit.snippet.get(hit.highlight.clone()) else {
+        return hit.snippet.clone();
+    };
+    if !color_enabled || matched.is_empty() {
+        return hit.snippet.clone();
+    }
+    let before = &hit.snippet[..hit.highlight.start];
+    let after = &hit.snippet[hit.highlight.end..];
+    format!("{before}{}{after}", matched.bold())
+}
+
+fn parse_session_name(value: &str) -> Result<String, String> {
+    tag::validate_session_name(value)?;
+    Ok(value.to_string())
+}
+
+/// Parses a relative age such as `30m`, `12h`, `7d`, or `2w`.
+fn parse_age(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let unit_start = value
+        .find(|ch: char| !ch.is_ascii_digit())
+        .ok_or_else(|| format!("missing unit in `{value}`; use m, h, d, or w"))?;
+    let (amount, unit) = value.split_at(unit_start);
+    let amount = amount
+        .parse::<u64>()
+        .map_err(|_| format!("invalid age `{value}`"))?;
+    let unit_secs = match unit {
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("unknown unit `{unit}`; use m, h, d, or w")),
+    };
+    amount
+        .checked_mul(unit_secs)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("age `{value}` is too large"))
+}
+
+/// Parses an RFC3339 timestamp, or an age as accepted by `parse_age` meaning
+/// that long before now.
+fn parse_time_bound(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value.trim()) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    let age = parse_age(value)
+        .map_err(|err| format!("expected an RFC3339 timestamp or an age such as `7d`: {err}"))?;
+    chrono::Duration::from_std(age)
+        .ok()
+        .and_then(|age| Utc::now().checked_sub_signed(age))
+        .ok_or_else(|| format!("age `{value}` is too large"))
+}
+
+fn session_row(item: &ThreadItem, names: &HashMap<ThreadId, String>) -> SessionRow {
+    SessionRow {
+        id: item.thread_id.map(|thread_id| thread_id.to_string()),
+        name: item
+            .thread_id
+            .and_then(|thread_id| names.get(&thread_id))
+            .cloned(),
+        updated_at: item.updated_at.clone().or_else(|| item.created_at.clone()),
+        cwd: item.cwd.as_ref().map(|cwd| cwd.display().to_string()),
+        model_provider: item.model_provider.clone(),
+        preview: item.preview.clone(),
+        forked_from: None,
+    }
+}
+
+fn format_row(row: &SessionRow) -> String {
+    let preview = row
+        .preview
+        .as_deref()
+        .map(|preview| single_line_preview(preview, PREVIEW_MAX_CHARS))
+        .unwrap_or_default();
+    let row_text = [
+        row.id.as_deref().unwrap_or("-"),
+        row.name.as_deref().unwrap_or("-"),
+        row.updated_at.as_deref().unwrap_or("-"),
+        row.cwd.as_deref().unwrap_or("-"),
+        row.model_provider.as_deref().unwrap_or("-"),
+        preview.as_str(),
+    ]
+    .join("  ");
+    match row.forked_from.as_deref() {
+        Some(forked_from) => format!("{row_text}  (forked from {forked_from})"),
+        None => row_text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_row_collapses_and_truncates_preview() {
+        let row = SessionRow {
+            id: Some("019a0000-0000-7000-8000-000000000001".to_string()),
+            name: None,
+            updated_at: Some("2025-07-01T00:00:00Z".to_string()),
+            cwd: None,
+            model_provider: Some("openai".to_string()),
+            preview: Some(format!("fix the\nbuild {}", "x".repeat(80))),
+            forked_from: None,
+        };
+
+        assert_eq!(
+            format_row(&row),
+            format!(
+                "019a0000-0000-7000-8000-000000000001  -  2025-07-01T00:00:00Z  -  openai  fix the build {}…",
+                "x".repeat(45)
+            )
+        );
+    }
+
+    #[test]
+    fn parse_age_accepts_each_unit() {
+        assert_eq!(
+            ["30m", "12h", "7d", "2w"].map(parse_age),
+            [
+                Ok(Duration::from_secs(30 * 60)),
+                Ok(Duration::from_secs(12 * 60 * 60)),
+                Ok(Duration::from_secs(7 * 24 * 60 * 60)),
+                Ok(Duration::from_secs(14 * 24 * 60 * 60)),
+            ]
+        );
+        assert_eq!(
+            parse_age("7"),
+            Err("missing unit in `7`; use m, h, d, or w".to_string())
+        );
+        assert_eq!(
+            parse_age("7y"),
+            Err("unknown unit `y`; use m, h, d, or w".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_time_bound_accepts_rfc3339_and_ages() {
+        assert_eq!(
+            parse_time_bound("2025-07-01T02:00:00+02:00"),
+            Ok(Utc
+                .with_ymd_and_hms(2025, 7, 1, 0, 0, 0)
+                .single()
+                .expect("valid datetime"))
+        );
+
+        let before = Utc::now();
+        let since = parse_time_bound("7d").expect("age should parse");
+        let after = Utc::now();
+        let age = chrono::Duration::days(7);
+        assert!(before - age <= since && since <= after - age);
+
+        assert_eq!(
+            parse_time_bound("2025-07-01"),
+            Err(
+                "expected an RFC3339 timestamp or an age such as `7d`: unknown unit `-07-01`; use m, h, d, or w"
+                    .to_string()
+            )
+        );
+    }
+}
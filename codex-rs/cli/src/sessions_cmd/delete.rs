@@ -0,0 +1,134 @@
+//! Removal of individual sessions for `codex sessions delete`.
+//!
+//! Deleting a session removes every on-disk form of its rollout, its names,
+//! and the rows derived from it in the state and thread history databases.
+//! Rollouts held by a running session are refused rather than pulled out from
+//! under the writer.
+
+use std::io::IsTerminal;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_core::config::Config;
+use codex_protocol::ThreadId;
+use codex_rollout::delete_rollout;
+use codex_rollout::state_db::get_state_db;
+
+/// A session argument resolved to its rollout.
+#[derive(Debug)]
+pub(super) struct DeleteTarget {
+    /// The id or name as given on the command line.
+    pub(super) session: String,
+    pub(super) thread_id: ThreadId,
+    pub(super) path: PathBuf,
+}
+
+/// Asks on the terminal before deleting `targets`. Without a terminal there is
+/// nobody to ask, so the caller must pass `--yes` instead.
+pub(super) fn confirm(targets: &[DeleteTarget], wipe: bool) -> Result<bool> {
+    if !(std::io::stdin().is_terminal() && std::io::stderr().is_terminal()) {
+        anyhow::bail!(
+            "cannot confirm session deletion without an interactive terminal; rerun with --yes"
+        );
+    }
+
+    let mut stderr = std::io::stderr().lock();
+    write!(stderr, "{}", confirmation_prompt(targets, wipe))?;
+    stderr.flush()?;
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    let answer = input.trim();
+    Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+}
+
+fn confirmation_prompt(targets: &[DeleteTarget], wipe: bool) -> String {
+    let mut prompt = match targets {
+        [target] => format!("Permanently delete session {}?\n", describe(target)),
+        targets => {
+            let mut prompt = format!("Permanently delete {} sessions?\n", targets.len());
+            for target in targets {
+                prompt.push_str(&format!("  {}\n", describe(target)));
+            }
+            prompt
+        }
+    };
+    if wipe {
+        prompt.push_str("Rollout files will be overwritten before they are removed.\n");
+    }
+    prompt.push_str("This cannot be undone. Continue? [y/N]: ");
+    prompt
+}
+
+pub(super) fn describe(target: &DeleteTarget) -> String {
+    let id = target.thread_id.to_string();
+    if target.session == id {
+        id
+    } else {
+        format!("{} ({id})", target.session)
+    }
+}
+
+/// Deletes one session's rollout and everything derived from it.
+pub(super) async fn delete_session(
+    config: &Config,
+    target: &DeleteTarget,
+    wipe: bool,
+) -> Result<()> {
+    delete_rollout(&config.codex_home, &target.path, wipe)
+        .await
+        .with_context(|| format!("failed to delete {}", target.path.display()))?;
+    if let Some(state_db) = get_state_db(config).await {
+        state_db
+            .delete_thread(target.thread_id)
+            .await
+            .context("failed to remove the session from the session index")?;
+    }
+    codex_state::delete_thread_history(&config.sqlite_home, target.thread_id)
+        .await
+        .context("failed to remove the session's thread history")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn target(session: &str, thread_id: &str) -> DeleteTarget {
+        DeleteTarget {
+            session: session.to_string(),
+            thread_id: ThreadId::from_string(thread_id).expect("thread id"),
+            path: PathBuf::from(format!("sessions/rollout-{thread_id}.jsonl")),
+        }
+    }
+
+    #[test]
+    fn confirmation_prompt_lists_every_session() {
+        let targets = [
+            target(
+                "019a0000-0000-7000-8000-000000000001",
+                "019a0000-0000-7000-8000-000000000001",
+            ),
+            target("release", "019a0000-0000-7000-8000-000000000002"),
+        ];
+
+        assert_eq!(
+            confirmation_prompt(&targets, /*wipe*/ true),
+            "\
+Permanently delete 2 sessions?
+  019a0000-0000-7000-8000-000000000001
+  release (019a0000-0000-7000-8000-000000000002)
+Rollout files will be overwritten before they are removed.
+This cannot be undone. Continue? [y/N]: "
+        );
+        assert_eq!(
+            confirmation_prompt(&targets[1..], /*wipe*/ false),
+            "\
+Permanently delete session release (019a0000-0000-7000-8000-000000000002)?
+This cannot be undone. Continue? [y/N]: "
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Summaries for `codex sessions encrypt` and `codex sessions decrypt`.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use codex_rollout::RolloutEncryptionStats;
+
+/// Prints the outcome of a migration pass, failing when any session could not
+/// be converted.
+pub(super) fn report(encrypt: bool, stats: RolloutEncryptionStats) -> Result<()> {
+    let RolloutEncryptionStats {
+        scanned,
+        converted,
+        skipped,
+        failed,
+        unreadable,
+    } = stats;
+    let (done, action) = if encrypt {
+        ("Encrypted", "encrypt")
+    } else {
+        ("Decrypted", "decrypt")
+    };
+    println!("{done} {converted} of {scanned} sessions ({skipped} skipped, {failed} failed).");
+    if let Some(message) = describe_unreadable(&unreadable) {
+        anyhow::bail!(message);
+    }
+    if failed > 0 {
+        anyhow::bail!("failed to {action} {failed} sessions");
+    }
+    Ok(())
+}
+
+/// Lists the sessions encrypted with a key other than the current one.
+fn describe_unreadable(unreadable: &[PathBuf]) -> Option<String> {
+    if unreadable.is_empty() {
+        return None;
+    }
+    let mut message = format!(
+        "{} sessions were encrypted with a different history encryption key:",
+        unreadable.len()
+    );
+    for path in unreadable {
+        message.push_str(&format!("\n  {}", path.display()));
+    }
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn describe_unreadable_lists_each_session() {
+        let unreadable = vec![
+            PathBuf::from("sessions/2025/07/01/rollout-a.jsonl.enc"),
+            PathBuf::from("sessions/2025/07/02/rollout-b.jsonl.enc"),
+        ];
+
+        assert_eq!(
+            describe_unreadable(&unreadable),
+            Some(
+                "2 sessions were encrypted with a different history encryption key:\n  \
+                 sessions/2025/07/01/rollout-a.jsonl.enc\n  \
+                 sessions/2025/07/02/rollout-b.jsonl.enc"
+                    .to_string()
+            )
+        );
+        assert_eq!(describe_unreadable(&[]), None);
+    }
+}
@@ -0,0 +1,427 @@
+//! Renders a saved session as a shareable transcript for `codex sessions export`.
+//!
+//! The rollout is replayed through the same thread history builder the app
+//! server uses, so rollbacks are applied and forked sessions include the
+//! history they inherited. Tool calls are collapsed to a one-line summary, and
+//! command output is cut to its first few lines.
+
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use codex_app_server_protocol::ThreadItem;
+use codex_app_server_protocol::UserInput;
+use codex_app_server_protocol::build_turns_from_rollout_items;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::TurnSummaryItem;
+use serde::Serialize;
+
+const OUTPUT_MAX_LINES: usize = 20;
+
+const HTML_STYLE: &str = "\
+body { font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\", sans-serif; max-width: 860px; margin: 2rem auto; padding: 0 1rem; line-height: 1.5; color: #1f2328; }
+.message { margin: 1.5rem 0; }
+.role { font-weight: 600; }
+.text { white-space: pre-wrap; }
+details, .tool { margin: 0.75rem 0; color: #57606a; }
+pre { background: #f6f8fa; padding: 0.75rem; overflow-x: auto; }
+code { font-family: ui-monospace, SFMono-Regular, Menlo, monospace; }";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Transcript {
+    id: String,
+    entries: Vec<TranscriptEntry>,
+    /// Recorded metadata for the turns in `entries`. Sessions recorded before
+    /// turn summaries existed have none.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    turns: Vec<TurnSummaryItem>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TranscriptEntry {
+    User {
+        text: String,
+    },
+    Assistant {
+        text: String,
+    },
+    Reasoning {
+        summary: String,
+    },
+    Command {
+        command: String,
+        exit_code: Option<i32>,
+        output: String,
+    },
+    ToolCall {
+        name: String,
+    },
+    FileChange {
+        paths: Vec<String>,
+    },
+}
+
+impl TranscriptEntry {
+    fn is_user(&self) -> bool {
+        matches!(self, TranscriptEntry::User { .. })
+    }
+}
+
+/// Rebuilds the conversation the model saw from a rollout's items.
+pub(crate) fn transcript_from_rollout_items(
+    id: String,
+    items: &[RolloutItem],
+    include_reasoning: bool,
+) -> Transcript {
+    let turns = build_turns_from_rollout_items(items);
+    // Summaries of rolled-back turns stay in the rollout; keep only the ones
+    // for turns that survived replay.
+    let turn_summaries = items
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::TurnSummary(summary)
+                if turns.iter().any(|turn| turn.id == summary.turn_id) =>
+            {
+                Some(summary.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    let entries = turns
+        .into_iter()
+        .flat_map(|turn| turn.items)
+        .filter_map(|item| transcript_entry(item, include_reasoning))
+        .collect();
+    Transcript {
+        id,
+        entries,
+        turns: turn_summaries,
+    }
+}
+
+fn transcript_entry(item: ThreadItem, include_reasoning: bool) -> Option<TranscriptEntry> {
+    match item {
+        ThreadItem::UserMessage { content, .. } => {
+            let text = content
+                .iter()
+                .map(user_input_text)
+                .collect::<Vec<_>>()
+                .join("\n");
+            (!text.is_empty()).then_some(TranscriptEntry::User { text })
+        }
+        ThreadItem::AgentMessage { text, .. } | ThreadItem::Plan { text, .. } => {
+            Some(TranscriptEntry::Assistant { text })
+        }
+        ThreadItem::Reasoning { summary, .. } => {
+            (include_reasoning && !summary.is_empty()).then(|| TranscriptEntry::Reasoning {
+                summary: summary.join("\n\n"),
+            })
+        }
+        ThreadItem::CommandExecution {
+            command,
+            aggregated_output,
+            exit_code,
+            ..
+        } => Some(TranscriptEntry::Command {
+            command,
+            exit_code,
+            output: truncate_output(aggregated_output.as_deref().unwrap_or_default()),
+        }),
+        ThreadItem::FileChange { changes, .. } => Some(TranscriptEntry::FileChange {
+            paths: changes.into_iter().map(|change| change.path).collect(),
+        }),
+        ThreadItem::McpToolCall { server, tool, .. } => Some(TranscriptEntry::ToolCall {
+            name: format!("{server}.{tool}"),
+        }),
+        ThreadItem::DynamicToolCall {
+            namespace, tool, ..
+        } => Some(TranscriptEntry::ToolCall {
+            name: match namespace {
+                Some(namespace) => format!("{namespace}.{tool}"),
+                None => tool,
+            },
+        }),
+        ThreadItem::HookPrompt { .. }
+        | ThreadItem::CollabAgentToolCall { .. }
+        | ThreadItem::SubAgentActivity { .. }
+        | ThreadItem::WebSearch(_)
+        | ThreadItem::ImageView { .. }
+        | ThreadItem::Sleep(_)
+        | ThreadItem::ImageGeneration(_)
+        | ThreadItem::EnteredReviewMode { .. }
+        | ThreadItem::ExitedReviewMode { .. }
+        | ThreadItem::ContextCompaction { .. } => None,
+    }
+}
+
+fn user_input_text(input: &UserInput) -> String {
+    match input {
+        UserInput::Text { text, .. } => text.clone(),
+        UserInput::Image { .. } => "[image]".to_string(),
+        UserInput::LocalImage { path, .. } => format!("[image: {}]", path.display()),
+        UserInput::Audio { .. } => "[audio]".to_string(),
+        UserInput::LocalAudio { path } => format!("[audio: {}]", path.display()),
+        UserInput::Skill { name, .. } => format!("[skill: {name}]"),
+        UserInput::Mention { name, .. } => format!("[mention: {name}]"),
+    }
+}
+
+/// Keeps the first [`OUTPUT_MAX_LINES`] lines of command output.
+fn truncate_output(output: &str) -> String {
+    let output = output.trim_end_matches('\n');
+    let lines = output.lines().collect::<Vec<_>>();
+    if lines.len() <= OUTPUT_MAX_LINES {
+        return output.to_string();
+    }
+    let omitted = lines.len() - OUTPUT_MAX_LINES;
+    format!(
+        "{}\n… {omitted} more lines",
+        lines[..OUTPUT_MAX_LINES].join("\n")
+    )
+}
+
+pub(crate) fn render_transcript(transcript: &Transcript, format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Markdown => Ok(render_markdown(transcript)),
+        ExportFormat::Html => Ok(render_html(transcript)),
+        ExportFormat::Json => Ok(format!("{}\n", serde_json::to_string_pretty(transcript)?)),
+    }
+}
+
+fn render_markdown(transcript: &Transcript) -> String {
+    let mut blocks = vec![format!("# Codex session {}", transcript.id)];
+    let mut previous_was_user = None;
+    for entry in &transcript.entries {
+        let is_user = entry.is_user();
+        if previous_was_user != Some(is_user) {
+            let heading = if is_user { "## User" } else { "## Assistant" };
+            blocks.push(heading.to_string());
+            previous_was_user = Some(is_user);
+        }
+        blocks.push(match entry {
+            TranscriptEntry::User { text } | TranscriptEntry::Assistant { text } => text.clone(),
+            TranscriptEntry::Reasoning { summary } => {
+                let mut quoted = String::from("> **Reasoning**\n>");
+                for line in summary.lines() {
+                    quoted.push('\n');
+                    quoted.push_str(format!("> {line}").trim_end());
+                }
+                quoted
+            }
+            TranscriptEntry::Command {
+                command,
+                exit_code,
+                output,
+            } => {
+                let mut block =
+                    format!("**Ran** {}{}", code_span(command), exit_suffix(*exit_code));
+                if !output.is_empty() {
+                    let fence = "`".repeat(longest_backtick_run(output).max(2) + 1);
+                    let _ = write!(block, "\n\n{fence}text\n{output}\n{fence}");
+                }
+                block
+            }
+            TranscriptEntry::ToolCall { name } => format!("**Called** {}", code_span(name)),
+            TranscriptEntry::FileChange { paths } => format!(
+                "**Edited** {}",
+                paths
+                    .iter()
+                    .map(|path| code_span(path))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        });
+    }
+    let mut markdown = blocks.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+fn render_html(transcript: &Transcript) -> String {
+    let id = escape_html(&transcript.id);
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Codex session {id}</title>\n<style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n<h1>Codex session {id}</h1>\n"
+    );
+    for entry in &transcript.entries {
+        match entry {
+            TranscriptEntry::User { text } => {
+                let _ = writeln!(
+                    html,
+                    "<div class=\"message user\"><div class=\"role\">User</div><div class=\"text\">{}</div></div>",
+                    escape_html(text)
+                );
+            }
+            TranscriptEntry::Assistant { text } => {
+                let _ = writeln!(
+                    html,
+                    "<div class=\"message assistant\"><div class=\"role\">Assistant</div><div class=\"text\">{}</div></div>",
+                    escape_html(text)
+                );
+            }
+            TranscriptEntry::Reasoning { summary } => {
+                let _ = writeln!(
+                    html,
+                    "<details class=\"reasoning\"><summary>Reasoning</summary><div class=\"text\">{}</div></details>",
+                    escape_html(summary)
+                );
+            }
+            TranscriptEntry::Command {
+                command,
+                exit_code,
+                output,
+            } => {
+                let summary = format!(
+                    "Ran <code>{}</code>{}",
+                    escape_html(command),
+                    exit_suffix(*exit_code)
+                );
+                if output.is_empty() {
+                    let _ = writeln!(html, "<div class=\"tool\">{summary}</div>");
+                } else {
+                    let _ = writeln!(
+                        html,
+                        "<details class=\"command\"><summary>{summary}</summary><pre>{}</pre></details>",
+                        escape_html(output)
+                    );
+                }
+            }
+            TranscriptEntry::ToolCall { name } => {
+                let _ = writeln!(
+                    html,
+                    "<div class=\"tool\">Called <code>{}</code></div>",
+                    escape_html(name)
+                );
+            }
+            TranscriptEntry::FileChange { paths } => {
+                let paths = paths
+                    .iter()
+                    .map(|path| format!("<code>{}</code>", escape_html(path)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = writeln!(html, "<div class=\"tool\">Edited {paths}</div>");
+            }
+        }
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn exit_suffix(exit_code: Option<i32>) -> String {
+    exit_code
+        .map(|exit_code| format!(" (exit {exit_code})"))
+        .unwrap_or_default()
+}
+
+/// Wraps `text` in a Markdown code span that survives backticks inside it.
+fn code_span(text: &str) -> String {
+    let ticks = "`".repeat(longest_backtick_run(text) + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{ticks} {text} {ticks}")
+    } else {
+        format!("{ticks}{text}{ticks}")
+    }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|ch| ch != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default()
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::RolloutLine;
+    use pretty_assertions::assert_eq;
+
+    const FIXTURE: &str = include_str!("../../tests/fixtures/export_rollout.jsonl");
+
+    fn fixture_transcript(include_reasoning: bool) -> Transcript {
+        let items = FIXTURE
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<RolloutLine>(line)
+                    .expect("fixture line")
+                    .item
+            })
+            .collect::<Vec<_>>();
+        transcript_from_rollout_items(
+            "019a0000-0000-7000-8000-000000000001".to_string(),
+            &items,
+            include_reasoning,
+        )
+    }
+
+    #[test]
+    fn markdown_export_snapshot() {
+        insta::assert_snapshot!(
+            "markdown_export",
+            render_transcript(
+                &fixture_transcript(/*include_reasoning*/ true),
+                ExportFormat::Markdown
+            )
+            .expect("render")
+        );
+    }
+
+    #[test]
+    fn html_export_snapshot() {
+        insta::assert_snapshot!(
+            "html_export",
+            render_transcript(
+                &fixture_transcript(/*include_reasoning*/ true),
+                ExportFormat::Html
+            )
+            .expect("render")
+        );
+    }
+
+    #[test]
+    fn json_export_snapshot() {
+        insta::assert_snapshot!(
+            "json_export",
+            render_transcript(
+                &fixture_transcript(/*include_reasoning*/ false),
+                ExportFormat::Json
+            )
+            .expect("render")
+        );
+    }
+
+    #[test]
+    fn truncate_output_keeps_leading_lines() {
+        let output = (1..=25).map(|line| format!("{line}\n")).collect::<String>();
+        let expected = (1..=20)
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(
+            truncate_output(&output),
+            format!("{expected}\n… 5 more lines")
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! Problem descriptions for `codex sessions fsck`.
+
+use std::path::Path;
+
+use codex_rollout::RolloutIntegrityReport;
+
+/// Describes each problem in `report`, one line per problem. A truncated tail
+/// is left out because the caller reports it together with the repair.
+pub(super) fn describe_problems(path: &Path, report: &RolloutIntegrityReport) -> Vec<String> {
+    let path = path.display();
+    let mut problems = Vec::new();
+    if report.valid_lines == 0 {
+        problems.push(format!("{path}: no valid lines"));
+    }
+    if !report.invalid_lines.is_empty() {
+        let lines = report
+            .invalid_lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        problems.push(format!("{path}: invalid lines {lines}"));
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn describe_problems_lists_invalid_lines() {
+        let report = RolloutIntegrityReport {
+            valid_lines: 0,
+            invalid_lines: vec![2, 5],
+            truncated_tail: true,
+        };
+
+        assert_eq!(
+            describe_problems(Path::new("rollout.jsonl"), &report),
+            vec![
+                "rollout.jsonl: no valid lines".to_string(),
+                "rollout.jsonl: invalid lines 2, 5".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! Usage summary for `codex sessions stats`.
+//!
+//! The numbers come from [`codex_rollout::usage::aggregate`]; this module only
+//! ranks them and renders them for people or, with `--json`, for tooling.
+//! `--format csv` instead writes one row per session from
+//! [`codex_rollout::usage::for_each_session`] as each rollout is read.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::io;
+use std::io::Write;
+
+use chrono::DateTime;
+use chrono::Days;
+use chrono::NaiveDate;
+use chrono::Utc;
+use codex_protocol::num_format::format_with_separators;
+use codex_protocol::protocol::TokenUsage;
+use codex_rollout::usage::DailyUsage;
+use codex_rollout::usage::SessionUsage;
+use codex_rollout::usage::UsageReport;
+use serde::Serialize;
+
+/// Entries shown in the busiest-day and top-model rankings.
+const TOP_ENTRIES: usize = 5;
+const SPARKLINE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const CSV_HEADER: [&str; 10] = [
+    "session_id",
+    "created_at",
+    "updated_at",
+    "model",
+    "model_provider",
+    "total_tokens",
+    "input_tokens",
+    "output_tokens",
+    "cached_input_tokens",
+    "reasoning_output_tokens",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum StatsFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct SessionStats {
+    since: DateTime<Utc>,
+    sessions: usize,
+    tokens: TokenUsage,
+    /// Wall-clock time spent in turns.
+    turn_duration_ms: i64,
+    /// Days with the most tokens, busiest first.
+    busiest_days: Vec<DailyUsage>,
+    /// Models with the most tokens summed across providers, most used first.
+    top_models: Vec<ModelTotal>,
+    /// Every day with usage, oldest first.
+    days: Vec<DailyUsage>,
+}
+
+#[derive(Debug, Serialize)]
+struct ModelTotal {
+    model: String,
+    usage: TokenUsage,
+}
+
+impl SessionStats {
+    pub(super) fn from_report(since: DateTime<Utc>, report: UsageReport) -> Self {
+        let UsageReport {
+            total,
+            by_model,
+            by_day,
+            sessions_with_usage,
+            sessions_without_usage,
+            turn_duration_ms,
+        } = report;
+
+        let mut busiest_days = by_day.clone();
+        // Stable sort, so ties keep the earlier day first.
+        busiest_days.sort_by_key(|day| std::cmp::Reverse(day.usage.total_tokens));
+        busiest_days.truncate(TOP_ENTRIES);
+
+        let mut models = BTreeMap::<String, TokenUsage>::new();
+        for model_usage in by_model {
+            models
+                .entry(model_usage.model)
+                .or_default()
+                .add_assign(&model_usage.usage);
+        }
+        let mut top_models = models
+            .into_iter()
+            .map(|(model, usage)| ModelTotal { model, usage })
+            .collect::<Vec<_>>();
+        top_models.sort_by_key(|model| std::cmp::Reverse(model.usage.total_tokens));
+        top_models.truncate(TOP_ENTRIES);
+
+        Self {
+            since,
+            sessions: sessions_with_usage + sessions_without_usage,
+            tokens: total,
+            turn_duration_ms,
+            busiest_days,
+            top_models,
+            days: by_day,
+        }
+    }
+}
+
+pub(super) fn render_stats(stats: &SessionStats) -> String {
+    let since = stats.since.format("%Y-%m-%d");
+    if stats.sessions == 0 {
+        return format!("No sessions since {since}.\n");
+    }
+
+    let tokens = &stats.tokens;
+    let mut lines = vec![
+        format!("Sessions since {since}: {}", stats.sessions),
+        format!(
+            "Tokens: {} input ({} cached), {} output, {} total",
+            format_with_separators(tokens.input_tokens),
+            format_with_separators(tokens.cached_input()),
+            format_with_separators(tokens.output_tokens),
+            format_with_separators(tokens.total_tokens),
+        ),
+        format!(
+            "Time in turns: {}",
+            format_duration_ms(stats.turn_duration_ms)
+        ),
+    ];
+    if let (Some(first), Some(last)) = (stats.days.first(), stats.days.last()) {
+        lines.push(format!(
+            "Daily tokens {}..{}: {}",
+            first.date,
+            last.date,
+            sparkline(&stats.days)
+        ));
+    }
+    if !stats.busiest_days.is_empty() {
+        lines.push("Busiest days:".to_string());
+        lines.extend(stats.busiest_days.iter().map(|day| {
+            format!(
+                "  {}  {} tokens",
+                day.date,
+                format_with_separators(day.usage.total_tokens)
+            )
+        }));
+    }
+    if !stats.top_models.is_empty() {
+        lines.push("Top models:".to_string());
+        let width = stats
+            .top_models
+            .iter()
+            .map(|model| model.model.len())
+            .max()
+            .unwrap_or_default();
+        lines.extend(stats.top_models.iter().map(|model| {
+            format!(
+                "  {:width$}  {} tokens",
+                model.model,
+                format_with_separators(model.usage.total_tokens)
+            )
+        }));
+    }
+    let mut rendered = lines.join("\n");
+    rendered.push('\n');
+    rendered
+}
+
+/// One glyph per day from the first to the last day in `days`, scaled to the
+/// busiest day. Days without usage are left blank.
+fn sparkline(days: &[DailyUsage]) -> String {
+    let (Some(first), Some(last)) = (days.first(), days.last()) else {
+        return String::new();
+    };
+    let max = days
+        .iter()
+        .map(|day| day.usage.total_tokens)
+        .max()
+        .unwrap_or_default()
+        .max(1);
+    let by_date = days
+        .iter()
+        .map(|day| (day.date, day.usage.total_tokens))
+        .collect::<BTreeMap<NaiveDate, i64>>();
+    let levels = SPARKLINE_LEVELS.len() as i64;
+    let mut line = String::new();
+    let mut date = first.date;
+    while date <= last.date {
+        let tokens = by_date.get(&date).copied().unwrap_or_default();
+        line.push(if tokens <= 0 {
+            ' '
+        } else {
+            let level = (tokens * levels).div_ceil(max).clamp(1, levels) - 1;
+            SPARKLINE_LEVELS[level as usize]
+        });
+        let Some(next) = date.checked_add_days(Days::new(1)) else {
+            break;
+        };
+        date = next;
+    }
+    line
+}
+
+pub(super) fn write_csv_header(out: &mut impl Write) -> io::Result<()> {
+    write_csv_record(out, &CSV_HEADER)
+}
+
+pub(super) fn write_csv_row(out: &mut impl Write, session: &SessionUsage) -> io::Result<()> {
+    let usage = &session.usage;
+    write_csv_record(
+        out,
+        &[
+            session
+                .thread_id
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            session.created_at.clone().unwrap_or_default(),
+            session.updated_at.clone().unwrap_or_default(),
+            session.model.clone(),
+            session.model_provider.clone(),
+            usage.total_tokens.to_string(),
+            usage.input_tokens.to_string(),
+            usage.output_tokens.to_string(),
+            usage.cached_input().to_string(),
+            usage.reasoning_output_tokens.to_string(),
+        ],
+    )
+}
+
+/// Writes one RFC 4180 record, ending in CRLF.
+fn write_csv_record(out: &mut impl Write, fields: &[impl AsRef<str>]) -> io::Result<()> {
+    let record = fields
+        .iter()
+        .map(|field| csv_field(field.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    write!(out, "{record}\r\n")
+}
+
+/// Quotes a field that contains a comma, quote, or line break, doubling any
+/// quotes inside it.
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+fn format_duration_ms(duration_ms: i64) -> String {
+    let minutes = duration_ms.max(0) / 60_000;
+    match (minutes / 60, minutes % 60) {
+        (0, 0) => format!("{}s", duration_ms.max(0) / 1_000),
+        (0, minutes) => format!("{minutes}m"),
+        (hours, minutes) => format!("{hours}h {minutes}m"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use codex_rollout::usage::ModelUsage;
+    use pretty_assertions::assert_eq;
+
+    fn usage(input_tokens: i64, output_tokens: i64) -> TokenUsage {
+        TokenUsage {
+            input_tokens,
+            cached_input_tokens: input_tokens / 2,
+            output_tokens,
+            total_tokens: input_tokens + output_tokens,
+            ..Default::default()
+        }
+    }
+
+    fn day(day: u32, usage: TokenUsage) -> DailyUsage {
+        DailyUsage {
+            date: NaiveDate::from_ymd_opt(2025, 7, day).expect("valid date"),
+            usage,
+        }
+    }
+
+    fn model(model_provider: &str, model: &str, usage: TokenUsage) -> ModelUsage {
+        ModelUsage {
+            model_provider: model_provider.to_string(),
+            model: model.to_string(),
+            usage,
+        }
+    }
+
+    fn fixture_stats() -> SessionStats {
+        let report = UsageReport {
+            total: usage(4_200, 900),
+            by_model: vec![
+                model("azure", "gpt-5", usage(1_000, 200)),
+                model("openai", "gpt-5", usage(2_000, 500)),
+                model("openai", "gpt-5-mini", usage(1_200, 200)),
+            ],
+            by_day: vec![
+                day(1, usage(1_000, 100)),
+                day(2, usage(3_000, 700)),
+                day(4, usage(200, 100)),
+            ],
+            sessions_with_usage: 3,
+            sessions_without_usage: 1,
+            turn_duration_ms: 5_400_000,
+        };
+        let since = Utc
+            .with_ymd_and_hms(2025, 6, 5, 0, 0, 0)
+            .single()
+            .expect("valid since");
+        SessionStats::from_report(since, report)
+    }
+
+    #[test]
+    fn json_stats_snapshot() {
+        insta::assert_snapshot!(
+            "json_stats",
+            serde_json::to_string_pretty(&fixture_stats()).expect("serialize stats")
+        );
+    }
+
+    #[test]
+    fn render_stats_ranks_days_and_models() {
+        assert_eq!(
+            render_stats(&fixture_stats()),
+            "\
+Sessions since 2025-06-05: 4
+Tokens: 4,200 input (2,100 cached), 900 output, 5,100 total
+Time in turns: 1h 30m
+Daily tokens 2025-07-01..2025-07-04: ▃█ ▁
+Busiest days:
+  2025-07-02  3,700 tokens
+  2025-07-01  1,100 tokens
+  2025-07-04  300 tokens
+Top models:
+  gpt-5       3,700 tokens
+  gpt-5-mini  1,400 tokens
+"
+        );
+    }
+
+    #[test]
+    fn csv_rows_quote_fields_that_need_it() -> anyhow::Result<()> {
+        let session = SessionUsage {
+            thread_id: None,
+            created_at: Some("2025-07-01T10:00:00Z".to_string()),
+            updated_at: None,
+            model: "gpt-5, \"fast\"".to_string(),
+            model_provider: "openai".to_string(),
+            usage: TokenUsage {
+                reasoning_output_tokens: 7,
+                ..usage(1_000, 200)
+            },
+            turn_duration_ms: 0,
+        };
+
+        let mut out = Vec::new();
+        write_csv_header(&mut out)?;
+        write_csv_row(&mut out, &session)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "session_id,created_at,updated_at,model,model_provider,total_tokens,input_tokens,\
+             output_tokens,cached_input_tokens,reasoning_output_tokens\r\n\
+             ,2025-07-01T10:00:00Z,,\"gpt-5, \"\"fast\"\"\",openai,1200,1000,200,500,7\r\n"
+        );
+        Ok(())
+    }
+}
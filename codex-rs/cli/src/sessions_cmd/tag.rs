@@ -0,0 +1,102 @@
+//! Session names for `codex sessions tag` and `codex sessions untag`.
+//!
+//! Names are stored in the append-only session index that `/rename` already
+//! writes, so the TUI and resume pick them up unchanged. Each name belongs to
+//! at most one session: tagging a session with a name another session holds
+//! clears it from the other session first.
+
+use std::io;
+use std::path::Path;
+
+use codex_protocol::ThreadId;
+use codex_rollout::append_thread_name;
+use codex_rollout::find_thread_ids_by_name;
+
+const SESSION_NAME_MAX_LEN: usize = 64;
+
+/// Checks that `name` is 1 to 64 ASCII letters, digits, `.`, `_`, or `-`,
+/// starting with a letter or digit. Names shaped like a session id are
+/// rejected so an id argument is never ambiguous.
+pub(super) fn validate_session_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > SESSION_NAME_MAX_LEN {
+        return Err(format!(
+            "session names must be 1 to {SESSION_NAME_MAX_LEN} characters long"
+        ));
+    }
+    if !name.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err("session names must start with a letter or digit".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '.' | '_' | '-'))
+    {
+        return Err(format!(
+            "invalid character `{c}` in session name; use letters, digits, `.`, `_`, or `-`"
+        ));
+    }
+    if ThreadId::from_string(name).is_ok() {
+        return Err("session names cannot look like a session id".to_string());
+    }
+    Ok(())
+}
+
+/// Gives `thread_id` the name `name`, clearing it from any other session that
+/// holds it. Returns the sessions the name was moved from.
+pub(super) async fn tag_session(
+    codex_home: &Path,
+    thread_id: ThreadId,
+    name: &str,
+) -> io::Result<Vec<ThreadId>> {
+    let moved_from = find_thread_ids_by_name(codex_home, name)
+        .await?
+        .into_iter()
+        .filter(|holder| *holder != thread_id)
+        .collect::<Vec<_>>();
+    for holder in &moved_from {
+        append_thread_name(codex_home, *holder, /*name*/ "").await?;
+    }
+    append_thread_name(codex_home, thread_id, name).await?;
+    Ok(moved_from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn validate_session_name_accepts_filesystem_safe_names() {
+        assert_eq!(
+            ["release-1.2", "bug_fix", "a", "0day"].map(validate_session_name),
+            [Ok(()), Ok(()), Ok(()), Ok(())]
+        );
+    }
+
+    #[test]
+    fn validate_session_name_rejects_unsafe_and_ambiguous_names() {
+        assert_eq!(
+            [
+                "",
+                "-flag",
+                "../escape",
+                "with space",
+                "019a0000-0000-7000-8000-000000000001",
+            ]
+            .map(validate_session_name),
+            [
+                Err("session names must be 1 to 64 characters long".to_string()),
+                Err("session names must start with a letter or digit".to_string()),
+                Err("session names must start with a letter or digit".to_string()),
+                Err(
+                    "invalid character ` ` in session name; use letters, digits, `.`, `_`, or `-`"
+                        .to_string()
+                ),
+                Err("session names cannot look like a session id".to_string()),
+            ]
+        );
+        assert_eq!(
+            validate_session_name(&"a".repeat(65)),
+            Err("session names must be 1 to 64 characters long".to_string())
+        );
+    }
+}
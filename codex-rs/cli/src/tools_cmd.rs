@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_arg0::Arg0DispatchPaths;
+use codex_core::EnabledTool;
+use codex_core::config::ConfigBuilder;
+use codex_core::config::ConfigOverrides;
+use codex_core::list_enabled_tools;
+use codex_home::CodexHomeUserInstructionsProvider;
+use codex_utils_cli::CliConfigOverrides;
+
+/// Subcommands:
+/// - `list` — show the tools the model would be offered with the current config
+#[derive(Debug, clap::Parser)]
+pub struct ToolsCli {
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+
+    #[command(subcommand)]
+    pub subcommand: ToolsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ToolsSubcommand {
+    /// List the built-in and MCP tools enabled by the effective config,
+    /// without making any model calls.
+    List(ListArgs),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ListArgs {
+    /// Output the tools as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl ToolsCli {
+    pub async fn run(self, arg0_paths: &Arg0DispatchPaths) -> Result<()> {
+        let ToolsCli {
+            config_overrides,
+            subcommand,
+        } = self;
+
+        match subcommand {
+            ToolsSubcommand::List(args) => run_list(&config_overrides, arg0_paths, args).await?,
+        }
+
+        Ok(())
+    }
+}
+
+async fn run_list(
+    config_overrides: &CliConfigOverrides,
+    arg0_paths: &Arg0DispatchPaths,
+    args: ListArgs,
+) -> Result<()> {
+    let ListArgs { json } = args;
+
+    let overrides = config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let config = ConfigBuilder::default()
+        .cli_overrides(overrides)
+        .harness_overrides(ConfigOverrides {
+            codex_self_exe: arg0_paths.codex_self_exe.clone(),
+            codex_linux_sandbox_exe: arg0_paths.codex_linux_sandbox_exe.clone(),
+            main_execve_wrapper_exe: arg0_paths.main_execve_wrapper_exe.clone(),
+            ephemeral: Some(true),
+            ..Default::default()
+        })
+        .build()
+        .await
+        .context("failed to load configuration")?;
+    let user_instructions_provider = Arc::new(CodexHomeUserInstructionsProvider::new(
+        config.codex_home.clone(),
+    ));
+    let tools = list_enabled_tools(config, /*state_db*/ None, user_instructions_provider)
+        .await
+        .context("failed to resolve enabled tools")?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&tools)?);
+        return Ok(());
+    }
+
+    if tools.is_empty() {
+        println!("No tools enabled.");
+        return Ok(());
+    }
+    let rows = tools.iter().map(qualified_name).collect::<Vec<_>>();
+    let name_width = rows.iter().map(String::len).max().unwrap_or(0);
+    for (name, tool) in rows.iter().zip(&tools) {
+        println!("{name:<name_width$}  {}", tool.kind.as_str());
+    }
+
+    Ok(())
+}
+
+fn qualified_name(tool: &EnabledTool) -> String {
+    match &tool.namespace {
+        Some(namespace) => format!("{namespace}.{}", tool.name),
+        None => tool.name.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::ToolKind;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn qualified_name_prefixes_namespace() {
+        let tools = [
+            EnabledTool {
+                namespace: None,
+                name: "exec_command".to_string(),
+                kind: ToolKind::Function,
+            },
+            EnabledTool {
+                namespace: Some("mcp__docs".to_string()),
+                name: "search".to_string(),
+                kind: ToolKind::Function,
+            },
+        ];
+
+        assert_eq!(
+            tools.iter().map(qualified_name).collect::<Vec<_>>(),
+            vec!["exec_command".to_string(), "mcp__docs.search".to_string()]
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Fixtures shared by the `codex sessions` integration tests.
+
+use serde_json::Value;
+use serde_json::json;
+
+/// Returns the `session_meta` line that opens a rollout for session `id`,
+/// recorded at `ts` in `cwd` and started from `source`.
+pub(crate) fn session_meta(id: &str, ts: &str, cwd: &str, source: &str) -> Value {
+    json!({
+        "timestamp": ts,
+        "type": "session_meta",
+        "payload": {
+            "session_id": id,
+            "id": id,
+            "timestamp": ts,
+            "cwd": cwd,
+            "originator": "test_originator",
+            "cli_version": "0.0.0",
+            "source": source,
+            "model_provider": "openai",
+            "base_instructions": null,
+        },
+    })
+}
@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use anyhow::Result;
+use predicates::str::contains;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+#[test]
+fn config_diff_lists_keys_changed_from_defaults() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        "sandbox_mode = \"workspace-write\"\n",
+    )?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    cmd.args(["config", "diff"])
+        .assert()
+        .success()
+        .stdout(contains("sandbox_mode: read-only -> workspace-write"));
+
+    Ok(())
+}
+
+#[test]
+fn config_diff_against_file_emits_json() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let base = codex_home.path().join("base.toml");
+    let other = codex_home.path().join("other.toml");
+    std::fs::write(&base, "model = \"gpt-5\"\napproval_policy = \"never\"\n")?;
+    std::fs::write(
+        &other,
+        "model = \"gpt-5\"\napproval_policy = \"on-request\"\n",
+    )?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    let output = cmd
+        .arg("config")
+        .arg("diff")
+        .arg(&other)
+        .arg("--against")
+        .arg(&base)
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+
+    let entries: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        entries,
+        json!([
+            {
+                "key": "approval_policy",
+                "base": "never",
+                "effective": "on-request",
+            }
+        ])
+    );
+
+    Ok(())
+}
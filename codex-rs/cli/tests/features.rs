@@ -117,3 +117,40 @@ async fn features_list_is_sorted_alphabetically_by_feature_name() -> Result<()>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn features_list_shows_stage_state_and_description() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    let mut cmd = codex_command(codex_home.path())?;
+    let output = cmd
+        .args(["features", "list"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output)?;
+
+    let shell_tool_row = stdout
+        .lines()
+        .find(|line| line.starts_with("shell_tool "))
+        .expect("feature list should include shell_tool");
+    let columns = shell_tool_row
+        .split("  ")
+        .map(str::trim)
+        .filter(|column| !column.is_empty())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        columns,
+        vec![
+            "shell_tool",
+            "stable",
+            "true",
+            "Enable the default shell tool."
+        ]
+    );
+
+    Ok(())
+}
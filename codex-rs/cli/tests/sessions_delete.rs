@@ -0,0 +1,80 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use common::session_meta;
+
+const FIRST: &str = "019a0000-0000-7000-8000-000000000001";
+const MISSING: &str = "019a0000-0000-7000-8000-0000000000ff";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn write_rollout(codex_home: &Path, id: &str) -> Result<PathBuf> {
+    let ts = "2025-07-01T00-00-01";
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let meta = session_meta(id, ts, "/tmp/project", "cli");
+    let path = dir.join(format!("rollout-{ts}-{id}.jsonl"));
+    fs::write(&path, format!("{meta}\n"))?;
+    Ok(path)
+}
+
+#[test]
+fn sessions_delete_reports_each_session_and_fails_for_a_missing_id() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let rollout = write_rollout(codex_home.path(), FIRST)?;
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "delete", "--yes", MISSING, FIRST])
+        .output()?;
+
+    assert!(!output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        format!("Deleted session {FIRST}.\n")
+    );
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(
+        stderr.contains(&format!(
+            "Failed to delete {MISSING}: no saved session with id {MISSING}"
+        )),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("failed to delete 1 of 2 sessions"),
+        "{stderr}"
+    );
+    assert!(!rollout.exists());
+    Ok(())
+}
+
+#[test]
+fn sessions_delete_refuses_a_session_locked_by_a_writer() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let rollout = write_rollout(codex_home.path(), FIRST)?;
+    // The writer of a live session holds an exclusive lock on this sidecar.
+    let lock = fs::File::create(rollout.with_extension("lock"))?;
+    lock.lock()?;
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "delete", "--yes", "--wipe", FIRST])
+        .output()?;
+
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("session is in use"), "{stderr}");
+    assert_eq!(String::from_utf8(output.stdout)?, "");
+    assert!(rollout.exists());
+    lock.unlock()?;
+    Ok(())
+}
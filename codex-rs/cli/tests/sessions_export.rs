@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use tempfile::TempDir;
+
+const SESSION_ID: &str = "019a0000-0000-7000-8000-000000000001";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn write_fixture_rollout(codex_home: &Path) -> Result<()> {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    fs::write(
+        dir.join(format!("rollout-2025-07-01T00-00-00-{SESSION_ID}.jsonl")),
+        include_str!("fixtures/export_rollout.jsonl"),
+    )?;
+    Ok(())
+}
+
+#[test]
+fn sessions_export_writes_json_transcript_to_out_file() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture_rollout(codex_home.path())?;
+    let out = codex_home.path().join("transcript.json");
+
+    codex_command(codex_home.path())?
+        .args([
+            "sessions", "export", SESSION_ID, "--format", "json", "--out",
+        ])
+        .arg(&out)
+        .assert()
+        .success();
+
+    let transcript: Value = serde_json::from_str(&fs::read_to_string(&out)?)?;
+    let entry_types = transcript["entries"]
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| entry["type"].as_str())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    assert_eq!(transcript["id"], SESSION_ID);
+    assert_eq!(
+        entry_types,
+        vec!["user", "command", "assistant", "user", "assistant"]
+    );
+    Ok(())
+}
+
+#[test]
+fn sessions_export_rejects_unknown_session() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "export", SESSION_ID])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(format!(
+            "no saved session with id {SESSION_ID}"
+        )));
+    Ok(())
+}
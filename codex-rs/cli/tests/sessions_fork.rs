@@ -0,0 +1,101 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+
+use common::session_meta;
+
+const SOURCE: &str = "019a0000-0000-7000-8000-000000000001";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn write_rollout(codex_home: &Path) -> Result<String> {
+    let ts = "2025-07-01T00-00-01";
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let lines = [
+        session_meta(SOURCE, ts, "/tmp/project", "cli"),
+        json!({
+            "timestamp": ts,
+            "type": "event_msg",
+            "payload": {"type": "user_message", "message": "first", "kind": "plain"},
+        }),
+        json!({
+            "timestamp": ts,
+            "type": "event_msg",
+            "payload": {"type": "user_message", "message": "second", "kind": "plain"},
+        }),
+    ];
+    let contents = lines
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    fs::write(dir.join(format!("rollout-{ts}-{SOURCE}.jsonl")), &contents)?;
+    Ok(contents)
+}
+
+#[test]
+fn sessions_fork_creates_a_session_listed_with_its_lineage() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_rollout(codex_home.path())?;
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "fork", SOURCE, "--at", "2"])
+        .output()?;
+
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(
+        stdout.starts_with(&format!("Forked session {SOURCE} into ")),
+        "{stdout}"
+    );
+    assert!(stdout.contains("(2 records)."), "{stdout}");
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "list", "--lineage", "--json"])
+        .output()?;
+
+    assert!(output.status.success(), "{output:?}");
+    let page: Value = serde_json::from_slice(&output.stdout)?;
+    let lineage = page["sessions"]
+        .as_array()
+        .map(|sessions| {
+            sessions
+                .iter()
+                .map(|session| session["forked_from"].clone())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    assert_eq!(lineage.len(), 2, "{page}");
+    assert!(lineage.contains(&json!(SOURCE)), "{page}");
+    assert!(lineage.contains(&Value::Null), "{page}");
+    Ok(())
+}
+
+#[test]
+fn sessions_fork_rejects_a_point_past_the_end() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_rollout(codex_home.path())?;
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "fork", SOURCE, "--at", "4"])
+        .output()?;
+
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(
+        stderr.contains("fork point 4 is outside the session's 3 lines"),
+        "{stderr}"
+    );
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+const SESSION_ID: &str = "019a0000-0000-7000-8000-000000000001";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn fsck(codex_home: &Path, args: &[&str]) -> Result<String> {
+    let output = codex_command(codex_home)?
+        .args(["sessions", "fsck"])
+        .args(args)
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Writes the export fixture followed by a partial line, as left by a crash
+/// mid-append.
+fn write_interrupted_rollout(codex_home: &Path) -> Result<(PathBuf, String)> {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("rollout-2025-07-01T00-00-00-{SESSION_ID}.jsonl"));
+    let complete = include_str!("fixtures/export_rollout.jsonl");
+    fs::write(&path, format!("{complete}{{\"timestamp\":\"2025-07"))?;
+    Ok((path, complete.to_string()))
+}
+
+#[test]
+fn sessions_fsck_reports_and_repairs_partial_final_line() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let (path, complete) = write_interrupted_rollout(codex_home.path())?;
+    let original = fs::read_to_string(&path)?;
+
+    assert_eq!(
+        fsck(codex_home.path(), &[])?,
+        format!(
+            "{}: ends in a partial line; rerun with --repair to remove it\n\
+             Checked 1 sessions: 1 with warnings, 0 repaired, 0 failed.\n",
+            path.display()
+        )
+    );
+    assert_eq!(fs::read_to_string(&path)?, original);
+
+    let backup = PathBuf::from(format!("{}.bak", path.display()));
+    assert_eq!(
+        fsck(codex_home.path(), &["--repair"])?,
+        format!(
+            "{}: removed partial final line (original saved to {})\n\
+             Checked 1 sessions: 1 with warnings, 1 repaired, 0 failed.\n",
+            path.display(),
+            backup.display()
+        )
+    );
+    assert_eq!(fs::read_to_string(&path)?, complete);
+    assert_eq!(fs::read_to_string(&backup)?, original);
+
+    assert_eq!(
+        fsck(codex_home.path(), &[])?,
+        "Checked 1 sessions: 0 with warnings, 0 repaired, 0 failed.\n"
+    );
+    Ok(())
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use common::session_meta;
+
+const FIRST: &str = "019a0000-0000-7000-8000-000000000001";
+const SECOND: &str = "019a0000-0000-7000-8000-000000000002";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn write_rollout(codex_home: &Path, id: &str, ts: &str) -> Result<PathBuf> {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let meta = session_meta(id, ts, "/tmp/project", "cli");
+    let path = dir.join(format!("rollout-{ts}-{id}.jsonl"));
+    fs::write(&path, format!("{meta}\n"))?;
+    Ok(path)
+}
+
+#[test]
+fn sessions_import_restores_an_archive_written_by_sessions_archive() -> Result<()> {
+    let source_home = TempDir::new()?;
+    let dest_home = TempDir::new()?;
+    let scratch = TempDir::new()?;
+    let rollouts = [
+        write_rollout(source_home.path(), FIRST, "2025-07-01T00-00-01")?,
+        write_rollout(source_home.path(), SECOND, "2025-07-01T00-00-02")?,
+    ];
+    let archive = scratch.path().join("sessions.tar.zst");
+
+    let output = codex_command(source_home.path())?
+        .args(["sessions", "archive", "--out"])
+        .arg(&archive)
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        format!("Archived 2 sessions to {}.\n", archive.display())
+    );
+
+    let output = codex_command(dest_home.path())?
+        .args(["sessions", "import"])
+        .arg(&archive)
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        "Imported 2 of 2 sessions (0 renamed, 0 skipped, 0 failed).\n"
+    );
+    for rollout in &rollouts {
+        let relative = rollout.strip_prefix(source_home.path())?;
+        assert_eq!(
+            fs::read_to_string(dest_home.path().join(relative))?,
+            fs::read_to_string(rollout)?
+        );
+    }
+
+    // Importing the same archive again leaves the identical sessions alone.
+    let output = codex_command(dest_home.path())?
+        .args(["sessions", "import"])
+        .arg(&archive)
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout)?,
+        "Imported 0 of 2 sessions (0 renamed, 2 skipped, 0 failed).\n"
+    );
+    Ok(())
+}
@@ -0,0 +1,223 @@
+mod common;
+
+use std::fs;
+use std::fs::File;
+use std::fs::FileTimes;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+
+use common::session_meta;
+
+const SESSION_COUNT: u64 = 12;
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn session_id(index: u64) -> String {
+    format!("019a0000-0000-7000-8000-{index:012}")
+}
+
+/// Writes a rollout created at `00:00:{index}` and last modified
+/// `SESSION_COUNT + 1 - index` hours after that, so created and updated order
+/// disagree.
+fn write_rollout(codex_home: &Path, index: u64, source: &str) -> Result<()> {
+    write_rollout_in(codex_home, index, source, "/tmp/project")
+}
+
+fn write_rollout_in(codex_home: &Path, index: u64, source: &str, cwd: &str) -> Result<()> {
+    let id = session_id(index);
+    let ts = format!("2025-07-01T00-00-{index:02}");
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let mut file = File::create(dir.join(format!("rollout-{ts}-{id}.jsonl")))?;
+    let meta = session_meta(&id, &ts, cwd, source);
+    let user_message = json!({
+        "timestamp": ts,
+        "type": "event_msg",
+        "payload": {
+            "type": "user_message",
+            "message": format!("session {index}"),
+            "kind": "plain",
+        },
+    });
+    writeln!(file, "{meta}")?;
+    writeln!(file, "{user_message}")?;
+
+    let created = SystemTime::UNIX_EPOCH + Duration::from_secs(1_751_328_000 + index);
+    let modified = created + Duration::from_secs(3_600 * (SESSION_COUNT + 1 - index));
+    file.set_times(FileTimes::new().set_modified(modified))?;
+    Ok(())
+}
+
+fn write_fixture(codex_home: &Path) -> Result<()> {
+    for index in 1..=SESSION_COUNT {
+        write_rollout(codex_home, index, "cli")?;
+    }
+    write_rollout(codex_home, SESSION_COUNT + 1, "exec")
+}
+
+fn list_page(codex_home: &Path, args: &[&str]) -> Result<(Vec<String>, Option<String>)> {
+    let output = codex_command(codex_home)?
+        .args(["sessions", "list", "--json"])
+        .args(args)
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    let page: Value = serde_json::from_slice(&output.stdout)?;
+    let ids = page["sessions"]
+        .as_array()
+        .expect("sessions array")
+        .iter()
+        .map(|session| session["id"].as_str().expect("session id").to_string())
+        .collect();
+    let next_cursor = page["next_cursor"].as_str().map(str::to_string);
+    Ok((ids, next_cursor))
+}
+
+#[test]
+fn sessions_list_pages_by_updated_at_with_cursor() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    let mut ids = Vec::new();
+    let mut page_sizes = Vec::new();
+    let mut cursor = None;
+    loop {
+        let mut args = vec!["--limit", "5"];
+        if let Some(cursor) = cursor.as_deref() {
+            args.extend(["--cursor", cursor]);
+        }
+        let (page_ids, next_cursor) = list_page(codex_home.path(), &args)?;
+        page_sizes.push(page_ids.len());
+        ids.extend(page_ids);
+        cursor = next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    // Lower indexes were modified most recently; the `exec` session is not
+    // interactive and is left out by default.
+    assert_eq!(page_sizes, vec![5, 5, 2]);
+    assert_eq!(ids, (1..=SESSION_COUNT).map(session_id).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn sessions_list_sorts_by_created_at_and_includes_all_sources() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    let (ids, next_cursor) = list_page(
+        codex_home.path(),
+        &["--sort", "created", "--limit", "3", "--all-sources"],
+    )?;
+
+    assert_eq!(
+        ids,
+        vec![
+            session_id(SESSION_COUNT + 1),
+            session_id(SESSION_COUNT),
+            session_id(SESSION_COUNT - 1),
+        ]
+    );
+    assert!(next_cursor.is_some());
+    Ok(())
+}
+
+#[test]
+fn threads_list_is_an_alias_for_sessions_list() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    let list = |command: &str| -> Result<Vec<u8>> {
+        let output = codex_command(codex_home.path())?
+            .args([
+                command, "list", "--json", "--limit", "2", "--sort", "created",
+            ])
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+        Ok(output.stdout)
+    };
+
+    assert_eq!(list("threads")?, list("sessions")?);
+    Ok(())
+}
+
+#[test]
+fn sessions_list_filters_by_cwd() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    for index in 1..=3 {
+        write_rollout_in(codex_home.path(), index, "cli", "/tmp/project")?;
+    }
+    for index in 4..=5 {
+        write_rollout_in(codex_home.path(), index, "cli", "/tmp/other")?;
+    }
+
+    let (other, _) = list_page(codex_home.path(), &["--cwd", "/tmp/other"])?;
+    let (both, _) = list_page(
+        codex_home.path(),
+        &["--cwd", "/tmp/other", "--cwd", "/tmp/project"],
+    )?;
+
+    assert_eq!(other, vec![session_id(4), session_id(5)]);
+    assert_eq!(both, (1..=5).map(session_id).collect::<Vec<_>>());
+    Ok(())
+}
+
+#[test]
+fn sessions_list_time_bounds_include_since_and_exclude_until() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    // Session 3 was created exactly at `--since` and session 6 exactly at
+    // `--until`.
+    let (created, _) = list_page(
+        codex_home.path(),
+        &[
+            "--sort",
+            "created",
+            "--since",
+            "2025-07-01T00:00:03Z",
+            "--until",
+            "2025-07-01T00:00:06Z",
+        ],
+    )?;
+    // Session 3 was last modified exactly at `--since` and session 1 exactly
+    // at `--until`.
+    let (updated, _) = list_page(
+        codex_home.path(),
+        &[
+            "--since",
+            "2025-07-01T10:00:03Z",
+            "--until",
+            "2025-07-01T12:00:01Z",
+        ],
+    )?;
+
+    assert_eq!(created, vec![session_id(5), session_id(4), session_id(3)]);
+    assert_eq!(updated, vec![session_id(2), session_id(3)]);
+    Ok(())
+}
+
+#[test]
+fn sessions_list_rejects_invalid_cursor() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "list", "--cursor", "not-a-cursor"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("invalid cursor: not-a-cursor"));
+    Ok(())
+}
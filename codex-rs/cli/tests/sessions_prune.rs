@@ -0,0 +1,89 @@
+use std::fs;
+use std::fs::FileTimes;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+/// Writes a 16-byte rollout for session `index` last modified `age` ago.
+fn write_rollout(codex_home: &Path, index: u64, age: Duration) -> Result<PathBuf> {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!(
+        "rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-{index:012}.jsonl"
+    ));
+    fs::write(&path, "x".repeat(16))?;
+    fs::File::options()
+        .write(true)
+        .open(&path)?
+        .set_times(FileTimes::new().set_modified(SystemTime::now() - age))?;
+    Ok(path)
+}
+
+#[test]
+fn sessions_prune_dry_run_lists_without_deleting() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let recent = write_rollout(codex_home.path(), 1, 2 * DAY)?;
+    let old = write_rollout(codex_home.path(), 2, 40 * DAY)?;
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "prune", "--older-than", "30d", "--dry-run"])
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout)?;
+    let lines = stdout.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines.len(), 2, "{stdout}");
+    assert!(
+        lines[0].ends_with(&format!("        16 bytes  {}", old.display())),
+        "{stdout}"
+    );
+    assert_eq!(
+        lines[1],
+        "Would delete 1 of 2 sessions (16 bytes, 0 failed)."
+    );
+    assert_eq!((recent.exists(), old.exists()), (true, true));
+    Ok(())
+}
+
+#[test]
+fn sessions_prune_deletes_sessions_past_keep_last() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let newest = write_rollout(codex_home.path(), 1, 2 * DAY)?;
+    let older = write_rollout(codex_home.path(), 2, 3 * DAY)?;
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "prune", "--keep-last", "1"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Deleted 1 of 2 sessions (16 bytes, 0 failed).",
+        ));
+
+    assert_eq!((newest.exists(), older.exists()), (true, false));
+    Ok(())
+}
+
+#[test]
+fn sessions_prune_requires_a_limit() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "prune", "--dry-run"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--older-than <AGE>"));
+    Ok(())
+}
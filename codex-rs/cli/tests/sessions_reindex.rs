@@ -0,0 +1,31 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use tempfile::TempDir;
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+#[test]
+fn sessions_reindex_rescans_every_rollout() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let dir = codex_home.path().join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let rollout =
+        dir.join("rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-000000000001.jsonl");
+    fs::write(&rollout, include_str!("fixtures/export_rollout.jsonl"))?;
+
+    for _ in 0..2 {
+        codex_command(codex_home.path())?
+            .args(["sessions", "reindex"])
+            .assert()
+            .success()
+            .stdout("Reindexed 1 of 1 sessions (0 failed).\n");
+    }
+    assert!(rollout.exists());
+    Ok(())
+}
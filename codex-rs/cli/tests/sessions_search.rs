@@ -0,0 +1,79 @@
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+
+const THREAD_ID: &str = "019a0000-0000-7000-8000-000000000001";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn write_rollout(codex_home: &Path) -> Result<()> {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let mut file =
+        File::create(dir.join(format!("rollout-2025-07-01T00-00-00-{THREAD_ID}.jsonl")))?;
+    for (timestamp, message) in [
+        (
+            "2025-07-01T00:00:01.000Z",
+            "which command runs the migration?",
+        ),
+        ("2025-07-01T00:00:02.000Z", "thanks"),
+    ] {
+        let line = json!({
+            "timestamp": timestamp,
+            "type": "event_msg",
+            "payload": {"type": "user_message", "message": message, "kind": "plain"},
+        });
+        writeln!(file, "{line}")?;
+    }
+    Ok(())
+}
+
+#[test]
+fn sessions_search_prints_matches_as_json() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_rollout(codex_home.path())?;
+
+    let output = codex_command(codex_home.path())?
+        .args(["sessions", "search", "MIGRATION", "--json"])
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    let mut matches: Value = serde_json::from_slice(&output.stdout)?;
+    for found in matches.as_array_mut().expect("matches array") {
+        found.as_object_mut().expect("match object").remove("path");
+    }
+
+    assert_eq!(
+        matches,
+        json!([{
+            "id": THREAD_ID,
+            "timestamp": "2025-07-01T00:00:01.000Z",
+            "line": 1,
+            "snippet": "which command runs the migration?",
+            "highlight": {"start": 23, "end": 32},
+        }])
+    );
+    Ok(())
+}
+
+#[test]
+fn sessions_search_rejects_invalid_regex() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "search", "--regex", "("])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("invalid regex: ("));
+    Ok(())
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+
+use common::session_meta;
+
+const FIRST: &str = "019a0000-0000-7000-8000-000000000001";
+const SECOND: &str = "019a0000-0000-7000-8000-000000000002";
+
+fn codex_command(codex_home: &Path) -> Result<assert_cmd::Command> {
+    let mut cmd = assert_cmd::Command::new(codex_utils_cargo_bin::cargo_bin("codex")?);
+    cmd.env("CODEX_HOME", codex_home);
+    Ok(cmd)
+}
+
+fn write_rollout(codex_home: &Path, id: &str, second: u32) -> Result<()> {
+    let ts = format!("2025-07-01T00-00-{second:02}");
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let meta = session_meta(id, &ts, "/tmp/project", "cli");
+    let user_message = json!({
+        "timestamp": ts,
+        "type": "event_msg",
+        "payload": {"type": "user_message", "message": format!("session {id}"), "kind": "plain"},
+    });
+    fs::write(
+        dir.join(format!("rollout-{ts}-{id}.jsonl")),
+        format!("{meta}\n{user_message}\n"),
+    )?;
+    Ok(())
+}
+
+fn write_fixture(codex_home: &Path) -> Result<()> {
+    write_rollout(codex_home, FIRST, /*second*/ 1)?;
+    write_rollout(codex_home, SECOND, /*second*/ 2)
+}
+
+fn tag(codex_home: &Path, session: &str, name: &str) -> Result<String> {
+    let output = codex_command(codex_home)?
+        .args(["sessions", "tag", session, name])
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Maps each listed session id to its name.
+fn listed_names(codex_home: &Path) -> Result<BTreeMap<String, Value>> {
+    let output = codex_command(codex_home)?
+        .args(["sessions", "list", "--json"])
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    let page: Value = serde_json::from_slice(&output.stdout)?;
+    Ok(page["sessions"]
+        .as_array()
+        .expect("sessions array")
+        .iter()
+        .map(|session| {
+            (
+                session["id"].as_str().expect("session id").to_string(),
+                session["name"].clone(),
+            )
+        })
+        .collect())
+}
+
+fn exported_id(codex_home: &Path, session: &str) -> Result<Value> {
+    let output = codex_command(codex_home)?
+        .args(["sessions", "export", session, "--format", "json"])
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+    let transcript: Value = serde_json::from_slice(&output.stdout)?;
+    Ok(transcript["id"].clone())
+}
+
+#[test]
+fn sessions_tag_moves_a_name_already_in_use() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    tag(codex_home.path(), FIRST, "release")?;
+    let stdout = tag(codex_home.path(), SECOND, "release")?;
+
+    assert_eq!(
+        stdout,
+        format!("Removed name release from session {FIRST}.\nNamed session {SECOND} release.\n")
+    );
+    assert_eq!(
+        listed_names(codex_home.path())?,
+        BTreeMap::from([
+            (FIRST.to_string(), Value::Null),
+            (SECOND.to_string(), json!("release")),
+        ])
+    );
+    assert_eq!(exported_id(codex_home.path(), "release")?, json!(SECOND));
+    Ok(())
+}
+
+#[test]
+fn sessions_resolve_names_and_ids_without_ambiguity() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+    tag(codex_home.path(), SECOND, "alpha")?;
+
+    // A name that looks like a session id is refused, so an id argument always
+    // refers to the session with that id.
+    codex_command(codex_home.path())?
+        .args(["sessions", "tag", FIRST, SECOND])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "session names cannot look like a session id",
+        ));
+    codex_command(codex_home.path())?
+        .args(["sessions", "tag", FIRST, "../alpha"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "session names must start with a letter or digit",
+        ));
+
+    assert_eq!(exported_id(codex_home.path(), "alpha")?, json!(SECOND));
+    assert_eq!(exported_id(codex_home.path(), FIRST)?, json!(FIRST));
+    Ok(())
+}
+
+#[test]
+fn sessions_untag_frees_the_name() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+    tag(codex_home.path(), FIRST, "release")?;
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "untag", "release"])
+        .assert()
+        .success()
+        .stdout(format!("Removed name release from session {FIRST}.\n"));
+
+    codex_command(codex_home.path())?
+        .args(["sessions", "export", "release"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "no saved session with id or name release",
+        ));
+    assert_eq!(
+        listed_names(codex_home.path())?,
+        BTreeMap::from([
+            (FIRST.to_string(), Value::Null),
+            (SECOND.to_string(), Value::Null),
+        ])
+    );
+    Ok(())
+}
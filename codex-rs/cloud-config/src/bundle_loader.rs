@@ -53,8 +53,11 @@ pub fn cloud_config_bundle_loader(
     })
 }
 
+/// Builds a loader whose [`AuthManager`] reads credentials from `auth_home`, the active auth
+/// profile's storage directory, while the bundle cache stays under `codex_home`.
 pub async fn cloud_config_bundle_loader_for_storage(
     codex_home: PathBuf,
+    auth_home: PathBuf,
     enable_codex_api_key_env: bool,
     credentials_store_mode: AuthCredentialsStoreMode,
     keyring_backend_kind: AuthKeyringBackendKind,
@@ -62,7 +65,7 @@ pub async fn cloud_config_bundle_loader_for_storage(
     auth_route_config: Option<AuthRouteConfig>,
 ) -> CloudConfigBundleLoader {
     let auth_manager = AuthManager::shared(
-        codex_home.clone(),
+        auth_home,
         enable_codex_api_key_env,
         credentials_store_mode,
         /*forced_chatgpt_workspace_id*/ None,
@@ -46,7 +46,7 @@ pub async fn load_auth_manager(chatgpt_base_url: Option<String>) -> Option<AuthM
     let config = Config::load_with_cli_overrides(Vec::new()).await.ok()?;
     Some(
         AuthManager::new(
-            config.codex_home.to_path_buf(),
+            config.auth_home(),
             /*enable_codex_api_key_env*/ false,
             config.cli_auth_credentials_store_mode,
             config.forced_chatgpt_workspace_id.clone(),
@@ -14,6 +14,7 @@ use codex_protocol::error::UsageLimitReachedError;
 use http::HeaderMap;
 use serde::Deserialize;
 use serde_json::Value;
+use std::time::Duration;
 
 pub fn map_api_error(err: ApiError) -> CodexErr {
     match err {
@@ -117,6 +118,7 @@ pub fn map_api_error(err: ApiError) -> CodexErr {
                     CodexErr::RetryLimit(RetryLimitReachedError {
                         status,
                         request_id: extract_request_tracking_id(headers.as_ref()),
+                        retry_after: extract_retry_after(headers.as_ref()),
                     })
                 } else {
                     CodexErr::UnexpectedStatus(UnexpectedResponseError {
@@ -137,6 +139,7 @@ pub fn map_api_error(err: ApiError) -> CodexErr {
             TransportError::RetryLimit => CodexErr::RetryLimit(RetryLimitReachedError {
                 status: http::StatusCode::INTERNAL_SERVER_ERROR,
                 request_id: None,
+                retry_after: None,
             }),
             TransportError::Timeout => CodexErr::RequestTimeout,
             TransportError::Network(msg) | TransportError::Build(msg) => {
@@ -183,6 +186,16 @@ fn extract_request_id(headers: Option<&HeaderMap>) -> Option<String> {
         .or_else(|| extract_header(headers, OAI_REQUEST_ID_HEADER))
 }
 
+/// Reads a `Retry-After` header given in seconds. The HTTP-date form is not
+/// used by the Responses API and is ignored.
+fn extract_retry_after(headers: Option<&HeaderMap>) -> Option<Duration> {
+    extract_header(headers, http::header::RETRY_AFTER.as_str())?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
 fn extract_header(headers: Option<&HeaderMap>, name: &str) -> Option<String> {
     headers.and_then(|map| {
         map.get(name)
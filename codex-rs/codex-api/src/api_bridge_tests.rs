@@ -318,6 +318,27 @@ fn map_api_error_ignores_unparseable_rate_limit_reached_type_headers() {
     }
 }
 
+#[test]
+fn map_api_error_reads_retry_after_on_rate_limited_response() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        http::header::RETRY_AFTER,
+        http::HeaderValue::from_static("12"),
+    );
+    let err = map_api_error(ApiError::Transport(TransportError::Http {
+        status: http::StatusCode::TOO_MANY_REQUESTS,
+        url: Some("http://example.com/v1/responses".to_string()),
+        headers: Some(headers),
+        body: Some("{}".to_string()),
+    }));
+
+    let CodexErr::RetryLimit(retry_limit) = err else {
+        panic!("expected CodexErr::RetryLimit, got {err:?}");
+    };
+    assert_eq!(retry_limit.status, http::StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(retry_limit.retry_after, Some(Duration::from_secs(12)));
+}
+
 #[test]
 fn map_api_error_extracts_identity_auth_details_from_headers() {
     let mut headers = HeaderMap::new();
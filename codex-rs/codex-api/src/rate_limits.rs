@@ -1,10 +1,12 @@
 use codex_protocol::account::PlanType;
 use codex_protocol::protocol::CreditsSnapshot;
+use codex_protocol::protocol::ExtraRateLimitWindow;
 use codex_protocol::protocol::RateLimitReachedType;
 use codex_protocol::protocol::RateLimitSnapshot;
 use codex_protocol::protocol::RateLimitWindow;
 use http::HeaderMap;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::fmt::Display;
 
@@ -90,8 +92,10 @@ pub fn parse_rate_limit_for_limit(
     Some(RateLimitSnapshot {
         limit_id: Some(normalized_limit_id),
         limit_name: parsed_limit_name,
+        scope: None,
         primary,
         secondary,
+        extra: Vec::new(),
         credits,
         individual_limit: None,
         spend_control_reached: None,
@@ -111,6 +115,9 @@ struct RateLimitEventWindow {
 struct RateLimitEventDetails {
     primary: Option<RateLimitEventWindow>,
     secondary: Option<RateLimitEventWindow>,
+    /// Any other window keyed by its label, e.g. `burst` or a per-model window.
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -136,13 +143,14 @@ pub fn parse_rate_limit_event(payload: &str) -> Option<RateLimitSnapshot> {
     if event.kind != "codex.rate_limits" {
         return None;
     }
-    let (primary, secondary) = if let Some(details) = event.rate_limits.as_ref() {
+    let (primary, secondary, extra) = if let Some(details) = event.rate_limits.as_ref() {
         (
             map_event_window(details.primary.as_ref()),
             map_event_window(details.secondary.as_ref()),
+            map_event_extra_windows(&details.extra),
         )
     } else {
-        (None, None)
+        (None, None, Vec::new())
     };
     let credits = event.credits.map(|credits| CreditsSnapshot {
         has_credits: credits.has_credits,
@@ -156,8 +164,10 @@ pub fn parse_rate_limit_event(payload: &str) -> Option<RateLimitSnapshot> {
     Some(RateLimitSnapshot {
         limit_id: Some(limit_id.unwrap_or_else(|| "codex".to_string())),
         limit_name: None,
+        scope: None,
         primary,
         secondary,
+        extra,
         credits,
         individual_limit: None,
         spend_control_reached: None,
@@ -175,6 +185,25 @@ fn map_event_window(window: Option<&RateLimitEventWindow>) -> Option<RateLimitWi
     })
 }
 
+/// Keeps the extra entries that look like rate-limit windows and ignores any
+/// other fields the server adds to `rate_limits`.
+fn map_event_extra_windows(
+    extra: &BTreeMap<String, serde_json::Value>,
+) -> Vec<ExtraRateLimitWindow> {
+    extra
+        .iter()
+        .filter_map(|(label, value)| {
+            let window = RateLimitEventWindow::deserialize(value).ok()?;
+            Some(ExtraRateLimitWindow {
+                label: label.clone(),
+                used_percent: window.used_percent,
+                window_minutes: window.window_minutes,
+                resets_at: window.reset_at,
+            })
+        })
+        .collect()
+}
+
 /// Parses the bespoke Codex rate-limit headers into a `RateLimitSnapshot`.
 pub fn parse_promo_message(headers: &HeaderMap) -> Option<String> {
     parse_header_str(headers, "x-codex-promo-message")
@@ -377,4 +406,37 @@ mod tests {
         assert_eq!(updates[0].secondary, None);
         assert_eq!(updates[0].credits, None);
     }
+
+    #[test]
+    fn parse_rate_limit_event_collects_extra_windows() {
+        let payload = r#"{
+            "type": "codex.rate_limits",
+            "rate_limits": {
+                "primary": {"used_percent": 10.0, "window_minutes": 300, "reset_at": 100},
+                "secondary": {"used_percent": 20.0, "window_minutes": 10080, "reset_at": 200},
+                "burst": {"used_percent": 55.5, "window_minutes": 1, "reset_at": 60},
+                "gpt-5-codex": {"used_percent": 30.0, "window_minutes": null, "reset_at": null},
+                "note": "not a window"
+            }
+        }"#;
+
+        let snapshot = parse_rate_limit_event(payload).expect("snapshot");
+        assert_eq!(
+            snapshot.extra,
+            vec![
+                ExtraRateLimitWindow {
+                    label: "burst".to_string(),
+                    used_percent: 55.5,
+                    window_minutes: Some(1),
+                    resets_at: Some(60),
+                },
+                ExtraRateLimitWindow {
+                    label: "gpt-5-codex".to_string(),
+                    used_percent: 30.0,
+                    window_minutes: None,
+                    resets_at: None,
+                },
+            ]
+        );
+    }
 }
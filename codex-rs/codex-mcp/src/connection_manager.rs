@@ -210,6 +210,10 @@ impl McpConnectionManager {
                         McpServerTransportConfig::StreamableHttp {
                             bearer_token_env_var,
                             ..
+                        }
+                        | McpServerTransportConfig::WebSocket {
+                            bearer_token_env_var,
+                            ..
                         } => bearer_token_env_var.is_some(),
                         McpServerTransportConfig::Stdio { .. } => false,
                     });
@@ -523,6 +527,14 @@ impl McpConnectionManager {
             .unwrap_or_default()
     }
 
+    /// Returns true when the server is configured with `requires_approval`, so
+    /// every tool call must be approved regardless of the approval policy.
+    pub fn server_requires_approval(&self, server_name: &str) -> bool {
+        self.server_metadata
+            .get(server_name)
+            .is_some_and(|metadata| metadata.requires_approval)
+    }
+
     pub fn is_host_owned_codex_apps_server(&self, server_name: &str) -> bool {
         server_name == CODEX_APPS_MCP_SERVER_NAME && self.server_metadata.contains_key(server_name)
     }
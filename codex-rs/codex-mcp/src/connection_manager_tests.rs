@@ -1459,6 +1459,7 @@ async fn list_all_tools_adds_server_metadata_to_tools() {
                 "https://docs.example".to_string(),
             )),
             supports_parallel_tool_calls: true,
+            requires_approval: false,
             default_tools_approval_mode: None,
             tool_approval_modes: HashMap::new(),
         },
@@ -1556,6 +1557,8 @@ async fn no_local_runtime_fails_local_stdio_but_keeps_local_http_server() {
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
@@ -1582,6 +1585,8 @@ async fn no_local_runtime_fails_local_stdio_but_keeps_local_http_server() {
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
@@ -1692,6 +1697,8 @@ fn mcp_init_error_display_prompts_for_github_pat() {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -1783,6 +1790,8 @@ fn mcp_init_error_display_reports_generic_errors() {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -257,6 +257,14 @@ async fn compute_auth_status(
 
     match &config.transport {
         McpServerTransportConfig::Stdio { .. } => Ok(McpAuthState::Unsupported),
+        McpServerTransportConfig::WebSocket {
+            bearer_token_env_var,
+            ..
+        } => Ok(if bearer_token_env_var.is_some() {
+            McpAuthState::BearerToken
+        } else {
+            McpAuthState::Unsupported
+        }),
         McpServerTransportConfig::StreamableHttp {
             url,
             bearer_token_env_var,
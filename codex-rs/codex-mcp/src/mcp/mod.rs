@@ -82,6 +82,10 @@ pub fn mcp_permission_prompt_is_auto_approved(
     permission_profile: &PermissionProfile,
     context: McpPermissionPromptAutoApproveContext,
 ) -> bool {
+    if context.server_requires_approval {
+        return false;
+    }
+
     if context.tool_approval_mode == Some(AppToolApproval::Approve) {
         return true;
     }
@@ -101,6 +105,9 @@ pub fn mcp_permission_prompt_is_auto_approved(
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub struct McpPermissionPromptAutoApproveContext {
     pub tool_approval_mode: Option<AppToolApproval>,
+    /// Set when the server is configured with `requires_approval`, which
+    /// disables every auto-approval path.
+    pub server_requires_approval: bool,
 }
 
 /// MCP runtime settings derived from `codex_core::config::Config`.
@@ -278,7 +285,8 @@ pub fn effective_mcp_servers_from_configured(
                                 .filter(|url| matches!(url.scheme(), "http" | "https"))
                                 .map(|url| url.origin())
                         }
-                        McpServerTransportConfig::Stdio { .. } => None,
+                        McpServerTransportConfig::Stdio { .. }
+                        | McpServerTransportConfig::WebSocket { .. } => None,
                     };
                     if server_origin.as_ref() != chatgpt_origin.as_ref() {
                         server.auth = McpServerAuth::OAuth;
@@ -524,6 +532,8 @@ fn mcp_server_config_for_url(
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: Some(Duration::from_secs(30)),
         tool_timeout_sec: None,
@@ -98,6 +98,7 @@ fn mcp_prompt_auto_approval_honors_approved_tools_in_all_permission_modes() {
             &PermissionProfile::read_only(),
             McpPermissionPromptAutoApproveContext {
                 tool_approval_mode: Some(AppToolApproval::Approve),
+                server_requires_approval: false,
             },
         ));
     }
@@ -107,6 +108,7 @@ fn mcp_prompt_auto_approval_honors_approved_tools_in_all_permission_modes() {
         &PermissionProfile::read_only(),
         McpPermissionPromptAutoApproveContext {
             tool_approval_mode: Some(AppToolApproval::Auto),
+            server_requires_approval: false,
         },
     ));
 }
@@ -118,10 +120,25 @@ fn mcp_prompt_auto_approval_rejects_auto_mode_in_default_permission_mode() {
         &PermissionProfile::read_only(),
         McpPermissionPromptAutoApproveContext {
             tool_approval_mode: Some(AppToolApproval::Auto),
+            server_requires_approval: false,
         },
     ));
 }
 
+#[test]
+fn mcp_prompt_auto_approval_never_applies_to_servers_requiring_approval() {
+    for tool_approval_mode in [None, Some(AppToolApproval::Approve)] {
+        assert!(!mcp_permission_prompt_is_auto_approved(
+            AskForApproval::Never,
+            &PermissionProfile::Disabled,
+            McpPermissionPromptAutoApproveContext {
+                tool_approval_mode,
+                server_requires_approval: true,
+            },
+        ));
+    }
+}
+
 #[test]
 fn tool_plugin_provenance_collects_app_and_mcp_sources() {
     let mut config = test_mcp_config(PathBuf::new());
@@ -378,6 +395,8 @@ async fn effective_mcp_servers_preserve_runtime_servers() {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -404,6 +423,8 @@ async fn effective_mcp_servers_preserve_runtime_servers() {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -202,6 +202,14 @@ fn bind_environment_env_vars(config: &mut McpServerConfig) -> Result<(), String>
             }
             return Ok(());
         }
+        McpServerTransportConfig::WebSocket { .. } => {
+            if is_local_environment {
+                return Ok(());
+            }
+            return Err(
+                "WebSocket MCP servers are only supported in the local environment".to_string(),
+            );
+        }
     };
     for env_var in env_vars {
         match env_var {
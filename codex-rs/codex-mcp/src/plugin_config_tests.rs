@@ -44,6 +44,8 @@ fn stdio_server(
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -78,6 +80,8 @@ fn declared_placement_preserves_local_plugin_normalization() {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -350,6 +354,8 @@ fn local_environment_placement_preserves_http_env_references() {
                     enabled: true,
                     required: false,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
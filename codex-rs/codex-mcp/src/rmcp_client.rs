@@ -1098,6 +1098,26 @@ async fn make_rmcp_client(
             .await
             .map_err(StartupOutcomeError::from)
         }
+        McpServerTransportConfig::WebSocket {
+            ws_url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        } => {
+            let resolved_bearer_token =
+                match resolve_bearer_token(server_name, bearer_token_env_var.as_deref()) {
+                    Ok(token) => token,
+                    Err(error) => return Err(error.into()),
+                };
+            RmcpClient::new_websocket_client(
+                &ws_url,
+                resolved_bearer_token,
+                http_headers,
+                env_http_headers,
+            )
+            .await
+            .map_err(StartupOutcomeError::from)
+        }
     }
 }
 
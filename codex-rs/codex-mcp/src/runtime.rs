@@ -107,7 +107,8 @@ impl McpRuntimeContext {
                 codex_config::McpServerTransportConfig::Stdio { .. } => Err(format!(
                     "local stdio MCP server `{server_name}` requires a local environment"
                 )),
-                codex_config::McpServerTransportConfig::StreamableHttp { .. } => Ok(None),
+                codex_config::McpServerTransportConfig::StreamableHttp { .. }
+                | codex_config::McpServerTransportConfig::WebSocket { .. } => Ok(None),
             };
         }
 
@@ -165,6 +166,8 @@ mod tests {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -51,13 +51,14 @@ impl EffectiveMcpServer {
 pub(crate) enum McpServerOrigin {
     Stdio,
     StreamableHttp(String),
+    WebSocket(String),
 }
 
 impl McpServerOrigin {
     pub fn as_str(&self) -> &str {
         match self {
             Self::Stdio => "stdio",
-            Self::StreamableHttp(origin) => origin,
+            Self::StreamableHttp(origin) | Self::WebSocket(origin) => origin,
         }
     }
 
@@ -67,6 +68,10 @@ impl McpServerOrigin {
                 let parsed = url::Url::parse(url).ok()?;
                 Some(Self::StreamableHttp(parsed.origin().ascii_serialization()))
             }
+            McpServerTransportConfig::WebSocket { ws_url, .. } => {
+                let parsed = url::Url::parse(ws_url).ok()?;
+                Some(Self::WebSocket(parsed.origin().ascii_serialization()))
+            }
             McpServerTransportConfig::Stdio { .. } => Some(Self::Stdio),
         }
     }
@@ -79,6 +84,7 @@ pub(crate) struct McpServerMetadata {
     pub pollutes_memory: bool,
     pub origin: Option<McpServerOrigin>,
     pub supports_parallel_tool_calls: bool,
+    pub requires_approval: bool,
     pub default_tools_approval_mode: Option<AppToolApproval>,
     pub tool_approval_modes: HashMap<String, AppToolApproval>,
 }
@@ -101,6 +107,7 @@ impl From<&EffectiveMcpServer> for McpServerMetadata {
                 pollutes_memory: true,
                 origin: McpServerOrigin::from_transport(&config.transport),
                 supports_parallel_tool_calls: config.supports_parallel_tool_calls,
+                requires_approval: config.requires_approval,
                 default_tools_approval_mode: config.default_tools_approval_mode,
                 tool_approval_modes: config
                     .tools
@@ -0,0 +1,84 @@
+//! Names of the function tools Codex registers itself, for `tools.disabled`.
+
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::IntoDeserializer;
+
+/// A built-in function tool that can be removed with `tools.disabled`.
+/// MCP, dynamic, and extension tools have their own enable/disable settings.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BuiltinToolName {
+    ExecCommand,
+    WriteStdin,
+    ShellCommand,
+    ApplyPatch,
+    ViewImage,
+    UpdatePlan,
+    RequestUserInput,
+    RequestPermissions,
+    ListMcpResources,
+    ListMcpResourceTemplates,
+    ReadMcpResource,
+    WaitForEnvironment,
+    #[serde(rename = "curr_time")]
+    CurrentTime,
+    Sleep,
+    #[serde(rename = "new_context")]
+    NewContextWindow,
+    GetContextRemaining,
+    SpawnAgent,
+    SendInput,
+    ResumeAgent,
+    WaitAgent,
+    CloseAgent,
+    SendMessage,
+    FollowupTask,
+    InterruptAgent,
+    ListAgents,
+}
+
+impl BuiltinToolName {
+    /// The tool name the model sees.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ExecCommand => "exec_command",
+            Self::WriteStdin => "write_stdin",
+            Self::ShellCommand => "shell_command",
+            Self::ApplyPatch => "apply_patch",
+            Self::ViewImage => "view_image",
+            Self::UpdatePlan => "update_plan",
+            Self::RequestUserInput => "request_user_input",
+            Self::RequestPermissions => "request_permissions",
+            Self::ListMcpResources => "list_mcp_resources",
+            Self::ListMcpResourceTemplates => "list_mcp_resource_templates",
+            Self::ReadMcpResource => "read_mcp_resource",
+            Self::WaitForEnvironment => "wait_for_environment",
+            Self::CurrentTime => "curr_time",
+            Self::Sleep => "sleep",
+            Self::NewContextWindow => "new_context",
+            Self::GetContextRemaining => "get_context_remaining",
+            Self::SpawnAgent => "spawn_agent",
+            Self::SendInput => "send_input",
+            Self::ResumeAgent => "resume_agent",
+            Self::WaitAgent => "wait_agent",
+            Self::CloseAgent => "close_agent",
+            Self::SendMessage => "send_message",
+            Self::FollowupTask => "followup_task",
+            Self::InterruptAgent => "interrupt_agent",
+            Self::ListAgents => "list_agents",
+        }
+    }
+}
+
+/// Parses the tool name the model sees, as spelled in `config.toml`.
+impl FromStr for BuiltinToolName {
+    type Err = serde::de::value::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::deserialize(name.into_deserializer())
+    }
+}
@@ -0,0 +1,223 @@
+//! YAML and JSON config documents.
+//!
+//! `config.yaml`, `config.yml`, and `config.json` are parsed into the same
+//! `toml::Value` tree as `config.toml`, so layering, schema validation, and
+//! `-c` overrides do not depend on the format a file was written in. Parse
+//! errors keep the line and column reported by the format's own parser.
+
+use crate::CONFIG_TOML_FILE;
+use crate::diagnostics::ConfigError;
+use crate::diagnostics::TextPosition;
+use crate::diagnostics::TextRange;
+use crate::diagnostics::config_error_from_toml;
+use crate::diagnostics::config_error_from_typed_toml;
+use codex_file_system::ExecutorFileSystem;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use codex_utils_path_uri::PathUri;
+use serde::de::DeserializeOwned;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use toml::Value as TomlValue;
+
+/// Alternate user config filenames in `codex_home`, in the order they are
+/// preferred when `config.toml` is absent.
+pub const ALTERNATE_CONFIG_FILES: &[&str] = &["config.yaml", "config.yml", "config.json"];
+
+/// Format of a config document, chosen by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFileFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFileFormat {
+    /// `.yaml`/`.yml` and `.json` files use those formats; anything else is
+    /// TOML.
+    pub fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("yaml" | "yml") => Self::Yaml,
+            Some("json") => Self::Json,
+            _ => Self::Toml,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Toml => "TOML",
+            Self::Yaml => "YAML",
+            Self::Json => "JSON",
+        }
+    }
+}
+
+/// Picks the user config file in `codex_home`. `config.toml` wins when it
+/// exists; otherwise the first existing entry of [`ALTERNATE_CONFIG_FILES`]
+/// is used. When several candidates exist, the returned warning names the
+/// file in use and the ones being ignored.
+pub(crate) async fn resolve_user_config_file(
+    fs: &dyn ExecutorFileSystem,
+    codex_home: &Path,
+) -> (AbsolutePathBuf, Option<String>) {
+    let mut existing = Vec::new();
+    for file_name in std::iter::once(CONFIG_TOML_FILE).chain(ALTERNATE_CONFIG_FILES.iter().copied())
+    {
+        let file = AbsolutePathBuf::resolve_path_against_base(file_name, codex_home);
+        if fs
+            .get_metadata(&PathUri::from_abs_path(&file), /*sandbox*/ None)
+            .await
+            .is_ok_and(|metadata| !metadata.is_directory)
+        {
+            existing.push((file_name, file));
+        }
+    }
+
+    let mut existing = existing.into_iter();
+    let Some((selected_name, selected_file)) = existing.next() else {
+        return (
+            AbsolutePathBuf::resolve_path_against_base(CONFIG_TOML_FILE, codex_home),
+            None,
+        );
+    };
+    let ignored = existing
+        .map(|(file_name, _)| format!("`{file_name}`"))
+        .collect::<Vec<_>>();
+    let warning = (!ignored.is_empty()).then(|| {
+        format!(
+            "Found multiple config files in {}; using `{selected_name}` and ignoring {}.",
+            codex_home.display(),
+            ignored.join(", ")
+        )
+    });
+    (selected_file, warning)
+}
+
+/// Synchronous form of [`resolve_user_config_file`] for config writers:
+/// the user config file in `codex_home`, or `config.toml` when none exists.
+pub fn user_config_file(codex_home: &Path) -> PathBuf {
+    std::iter::once(CONFIG_TOML_FILE)
+        .chain(ALTERNATE_CONFIG_FILES.iter().copied())
+        .map(|file_name| codex_home.join(file_name))
+        .find(|file| file.is_file())
+        .unwrap_or_else(|| codex_home.join(CONFIG_TOML_FILE))
+}
+
+/// Config edits rewrite TOML in place to keep comments and layout, so they
+/// are refused for YAML and JSON files instead of writing a `config.toml`
+/// that would shadow the user's file.
+pub fn ensure_editable_config_file(path: &Path) -> io::Result<()> {
+    match ConfigFileFormat::from_path(path) {
+        ConfigFileFormat::Toml => Ok(()),
+        format @ (ConfigFileFormat::Yaml | ConfigFileFormat::Json) => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{} is a {} config file and Codex can only edit TOML config; make this change by hand or convert the file to {CONFIG_TOML_FILE}",
+                path.display(),
+                format.label()
+            ),
+        )),
+    }
+}
+
+/// The user config file in `codex_home` that config edits should write to.
+pub fn user_config_file_for_edit(codex_home: &Path) -> io::Result<PathBuf> {
+    let path = user_config_file(codex_home);
+    ensure_editable_config_file(&path)?;
+    Ok(path)
+}
+
+/// Parses a config document into a TOML table. The document must be a
+/// mapping at the top level; an empty document is an empty table.
+pub fn parse_config_document(
+    path: &Path,
+    format: ConfigFileFormat,
+    contents: &str,
+) -> Result<toml::Table, ConfigError> {
+    if contents.trim().is_empty() {
+        return Ok(toml::Table::new());
+    }
+    let value = match format {
+        ConfigFileFormat::Toml => {
+            return toml::from_str(contents)
+                .map_err(|err| config_error_from_toml(path, contents, err));
+        }
+        ConfigFileFormat::Yaml => {
+            serde_yaml::from_str::<TomlValue>(contents).map_err(|err| yaml_error(path, &err))?
+        }
+        ConfigFileFormat::Json => {
+            serde_json::from_str::<TomlValue>(contents).map_err(|err| json_error(path, &err))?
+        }
+    };
+    match value {
+        TomlValue::Table(table) => Ok(table),
+        _ => Err(ConfigError::new(
+            path.to_path_buf(),
+            point_range(/*line*/ 1, /*column*/ 1),
+            format!("expected a {} mapping at the top level", format.label()),
+        )),
+    }
+}
+
+/// Deserializes a config document as `T` with the format's own parser, so
+/// type errors point at the offending line and column.
+pub(crate) fn config_error_from_typed_document<T: DeserializeOwned>(
+    path: &Path,
+    contents: &str,
+) -> Option<ConfigError> {
+    match ConfigFileFormat::from_path(path) {
+        ConfigFileFormat::Toml => config_error_from_typed_toml::<T>(path, contents),
+        ConfigFileFormat::Yaml => serde_yaml::from_str::<T>(contents)
+            .err()
+            .map(|err| yaml_error(path, &err)),
+        ConfigFileFormat::Json => serde_json::from_str::<T>(contents)
+            .err()
+            .map(|err| json_error(path, &err)),
+    }
+}
+
+fn yaml_error(path: &Path, err: &serde_yaml::Error) -> ConfigError {
+    let (line, column) = err
+        .location()
+        .map_or((1, 1), |location| (location.line(), location.column()));
+    ConfigError::new(
+        path.to_path_buf(),
+        point_range(line, column),
+        message_without_location(err.to_string(), line, column),
+    )
+}
+
+fn json_error(path: &Path, err: &serde_json::Error) -> ConfigError {
+    let (line, column) = (err.line(), err.column());
+    ConfigError::new(
+        path.to_path_buf(),
+        point_range(line, column.max(1)),
+        message_without_location(err.to_string(), line, column),
+    )
+}
+
+/// Both parsers append ` at line L column C` to their messages; the location
+/// is reported separately by `ConfigError`.
+fn message_without_location(message: String, line: usize, column: usize) -> String {
+    match message.strip_suffix(&format!(" at line {line} column {column}")) {
+        Some(message) => message.to_string(),
+        None => message,
+    }
+}
+
+fn point_range(line: usize, column: usize) -> TextRange {
+    let position = TextPosition { line, column };
+    TextRange {
+        start: position,
+        end: position,
+    }
+}
+
+#[cfg(test)]
+#[path = "config_formats_tests.rs"]
+mod tests;
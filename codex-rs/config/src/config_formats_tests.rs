@@ -0,0 +1,81 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use std::path::PathBuf;
+
+#[test]
+fn format_is_chosen_by_extension() {
+    assert_eq!(
+        [
+            "config.toml",
+            "config.yaml",
+            "config.YML",
+            "config.json",
+            "overrides",
+        ]
+        .map(|path| ConfigFileFormat::from_path(Path::new(path))),
+        [
+            ConfigFileFormat::Toml,
+            ConfigFileFormat::Yaml,
+            ConfigFileFormat::Yaml,
+            ConfigFileFormat::Json,
+            ConfigFileFormat::Toml,
+        ]
+    );
+}
+
+#[test]
+fn yaml_and_json_documents_parse_to_the_same_table() {
+    let toml_table = parse_config_document(
+        Path::new("config.toml"),
+        ConfigFileFormat::Toml,
+        "model = \"gpt-main\"\n[tools]\nweb_search = true\n",
+    )
+    .expect("parse TOML");
+    let yaml_table = parse_config_document(
+        Path::new("config.yaml"),
+        ConfigFileFormat::Yaml,
+        "model: gpt-main\ntools:\n  web_search: true\n",
+    )
+    .expect("parse YAML");
+    let json_table = parse_config_document(
+        Path::new("config.json"),
+        ConfigFileFormat::Json,
+        r#"{"model": "gpt-main", "tools": {"web_search": true}}"#,
+    )
+    .expect("parse JSON");
+
+    assert_eq!(yaml_table, toml_table);
+    assert_eq!(json_table, toml_table);
+}
+
+#[test]
+fn non_mapping_documents_are_rejected() {
+    assert_eq!(
+        parse_config_document(
+            Path::new("config.yaml"),
+            ConfigFileFormat::Yaml,
+            "- model\n"
+        ),
+        Err(ConfigError::new(
+            PathBuf::from("config.yaml"),
+            point_range(/*line*/ 1, /*column*/ 1),
+            "expected a YAML mapping at the top level",
+        ))
+    );
+}
+
+#[test]
+fn json_errors_keep_line_and_column() {
+    assert_eq!(
+        parse_config_document(
+            Path::new("config.json"),
+            ConfigFileFormat::Json,
+            "{\n  \"model\": \"gpt-main\",\n  \"features\": }\n",
+        ),
+        Err(ConfigError::new(
+            PathBuf::from("config.json"),
+            point_range(/*line*/ 3, /*column*/ 15),
+            "expected value",
+        ))
+    );
+}
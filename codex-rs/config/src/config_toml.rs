@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::BuiltinToolName;
 use crate::HooksToml;
 use crate::permissions_toml::PermissionsToml;
 use crate::profile_toml::ConfigProfile;
@@ -13,12 +14,14 @@ use crate::types::AppsConfigToml;
 use crate::types::AuthCredentialsStoreMode;
 use crate::types::FeedbackConfigToml;
 use crate::types::History;
+use crate::types::LimitsToml;
 use crate::types::MarketplaceConfig;
 use crate::types::McpServerConfig;
 use crate::types::MemoriesToml;
 use crate::types::Notice;
 use crate::types::OAuthCredentialsStoreMode;
 use crate::types::OtelConfigToml;
+use crate::types::OutboundNetworkToml;
 use crate::types::PluginConfig;
 use crate::types::SandboxWorkspaceWrite;
 use crate::types::ShellEnvironmentPolicyToml;
@@ -66,6 +69,7 @@ const RESERVED_MODEL_PROVIDER_IDS: [&str; 4] = [
 ];
 
 pub const DEFAULT_PROJECT_DOC_MAX_BYTES: usize = 32 * 1024;
+pub const DEFAULT_PROJECT_DOC_MAX_DEPTH: usize = 64;
 
 const fn default_allow_login_shell() -> Option<bool> {
     Some(true)
@@ -79,6 +83,10 @@ const fn default_project_doc_max_bytes() -> Option<usize> {
     Some(DEFAULT_PROJECT_DOC_MAX_BYTES)
 }
 
+const fn default_project_doc_max_depth() -> Option<usize> {
+    Some(DEFAULT_PROJECT_DOC_MAX_DEPTH)
+}
+
 fn default_project_doc_fallback_filenames() -> Option<Vec<String>> {
     Some(Vec::new())
 }
@@ -258,8 +266,21 @@ pub struct ConfigToml {
     #[serde(default)]
     pub cli_auth_credentials_store: Option<AuthCredentialsStoreMode>,
 
-    /// Definition for MCP servers that Codex can reach out to for tool calls.
+    /// Auth profile whose credentials to use, overriding the one selected with
+    /// `codex auth use`. Set it in a project's `.codex/config.toml` to pin that
+    /// project to an account.
     #[serde(default)]
+    pub auth_profile: Option<String>,
+
+    /// How many seconds before a ChatGPT access token expires Codex refreshes it in the
+    /// background. Default: `300` (5 minutes).
+    pub chatgpt_token_refresh_margin_seconds: Option<u64>,
+
+    /// Definition for MCP servers that Codex can reach out to for tool calls.
+    #[serde(
+        default,
+        deserialize_with = "crate::mcp_types::deserialize_mcp_servers"
+    )]
     // Uses the raw MCP input shape (custom deserialization) rather than `McpServerConfig`.
     #[schemars(schema_with = "crate::schema::mcp_servers_schema")]
     pub mcp_servers: HashMap<String, McpServerConfig>,
@@ -291,7 +312,14 @@ pub struct ConfigToml {
     #[serde(default = "default_project_doc_max_bytes")]
     pub project_doc_max_bytes: Option<usize>,
 
-    /// Ordered list of fallback filenames to look for when AGENTS.md is missing.
+    /// Maximum number of parent directories above the working directory to
+    /// search for AGENTS.md project docs.
+    #[serde(default = "default_project_doc_max_depth")]
+    pub project_doc_max_depth: Option<usize>,
+
+    /// Ordered list of fallback filenames to look for when AGENTS.md is missing,
+    /// such as `CLAUDE.md` or `.agentrc`. Names with an extension other than
+    /// `.md`, `.markdown`, or `.txt` are ignored.
     #[serde(default = "default_project_doc_fallback_filenames")]
     pub project_doc_fallback_filenames: Option<Vec<String>>,
 
@@ -369,6 +397,10 @@ pub struct ConfigToml {
     /// Base URL for requests to ChatGPT (as opposed to the OpenAI API).
     pub chatgpt_base_url: Option<String>,
 
+    /// How long a ChatGPT rate-limit status request may take, in milliseconds,
+    /// before it is abandoned. Default: `5000` (5 seconds).
+    pub rate_limits_timeout_ms: Option<u64>,
+
     /// Optional product SKU forwarded on host-owned Codex Apps MCP requests.
     pub apps_mcp_product_sku: Option<String>,
 
@@ -501,6 +533,12 @@ pub struct ConfigToml {
     /// OTEL configuration.
     pub otel: Option<OtelConfigToml>,
 
+    /// Proxy and TLS trust settings for outbound HTTP requests.
+    pub network: Option<OutboundNetworkToml>,
+
+    /// Rate-limit usage warnings and client-side throttling.
+    pub limits: Option<LimitsToml>,
+
     /// Windows-specific configuration.
     #[serde(default)]
     pub windows: Option<WindowsToml>,
@@ -641,6 +679,13 @@ pub struct ToolsToml {
     )]
     pub web_search: Option<WebSearchToolConfig>,
     pub experimental_request_user_input: Option<ExperimentalRequestUserInput>,
+    /// Built-in tools to leave out of the tool registry. The model never sees
+    /// them, and calls to them are rejected.
+    pub disabled: Option<Vec<BuiltinToolName>>,
+    /// Per-tool timeouts, in seconds, for built-in tools. A call that runs
+    /// longer is cut off and the model is told it timed out. Tools without an
+    /// entry are unbounded.
+    pub timeout_sec: Option<HashMap<BuiltinToolName, f64>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
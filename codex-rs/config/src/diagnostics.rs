@@ -5,6 +5,7 @@ use crate::ConfigLayerEntry;
 use crate::ConfigLayerSource;
 use crate::ConfigLayerStack;
 use crate::ConfigLayerStackOrdering;
+use crate::config_formats::config_error_from_typed_document;
 use crate::format_config_layer_source;
 use codex_utils_absolute_path::AbsolutePathBufGuard;
 use serde::de::DeserializeOwned;
@@ -238,7 +239,7 @@ where
             continue;
         };
         let _guard = AbsolutePathBufGuard::new(parent);
-        if let Some(error) = config_error_from_typed_toml::<T>(&path, &contents) {
+        if let Some(error) = config_error_from_typed_document::<T>(&path, &contents) {
             return Some(error);
         }
     }
@@ -57,3 +57,12 @@ pub(crate) fn normalized_with_key_aliases(value: &TomlValue, path: &[String]) ->
         _ => value.clone(),
     }
 }
+
+/// Returns the canonical name for `key` when it is a legacy alias inside the
+/// table at `table_path`.
+pub(crate) fn canonical_key<'a>(table_path: &[&str], key: &'a str) -> &'a str {
+    CONFIG_KEY_ALIASES
+        .iter()
+        .find(|alias| alias.table_path == table_path && alias.legacy_key == key)
+        .map_or(key, |alias| alias.canonical_key)
+}
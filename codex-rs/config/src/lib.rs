@@ -1,5 +1,7 @@
+mod builtin_tools;
 mod cloud_config_bundle;
 mod cloud_config_layers;
+mod config_formats;
 mod config_layer_source;
 mod config_requirements;
 pub mod config_toml;
@@ -15,6 +17,7 @@ mod mcp_edit;
 mod mcp_requirements;
 mod mcp_types;
 mod merge;
+mod override_validation;
 mod overrides;
 pub mod permissions_toml;
 mod plugin_edit;
@@ -23,6 +26,7 @@ mod project_root_markers;
 mod requirements_exec_policy;
 mod requirements_layers;
 pub mod schema;
+mod schema_example;
 mod skills_config;
 mod state;
 mod strict_config;
@@ -33,6 +37,7 @@ pub mod types;
 
 pub const CONFIG_TOML_FILE: &str = "config.toml";
 
+pub use builtin_tools::BuiltinToolName;
 pub use cloud_config_bundle::CloudConfigBundle;
 pub use cloud_config_bundle::CloudConfigBundleLayers;
 pub use cloud_config_bundle::CloudConfigBundleLoadError;
@@ -48,6 +53,12 @@ pub use cloud_config_layers::cloud_config_layers_from_fragments;
 pub use codex_protocol::config_types::ProfileV2Name;
 pub use codex_protocol::config_types::ProfileV2NameParseError;
 pub use codex_utils_absolute_path::AbsolutePathBuf;
+pub use config_formats::ALTERNATE_CONFIG_FILES;
+pub use config_formats::ConfigFileFormat;
+pub use config_formats::ensure_editable_config_file;
+pub use config_formats::parse_config_document;
+pub use config_formats::user_config_file;
+pub use config_formats::user_config_file_for_edit;
 pub use config_layer_source::ConfigLayer;
 pub use config_layer_source::ConfigLayerMetadata;
 pub use config_layer_source::ConfigLayerSource;
@@ -129,7 +140,11 @@ pub use mcp_types::McpServerToolConfig;
 pub use mcp_types::McpServerTransportConfig;
 pub use mcp_types::RawMcpServerConfig;
 pub use merge::merge_toml_values;
+pub use override_validation::ConfigOverrideIssue;
+pub use override_validation::validate_config_override;
 pub use overrides::build_cli_overrides_layer;
+pub use overrides::override_path_segments;
+pub use overrides::quote_override_path_segment;
 pub use plugin_edit::PluginConfigEdit;
 pub use plugin_edit::apply_user_plugin_config_edits;
 pub use plugin_edit::clear_user_plugin;
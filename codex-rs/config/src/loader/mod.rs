@@ -11,6 +11,9 @@ use crate::ConfigLayerSource;
 use crate::ProfileV2Name;
 use crate::RequirementsLayerEntry;
 use crate::compose_requirements;
+use crate::config_formats::ConfigFileFormat;
+use crate::config_formats::parse_config_document;
+use crate::config_formats::resolve_user_config_file;
 use crate::config_requirements::RequirementSource;
 use crate::config_requirements::SandboxModeRequirement;
 use crate::config_toml::ConfigToml;
@@ -241,8 +244,13 @@ pub async fn load_config_layers_state(
     // Add the base user config layer. When profile-v2 is selected, add the
     // profile config as a second user layer on top so the profile only needs to
     // contain overrides.
-    let active_user_file = overrides.user_config_path(codex_home)?;
-    let base_user_file = AbsolutePathBuf::resolve_path_against_base(CONFIG_TOML_FILE, codex_home);
+    let default_user_file =
+        AbsolutePathBuf::resolve_path_against_base(CONFIG_TOML_FILE, codex_home);
+    let (base_user_file, user_config_warning) = resolve_user_config_file(fs, codex_home).await;
+    let active_user_file = match overrides.user_config_path(codex_home)? {
+        active_user_file if active_user_file == default_user_file => base_user_file.clone(),
+        active_user_file => active_user_file,
+    };
     let base_user_layer = load_user_config_layer(
         fs,
         &base_user_file,
@@ -288,7 +296,7 @@ pub async fn load_config_layers_state(
         );
     }
 
-    let mut startup_warnings = None;
+    let mut startup_warnings = user_config_warning.map(|warning| vec![warning]);
     if let Some(cwd) = cwd {
         let mut merged_so_far = TomlValue::Table(toml::map::Map::new());
         for layer in &layers {
@@ -347,7 +355,9 @@ pub async fn load_config_layers_state(
         )
         .await?;
         layers.extend(project_layers.layers);
-        startup_warnings = Some(project_layers.startup_warnings);
+        startup_warnings
+            .get_or_insert_with(Vec::new)
+            .extend(project_layers.startup_warnings);
     }
 
     // Add a layer for runtime overrides from the CLI or UI, if any exist.
@@ -460,12 +470,13 @@ fn insert_layer_by_precedence(layers: &mut Vec<ConfigLayerEntry>, layer: ConfigL
     }
 }
 
-/// Attempts to load a config.toml file from `config_toml`.
-/// - If the file exists and is valid TOML, passes the parsed `toml::Value` to
+/// Attempts to load a config file from `toml_file`. YAML and JSON files are
+/// recognized by extension and parsed into the same TOML value tree.
+/// - If the file exists and is valid, passes the parsed `toml::Value` to
 ///   `create_entry` and returns the resulting layer entry.
 /// - If the file does not exist, uses an empty `Table` with `create_entry` and
 ///   returns the resulting layer entry.
-/// - If there is an error reading the file or parsing the TOML, returns an
+/// - If there is an error reading or parsing the file, returns an
 ///   error.
 async fn load_config_toml_for_required_layer(
     fs: &dyn ExecutorFileSystem,
@@ -485,11 +496,24 @@ async fn load_config_toml_for_required_layer(
                     ),
                 )
             })?;
-            let config: TomlValue = toml::from_str(&contents).map_err(|err| {
-                let config_error =
-                    config_error_from_toml(toml_file.as_path(), &contents, err.clone());
-                io_error_from_config_error(io::ErrorKind::InvalidData, config_error, Some(err))
-            })?;
+            let config: TomlValue = match ConfigFileFormat::from_path(toml_file.as_path()) {
+                ConfigFileFormat::Toml => toml::from_str(&contents).map_err(|err| {
+                    let config_error =
+                        config_error_from_toml(toml_file.as_path(), &contents, err.clone());
+                    io_error_from_config_error(io::ErrorKind::InvalidData, config_error, Some(err))
+                })?,
+                format @ (ConfigFileFormat::Yaml | ConfigFileFormat::Json) => {
+                    parse_config_document(toml_file.as_path(), format, &contents)
+                        .map(TomlValue::Table)
+                        .map_err(|config_error| {
+                            io_error_from_config_error(
+                                io::ErrorKind::InvalidData,
+                                config_error,
+                                /*source*/ None,
+                            )
+                        })?
+                }
+            };
             if strict_config {
                 validate_config_toml_strictly(
                     toml_file.as_path(),
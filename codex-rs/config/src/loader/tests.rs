@@ -70,10 +70,21 @@ impl ExecutorFileSystem for TestFileSystem {
 
     fn get_metadata<'a>(
         &'a self,
-        _path: &'a PathUri,
+        path: &'a PathUri,
         _sandbox: Option<&'a FileSystemSandboxContext>,
     ) -> ExecutorFileSystemFuture<'a, FileMetadata> {
-        Box::pin(async move { unimplemented!("test filesystem only supports reads") })
+        Box::pin(async move {
+            let path = path.to_abs_path()?;
+            let metadata = tokio::fs::metadata(path.as_path()).await?;
+            Ok(FileMetadata {
+                is_directory: metadata.is_dir(),
+                is_file: metadata.is_file(),
+                is_symlink: metadata.is_symlink(),
+                size: metadata.len(),
+                created_at_ms: 0,
+                modified_at_ms: 0,
+            })
+        })
     }
 
     fn read_directory<'a>(
@@ -254,3 +265,127 @@ model = "gpt-dev"
     .await
     .expect("profile-v2 should allow unrelated legacy profiles in base user config");
 }
+
+async fn load_user_layer_from_codex_home(codex_home: &Path) -> io::Result<ConfigLayerStack> {
+    load_config_layers_state(
+        &TestFileSystem,
+        codex_home,
+        /*cwd*/ None,
+        &[],
+        LoaderOverrides::without_managed_config_for_tests(),
+        &crate::NoopThreadConfigLoader,
+    )
+    .await
+}
+
+#[tokio::test]
+async fn yaml_and_json_user_configs_load_like_toml() {
+    let documents = [
+        (
+            CONFIG_TOML_FILE,
+            r#"
+model = "gpt-main"
+model_reasoning_effort = "high"
+
+[features]
+code_mode = true
+
+[mcp_servers.docs]
+command = "docs-server"
+args = ["--stdio"]
+"#,
+        ),
+        (
+            "config.yaml",
+            r#"
+model: gpt-main
+model_reasoning_effort: high
+features:
+  code_mode: true
+mcp_servers:
+  docs:
+    command: docs-server
+    args: ["--stdio"]
+"#,
+        ),
+        (
+            "config.json",
+            r#"{
+  "model": "gpt-main",
+  "model_reasoning_effort": "high",
+  "features": { "code_mode": true },
+  "mcp_servers": { "docs": { "command": "docs-server", "args": ["--stdio"] } }
+}"#,
+        ),
+    ];
+
+    let mut effective_configs = Vec::new();
+    for (file_name, contents) in documents {
+        let tmp = tempdir().expect("tempdir");
+        std::fs::write(tmp.path().join(file_name), contents).expect("write user config");
+        let layers = load_user_layer_from_codex_home(tmp.path())
+            .await
+            .unwrap_or_else(|err| panic!("{file_name} should load: {err}"));
+        assert_eq!(layers.startup_warnings(), None);
+        let config: ConfigToml = layers
+            .effective_config()
+            .try_into()
+            .unwrap_or_else(|err| panic!("{file_name} should deserialize: {err}"));
+        effective_configs.push(config);
+    }
+
+    assert_ne!(effective_configs[0], ConfigToml::default());
+    assert_eq!(effective_configs[0], effective_configs[1]);
+    assert_eq!(effective_configs[0], effective_configs[2]);
+}
+
+#[tokio::test]
+async fn toml_user_config_wins_over_alternate_formats_with_warning() {
+    let tmp = tempdir().expect("tempdir");
+    std::fs::write(tmp.path().join(CONFIG_TOML_FILE), r#"model = "from-toml""#)
+        .expect("write config.toml");
+    std::fs::write(tmp.path().join("config.yaml"), "model: from-yaml\n")
+        .expect("write config.yaml");
+    std::fs::write(tmp.path().join("config.json"), r#"{"model": "from-json"}"#)
+        .expect("write config.json");
+
+    let layers = load_user_layer_from_codex_home(tmp.path())
+        .await
+        .expect("load config layers");
+
+    assert_eq!(
+        layers.effective_config().get("model"),
+        Some(&TomlValue::String("from-toml".to_string()))
+    );
+    assert_eq!(
+        layers.startup_warnings(),
+        Some(
+            [format!(
+                "Found multiple config files in {}; using `config.toml` and ignoring `config.yaml`, `config.json`.",
+                tmp.path().display()
+            )]
+            .as_slice()
+        )
+    );
+}
+
+#[tokio::test]
+async fn json_user_config_errors_report_line_and_column() {
+    let tmp = tempdir().expect("tempdir");
+    let config_path = tmp.path().join("config.json");
+    std::fs::write(
+        &config_path,
+        "{\n  \"model\": \"gpt-main\",\n  \"features\": }\n",
+    )
+    .expect("write config.json");
+
+    let err = load_user_layer_from_codex_home(tmp.path())
+        .await
+        .expect_err("invalid JSON should fail");
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        format!("{}:3:15: expected value", config_path.display())
+    );
+}
@@ -8,7 +8,7 @@ use toml_edit::Table as TomlTable;
 use toml_edit::Value as TomlValue;
 use toml_edit::value;
 
-use crate::CONFIG_TOML_FILE;
+use crate::user_config_file_for_edit;
 
 pub struct MarketplaceConfigUpdate<'a> {
     pub last_updated: &'a str,
@@ -31,7 +31,7 @@ pub fn record_user_marketplace(
     marketplace_name: &str,
     update: &MarketplaceConfigUpdate<'_>,
 ) -> std::io::Result<()> {
-    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_path = user_config_file_for_edit(codex_home)?;
     let mut doc = read_or_create_document(&config_path)?;
     upsert_marketplace(&mut doc, marketplace_name, update);
     fs::create_dir_all(codex_home)?;
@@ -47,7 +47,7 @@ pub fn remove_user_marketplace_config(
     codex_home: &Path,
     marketplace_name: &str,
 ) -> std::io::Result<RemoveMarketplaceConfigOutcome> {
-    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_path = user_config_file_for_edit(codex_home)?;
     let mut doc = match fs::read_to_string(&config_path) {
         Ok(raw) => raw
             .parse::<DocumentMut>()
@@ -183,6 +183,7 @@ fn new_implicit_table() -> TomlTable {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::CONFIG_TOML_FILE;
     use pretty_assertions::assert_eq;
     use tempfile::TempDir;
 
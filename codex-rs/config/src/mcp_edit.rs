@@ -12,23 +12,33 @@ use toml_edit::Table as TomlTable;
 use toml_edit::value;
 
 use crate::AppToolApproval;
-use crate::CONFIG_TOML_FILE;
+use crate::ConfigFileFormat;
 use crate::McpServerAuth;
 use crate::McpServerConfig;
 use crate::McpServerEnvVar;
 use crate::McpServerTransportConfig;
+use crate::io_error_from_config_error;
+use crate::parse_config_document;
+use crate::user_config_file;
+use crate::user_config_file_for_edit;
 
 pub async fn load_global_mcp_servers(
     codex_home: &Path,
 ) -> std::io::Result<BTreeMap<String, McpServerConfig>> {
-    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_path = user_config_file(codex_home);
     let raw = match tokio::fs::read_to_string(&config_path).await {
         Ok(raw) => raw,
         Err(err) if err.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
         Err(err) => return Err(err),
     };
-    let parsed = toml::from_str::<TomlValue>(&raw)
-        .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))?;
+    let parsed = parse_config_document(
+        &config_path,
+        ConfigFileFormat::from_path(&config_path),
+        &raw,
+    )
+    .map_err(|config_error| {
+        io_error_from_config_error(ErrorKind::InvalidData, config_error, /*source*/ None)
+    })?;
     let Some(servers_value) = parsed.get("mcp_servers") else {
         return Ok(BTreeMap::new());
     };
@@ -87,7 +97,7 @@ impl ConfigEditsBuilder {
     }
 
     fn apply_blocking(self) -> std::io::Result<()> {
-        let config_path = self.codex_home.join(CONFIG_TOML_FILE);
+        let config_path = user_config_file_for_edit(&self.codex_home)?;
         let mut doc = read_or_create_document(&config_path)?;
         if let Some(servers) = self.mcp_servers.as_ref() {
             replace_mcp_servers(&mut doc, servers);
@@ -171,6 +181,27 @@ fn serialize_mcp_server(config: &McpServerConfig) -> TomlItem {
                 entry["env_http_headers"] = table_from_pairs(headers.iter());
             }
         }
+        McpServerTransportConfig::WebSocket {
+            ws_url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        } => {
+            entry["ws_url"] = value(ws_url.clone());
+            if let Some(env_var) = bearer_token_env_var {
+                entry["bearer_token_env_var"] = value(env_var.clone());
+            }
+            if let Some(headers) = http_headers
+                && !headers.is_empty()
+            {
+                entry["http_headers"] = table_from_pairs(headers.iter());
+            }
+            if let Some(headers) = env_http_headers
+                && !headers.is_empty()
+            {
+                entry["env_http_headers"] = table_from_pairs(headers.iter());
+            }
+        }
     }
 
     if matches!(&config.auth, McpServerAuth::ChatGpt) {
@@ -188,6 +219,12 @@ fn serialize_mcp_server(config: &McpServerConfig) -> TomlItem {
     if config.supports_parallel_tool_calls {
         entry["supports_parallel_tool_calls"] = value(true);
     }
+    if config.requires_approval {
+        entry["requires_approval"] = value(true);
+    }
+    if let Some(description) = &config.description {
+        entry["description"] = value(description.clone());
+    }
     if let Some(timeout) = config.startup_timeout_sec {
         entry["startup_timeout_sec"] = value(timeout.as_secs_f64());
     }
@@ -1,10 +1,12 @@
 use super::*;
+use crate::CONFIG_TOML_FILE;
 use crate::McpServerOAuthConfig;
 use crate::McpServerToolConfig;
 use pretty_assertions::assert_eq;
 use std::collections::HashMap;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
+use tempfile::TempDir;
 
 #[tokio::test]
 async fn replace_mcp_servers_serializes_per_tool_approval_overrides() -> anyhow::Result<()> {
@@ -28,6 +30,8 @@ async fn replace_mcp_servers_serializes_per_tool_approval_overrides() -> anyhow:
             enabled: true,
             required: false,
             supports_parallel_tool_calls: true,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -107,6 +111,8 @@ async fn replace_mcp_servers_serializes_oauth_client_id() -> anyhow::Result<()>
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -146,3 +152,45 @@ client_id = "eci-prd-pub-codex-123"
 
     Ok(())
 }
+
+#[tokio::test]
+async fn load_global_mcp_servers_reads_yaml_user_config() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.yaml"),
+        "mcp_servers:\n  docs:\n    command: docs-server\n",
+    )?;
+
+    let servers = load_global_mcp_servers(codex_home.path()).await?;
+
+    assert_eq!(
+        servers.keys().cloned().collect::<Vec<_>>(),
+        vec!["docs".to_string()]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn replace_mcp_servers_refuses_to_edit_yaml_user_config() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let yaml_path = codex_home.path().join("config.yaml");
+    std::fs::write(&yaml_path, "model: gpt-5\n")?;
+
+    let err = ConfigEditsBuilder::new(codex_home.path())
+        .replace_mcp_servers(&BTreeMap::new())
+        .apply()
+        .await
+        .expect_err("YAML config should not be edited");
+
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "{} is a YAML config file and Codex can only edit TOML config; make this change by hand or convert the file to config.toml",
+            yaml_path.display()
+        )
+    );
+    assert!(!codex_home.path().join(CONFIG_TOML_FILE).exists());
+    assert_eq!(std::fs::read_to_string(&yaml_path)?, "model: gpt-5\n");
+    Ok(())
+}
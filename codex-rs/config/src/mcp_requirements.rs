@@ -139,7 +139,10 @@ impl McpServerRequirement {
                 Self::Identity {
                     identity: McpServerIdentity::Url { url: want_url },
                 },
-                McpServerTransportConfig::StreamableHttp { url: got_url, .. },
+                McpServerTransportConfig::StreamableHttp { url: got_url, .. }
+                | McpServerTransportConfig::WebSocket {
+                    ws_url: got_url, ..
+                },
             ) => got_url == want_url,
             (Self::Command(matcher), McpServerTransportConfig::Stdio { command, args, .. }) => {
                 matcher.executable == *command
@@ -150,9 +153,11 @@ impl McpServerRequirement {
                         .zip(args)
                         .all(|(matcher, arg)| matcher.matches(arg))
             }
-            (Self::Url(matcher), McpServerTransportConfig::StreamableHttp { url, .. }) => {
-                matcher.matches(url)
-            }
+            (
+                Self::Url(matcher),
+                McpServerTransportConfig::StreamableHttp { url, .. }
+                | McpServerTransportConfig::WebSocket { ws_url: url, .. },
+            ) => matcher.matches(url),
             _ => false,
         }
     }
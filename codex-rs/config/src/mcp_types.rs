@@ -16,6 +16,10 @@ use crate::RequirementSource;
 /// Effective MCP environment id when config omits `environment_id`.
 pub const DEFAULT_MCP_SERVER_ENVIRONMENT_ID: &str = "local";
 
+/// Upper bound for `startup_timeout_sec` / `startup_timeout_ms`. Larger values
+/// are almost certainly unit mistakes.
+const MAX_MCP_STARTUP_TIMEOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AppToolApproval {
@@ -177,6 +181,16 @@ pub struct McpServerConfig {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub supports_parallel_tool_calls: bool,
 
+    /// When `true`, every tool call routed to this server goes through the
+    /// approval flow, regardless of the global approval policy or tool
+    /// approval modes.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub requires_approval: bool,
+
+    /// Human-readable description of what this server is for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
     /// Reason this server was disabled after applying requirements.
     #[serde(skip)]
     pub disabled_reason: Option<McpServerDisabledReason>,
@@ -266,6 +280,11 @@ pub struct RawMcpServerConfig {
     pub bearer_token: Option<String>,
     pub bearer_token_env_var: Option<String>,
 
+    // websocket
+    /// `ws://` or `wss://` URL for an MCP server that speaks JSON-RPC over a
+    /// WebSocket connection.
+    pub ws_url: Option<String>,
+
     // shared
     #[serde(default)]
     pub environment_id: Option<String>,
@@ -275,9 +294,8 @@ pub struct RawMcpServerConfig {
     pub startup_timeout_sec: Option<f64>,
     #[serde(default)]
     pub startup_timeout_ms: Option<u64>,
-    #[serde(default, with = "option_duration_secs")]
-    #[schemars(with = "Option<f64>")]
-    pub tool_timeout_sec: Option<Duration>,
+    #[serde(default)]
+    pub tool_timeout_sec: Option<f64>,
     #[serde(default)]
     pub enabled: Option<bool>,
     #[serde(default)]
@@ -285,6 +303,10 @@ pub struct RawMcpServerConfig {
     #[serde(default)]
     pub supports_parallel_tool_calls: Option<bool>,
     #[serde(default)]
+    pub requires_approval: Option<bool>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
     pub default_tools_approval_mode: Option<AppToolApproval>,
     #[serde(default)]
     pub enabled_tools: Option<Vec<String>>,
@@ -307,6 +329,32 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
     type Error = String;
 
     fn try_from(raw: RawMcpServerConfig) -> Result<Self, Self::Error> {
+        raw.into_config(/*key_prefix*/ "")
+    }
+}
+
+/// Deserializes the `mcp_servers` table so validation errors name the full
+/// key path, e.g. `mcp_servers.docs.startup_timeout_sec`.
+pub(crate) fn deserialize_mcp_servers<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, McpServerConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    HashMap::<String, RawMcpServerConfig>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, raw)| {
+            raw.into_config(&format!("mcp_servers.{name}."))
+                .map(|config| (name, config))
+                .map_err(SerdeError::custom)
+        })
+        .collect()
+}
+
+impl RawMcpServerConfig {
+    /// Validates and maps this entry. `key_prefix` is prepended to the field
+    /// names in timeout errors so they read as config key paths.
+    fn into_config(self, key_prefix: &str) -> Result<McpServerConfig, String> {
         let RawMcpServerConfig {
             command,
             args,
@@ -318,6 +366,7 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             url,
             bearer_token,
             bearer_token_env_var,
+            ws_url,
             environment_id,
             auth,
             startup_timeout_sec,
@@ -326,6 +375,8 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             enabled,
             required,
             supports_parallel_tool_calls,
+            requires_approval,
+            description,
             default_tools_approval_mode,
             enabled_tools,
             disabled_tools,
@@ -334,15 +385,49 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             oauth_resource,
             _name: _,
             tools,
-        } = raw;
-
-        let startup_timeout_sec = match (startup_timeout_sec, startup_timeout_ms) {
-            (Some(sec), _) => {
-                Some(Duration::try_from_secs_f64(sec).map_err(|err| err.to_string())?)
+        } = self;
+
+        let max_startup_timeout_sec = MAX_MCP_STARTUP_TIMEOUT.as_secs();
+        let max_startup_timeout_ms = MAX_MCP_STARTUP_TIMEOUT.as_millis();
+        let timeout_from_sec = startup_timeout_sec
+            .map(|sec| match Duration::try_from_secs_f64(sec) {
+                Ok(timeout) if !timeout.is_zero() && timeout <= MAX_MCP_STARTUP_TIMEOUT => {
+                    Ok(timeout)
+                }
+                _ => Err(format!(
+                    "{key_prefix}startup_timeout_sec must be greater than 0 and at most {max_startup_timeout_sec} seconds, got {sec}"
+                )),
+            })
+            .transpose()?;
+        let timeout_from_ms = startup_timeout_ms
+            .map(|ms| {
+                let timeout = Duration::from_millis(ms);
+                if timeout.is_zero() || timeout > MAX_MCP_STARTUP_TIMEOUT {
+                    return Err(format!(
+                        "{key_prefix}startup_timeout_ms must be greater than 0 and at most {max_startup_timeout_ms} milliseconds, got {ms}"
+                    ));
+                }
+                Ok(timeout)
+            })
+            .transpose()?;
+        let startup_timeout_sec = match (timeout_from_sec, timeout_from_ms) {
+            (Some(from_sec), Some(from_ms)) if from_sec != from_ms => {
+                return Err(format!(
+                    "{key_prefix}startup_timeout_sec ({from_sec:?}) and {key_prefix}startup_timeout_ms ({from_ms:?}) disagree; set only one of them"
+                ));
             }
-            (None, Some(ms)) => Some(Duration::from_millis(ms)),
+            (Some(timeout), _) | (None, Some(timeout)) => Some(timeout),
             (None, None) => None,
         };
+        let tool_timeout_sec = tool_timeout_sec
+            .map(|sec| {
+                Duration::try_from_secs_f64(sec).map_err(|_| {
+                    format!(
+                        "{key_prefix}tool_timeout_sec must be a non-negative number of seconds, got {sec}"
+                    )
+                })
+            })
+            .transpose()?;
 
         fn throw_if_set<T>(transport: &str, field: &str, value: Option<&T>) -> Result<(), String> {
             if value.is_none() {
@@ -353,6 +438,7 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
 
         let transport = if let Some(command) = command {
             throw_if_set("stdio", "url", url.as_ref())?;
+            throw_if_set("stdio", "ws_url", ws_url.as_ref())?;
             throw_if_set(
                 "stdio",
                 "bearer_token_env_var",
@@ -376,6 +462,7 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
                 cwd,
             }
         } else if let Some(url) = url {
+            throw_if_set("streamable_http", "ws_url", ws_url.as_ref())?;
             throw_if_set("streamable_http", "args", args.as_ref())?;
             throw_if_set("streamable_http", "env", env.as_ref())?;
             throw_if_set("streamable_http", "env_vars", env_vars.as_ref())?;
@@ -387,6 +474,21 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
                 http_headers,
                 env_http_headers,
             }
+        } else if let Some(ws_url) = ws_url {
+            throw_if_set("websocket", "args", args.as_ref())?;
+            throw_if_set("websocket", "env", env.as_ref())?;
+            throw_if_set("websocket", "env_vars", env_vars.as_ref())?;
+            throw_if_set("websocket", "cwd", cwd.as_ref())?;
+            throw_if_set("websocket", "bearer_token", bearer_token.as_ref())?;
+            throw_if_set("websocket", "oauth", oauth.as_ref())?;
+            throw_if_set("websocket", "oauth_resource", oauth_resource.as_ref())?;
+            throw_if_set("websocket", "auth", auth.as_ref())?;
+            McpServerTransportConfig::WebSocket {
+                ws_url,
+                bearer_token_env_var,
+                http_headers,
+                env_http_headers,
+            }
         } else {
             return Err("invalid transport".to_string());
         };
@@ -394,7 +496,7 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
         let environment_id =
             environment_id.unwrap_or_else(|| DEFAULT_MCP_SERVER_ENVIRONMENT_ID.to_string());
 
-        Ok(Self {
+        Ok(McpServerConfig {
             transport,
             auth: auth.unwrap_or_default(),
             environment_id,
@@ -403,6 +505,8 @@ impl TryFrom<RawMcpServerConfig> for McpServerConfig {
             enabled: enabled.unwrap_or_else(default_enabled),
             required: required.unwrap_or_default(),
             supports_parallel_tool_calls: supports_parallel_tool_calls.unwrap_or_default(),
+            requires_approval: requires_approval.unwrap_or_default(),
+            description,
             disabled_reason: None,
             default_tools_approval_mode,
             enabled_tools,
@@ -460,6 +564,22 @@ pub enum McpServerTransportConfig {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         env_http_headers: Option<HashMap<String, String>>,
     },
+    /// JSON-RPC messages exchanged as text frames over a single WebSocket
+    /// connection.
+    WebSocket {
+        ws_url: String,
+        /// Name of the environment variable to read for a bearer token sent
+        /// with the WebSocket upgrade request.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bearer_token_env_var: Option<String>,
+        /// Additional HTTP headers to include in the WebSocket upgrade request.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        http_headers: Option<HashMap<String, String>>,
+        /// Upgrade request headers where the value is sourced from an
+        /// environment variable.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        env_http_headers: Option<HashMap<String, String>>,
+    },
 }
 
 mod option_duration_secs {
@@ -394,6 +394,29 @@ fn deserialize_server_config_with_default_tool_approval_mode() {
     assert_eq!(round_tripped, cfg);
 }
 
+#[test]
+fn deserialize_server_config_with_requires_approval_and_description() {
+    let cfg: McpServerConfig = toml::from_str(
+        r#"
+            command = "echo"
+            requires_approval = true
+            description = "Production database access"
+        "#,
+    )
+    .expect("should deserialize requires_approval and description");
+
+    assert!(cfg.requires_approval);
+    assert_eq!(
+        cfg.description,
+        Some("Production database access".to_string())
+    );
+
+    let round_tripped: McpServerConfig =
+        toml::from_str(&toml::to_string(&cfg).expect("should serialize MCP config"))
+            .expect("should deserialize serialized MCP config");
+    assert_eq!(round_tripped, cfg);
+}
+
 #[test]
 fn serialize_round_trips_server_config_with_parallel_tool_calls() {
     let cfg: McpServerConfig = toml::from_str(
@@ -438,6 +461,8 @@ fn deserialize_ignores_unknown_server_fields() {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -452,6 +477,156 @@ fn deserialize_ignores_unknown_server_fields() {
     );
 }
 
+#[test]
+fn deserialize_normalizes_startup_timeout_fields() {
+    let startup_timeout = |fields: &str| {
+        toml::from_str::<McpServerConfig>(&format!("command = \"echo\"\n{fields}"))
+            .map(|cfg| cfg.startup_timeout_sec)
+            .map_err(|err| err.to_string())
+    };
+
+    assert_eq!(startup_timeout(""), Ok(None));
+    assert_eq!(
+        startup_timeout("startup_timeout_sec = 1.5"),
+        Ok(Some(Duration::from_millis(1_500)))
+    );
+    assert_eq!(
+        startup_timeout("startup_timeout_ms = 2500"),
+        Ok(Some(Duration::from_millis(2_500)))
+    );
+    assert_eq!(
+        startup_timeout("startup_timeout_sec = 2\nstartup_timeout_ms = 2000"),
+        Ok(Some(Duration::from_secs(2)))
+    );
+}
+
+#[test]
+fn deserialize_rejects_invalid_startup_timeouts() {
+    for (fields, expected) in [
+        (
+            "startup_timeout_sec = 2\nstartup_timeout_ms = 3000",
+            "startup_timeout_sec (2s) and startup_timeout_ms (3s) disagree; set only one of them",
+        ),
+        (
+            "startup_timeout_sec = -1.0",
+            "startup_timeout_sec must be greater than 0 and at most 86400 seconds, got -1",
+        ),
+        (
+            "startup_timeout_sec = 0",
+            "startup_timeout_sec must be greater than 0 and at most 86400 seconds, got 0",
+        ),
+        (
+            "startup_timeout_sec = nan",
+            "startup_timeout_sec must be greater than 0 and at most 86400 seconds, got NaN",
+        ),
+        (
+            "startup_timeout_sec = 1e12",
+            "startup_timeout_sec must be greater than 0 and at most 86400 seconds, got 1000000000000",
+        ),
+        (
+            "startup_timeout_ms = 0",
+            "startup_timeout_ms must be greater than 0 and at most 86400000 milliseconds, got 0",
+        ),
+        (
+            "startup_timeout_ms = 86400001",
+            "startup_timeout_ms must be greater than 0 and at most 86400000 milliseconds, got 86400001",
+        ),
+    ] {
+        let err = toml::from_str::<McpServerConfig>(&format!("command = \"echo\"\n{fields}"))
+            .expect_err("invalid startup timeout should be rejected");
+        assert!(
+            err.to_string().contains(expected),
+            "unexpected error for `{fields}`: {err}"
+        );
+    }
+}
+
+#[test]
+fn mcp_servers_timeout_errors_name_the_key_path() {
+    for (fields, expected) in [
+        (
+            "startup_timeout_sec = 0",
+            "mcp_servers.docs.startup_timeout_sec must be greater than 0 and at most 86400 seconds, got 0",
+        ),
+        (
+            "startup_timeout_ms = 86400001",
+            "mcp_servers.docs.startup_timeout_ms must be greater than 0 and at most 86400000 milliseconds, got 86400001",
+        ),
+        (
+            "tool_timeout_sec = -1.0",
+            "mcp_servers.docs.tool_timeout_sec must be a non-negative number of seconds, got -1",
+        ),
+    ] {
+        let err = toml::from_str::<crate::config_toml::ConfigToml>(&format!(
+            "[mcp_servers.docs]\ncommand = \"echo\"\n{fields}"
+        ))
+        .expect_err("invalid timeout should be rejected");
+        assert!(
+            err.to_string().contains(expected),
+            "unexpected error for `{fields}`: {err}"
+        );
+    }
+}
+
+#[test]
+fn deserialize_websocket_server_config() {
+    let cfg: McpServerConfig = toml::from_str(
+        r#"
+            ws_url = "wss://example.com/mcp"
+            bearer_token_env_var = "WS_TOKEN"
+            http_headers = { "X-Foo" = "bar" }
+            startup_timeout_sec = 5
+        "#,
+    )
+    .expect("should deserialize websocket config");
+
+    assert_eq!(
+        cfg.transport,
+        McpServerTransportConfig::WebSocket {
+            ws_url: "wss://example.com/mcp".to_string(),
+            bearer_token_env_var: Some("WS_TOKEN".to_string()),
+            http_headers: Some(HashMap::from([("X-Foo".to_string(), "bar".to_string())])),
+            env_http_headers: None,
+        }
+    );
+    assert_eq!(cfg.startup_timeout_sec, Some(Duration::from_secs(5)));
+    assert!(cfg.enabled);
+}
+
+#[test]
+fn deserialize_rejects_url_and_ws_url() {
+    let err = toml::from_str::<McpServerConfig>(
+        r#"
+            url = "https://example.com/mcp"
+            ws_url = "wss://example.com/mcp"
+        "#,
+    )
+    .expect_err("should reject url+ws_url");
+
+    assert!(
+        err.to_string()
+            .contains("ws_url is not supported for streamable_http"),
+        "unexpected error: {err}"
+    );
+}
+
+#[test]
+fn deserialize_rejects_stdio_fields_for_websocket() {
+    let err = toml::from_str::<McpServerConfig>(
+        r#"
+            ws_url = "wss://example.com/mcp"
+            env = { "FOO" = "BAR" }
+        "#,
+    )
+    .expect_err("should reject env for websocket transport");
+
+    assert!(
+        err.to_string()
+            .contains("env is not supported for websocket"),
+        "unexpected error: {err}"
+    );
+}
+
 #[test]
 fn deserialize_rejects_command_and_url() {
     toml::from_str::<McpServerConfig>(
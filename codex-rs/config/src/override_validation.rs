@@ -0,0 +1,334 @@
+//! Schema-backed validation for `-c key=value` config overrides.
+//!
+//! Overrides are merged into the config layers as raw TOML, so a typo or a
+//! wrong-typed value only fails later when the merged config is deserialized.
+//! This module walks the generated `config.toml` JSON schema along an
+//! override's dotted key path so callers can report the problem against the
+//! flag that introduced it.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use serde_json::Value as JsonValue;
+use toml::Value as TomlValue;
+
+use crate::key_aliases::canonical_key;
+use crate::key_aliases::normalized_with_key_aliases;
+use crate::overrides::override_path_segments;
+use crate::schema::config_schema;
+
+pub(crate) static CONFIG_SCHEMA: LazyLock<JsonValue> =
+    LazyLock::new(|| serde_json::to_value(config_schema()).unwrap_or(JsonValue::Null));
+
+/// Why a config override does not match the `config.toml` schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOverrideIssue {
+    /// No config key exists at `key`.
+    UnknownKey {
+        key: String,
+        suggestion: Option<String>,
+    },
+    /// `key` only accepts one of `allowed`.
+    InvalidValue {
+        key: String,
+        value: String,
+        allowed: Vec<String>,
+        suggestion: Option<String>,
+    },
+    /// `key` expects a value of a different type.
+    WrongType {
+        key: String,
+        expected: Vec<String>,
+        actual: String,
+    },
+}
+
+impl fmt::Display for ConfigOverrideIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownKey { key, suggestion } => {
+                write!(f, "unknown configuration field `{key}`")?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, "; did you mean `{suggestion}`?")?;
+                }
+                Ok(())
+            }
+            Self::InvalidValue {
+                key,
+                value,
+                allowed,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "invalid value `{value}` for `{key}`; expected one of: {}",
+                    allowed.join(", ")
+                )?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (did you mean `{suggestion}`?)")?;
+                }
+                Ok(())
+            }
+            Self::WrongType {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid type for `{key}`: expected {}, got {actual}",
+                expected.join(" or ")
+            ),
+        }
+    }
+}
+
+/// Checks one dotted-path override against the `config.toml` schema.
+///
+/// Paths that pass through schema nodes without fixed keys (for example
+/// untagged unions) are accepted without further checks, and legacy key
+/// aliases resolve to their canonical keys.
+pub fn validate_config_override(path: &str, value: &TomlValue) -> Result<(), ConfigOverrideIssue> {
+    let mut schema = &*CONFIG_SCHEMA;
+    let mut table_path = Vec::new();
+    let mut key = String::new();
+    let segments = override_path_segments(path);
+    for segment in &segments {
+        let segment = segment.as_str();
+        let canonical_segment = canonical_key(&table_path, segment);
+        let parent_key = key.clone();
+        if !key.is_empty() {
+            key.push('.');
+        }
+        key.push_str(segment);
+        match child_schema(schema, canonical_segment) {
+            ChildSchema::Known(child) => schema = child,
+            ChildSchema::Unknown(candidates) => {
+                return Err(ConfigOverrideIssue::UnknownKey {
+                    suggestion: closest_match(segment, candidates).map(|candidate| {
+                        if parent_key.is_empty() {
+                            candidate.to_string()
+                        } else {
+                            format!("{parent_key}.{candidate}")
+                        }
+                    }),
+                    key,
+                });
+            }
+            ChildSchema::Unchecked => return Ok(()),
+        }
+        table_path.push(canonical_segment);
+    }
+    let table_path = table_path
+        .into_iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    validate_value(
+        &key,
+        schema,
+        &normalized_with_key_aliases(value, &table_path),
+    )
+}
+
+enum ChildSchema<'a> {
+    Known(&'a JsonValue),
+    Unknown(Vec<&'a str>),
+    Unchecked,
+}
+
+fn child_schema<'a>(schema: &'a JsonValue, key: &str) -> ChildSchema<'a> {
+    let schema = resolve(schema);
+    let properties = schema.get("properties").and_then(JsonValue::as_object);
+    if let Some(child) = properties.and_then(|properties| properties.get(key)) {
+        return ChildSchema::Known(child);
+    }
+    match schema.get("additionalProperties") {
+        Some(JsonValue::Bool(false)) => ChildSchema::Unknown(
+            properties
+                .map(|properties| properties.keys().map(String::as_str).collect())
+                .unwrap_or_default(),
+        ),
+        Some(additional @ JsonValue::Object(_)) => ChildSchema::Known(additional),
+        _ => ChildSchema::Unchecked,
+    }
+}
+
+fn validate_value(
+    key: &str,
+    schema: &JsonValue,
+    value: &TomlValue,
+) -> Result<(), ConfigOverrideIssue> {
+    let schema = resolve(schema);
+    if let TomlValue::String(value) = value
+        && let Some(allowed) = string_choices(schema)
+        && !allowed.contains(value)
+    {
+        return Err(ConfigOverrideIssue::InvalidValue {
+            key: key.to_string(),
+            value: value.clone(),
+            suggestion: closest_match(value, allowed.iter().map(String::as_str).collect())
+                .map(str::to_string),
+            allowed,
+        });
+    }
+
+    if let Some(expected) = instance_types(schema)
+        && !expected
+            .iter()
+            .any(|instance_type| toml_value_has_type(value, instance_type))
+    {
+        return Err(ConfigOverrideIssue::WrongType {
+            key: key.to_string(),
+            expected,
+            actual: value.type_str().to_string(),
+        });
+    }
+
+    if let TomlValue::Table(table) = value {
+        for (child_key, child_value) in table {
+            let child_path = format!("{key}.{child_key}");
+            match child_schema(schema, child_key) {
+                ChildSchema::Known(child) => validate_value(&child_path, child, child_value)?,
+                ChildSchema::Unknown(candidates) => {
+                    return Err(ConfigOverrideIssue::UnknownKey {
+                        suggestion: closest_match(child_key, candidates)
+                            .map(|candidate| format!("{key}.{candidate}")),
+                        key: child_path,
+                    });
+                }
+                ChildSchema::Unchecked => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Follows `$ref` and single-entry `allOf` wrappers, which schemars emits for
+/// documented enum and struct fields.
+pub(crate) fn resolve(mut schema: &JsonValue) -> &JsonValue {
+    loop {
+        if let Some(reference) = schema.get("$ref").and_then(JsonValue::as_str)
+            && let Some(name) = reference.strip_prefix("#/definitions/")
+            && let Some(definition) = CONFIG_SCHEMA
+                .get("definitions")
+                .and_then(|definitions| definitions.get(name))
+        {
+            schema = definition;
+            continue;
+        }
+        match schema.get("allOf").and_then(JsonValue::as_array) {
+            Some(all_of) if all_of.len() == 1 => schema = &all_of[0],
+            _ => return schema,
+        }
+    }
+}
+
+fn variants(schema: &JsonValue) -> Option<&Vec<JsonValue>> {
+    schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(JsonValue::as_array)
+}
+
+/// Returns the accepted string values when a schema only accepts a fixed set
+/// of strings, including enums whose variants are split across `oneOf`.
+pub(crate) fn string_choices(schema: &JsonValue) -> Option<Vec<String>> {
+    let Some(variants) = variants(schema) else {
+        let choices = schema
+            .get("enum")?
+            .as_array()?
+            .iter()
+            .filter_map(|value| value.as_str().map(str::to_string))
+            .collect::<Vec<_>>();
+        return (!choices.is_empty()).then_some(choices);
+    };
+
+    let mut choices = Vec::new();
+    for variant in variants {
+        let variant = resolve(variant);
+        match string_choices(variant) {
+            Some(variant_choices) => choices.extend(variant_choices),
+            // A variant that accepts arbitrary strings makes any string valid.
+            None if instance_types(variant).is_none_or(|types| {
+                types.iter().any(|instance_type| instance_type == "string")
+            }) =>
+            {
+                return None;
+            }
+            None => {}
+        }
+    }
+    (!choices.is_empty()).then_some(choices)
+}
+
+pub(crate) fn instance_types(schema: &JsonValue) -> Option<Vec<String>> {
+    if let Some(variants) = variants(schema) {
+        let mut types = Vec::new();
+        for variant in variants {
+            for instance_type in instance_types(resolve(variant))? {
+                if !types.contains(&instance_type) {
+                    types.push(instance_type);
+                }
+            }
+        }
+        return Some(types);
+    }
+
+    match schema.get("type")? {
+        JsonValue::String(instance_type) => Some(vec![instance_type.clone()]),
+        JsonValue::Array(instance_types) => Some(
+            instance_types
+                .iter()
+                .filter_map(|instance_type| instance_type.as_str().map(str::to_string))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn toml_value_has_type(value: &TomlValue, instance_type: &str) -> bool {
+    match value {
+        TomlValue::String(_) | TomlValue::Datetime(_) => instance_type == "string",
+        TomlValue::Integer(_) => matches!(instance_type, "integer" | "number"),
+        TomlValue::Float(_) => instance_type == "number",
+        TomlValue::Boolean(_) => instance_type == "boolean",
+        TomlValue::Array(_) => instance_type == "array",
+        TomlValue::Table(_) => instance_type == "object",
+    }
+}
+
+/// Picks the candidate closest to `input` by edit distance, if any is close
+/// enough to be a plausible typo. Prefix matches such as `sandbox` for
+/// `sandbox_mode` also count.
+fn closest_match<'a>(input: &str, candidates: Vec<&'a str>) -> Option<&'a str> {
+    let max_distance = (input.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = edit_distance(input, candidate);
+            let is_prefix_match =
+                input.len() >= 3 && (candidate.starts_with(input) || input.starts_with(candidate));
+            (distance <= max_distance || is_prefix_match).then_some((distance, candidate))
+        })
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut previous = (0..=right.len()).collect::<Vec<_>>();
+    for (left_index, left_char) in left.chars().enumerate() {
+        let mut current = vec![left_index + 1; right.len() + 1];
+        for (right_index, right_char) in right.iter().enumerate() {
+            let substitution = previous[right_index] + usize::from(left_char != *right_char);
+            current[right_index + 1] = substitution
+                .min(previous[right_index + 1] + 1)
+                .min(current[right_index] + 1);
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+#[cfg(test)]
+#[path = "override_validation_tests.rs"]
+mod tests;
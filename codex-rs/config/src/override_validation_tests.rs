@@ -0,0 +1,134 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn issue(path: &str, value: TomlValue) -> Option<ConfigOverrideIssue> {
+    validate_config_override(path, &value).err()
+}
+
+#[test]
+fn unknown_keys_suggest_the_closest_field() {
+    assert_eq!(
+        issue("sandbox", TomlValue::from("workspace-write")),
+        Some(ConfigOverrideIssue::UnknownKey {
+            key: "sandbox".to_string(),
+            suggestion: Some("sandbox_mode".to_string()),
+        })
+    );
+    assert_eq!(
+        issue(
+            "sandbox_workspace_write.network_acess",
+            TomlValue::Boolean(true)
+        ),
+        Some(ConfigOverrideIssue::UnknownKey {
+            key: "sandbox_workspace_write.network_acess".to_string(),
+            suggestion: Some("sandbox_workspace_write.network_access".to_string()),
+        })
+    );
+    assert_eq!(
+        issue("mcp_servers.foo.enabld", TomlValue::Boolean(true)),
+        Some(ConfigOverrideIssue::UnknownKey {
+            key: "mcp_servers.foo.enabld".to_string(),
+            suggestion: Some("mcp_servers.foo.enabled".to_string()),
+        })
+    );
+    assert_eq!(
+        issue("reasoning_level", TomlValue::from("xhigh")),
+        Some(ConfigOverrideIssue::UnknownKey {
+            key: "reasoning_level".to_string(),
+            suggestion: None,
+        })
+    );
+}
+
+#[test]
+fn enum_values_list_allowed_choices() {
+    assert_eq!(
+        issue("sandbox_mode", TomlValue::from("workspace_write")),
+        Some(ConfigOverrideIssue::InvalidValue {
+            key: "sandbox_mode".to_string(),
+            value: "workspace_write".to_string(),
+            allowed: vec![
+                "read-only".to_string(),
+                "workspace-write".to_string(),
+                "danger-full-access".to_string(),
+            ],
+            suggestion: Some("workspace-write".to_string()),
+        })
+    );
+    assert_eq!(
+        issue("approval_policy", TomlValue::from("never_ask")),
+        Some(ConfigOverrideIssue::InvalidValue {
+            key: "approval_policy".to_string(),
+            value: "never_ask".to_string(),
+            allowed: vec![
+                "untrusted".to_string(),
+                "on-request".to_string(),
+                "never".to_string(),
+            ],
+            suggestion: Some("never".to_string()),
+        })
+    );
+}
+
+#[test]
+fn wrong_typed_values_report_expected_type() {
+    assert_eq!(
+        issue("mcp_servers.foo.enabled", TomlValue::from("yes")),
+        Some(ConfigOverrideIssue::WrongType {
+            key: "mcp_servers.foo.enabled".to_string(),
+            expected: vec!["boolean".to_string()],
+            actual: "string".to_string(),
+        })
+    );
+    assert_eq!(
+        issue("features.multi_agent", TomlValue::from("yes")),
+        Some(ConfigOverrideIssue::WrongType {
+            key: "features.multi_agent".to_string(),
+            expected: vec!["boolean".to_string()],
+            actual: "string".to_string(),
+        })
+    );
+}
+
+#[test]
+fn valid_overrides_pass() {
+    for (path, value) in [
+        ("model", TomlValue::from("o3")),
+        ("mcp_servers.foo.enabled", TomlValue::Boolean(true)),
+        ("sandbox_mode", TomlValue::from("workspace-write")),
+        ("shell_environment_policy.inherit", TomlValue::from("all")),
+        ("features.use_legacy_landlock", TomlValue::Boolean(true)),
+        ("agents.max_threads", TomlValue::Integer(3)),
+        ("model_reasoning_effort", TomlValue::from("xhigh")),
+    ] {
+        assert_eq!(issue(path, value), None, "{path}");
+    }
+}
+
+#[test]
+fn table_values_are_checked_recursively() {
+    let value: TomlValue = toml::from_str("animationz = true").expect("parse table");
+    assert_eq!(
+        issue("tui", value),
+        Some(ConfigOverrideIssue::UnknownKey {
+            key: "tui.animationz".to_string(),
+            suggestion: Some("tui.animations".to_string()),
+        })
+    );
+}
+
+#[test]
+fn issues_render_actionable_messages() {
+    assert_eq!(
+        issue("sandbox_mode", TomlValue::from("workspace_write")).map(|issue| issue.to_string()),
+        Some(
+            "invalid value `workspace_write` for `sandbox_mode`; expected one of: read-only, \
+             workspace-write, danger-full-access (did you mean `workspace-write`?)"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        issue("sandbox", TomlValue::from("workspace-write")).map(|issue| issue.to_string()),
+        Some("unknown configuration field `sandbox`; did you mean `sandbox_mode`?".to_string())
+    );
+}
@@ -12,12 +12,45 @@ pub fn build_cli_overrides_layer(cli_overrides: &[(String, TomlValue)]) -> TomlV
     root
 }
 
+/// Splits a dotted override path into its keys. A key may be quoted the way
+/// TOML quotes keys, e.g. `projects."/a/b.c".trust_level`, so it can contain
+/// dots.
+pub fn override_path_segments(path: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut segment = String::new();
+    let mut quote = None;
+    for ch in path.chars() {
+        match quote {
+            Some(open) if ch == open => quote = None,
+            Some(_) => segment.push(ch),
+            None if ch == '"' || ch == '\'' => quote = Some(ch),
+            None if ch == '.' => segments.push(std::mem::take(&mut segment)),
+            None => segment.push(ch),
+        }
+    }
+    segments.push(segment);
+    segments
+}
+
+/// Quotes `key` for use as one segment of a dotted override path when it
+/// would otherwise be split or unquoted by [`override_path_segments`].
+pub fn quote_override_path_segment(key: &str) -> String {
+    if !key.contains(['.', '"', '\'']) {
+        key.to_string()
+    } else if key.contains('"') {
+        format!("'{key}'")
+    } else {
+        format!("\"{key}\"")
+    }
+}
+
 /// Apply a single dotted-path override onto a TOML value.
 fn apply_toml_override(root: &mut TomlValue, path: &str, value: TomlValue) {
     use toml::value::Table;
 
     let mut current = root;
-    let mut segments_iter = path.split('.').peekable();
+    let segments = override_path_segments(path);
+    let mut segments_iter = segments.iter().peekable();
 
     while let Some(segment) = segments_iter.next() {
         let is_last = segments_iter.peek().is_none();
@@ -10,7 +10,7 @@ use toml_edit::Item as TomlItem;
 use toml_edit::Table as TomlTable;
 use toml_edit::value;
 
-use crate::CONFIG_TOML_FILE;
+use crate::user_config_file_for_edit;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PluginConfigEdit {
@@ -55,7 +55,7 @@ fn apply_user_plugin_config_edits_blocking(
         return Ok(());
     }
 
-    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_path = user_config_file_for_edit(codex_home)?;
     let write_paths = resolve_symlink_write_paths(&config_path)?;
     let mut doc = read_or_create_document(write_paths.read_path.as_deref())?;
     let mut mutated = false;
@@ -180,6 +180,7 @@ fn preserve_decor(existing: &TomlItem, replacement: &mut TomlItem) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::CONFIG_TOML_FILE;
     use pretty_assertions::assert_eq;
     use tempfile::TempDir;
 
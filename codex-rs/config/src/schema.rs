@@ -1,10 +1,13 @@
 use crate::config_toml::ConfigToml;
 use crate::types::RawMcpServerConfig;
 use codex_features::FEATURES;
+use codex_features::FeatureSpec;
+use codex_features::FeatureValueType;
 use codex_features::legacy_feature_keys;
 use schemars::r#gen::SchemaGenerator;
 use schemars::r#gen::SchemaSettings;
 use schemars::schema::InstanceType;
+use schemars::schema::NumberValidation;
 use schemars::schema::ObjectValidation;
 use schemars::schema::RootSchema;
 use schemars::schema::Schema;
@@ -14,6 +17,8 @@ use serde_json::Map;
 use serde_json::Value;
 use std::path::Path;
 
+pub use crate::schema_example::config_example_toml;
+
 /// Schema for the `[features]` map with known + legacy keys only.
 pub fn features_schema(schema_gen: &mut SchemaGenerator) -> Schema {
     let mut object = SchemaObject {
@@ -26,70 +31,38 @@ pub fn features_schema(schema_gen: &mut SchemaGenerator) -> Schema {
         if feature.id == codex_features::Feature::Artifact {
             continue;
         }
-        if feature.id == codex_features::Feature::CodeMode {
-            validation.properties.insert(
-                feature.key.to_string(),
-                schema_gen.subschema_for::<codex_features::FeatureToml<
-                    codex_features::CodeModeConfigToml,
-                >>(),
-            );
-            continue;
-        }
-        if feature.id == codex_features::Feature::MultiAgentV2 {
-            validation.properties.insert(
-                feature.key.to_string(),
-                schema_gen.subschema_for::<codex_features::FeatureToml<
-                    codex_features::MultiAgentV2ConfigToml,
-                >>(),
-            );
-            continue;
-        }
-        if feature.id == codex_features::Feature::TokenBudget {
-            validation.properties.insert(
-                feature.key.to_string(),
-                schema_gen.subschema_for::<codex_features::FeatureToml<
-                    codex_features::TokenBudgetConfigToml,
-                >>(),
-            );
-            continue;
-        }
-        if feature.id == codex_features::Feature::RolloutBudget {
-            validation.properties.insert(
-                feature.key.to_string(),
-                schema_gen.subschema_for::<codex_features::FeatureToml<
-                    codex_features::RolloutBudgetConfigToml,
-                >>(),
-            );
-            continue;
-        }
-        if feature.id == codex_features::Feature::CurrentTimeReminder {
-            validation.properties.insert(
-                feature.key.to_string(),
-                schema_gen.subschema_for::<codex_features::FeatureToml<
-                    codex_features::CurrentTimeReminderConfigToml,
-                >>(),
-            );
-            continue;
-        }
-        if feature.id == codex_features::Feature::AppsMcpPathOverride {
-            validation.properties.insert(
-                feature.key.to_string(),
-                removed_apps_mcp_path_override_schema(schema_gen),
-            );
-            continue;
-        }
-        if feature.id == codex_features::Feature::NetworkProxy {
-            validation.properties.insert(
-                feature.key.to_string(),
-                schema_gen.subschema_for::<codex_features::FeatureToml<
-                    codex_features::NetworkProxyConfigToml,
-                >>(),
-            );
-            continue;
-        }
-        validation
-            .properties
-            .insert(feature.key.to_string(), schema_gen.subschema_for::<bool>());
+        let schema = if feature.id == codex_features::Feature::CodeMode {
+            schema_gen
+                .subschema_for::<codex_features::FeatureToml<codex_features::CodeModeConfigToml>>()
+        } else if feature.id == codex_features::Feature::MultiAgentV2 {
+            schema_gen.subschema_for::<codex_features::FeatureToml<
+                codex_features::MultiAgentV2ConfigToml,
+            >>()
+        } else if feature.id == codex_features::Feature::TokenBudget {
+            schema_gen.subschema_for::<codex_features::FeatureToml<
+                codex_features::TokenBudgetConfigToml,
+            >>()
+        } else if feature.id == codex_features::Feature::RolloutBudget {
+            schema_gen.subschema_for::<codex_features::FeatureToml<
+                codex_features::RolloutBudgetConfigToml,
+            >>()
+        } else if feature.id == codex_features::Feature::CurrentTimeReminder {
+            schema_gen.subschema_for::<codex_features::FeatureToml<
+                codex_features::CurrentTimeReminderConfigToml,
+            >>()
+        } else if feature.id == codex_features::Feature::AppsMcpPathOverride {
+            removed_apps_mcp_path_override_schema(schema_gen)
+        } else if feature.id == codex_features::Feature::NetworkProxy {
+            schema_gen.subschema_for::<codex_features::FeatureToml<
+                codex_features::NetworkProxyConfigToml,
+            >>()
+        } else {
+            feature_value_schema(schema_gen, feature.value_type)
+        };
+        validation.properties.insert(
+            feature.key.to_string(),
+            with_feature_description(schema, feature),
+        );
     }
     for legacy_key in legacy_feature_keys() {
         validation
@@ -102,6 +75,67 @@ pub fn features_schema(schema_gen: &mut SchemaGenerator) -> Schema {
     Schema::Object(object)
 }
 
+/// Schema for a plain `[features]` entry of the given value type.
+fn feature_value_schema(schema_gen: &mut SchemaGenerator, value_type: FeatureValueType) -> Schema {
+    match value_type {
+        FeatureValueType::Bool => schema_gen.subschema_for::<bool>(),
+        FeatureValueType::Integer { min, max } => Schema::Object(SchemaObject {
+            instance_type: Some(InstanceType::Integer.into()),
+            format: Some("int64".to_string()),
+            number: Some(Box::new(NumberValidation {
+                minimum: Some(min as f64),
+                maximum: Some(max as f64),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }),
+        FeatureValueType::Enum(variants) => Schema::Object(SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            enum_values: Some(
+                variants
+                    .iter()
+                    .map(|variant| Value::from(*variant))
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+    }
+}
+
+/// Documents a `[features]` entry with its description, constraints, and
+/// stability.
+/// References are wrapped in `allOf` so the description is not a sibling of
+/// `$ref`, matching how schemars documents struct fields.
+fn with_feature_description(schema: Schema, feature: &FeatureSpec) -> Schema {
+    let mut object = match schema {
+        Schema::Object(object) if object.is_ref() => SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                all_of: Some(vec![Schema::Object(object)]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+        Schema::Object(object) => object,
+        Schema::Bool(_) => return schema,
+    };
+    let mut description = feature.description.to_string();
+    for (label, features) in [
+        ("Requires", feature.requires),
+        ("Conflicts with", feature.conflicts_with),
+    ] {
+        if !features.is_empty() {
+            let keys = features
+                .iter()
+                .map(|feature| format!("`{}`", feature.key()))
+                .collect::<Vec<_>>();
+            description.push_str(&format!("\n\n{label}: {}.", keys.join(", ")));
+        }
+    }
+    description.push_str(&format!("\n\nStability: {}.", feature.stage.label()));
+    object.metadata().description = Some(description);
+    Schema::Object(object)
+}
+
 fn removed_apps_mcp_path_override_schema(schema_gen: &mut SchemaGenerator) -> Schema {
     let mut config_validation = ObjectValidation::default();
     config_validation
@@ -139,6 +173,23 @@ pub fn mcp_servers_schema(schema_gen: &mut SchemaGenerator) -> Schema {
     };
     object.object = Some(Box::new(validation));
 
+    // `startup_timeout_sec` and `startup_timeout_ms` configure the same value,
+    // so editors should flag servers that set both.
+    if let Some(Schema::Object(server)) = schema_gen.definitions_mut().get_mut("RawMcpServerConfig")
+    {
+        let both_startup_timeouts = SchemaObject {
+            object: Some(Box::new(ObjectValidation {
+                required: ["startup_timeout_ms", "startup_timeout_sec"]
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        server.subschemas().not = Some(Box::new(Schema::Object(both_startup_timeouts)));
+    }
+
     Schema::Object(object)
 }
 
@@ -184,3 +235,9 @@ pub fn write_config_schema(out_path: &Path) -> anyhow::Result<()> {
     std::fs::write(out_path, json)?;
     Ok(())
 }
+
+/// Write the commented example `config.toml` fixture to disk.
+pub fn write_config_example(out_path: &Path) -> anyhow::Result<()> {
+    std::fs::write(out_path, config_example_toml())?;
+    Ok(())
+}
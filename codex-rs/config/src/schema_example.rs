@@ -0,0 +1,208 @@
+//! Renders a commented `config.example.toml` from the `config.toml` schema.
+//!
+//! Every key in the schema is written as a commented-out line with its
+//! documentation, the accepted values for enums, and the default value when
+//! the schema records one. Map-valued tables such as `mcp_servers` get one
+//! illustrative entry. Keys are emitted in sorted order so the output is
+//! deterministic and can be checked in as a fixture.
+
+use serde_json::Value as JsonValue;
+
+use crate::override_validation::CONFIG_SCHEMA;
+use crate::override_validation::instance_types;
+use crate::override_validation::resolve;
+use crate::override_validation::string_choices;
+
+const HEADER: &str = "\
+# Example `config.toml` for Codex, generated from `config.schema.json`.
+# Run `just write-config-schema` to regenerate it.
+#
+# Every setting is commented out and shows its default value when Codex has
+# one. Values in angle brackets are placeholders to replace before uncommenting.
+";
+
+/// Name of the illustrative entry rendered for map-valued tables.
+const EXAMPLE_ENTRY_NAME: &str = "example";
+
+enum TableKind<'a> {
+    /// A table with a fixed set of keys.
+    Table(&'a JsonValue),
+    /// A table keyed by user-chosen names whose values share one schema.
+    Map(&'a JsonValue),
+}
+
+/// Render the commented example `config.toml`.
+pub fn config_example_toml() -> String {
+    let mut out = HEADER.to_string();
+    render_table(&mut out, &[], &CONFIG_SCHEMA, &mut Vec::new());
+    out
+}
+
+fn render_table<'a>(
+    out: &mut String,
+    path: &[&str],
+    schema: &'a JsonValue,
+    stack: &mut Vec<&'a JsonValue>,
+) {
+    stack.push(schema);
+    let mut properties = table_properties(schema)
+        .map(|properties| properties.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    properties.sort_by_key(|(key, _)| *key);
+
+    let mut tables = Vec::new();
+    for (key, child) in properties {
+        if let Some(kind) = table_kind(child, stack) {
+            tables.push((key, child, kind));
+            continue;
+        }
+        out.push('\n');
+        push_description(out, child);
+        if let Some(allowed) = string_choices(resolve(child)) {
+            out.push_str(&format!("# Allowed values: {}\n", allowed.join(", ")));
+        }
+        out.push_str(&format!("# {} = {}\n", toml_key(key), example_value(child)));
+    }
+
+    for (key, child, kind) in tables {
+        out.push('\n');
+        push_description(out, child);
+        let mut child_path = path.to_vec();
+        child_path.push(key);
+        let child_schema = match kind {
+            TableKind::Table(child_schema) => child_schema,
+            TableKind::Map(entry_schema) => {
+                child_path.push(EXAMPLE_ENTRY_NAME);
+                entry_schema
+            }
+        };
+        let header = child_path
+            .iter()
+            .map(|segment| toml_key(segment))
+            .collect::<Vec<_>>()
+            .join(".");
+        out.push_str(&format!("# [{header}]\n"));
+        render_table(out, &child_path, child_schema, stack);
+    }
+    stack.pop();
+}
+
+fn table_properties(schema: &JsonValue) -> Option<&serde_json::Map<String, JsonValue>> {
+    schema
+        .get("properties")
+        .and_then(JsonValue::as_object)
+        .filter(|properties| !properties.is_empty())
+}
+
+/// Returns how a property should be rendered when it is a table. Schemas
+/// already being rendered are treated as leaves so recursive types terminate.
+fn table_kind<'a>(schema: &'a JsonValue, stack: &[&JsonValue]) -> Option<TableKind<'a>> {
+    let is_rendering = |schema: &JsonValue| stack.iter().any(|seen| std::ptr::eq(*seen, schema));
+    let schema = resolve(schema);
+    if is_rendering(schema) {
+        return None;
+    }
+    if table_properties(schema).is_some() {
+        return Some(TableKind::Table(schema));
+    }
+    match schema.get("additionalProperties") {
+        Some(entry @ JsonValue::Object(_)) => {
+            let entry = resolve(entry);
+            (table_properties(entry).is_some() && !is_rendering(entry))
+                .then_some(TableKind::Map(entry))
+        }
+        _ => None,
+    }
+}
+
+fn push_description(out: &mut String, schema: &JsonValue) {
+    let Some(description) = schema
+        .get("description")
+        .or_else(|| resolve(schema).get("description"))
+        .and_then(JsonValue::as_str)
+    else {
+        return;
+    };
+    for line in description.trim().lines() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            out.push_str("#\n");
+        } else {
+            out.push_str(&format!("# {line}\n"));
+        }
+    }
+}
+
+/// The schema default rendered as TOML, or a `<type>` placeholder when the
+/// schema has no default.
+fn example_value(schema: &JsonValue) -> String {
+    let resolved = resolve(schema);
+    if let Some(default) = schema
+        .get("default")
+        .or_else(|| resolved.get("default"))
+        .and_then(toml_literal)
+    {
+        return default;
+    }
+    match instance_types(resolved) {
+        Some(types) if !types.is_empty() => {
+            let types = types
+                .iter()
+                .map(|instance_type| match instance_type.as_str() {
+                    "object" => "table",
+                    instance_type => instance_type,
+                })
+                .collect::<Vec<_>>();
+            format!("<{}>", types.join(" | "))
+        }
+        _ => "<value>".to_string(),
+    }
+}
+
+/// Renders a JSON default as an inline TOML value. TOML has no null, so null
+/// values (and null entries inside arrays and tables) are omitted.
+fn toml_literal(value: &JsonValue) -> Option<String> {
+    match value {
+        JsonValue::Null => None,
+        JsonValue::Bool(value) => Some(value.to_string()),
+        JsonValue::Number(value) => Some(value.to_string()),
+        JsonValue::String(_) => Some(value.to_string()),
+        JsonValue::Array(items) => Some(format!(
+            "[{}]",
+            items
+                .iter()
+                .filter_map(toml_literal)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        JsonValue::Object(entries) => {
+            let mut entries = entries
+                .iter()
+                .filter_map(|(key, value)| {
+                    toml_literal(value).map(|value| (key, format!("{} = {value}", toml_key(key))))
+                })
+                .collect::<Vec<_>>();
+            if entries.is_empty() {
+                return Some("{}".to_string());
+            }
+            entries.sort_by_key(|(key, _)| *key);
+            let entries = entries
+                .into_iter()
+                .map(|(_, entry)| entry)
+                .collect::<Vec<_>>();
+            Some(format!("{{ {} }}", entries.join(", ")))
+        }
+    }
+}
+
+fn toml_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        key.to_string()
+    } else {
+        JsonValue::from(key).to_string()
+    }
+}
@@ -180,6 +180,7 @@ fn model_provider_from_proto(
             .map(model_provider_auth_from_proto)
             .transpose()?,
         aws: None,
+        azure: None,
         wire_api,
         query_params: provider.query_params.map(|map| map.values),
         http_headers: provider.http_headers.map(|map| map.values),
@@ -207,6 +208,7 @@ fn model_provider_to_proto(
         experimental_bearer_token,
         auth,
         aws: _,
+        azure: _,
         wire_api,
         query_params,
         http_headers,
@@ -537,6 +539,7 @@ mod tests {
             requires_openai_auth: false,
             supports_websockets: true,
             aws: None,
+            azure: None,
         }
     }
 
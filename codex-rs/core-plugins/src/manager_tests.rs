@@ -882,6 +882,8 @@ async fn load_plugins_loads_default_skills_and_mcp_servers() {
                     enabled: true,
                     required: false,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
@@ -974,6 +976,8 @@ enabled = true
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
@@ -1866,6 +1870,8 @@ async fn load_plugins_uses_manifest_configured_component_paths() {
                     enabled: true,
                     required: false,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
@@ -2195,6 +2201,8 @@ async fn load_plugins_ignores_manifest_component_paths_without_dot_slash() {
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
@@ -2446,6 +2454,8 @@ fn capability_index_filters_inactive_and_zero_capability_plugins() {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
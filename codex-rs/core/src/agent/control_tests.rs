@@ -234,6 +234,7 @@ async fn persisted_originator(thread: &CodexThread) -> String {
             | RolloutItem::InterAgentCommunicationMetadata { .. }
             | RolloutItem::EventMsg(_)
             | RolloutItem::Compacted(_)
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_)
             | RolloutItem::TurnContext(_) => None,
         })
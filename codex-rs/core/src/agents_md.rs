@@ -1,7 +1,8 @@
 //! AGENTS.md discovery and user instruction assembly.
 //!
 //! Project-level documentation is primarily stored in files named `AGENTS.md`.
-//! Additional fallback filenames can be configured via `project_doc_fallback_filenames`.
+//! Additional fallback filenames can be configured via `project_doc_fallback_filenames`;
+//! entries with an extension other than `.md`, `.markdown`, or `.txt` are ignored.
 //! We include the concatenation of all files found along the path from the
 //! project root to the current working directory as follows:
 //!
@@ -13,7 +14,8 @@
 //! 2.  Collect every `AGENTS.md` found from the project root down to the
 //!     current working directory (inclusive) and concatenate their contents in
 //!     that order.
-//! 3.  We do **not** walk past the project root.
+//! 3.  We do **not** walk past the project root, nor more than
+//!     `project_doc_max_depth` parents above the working directory.
 
 use crate::config::Config;
 use crate::context::UserInstructions as ContextUserInstructions;
@@ -31,6 +33,7 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_path_uri::PathUri;
 use futures::StreamExt;
 use std::io;
+use std::path::Path;
 use toml::Value as TomlValue;
 use tracing::error;
 
@@ -38,6 +41,9 @@ use tracing::error;
 pub const DEFAULT_AGENTS_MD_FILENAME: &str = "AGENTS.md";
 /// Preferred local override for AGENTS.md instructions.
 pub const LOCAL_AGENTS_MD_FILENAME: &str = "AGENTS.override.md";
+/// Extensions accepted for configured fallback filenames. Names without an
+/// extension, such as `.agentrc`, are accepted as well.
+const FALLBACK_FILENAME_EXTENSIONS: &[&str] = &["md", "markdown", "txt"];
 
 /// When both user and project AGENTS.md docs are present, they will be
 /// concatenated with the following separator.
@@ -193,6 +199,14 @@ async fn agents_md_paths(
             if cursor == root {
                 break;
             }
+            if dirs.len() > config.project_doc_max_depth {
+                tracing::warn!(
+                    cwd = %dir,
+                    max_depth = config.project_doc_max_depth,
+                    "project root is deeper than project_doc_max_depth; skipping higher directories"
+                );
+                break;
+            }
             let Some(parent) = cursor.parent() else {
                 break;
             };
@@ -240,6 +254,16 @@ fn candidate_filenames(config: &Config) -> Vec<&str> {
         if candidate.is_empty() {
             continue;
         }
+        let extension = Path::new(candidate)
+            .extension()
+            .and_then(|extension| extension.to_str());
+        if extension.is_some_and(|extension| {
+            !FALLBACK_FILENAME_EXTENSIONS
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(extension))
+        }) {
+            continue;
+        }
         if !names.contains(&candidate) {
             names.push(candidate);
         }
@@ -654,6 +654,28 @@ async fn total_byte_limit_truncates_later_project_docs() {
     assert_eq!(loaded.text(), "root\n\nabc");
 }
 
+#[tokio::test]
+async fn max_depth_stops_discovery_below_project_root() {
+    let repo = tempfile::tempdir().expect("tempdir");
+    fs::write(repo.path().join(".git"), "").unwrap();
+    fs::write(repo.path().join("AGENTS.md"), "root").unwrap();
+    let nested = repo.path().join("a").join("b");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(repo.path().join("a").join("AGENTS.md"), "middle").unwrap();
+
+    let mut config = make_config(&repo, /*limit*/ 4096, /*instructions*/ None).await;
+    config.cwd = nested.abs();
+    config.project_doc_max_depth = 1;
+
+    let discovery = agents_md_paths(&config).await.expect("discover paths");
+    assert_eq!(
+        discovery,
+        vec![PathUri::from_abs_path(
+            &repo.path().join("a").join("AGENTS.md").abs()
+        )]
+    );
+}
+
 #[tokio::test]
 async fn read_agents_md_propagates_metadata_errors() {
     let tmp = tempfile::tempdir().expect("tempdir");
@@ -1526,6 +1548,28 @@ async fn agents_md_preferred_over_fallbacks() {
     );
 }
 
+/// Fallbacks without an extension are discovered; unknown extensions are not.
+#[tokio::test]
+async fn fallback_filenames_with_unknown_extensions_are_ignored() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    fs::write(tmp.path().join("notes.json"), "{}").unwrap();
+    fs::write(tmp.path().join(".agentrc"), "agentrc instructions").unwrap();
+
+    let cfg = make_config_with_fallback(
+        &tmp,
+        /*limit*/ 4096,
+        /*instructions*/ None,
+        &["notes.json", ".agentrc"],
+    )
+    .await;
+
+    let discovery = agents_md_paths(&cfg).await.expect("discover paths");
+    assert_eq!(
+        discovery.iter().map(PathUri::basename).collect::<Vec<_>>(),
+        vec![Some(".agentrc".to_string())]
+    );
+}
+
 #[tokio::test]
 async fn agents_md_directory_is_ignored() {
     let tmp = tempfile::tempdir().expect("tempdir");
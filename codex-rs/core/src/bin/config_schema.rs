@@ -8,10 +8,18 @@ use std::path::PathBuf;
 struct Args {
     #[arg(short, long, value_name = "PATH")]
     out: Option<PathBuf>,
+
+    /// Write a commented example `config.toml` to PATH instead of the schema.
+    #[arg(long, value_name = "PATH")]
+    example: Option<PathBuf>,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
+    if let Some(example_path) = args.example {
+        codex_config::schema::write_config_example(&example_path)?;
+        return Ok(());
+    }
     let out_path = args
         .out
         .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("config.schema.json"));
@@ -0,0 +1,33 @@
+use super::Config;
+use super::ConfigTomlLoadResult;
+use codex_login::auth_profile_home;
+use codex_login::ensure_auth_profile_home;
+use codex_login::resolve_auth_profile;
+use std::path::Path;
+use std::path::PathBuf;
+
+impl Config {
+    /// Directory that auth storage reads and writes for this session's auth profile.
+    pub fn auth_home(&self) -> PathBuf {
+        auth_profile_home(self.codex_home.as_path(), &self.auth_profile)
+    }
+}
+
+/// Resolve the auth profile's storage directory from a partially loaded bootstrap config.
+///
+/// This is intended for startup paths that must read auth before managed cloud
+/// requirements can be loaded and before a full [`Config`] exists.
+pub fn resolve_bootstrap_auth_home(
+    codex_home: &Path,
+    bootstrap_config: &ConfigTomlLoadResult,
+) -> std::io::Result<PathBuf> {
+    let auth_profile = resolve_auth_profile(
+        codex_home,
+        bootstrap_config.config_toml.auth_profile.as_deref(),
+    )?;
+    ensure_auth_profile_home(codex_home, &auth_profile)
+}
+
+#[cfg(test)]
+#[path = "auth_profile_tests.rs"]
+mod tests;
@@ -0,0 +1,120 @@
+use super::*;
+use crate::config::ConfigBuilder;
+use crate::config::ConfigOverrides;
+use codex_config::CONFIG_TOML_FILE;
+use codex_config::ConfigLayerStack;
+use codex_config::ConfigRequirements;
+use codex_config::ConfigRequirementsToml;
+use codex_config::config_toml::ConfigToml;
+use codex_login::set_active_auth_profile;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use toml::Value as TomlValue;
+
+/// Creates a trusted workspace whose project config pins the `work` auth profile.
+fn workspace_pinned_to_work(codex_home: &TempDir) -> std::io::Result<TempDir> {
+    let workspace = TempDir::new()?;
+    let workspace_key = workspace.path().to_string_lossy().replace('\\', "\\\\");
+    std::fs::write(
+        codex_home.path().join(CONFIG_TOML_FILE),
+        format!(
+            r#"
+[projects."{workspace_key}"]
+trust_level = "trusted"
+"#,
+        ),
+    )?;
+    let project_config_dir = workspace.path().join(".codex");
+    std::fs::create_dir_all(&project_config_dir)?;
+    std::fs::write(
+        project_config_dir.join(CONFIG_TOML_FILE),
+        "auth_profile = \"work\"\n",
+    )?;
+    Ok(workspace)
+}
+
+#[tokio::test]
+async fn project_config_pins_its_auth_profile() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let workspace = workspace_pinned_to_work(&codex_home)?;
+    set_active_auth_profile(codex_home.path(), "personal")?;
+
+    let config = ConfigBuilder::without_managed_config_for_tests()
+        .codex_home(codex_home.path().to_path_buf())
+        .harness_overrides(ConfigOverrides {
+            cwd: Some(workspace.path().to_path_buf()),
+            ..Default::default()
+        })
+        .build()
+        .await?;
+
+    let expected_home = codex_home.path().join("auth-profiles").join("work");
+    assert_eq!(
+        (config.auth_profile.as_str(), config.auth_home()),
+        ("work", expected_home.clone())
+    );
+    assert!(expected_home.is_dir());
+    Ok(())
+}
+
+#[tokio::test]
+async fn auth_profile_override_beats_project_pin() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let workspace = workspace_pinned_to_work(&codex_home)?;
+
+    let config = ConfigBuilder::without_managed_config_for_tests()
+        .codex_home(codex_home.path().to_path_buf())
+        .cli_overrides(vec![(
+            "auth_profile".to_string(),
+            TomlValue::String("personal".to_string()),
+        )])
+        .harness_overrides(ConfigOverrides {
+            cwd: Some(workspace.path().to_path_buf()),
+            ..Default::default()
+        })
+        .build()
+        .await?;
+
+    assert_eq!(
+        (config.auth_profile.as_str(), config.auth_home()),
+        (
+            "personal",
+            codex_home.path().join("auth-profiles").join("personal")
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn resolve_bootstrap_auth_home_follows_the_active_profile() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let bootstrap_config = |auth_profile: Option<&str>| -> std::io::Result<ConfigTomlLoadResult> {
+        Ok(ConfigTomlLoadResult {
+            config_toml: ConfigToml {
+                auth_profile: auth_profile.map(str::to_string),
+                ..Default::default()
+            },
+            config_layer_stack: ConfigLayerStack::new(
+                Vec::new(),
+                ConfigRequirements::default(),
+                ConfigRequirementsToml::default(),
+            )?,
+        })
+    };
+
+    let before_switch = resolve_bootstrap_auth_home(codex_home.path(), &bootstrap_config(None)?)?;
+    set_active_auth_profile(codex_home.path(), "work")?;
+    let after_switch = resolve_bootstrap_auth_home(codex_home.path(), &bootstrap_config(None)?)?;
+    let pinned =
+        resolve_bootstrap_auth_home(codex_home.path(), &bootstrap_config(Some("default"))?)?;
+
+    assert_eq!(
+        (before_switch, after_switch, pinned),
+        (
+            codex_home.path().to_path_buf(),
+            codex_home.path().join("auth-profiles").join("work"),
+            codex_home.path().to_path_buf(),
+        )
+    );
+    Ok(())
+}
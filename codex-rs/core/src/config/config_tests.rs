@@ -42,6 +42,7 @@ use codex_config::types::ApprovalsReviewer;
 use codex_config::types::BundledSkillsConfig;
 use codex_config::types::FeedbackConfigToml;
 use codex_config::types::HistoryPersistence;
+use codex_config::types::HistorySinkToml;
 use codex_config::types::McpServerEnvVar;
 use codex_config::types::McpServerOAuthConfig;
 use codex_config::types::McpServerToolConfig;
@@ -55,6 +56,7 @@ use codex_config::types::NotificationMethod;
 use codex_config::types::Notifications;
 use codex_config::types::OtelConfigToml;
 use codex_config::types::OtelExporterKind;
+use codex_config::types::OutboundNetworkToml;
 use codex_config::types::ResumeCwdMode;
 use codex_config::types::SandboxWorkspaceWrite;
 use codex_config::types::SessionPickerViewMode;
@@ -136,6 +138,8 @@ fn stdio_mcp_with_args(command: &str, args: &[&str]) -> McpServerConfig {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -162,6 +166,8 @@ fn http_mcp(url: &str) -> McpServerConfig {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: None,
         tool_timeout_sec: None,
@@ -231,6 +237,10 @@ persistence = "save-all"
         Some(History {
             persistence: HistoryPersistence::SaveAll,
             max_bytes: None,
+            retention_days: None,
+            max_total_bytes: None,
+            encrypt: false,
+            sink: None,
         }),
         history_with_persistence_cfg.history
     );
@@ -246,10 +256,55 @@ persistence = "none"
         Some(History {
             persistence: HistoryPersistence::None,
             max_bytes: None,
+            retention_days: None,
+            max_total_bytes: None,
+            encrypt: false,
+            sink: None,
         }),
         history_no_persistence_cfg.history
     );
 
+    let history_with_retention = r#"
+[history]
+retention_days = 30
+max_total_bytes = 1073741824
+"#;
+    let history_with_retention_cfg = toml::from_str::<ConfigToml>(history_with_retention)
+        .expect("TOML deserialization should succeed");
+    assert_eq!(
+        Some(History {
+            persistence: HistoryPersistence::SaveAll,
+            max_bytes: None,
+            retention_days: Some(30),
+            max_total_bytes: Some(1_073_741_824),
+            encrypt: false,
+            sink: None,
+        }),
+        history_with_retention_cfg.history
+    );
+
+    let history_with_sink = r#"
+[history.sink]
+type = "webhook"
+url = "https://audit.example.com/codex"
+headers = { Authorization = "Bearer token" }
+batch_size = 20
+"#;
+    let history_with_sink_cfg = toml::from_str::<ConfigToml>(history_with_sink)
+        .expect("TOML deserialization should succeed");
+    assert_eq!(
+        Some(History {
+            sink: Some(HistorySinkToml::Webhook {
+                url: "https://audit.example.com/codex".to_string(),
+                headers: HashMap::from([("Authorization".to_string(), "Bearer token".to_string())]),
+                batch_size: Some(20),
+                flush_interval_ms: None,
+            }),
+            ..History::default()
+        }),
+        history_with_sink_cfg.history
+    );
+
     let memories = r#"
 [memories]
 disable_on_external_context = true
@@ -361,6 +416,8 @@ web_search = true
         Some(ToolsToml {
             web_search: None,
             experimental_request_user_input: None,
+            disabled: None,
+            timeout_sec: None,
         })
     );
 }
@@ -380,6 +437,8 @@ web_search = false
         Some(ToolsToml {
             web_search: None,
             experimental_request_user_input: None,
+            disabled: None,
+            timeout_sec: None,
         })
     );
 }
@@ -398,6 +457,8 @@ fn tools_experimental_request_user_input_defaults_to_enabled() {
         Some(ToolsToml {
             web_search: None,
             experimental_request_user_input: Some(ExperimentalRequestUserInput { enabled: true }),
+            disabled: None,
+            timeout_sec: None,
         })
     );
 }
@@ -417,10 +478,201 @@ enabled = false
         Some(ToolsToml {
             web_search: None,
             experimental_request_user_input: Some(ExperimentalRequestUserInput { enabled: false }),
+            disabled: None,
+            timeout_sec: None,
         })
     );
 }
 
+#[tokio::test]
+async fn load_config_resolves_disabled_builtin_tools() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[tools]
+disabled = ["apply_patch", "curr_time"]
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(
+        config.disabled_builtin_tools,
+        vec![BuiltinToolName::ApplyPatch, BuiltinToolName::CurrentTime]
+    );
+    Ok(())
+}
+
+#[test]
+fn tools_disabled_rejects_unknown_tool_names() {
+    let err = toml::from_str::<ConfigToml>(
+        r#"
+[tools]
+disabled = ["not_a_tool"]
+"#,
+    )
+    .expect_err("unknown built-in tool names should be rejected");
+
+    assert!(
+        err.to_string().contains("unknown variant `not_a_tool`"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn load_config_resolves_builtin_tool_timeouts() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[tools.timeout_sec]
+view_image = 30
+exec_command = 0.5
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(
+        config.builtin_tool_timeouts,
+        HashMap::from([
+            (BuiltinToolName::ViewImage, Duration::from_secs(30)),
+            (BuiltinToolName::ExecCommand, Duration::from_millis(500)),
+        ])
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_config_rejects_non_positive_builtin_tool_timeouts() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[tools.timeout_sec]
+view_image = 0
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let err = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await
+    .expect_err("zero timeout should be rejected");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        "tools.timeout_sec.view_image must be a positive number of seconds, got 0"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_config_sorts_and_dedups_rate_limit_warn_at() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[limits]
+warn_at = [90, 75, 90]
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(config.rate_limit_warn_at, vec![75, 90]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_config_rejects_invalid_history_sink_headers() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[history.sink]
+type = "webhook"
+url = "https://audit.example.com/codex"
+headers = { "X Audit Token" = "secret" }
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let err = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await
+    .expect_err("a header name with spaces should be rejected");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(
+        err.to_string()
+            .starts_with("history.sink.headers: invalid header name `X Audit Token`"),
+        "unexpected error: {err}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_config_reads_rate_limits_timeout() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str("rate_limits_timeout_ms = 1500")
+        .expect("TOML deserialization should succeed");
+    let config = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await?;
+
+    assert_eq!(
+        config.rate_limits_timeout,
+        Some(Duration::from_millis(1500))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_config_rejects_out_of_range_rate_limit_warn_at() -> std::io::Result<()> {
+    let codex_home = tempdir()?;
+    let cfg: ConfigToml = toml::from_str(
+        r#"
+[limits]
+warn_at = [0, 90]
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+    let err = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await
+    .expect_err("zero threshold should be rejected");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        "limits.warn_at entries must be between 1 and 100, got 0"
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn load_config_resolves_experimental_request_user_input_enabled() -> std::io::Result<()> {
     let codex_home = tempdir()?;
@@ -431,6 +683,8 @@ async fn load_config_resolves_experimental_request_user_input_enabled() -> std::
                 experimental_request_user_input: Some(ExperimentalRequestUserInput {
                     enabled: false,
                 }),
+                disabled: None,
+                timeout_sec: None,
             }),
             ..ConfigToml::default()
         },
@@ -3951,6 +4205,21 @@ theme = "dark"
     assert!(err.to_string().contains("theme"));
 }
 
+#[test]
+fn sandbox_workspace_write_rejects_misspelled_keys() {
+    let err = toml::from_str::<ConfigToml>(
+        r#"sandbox_mode = "workspace-write"
+
+[sandbox_workspace_write]
+writeable_roots = ["/tmp/extra"]
+"#,
+    )
+    .expect_err("misspelled sandbox_workspace_write keys should be rejected");
+
+    assert!(err.to_string().contains("unknown field"));
+    assert!(err.to_string().contains("writeable_roots"));
+}
+
 #[tokio::test]
 async fn runtime_config_resolves_session_picker_view_default_and_override() {
     let cfg = Config::load_from_base_config_with_overrides(
@@ -5566,6 +5835,37 @@ async fn feedback_enabled_defaults_to_true() -> std::io::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn load_config_rejects_invalid_network_extra_ca_certs() -> std::io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let cert_path = codex_home.abs().join("broken-ca.pem");
+    std::fs::write(&cert_path, "-----BEGIN CERTIFICATE-----\nMIIBroken")?;
+    let cfg = ConfigToml {
+        network: Some(OutboundNetworkToml {
+            extra_ca_certs: Some(vec![cert_path.clone()]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let error = Config::load_from_base_config_with_overrides(
+        cfg,
+        ConfigOverrides::default(),
+        codex_home.abs(),
+    )
+    .await
+    .expect_err("malformed extra CA file should be rejected");
+
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    let message = error.to_string();
+    assert!(message.contains("network.extra_ca_certs"), "{message}");
+    assert!(
+        message.contains(&cert_path.display().to_string()),
+        "{message}"
+    );
+    Ok(())
+}
+
 #[test]
 fn web_search_mode_defaults_to_none_if_unset() {
     let cfg = ConfigToml::default();
@@ -5905,6 +6205,8 @@ async fn replace_mcp_servers_round_trips_entries() -> anyhow::Result<()> {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(3)),
             tool_timeout_sec: Some(Duration::from_secs(5)),
@@ -6266,6 +6568,8 @@ async fn replace_mcp_servers_serializes_env_sorted() -> anyhow::Result<()> {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6343,6 +6647,8 @@ async fn replace_mcp_servers_serializes_env_vars() -> anyhow::Result<()> {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6405,6 +6711,8 @@ async fn replace_mcp_servers_serializes_sourced_env_vars() -> anyhow::Result<()>
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6458,6 +6766,8 @@ async fn replace_mcp_servers_serializes_cwd() -> anyhow::Result<()> {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6513,6 +6823,8 @@ async fn replace_mcp_servers_streamable_http_serializes_bearer_token() -> anyhow
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(2)),
             tool_timeout_sec: None,
@@ -6584,6 +6896,8 @@ async fn replace_mcp_servers_streamable_http_serializes_custom_headers() -> anyh
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(2)),
             tool_timeout_sec: None,
@@ -6667,6 +6981,8 @@ async fn replace_mcp_servers_streamable_http_removes_optional_sections() -> anyh
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(2)),
             tool_timeout_sec: None,
@@ -6703,6 +7019,8 @@ async fn replace_mcp_servers_streamable_http_removes_optional_sections() -> anyh
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6774,6 +7092,8 @@ async fn replace_mcp_servers_streamable_http_isolates_headers_between_servers()
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: Some(Duration::from_secs(2)),
                 tool_timeout_sec: None,
@@ -6801,6 +7121,8 @@ async fn replace_mcp_servers_streamable_http_isolates_headers_between_servers()
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
@@ -6890,6 +7212,8 @@ async fn replace_mcp_servers_serializes_disabled_flag() -> anyhow::Result<()> {
             enabled: false,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6941,6 +7265,8 @@ async fn replace_mcp_servers_serializes_required_flag() -> anyhow::Result<()> {
             enabled: true,
             required: true,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6992,6 +7318,8 @@ async fn replace_mcp_servers_serializes_tool_filters() -> anyhow::Result<()> {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -7047,6 +7375,8 @@ async fn replace_mcp_servers_streamable_http_serializes_oauth_resource() -> anyh
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
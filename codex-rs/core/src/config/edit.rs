@@ -1,11 +1,12 @@
 use crate::path_utils::resolve_symlink_write_paths;
 use crate::path_utils::write_atomically;
 use anyhow::Context;
-use codex_config::CONFIG_TOML_FILE;
+use codex_config::ensure_editable_config_file;
 use codex_config::types::McpServerConfig;
 use codex_config::types::ResumeCwdMode;
 use codex_config::types::SessionPickerViewMode;
 use codex_config::types::ToolSuggestDisabledTool;
+use codex_config::user_config_file;
 use codex_features::FEATURES;
 use codex_protocol::config_types::Personality;
 use codex_protocol::config_types::ServiceTier;
@@ -690,7 +691,7 @@ fn write_skill_config_selector(table: &mut TomlTable, selector: &SkillConfigSele
 
 /// Persist edits using a blocking strategy.
 pub fn apply_blocking(codex_home: &Path, edits: &[ConfigEdit]) -> anyhow::Result<()> {
-    let config_path = codex_home.join(CONFIG_TOML_FILE);
+    let config_path = user_config_file(codex_home);
     apply_blocking_to_resolved_file(&config_path, edits)
 }
 
@@ -702,6 +703,7 @@ fn apply_blocking_to_resolved_file(
         return Ok(());
     }
 
+    ensure_editable_config_file(resolved_config_file)?;
     let write_paths = resolve_symlink_write_paths(resolved_config_file)?;
     let serialized = match write_paths.read_path {
         Some(path) => match std::fs::read_to_string(&path) {
@@ -748,7 +750,7 @@ pub struct ConfigEditsBuilder {
 
 impl ConfigEditsBuilder {
     pub fn new(codex_home: &Path) -> Self {
-        Self::for_config_path(&codex_home.join(CONFIG_TOML_FILE))
+        Self::for_config_path(&user_config_file(codex_home))
     }
 
     pub fn for_config(config: &crate::config::Config) -> Self {
@@ -756,7 +758,7 @@ impl ConfigEditsBuilder {
             .config_layer_stack
             .get_user_config_file()
             .map(codex_utils_absolute_path::AbsolutePathBuf::to_path_buf)
-            .unwrap_or_else(|| config.codex_home.join(CONFIG_TOML_FILE).to_path_buf());
+            .unwrap_or_else(|| user_config_file(config.codex_home.as_path()));
         Self::for_config_path(&config_path)
     }
 
@@ -767,6 +769,12 @@ impl ConfigEditsBuilder {
         }
     }
 
+    /// Fails when the target config file is not TOML, so callers can check before making
+    /// changes that depend on the edit being persisted.
+    pub fn ensure_editable(&self) -> std::io::Result<()> {
+        ensure_editable_config_file(&self.config_path)
+    }
+
     pub fn set_model(mut self, model: Option<&str>, effort: Option<ReasoningEffort>) -> Self {
         self.edits.push(ConfigEdit::SetModel {
             model: model.map(ToOwned::to_owned),
@@ -852,6 +860,14 @@ impl ConfigEditsBuilder {
         self
     }
 
+    pub fn set_cli_auth_credentials_store(mut self, mode: &str) -> Self {
+        self.edits.push(ConfigEdit::SetPath {
+            segments: vec!["cli_auth_credentials_store".to_string()],
+            value: value(mode),
+        });
+        self
+    }
+
     pub fn set_windows_sandbox_mode(mut self, mode: &str) -> Self {
         self.edits.push(ConfigEdit::SetPath {
             segments: vec!["windows".to_string(), "sandbox".to_string()],
@@ -94,6 +94,27 @@ fn serialize_mcp_server_table(config: &McpServerConfig) -> TomlTable {
                 entry["env_http_headers"] = table_from_pairs(headers.iter());
             }
         }
+        McpServerTransportConfig::WebSocket {
+            ws_url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        } => {
+            entry["ws_url"] = value(ws_url.clone());
+            if let Some(env_var) = bearer_token_env_var {
+                entry["bearer_token_env_var"] = value(env_var.clone());
+            }
+            if let Some(headers) = http_headers
+                && !headers.is_empty()
+            {
+                entry["http_headers"] = table_from_pairs(headers.iter());
+            }
+            if let Some(headers) = env_http_headers
+                && !headers.is_empty()
+            {
+                entry["env_http_headers"] = table_from_pairs(headers.iter());
+            }
+        }
     }
 
     if matches!(&config.auth, McpServerAuth::ChatGpt) {
@@ -111,6 +132,12 @@ fn serialize_mcp_server_table(config: &McpServerConfig) -> TomlTable {
     if config.supports_parallel_tool_calls {
         entry["supports_parallel_tool_calls"] = value(true);
     }
+    if config.requires_approval {
+        entry["requires_approval"] = value(true);
+    }
+    if let Some(description) = &config.description {
+        entry["description"] = value(description.clone());
+    }
     if let Some(timeout) = config.startup_timeout_sec {
         entry["startup_timeout_sec"] = value(timeout.as_secs_f64());
     }
@@ -1,4 +1,5 @@
 use super::*;
+use codex_config::CONFIG_TOML_FILE;
 use codex_config::types::AppToolApproval;
 use codex_config::types::McpServerOAuthConfig;
 use codex_config::types::McpServerToolConfig;
@@ -34,6 +35,36 @@ model_reasoning_effort = "high"
     assert_eq!(contents, expected);
 }
 
+#[test]
+fn blocking_set_model_refuses_yaml_user_config() {
+    let tmp = tempdir().expect("tmpdir");
+    let codex_home = tmp.path();
+    let yaml_path = codex_home.join("config.yaml");
+    std::fs::write(&yaml_path, "model: gpt-5\n").expect("write yaml config");
+
+    let err = apply_blocking(
+        codex_home,
+        &[ConfigEdit::SetModel {
+            model: Some("gpt-5.4".to_string()),
+            effort: None,
+        }],
+    )
+    .expect_err("YAML config should not be edited");
+
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "{} is a YAML config file and Codex can only edit TOML config; make this change by hand or convert the file to config.toml",
+            yaml_path.display()
+        )
+    );
+    assert!(!codex_home.join(CONFIG_TOML_FILE).exists());
+    assert_eq!(
+        std::fs::read_to_string(&yaml_path).expect("read yaml config"),
+        "model: gpt-5\n"
+    );
+}
+
 #[test]
 fn set_service_tier_saves_default_as_default() {
     let tmp = tempdir().expect("tmpdir");
@@ -892,6 +923,8 @@ fn blocking_replace_mcp_servers_round_trips() {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: true,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -923,6 +956,8 @@ fn blocking_replace_mcp_servers_round_trips() {
             enabled: false,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: Some(std::time::Duration::from_secs(5)),
             tool_timeout_sec: None,
@@ -995,6 +1030,8 @@ fn blocking_replace_mcp_servers_serializes_tool_approval_overrides() {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -1056,6 +1093,8 @@ foo = { command = "cmd" }
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -1107,6 +1146,8 @@ foo = { command = "cmd" } # keep me
             enabled: false,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -1157,6 +1198,8 @@ foo = { command = "cmd", args = ["--flag"] } # keep me
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -1208,6 +1251,8 @@ foo = { command = "cmd" }
             enabled: false,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -6,6 +6,7 @@ use crate::unified_exec::MIN_EMPTY_YIELD_TIME_MS;
 use crate::windows_sandbox::WindowsSandboxLevelExt;
 use crate::windows_sandbox::resolve_windows_sandbox_mode;
 use crate::windows_sandbox::resolve_windows_sandbox_private_desktop;
+use codex_config::BuiltinToolName;
 use codex_config::CloudConfigBundleLoader;
 use codex_config::ConfigLayerSource;
 use codex_config::ConfigLayerStack;
@@ -24,6 +25,7 @@ use codex_config::ThreadConfigLoader;
 use codex_config::config_toml::ConfigLockfileToml;
 use codex_config::config_toml::ConfigToml;
 use codex_config::config_toml::DEFAULT_PROJECT_DOC_MAX_BYTES;
+use codex_config::config_toml::DEFAULT_PROJECT_DOC_MAX_DEPTH;
 use codex_config::config_toml::ProjectConfig;
 use codex_config::config_toml::RealtimeAudioConfig;
 use codex_config::config_toml::RealtimeConfig;
@@ -37,6 +39,7 @@ use codex_config::types::ApprovalsReviewer;
 use codex_config::types::AuthCredentialsStoreMode;
 use codex_config::types::AuthKeyringBackendKind;
 use codex_config::types::History;
+use codex_config::types::HistorySinkToml;
 use codex_config::types::McpServerConfig;
 use codex_config::types::McpServerDisabledReason;
 use codex_config::types::MemoriesConfig;
@@ -72,10 +75,14 @@ use codex_features::NetworkProxyConfigToml;
 use codex_features::TokenBudgetConfigToml;
 use codex_git_utils::resolve_root_git_project_for_trust;
 use codex_http_client::HttpClientFactory;
+use codex_http_client::HttpNetworkSettings;
 use codex_http_client::OutboundProxyPolicy;
 use codex_install_context::InstallContext;
 use codex_login::AuthManagerConfig;
 use codex_login::AuthRouteConfig;
+use codex_login::DEFAULT_CHATGPT_TOKEN_REFRESH_MARGIN;
+use codex_login::ensure_auth_profile_home;
+use codex_login::resolve_auth_profile;
 use codex_mcp::McpConfig;
 use codex_mcp::McpPluginAttribution;
 use codex_mcp::McpServerRegistration;
@@ -127,6 +134,7 @@ use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::config::permissions::BUILT_IN_READ_ONLY_PROFILE;
 use crate::config::permissions::BUILT_IN_WORKSPACE_PROFILE;
@@ -147,6 +155,7 @@ use toml_edit::DocumentMut;
 
 pub(crate) mod agent_roles;
 mod auth_keyring;
+mod auth_profile;
 pub mod edit;
 mod managed_features;
 mod network_proxy_spec;
@@ -157,6 +166,7 @@ mod resolved_permission_profile;
 #[cfg(test)]
 mod schema;
 pub use auth_keyring::resolve_bootstrap_auth_keyring_backend_kind;
+pub use auth_profile::resolve_bootstrap_auth_home;
 pub use codex_config::ConfigLoadOptions;
 pub use codex_config::Constrained;
 pub use codex_config::ConstraintError;
@@ -825,6 +835,15 @@ pub struct Config {
     /// auto: Use the OS-specific keyring service if available, otherwise use a file.
     pub cli_auth_credentials_store_mode: AuthCredentialsStoreMode,
 
+    /// Auth profile whose credentials this session uses: the one pinned with
+    /// `--auth-profile` or `auth_profile`, otherwise the one selected with
+    /// `codex auth use`.
+    pub auth_profile: String,
+
+    /// How long before a ChatGPT access token expires Codex refreshes it in
+    /// the background.
+    pub chatgpt_token_refresh_margin: Duration,
+
     /// Definition for MCP servers that Codex can reach out to for tool calls.
     pub mcp_servers: Constrained<HashMap<String, McpServerConfig>>,
 
@@ -855,6 +874,10 @@ pub struct Config {
     /// Maximum number of bytes to include from an AGENTS.md project doc file.
     pub project_doc_max_bytes: usize,
 
+    /// Maximum number of parent directories above `cwd` searched for
+    /// project docs.
+    pub project_doc_max_depth: usize,
+
     /// Additional filenames to try when looking for project-level docs.
     pub project_doc_fallback_filenames: Vec<String>,
 
@@ -909,7 +932,8 @@ pub struct Config {
     /// Effective config lock used for strict replay validation.
     pub config_lock_toml: Option<Arc<ConfigLockfileToml>>,
 
-    /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
+    /// Settings that govern if and what will be written to `~/.codex/history.jsonl`,
+    /// and how long session rollouts are kept.
     pub history: History,
 
     /// When true, session is not persisted on disk. Default to `false`
@@ -972,6 +996,10 @@ pub struct Config {
     /// Base URL for requests to ChatGPT (as opposed to the OpenAI API).
     pub chatgpt_base_url: String,
 
+    /// Bound on ChatGPT rate-limit status requests. `None` keeps the backend
+    /// client's default.
+    pub rate_limits_timeout: Option<Duration>,
+
     /// Whether Codex-owned clients should respect host system proxy settings.
     pub respect_system_proxy: bool,
 
@@ -1028,6 +1056,13 @@ pub struct Config {
     /// Whether to register the experimental request_user_input tool.
     pub experimental_request_user_input_enabled: bool,
 
+    /// Built-in tools removed from the tool registry by `tools.disabled`.
+    pub disabled_builtin_tools: Vec<BuiltinToolName>,
+
+    /// Per-tool call timeouts from `tools.timeout_sec`. Built-in tools without
+    /// an entry are unbounded.
+    pub builtin_tool_timeouts: HashMap<BuiltinToolName, Duration>,
+
     /// Configuration for the experimental code-mode tool surface.
     pub code_mode: CodeModeConfig,
 
@@ -1088,6 +1123,18 @@ pub struct Config {
 
     /// OTEL configuration (exporter type, endpoint, headers, etc.).
     pub otel: codex_config::types::OtelConfig,
+
+    /// Proxy and TLS trust settings from `[network]`, applied to every outbound
+    /// HTTP client once installed with `codex_http_client::set_http_network_settings`.
+    pub http_network: HttpNetworkSettings,
+
+    /// Sorted, deduplicated usage percentages from `[limits] warn_at`. Empty
+    /// disables core rate-limit warnings.
+    pub rate_limit_warn_at: Vec<u8>,
+
+    /// When true, model requests are delayed as rate-limit usage nears the
+    /// limit. Set by `[limits] auto_throttle`.
+    pub rate_limit_auto_throttle: bool,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
@@ -1229,8 +1276,8 @@ pub struct TerminalResizeReflowConfig {
 }
 
 impl AuthManagerConfig for Config {
-    fn codex_home(&self) -> PathBuf {
-        self.codex_home.to_path_buf()
+    fn auth_home(&self) -> PathBuf {
+        Config::auth_home(self)
     }
 
     fn cli_auth_credentials_store_mode(&self) -> AuthCredentialsStoreMode {
@@ -2510,6 +2557,56 @@ fn resolve_experimental_request_user_input_enabled(config_toml: &ConfigToml) ->
         .is_none_or(|config| config.enabled)
 }
 
+fn resolve_builtin_tool_timeouts(
+    config_toml: &ConfigToml,
+) -> std::io::Result<HashMap<BuiltinToolName, Duration>> {
+    let Some(timeouts) = config_toml
+        .tools
+        .as_ref()
+        .and_then(|tools| tools.timeout_sec.as_ref())
+    else {
+        return Ok(HashMap::new());
+    };
+    timeouts
+        .iter()
+        .map(|(tool, secs)| {
+            Duration::try_from_secs_f64(*secs)
+                .ok()
+                .filter(|timeout| !timeout.is_zero())
+                .map(|timeout| (*tool, timeout))
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "tools.timeout_sec.{} must be a positive number of seconds, got {secs}",
+                            tool.as_str()
+                        ),
+                    )
+                })
+        })
+        .collect()
+}
+
+fn resolve_rate_limit_warn_at(config_toml: &ConfigToml) -> std::io::Result<Vec<u8>> {
+    let mut thresholds = config_toml
+        .limits
+        .as_ref()
+        .and_then(|limits| limits.warn_at.clone())
+        .unwrap_or_default();
+    if let Some(invalid) = thresholds
+        .iter()
+        .find(|threshold| !(1..=100).contains(*threshold))
+    {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!("limits.warn_at entries must be between 1 and 100, got {invalid}"),
+        ));
+    }
+    thresholds.sort_unstable();
+    thresholds.dedup();
+    Ok(thresholds)
+}
+
 fn resolve_orchestrator_feature_enabled(
     feature: Option<&codex_config::config_toml::OrchestratorFeatureToml>,
 ) -> bool {
@@ -3164,6 +3261,9 @@ impl Config {
             },
             feature_overrides,
         );
+        configured_features
+            .validate_constraints()
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
         let features = ManagedFeatures::from_configured_with_warnings(
             configured_features,
             feature_requirements,
@@ -3507,6 +3607,18 @@ impl Config {
         let web_search_config = resolve_web_search_config(&cfg);
         let experimental_request_user_input_enabled =
             resolve_experimental_request_user_input_enabled(&cfg);
+        let disabled_builtin_tools = cfg
+            .tools
+            .as_ref()
+            .and_then(|tools| tools.disabled.clone())
+            .unwrap_or_default();
+        let builtin_tool_timeouts = resolve_builtin_tool_timeouts(&cfg)?;
+        let rate_limit_warn_at = resolve_rate_limit_warn_at(&cfg)?;
+        let rate_limit_auto_throttle = cfg
+            .limits
+            .as_ref()
+            .and_then(|limits| limits.auto_throttle)
+            .unwrap_or(false);
         let code_mode = resolve_code_mode_config(&cfg);
         let multi_agent_v2 = resolve_multi_agent_v2_config(&cfg);
         let token_budget = resolve_token_budget_config(&cfg, &features)?;
@@ -3546,6 +3658,19 @@ impl Config {
         let allow_login_shell = cfg.allow_login_shell.unwrap_or(true);
 
         let history = cfg.history.unwrap_or_default();
+        // Reject malformed webhook headers now; at send time they would fail every batch.
+        if let Some(HistorySinkToml::Webhook { headers, .. }) = history.sink.as_ref() {
+            let headers = headers
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect::<Vec<_>>();
+            codex_rollout::webhook_headers(&headers).map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("history.sink.headers: {err}"),
+                )
+            })?;
+        }
 
         if multi_agent_v2.max_concurrent_threads_per_session == 0 {
             return Err(std::io::Error::new(
@@ -3748,6 +3873,10 @@ impl Config {
         let check_for_update_on_startup = cfg.check_for_update_on_startup.unwrap_or(true);
         let model_catalog = load_model_catalog(cfg.model_catalog_json.clone())?;
 
+        let auth_profile =
+            resolve_auth_profile(codex_home.as_path(), cfg.auth_profile.as_deref())?;
+        ensure_auth_profile_home(codex_home.as_path(), &auth_profile)?;
+
         let log_dir = cfg
             .log_dir
             .as_ref()
@@ -3871,6 +4000,27 @@ impl Config {
         )
         .map_err(std::io::Error::from)?;
         let otel = otel::resolve_config(cfg.otel.unwrap_or_default(), &mut startup_warnings);
+        let network = cfg.network.unwrap_or_default();
+        let http_network = HttpNetworkSettings::new(
+            network.proxy_url,
+            network.no_proxy,
+            network
+                .extra_ca_certs
+                .unwrap_or_default()
+                .into_iter()
+                .map(AbsolutePathBuf::into_path_buf)
+                .collect(),
+            network.danger_accept_invalid_certs.unwrap_or(false),
+        )
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        let http_network = http_network.with_backend_timeouts(
+            network
+                .backend_connect_timeout_ms
+                .map(Duration::from_millis),
+            network
+                .backend_request_timeout_ms
+                .map(Duration::from_millis),
+        );
         let config = Self {
             model,
             service_tier,
@@ -3918,6 +4068,10 @@ impl Config {
                 cfg.cli_auth_credentials_store.unwrap_or_default(),
                 env!("CARGO_PKG_VERSION"),
             ),
+            auth_profile,
+            chatgpt_token_refresh_margin: cfg
+                .chatgpt_token_refresh_margin_seconds
+                .map_or(DEFAULT_CHATGPT_TOKEN_REFRESH_MARGIN, Duration::from_secs),
             mcp_servers,
             // The config.toml omits "_mode" because it's a config file. However, "_mode"
             // is important in code to differentiate the mode from the store implementation.
@@ -3929,6 +4083,9 @@ impl Config {
             mcp_oauth_callback_url: cfg.mcp_oauth_callback_url.clone(),
             model_providers,
             project_doc_max_bytes: cfg.project_doc_max_bytes.unwrap_or(AGENTS_MD_MAX_BYTES),
+            project_doc_max_depth: cfg
+                .project_doc_max_depth
+                .unwrap_or(DEFAULT_PROJECT_DOC_MAX_DEPTH),
             project_doc_fallback_filenames: cfg
                 .project_doc_fallback_filenames
                 .unwrap_or_default()
@@ -3997,6 +4154,7 @@ impl Config {
             chatgpt_base_url: cfg
                 .chatgpt_base_url
                 .unwrap_or("https://chatgpt.com/backend-api/".to_string()),
+            rate_limits_timeout: cfg.rate_limits_timeout_ms.map(Duration::from_millis),
             respect_system_proxy,
             apps_mcp_product_sku: cfg.apps_mcp_product_sku.clone(),
             realtime_audio: cfg
@@ -4030,6 +4188,8 @@ impl Config {
             web_search_mode: constrained_web_search_mode.value,
             web_search_config,
             experimental_request_user_input_enabled,
+            disabled_builtin_tools,
+            builtin_tool_timeouts,
             code_mode,
             use_experimental_unified_exec_tool,
             background_terminal_max_timeout,
@@ -4107,6 +4267,9 @@ impl Config {
                 .map(|t| t.keymap.clone())
                 .unwrap_or_default(),
             otel,
+            http_network,
+            rate_limit_warn_at,
+            rate_limit_auto_throttle,
         };
         Ok(config)
         })
@@ -1,5 +1,7 @@
 use codex_config::schema::canonicalize;
+use codex_config::schema::config_example_toml;
 use codex_config::schema::config_schema_json;
+use codex_config::schema::write_config_example;
 use codex_config::schema::write_config_schema;
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use super::canonicalize;
+use super::config_example_toml;
 use super::config_schema_json;
+use super::write_config_example;
 use super::write_config_schema;
 
 use pretty_assertions::assert_eq;
@@ -54,6 +56,33 @@ Run `just write-config-schema` to overwrite with your changes.\n\n{diff}"
     );
 }
 
+#[test]
+fn config_example_matches_fixture() {
+    let fixture_path = codex_utils_cargo_bin::find_resource!("config.example.toml")
+        .expect("resolve config example fixture path");
+    let fixture = std::fs::read_to_string(fixture_path).expect("read config example fixture");
+    #[cfg(windows)]
+    let fixture = fixture.replace("\r\n", "\n");
+    let example = config_example_toml();
+    if fixture != example {
+        let diff = TextDiff::from_lines(&fixture, &example)
+            .unified_diff()
+            .header("fixture", "generated")
+            .to_string();
+        panic!(
+            "Current example `config.toml` doesn't match the fixture. \
+Run `just write-config-schema` to overwrite with your changes.\n\n{diff}"
+        );
+    }
+
+    let tmp = TempDir::new().expect("create temp dir");
+    let tmp_path = tmp.path().join("config.example.toml");
+    write_config_example(&tmp_path).expect("write config example to temp path");
+    let tmp_contents =
+        std::fs::read_to_string(&tmp_path).expect("read back config example from temp path");
+    assert_eq!(fixture, tmp_contents);
+}
+
 #[test]
 fn config_schema_hides_unsupported_inline_mcp_bearer_token() {
     let schema_json = config_schema_json().expect("serialize config schema");
@@ -1,5 +1,8 @@
 use std::time::Duration;
 
+use codex_protocol::protocol::USER_SHELL_COMMAND_CLOSE_TAG;
+use codex_protocol::protocol::USER_SHELL_COMMAND_OPEN_TAG;
+
 use super::ContextualUserFragment;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -36,7 +39,7 @@ impl ContextualUserFragment for UserShellCommand {
     }
 
     fn type_markers() -> (&'static str, &'static str) {
-        ("<user_shell_command>", "</user_shell_command>")
+        (USER_SHELL_COMMAND_OPEN_TAG, USER_SHELL_COMMAND_CLOSE_TAG)
     }
 
     fn body(&self) -> String {
@@ -72,8 +72,11 @@ pub use utils::path_utils;
 pub(crate) mod plugins;
 #[doc(hidden)]
 pub(crate) mod prompt_debug;
+pub use prompt_debug::EnabledTool;
+pub use prompt_debug::ToolKind;
 #[doc(hidden)]
 pub use prompt_debug::build_prompt_input;
+pub use prompt_debug::list_enabled_tools;
 pub(crate) mod mentions {
     pub(crate) use crate::plugins::build_connector_slug_counts;
     pub(crate) use crate::plugins::build_skill_name_counts;
@@ -158,6 +161,7 @@ pub use rollout::ThreadItem;
 pub use rollout::ThreadSortKey;
 pub use rollout::ThreadsPage;
 pub use rollout::append_thread_name;
+pub use rollout::configure_rollout_key_store;
 pub use rollout::find_archived_thread_path_by_id_str;
 #[deprecated(note = "use find_thread_path_by_id_str")]
 pub use rollout::find_conversation_path_by_id_str;
@@ -169,6 +173,7 @@ pub use rollout::parse_cursor;
 pub use rollout::read_head_for_summary;
 pub use rollout::read_session_meta_line;
 pub use rollout::rollout_date_parts;
+pub use rollout::usage;
 mod function_tool;
 mod state;
 mod tasks;
@@ -318,6 +318,9 @@ fn canonical_mcp_server_key(name: &str, config: &McpServerConfig) -> String {
         McpServerTransportConfig::StreamableHttp { url, .. } => {
             canonical_mcp_key("streamable_http", url, name)
         }
+        McpServerTransportConfig::WebSocket { ws_url, .. } => {
+            canonical_mcp_key("websocket", ws_url, name)
+        }
     }
 }
 
@@ -361,6 +364,8 @@ fn mcp_dependency_to_server_config(
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -392,6 +397,8 @@ fn mcp_dependency_to_server_config(
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
@@ -256,6 +256,7 @@ pub(crate) async fn handle_mcp_tool_call(
         &hook_tool_name,
         metadata.as_ref(),
         approval_mode,
+        manager.server_requires_approval(&server),
     )
     .await
     {
@@ -1219,7 +1220,15 @@ async fn maybe_request_mcp_tool_approval(
     hook_tool_name: &HookToolName,
     metadata: Option<&McpToolApprovalMetadata>,
     approval_mode: AppToolApproval,
+    server_requires_approval: bool,
 ) -> Option<McpToolApprovalDecision> {
+    // Servers configured with `requires_approval` prompt for every call and
+    // never reuse remembered approvals.
+    let approval_mode = if server_requires_approval {
+        AppToolApproval::Prompt
+    } else {
+        approval_mode
+    };
     let turn_context = &step_context.turn;
     let manager = step_context.mcp.manager();
     let approvals_reviewer = mcp_approvals_reviewer(turn_context, &invocation.server, metadata);
@@ -1228,6 +1237,7 @@ async fn maybe_request_mcp_tool_approval(
         &turn_context.permission_profile(),
         McpPermissionPromptAutoApproveContext {
             tool_approval_mode: Some(approval_mode),
+            server_requires_approval,
         },
     ) {
         return None;
@@ -2524,6 +2524,7 @@ async fn approve_mode_skips_when_annotations_do_not_require_approval() {
         &HookToolName::new("mcp__test__tool"),
         Some(&metadata),
         AppToolApproval::Approve,
+        /*server_requires_approval*/ false,
     )
     .await;
 
@@ -2600,6 +2601,7 @@ async fn guardian_mode_skips_auto_when_annotations_do_not_require_approval() {
         &HookToolName::new("mcp__test__tool"),
         Some(&metadata),
         AppToolApproval::Auto,
+        /*server_requires_approval*/ false,
     )
     .await;
 
@@ -2659,6 +2661,7 @@ async fn permission_request_hook_allows_mcp_tool_call() {
         &HookToolName::new("mcp__memory__create_entities"),
         Some(&metadata),
         AppToolApproval::Auto,
+        /*server_requires_approval*/ false,
     )
     .await;
 
@@ -2721,6 +2724,7 @@ async fn permission_request_hook_uses_hook_tool_name_without_metadata() {
         &HookToolName::new("mcp__memory__create_entities"),
         /*metadata*/ None,
         AppToolApproval::Auto,
+        /*server_requires_approval*/ false,
     )
     .await;
 
@@ -2803,6 +2807,7 @@ async fn permission_request_hook_runs_after_remembered_mcp_approval() {
         &HookToolName::new("mcp__memory__create_entities"),
         Some(&metadata),
         AppToolApproval::Auto,
+        /*server_requires_approval*/ false,
     )
     .await;
 
@@ -2886,6 +2891,7 @@ async fn guardian_mode_mcp_denial_returns_rationale_message() {
         &HookToolName::new("mcp__test__tool"),
         Some(&metadata),
         AppToolApproval::Auto,
+        /*server_requires_approval*/ false,
     )
     .await;
 
@@ -2946,6 +2952,7 @@ async fn prompt_mode_waits_for_approval_when_annotations_do_not_require_approval
                 &HookToolName::new("mcp__test__tool"),
                 Some(&metadata),
                 AppToolApproval::Prompt,
+                /*server_requires_approval*/ false,
             )
             .await
         })
@@ -2960,6 +2967,98 @@ async fn prompt_mode_waits_for_approval_when_annotations_do_not_require_approval
     approval_task.abort();
 }
 
+#[tokio::test]
+async fn server_requiring_approval_denies_call_via_permission_request_hook() {
+    let (mut session, mut turn_context) = make_session_and_context().await;
+    turn_context
+        .approval_policy
+        .set(AskForApproval::Never)
+        .expect("test setup should allow updating approval policy");
+    turn_context.permission_profile = PermissionProfile::Disabled;
+    install_mcp_permission_request_hook(
+        &mut session,
+        &turn_context,
+        "mcp__guarded__.*",
+        &serde_json::json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PermissionRequest",
+                "decision": {
+                    "behavior": "deny",
+                    "message": "guarded server calls are not allowed"
+                }
+            }
+        }),
+    );
+    let session = Arc::new(session);
+    let turn_context = Arc::new(turn_context);
+    let invocation = McpInvocation {
+        server: "guarded".to_string(),
+        tool: "read_docs".to_string(),
+        arguments: None,
+    };
+
+    let decision = maybe_request_mcp_tool_approval(
+        &session,
+        &StepContext::for_test(Arc::clone(&turn_context)),
+        "call-guarded-deny",
+        &invocation,
+        &HookToolName::new("mcp__guarded__read_docs"),
+        /*metadata*/ None,
+        AppToolApproval::Approve,
+        /*server_requires_approval*/ true,
+    )
+    .await;
+
+    assert_eq!(
+        decision,
+        Some(McpToolApprovalDecision::Decline {
+            message: Some("guarded server calls are not allowed".to_string()),
+        })
+    );
+}
+
+#[tokio::test]
+async fn server_requiring_approval_accepts_call_via_permission_request_hook() {
+    let (mut session, mut turn_context) = make_session_and_context().await;
+    turn_context
+        .approval_policy
+        .set(AskForApproval::Never)
+        .expect("test setup should allow updating approval policy");
+    turn_context.permission_profile = PermissionProfile::Disabled;
+    install_mcp_permission_request_hook(
+        &mut session,
+        &turn_context,
+        "mcp__guarded__.*",
+        &serde_json::json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PermissionRequest",
+                "decision": { "behavior": "allow" }
+            }
+        }),
+    );
+    let session = Arc::new(session);
+    let turn_context = Arc::new(turn_context);
+    let invocation = McpInvocation {
+        server: "guarded".to_string(),
+        tool: "read_docs".to_string(),
+        arguments: None,
+    };
+
+    let decision = maybe_request_mcp_tool_approval(
+        &session,
+        &StepContext::for_test(Arc::clone(&turn_context)),
+        "call-guarded-allow",
+        &invocation,
+        &HookToolName::new("mcp__guarded__read_docs"),
+        /*metadata*/ None,
+        AppToolApproval::Approve,
+        /*server_requires_approval*/ true,
+    )
+    .await;
+
+    assert_eq!(decision, Some(McpToolApprovalDecision::Accept));
+}
+
 #[tokio::test]
 async fn full_access_mode_skips_mcp_tool_approval_for_all_approval_modes() {
     let (session, mut turn_context) = make_session_and_context().await;
@@ -3004,6 +3103,7 @@ async fn full_access_mode_skips_mcp_tool_approval_for_all_approval_modes() {
             &HookToolName::new("mcp__test__tool"),
             Some(&metadata),
             approval_mode,
+            /*server_requires_approval*/ false,
         )
         .await;
 
@@ -3093,6 +3193,7 @@ async fn approve_mode_skips_guardian_in_every_permission_mode() {
             &HookToolName::new("mcp__test__tool"),
             Some(&metadata),
             AppToolApproval::Approve,
+            /*server_requires_approval*/ false,
         )
         .await;
 
@@ -9,6 +9,9 @@ use codex_protocol::error::Result as CodexResult;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::user_input::UserInput;
+use codex_tools::ResponsesApiNamespaceTool;
+use codex_tools::ToolSpec;
+use serde::Serialize;
 use tokio_util::sync::CancellationToken;
 
 use crate::config::Config;
@@ -17,6 +20,7 @@ use crate::session::session::Session;
 use crate::session::turn::build_prompt;
 use crate::session::turn::built_tools;
 use crate::state_db_bridge::StateDbHandle;
+use crate::thread_manager::NewThread;
 use crate::thread_manager::ThreadManager;
 use crate::thread_manager::thread_store_from_config;
 use codex_extension_api::empty_extension_registry;
@@ -24,11 +28,104 @@ use codex_extension_api::empty_extension_registry;
 /// Build the model-visible `input` list for a single debug turn.
 #[doc(hidden)]
 pub async fn build_prompt_input(
-    mut config: Config,
+    config: Config,
     input: Vec<UserInput>,
     state_db: Option<StateDbHandle>,
     user_instructions_provider: Arc<dyn UserInstructionsProvider>,
 ) -> CodexResult<Vec<ResponseItem>> {
+    let (thread_manager, thread) =
+        start_debug_thread(config, state_db, user_instructions_provider).await?;
+
+    let output = build_prompt_input_from_session(&thread.thread.session, input).await;
+    let shutdown = thread.thread.shutdown_and_wait().await;
+    let _removed = thread_manager.remove_thread(&thread.thread_id).await;
+
+    shutdown?;
+    output
+}
+
+/// Resolve the tools the model would be offered on the first turn: built-in
+/// tools left enabled by config plus tools discovered from MCP servers. Starts
+/// an ephemeral thread to connect MCP servers but makes no model calls.
+pub async fn list_enabled_tools(
+    config: Config,
+    state_db: Option<StateDbHandle>,
+    user_instructions_provider: Arc<dyn UserInstructionsProvider>,
+) -> CodexResult<Vec<EnabledTool>> {
+    let (thread_manager, thread) =
+        start_debug_thread(config, state_db, user_instructions_provider).await?;
+
+    let output = model_visible_specs_from_session(&thread.thread.session).await;
+    let shutdown = thread.thread.shutdown_and_wait().await;
+    let _removed = thread_manager.remove_thread(&thread.thread_id).await;
+
+    shutdown?;
+    Ok(enabled_tools_from_specs(&output?))
+}
+
+/// Wire kind of a tool offered to the model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolKind {
+    Function,
+    Freeform,
+    ToolSearch,
+    WebSearch,
+}
+
+impl ToolKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Freeform => "freeform",
+            Self::ToolSearch => "tool_search",
+            Self::WebSearch => "web_search",
+        }
+    }
+}
+
+/// A tool offered to the model. Tools grouped under a namespace, such as the
+/// tools of one MCP server, carry that namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EnabledTool {
+    pub namespace: Option<String>,
+    pub name: String,
+    pub kind: ToolKind,
+}
+
+pub(crate) fn enabled_tools_from_specs(specs: &[ToolSpec]) -> Vec<EnabledTool> {
+    let mut tools = Vec::new();
+    for spec in specs {
+        let kind = match spec {
+            ToolSpec::Namespace(namespace) => {
+                tools.extend(namespace.tools.iter().map(|tool| match tool {
+                    ResponsesApiNamespaceTool::Function(tool) => EnabledTool {
+                        namespace: Some(namespace.name.clone()),
+                        name: tool.name.clone(),
+                        kind: ToolKind::Function,
+                    },
+                }));
+                continue;
+            }
+            ToolSpec::Function(_) => ToolKind::Function,
+            ToolSpec::Freeform(_) => ToolKind::Freeform,
+            ToolSpec::ToolSearch { .. } => ToolKind::ToolSearch,
+            ToolSpec::WebSearch { .. } => ToolKind::WebSearch,
+        };
+        tools.push(EnabledTool {
+            namespace: None,
+            name: spec.name().to_string(),
+            kind,
+        });
+    }
+    tools
+}
+
+async fn start_debug_thread(
+    mut config: Config,
+    state_db: Option<StateDbHandle>,
+    user_instructions_provider: Arc<dyn UserInstructionsProvider>,
+) -> CodexResult<(ThreadManager, NewThread)> {
     config.ephemeral = true;
 
     let auth_manager =
@@ -65,13 +162,7 @@ pub async fn build_prompt_input(
         /*external_time_provider*/ None,
     );
     let thread = thread_manager.start_thread(config).await?;
-
-    let output = build_prompt_input_from_session(&thread.thread.session, input).await;
-    let shutdown = thread.thread.shutdown_and_wait().await;
-    let _removed = thread_manager.remove_thread(&thread.thread_id).await;
-
-    shutdown?;
-    output
+    Ok((thread_manager, thread))
 }
 
 pub(crate) async fn build_prompt_input_from_session(
@@ -105,3 +196,14 @@ pub(crate) async fn build_prompt_input_from_session(
 
     Ok(prompt.input)
 }
+
+async fn model_visible_specs_from_session(sess: &Arc<Session>) -> CodexResult<Vec<ToolSpec>> {
+    let turn_context = sess.new_default_turn().await;
+    let step_context = sess.capture_step_context(Arc::clone(&turn_context)).await;
+    let router = built_tools(sess, step_context.as_ref(), &CancellationToken::new()).await?;
+    Ok(router.model_visible_specs())
+}
+
+#[cfg(test)]
+#[path = "prompt_debug_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+use super::*;
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiNamespace;
+use codex_tools::ResponsesApiTool;
+use pretty_assertions::assert_eq;
+use std::collections::BTreeMap;
+
+fn function_tool(name: &str) -> ResponsesApiTool {
+    ResponsesApiTool {
+        name: name.to_string(),
+        description: String::new(),
+        strict: false,
+        defer_loading: None,
+        parameters: JsonSchema::object(
+            BTreeMap::new(),
+            /*required*/ None,
+            /*additional_properties*/ None,
+        ),
+        output_schema: None,
+    }
+}
+
+#[test]
+fn enabled_tools_flatten_namespaces_and_keep_order() {
+    let specs = vec![
+        ToolSpec::Function(function_tool("exec_command")),
+        ToolSpec::Namespace(ResponsesApiNamespace {
+            name: "mcp__docs".to_string(),
+            description: String::new(),
+            tools: vec![
+                ResponsesApiNamespaceTool::Function(function_tool("search")),
+                ResponsesApiNamespaceTool::Function(function_tool("fetch")),
+            ],
+        }),
+        ToolSpec::WebSearch {
+            external_web_access: Some(true),
+            indexed_web_access: None,
+            filters: None,
+            user_location: None,
+            search_context_size: None,
+            search_content_types: None,
+        },
+    ];
+
+    assert_eq!(
+        enabled_tools_from_specs(&specs),
+        vec![
+            EnabledTool {
+                namespace: None,
+                name: "exec_command".to_string(),
+                kind: ToolKind::Function,
+            },
+            EnabledTool {
+                namespace: Some("mcp__docs".to_string()),
+                name: "search".to_string(),
+                kind: ToolKind::Function,
+            },
+            EnabledTool {
+                namespace: Some("mcp__docs".to_string()),
+                name: "fetch".to_string(),
+                kind: ToolKind::Function,
+            },
+            EnabledTool {
+                namespace: None,
+                name: "web_search".to_string(),
+                kind: ToolKind::WebSearch,
+            },
+        ]
+    );
+}
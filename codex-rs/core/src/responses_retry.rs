@@ -6,9 +6,12 @@ use crate::client::ModelClientSession;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
 use crate::util::backoff;
+use codex_async_utils::OrCancelExt;
 use codex_protocol::error::CodexErr;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::WarningEvent;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
 use tracing::warn;
 
 #[derive(Debug, Clone, Copy)]
@@ -78,6 +81,37 @@ pub(crate) async fn handle_retryable_response_stream_error(
     Err(err)
 }
 
+/// Waits before a model request when `[limits] auto_throttle` asks for it,
+/// telling the user why.
+pub(crate) async fn pace_rate_limited_request(
+    sess: &Session,
+    turn_context: &TurnContext,
+    cancellation_token: &CancellationToken,
+) -> Result<(), CodexErr> {
+    let Some(throttle) = sess.rate_limit_throttle_delay(turn_context).await else {
+        return Ok(());
+    };
+    let seconds = throttle.delay.as_secs_f64().ceil();
+    let message = match throttle.used_percent {
+        Some(used_percent) => format!(
+            "Rate-limit usage is at {used_percent:.0}%; waiting {seconds}s before the next request."
+        ),
+        None => format!("Rate limited; waiting {seconds}s before the next request."),
+    };
+    info!(
+        turn_id = %turn_context.sub_id,
+        delay = ?throttle.delay,
+        used_percent = ?throttle.used_percent,
+        "throttling model request"
+    );
+    sess.send_event(turn_context, EventMsg::Warning(WarningEvent { message }))
+        .await;
+    tokio::time::sleep(throttle.delay)
+        .or_cancel(cancellation_token)
+        .await?;
+    Ok(())
+}
+
 fn log_retry(
     request: ResponsesStreamRequest,
     turn_context: &TurnContext,
@@ -22,6 +22,23 @@ pub use codex_rollout::parse_cursor;
 pub use codex_rollout::read_head_for_summary;
 pub use codex_rollout::read_session_meta_line;
 pub use codex_rollout::rollout_date_parts;
+pub use codex_rollout::usage;
+
+use codex_config::types::AuthCredentialsStoreMode;
+use codex_rollout::RolloutKeyStorage;
+
+/// Keeps the history encryption key where `cli_auth_credentials_store` keeps
+/// credentials.
+pub fn configure_rollout_key_store(config: &Config) {
+    let storage = match config.cli_auth_credentials_store_mode {
+        AuthCredentialsStoreMode::File => RolloutKeyStorage::File,
+        AuthCredentialsStoreMode::Keyring => RolloutKeyStorage::Keyring,
+        AuthCredentialsStoreMode::Auto => RolloutKeyStorage::Auto,
+        // Encrypted rollouts outlive the process, so the key has to as well.
+        AuthCredentialsStoreMode::Ephemeral => RolloutKeyStorage::Auto,
+    };
+    codex_rollout::configure_rollout_key_store(config.codex_home.as_path(), storage);
+}
 
 impl codex_rollout::RolloutConfigView for Config {
     fn codex_home(&self) -> &std::path::Path {
@@ -43,6 +60,10 @@ impl codex_rollout::RolloutConfigView for Config {
     fn generate_memories(&self) -> bool {
         self.memories.generate_memories
     }
+
+    fn encrypt_history(&self) -> bool {
+        self.history.encrypt
+    }
 }
 
 pub(crate) mod list {
@@ -7,6 +7,8 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
+use std::time::Duration;
+use std::time::Instant;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
@@ -164,6 +166,7 @@ use rmcp::model::RequestId;
 use serde_json::Value;
 use tokio::sync::Mutex;
 use tokio::sync::RwLock;
+use tokio::sync::broadcast;
 use tokio::sync::oneshot;
 use tokio::sync::watch;
 use tokio::task::JoinHandle;
@@ -317,6 +320,7 @@ use crate::state::AutoCompactWindowSnapshot;
 use crate::state::PendingRequestPermissions;
 use crate::state::SessionServices;
 use crate::state::SessionState;
+use crate::state::ThrottleDelay;
 #[cfg(test)]
 use crate::stream_events_utils::HandleOutputCtx;
 #[cfg(test)]
@@ -689,6 +693,10 @@ impl Session {
 
         // Generate a unique ID for the lifetime of this session.
         let session_source_clone = session_configuration.session_source.clone();
+        let forwards_token_refresh_warnings = !matches!(
+            session_source_clone,
+            SessionSource::Internal(_) | SessionSource::SubAgent(_)
+        );
         let (agent_status_tx, agent_status_rx) = watch::channel(AgentStatus::PendingInit);
 
         let session = Box::pin(Session::new(
@@ -742,11 +750,15 @@ impl Session {
                 .instrument(info_span!("session_loop", thread_id = %thread_id))
                 .await;
         });
+        let session_loop_termination = session_loop_termination_from_handle(session_loop_handle);
+        if forwards_token_refresh_warnings {
+            spawn_token_refresh_warning_forwarder(&session, session_loop_termination.clone());
+        }
         let io = SessionIo {
             tx_sub,
             rx_event,
             agent_status: agent_status_rx,
-            session_loop_termination: session_loop_termination_from_handle(session_loop_handle),
+            session_loop_termination,
         };
 
         Ok((session, io))
@@ -869,6 +881,38 @@ fn unsupported_service_tier_warning(
     ))
 }
 
+/// Surfaces background token refresh failures in this thread. Internal and subagent threads
+/// skip this so the warning shows up once, in the thread the user is looking at. The forwarder
+/// stops with the session loop.
+fn spawn_token_refresh_warning_forwarder(
+    session: &Arc<Session>,
+    mut session_loop_termination: SessionLoopTermination,
+) {
+    let mut warnings = session.services.auth_manager.token_refresh_warnings();
+    let session = Arc::downgrade(session);
+    tokio::spawn(async move {
+        loop {
+            let message = tokio::select! {
+                () = &mut session_loop_termination => return,
+                received = warnings.recv() => match received {
+                    Ok(message) => message,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return,
+                },
+            };
+            let Some(session) = session.upgrade() else {
+                return;
+            };
+            session
+                .send_event_raw_without_materializing_rollout(Event {
+                    id: INITIAL_SUBMIT_ID.to_owned(),
+                    msg: EventMsg::Warning(WarningEvent { message }),
+                })
+                .await;
+        }
+    });
+}
+
 fn session_permission_profile_state_from_config(
     config: &Config,
 ) -> CodexResult<PermissionProfileState> {
@@ -3741,6 +3785,37 @@ impl Session {
         }
     }
 
+    /// Returns how long `[limits] auto_throttle` should hold the next model
+    /// request, or `None` when throttling is off or not needed right now.
+    pub(crate) async fn rate_limit_throttle_delay(
+        &self,
+        turn_context: &TurnContext,
+    ) -> Option<ThrottleDelay> {
+        if !turn_context.config.rate_limit_auto_throttle {
+            return None;
+        }
+        let mut state = self.state.lock().await;
+        state.rate_limit_throttle_delay(&turn_context.config.model_provider_id, Instant::now())
+    }
+
+    /// Makes `[limits] auto_throttle` hold the next model request until a
+    /// server-requested `Retry-After` has passed.
+    pub(crate) async fn record_rate_limit_retry_after(
+        &self,
+        turn_context: &TurnContext,
+        retry_after: Duration,
+    ) {
+        if !turn_context.config.rate_limit_auto_throttle {
+            return;
+        }
+        let mut state = self.state.lock().await;
+        state.record_rate_limit_retry_after(
+            &turn_context.config.model_provider_id,
+            retry_after,
+            Instant::now(),
+        );
+    }
+
     pub(crate) async fn mcp_dependency_prompted(&self) -> HashSet<String> {
         let state = self.state.lock().await;
         state.mcp_dependency_prompted()
@@ -3760,12 +3835,18 @@ impl Session {
     }
 
     pub(crate) async fn send_token_count_event(&self, turn_context: &TurnContext) {
-        let (info, rate_limits) = {
-            let state = self.state.lock().await;
-            state.token_info_and_rate_limits()
+        let (info, rate_limits, rate_limit_warnings) = {
+            let mut state = self.state.lock().await;
+            let (info, rate_limits) = state.token_info_and_rate_limits();
+            let warnings = state.take_rate_limit_warnings(&turn_context.config.rate_limit_warn_at);
+            (info, rate_limits, warnings)
         };
         let event = EventMsg::TokenCount(TokenCountEvent { info, rate_limits });
         self.send_event(turn_context, event).await;
+        for warning in rate_limit_warnings {
+            self.send_event(turn_context, EventMsg::RateLimitWarning(warning))
+                .await;
+        }
     }
 
     pub(crate) async fn set_total_tokens_full(&self, turn_context: &TurnContext) {
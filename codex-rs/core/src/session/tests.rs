@@ -2845,6 +2845,7 @@ async fn start_new_context_window_assigns_and_persists_item_ids() {
         | RolloutItem::InterAgentCommunication(_)
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::TurnContext(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_)
         | RolloutItem::EventMsg(_) => None,
     });
@@ -2904,6 +2905,7 @@ async fn record_initial_history_assigns_and_persists_id_for_forked_response_item
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::Compacted(_)
         | RolloutItem::TurnContext(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_)
         | RolloutItem::EventMsg(_) => None,
     });
@@ -3762,12 +3764,14 @@ async fn set_rate_limits_retains_previous_credits() {
     let initial = RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 10.0,
             window_minutes: Some(15),
             resets_at: Some(1_700),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: Some(CreditsSnapshot {
             has_credits: true,
             unlimited: false,
@@ -3783,6 +3787,7 @@ async fn set_rate_limits_retains_previous_credits() {
     let update = RateLimitSnapshot {
         limit_id: Some("codex_other".to_string()),
         limit_name: Some("codex_other".to_string()),
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 40.0,
             window_minutes: Some(30),
@@ -3793,6 +3798,7 @@ async fn set_rate_limits_retains_previous_credits() {
             window_minutes: Some(60),
             resets_at: Some(1_900),
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -3806,8 +3812,10 @@ async fn set_rate_limits_retains_previous_credits() {
         Some(RateLimitSnapshot {
             limit_id: Some("codex_other".to_string()),
             limit_name: Some("codex_other".to_string()),
+            scope: None,
             primary: update.primary.clone(),
             secondary: update.secondary,
+            extra: Vec::new(),
             credits: initial.credits,
             individual_limit: initial.individual_limit,
             spend_control_reached: initial.spend_control_reached,
@@ -3871,6 +3879,7 @@ async fn set_rate_limits_updates_plan_type_when_present() {
     let initial = RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 15.0,
             window_minutes: Some(20),
@@ -3881,6 +3890,7 @@ async fn set_rate_limits_updates_plan_type_when_present() {
             window_minutes: Some(45),
             resets_at: Some(1_650),
         }),
+        extra: Vec::new(),
         credits: Some(CreditsSnapshot {
             has_credits: true,
             unlimited: false,
@@ -3896,12 +3906,14 @@ async fn set_rate_limits_updates_plan_type_when_present() {
     let update = RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 35.0,
             window_minutes: Some(25),
             resets_at: Some(1_700),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -3915,8 +3927,10 @@ async fn set_rate_limits_updates_plan_type_when_present() {
         Some(RateLimitSnapshot {
             limit_id: Some("codex".to_string()),
             limit_name: None,
+            scope: None,
             primary: update.primary,
             secondary: update.secondary,
+            extra: Vec::new(),
             credits: initial.credits,
             individual_limit: initial.individual_limit,
             spend_control_reached: initial.spend_control_reached,
@@ -7935,6 +7949,8 @@ async fn built_tools_uses_the_step_mcp_runtime() -> anyhow::Result<()> {
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: None,
             tool_timeout_sec: None,
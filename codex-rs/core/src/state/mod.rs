@@ -1,5 +1,7 @@
 mod additional_context;
 mod auto_compact_window;
+mod rate_limit_throttle;
+mod rate_limit_warnings;
 mod service;
 mod session;
 mod turn;
@@ -7,6 +9,7 @@ mod turn;
 pub(crate) use additional_context::AdditionalContextStore;
 pub(crate) use auto_compact_window::AutoCompactWindowIds;
 pub(crate) use auto_compact_window::AutoCompactWindowSnapshot;
+pub(crate) use rate_limit_throttle::ThrottleDelay;
 pub(crate) use service::SessionServices;
 pub(crate) use session::SessionState;
 pub(crate) use turn::ActiveTurn;
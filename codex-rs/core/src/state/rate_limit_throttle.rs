@@ -0,0 +1,242 @@
+//! Client-side pacing of model requests for `[limits] auto_throttle`.
+//!
+//! Callers pass the current [`Instant`] in, so delays can be computed against
+//! synthetic snapshots and times without sleeping.
+
+use std::collections::HashMap;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_protocol::protocol::RateLimitSnapshot;
+
+/// Usage of any rate-limit window at or above which requests are delayed.
+const THROTTLE_AT_PERCENT: f64 = 90.0;
+/// Delay before the first throttled request; it doubles for each further
+/// request while usage stays above the threshold.
+const BASE_DELAY: Duration = Duration::from_secs(2);
+const MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// Why and for how long the next request should wait.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct ThrottleDelay {
+    pub(crate) delay: Duration,
+    /// Highest window usage when the delay comes from rate-limit usage, or
+    /// `None` when only a server `Retry-After` is being honored.
+    pub(crate) used_percent: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitThrottle {
+    /// Throttle state keyed by model provider id.
+    providers: HashMap<String, ProviderThrottle>,
+}
+
+#[derive(Debug, Default)]
+struct ProviderThrottle {
+    /// Requests delayed in a row because usage stayed above the threshold.
+    throttled_requests: u32,
+    /// Earliest start for the next request, from a server `Retry-After`.
+    not_before: Option<Instant>,
+}
+
+impl RateLimitThrottle {
+    /// Returns how long to wait before the next request to `provider`, or
+    /// `None` to send it right away.
+    ///
+    /// A request made while `snapshot` is over the threshold counts toward
+    /// the growing delay; a snapshot with headroom resets it.
+    pub(crate) fn next_delay(
+        &mut self,
+        provider: &str,
+        snapshot: Option<&RateLimitSnapshot>,
+        now: Instant,
+    ) -> Option<ThrottleDelay> {
+        let throttle = self.providers.entry(provider.to_string()).or_default();
+        let used_percent = snapshot
+            .and_then(max_used_percent)
+            .filter(|used_percent| *used_percent >= THROTTLE_AT_PERCENT);
+        let usage_delay = match used_percent {
+            Some(_) => {
+                throttle.throttled_requests = throttle.throttled_requests.saturating_add(1);
+                usage_delay(throttle.throttled_requests)
+            }
+            None => {
+                throttle.throttled_requests = 0;
+                Duration::ZERO
+            }
+        };
+        let retry_after_delay = throttle
+            .not_before
+            .take()
+            .map(|not_before| not_before.saturating_duration_since(now))
+            .unwrap_or_default();
+
+        let delay = usage_delay.max(retry_after_delay);
+        if delay.is_zero() {
+            return None;
+        }
+        Some(ThrottleDelay {
+            delay,
+            used_percent: used_percent.filter(|_| usage_delay >= retry_after_delay),
+        })
+    }
+
+    /// Holds the next request to `provider` until `retry_after` has passed.
+    pub(crate) fn record_retry_after(
+        &mut self,
+        provider: &str,
+        retry_after: Duration,
+        now: Instant,
+    ) {
+        let throttle = self.providers.entry(provider.to_string()).or_default();
+        let not_before = now + retry_after;
+        throttle.not_before = Some(
+            throttle
+                .not_before
+                .map_or(not_before, |current| current.max(not_before)),
+        );
+    }
+}
+
+fn usage_delay(throttled_requests: u32) -> Duration {
+    let doublings = throttled_requests.saturating_sub(1).min(16);
+    BASE_DELAY.saturating_mul(1 << doublings).min(MAX_DELAY)
+}
+
+fn max_used_percent(snapshot: &RateLimitSnapshot) -> Option<f64> {
+    snapshot
+        .primary
+        .iter()
+        .chain(snapshot.secondary.iter())
+        .map(|window| window.used_percent)
+        .chain(snapshot.extra.iter().map(|window| window.used_percent))
+        .reduce(f64::max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::ExtraRateLimitWindow;
+    use codex_protocol::protocol::RateLimitWindow;
+    use pretty_assertions::assert_eq;
+
+    const PROVIDER: &str = "openai";
+
+    fn snapshot(primary: f64, extra: Option<f64>) -> RateLimitSnapshot {
+        RateLimitSnapshot {
+            limit_id: Some("codex".to_string()),
+            limit_name: None,
+            scope: None,
+            primary: Some(RateLimitWindow {
+                used_percent: primary,
+                window_minutes: Some(300),
+                resets_at: None,
+            }),
+            secondary: None,
+            extra: extra
+                .map(|used_percent| ExtraRateLimitWindow {
+                    label: "burst".to_string(),
+                    used_percent,
+                    window_minutes: Some(1),
+                    resets_at: None,
+                })
+                .into_iter()
+                .collect(),
+            credits: None,
+            individual_limit: None,
+            spend_control_reached: None,
+            plan_type: None,
+            rate_limit_reached_type: None,
+        }
+    }
+
+    fn delays(throttle: &mut RateLimitThrottle, used: &[f64], now: Instant) -> Vec<Duration> {
+        used.iter()
+            .map(|used_percent| {
+                throttle
+                    .next_delay(PROVIDER, Some(&snapshot(*used_percent, None)), now)
+                    .map(|delay| delay.delay)
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn delay_grows_while_usage_stays_high_and_resets_with_headroom() {
+        let mut throttle = RateLimitThrottle::default();
+        let now = Instant::now();
+
+        assert_eq!(
+            delays(&mut throttle, &[50.0, 97.0, 97.0, 98.0, 60.0, 95.0], now),
+            vec![
+                Duration::ZERO,
+                Duration::from_secs(2),
+                Duration::from_secs(4),
+                Duration::from_secs(8),
+                Duration::ZERO,
+                Duration::from_secs(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn delay_is_capped() {
+        let mut throttle = RateLimitThrottle::default();
+        let now = Instant::now();
+
+        let delays = delays(&mut throttle, &[99.0; 40], now);
+        assert_eq!(delays.last(), Some(&MAX_DELAY));
+    }
+
+    #[test]
+    fn extra_windows_count_toward_usage() {
+        let mut throttle = RateLimitThrottle::default();
+
+        assert_eq!(
+            throttle.next_delay(
+                PROVIDER,
+                Some(&snapshot(/*primary*/ 10.0, Some(92.5))),
+                Instant::now()
+            ),
+            Some(ThrottleDelay {
+                delay: BASE_DELAY,
+                used_percent: Some(92.5),
+            })
+        );
+    }
+
+    #[test]
+    fn retry_after_holds_only_the_next_request() {
+        let mut throttle = RateLimitThrottle::default();
+        let start = Instant::now();
+        throttle.record_retry_after(PROVIDER, Duration::from_secs(30), start);
+
+        let later = start + Duration::from_secs(10);
+        assert_eq!(
+            throttle.next_delay(PROVIDER, /*snapshot*/ None, later),
+            Some(ThrottleDelay {
+                delay: Duration::from_secs(20),
+                used_percent: None,
+            })
+        );
+        assert_eq!(
+            throttle.next_delay(PROVIDER, /*snapshot*/ None, later),
+            None
+        );
+    }
+
+    #[test]
+    fn providers_are_throttled_independently() {
+        let mut throttle = RateLimitThrottle::default();
+        let now = Instant::now();
+        throttle.record_retry_after(PROVIDER, Duration::from_secs(30), now);
+
+        assert_eq!(throttle.next_delay("azure", /*snapshot*/ None, now), None);
+        assert_eq!(
+            throttle
+                .next_delay(PROVIDER, /*snapshot*/ None, now)
+                .map(|delay| delay.delay),
+            Some(Duration::from_secs(30))
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Tracks which `[limits] warn_at` thresholds have fired for each rate-limit window.
+
+use std::collections::HashMap;
+
+use codex_protocol::protocol::RateLimitSnapshot;
+use codex_protocol::protocol::RateLimitWarningEvent;
+use codex_protocol::protocol::RateLimitWindow;
+use codex_protocol::protocol::RateLimitWindowKind;
+
+/// Usage must drop this many points below a threshold before it warns again,
+/// so usage hovering around a threshold does not repeat the warning.
+const REARM_MARGIN_PERCENT: f64 = 2.0;
+
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitWarningTracker {
+    /// Highest threshold already warned about, keyed by limit id and window.
+    warned: HashMap<(String, RateLimitWindowKind), u8>,
+}
+
+impl RateLimitWarningTracker {
+    /// Returns one warning per window whose usage newly reached a threshold.
+    ///
+    /// `thresholds` must be sorted ascending. When a single update crosses
+    /// several thresholds, only the highest one is reported.
+    pub(crate) fn observe(
+        &mut self,
+        thresholds: &[u8],
+        snapshot: &RateLimitSnapshot,
+    ) -> Vec<RateLimitWarningEvent> {
+        if thresholds.is_empty() {
+            return Vec::new();
+        }
+        [
+            (RateLimitWindowKind::Primary, snapshot.primary.as_ref()),
+            (RateLimitWindowKind::Secondary, snapshot.secondary.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(window_kind, window)| {
+            let window = window?;
+            let threshold_percent =
+                self.observe_window(thresholds, snapshot, window_kind, window)?;
+            Some(RateLimitWarningEvent {
+                limit_id: snapshot.limit_id.clone(),
+                limit_name: snapshot.limit_name.clone(),
+                window_kind,
+                threshold_percent,
+                window: window.clone(),
+            })
+        })
+        .collect()
+    }
+
+    fn observe_window(
+        &mut self,
+        thresholds: &[u8],
+        snapshot: &RateLimitSnapshot,
+        window_kind: RateLimitWindowKind,
+        window: &RateLimitWindow,
+    ) -> Option<u8> {
+        let key = (snapshot.limit_id.clone().unwrap_or_default(), window_kind);
+        let used_percent = window.used_percent;
+        let previously_warned = self.warned.get(&key).copied();
+
+        // Re-arm every threshold that usage has clearly dropped back below.
+        let still_warned = thresholds
+            .iter()
+            .copied()
+            .filter(|threshold| {
+                Some(*threshold) <= previously_warned
+                    && used_percent + REARM_MARGIN_PERCENT > f64::from(*threshold)
+            })
+            .max();
+        match still_warned {
+            Some(threshold) => self.warned.insert(key.clone(), threshold),
+            None => self.warned.remove(&key),
+        };
+
+        let reached = thresholds
+            .iter()
+            .copied()
+            .filter(|threshold| used_percent >= f64::from(*threshold))
+            .max()?;
+        if Some(reached) <= still_warned {
+            return None;
+        }
+        self.warned.insert(key, reached);
+        Some(reached)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const THRESHOLDS: [u8; 2] = [75, 90];
+
+    fn snapshot(primary: Option<f64>, secondary: Option<f64>) -> RateLimitSnapshot {
+        let window = |used_percent| RateLimitWindow {
+            used_percent,
+            window_minutes: Some(300),
+            resets_at: Some(1_700_000_000),
+        };
+        RateLimitSnapshot {
+            limit_id: Some("codex".to_string()),
+            limit_name: None,
+            scope: None,
+            primary: primary.map(window),
+            secondary: secondary.map(window),
+            extra: Vec::new(),
+            credits: None,
+            individual_limit: None,
+            spend_control_reached: None,
+            plan_type: None,
+            rate_limit_reached_type: None,
+        }
+    }
+
+    /// Feeds primary-window usage values and returns the thresholds that fired.
+    fn fired(tracker: &mut RateLimitWarningTracker, used: &[f64]) -> Vec<Vec<u8>> {
+        used.iter()
+            .map(|used_percent| {
+                tracker
+                    .observe(&THRESHOLDS, &snapshot(Some(*used_percent), None))
+                    .into_iter()
+                    .map(|event| event.threshold_percent)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn warns_once_per_threshold_as_usage_climbs() {
+        let mut tracker = RateLimitWarningTracker::default();
+
+        assert_eq!(
+            fired(&mut tracker, &[10.0, 74.9, 75.0, 80.0, 90.0, 99.0]),
+            vec![vec![], vec![], vec![75], vec![], vec![90], vec![]]
+        );
+    }
+
+    #[test]
+    fn jump_past_several_thresholds_reports_the_highest() {
+        let mut tracker = RateLimitWarningTracker::default();
+
+        assert_eq!(
+            fired(&mut tracker, &[10.0, 95.0, 96.0]),
+            vec![vec![], vec![90], vec![]]
+        );
+    }
+
+    #[test]
+    fn hovering_at_a_threshold_does_not_repeat() {
+        let mut tracker = RateLimitWarningTracker::default();
+
+        assert_eq!(
+            fired(&mut tracker, &[90.0, 89.5, 90.0, 88.5, 90.5]),
+            vec![vec![90], vec![], vec![], vec![], vec![]]
+        );
+    }
+
+    #[test]
+    fn dropping_below_a_threshold_rearms_it() {
+        let mut tracker = RateLimitWarningTracker::default();
+
+        assert_eq!(
+            fired(&mut tracker, &[92.0, 80.0, 91.0, 3.0, 76.0, 90.0]),
+            vec![vec![90], vec![], vec![90], vec![], vec![75], vec![90]]
+        );
+    }
+
+    #[test]
+    fn tracks_windows_and_limits_independently() {
+        let mut tracker = RateLimitWarningTracker::default();
+
+        let events = tracker.observe(&THRESHOLDS, &snapshot(Some(80.0), Some(91.0)));
+        assert_eq!(
+            events,
+            vec![
+                RateLimitWarningEvent {
+                    limit_id: Some("codex".to_string()),
+                    limit_name: None,
+                    window_kind: RateLimitWindowKind::Primary,
+                    threshold_percent: 75,
+                    window: RateLimitWindow {
+                        used_percent: 80.0,
+                        window_minutes: Some(300),
+                        resets_at: Some(1_700_000_000),
+                    },
+                },
+                RateLimitWarningEvent {
+                    limit_id: Some("codex".to_string()),
+                    limit_name: None,
+                    window_kind: RateLimitWindowKind::Secondary,
+                    threshold_percent: 90,
+                    window: RateLimitWindow {
+                        used_percent: 91.0,
+                        window_minutes: Some(300),
+                        resets_at: Some(1_700_000_000),
+                    },
+                },
+            ]
+        );
+
+        let other_limit = RateLimitSnapshot {
+            limit_id: Some("codex_other".to_string()),
+            ..snapshot(Some(80.0), None)
+        };
+        let thresholds: Vec<u8> = tracker
+            .observe(&THRESHOLDS, &other_limit)
+            .into_iter()
+            .map(|event| event.threshold_percent)
+            .collect();
+        assert_eq!(thresholds, vec![75]);
+    }
+
+    #[test]
+    fn no_thresholds_never_warns() {
+        let mut tracker = RateLimitWarningTracker::default();
+
+        assert_eq!(
+            tracker.observe(&[], &snapshot(Some(100.0), None)),
+            Vec::new()
+        );
+    }
+}
@@ -6,17 +6,23 @@ use codex_sandboxing::policy_transforms::merge_permission_profiles;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
+use std::time::Duration;
+use std::time::Instant;
 
 use super::AdditionalContextStore;
 use super::auto_compact_window::AutoCompactWindow;
 use super::auto_compact_window::AutoCompactWindowIds;
 use super::auto_compact_window::AutoCompactWindowSnapshot;
+use super::rate_limit_throttle::RateLimitThrottle;
+use super::rate_limit_throttle::ThrottleDelay;
+use super::rate_limit_warnings::RateLimitWarningTracker;
 use crate::context_manager::ContextManager;
 use crate::session::PreviousTurnSettings;
 use crate::session::session::SessionConfiguration;
 use crate::session::time_reminder::CurrentTimeReminderState;
 use crate::session_startup_prewarm::SessionStartupPrewarmHandle;
 use codex_protocol::protocol::RateLimitSnapshot;
+use codex_protocol::protocol::RateLimitWarningEvent;
 use codex_protocol::protocol::TokenUsage;
 use codex_protocol::protocol::TokenUsageInfo;
 use codex_protocol::protocol::TurnContextItem;
@@ -27,6 +33,8 @@ pub(crate) struct SessionState {
     pub(crate) session_configuration: SessionConfiguration,
     pub(crate) history: ContextManager,
     pub(crate) latest_rate_limits: Option<RateLimitSnapshot>,
+    rate_limit_warnings: RateLimitWarningTracker,
+    rate_limit_throttle: RateLimitThrottle,
     pub(crate) server_reasoning_included: bool,
     pub(crate) mcp_dependency_prompted: HashSet<String>,
     pub(crate) additional_context: AdditionalContextStore,
@@ -64,6 +72,8 @@ impl SessionState {
             session_configuration,
             history,
             latest_rate_limits: None,
+            rate_limit_warnings: RateLimitWarningTracker::default(),
+            rate_limit_throttle: RateLimitThrottle::default(),
             server_reasoning_included: false,
             mcp_dependency_prompted: HashSet::new(),
             additional_context: AdditionalContextStore::default(),
@@ -212,6 +222,39 @@ impl SessionState {
         ));
     }
 
+    /// Checks the latest rate limits against `[limits] warn_at` thresholds and
+    /// returns warnings for thresholds reached since they were last armed.
+    pub(crate) fn take_rate_limit_warnings(
+        &mut self,
+        thresholds: &[u8],
+    ) -> Vec<RateLimitWarningEvent> {
+        match self.latest_rate_limits.as_ref() {
+            Some(snapshot) => self.rate_limit_warnings.observe(thresholds, snapshot),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns how long `[limits] auto_throttle` should hold the next request
+    /// to `provider`, based on the latest rate limits.
+    pub(crate) fn rate_limit_throttle_delay(
+        &mut self,
+        provider: &str,
+        now: Instant,
+    ) -> Option<ThrottleDelay> {
+        self.rate_limit_throttle
+            .next_delay(provider, self.latest_rate_limits.as_ref(), now)
+    }
+
+    pub(crate) fn record_rate_limit_retry_after(
+        &mut self,
+        provider: &str,
+        retry_after: Duration,
+        now: Instant,
+    ) {
+        self.rate_limit_throttle
+            .record_retry_after(provider, retry_after, now);
+    }
+
     pub(crate) fn token_info_and_rate_limits(
         &self,
     ) -> (Option<TokenUsageInfo>, Option<RateLimitSnapshot>) {
@@ -328,6 +371,16 @@ fn merge_rate_limit_fields(
     if snapshot.credits.is_none() {
         snapshot.credits = previous.and_then(|prior| prior.credits.clone());
     }
+    // Header-derived updates never carry extra windows or a scope, so keep the
+    // ones last reported for the same limit.
+    if let Some(prior) = previous.filter(|prior| prior.limit_id == snapshot.limit_id) {
+        if snapshot.extra.is_empty() {
+            snapshot.extra = prior.extra.clone();
+        }
+        if snapshot.scope.is_none() {
+            snapshot.scope = prior.scope;
+        }
+    }
     if snapshot.individual_limit.is_none() {
         snapshot.individual_limit = previous.and_then(|prior| prior.individual_limit.clone());
     }
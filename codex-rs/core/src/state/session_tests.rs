@@ -2,6 +2,8 @@ use super::*;
 use crate::session::tests::make_session_configuration_for_tests;
 use crate::state::AutoCompactWindowSnapshot;
 use codex_protocol::protocol::CreditsSnapshot;
+use codex_protocol::protocol::ExtraRateLimitWindow;
+use codex_protocol::protocol::RateLimitScope;
 use codex_protocol::protocol::RateLimitWindow;
 use codex_protocol::protocol::SpendControlLimitSnapshot;
 use pretty_assertions::assert_eq;
@@ -43,12 +45,14 @@ async fn set_rate_limits_defaults_limit_id_to_codex_when_missing() {
     state.set_rate_limits(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 12.0,
             window_minutes: Some(60),
             resets_at: Some(100),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -65,6 +69,52 @@ async fn set_rate_limits_defaults_limit_id_to_codex_when_missing() {
     );
 }
 
+#[tokio::test]
+async fn set_rate_limits_keeps_extra_windows_and_scope_for_the_same_limit() {
+    let session_configuration = make_session_configuration_for_tests().await;
+    let mut state = SessionState::new(session_configuration);
+    let snapshot = |limit_id: &str,
+                    scope: Option<RateLimitScope>,
+                    extra: Vec<ExtraRateLimitWindow>| RateLimitSnapshot {
+        limit_id: Some(limit_id.to_string()),
+        limit_name: None,
+        scope,
+        primary: Some(RateLimitWindow {
+            used_percent: 12.0,
+            window_minutes: Some(300),
+            resets_at: Some(100),
+        }),
+        secondary: None,
+        extra,
+        credits: None,
+        individual_limit: None,
+        spend_control_reached: None,
+        plan_type: None,
+        rate_limit_reached_type: None,
+    };
+    let burst = vec![ExtraRateLimitWindow {
+        label: "burst".to_string(),
+        used_percent: 40.0,
+        window_minutes: Some(1),
+        resets_at: Some(60),
+    }];
+
+    let organization = Some(RateLimitScope::Organization);
+
+    state.set_rate_limits(snapshot("codex", organization, burst.clone()));
+    state.set_rate_limits(snapshot("codex", /*scope*/ None, Vec::new()));
+    assert_eq!(
+        state.latest_rate_limits,
+        Some(snapshot("codex", organization, burst.clone()))
+    );
+
+    state.set_rate_limits(snapshot("codex_other", /*scope*/ None, Vec::new()));
+    assert_eq!(
+        state.latest_rate_limits,
+        Some(snapshot("codex_other", /*scope*/ None, Vec::new()))
+    );
+}
+
 #[tokio::test]
 async fn replace_history_clears_auto_compact_window_prefill() {
     let session_configuration = make_session_configuration_for_tests().await;
@@ -89,12 +139,14 @@ async fn set_rate_limits_defaults_to_codex_when_limit_id_missing_after_other_buc
     state.set_rate_limits(RateLimitSnapshot {
         limit_id: Some("codex_other".to_string()),
         limit_name: Some("codex_other".to_string()),
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 20.0,
             window_minutes: Some(60),
             resets_at: Some(200),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -104,12 +156,14 @@ async fn set_rate_limits_defaults_to_codex_when_limit_id_missing_after_other_buc
     state.set_rate_limits(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 30.0,
             window_minutes: Some(60),
             resets_at: Some(300),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -134,12 +188,14 @@ async fn set_rate_limits_carries_account_metadata_from_codex_to_codex_other() {
     state.set_rate_limits(RateLimitSnapshot {
         limit_id: Some("codex".to_string()),
         limit_name: Some("codex".to_string()),
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 10.0,
             window_minutes: Some(60),
             resets_at: Some(100),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: Some(CreditsSnapshot {
             has_credits: true,
             unlimited: false,
@@ -159,12 +215,14 @@ async fn set_rate_limits_carries_account_metadata_from_codex_to_codex_other() {
     state.set_rate_limits(RateLimitSnapshot {
         limit_id: Some("codex_other".to_string()),
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 30.0,
             window_minutes: Some(120),
             resets_at: Some(200),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -177,12 +235,14 @@ async fn set_rate_limits_carries_account_metadata_from_codex_to_codex_other() {
         Some(RateLimitSnapshot {
             limit_id: Some("codex_other".to_string()),
             limit_name: None,
+            scope: None,
             primary: Some(RateLimitWindow {
                 used_percent: 30.0,
                 window_minutes: Some(120),
                 resets_at: Some(200),
             }),
             secondary: None,
+            extra: Vec::new(),
             credits: Some(CreditsSnapshot {
                 has_credits: true,
                 unlimited: false,
@@ -203,8 +263,10 @@ async fn set_rate_limits_carries_account_metadata_from_codex_to_codex_other() {
     state.set_rate_limits(RateLimitSnapshot {
         limit_id: Some("codex_other".to_string()),
         limit_name: None,
+        scope: None,
         primary: None,
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: Some(false),
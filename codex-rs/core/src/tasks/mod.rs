@@ -11,6 +11,7 @@ use std::time::Instant;
 use codex_extension_api::ExtensionData;
 use futures::future::BoxFuture;
 use tokio::select;
+use tokio::sync::Mutex;
 use tokio::sync::Notify;
 use tokio_util::sync::CancellationToken;
 use tokio_util::task::AbortOnDropHandle;
@@ -34,6 +35,7 @@ use crate::session::turn_context::TurnContext;
 use crate::state::ActiveTurn;
 use crate::state::RunningTask;
 use crate::state::TaskKind;
+use crate::state::TurnState;
 use codex_analytics::TurnProfileFact;
 use codex_analytics::TurnTokenUsageFact;
 use codex_login::AuthManager;
@@ -47,10 +49,12 @@ use codex_otel::TURN_TOOL_CALL_METRIC;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::MultiAgentVersion;
+use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::TokenUsage;
 use codex_protocol::protocol::TurnAbortReason;
 use codex_protocol::protocol::TurnAbortedEvent;
 use codex_protocol::protocol::TurnCompleteEvent;
+use codex_protocol::protocol::TurnSummaryItem;
 use codex_protocol::protocol::WarningEvent;
 
 use codex_features::Feature;
@@ -167,6 +171,20 @@ pub(crate) fn emit_compact_metric(
     );
 }
 
+/// Token usage accumulated since `start`, clamped at zero per field.
+fn token_usage_since(total: &TokenUsage, start: &TokenUsage) -> TokenUsage {
+    TokenUsage {
+        input_tokens: (total.input_tokens - start.input_tokens).max(0),
+        cached_input_tokens: (total.cached_input_tokens - start.cached_input_tokens).max(0),
+        cache_write_input_tokens: (total.cache_write_input_tokens - start.cache_write_input_tokens)
+            .max(0),
+        output_tokens: (total.output_tokens - start.output_tokens).max(0),
+        reasoning_output_tokens: (total.reasoning_output_tokens - start.reasoning_output_tokens)
+            .max(0),
+        total_tokens: (total.total_tokens - start.total_tokens).max(0),
+    }
+}
+
 fn bool_tag(value: bool) -> &'static str {
     if value { "true" } else { "false" }
 }
@@ -503,7 +521,8 @@ impl Session {
             aborted_turn = task.is_some();
             turn_context = task.as_ref().map(|task| Arc::clone(&task.turn_context));
             if let Some(task) = task {
-                self.handle_task_abort(task, reason.clone()).await;
+                self.handle_task_abort(task, active_turn.turn_state.as_ref(), reason.clone())
+                    .await;
             }
             if aborted_turn {
                 active_turn_to_clear = Some(active_turn);
@@ -548,7 +567,8 @@ impl Session {
         let task = active_turn.task.take();
         let turn_context = task.as_ref().map(|task| Arc::clone(&task.turn_context));
         if let Some(task) = task {
-            self.handle_task_abort(task, reason.clone()).await;
+            self.handle_task_abort(task, active_turn.turn_state.as_ref(), reason.clone())
+                .await;
         }
         if let Some(turn_context) = turn_context.as_deref() {
             self.emit_turn_abort_lifecycle(reason.clone(), turn_context.extension_data.as_ref())
@@ -664,26 +684,8 @@ impl Session {
                 &[tmp_mem],
             );
             let total_token_usage = self.total_token_usage().await.unwrap_or_default();
-            let turn_token_usage = TokenUsage {
-                input_tokens: (total_token_usage.input_tokens
-                    - token_usage_at_turn_start.input_tokens)
-                    .max(0),
-                cached_input_tokens: (total_token_usage.cached_input_tokens
-                    - token_usage_at_turn_start.cached_input_tokens)
-                    .max(0),
-                cache_write_input_tokens: (total_token_usage.cache_write_input_tokens
-                    - token_usage_at_turn_start.cache_write_input_tokens)
-                    .max(0),
-                output_tokens: (total_token_usage.output_tokens
-                    - token_usage_at_turn_start.output_tokens)
-                    .max(0),
-                reasoning_output_tokens: (total_token_usage.reasoning_output_tokens
-                    - token_usage_at_turn_start.reasoning_output_tokens)
-                    .max(0),
-                total_tokens: (total_token_usage.total_tokens
-                    - token_usage_at_turn_start.total_tokens)
-                    .max(0),
-            };
+            let turn_token_usage =
+                token_usage_since(&total_token_usage, &token_usage_at_turn_start);
             let current_span = Span::current();
             current_span.record(
                 "codex.turn.token_usage.input_tokens",
@@ -768,6 +770,8 @@ impl Session {
                 turn_id: turn_context.sub_id.clone(),
                 profile: turn_context.turn_timing_state.complete_profile(),
             });
+        self.persist_turn_summary(turn_context.as_ref(), turn_state.as_ref(), duration_ms)
+            .await;
         let event = if let Some(reason) = abort_reason {
             self.emit_turn_abort_lifecycle(reason.clone(), turn_context.extension_data.as_ref())
                 .await;
@@ -851,7 +855,39 @@ impl Session {
             .await
     }
 
-    async fn handle_task_abort(self: &Arc<Self>, task: RunningTask, reason: TurnAbortReason) {
+    /// Persists the `turn_summary` rollout item for a turn that just ended.
+    async fn persist_turn_summary(
+        &self,
+        turn_context: &TurnContext,
+        turn_state: &Mutex<TurnState>,
+        duration_ms: Option<i64>,
+    ) {
+        let (tool_calls, token_usage_at_turn_start) = {
+            let ts = turn_state.lock().await;
+            (
+                ts.tool_calls_by_name.clone(),
+                ts.token_usage_at_turn_start.clone(),
+            )
+        };
+        let total_token_usage = self.total_token_usage().await.unwrap_or_default();
+        let summary = TurnSummaryItem {
+            turn_id: turn_context.sub_id.clone(),
+            duration_ms,
+            model: turn_context.model_info.slug.clone(),
+            effort: turn_context.effective_reasoning_effort(),
+            tool_calls,
+            token_usage: token_usage_since(&total_token_usage, &token_usage_at_turn_start),
+        };
+        self.persist_rollout_items(&[RolloutItem::TurnSummary(summary)])
+            .await;
+    }
+
+    async fn handle_task_abort(
+        self: &Arc<Self>,
+        task: RunningTask,
+        turn_state: &Mutex<TurnState>,
+        reason: TurnAbortReason,
+    ) {
         let sub_id = task.turn_context.sub_id.clone();
         if task.cancellation_token.is_cancelled() {
             return;
@@ -918,6 +954,8 @@ impl Session {
                 turn_id: task.turn_context.sub_id.clone(),
                 profile: task.turn_context.turn_timing_state.complete_profile(),
             });
+        self.persist_turn_summary(task.turn_context.as_ref(), turn_state, duration_ms)
+            .await;
         let event = EventMsg::TurnAborted(TurnAbortedEvent {
             turn_id: Some(task.turn_context.sub_id.clone()),
             reason,
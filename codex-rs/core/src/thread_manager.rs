@@ -25,6 +25,7 @@ use codex_app_server_protocol::TurnStatus;
 use codex_code_mode::CodeModeSessionProvider;
 use codex_code_mode::InProcessCodeModeSessionProvider;
 use codex_code_mode::ProcessOwnedCodeModeSessionProvider;
+use codex_config::types::HistorySinkToml;
 use codex_core_plugins::PluginsManager;
 use codex_exec_server::EnvironmentManager;
 use codex_extension_api::ExtensionDataInit;
@@ -63,6 +64,11 @@ use codex_protocol::protocol::TurnAbortReason;
 use codex_protocol::protocol::TurnAbortedEvent;
 use codex_protocol::protocol::TurnEnvironmentSelection;
 use codex_protocol::protocol::W3cTraceContext;
+use codex_rollout::DEFAULT_HISTORY_SINK_BATCH_SIZE;
+use codex_rollout::DEFAULT_HISTORY_SINK_FLUSH_INTERVAL;
+use codex_rollout::HistorySink;
+use codex_rollout::HistorySinkConfig;
+use codex_rollout::RolloutPrunePolicy;
 use codex_rollout::state_db::StateDbHandle;
 use codex_thread_store::InMemoryThreadStore;
 use codex_thread_store::LoadThreadHistoryParams;
@@ -291,15 +297,68 @@ pub fn thread_store_from_config(
             {
                 codex_rollout::spawn_rollout_compression_worker(config.codex_home.to_path_buf());
             }
-            Arc::new(LocalThreadStore::new(
-                LocalThreadStoreConfig::from_config(config),
-                state_db,
-            ))
+            codex_rollout::spawn_session_start_prune(
+                codex_rollout::RolloutConfig::from_view(config),
+                rollout_retention_policy(config),
+            );
+            crate::rollout::configure_rollout_key_store(config);
+            let history_sink = history_sink_config(config)
+                .and_then(|sink_config| HistorySink::shared(&sink_config));
+            Arc::new(
+                LocalThreadStore::new(LocalThreadStoreConfig::from_config(config), state_db)
+                    .with_history_sink(history_sink),
+            )
         }
         ThreadStoreConfig::InMemory { id } => InMemoryThreadStore::for_id(id),
     }
 }
 
+/// Retention limits from `[history]`; empty unless the user opted in.
+fn rollout_retention_policy(config: &Config) -> RolloutPrunePolicy {
+    RolloutPrunePolicy {
+        older_than: config
+            .history
+            .retention_days
+            .map(|days| Duration::from_secs(days.saturating_mul(24 * 60 * 60))),
+        keep_last: None,
+        max_total_bytes: config.history.max_total_bytes,
+    }
+}
+
+/// Event sink from `[history.sink]`, if configured.
+fn history_sink_config(config: &Config) -> Option<HistorySinkConfig> {
+    let sink = match config.history.sink.as_ref()? {
+        HistorySinkToml::Webhook {
+            url,
+            headers,
+            batch_size,
+            flush_interval_ms,
+        } => {
+            let mut headers = headers
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect::<Vec<_>>();
+            headers.sort();
+            HistorySinkConfig::Webhook {
+                url: url.clone(),
+                headers,
+                batch_size: batch_size.unwrap_or(DEFAULT_HISTORY_SINK_BATCH_SIZE),
+                flush_interval: flush_interval_ms
+                    .map(Duration::from_millis)
+                    .unwrap_or(DEFAULT_HISTORY_SINK_FLUSH_INTERVAL),
+            }
+        }
+        HistorySinkToml::File {
+            path,
+            rotation_bytes,
+        } => HistorySinkConfig::File {
+            path: path.to_path_buf(),
+            rotation_bytes: *rotation_bytes,
+        },
+    };
+    Some(sink)
+}
+
 /// Construct the default SQLite-backed agent graph store when local state is available.
 pub fn local_agent_graph_store_from_state_db(
     state_db: Option<&StateDbHandle>,
@@ -327,6 +386,7 @@ impl ThreadManager {
         attestation_provider: Option<Arc<dyn AttestationProvider>>,
         external_time_provider: Option<Arc<dyn TimeProvider>>,
     ) -> Self {
+        auth_manager.start_proactive_token_refresh(config.chatgpt_token_refresh_margin);
         let codex_home = config.codex_home.clone();
         let restriction_product = session_source.restriction_product();
         let (thread_created_tx, _) = broadcast::channel(THREAD_CREATED_CHANNEL_CAPACITY);
@@ -460,6 +520,7 @@ impl ThreadManager {
                 codex_home: codex_home.clone(),
                 sqlite_home: codex_home.clone(),
                 default_model_provider_id: OPENAI_PROVIDER_ID.to_string(),
+                encrypt_history: false,
             },
             state_db.clone(),
         ));
@@ -23,8 +23,10 @@ use crate::tools::handlers::multi_agents_spec::MULTI_AGENT_V1_NAMESPACE;
 use crate::tools::hook_names::HookToolName;
 use crate::tools::lifecycle::notify_tool_finish;
 use crate::tools::lifecycle::notify_tool_start;
+use crate::tools::spec_plan::is_disabled_builtin_tool;
 use crate::tools::tool_dispatch_trace::ToolDispatchTrace;
 use crate::util::error_or_panic;
+use codex_config::BuiltinToolName;
 use codex_extension_api::ToolCallOutcome;
 use codex_protocol::models::FunctionCallOutputPayload;
 use codex_protocol::models::ResponseInputItem;
@@ -429,6 +431,11 @@ impl ToolRegistry {
             if let Some(active_turn) = active.as_mut() {
                 let mut turn_state = active_turn.turn_state.lock().await;
                 turn_state.tool_calls = turn_state.tool_calls.saturating_add(1);
+                let calls = turn_state
+                    .tool_calls_by_name
+                    .entry(tool_name_flat.to_string())
+                    .or_default();
+                *calls = calls.saturating_add(1);
             }
         }
 
@@ -436,7 +443,11 @@ impl ToolRegistry {
         let tool = match self.tool(&tool_name) {
             Some(tool) => tool,
             None => {
-                let message = unsupported_tool_call_message(&invocation.payload, &tool_name);
+                let message = if is_disabled_builtin_tool(&invocation.turn, &tool_name) {
+                    format!("tool {tool_name} is disabled by `tools.disabled` in config")
+                } else {
+                    unsupported_tool_call_message(&invocation.payload, &tool_name)
+                };
                 let log_payload = invocation.payload.log_payload();
                 otel.tool_result_with_tags(
                     tool_name_flat.as_ref(),
@@ -699,7 +710,19 @@ async fn handle_any_tool(
 ) -> Result<AnyToolResult, FunctionCallError> {
     let call_id = invocation.call_id.clone();
     let payload = invocation.payload.clone();
-    let output = tool.handle(invocation.clone()).await?;
+    let output = match builtin_tool_timeout(&invocation) {
+        // Dropping the handler future on timeout cancels the call.
+        Some(timeout) => tokio::time::timeout(timeout, tool.handle(invocation.clone()))
+            .await
+            .map_err(|_| {
+                FunctionCallError::RespondToModel(format!(
+                    "{} timed out after {} seconds",
+                    invocation.tool_name,
+                    timeout.as_secs_f64()
+                ))
+            })??,
+        None => tool.handle(invocation.clone()).await?,
+    };
     if output.contains_external_context()
         && invocation.turn.config.memories.disable_on_external_context
     {
@@ -720,6 +743,22 @@ async fn handle_any_tool(
     })
 }
 
+/// The `tools.timeout_sec` entry for the invoked built-in tool, if any.
+/// Built-in tools are never namespaced, so namespaced tools that share a
+/// built-in's name (MCP or dynamic tools, for example) never match.
+fn builtin_tool_timeout(invocation: &ToolInvocation) -> Option<Duration> {
+    if invocation.tool_name.namespace.is_some() {
+        return None;
+    }
+    let tool = invocation.tool_name.name.parse::<BuiltinToolName>().ok()?;
+    invocation
+        .turn
+        .config
+        .builtin_tool_timeouts
+        .get(&tool)
+        .copied()
+}
+
 fn function_hook_tool_name(invocation: &ToolInvocation) -> HookToolName {
     if invocation.tool_name.name == "spawn_agent"
         && matches!(
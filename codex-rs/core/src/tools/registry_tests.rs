@@ -75,6 +75,34 @@ impl LifecycleTestHandler {
 
 impl CoreToolRuntime for LifecycleTestHandler {}
 
+struct SlowTestHandler {
+    tool_name: codex_tools::ToolName,
+}
+
+impl ToolExecutor<ToolInvocation> for SlowTestHandler {
+    fn tool_name(&self) -> codex_tools::ToolName {
+        self.tool_name.clone()
+    }
+
+    fn spec(&self) -> codex_tools::ToolSpec {
+        test_spec(&self.tool_name)
+    }
+
+    fn handle(&self, _invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(
+                Box::new(crate::tools::context::FunctionToolOutput::from_text(
+                    "finished".to_string(),
+                    Some(true),
+                )) as Box<dyn crate::tools::context::ToolOutput>,
+            )
+        })
+    }
+}
+
+impl CoreToolRuntime for SlowTestHandler {}
+
 fn test_spec(tool_name: &codex_tools::ToolName) -> codex_tools::ToolSpec {
     codex_tools::ToolSpec::Function(codex_tools::ResponsesApiTool {
         name: tool_name.name.clone(),
@@ -458,6 +486,69 @@ async fn dispatch_notifies_tool_lifecycle_contributors() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn dispatch_cuts_off_builtin_tool_after_configured_timeout() {
+    let (session, mut turn) = crate::session::tests::make_session_and_context().await;
+    let mut config = (*turn.config).clone();
+    config.builtin_tool_timeouts = HashMap::from([(
+        codex_config::BuiltinToolName::ViewImage,
+        Duration::from_millis(50),
+    )]);
+    turn.config = Arc::new(config);
+    let tool_name = codex_tools::ToolName::plain("view_image");
+    let registry = ToolRegistry::with_handler_for_test(Arc::new(SlowTestHandler {
+        tool_name: tool_name.clone(),
+    }));
+
+    let started = std::time::Instant::now();
+    let result = registry
+        .dispatch_any_with_terminal_outcome(
+            test_invocation(Arc::new(session), Arc::new(turn), "slow-call", tool_name),
+            /*terminal_outcome_reached*/ None,
+        )
+        .await;
+
+    let err = match result {
+        Ok(_) => panic!("slow handler should be cut off"),
+        Err(err) => err,
+    };
+    assert_eq!(
+        err,
+        FunctionCallError::RespondToModel("view_image timed out after 0.05 seconds".to_string())
+    );
+    assert!(started.elapsed() < Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn builtin_tool_timeout_ignores_namespaced_tools() {
+    let (session, mut turn) = crate::session::tests::make_session_and_context().await;
+    let mut config = (*turn.config).clone();
+    config.builtin_tool_timeouts = HashMap::from([(
+        codex_config::BuiltinToolName::ViewImage,
+        Duration::from_secs(5),
+    )]);
+    turn.config = Arc::new(config);
+    let session = Arc::new(session);
+    let turn = Arc::new(turn);
+
+    let timeouts = [
+        codex_tools::ToolName::plain("view_image"),
+        codex_tools::ToolName::namespaced("mcp__images__", "view_image"),
+        codex_tools::ToolName::plain("exec_command"),
+        codex_tools::ToolName::plain("not_a_builtin"),
+    ]
+    .map(|tool_name| {
+        builtin_tool_timeout(&test_invocation(
+            Arc::clone(&session),
+            Arc::clone(&turn),
+            "call",
+            tool_name,
+        ))
+    });
+
+    assert_eq!(timeouts, [Some(Duration::from_secs(5)), None, None, None]);
+}
+
 fn test_invocation(
     session: Arc<crate::session::session::Session>,
     turn: Arc<crate::session::turn_context::TurnContext>,
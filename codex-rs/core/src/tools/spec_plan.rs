@@ -589,6 +589,7 @@ fn add_tool_sources(context: &CoreToolPlanContext<'_>, planned_tools: &mut Plann
     add_mcp_resource_tools(context, planned_tools);
     add_core_utility_tools(context, planned_tools);
     add_collaboration_tools(context, planned_tools);
+    remove_disabled_builtin_tools(context.step_context.turn.as_ref(), planned_tools);
     for runtime in context.tool_runtimes {
         planned_tools.add_arc(Arc::clone(runtime));
     }
@@ -599,6 +600,22 @@ fn add_tool_sources(context: &CoreToolPlanContext<'_>, planned_tools: &mut Plann
     }
 }
 
+/// Drops the built-in tools listed in `tools.disabled`. Runs before MCP,
+/// dynamic, and extension tools are added so only built-in tools match.
+fn remove_disabled_builtin_tools(turn_context: &TurnContext, planned_tools: &mut PlannedTools) {
+    planned_tools
+        .runtimes
+        .retain(|runtime| !is_disabled_builtin_tool(turn_context, &runtime.tool_name()));
+}
+
+pub(crate) fn is_disabled_builtin_tool(turn_context: &TurnContext, tool_name: &ToolName) -> bool {
+    turn_context
+        .config
+        .disabled_builtin_tools
+        .iter()
+        .any(|disabled| disabled.as_str() == tool_name.name)
+}
+
 fn standalone_web_search_enabled(turn_context: &TurnContext) -> bool {
     namespace_tools_enabled(turn_context)
         && (turn_context.model_info.use_responses_lite
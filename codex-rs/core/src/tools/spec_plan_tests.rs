@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use codex_config::BuiltinToolName;
 use codex_features::Feature;
 use codex_login::AuthManager;
 use codex_login::CodexAuth;
@@ -463,6 +464,24 @@ async fn request_user_input_tool_respects_experimental_config_gate() {
     disabled.assert_registered_lacks(&["request_user_input"]);
 }
 
+#[tokio::test]
+async fn disabled_builtin_tools_are_not_registered() {
+    let enabled = probe(|_| {}).await;
+    enabled.assert_visible_contains(&["update_plan", "request_user_input"]);
+
+    let disabled = probe(|turn| {
+        update_config(turn, |config| {
+            config.disabled_builtin_tools = vec![
+                BuiltinToolName::UpdatePlan,
+                BuiltinToolName::RequestUserInput,
+            ];
+        });
+    })
+    .await;
+    disabled.assert_visible_lacks(&["update_plan", "request_user_input"]);
+    disabled.assert_registered_lacks(&["update_plan", "request_user_input"]);
+}
+
 #[tokio::test]
 async fn request_user_input_stays_direct_in_code_mode_only() {
     let plan = probe(|turn| {
@@ -78,6 +78,7 @@ async fn responses_stream_includes_subagent_header_on_review() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -214,6 +215,7 @@ async fn responses_stream_includes_subagent_header_on_other() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -331,6 +333,7 @@ async fn responses_respects_model_info_overrides_from_config() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -2277,6 +2277,7 @@ fn websocket_provider_with_connect_timeout(
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -196,6 +196,8 @@ fn insert_rmcp_test_server(config: &mut Config, command: String, approval_mode:
             enabled: true,
             required: false,
             supports_parallel_tool_calls: false,
+            requires_approval: false,
+            description: None,
             disabled_reason: None,
             startup_timeout_sec: Some(Duration::from_secs(10)),
             tool_timeout_sec: None,
@@ -45,6 +45,8 @@ async fn refresh_keeps_superseded_mcp_server_alive_for_in_flight_calls() -> anyh
                     enabled: true,
                     required: false,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     disabled_reason: None,
                     startup_timeout_sec: Some(Duration::from_secs(10)),
                     tool_timeout_sec: None,
@@ -103,6 +103,8 @@ async fn session_resource_client_follows_published_mcp_runtime() -> Result<()> {
         enabled: true,
         required: false,
         supports_parallel_tool_calls: false,
+        requires_approval: false,
+        description: None,
         disabled_reason: None,
         startup_timeout_sec: Some(Duration::from_millis(100)),
         tool_timeout_sec: None,
@@ -135,6 +135,7 @@ mod tool_parallelism;
 mod tools;
 mod truncation;
 mod turn_state;
+mod turn_summary;
 mod unified_exec;
 mod unified_exec_process_events;
 #[cfg(unix)]
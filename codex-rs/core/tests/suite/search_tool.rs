@@ -1115,6 +1115,8 @@ async fn tool_search_indexes_only_enabled_non_app_mcp_tools() -> Result<()> {
                     oauth: None,
                     oauth_resource: None,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     tools: HashMap::new(),
                 },
             );
@@ -1242,6 +1244,8 @@ async fn tool_search_surfaced_mcp_tool_errors_are_returned_to_model() -> Result<
                     oauth: None,
                     oauth_resource: None,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     tools: HashMap::new(),
                 },
             );
@@ -1391,6 +1395,8 @@ async fn tool_search_uses_non_app_mcp_server_instructions_as_namespace_descripti
                     oauth: None,
                     oauth_resource: None,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     tools: HashMap::new(),
                 },
             );
@@ -71,6 +71,7 @@ async fn continue_after_stream_error() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -53,6 +53,7 @@ async fn retries_on_early_close() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
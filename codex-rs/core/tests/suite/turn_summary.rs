@@ -0,0 +1,98 @@
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::TurnSummaryItem;
+use core_test_support::responses::ev_assistant_message;
+use core_test_support::responses::ev_completed;
+use core_test_support::responses::ev_response_created;
+use core_test_support::responses::ev_shell_command_call;
+use core_test_support::responses::mount_sse_sequence;
+use core_test_support::responses::sse;
+use core_test_support::responses::start_mock_server;
+use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
+use pretty_assertions::assert_eq;
+
+fn turn_summaries(path: &Path) -> Vec<TurnSummaryItem> {
+    std::fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RolloutLine>(line).ok())
+        .filter_map(|line| match line.item {
+            RolloutItem::TurnSummary(summary) => Some(summary),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The rollout is flushed right after the terminal turn event, so poll until
+/// `count` summaries are on disk.
+async fn wait_for_turn_summaries(path: &Path, count: usize) -> Vec<TurnSummaryItem> {
+    for _ in 0..100 {
+        let summaries = turn_summaries(path);
+        if summaries.len() >= count {
+            return summaries;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    turn_summaries(path)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn each_turn_records_one_turn_summary() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_response_created("resp-1"),
+                ev_shell_command_call("call-1", "echo summary"),
+                ev_completed("resp-1"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-2"),
+                ev_assistant_message("msg-1", "ran it"),
+                ev_completed("resp-2"),
+            ]),
+            sse(vec![
+                ev_response_created("resp-3"),
+                ev_assistant_message("msg-2", "nothing to run"),
+                ev_completed("resp-3"),
+            ]),
+        ],
+    )
+    .await;
+
+    let test = test_codex().build(&server).await?;
+    let rollout_path = test
+        .session_configured
+        .rollout_path
+        .clone()
+        .expect("rollout path");
+    test.submit_turn("run a shell command").await?;
+    test.submit_turn("just answer").await?;
+
+    let summaries = wait_for_turn_summaries(&rollout_path, /*count*/ 2).await;
+    let model = test.session_configured.model.clone();
+    assert_eq!(
+        summaries
+            .iter()
+            .map(|summary| (
+                summary.model.clone(),
+                summary.tool_calls.clone().into_iter().collect::<Vec<_>>(),
+                summary.duration_ms.is_some(),
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (model.clone(), vec![("shell_command".to_string(), 1)], true),
+            (model, Vec::new(), true),
+        ]
+    );
+    assert_ne!(summaries[0].turn_id, summaries[1].turn_id);
+    Ok(())
+}
@@ -9,7 +9,9 @@ use std::error::Error as StdError;
 use std::time::Duration;
 
 use codex_exec_server_protocol::JSONRPCErrorError;
+use codex_http_client::TraceOptions;
 use codex_http_client::build_reqwest_client_with_custom_ca;
+use codex_http_client::execute_traced;
 use codex_http_client::with_chatgpt_cloudflare_cookie_store;
 use futures::FutureExt;
 use futures::StreamExt;
@@ -164,8 +166,16 @@ impl ReqwestHttpRequestRunner {
         if let Some(body) = params.body {
             request = request.body(body.into_inner());
         }
+        let trace_options = TraceOptions {
+            buffer_response_body: !params.stream_response,
+            ..TraceOptions::default()
+        };
+        let send = async {
+            let (client, request) = request.build_split();
+            execute_traced(&client, request?, trace_options).await
+        };
 
-        let response = match request.send().instrument(request_span.clone()).await {
+        let response = match send.instrument(request_span.clone()).await {
             Ok(response) => response,
             Err(error) => {
                 request_span.record("error.type", "request");
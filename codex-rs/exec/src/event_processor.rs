@@ -1,5 +1,9 @@
 use std::path::Path;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use codex_app_server_protocol::AccountRateLimitWarningNotification;
+use codex_app_server_protocol::RateLimitWindowKind;
 use codex_app_server_protocol::ServerNotification;
 use codex_core::config::Config;
 use codex_protocol::protocol::SessionConfiguredEvent;
@@ -46,3 +50,37 @@ fn write_last_message_file(contents: &str, last_message_path: Option<&Path>) {
         eprintln!("Failed to write last message file {path:?}: {e}");
     }
 }
+
+/// One-line summary of a `[limits] warn_at` threshold crossing, including time until reset.
+pub(crate) fn rate_limit_warning_message(
+    notification: &AccountRateLimitWarningNotification,
+) -> String {
+    let window = match notification.window_kind {
+        RateLimitWindowKind::Primary => "primary",
+        RateLimitWindowKind::Secondary => "secondary",
+    };
+    let limit = notification
+        .limit_name
+        .as_deref()
+        .or(notification.limit_id.as_deref())
+        .unwrap_or("codex");
+    let used_percent = notification.window.used_percent;
+    let threshold_percent = notification.threshold_percent;
+    let resets = notification
+        .window
+        .resets_at
+        .and_then(|resets_at| {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+            let now = i64::try_from(now.as_secs()).ok()?;
+            let minutes = (resets_at - now).max(0) / 60;
+            Some(format!(
+                "; resets in {}h {:02}m",
+                minutes / 60,
+                minutes % 60
+            ))
+        })
+        .unwrap_or_default();
+    format!(
+        "{limit} {window} rate limit window is {used_percent}% used (warn_at {threshold_percent}%){resets}"
+    )
+}
@@ -19,6 +19,7 @@ use owo_colors::Style;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor::handle_last_message;
+use crate::event_processor::rate_limit_warning_message;
 
 pub(crate) struct EventProcessorWithHumanOutput {
     bold: Style,
@@ -239,6 +240,14 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                 CodexStatus::Running
             }
             ServerNotification::Warning(notification) => self.process_warning(notification.message),
+            ServerNotification::AccountRateLimitWarning(notification) => {
+                eprintln!(
+                    "{} {}",
+                    "rate limit warning:".style(self.yellow).style(self.bold),
+                    rate_limit_warning_message(&notification)
+                );
+                CodexStatus::Running
+            }
             ServerNotification::Error(notification) => {
                 eprintln!(
                     "{} {}",
@@ -21,6 +21,7 @@ use serde_json::json;
 pub use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor::handle_last_message;
+use crate::event_processor::rate_limit_warning_message;
 use crate::exec_events::AgentMessageItem;
 use crate::exec_events::CollabAgentState;
 use crate::exec_events::CollabAgentStatus;
@@ -432,6 +433,11 @@ impl EventProcessorWithJsonOutput {
                 events.extend(warning.events);
                 warning.status
             }
+            ServerNotification::AccountRateLimitWarning(notification) => {
+                let warning = self.collect_warning(rate_limit_warning_message(&notification));
+                events.extend(warning.events);
+                warning.status
+            }
             ServerNotification::Error(notification) => {
                 let message = match notification.error.additional_details {
                     Some(details) if !details.is_empty() => {
@@ -86,6 +86,42 @@ fn runtime_warning_emits_a_non_fatal_error_item() {
     );
 }
 
+#[test]
+fn rate_limit_warning_emits_a_non_fatal_error_item() {
+    let mut processor = EventProcessorWithJsonOutput::new(/*last_message_path*/ None);
+
+    let collected = processor.collect_thread_events(ServerNotification::AccountRateLimitWarning(
+        codex_app_server_protocol::AccountRateLimitWarningNotification {
+            thread_id: "thread-1".to_string(),
+            limit_id: Some("codex".to_string()),
+            limit_name: None,
+            window_kind: codex_app_server_protocol::RateLimitWindowKind::Secondary,
+            threshold_percent: 75,
+            window: codex_app_server_protocol::RateLimitWindow {
+                used_percent: 80,
+                window_duration_mins: Some(10_080),
+                resets_at: None,
+            },
+        },
+    ));
+
+    assert_eq!(
+        collected,
+        CollectedThreadEvents {
+            events: vec![ThreadEvent::ItemCompleted(ItemCompletedEvent {
+                item: ExecThreadItem {
+                    id: "item_0".to_string(),
+                    details: ThreadItemDetails::Error(ErrorItem {
+                        message: "codex secondary rate limit window is 80% used (warn_at 75%)"
+                            .to_string(),
+                    }),
+                },
+            })],
+            status: CodexStatus::Running,
+        }
+    );
+}
+
 #[test]
 fn mcp_tool_call_result_preserves_meta_in_jsonl_event() {
     let mut processor = EventProcessorWithJsonOutput::new(/*last_message_path*/ None);
@@ -179,6 +179,8 @@ async fn reads_declared_config_only_through_executor_file_system() {
                     enabled: true,
                     required: false,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
@@ -205,6 +207,8 @@ async fn reads_declared_config_only_through_executor_file_system() {
                     enabled: true,
                     required: false,
                     supports_parallel_tool_calls: false,
+                    requires_approval: false,
+                    description: None,
                     disabled_reason: None,
                     startup_timeout_sec: None,
                     tool_timeout_sec: None,
@@ -262,6 +266,8 @@ async fn reads_manifest_object_config_without_executor_file_system_access() {
                 enabled: true,
                 required: false,
                 supports_parallel_tool_calls: false,
+                requires_approval: false,
+                description: None,
                 disabled_reason: None,
                 startup_timeout_sec: None,
                 tool_timeout_sec: None,
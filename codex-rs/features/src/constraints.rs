@@ -0,0 +1,102 @@
+use crate::FEATURES;
+use crate::Feature;
+use crate::FeatureSpec;
+use crate::Features;
+use std::fmt;
+
+/// A `requires` or `conflicts_with` constraint broken by the resolved
+/// feature set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureConstraintViolation {
+    /// `feature` is enabled but `required` is not.
+    MissingRequirement { feature: Feature, required: Feature },
+    /// `feature` and `conflicting` are both enabled.
+    Conflict {
+        feature: Feature,
+        conflicting: Feature,
+    },
+}
+
+impl fmt::Display for FeatureConstraintViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRequirement { feature, required } => write!(
+                f,
+                "`{}` requires `{}` to be enabled",
+                feature.key(),
+                required.key()
+            ),
+            Self::Conflict {
+                feature,
+                conflicting,
+            } => write!(
+                f,
+                "`{}` cannot be enabled together with `{}`",
+                feature.key(),
+                conflicting.key()
+            ),
+        }
+    }
+}
+
+/// Every feature constraint violated by a resolved feature set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureConstraintError {
+    pub violations: Vec<FeatureConstraintViolation>,
+}
+
+impl fmt::Display for FeatureConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid [features] combination:")?;
+        for violation in &self.violations {
+            write!(f, "\n  - {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for FeatureConstraintError {}
+
+impl Features {
+    /// Checks the `requires` and `conflicts_with` constraints declared in
+    /// [`FEATURES`], reporting every violation at once.
+    pub fn validate_constraints(&self) -> Result<(), FeatureConstraintError> {
+        let violations = constraint_violations(self, FEATURES);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(FeatureConstraintError { violations })
+        }
+    }
+}
+
+pub(crate) fn constraint_violations(
+    features: &Features,
+    specs: &[FeatureSpec],
+) -> Vec<FeatureConstraintViolation> {
+    let mut violations = Vec::new();
+    for spec in specs.iter().filter(|spec| features.enabled(spec.id)) {
+        for &required in spec.requires {
+            if !features.enabled(required) {
+                violations.push(FeatureConstraintViolation::MissingRequirement {
+                    feature: spec.id,
+                    required,
+                });
+            }
+        }
+        for &conflicting in spec.conflicts_with {
+            // A conflict declared on both sides is reported once.
+            let reported = violations.contains(&FeatureConstraintViolation::Conflict {
+                feature: conflicting,
+                conflicting: spec.id,
+            });
+            if features.enabled(conflicting) && !reported {
+                violations.push(FeatureConstraintViolation::Conflict {
+                    feature: spec.id,
+                    conflicting,
+                });
+            }
+        }
+    }
+    violations
+}
@@ -0,0 +1,103 @@
+use crate::FEATURES;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::de::Error as _;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Kind of value a `[features]` entry accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeatureValueType {
+    /// A plain on/off toggle.
+    Bool,
+    /// An integer within an inclusive range, such as a rollout percentage.
+    Integer { min: i64, max: i64 },
+    /// One of a fixed set of variant names.
+    Enum(&'static [&'static str]),
+}
+
+impl FeatureValueType {
+    /// Checks that `value` matches this type.
+    pub fn validate(self, value: &FeatureValue) -> Result<(), String> {
+        match (self, value) {
+            (Self::Bool, FeatureValue::Bool(_)) => Ok(()),
+            (Self::Integer { min, max }, FeatureValue::Integer(value)) => {
+                if (min..=max).contains(value) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "expected an integer between {min} and {max}, got {value}"
+                    ))
+                }
+            }
+            (Self::Enum(variants), FeatureValue::String(value)) => {
+                if variants.contains(&value.as_str()) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "expected one of {}, got \"{value}\"",
+                        variants.join(", ")
+                    ))
+                }
+            }
+            (expected, value) => Err(format!("expected {expected}, got {value}")),
+        }
+    }
+}
+
+impl fmt::Display for FeatureValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => f.write_str("a boolean"),
+            Self::Integer { min, max } => write!(f, "an integer between {min} and {max}"),
+            Self::Enum(variants) => write!(f, "one of {}", variants.join(", ")),
+        }
+    }
+}
+
+/// Value of a `[features]` entry.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[serde(untagged)]
+pub enum FeatureValue {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+}
+
+impl fmt::Display for FeatureValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+/// Value type for a `[features]` key. Legacy aliases and unknown keys are
+/// boolean toggles.
+pub(crate) fn value_type_for_key(key: &str) -> FeatureValueType {
+    FEATURES
+        .iter()
+        .find(|spec| spec.key == key)
+        .map_or(FeatureValueType::Bool, |spec| spec.value_type)
+}
+
+/// Deserializes the flattened `[features]` entries, rejecting values that do
+/// not match the type each feature declares.
+pub(crate) fn deserialize_feature_entries<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, FeatureValue>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let entries = BTreeMap::<String, FeatureValue>::deserialize(deserializer)?;
+    for (key, value) in &entries {
+        value_type_for_key(key)
+            .validate(value)
+            .map_err(|err| D::Error::custom(format!("invalid value for features.{key}: {err}")))?;
+    }
+    Ok(entries)
+}
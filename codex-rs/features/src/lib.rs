@@ -14,8 +14,12 @@ use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use toml::Table;
 
+mod constraints;
 mod feature_configs;
+mod feature_value;
 mod legacy;
+pub use constraints::FeatureConstraintError;
+pub use constraints::FeatureConstraintViolation;
 pub use feature_configs::CodeModeConfigToml;
 pub use feature_configs::CurrentTimeReminderConfigToml;
 pub use feature_configs::CurrentTimeReminderDeliveryMode;
@@ -28,6 +32,9 @@ pub use feature_configs::NetworkProxyUnixSocketPermissionToml;
 use feature_configs::RemovedAppsMcpPathOverrideConfigToml;
 pub use feature_configs::RolloutBudgetConfigToml;
 pub use feature_configs::TokenBudgetConfigToml;
+pub use feature_value::FeatureValue;
+pub use feature_value::FeatureValueType;
+use feature_value::deserialize_feature_entries;
 use legacy::LegacyFeatureToggles;
 pub use legacy::legacy_feature_keys;
 
@@ -51,6 +58,17 @@ pub enum Stage {
 }
 
 impl Stage {
+    /// Short stability label shown in `codex features list` and the config schema.
+    pub fn label(self) -> &'static str {
+        match self {
+            Stage::UnderDevelopment => "under development",
+            Stage::Experimental { .. } => "experimental",
+            Stage::Stable => "stable",
+            Stage::Deprecated => "deprecated",
+            Stage::Removed => "removed",
+        }
+    }
+
     pub fn experimental_menu_name(self) -> Option<&'static str> {
         match self {
             Stage::Experimental { name, .. } => Some(name),
@@ -333,6 +351,8 @@ pub struct LegacyFeatureUsage {
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Features {
     enabled: BTreeSet<Feature>,
+    /// Configured values for features whose value type is not boolean.
+    values: BTreeMap<Feature, FeatureValue>,
     legacy_usages: BTreeSet<LegacyFeatureUsage>,
 }
 
@@ -371,6 +391,7 @@ impl Features {
         }
         Self {
             enabled: set,
+            values: BTreeMap::new(),
             legacy_usages: BTreeSet::new(),
         }
     }
@@ -394,6 +415,20 @@ impl Features {
 
     pub fn disable(&mut self, f: Feature) -> &mut Self {
         self.enabled.remove(&f);
+        self.values.remove(&f);
+        self
+    }
+
+    /// Configured value of a non-boolean feature, if one is set.
+    pub fn value(&self, f: Feature) -> Option<&FeatureValue> {
+        self.values.get(&f)
+    }
+
+    /// Sets the value of a non-boolean feature. Setting a value enables the
+    /// feature.
+    pub fn set_value(&mut self, f: Feature, value: FeatureValue) -> &mut Self {
+        self.enabled.insert(f);
+        self.values.insert(f, value);
         self
     }
 
@@ -654,15 +689,21 @@ pub struct FeaturesToml {
     #[schemars(skip)]
     removed_apps_mcp_path_override: Option<FeatureToml<RemovedAppsMcpPathOverrideConfigToml>>,
     pub network_proxy: Option<FeatureToml<NetworkProxyConfigToml>>,
-    /// Boolean feature toggles keyed by canonical or legacy feature name.
-    #[serde(flatten)]
-    entries: BTreeMap<String, bool>,
+    /// Feature values keyed by canonical or legacy feature name. Each value
+    /// must match the type declared by its `FeatureSpec`.
+    #[serde(flatten, deserialize_with = "deserialize_feature_entries")]
+    entries: BTreeMap<String, FeatureValue>,
 }
 
 impl Features {
     fn apply_toml(&mut self, features: &FeaturesToml) {
         let entries = features.entries();
         self.apply_map(&entries);
+        for (key, value) in features.values() {
+            if let Some(feature) = canonical_feature_for_key(&key) {
+                self.set_value(feature, value);
+            }
+        }
     }
 }
 
@@ -674,8 +715,17 @@ impl FeaturesToml {
         self.entries.remove("apps_mcp_path_override");
     }
 
+    /// Boolean toggles, including the `enabled` state of features configured
+    /// with a table.
     pub fn entries(&self) -> BTreeMap<String, bool> {
-        let mut entries = self.entries.clone();
+        let mut entries = self
+            .entries
+            .iter()
+            .filter_map(|(key, value)| match value {
+                FeatureValue::Bool(enabled) => Some((key.clone(), *enabled)),
+                FeatureValue::Integer(_) | FeatureValue::String(_) => None,
+            })
+            .collect::<BTreeMap<_, _>>();
         if let Some(enabled) = self.code_mode.as_ref().and_then(FeatureToml::enabled) {
             entries.insert(Feature::CodeMode.key().to_string(), enabled);
         }
@@ -701,6 +751,15 @@ impl FeaturesToml {
         entries
     }
 
+    /// Values of features whose value type is not boolean.
+    pub fn values(&self) -> BTreeMap<String, FeatureValue> {
+        self.entries
+            .iter()
+            .filter(|(_, value)| !matches!(value, FeatureValue::Bool(_)))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
     pub fn materialize_resolved_enabled(&mut self, features: &Features) {
         self.clear_removed_compatibility_entries();
         let Self {
@@ -730,8 +789,13 @@ impl FeaturesToml {
                 materialize_resolved_feature_enabled(current_time_reminder, enabled);
             } else if spec.id == Feature::NetworkProxy {
                 materialize_resolved_feature_enabled(network_proxy, enabled);
+            } else if spec.value_type == FeatureValueType::Bool {
+                entries.insert(spec.key.to_string(), FeatureValue::Bool(enabled));
             } else {
-                entries.insert(spec.key.to_string(), enabled);
+                match features.value(spec.id) {
+                    Some(value) => entries.insert(spec.key.to_string(), value.clone()),
+                    None => entries.remove(spec.key),
+                };
             }
         }
     }
@@ -750,7 +814,10 @@ fn materialize_resolved_feature_enabled<T: FeatureConfig>(
 impl From<BTreeMap<String, bool>> for FeaturesToml {
     fn from(entries: BTreeMap<String, bool>) -> Self {
         Self {
-            entries,
+            entries: entries
+                .into_iter()
+                .map(|(key, enabled)| (key, FeatureValue::Bool(enabled)))
+                .collect(),
             ..Default::default()
         }
     }
@@ -793,8 +860,17 @@ pub trait FeatureConfig {
 pub struct FeatureSpec {
     pub id: Feature,
     pub key: &'static str,
+    /// One-line summary of what the feature toggles, shown in the config
+    /// schema and `codex features list`.
+    pub description: &'static str,
+    /// Kind of value the `[features]` entry accepts.
+    pub value_type: FeatureValueType,
     pub stage: Stage,
     pub default_enabled: bool,
+    /// Features that must also be enabled when this one is.
+    pub requires: &'static [Feature],
+    /// Features that must not be enabled together with this one.
+    pub conflicts_with: &'static [Feature],
 }
 
 pub const FEATURES: &[FeatureSpec] = &[
@@ -802,538 +878,892 @@ pub const FEATURES: &[FeatureSpec] = &[
     FeatureSpec {
         id: Feature::GhostCommit,
         key: "undo",
+        description: "Removed compatibility flag retained as a no-op so old configs can still parse `undo`.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ShellTool,
         key: "shell_tool",
+        description: "Enable the default shell tool.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::SecretAuthStorage,
         key: "secret_auth_storage",
+        description: "Store CLI auth in the encrypted local secrets backend when keyring storage is selected.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: cfg!(windows),
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::UnifiedExec,
         key: "unified_exec",
+        description: "Use the single unified PTY-backed exec tool.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: !cfg!(windows),
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ShellZshFork,
         key: "shell_zsh_fork",
+        description: "Route shell tool execution through the zsh exec bridge.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::UnifiedExecZshFork,
         key: "unified_exec_zsh_fork",
+        description: "Allow unified exec to compose with the zsh exec bridge.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ShellSnapshot,
         key: "shell_snapshot",
+        description: "Experimental shell snapshotting.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::DeferredExecutor,
         key: "deferred_executor",
+        description: "Allow turns to start while selected executors are still starting.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::JsRepl,
         key: "js_repl",
+        description: "Removed compatibility flag for the deleted JavaScript REPL feature.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CodeMode,
         key: "code_mode",
+        description: "Enable JavaScript code mode backed by the in-process V8 runtime.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CodeModeHost,
         key: "code_mode_host",
+        description: "Run JavaScript code mode in the standalone host process.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CodeModeOnly,
         key: "code_mode_only",
+        description: "Restrict model-visible tools to code mode entrypoints (`exec`, `wait`).",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[Feature::CodeMode],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::JsReplToolsOnly,
         key: "js_repl_tools_only",
+        description: "Removed compatibility flag for the deleted JavaScript REPL tool-only mode.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::TerminalResizeReflow,
         key: "terminal_resize_reflow",
+        description: "Removed compatibility flag. Transcript scrollback reflow on terminal resize is always on.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::WebSearchRequest,
         key: "web_search_request",
+        description: "Allow the model to request web searches that fetch live content.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Deprecated,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::WebSearchCached,
         key: "web_search_cached",
+        description: "Allow the model to request web searches that fetch cached content. Takes precedence over `WebSearchRequest`.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Deprecated,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::StandaloneWebSearch,
         key: "standalone_web_search",
+        description: "Expose the extension-backed standalone web search tool.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::SearchTool,
         key: "search_tool",
+        description: "Legacy search-tool feature flag kept for backward compatibility.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CodexGitCommit,
         key: "codex_git_commit",
+        description: "Removed legacy git commit attribution guidance flag.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RuntimeMetrics,
         key: "runtime_metrics",
+        description: "Enable runtime metrics snapshots via a manual reader.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Sqlite,
         key: "sqlite",
+        description: "Persist rollout metadata to a local SQLite database.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::MemoryTool,
         key: "memories",
+        description: "Enable startup memory extraction and file-backed memory consolidation.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ExternalAgentMemoryImport,
         key: "external_agent_memory_import",
+        description: "Enable importing project-scoped memory from external agents.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::LocalThreadStoreCompression,
         key: "local_thread_store_compression",
+        description: "Compress cold local thread-store rollout files.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Chronicle,
         key: "chronicle",
+        description: "Enable the Chronicle sidecar for passive screen-context memories.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ApplyPatchFreeform,
         key: "apply_patch_freeform",
+        description: "Removed compatibility flag for the deleted apply_patch fallback feature.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ApplyPatchStreamingEvents,
         key: "apply_patch_streaming_events",
+        description: "Stream structured progress while apply_patch input is being generated.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ExecPermissionApprovals,
         key: "exec_permission_approvals",
+        description: "Allow exec tools to request additional permissions while staying sandboxed.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CodexHooks,
         key: "hooks",
+        description: "Enable Claude-style lifecycle hooks loaded from hooks.json files.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RequestPermissionsTool,
         key: "request_permissions_tool",
+        description: "Expose the built-in request_permissions tool.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::UseLinuxSandboxBwrap,
         key: "use_linux_sandbox_bwrap",
+        description: "Removed legacy Linux bubblewrap opt-in flag retained as a no-op so old wrappers and config can still parse it.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::UseLegacyLandlock,
         key: "use_legacy_landlock",
+        description: "Use the legacy Landlock Linux sandbox fallback instead of the default bubblewrap pipeline.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Deprecated,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RequestRule,
         key: "request_rule",
+        description: "Allow the model to request approval and propose exec rules.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::WindowsSandbox,
         key: "experimental_windows_sandbox",
+        description: "Enable Windows sandbox (restricted token) on Windows.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::WindowsSandboxElevated,
         key: "elevated_windows_sandbox",
+        description: "Use the elevated Windows sandbox pipeline (setup + runner).",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RemoteModels,
         key: "remote_models",
+        description: "Legacy remote models flag kept for backward compatibility.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::EnableRequestCompression,
         key: "enable_request_compression",
+        description: "Compress request bodies (zstd) when sending streaming requests to codex-backend.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::NetworkProxy,
         key: "network_proxy",
+        description: "Start the managed network proxy for sandboxed sessions.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Experimental {
             name: "Network proxy",
             menu_description: "Apply network proxy restrictions to sandboxed sessions that already have network access.",
             announcement: "NEW: Network proxy can now be enabled from /experimental. Restart Codex after enabling it.",
         },
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RespectSystemProxy,
         key: "respect_system_proxy",
+        description: "Respect host system proxy settings for Codex-owned network clients.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Collab,
         key: "multi_agent",
+        description: "Enable collab tools.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::MultiAgentV2,
         key: "multi_agent_v2",
+        description: "Enable task-path-based multi-agent routing.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::MultiAgentMode,
         key: "multi_agent_mode",
+        description: "Removed compatibility flag retained as a no-op.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::SpawnCsv,
         key: "enable_fanout",
+        description: "Removed compatibility flag for the deleted agent-job tools.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Apps,
         key: "apps",
+        description: "Enable apps.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::EnableMcpApps,
         key: "enable_mcp_apps",
+        description: "Enable MCP apps.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::AppsMcpPathOverride,
         key: "apps_mcp_path_override",
+        description: "Removed compatibility flag for the legacy Apps MCP path override.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ToolSearch,
         key: "tool_search",
+        description: "Removed compatibility flag retained as a no-op now that tool_search is always enabled.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ToolSearchAlwaysDeferMcpTools,
         key: "tool_search_always_defer_mcp_tools",
+        description: "Removed compatibility flag. MCP tools are always deferred when tool_search is available.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::NonPrefixedMcpToolNames,
         key: "non_prefixed_mcp_tool_names",
+        description: "Expose MCP model-visible namespaces without the legacy `mcp__` prefix.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::UnavailableDummyTools,
         key: "unavailable_dummy_tools",
+        description: "Removed compatibility flag for the deleted unavailable-tool placeholder backfill.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ToolSuggest,
         key: "tool_suggest",
+        description: "Enable discoverable tool suggestions for apps.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Plugins,
         key: "plugins",
+        description: "Enable plugins.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ExecutorCapabilityDiscovery,
         key: "executor_capability_discovery",
+        description: "Discover selected-root plugin and skill manifests through one high-level exec-server RPC.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::PluginHooks,
         key: "plugin_hooks",
+        description: "Removed compatibility flag for plugin-bundled lifecycle hooks.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::InAppBrowser,
         key: "in_app_browser",
+        description: "Allow the in-app browser pane in desktop apps.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::BrowserUse,
         key: "browser_use",
+        description: "Allow Browser Use agent integration in desktop apps.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::BrowserUseFullCdpAccess,
         key: "browser_use_full_cdp_access",
+        description: "Allow Browser Use integration to access the full Chrome DevTools Protocol surface.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::BrowserUseExternal,
         key: "browser_use_external",
+        description: "Allow Browser Use integration with external browsers.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ComputerUse,
         key: "computer_use",
+        description: "Allow Codex Computer Use.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RemotePlugin,
         key: "remote_plugin",
+        description: "Enable the PS-backed remote plugin catalog.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::PluginSharing,
         key: "plugin_sharing",
+        description: "Enable remote plugin sharing flows.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ExternalMigration,
         key: "external_migration",
+        description: "Removed compatibility flag retained as a no-op.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ImageGeneration,
         key: "image_generation",
+        description: "Enable extension-backed image generation.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ResizeAllImages,
         key: "resize_all_images",
+        description: "Removed compatibility flag for always-on centralized image preparation.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ItemIds,
         key: "item_ids",
+        description: "Generate Responses API item IDs for client-created history items.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ConcurrentReasoningSummaries,
         key: "concurrent_reasoning_summaries",
+        description: "Request sequential cutoff reasoning summary delivery.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::SkillMcpDependencyInstall,
         key: "skill_mcp_dependency_install",
+        description: "Allow prompting and installing missing MCP dependencies.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::SkillSearch,
         key: "skill_search",
+        description: "Run cheap skill-search methods in shadow mode and emit experiment metrics.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::SkillEnvVarDependencyPrompt,
         key: "skill_env_var_dependency_prompt",
+        description: "Removed compatibility flag for deleted skill env var dependency prompting.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::MentionsV2,
         key: "mentions_v2",
+        description: "Enable the unified mention popup used by default in the TUI.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Steer,
         key: "steer",
+        description: "Steer feature flag - when enabled, Enter submits immediately instead of queuing. Kept for config backward compatibility; behavior is always steer-enabled.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::DefaultModeRequestUserInput,
         key: "default_mode_request_user_input",
+        description: "Allow request_user_input in Default collaboration mode.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::TerminalVisualizationInstructions,
         key: "terminal_visualization_instructions",
+        description: "Add terminal-specific visualization guidance to TUI developer instructions.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::GuardianApproval,
         key: "guardian_approval",
+        description: "Enable automatic review for approval prompts.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Goals,
         key: "goals",
+        description: "Enable persisted thread goals and automatic goal continuation.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::TokenBudget,
         key: "token_budget",
+        description: "Add current context-window metadata to model-visible context.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RolloutBudget,
         key: "rollout_budget",
+        description: "Track and report a shared token budget across a session's agent threads.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CurrentTimeReminder,
         key: "current_time_reminder",
+        description: "Add current-time reminders to model-visible context.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::CollaborationModes,
         key: "collaboration_modes",
+        description: "Enable collaboration modes (Plan, Default). Kept for config backward compatibility; behavior is always collaboration-modes-enabled.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ToolCallMcpElicitation,
         key: "tool_call_mcp_elicitation",
+        description: "Route MCP tool approval prompts through the MCP elicitation request path.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::AuthElicitation,
         key: "auth_elicitation",
+        description: "Prompt Codex Apps connector auth failures through MCP URL elicitations.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Personality,
         key: "personality",
+        description: "Enable personality selection in the TUI.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::Artifact,
         key: "artifact",
+        description: "Enable native artifact tools.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::FastMode,
         key: "fast_mode",
+        description: "Enable Fast mode selection in the TUI and request layer.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RealtimeConversation,
         key: "realtime_conversation",
+        description: "Enable experimental realtime voice conversation mode in the TUI.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RemoteControl,
         key: "remote_control",
+        description: "Removed compatibility flag for the deleted remote control feature.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ImageDetailOriginal,
         key: "image_detail_original",
+        description: "Removed compatibility flag retained as a no-op so old wrappers can still pass `--enable image_detail_original`.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::TuiAppServer,
         key: "tui_app_server",
+        description: "Removed compatibility flag. The TUI now always uses the app-server implementation.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::PreventIdleSleep,
         key: "prevent_idle_sleep",
+        description: "Prevent idle system sleep while a turn is actively running.",
+        value_type: FeatureValueType::Bool,
         stage: if cfg!(any(
             target_os = "macos",
             target_os = "linux",
@@ -1348,42 +1778,68 @@ pub const FEATURES: &[FeatureSpec] = &[
             Stage::UnderDevelopment
         },
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::WorkspaceOwnerUsageNudge,
         key: "workspace_owner_usage_nudge",
+        description: "Removed compatibility flag retained as a no-op now that workspace owner usage nudges are always enabled.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ResponsesWebsockets,
         key: "responses_websockets",
+        description: "Legacy rollout flag for Responses API WebSocket transport experiments.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::ResponsesWebsocketsV2,
         key: "responses_websockets_v2",
+        description: "Legacy rollout flag for Responses API WebSocket transport v2 experiments.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Removed,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::RemoteCompactionV2,
         key: "remote_compaction_v2",
+        description: "Enable remote compaction v2 over the normal Responses API.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::UseAgentIdentity,
         key: "use_agent_identity",
+        description: "Use Agent Identity for ChatGPT-authenticated sessions.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::UnderDevelopment,
         default_enabled: false,
+        requires: &[],
+        conflicts_with: &[],
     },
     FeatureSpec {
         id: Feature::WorkspaceDependencies,
         key: "workspace_dependencies",
+        description: "Enable workspace dependency support.",
+        value_type: FeatureValueType::Bool,
         stage: Stage::Stable,
         default_enabled: true,
+        requires: &[],
+        conflicts_with: &[],
     },
 ];
 
@@ -1,7 +1,12 @@
 use crate::Feature;
 use crate::FeatureConfigSource;
+use crate::FeatureConstraintError;
+use crate::FeatureConstraintViolation;
 use crate::FeatureOverrides;
+use crate::FeatureSpec;
 use crate::FeatureToml;
+use crate::FeatureValue;
+use crate::FeatureValueType;
 use crate::Features;
 use crate::FeaturesToml;
 use crate::Stage;
@@ -204,14 +209,14 @@ fn apps_require_feature_flag_and_chatgpt_auth() {
 #[test]
 fn from_sources_applies_base_profile_and_overrides() {
     let mut base_entries = BTreeMap::new();
-    base_entries.insert("plugins".to_string(), true);
+    base_entries.insert("plugins".to_string(), FeatureValue::Bool(true));
     let base_features = FeaturesToml {
         entries: base_entries,
         ..Default::default()
     };
 
     let mut profile_entries = BTreeMap::new();
-    profile_entries.insert("code_mode_only".to_string(), true);
+    profile_entries.insert("code_mode_only".to_string(), FeatureValue::Bool(true));
     let profile_features = FeaturesToml {
         entries: profile_entries,
         ..Default::default()
@@ -545,3 +550,120 @@ code_mode = true
         message
     );
 }
+
+#[test]
+fn ranged_integer_feature_value_rejects_out_of_range_values() {
+    let value_type = FeatureValueType::Integer { min: 0, max: 100 };
+
+    assert_eq!(value_type.validate(&FeatureValue::Integer(25)), Ok(()));
+    assert_eq!(
+        value_type.validate(&FeatureValue::Integer(101)),
+        Err("expected an integer between 0 and 100, got 101".to_string())
+    );
+    assert_eq!(
+        value_type.validate(&FeatureValue::Bool(true)),
+        Err("expected an integer between 0 and 100, got true".to_string())
+    );
+}
+
+#[test]
+fn enum_feature_value_rejects_unknown_variants() {
+    let value_type = FeatureValueType::Enum(&["control", "treatment"]);
+
+    assert_eq!(
+        value_type.validate(&FeatureValue::String("treatment".to_string())),
+        Ok(())
+    );
+    assert_eq!(
+        value_type.validate(&FeatureValue::String("holdout".to_string())),
+        Err("expected one of control, treatment, got \"holdout\"".to_string())
+    );
+}
+
+#[test]
+fn boolean_features_accept_only_booleans() {
+    let features: FeaturesToml =
+        toml::from_str("shell_tool = false").expect("boolean feature should deserialize");
+    assert_eq!(
+        features.entries(),
+        BTreeMap::from([("shell_tool".to_string(), false)])
+    );
+
+    let err = toml::from_str::<FeaturesToml>("shell_tool = 1")
+        .expect_err("integer value for a boolean feature should be rejected");
+    assert!(
+        err.to_string()
+            .contains("invalid value for features.shell_tool: expected a boolean, got 1"),
+        "{err}"
+    );
+}
+
+fn constrained_spec(
+    id: Feature,
+    requires: &'static [Feature],
+    conflicts_with: &'static [Feature],
+) -> FeatureSpec {
+    FeatureSpec {
+        id,
+        key: id.key(),
+        description: "",
+        value_type: FeatureValueType::Bool,
+        stage: Stage::Stable,
+        default_enabled: false,
+        requires,
+        conflicts_with,
+    }
+}
+
+#[test]
+fn default_features_satisfy_declared_constraints() {
+    assert_eq!(Features::with_defaults().validate_constraints(), Ok(()));
+}
+
+#[test]
+fn satisfied_requirement_reports_no_violations() {
+    let specs = [constrained_spec(Feature::Apps, &[Feature::Plugins], &[])];
+    let mut features = Features::with_defaults();
+    features.enable(Feature::Apps).enable(Feature::Plugins);
+
+    assert_eq!(
+        crate::constraints::constraint_violations(&features, &specs),
+        Vec::new()
+    );
+}
+
+#[test]
+fn missing_requirement_and_conflict_are_reported_together() {
+    let specs = [
+        constrained_spec(Feature::Apps, &[Feature::Plugins], &[]),
+        constrained_spec(Feature::Goals, &[], &[Feature::Steer]),
+        constrained_spec(Feature::Steer, &[], &[Feature::Goals]),
+    ];
+    let mut features = Features::with_defaults();
+    features
+        .enable(Feature::Apps)
+        .disable(Feature::Plugins)
+        .enable(Feature::Goals)
+        .enable(Feature::Steer);
+
+    let error = FeatureConstraintError {
+        violations: crate::constraints::constraint_violations(&features, &specs),
+    };
+    assert_eq!(
+        error.violations,
+        vec![
+            FeatureConstraintViolation::MissingRequirement {
+                feature: Feature::Apps,
+                required: Feature::Plugins,
+            },
+            FeatureConstraintViolation::Conflict {
+                feature: Feature::Goals,
+                conflicting: Feature::Steer,
+            },
+        ]
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid [features] combination:\n  - `apps` requires `plugins` to be enabled\n  - `goals` cannot be enabled together with `steer`"
+    );
+}
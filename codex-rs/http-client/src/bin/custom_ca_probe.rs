@@ -9,6 +9,7 @@
 //! - multi-cert PEM bundles load,
 //! - error messages guide users when CA files are invalid.
 //! - optional HTTPS probes can complete a request through the constructed client.
+//! - `[network]` extra CA files are trusted once installed as process-wide settings.
 //!
 //! The detailed explanation of what "hermetic" means here lives in `codex_http_client::custom_ca`.
 //! This binary exists so the tests can exercise
@@ -19,6 +20,7 @@ use std::env;
 use std::process;
 use std::time::Duration;
 
+const PROBE_EXTRA_CA_ENV: &str = "CODEX_CUSTOM_CA_PROBE_EXTRA_CA";
 const PROBE_TLS13_ENV: &str = "CODEX_CUSTOM_CA_PROBE_TLS13";
 const PROBE_PROXY_ENV: &str = "CODEX_CUSTOM_CA_PROBE_PROXY";
 const PROBE_URL_ENV: &str = "CODEX_CUSTOM_CA_PROBE_URL";
@@ -45,6 +47,16 @@ fn main() {
 }
 
 async fn run_probe() -> Result<(), String> {
+    if let Some(extra_ca) = env::var_os(PROBE_EXTRA_CA_ENV) {
+        let settings = codex_http_client::HttpNetworkSettings::new(
+            /*proxy_url*/ None,
+            /*no_proxy*/ None,
+            vec![extra_ca.into()],
+            /*danger_accept_invalid_certs*/ false,
+        )
+        .map_err(|error| error.to_string())?;
+        codex_http_client::set_http_network_settings(settings);
+    }
     let proxy_url = env::var(PROBE_PROXY_ENV).ok();
     let target_url = env::var(PROBE_URL_ENV).ok();
     let mut builder = reqwest::Client::builder();
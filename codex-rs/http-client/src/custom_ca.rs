@@ -58,6 +58,8 @@ use thiserror::Error;
 use tracing::info;
 use tracing::warn;
 
+use crate::network_settings::http_network_settings;
+
 pub const CODEX_CA_CERT_ENV: &str = "CODEX_CA_CERTIFICATE";
 pub const SSL_CERT_FILE_ENV: &str = "SSL_CERT_FILE";
 const CA_CERT_HINT: &str = "If you set CODEX_CA_CERTIFICATE or SSL_CERT_FILE, ensure it points to a PEM file containing one or more CERTIFICATE blocks, or unset it to use system roots.";
@@ -225,11 +227,12 @@ pub fn build_reqwest_client_for_subprocess_tests(
 fn maybe_build_rustls_client_config_with_env(
     env_source: &dyn EnvSource,
 ) -> Result<Option<Arc<ClientConfig>>, BuildCustomCaTransportError> {
-    let Some(bundle) = env_source.configured_ca_bundle() else {
+    let bundle = env_source.configured_ca_bundle();
+    if bundle.is_none() && http_network_settings().extra_root_certificates().is_empty() {
         return Ok(None);
-    };
+    }
 
-    build_rustls_client_config(Some(&bundle)).map(Some)
+    build_rustls_client_config(bundle.as_ref()).map(Some)
 }
 
 fn build_rustls_client_config_with_env(
@@ -257,6 +260,12 @@ fn build_rustls_client_config(
         );
     }
     let _ = root_store.add_parsable_certificates(certs);
+    let _ = root_store.add_parsable_certificates(
+        http_network_settings()
+            .extra_root_certificates()
+            .iter()
+            .cloned(),
+    );
 
     if let Some(bundle) = bundle {
         let certificates = bundle.load_certificates()?;
@@ -295,8 +304,9 @@ fn build_rustls_client_config(
 /// certificate with that builder.
 fn build_reqwest_client_with_env(
     env_source: &dyn EnvSource,
-    mut builder: reqwest::ClientBuilder,
+    builder: reqwest::ClientBuilder,
 ) -> Result<reqwest::Client, BuildCustomCaTransportError> {
+    let mut builder = http_network_settings().apply_to_reqwest_builder(builder);
     if let Some(bundle) = env_source.configured_ca_bundle() {
         ensure_rustls_crypto_provider();
         info!(
@@ -369,6 +379,18 @@ fn build_reqwest_client_with_env(
 
 /// Abstracts environment access so tests can cover precedence rules without mutating process-wide
 /// variables.
+/// Loads every certificate from the PEM file at `path`, attributing errors to `source`.
+pub(crate) fn load_ca_certificates(
+    source: &'static str,
+    path: &Path,
+) -> Result<Vec<CertificateDer<'static>>, BuildCustomCaTransportError> {
+    ConfiguredCaBundle {
+        source_env: source,
+        path: path.to_path_buf(),
+    }
+    .load_certificates()
+}
+
 trait EnvSource {
     /// Returns the environment variable value for `key`, if this source considers it set.
     ///
@@ -1,3 +1,5 @@
+use crate::http_trace::TraceOptions;
+use crate::http_trace::execute_traced;
 use http::Error as HttpError;
 use http::HeaderMap;
 use http::HeaderName;
@@ -146,8 +148,17 @@ impl RequestBuilder {
 
     pub async fn send(self) -> Result<Response, reqwest::Error> {
         let headers = trace_headers();
-
-        match self.builder.headers(headers).send().await {
+        let (client, request) = self.builder.headers(headers).build_split();
+        let options = TraceOptions {
+            omit_bodies: self.request_logging == RequestLogging::Disabled,
+            ..TraceOptions::default()
+        };
+        let outcome = match request {
+            Ok(request) => execute_traced(&client, request, options).await,
+            Err(error) => Err(error),
+        };
+
+        match outcome {
             Ok(response) => {
                 if self.request_logging == RequestLogging::Enabled {
                     tracing::debug!(
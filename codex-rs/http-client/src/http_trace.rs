@@ -0,0 +1,473 @@
+//! Opt-in HTTP request tracing, enabled with `--trace-http` or `CODEX_HTTP_TRACE`.
+//!
+//! Once [`install_http_trace`] has run, every request sent through [`execute_traced`] (which
+//! [`crate::HttpClient`] and the ChatGPT backend client both use) appends one JSON line to a
+//! daily rotating file under `$CODEX_HOME/log/http/`. Each line records the method, URL, status
+//! or error, latency, and retry attempt; [`HttpTraceLevel::Bodies`] adds truncated request and
+//! response bodies.
+//!
+//! Secrets are redacted before anything is written: credential headers such as `Authorization`
+//! and `ChatGPT-Account-Id`, credential-looking query parameters and JSON fields, and URL
+//! passwords. Values seen in credential headers are remembered, so a bearer token that later
+//! shows up in a URL path or body is redacted there as well.
+
+use std::fmt;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use bytes::Bytes;
+use http::HeaderMap;
+use http::header::CONTENT_TYPE;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use tracing_appender::rolling::RollingFileAppender;
+use tracing_appender::rolling::Rotation;
+
+/// Environment variable that enables tracing when `--trace-http` is not passed.
+pub const CODEX_HTTP_TRACE_ENV_VAR: &str = "CODEX_HTTP_TRACE";
+
+const REDACTED: &str = "REDACTED";
+const LOG_FILE_PREFIX: &str = "http";
+const LOG_FILE_SUFFIX: &str = "jsonl";
+const MAX_LOG_FILES: usize = 7;
+/// Bodies longer than this are cut at the nearest character boundary.
+const MAX_BODY_BYTES: usize = 4096;
+/// Header values shorter than this are not remembered as secrets, so redacting them elsewhere
+/// cannot mangle unrelated text.
+const MIN_SECRET_LEN: usize = 8;
+
+const SENSITIVE_HEADERS: [&str; 6] = [
+    "authorization",
+    "proxy-authorization",
+    "chatgpt-account-id",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+const SENSITIVE_PARAMS: [&str; 5] = ["auth", "code", "key", "sig", "signature"];
+const SENSITIVE_NAME_PARTS: [&str; 6] = [
+    "token", "secret", "password", "api_key", "api-key", "apikey",
+];
+
+static HTTP_TRACER: OnceLock<HttpTracer> = OnceLock::new();
+
+/// How much of each request the trace records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HttpTraceLevel {
+    /// Method, URL, headers, status, latency, and retry attempt.
+    Requests,
+    /// Everything in [`HttpTraceLevel::Requests`] plus truncated bodies.
+    Bodies,
+}
+
+impl HttpTraceLevel {
+    /// Reads [`CODEX_HTTP_TRACE_ENV_VAR`]. Unset, empty, `0`, and `false` leave tracing off.
+    pub fn from_env() -> Option<Self> {
+        let value = std::env::var(CODEX_HTTP_TRACE_ENV_VAR).ok()?;
+        match value.trim() {
+            "" | "0" | "false" => None,
+            value => match value.parse() {
+                Ok(level) => Some(level),
+                Err(err) => {
+                    tracing::warn!("ignoring {CODEX_HTTP_TRACE_ENV_VAR}: {err}");
+                    None
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown HTTP trace level `{0}`; expected `requests` (or `1`) or `bodies` (or `2`)")]
+pub struct ParseHttpTraceLevelError(String);
+
+impl FromStr for HttpTraceLevel {
+    type Err = ParseHttpTraceLevelError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "requests" => Ok(Self::Requests),
+            "2" | "bodies" => Ok(Self::Bodies),
+            _ => Err(ParseHttpTraceLevelError(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for HttpTraceLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Requests => f.write_str("requests"),
+            Self::Bodies => f.write_str("bodies"),
+        }
+    }
+}
+
+/// Starts tracing requests at `level` into `codex_home/log/http/`, returning that directory.
+///
+/// Tracing is process-wide and can only be installed once; later calls keep the first
+/// installation and return its directory.
+pub fn install_http_trace(level: HttpTraceLevel, codex_home: &Path) -> io::Result<PathBuf> {
+    if let Some(tracer) = HTTP_TRACER.get() {
+        return Ok(tracer.dir.clone());
+    }
+    let dir = codex_home.join("log").join("http");
+    std::fs::create_dir_all(&dir)?;
+    let writer = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix(LOG_FILE_SUFFIX)
+        .max_log_files(MAX_LOG_FILES)
+        .build(&dir)
+        .map_err(io::Error::other)?;
+    let tracer = HttpTracer {
+        level,
+        dir,
+        writer: Mutex::new(writer),
+        secrets: Mutex::new(Vec::new()),
+    };
+    Ok(HTTP_TRACER.get_or_init(|| tracer).dir.clone())
+}
+
+/// Returns the installed trace level, or `None` when tracing is off.
+pub fn http_trace_level() -> Option<HttpTraceLevel> {
+    HTTP_TRACER.get().map(|tracer| tracer.level)
+}
+
+/// Redacts `secret` wherever it appears in later trace entries. Use this for configured
+/// credentials that may reach a URL or body before they are ever sent in a header.
+pub fn register_http_trace_secret(secret: &str) {
+    if let Some(tracer) = HTTP_TRACER.get()
+        && let Ok(mut secrets) = tracer.secrets.lock()
+    {
+        remember_secret(&mut secrets, secret);
+    }
+}
+
+/// Per-request details the trace cannot get from the request itself.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TraceOptions {
+    /// 1-based attempt number when the caller retries the request.
+    pub attempt: Option<u64>,
+    /// Reads the whole response body so it can be recorded. Leave this off for streaming
+    /// responses, which would otherwise be held until they finish.
+    pub buffer_response_body: bool,
+    /// Never records bodies for this request, e.g. for authentication endpoints.
+    pub omit_bodies: bool,
+}
+
+/// Sends `request` with `client`, recording it when tracing is installed.
+pub async fn execute_traced(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+    options: TraceOptions,
+) -> reqwest::Result<reqwest::Response> {
+    let Some(tracer) = HTTP_TRACER.get() else {
+        return client.execute(request).await;
+    };
+    let include_bodies = tracer.level == HttpTraceLevel::Bodies && !options.omit_bodies;
+    let method = request.method().to_string();
+    let url = request.url().to_string();
+    let request_headers = request.headers().clone();
+    let request_body = request
+        .body()
+        .filter(|_| include_bodies)
+        .and_then(reqwest::Body::as_bytes)
+        .map(Bytes::copy_from_slice);
+
+    let started = Instant::now();
+    let (outcome, response_body) = match client.execute(request).await {
+        Ok(response) if include_bodies && options.buffer_response_body => {
+            match buffer_response(response).await {
+                Ok((response, body)) => (Ok(response), Some(body)),
+                Err(err) => (Err(err), None),
+            }
+        }
+        outcome => (outcome, None),
+    };
+
+    let (status, error, response_headers) = match &outcome {
+        Ok(response) => (
+            Some(response.status().as_u16()),
+            None,
+            Some(response.headers()),
+        ),
+        Err(err) => (
+            err.status().map(|status| status.as_u16()),
+            Some(err.to_string()),
+            None,
+        ),
+    };
+    tracer.record(&TraceRecord {
+        method: &method,
+        url: &url,
+        attempt: options.attempt,
+        status,
+        error,
+        latency: started.elapsed(),
+        request_headers: &request_headers,
+        response_headers,
+        request_body: request_body.as_deref(),
+        response_body: response_body.as_deref(),
+    });
+    outcome
+}
+
+/// Reads the body of `response` and returns an equivalent response that replays it.
+async fn buffer_response(
+    response: reqwest::Response,
+) -> reqwest::Result<(reqwest::Response, Bytes)> {
+    let status = response.status();
+    let version = response.version();
+    let headers = response.headers().clone();
+    let body = response.bytes().await?;
+    let mut replay = http::Response::new(body.clone());
+    *replay.status_mut() = status;
+    *replay.version_mut() = version;
+    *replay.headers_mut() = headers;
+    Ok((reqwest::Response::from(replay), body))
+}
+
+struct HttpTracer {
+    level: HttpTraceLevel,
+    dir: PathBuf,
+    writer: Mutex<RollingFileAppender>,
+    /// Credential values seen so far, redacted wherever they appear.
+    secrets: Mutex<Vec<String>>,
+}
+
+impl HttpTracer {
+    fn record(&self, record: &TraceRecord<'_>) {
+        let mut entry = {
+            let Ok(mut secrets) = self.secrets.lock() else {
+                return;
+            };
+            trace_entry(record, &mut secrets)
+        };
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or_default();
+        if let Value::Object(fields) = &mut entry {
+            fields.insert("timestamp_ms".to_string(), json!(timestamp_ms));
+        }
+        if let Ok(mut writer) = self.writer.lock()
+            && let Err(err) = writeln!(writer, "{entry}")
+        {
+            tracing::debug!("failed to write HTTP trace entry: {err}");
+        }
+    }
+}
+
+struct TraceRecord<'a> {
+    method: &'a str,
+    url: &'a str,
+    attempt: Option<u64>,
+    status: Option<u16>,
+    error: Option<String>,
+    latency: Duration,
+    request_headers: &'a HeaderMap,
+    response_headers: Option<&'a HeaderMap>,
+    request_body: Option<&'a [u8]>,
+    response_body: Option<&'a [u8]>,
+}
+
+/// Builds the redacted JSON entry for `record`, first remembering any credentials it carries.
+fn trace_entry(record: &TraceRecord<'_>, secrets: &mut Vec<String>) -> Value {
+    learn_secrets(record.request_headers, secrets);
+    let request_content_type = content_type(record.request_headers);
+    let response_content_type = record.response_headers.and_then(content_type);
+
+    let mut entry = Map::new();
+    entry.insert("method".to_string(), json!(record.method));
+    entry.insert("url".to_string(), json!(redact_url(record.url, secrets)));
+    entry.insert("attempt".to_string(), json!(record.attempt));
+    entry.insert("status".to_string(), json!(record.status));
+    if let Some(error) = &record.error {
+        entry.insert("error".to_string(), json!(scrub_secrets(error, secrets)));
+    }
+    entry.insert("latency_ms".to_string(), json!(record.latency.as_millis()));
+    entry.insert(
+        "request_headers".to_string(),
+        redact_headers(record.request_headers, secrets),
+    );
+    if let Some(headers) = record.response_headers {
+        entry.insert(
+            "response_headers".to_string(),
+            redact_headers(headers, secrets),
+        );
+    }
+    if let Some(body) = record.request_body {
+        entry.insert(
+            "request_body".to_string(),
+            json!(redact_body(body, request_content_type.as_deref(), secrets)),
+        );
+    }
+    if let Some(body) = record.response_body {
+        entry.insert(
+            "response_body".to_string(),
+            json!(redact_body(body, response_content_type.as_deref(), secrets)),
+        );
+    }
+    Value::Object(entry)
+}
+
+fn content_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_ascii_lowercase)
+}
+
+/// Whether a header, parameter, or JSON field name looks like it holds a credential. Counts
+/// such as `max_tokens` or `x-ratelimit-remaining-tokens` do not.
+fn is_sensitive_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_NAME_PARTS.iter().any(|part| name.contains(part)) && !name.ends_with("tokens")
+}
+
+fn is_sensitive_header(name: &str) -> bool {
+    SENSITIVE_HEADERS.contains(&name.to_ascii_lowercase().as_str()) || is_sensitive_name(name)
+}
+
+fn is_sensitive_param(name: &str) -> bool {
+    SENSITIVE_PARAMS.contains(&name.to_ascii_lowercase().as_str()) || is_sensitive_name(name)
+}
+
+fn remember_secret(secrets: &mut Vec<String>, secret: &str) {
+    let secret = secret.trim();
+    if secret.len() >= MIN_SECRET_LEN && !secrets.iter().any(|known| known == secret) {
+        secrets.push(secret.to_string());
+    }
+}
+
+/// Remembers the values of credential headers, without their `Bearer`/`Basic` scheme.
+fn learn_secrets(headers: &HeaderMap, secrets: &mut Vec<String>) {
+    for (name, value) in headers {
+        if !is_sensitive_header(name.as_str()) {
+            continue;
+        }
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+        let credential = value
+            .split_once(' ')
+            .filter(|(scheme, _)| {
+                scheme.eq_ignore_ascii_case("bearer") || scheme.eq_ignore_ascii_case("basic")
+            })
+            .map_or(value, |(_, credential)| credential);
+        remember_secret(secrets, credential);
+    }
+}
+
+fn scrub_secrets(text: &str, secrets: &[String]) -> String {
+    secrets.iter().fold(text.to_string(), |text, secret| {
+        text.replace(secret, REDACTED)
+    })
+}
+
+fn redact_headers(headers: &HeaderMap, secrets: &[String]) -> Value {
+    let mut redacted = Map::new();
+    for (name, value) in headers {
+        let value = if is_sensitive_header(name.as_str()) {
+            REDACTED.to_string()
+        } else {
+            scrub_secrets(&String::from_utf8_lossy(value.as_bytes()), secrets)
+        };
+        match redacted.get_mut(name.as_str()) {
+            Some(Value::String(existing)) => {
+                existing.push_str(", ");
+                existing.push_str(&value);
+            }
+            _ => {
+                redacted.insert(name.as_str().to_string(), Value::String(value));
+            }
+        }
+    }
+    Value::Object(redacted)
+}
+
+/// Redacts the URL password, credential-looking query and fragment parameters, and known
+/// secrets anywhere else in the URL. The rest of the URL keeps its original encoding.
+fn redact_url(url: &str, secrets: &[String]) -> String {
+    let Ok(mut parsed) = reqwest::Url::parse(url) else {
+        return scrub_secrets(url, secrets);
+    };
+    if parsed.password().is_some() {
+        let _ = parsed.set_password(Some(REDACTED));
+    }
+    if let Some(query) = parsed.query().map(redact_pairs) {
+        parsed.set_query(Some(&query));
+    }
+    if let Some(fragment) = parsed.fragment().map(redact_pairs) {
+        parsed.set_fragment(Some(&fragment));
+    }
+    scrub_secrets(parsed.as_str(), secrets)
+}
+
+/// Redacts the values of credential-looking keys in `a=1&b=2` pairs.
+fn redact_pairs(pairs: &str) -> String {
+    pairs
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_sensitive_param(key) => format!("{key}={REDACTED}"),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn redact_body(body: &[u8], content_type: Option<&str>, secrets: &[String]) -> String {
+    let text = String::from_utf8_lossy(body);
+    let redacted = if let Ok(mut value) = serde_json::from_str::<Value>(&text) {
+        redact_json(&mut value);
+        value.to_string()
+    } else if content_type
+        .is_some_and(|content_type| content_type.starts_with("application/x-www-form-urlencoded"))
+    {
+        redact_pairs(&text)
+    } else {
+        text.into_owned()
+    };
+    truncate_body(scrub_secrets(&redacted, secrets))
+}
+
+fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                if is_sensitive_name(key) && field.is_string() {
+                    *field = json!(REDACTED);
+                } else {
+                    redact_json(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_json),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+}
+
+fn truncate_body(body: String) -> String {
+    if body.len() <= MAX_BODY_BYTES {
+        return body;
+    }
+    let mut end = MAX_BODY_BYTES;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}… ({} bytes total)", &body[..end], body.len())
+}
+
+#[cfg(test)]
+#[path = "http_trace_tests.rs"]
+mod tests;
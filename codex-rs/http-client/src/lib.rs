@@ -3,6 +3,8 @@ mod chatgpt_hosts;
 mod custom_ca;
 mod default_client;
 mod error;
+mod http_trace;
+mod network_settings;
 mod outbound_proxy;
 mod request;
 mod transport;
@@ -24,6 +26,18 @@ pub use crate::default_client::HttpClient;
 pub use crate::default_client::RequestBuilder;
 pub use crate::error::StreamError;
 pub use crate::error::TransportError;
+pub use crate::http_trace::CODEX_HTTP_TRACE_ENV_VAR;
+pub use crate::http_trace::HttpTraceLevel;
+pub use crate::http_trace::ParseHttpTraceLevelError;
+pub use crate::http_trace::TraceOptions;
+pub use crate::http_trace::execute_traced;
+pub use crate::http_trace::http_trace_level;
+pub use crate::http_trace::install_http_trace;
+pub use crate::http_trace::register_http_trace_secret;
+pub use crate::network_settings::HttpNetworkSettings;
+pub use crate::network_settings::HttpNetworkSettingsError;
+pub use crate::network_settings::http_network_settings;
+pub use crate::network_settings::set_http_network_settings;
 pub use crate::outbound_proxy::BuildRouteAwareHttpClientError;
 pub use crate::outbound_proxy::ClientRouteClass;
 pub use crate::outbound_proxy::HttpClientFactory;
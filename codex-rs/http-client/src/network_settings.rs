@@ -0,0 +1,199 @@
+//! Outbound HTTP settings from the `[network]` config table.
+//!
+//! Applications resolve these once from config and install them with
+//! [`set_http_network_settings`], next to the other process-wide client defaults. Every client
+//! built through [`crate::build_reqwest_client_with_custom_ca`] (including route-aware clients
+//! from [`crate::HttpClientFactory`]) then shares one proxy, trust-root, and certificate policy,
+//! and secure websocket connections pick up the extra trust roots as well.
+//!
+//! Validation happens in [`HttpNetworkSettings::new`] so a bad proxy URL or CA file fails config
+//! loading with the offending value named, instead of surfacing later on the first request.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use rustls_pki_types::CertificateDer;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::custom_ca::BuildCustomCaTransportError;
+use crate::custom_ca::load_ca_certificates;
+
+/// Names the config key that selected an extra CA file in errors and logs.
+const EXTRA_CA_CERTS_SOURCE: &str = "network.extra_ca_certs";
+
+static HTTP_NETWORK_SETTINGS: RwLock<Option<HttpNetworkSettings>> = RwLock::new(None);
+
+#[derive(Debug, Error)]
+pub enum HttpNetworkSettingsError {
+    #[error("Invalid network.proxy_url: {0}")]
+    InvalidProxyUrl(#[source] reqwest::Error),
+
+    #[error(transparent)]
+    ExtraCaCerts(#[from] BuildCustomCaTransportError),
+}
+
+/// Validated `[network]` settings shared by every outbound HTTP client.
+///
+/// The proxy URL and no-proxy list are redacted from `Debug` output because they may contain
+/// credentials or private hostnames.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct HttpNetworkSettings {
+    proxy_url: Option<String>,
+    no_proxy: Option<String>,
+    extra_ca_certs: Vec<PathBuf>,
+    extra_root_certificates: Vec<CertificateDer<'static>>,
+    danger_accept_invalid_certs: bool,
+    backend_connect_timeout: Option<Duration>,
+    backend_request_timeout: Option<Duration>,
+}
+
+impl fmt::Debug for HttpNetworkSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpNetworkSettings")
+            .field("proxy_url", &self.proxy_url.as_ref().map(|_| "<redacted>"))
+            .field("no_proxy", &self.no_proxy.as_ref().map(|_| "<redacted>"))
+            .field("extra_ca_certs", &self.extra_ca_certs)
+            .field(
+                "danger_accept_invalid_certs",
+                &self.danger_accept_invalid_certs,
+            )
+            .field("backend_connect_timeout", &self.backend_connect_timeout)
+            .field("backend_request_timeout", &self.backend_request_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpNetworkSettings {
+    /// Validates `proxy_url` and loads every certificate from `extra_ca_certs`, failing with the
+    /// path of the first file that is missing or does not hold usable PEM certificates.
+    pub fn new(
+        proxy_url: Option<String>,
+        no_proxy: Option<String>,
+        extra_ca_certs: Vec<PathBuf>,
+        danger_accept_invalid_certs: bool,
+    ) -> Result<Self, HttpNetworkSettingsError> {
+        if let Some(proxy_url) = proxy_url.as_deref() {
+            reqwest::Proxy::all(proxy_url).map_err(HttpNetworkSettingsError::InvalidProxyUrl)?;
+        }
+        let mut extra_root_certificates = Vec::new();
+        for path in &extra_ca_certs {
+            let certificates = load_ca_certificates(EXTRA_CA_CERTS_SOURCE, path)?;
+            for (idx, certificate) in certificates.iter().enumerate() {
+                // Reject certificates reqwest cannot register now, so applying the settings to a
+                // client builder later cannot fail.
+                if let Err(source) = reqwest::Certificate::from_der(certificate.as_ref()) {
+                    return Err(BuildCustomCaTransportError::RegisterCertificate {
+                        source_env: EXTRA_CA_CERTS_SOURCE,
+                        path: path.clone(),
+                        certificate_index: idx + 1,
+                        source,
+                    }
+                    .into());
+                }
+            }
+            extra_root_certificates.extend(certificates);
+        }
+        Ok(Self {
+            proxy_url,
+            no_proxy,
+            extra_ca_certs,
+            extra_root_certificates,
+            danger_accept_invalid_certs,
+            backend_connect_timeout: None,
+            backend_request_timeout: None,
+        })
+    }
+
+    /// Sets the default connect and overall request timeouts for ChatGPT backend clients. `None`
+    /// keeps the backend client's built-in default.
+    pub fn with_backend_timeouts(
+        mut self,
+        connect_timeout: Option<Duration>,
+        request_timeout: Option<Duration>,
+    ) -> Self {
+        self.backend_connect_timeout = connect_timeout;
+        self.backend_request_timeout = request_timeout;
+        self
+    }
+
+    pub fn proxy_url(&self) -> Option<&str> {
+        self.proxy_url.as_deref()
+    }
+
+    pub fn no_proxy(&self) -> Option<&str> {
+        self.no_proxy.as_deref()
+    }
+
+    pub fn extra_ca_certs(&self) -> &[PathBuf] {
+        &self.extra_ca_certs
+    }
+
+    pub fn danger_accept_invalid_certs(&self) -> bool {
+        self.danger_accept_invalid_certs
+    }
+
+    pub fn backend_connect_timeout(&self) -> Option<Duration> {
+        self.backend_connect_timeout
+    }
+
+    pub fn backend_request_timeout(&self) -> Option<Duration> {
+        self.backend_request_timeout
+    }
+
+    pub(crate) fn extra_root_certificates(&self) -> &[CertificateDer<'static>] {
+        &self.extra_root_certificates
+    }
+
+    /// Layers these settings onto `builder`. A configured proxy replaces reqwest's environment
+    /// proxy detection for every destination outside `no_proxy`.
+    pub(crate) fn apply_to_reqwest_builder(
+        &self,
+        mut builder: reqwest::ClientBuilder,
+    ) -> reqwest::ClientBuilder {
+        if let Some(proxy_url) = self.proxy_url.as_deref()
+            && let Ok(proxy) = reqwest::Proxy::all(proxy_url)
+        {
+            let no_proxy = self
+                .no_proxy
+                .as_deref()
+                .and_then(reqwest::NoProxy::from_string);
+            builder = builder.proxy(proxy.no_proxy(no_proxy));
+        }
+        if !self.extra_root_certificates.is_empty() {
+            builder = builder.use_rustls_tls();
+            for certificate in &self.extra_root_certificates {
+                if let Ok(certificate) = reqwest::Certificate::from_der(certificate.as_ref()) {
+                    builder = builder.add_root_certificate(certificate);
+                }
+            }
+        }
+        if self.danger_accept_invalid_certs {
+            warn!(
+                "TLS certificate verification is disabled by network.danger_accept_invalid_certs"
+            );
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        builder
+    }
+}
+
+/// Installs the `[network]` settings used by every HTTP client built after this call.
+pub fn set_http_network_settings(settings: HttpNetworkSettings) {
+    let Ok(mut guard) = HTTP_NETWORK_SETTINGS.write() else {
+        warn!("Failed to acquire HTTP network settings lock");
+        return;
+    };
+    *guard = Some(settings);
+}
+
+/// Returns the installed settings, or the defaults when none were installed.
+pub fn http_network_settings() -> HttpNetworkSettings {
+    HTTP_NETWORK_SETTINGS
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone())
+        .unwrap_or_default()
+}
@@ -18,6 +18,7 @@ use tokio::sync::Semaphore;
 use crate::custom_ca::BuildCustomCaTransportError;
 use crate::custom_ca::build_reqwest_client_with_custom_ca;
 use crate::default_client::HttpClient;
+use crate::network_settings::http_network_settings;
 use sha2::Digest;
 use sha2::Sha256;
 use thiserror::Error;
@@ -164,6 +165,9 @@ impl HttpClientFactory {
     /// resolution is unavailable, explicit environment settings are resolved before falling back
     /// to a direct route.
     pub fn resolve_proxy_route(&self, request_url: &str) -> OutboundProxyRoute {
+        if let Some(route) = configured_proxy_route() {
+            return route;
+        }
         resolve_proxy_route(
             &ProcessEnv,
             request_url,
@@ -177,6 +181,9 @@ impl HttpClientFactory {
         &self,
         request_url: String,
     ) -> io::Result<OutboundProxyRoute> {
+        if let Some(route) = configured_proxy_route() {
+            return Ok(route);
+        }
         if matches!(
             self.outbound_proxy_policy,
             OutboundProxyPolicy::ReqwestDefault
@@ -382,17 +389,32 @@ fn build_reqwest_client_for_route(
     route_class: ClientRouteClass,
     outbound_proxy_policy: OutboundProxyPolicy,
 ) -> Result<reqwest::Client, BuildRouteAwareHttpClientError> {
-    let builder = configure_proxy_for_route(
-        &ProcessEnv,
-        builder,
-        request_url,
-        route_class,
-        outbound_proxy_policy,
-        resolve_system_proxy,
-    )?;
+    // `network.proxy_url` is applied by the shared client builder and takes precedence over
+    // system and environment discovery.
+    let builder = if configured_proxy_route().is_some() {
+        builder
+    } else {
+        configure_proxy_for_route(
+            &ProcessEnv,
+            builder,
+            request_url,
+            route_class,
+            outbound_proxy_policy,
+            resolve_system_proxy,
+        )?
+    };
     build_reqwest_client_with_custom_ca(builder).map_err(Into::into)
 }
 
+/// Returns the route for `network.proxy_url` when one is configured.
+fn configured_proxy_route() -> Option<OutboundProxyRoute> {
+    let settings = http_network_settings();
+    settings.proxy_url().map(|url| OutboundProxyRoute::Proxy {
+        url: url.to_string(),
+        no_proxy: settings.no_proxy().map(str::to_string),
+    })
+}
+
 fn configure_proxy_for_route(
     env: &dyn EnvSource,
     builder: reqwest::ClientBuilder,
@@ -39,6 +39,7 @@ use std::time::Instant;
 use tempfile::TempDir;
 
 const CODEX_CA_CERT_ENV: &str = "CODEX_CA_CERTIFICATE";
+const PROBE_EXTRA_CA_ENV: &str = "CODEX_CUSTOM_CA_PROBE_EXTRA_CA";
 const PROBE_PROXY_ENV: &str = "CODEX_CUSTOM_CA_PROBE_PROXY";
 const PROBE_TLS13_ENV: &str = "CODEX_CUSTOM_CA_PROBE_TLS13";
 const PROBE_URL_ENV: &str = "CODEX_CUSTOM_CA_PROBE_URL";
@@ -94,6 +95,7 @@ fn probe_command() -> Command {
     // `Command` inherits the parent environment by default, so scrub CA-related variables first or
     // these tests can accidentally pass/fail based on the developer shell or CI runner.
     cmd.env_remove(CODEX_CA_CERT_ENV);
+    cmd.env_remove(PROBE_EXTRA_CA_ENV);
     cmd.env_remove(PROBE_PROXY_ENV);
     cmd.env_remove(PROBE_TLS13_ENV);
     cmd.env_remove(PROBE_URL_ENV);
@@ -454,6 +456,30 @@ fn posts_to_tls13_server_using_custom_ca_bundle() {
     assert_token_exchange_request(&request);
 }
 
+#[test]
+fn posts_to_tls13_server_using_network_extra_ca_certs() {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let server = spawn_tls13_test_server();
+    let cert_path = write_cert_file(&temp_dir, "extra-ca.pem", &server.ca_cert_pem);
+
+    let output = run_probe_posting_to_tls13_server(
+        &[(PROBE_EXTRA_CA_ENV, cert_path.as_path())],
+        &server.url,
+    );
+    let server_result = server.request_rx.recv_timeout(Duration::from_secs(5));
+
+    assert!(
+        output.status.success(),
+        "custom_ca_probe failed\nstdout:\n{}\nstderr:\n{}\nserver:\n{server_result:?}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let request = server_result
+        .expect("TLS test server should report a request")
+        .expect("TLS test server should accept the probe request");
+    assert_token_exchange_request(&request);
+}
+
 #[test]
 fn posts_to_token_origin_through_tls_intercepting_proxy_with_custom_ca_bundle() {
     let temp_dir = TempDir::new().expect("tempdir");
@@ -517,6 +543,24 @@ fn rejects_malformed_pem_with_hint() {
     assert!(stderr.contains("SSL_CERT_FILE"));
 }
 
+#[test]
+fn rejects_malformed_network_extra_ca_certs_naming_the_file() {
+    let temp_dir = TempDir::new().expect("tempdir");
+    let cert_path = write_cert_file(
+        &temp_dir,
+        "malformed-extra.pem",
+        "-----BEGIN CERTIFICATE-----\nMIIBroken",
+    );
+
+    let output = run_probe(&[(PROBE_EXTRA_CA_ENV, cert_path.as_path())]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("failed to parse PEM file"));
+    assert!(stderr.contains("network.extra_ca_certs"));
+    assert!(stderr.contains(&cert_path.display().to_string()));
+}
+
 #[test]
 fn accepts_openssl_trusted_certificate() {
     let temp_dir = TempDir::new().expect("tempdir");
@@ -0,0 +1,41 @@
+#![allow(clippy::expect_used)]
+//! End-to-end coverage for HTTP trace redaction. Tracing is process-wide, so this lives in its
+//! own test binary instead of beside the unit tests.
+
+use std::net::TcpListener;
+
+use codex_http_client::HttpTraceLevel;
+use codex_http_client::TraceOptions;
+use codex_http_client::execute_traced;
+use codex_http_client::install_http_trace;
+use codex_http_client::register_http_trace_secret;
+use pretty_assertions::assert_eq;
+
+const CONFIGURED_TOKEN: &str = "configured-provider-token-0123456789";
+
+#[tokio::test]
+async fn registered_secrets_never_reach_the_trace_file() -> anyhow::Result<()> {
+    let codex_home = tempfile::tempdir()?;
+    let trace_dir = install_http_trace(HttpTraceLevel::Bodies, codex_home.path())?;
+    register_http_trace_secret(CONFIGURED_TOKEN);
+
+    // Nothing listens on the port once the listener is dropped, so the request fails fast
+    // but is still recorded with its URL and body.
+    let addr = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+    let client = reqwest::Client::new();
+    let request = client
+        .post(format!("http://{addr}/v1/responses?q={CONFIGURED_TOKEN}"))
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(format!(r#"{{"input":"{CONFIGURED_TOKEN}"}}"#))
+        .build()?;
+    let outcome = execute_traced(&client, request, TraceOptions::default()).await;
+    assert_eq!(outcome.is_err(), true);
+
+    let mut trace = String::new();
+    for entry in std::fs::read_dir(&trace_dir)? {
+        trace.push_str(&std::fs::read_to_string(entry?.path())?);
+    }
+    assert_eq!(trace.lines().count(), 1);
+    assert_eq!(trace.contains(CONFIGURED_TOKEN), false);
+    Ok(())
+}
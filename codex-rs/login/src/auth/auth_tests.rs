@@ -1055,6 +1055,36 @@ async fn external_bearer_only_auth_manager_disables_auto_refresh_when_interval_i
     assert_eq!(second.as_deref(), Some("provider-token"));
 }
 
+#[tokio::test]
+async fn external_bearer_only_auth_manager_reruns_command_when_token_expires() {
+    let script = ProviderAuthScript::new(&[
+        r#"{"token": "short-lived-token", "expires_at": "2000-01-01T00:00:00Z"}"#,
+        r#"{"token": "long-lived-token", "expires_at": "2999-01-01T00:00:00Z"}"#,
+        "unused-token",
+    ])
+    .unwrap();
+    let manager = AuthManager::external_bearer_only(script.auth_config());
+
+    let mut tokens = Vec::new();
+    for _ in 0..3 {
+        tokens.push(
+            manager
+                .auth()
+                .await
+                .and_then(|auth| auth.api_key().map(str::to_string)),
+        );
+    }
+
+    assert_eq!(
+        tokens,
+        vec![
+            Some("short-lived-token".to_string()),
+            Some("long-lived-token".to_string()),
+            Some("long-lived-token".to_string()),
+        ]
+    );
+}
+
 #[tokio::test]
 async fn external_bearer_only_auth_manager_returns_none_when_command_fails() {
     let script = ProviderAuthScript::new_failing().unwrap();
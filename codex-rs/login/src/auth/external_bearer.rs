@@ -2,13 +2,18 @@ use super::manager::CodexAuth;
 use super::manager::ExternalAuth;
 use super::manager::ExternalAuthFuture;
 use super::manager::ExternalAuthRefreshContext;
+use chrono::DateTime;
+use chrono::Utc;
+use codex_http_client::register_http_trace_secret;
 use codex_protocol::config_types::ModelProviderAuthInfo;
+use serde::Deserialize;
 use std::fmt;
 use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Stdio;
 use std::sync::Arc;
+use std::time::Duration;
 use std::time::Instant;
 use tokio::process::Command;
 use tokio::sync::Mutex;
@@ -32,33 +37,25 @@ impl BearerTokenRefresher {
     async fn resolve(&self) -> io::Result<CodexAuth> {
         let access_token = {
             let mut cached = self.state.cached_token.lock().await;
-            if let Some(cached_token) = cached.as_ref() {
-                let should_use_cached_token = match self.state.config.refresh_interval() {
-                    Some(refresh_interval) => cached_token.fetched_at.elapsed() < refresh_interval,
-                    None => true,
-                };
-                if should_use_cached_token {
-                    return Ok(CodexAuth::from_api_key(cached_token.access_token.as_str()));
-                }
+            if let Some(cached_token) = cached.as_ref()
+                && cached_token.is_fresh(self.state.config.refresh_interval())
+            {
+                return Ok(CodexAuth::from_api_key(cached_token.access_token.as_str()));
             }
 
-            let access_token = run_provider_auth_command(&self.state.config).await?;
-            *cached = Some(CachedExternalBearerToken {
-                access_token: access_token.clone(),
-                fetched_at: Instant::now(),
-            });
+            let token = run_provider_auth_command(&self.state.config).await?;
+            let access_token = token.access_token.clone();
+            *cached = Some(token);
             access_token
         };
         Ok(CodexAuth::from_api_key(access_token.as_str()))
     }
 
     async fn refresh(&self, _context: ExternalAuthRefreshContext) -> io::Result<CodexAuth> {
-        let access_token = run_provider_auth_command(&self.state.config).await?;
+        let token = run_provider_auth_command(&self.state.config).await?;
+        let access_token = token.access_token.clone();
         let mut cached = self.state.cached_token.lock().await;
-        *cached = Some(CachedExternalBearerToken {
-            access_token: access_token.clone(),
-            fetched_at: Instant::now(),
-        });
+        *cached = Some(token);
         Ok(CodexAuth::from_api_key(access_token.as_str()))
     }
 }
@@ -97,9 +94,33 @@ impl ExternalBearerAuthState {
 struct CachedExternalBearerToken {
     access_token: String,
     fetched_at: Instant,
+    /// Expiry the command reported alongside the token, if any.
+    expires_at: Option<Instant>,
 }
 
-async fn run_provider_auth_command(config: &ModelProviderAuthInfo) -> io::Result<String> {
+impl CachedExternalBearerToken {
+    /// A token is reused until `refresh_interval` elapses or its reported expiry passes,
+    /// whichever comes first. The expiry applies even when proactive refresh is disabled.
+    fn is_fresh(&self, refresh_interval: Option<Duration>) -> bool {
+        refresh_interval.is_none_or(|refresh_interval| self.fetched_at.elapsed() < refresh_interval)
+            && self
+                .expires_at
+                .is_none_or(|expires_at| Instant::now() < expires_at)
+    }
+}
+
+/// JSON a provider auth command may print instead of a bare token, e.g.
+/// `{"token": "...", "expires_at": "2026-01-01T00:00:00Z"}`.
+#[derive(Deserialize)]
+struct ProviderAuthCommandOutput {
+    token: String,
+    #[serde(default)]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+async fn run_provider_auth_command(
+    config: &ModelProviderAuthInfo,
+) -> io::Result<CachedExternalBearerToken> {
     let program = resolve_provider_auth_program(&config.command, &config.cwd)?;
     let mut command = Command::new(&program);
     command
@@ -146,15 +167,41 @@ async fn run_provider_auth_command(config: &ModelProviderAuthInfo) -> io::Result
             config.command
         ))
     })?;
-    let access_token = stdout.trim().to_string();
+    let token = parse_provider_auth_output(&config.command, &stdout)?;
+    register_http_trace_secret(&token.access_token);
+    Ok(token)
+}
+
+/// Reads the command's stdout as a bare token, or as [`ProviderAuthCommandOutput`] when it
+/// prints a JSON object.
+fn parse_provider_auth_output(
+    command: &str,
+    stdout: &str,
+) -> io::Result<CachedExternalBearerToken> {
+    let stdout = stdout.trim();
+    let (access_token, expires_at) = if stdout.starts_with('{') {
+        let output: ProviderAuthCommandOutput = serde_json::from_str(stdout).map_err(|err| {
+            io::Error::other(format!(
+                "provider auth command `{command}` printed invalid JSON: {err}"
+            ))
+        })?;
+        (output.token.trim().to_string(), output.expires_at)
+    } else {
+        (stdout.to_string(), None)
+    };
     if access_token.is_empty() {
         return Err(io::Error::other(format!(
-            "provider auth command `{}` produced an empty token",
-            config.command
+            "provider auth command `{command}` produced an empty token"
         )));
     }
 
-    Ok(access_token)
+    let fetched_at = Instant::now();
+    Ok(CachedExternalBearerToken {
+        access_token,
+        fetched_at,
+        expires_at: expires_at
+            .map(|expires_at| fetched_at + (expires_at - Utc::now()).to_std().unwrap_or_default()),
+    })
 }
 
 fn resolve_provider_auth_program(command: &str, cwd: &Path) -> io::Result<PathBuf> {
@@ -169,3 +216,7 @@ fn resolve_provider_auth_program(command: &str, cwd: &Path) -> io::Result<PathBu
 
     Ok(PathBuf::from(command))
 }
+
+#[cfg(test)]
+#[path = "external_bearer_tests.rs"]
+mod tests;
@@ -0,0 +1,55 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn parsed(stdout: &str) -> io::Result<(String, bool, bool)> {
+    let token = parse_provider_auth_output("mycorp-token", stdout)?;
+    Ok((
+        token.access_token.clone(),
+        token.expires_at.is_some(),
+        token.is_fresh(/*refresh_interval*/ None),
+    ))
+}
+
+#[test]
+fn provider_auth_output_accepts_bare_tokens_and_json() -> io::Result<()> {
+    assert_eq!(
+        [
+            parsed("bare-token\n")?,
+            parsed(r#"{"token": "json-token"}"#)?,
+            parsed(r#"{"token": "fresh-token", "expires_at": "2999-01-01T00:00:00Z"}"#)?,
+            parsed(r#"{"token": "expired-token", "expires_at": "2000-01-01T00:00:00Z"}"#)?,
+        ],
+        [
+            ("bare-token".to_string(), false, true),
+            ("json-token".to_string(), false, true),
+            ("fresh-token".to_string(), true, true),
+            ("expired-token".to_string(), true, false),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn provider_auth_output_rejects_malformed_json_and_empty_tokens() {
+    let error_message = |stdout: &str| {
+        parse_provider_auth_output("mycorp-token", stdout)
+            .err()
+            .map(|err| err.to_string())
+    };
+
+    assert_eq!(
+        [
+            error_message(r#"{"expires_at": "2999-01-01T00:00:00Z"}"#),
+            error_message(r#"{"token": "  "}"#),
+            error_message("\n"),
+        ],
+        [
+            Some(
+                "provider auth command `mycorp-token` printed invalid JSON: missing field `token` at line 1 column 38"
+                    .to_string()
+            ),
+            Some("provider auth command `mycorp-token` produced an empty token".to_string()),
+            Some("provider auth command `mycorp-token` produced an empty token".to_string()),
+        ]
+    );
+}
@@ -18,6 +18,7 @@ use std::sync::atomic::Ordering;
 use std::time::Duration;
 use std::time::Instant;
 use tokio::sync::Semaphore;
+use tokio::sync::broadcast;
 use tokio::sync::watch;
 use tracing::instrument;
 
@@ -38,6 +39,7 @@ use super::agent_identity::require_agent_identity_authapi_base_url;
 use super::agent_identity::verified_record_from_jwt;
 use super::external_bearer::BearerTokenRefresher;
 use super::revoke::revoke_auth_tokens;
+use super::token_refresh_scheduler::TokenRefreshScheduler;
 use crate::auth::AuthHeaders;
 pub use crate::auth::agent_identity::AgentIdentityAuth;
 pub use crate::auth::agent_identity::AgentIdentityAuthError;
@@ -1779,6 +1781,7 @@ pub struct AuthManager {
     agent_identity_bootstrap_cooldown: Mutex<AgentIdentityBootstrapCooldown>,
     external_auth: RwLock<Option<Arc<dyn ExternalAuth>>>,
     auth_route_config: Option<AuthRouteConfig>,
+    token_refresh_scheduler: TokenRefreshScheduler,
 }
 
 /// Configuration view required to construct a shared [`AuthManager`].
@@ -1788,8 +1791,9 @@ pub struct AuthManager {
 /// `codex_core::config::Config`, but this trait keeps `codex-login` independent
 /// from `codex-core`.
 pub trait AuthManagerConfig {
-    /// Returns the Codex home directory used for auth storage.
-    fn codex_home(&self) -> PathBuf;
+    /// Returns the directory auth storage reads and writes: `CODEX_HOME` for the default auth
+    /// profile, or the resolved named profile's directory.
+    fn auth_home(&self) -> PathBuf;
 
     /// Returns the CLI auth credential storage mode for auth loading.
     fn cli_auth_credentials_store_mode(&self) -> AuthCredentialsStoreMode;
@@ -1881,6 +1885,7 @@ impl AuthManager {
             agent_identity_bootstrap_cooldown: Mutex::default(),
             external_auth: RwLock::new(None),
             auth_route_config,
+            token_refresh_scheduler: TokenRefreshScheduler::default(),
         }
     }
 
@@ -1907,6 +1912,7 @@ impl AuthManager {
             agent_identity_bootstrap_cooldown: Mutex::default(),
             external_auth: RwLock::new(None),
             auth_route_config: None,
+            token_refresh_scheduler: TokenRefreshScheduler::default(),
         })
     }
 
@@ -1932,6 +1938,7 @@ impl AuthManager {
             agent_identity_bootstrap_cooldown: Mutex::default(),
             external_auth: RwLock::new(None),
             auth_route_config: None,
+            token_refresh_scheduler: TokenRefreshScheduler::default(),
         })
     }
 
@@ -1965,6 +1972,7 @@ impl AuthManager {
             agent_identity_bootstrap_cooldown: Mutex::default(),
             external_auth: RwLock::new(None),
             auth_route_config: None,
+            token_refresh_scheduler: TokenRefreshScheduler::default(),
         })
     }
 
@@ -1990,6 +1998,7 @@ impl AuthManager {
                 Arc::new(BearerTokenRefresher::new(config)) as Arc<dyn ExternalAuth>
             )),
             auth_route_config: None,
+            token_refresh_scheduler: TokenRefreshScheduler::default(),
         })
     }
 
@@ -2317,7 +2326,7 @@ impl AuthManager {
         enable_codex_api_key_env: bool,
     ) -> Arc<Self> {
         Self::shared(
-            config.codex_home(),
+            config.auth_home(),
             enable_codex_api_key_env,
             config.cli_auth_credentials_store_mode(),
             config.forced_chatgpt_workspace_id(),
@@ -2328,6 +2337,19 @@ impl AuthManager {
         .await
     }
 
+    /// Starts refreshing managed ChatGPT access tokens in the background `margin` before they
+    /// expire, so long sessions do not wait on a refresh (or fail on an expired token) mid-turn.
+    /// Later calls are no-ops.
+    pub fn start_proactive_token_refresh(self: &Arc<Self>, margin: Duration) {
+        self.token_refresh_scheduler.start(self, margin);
+    }
+
+    /// Subscribes to warnings about background token refreshes that failed while the access
+    /// token was still valid.
+    pub fn token_refresh_warnings(&self) -> broadcast::Receiver<String> {
+        self.token_refresh_scheduler.subscribe()
+    }
+
     pub fn unauthorized_recovery(self: &Arc<Self>) -> UnauthorizedRecovery {
         UnauthorizedRecovery::new(Arc::clone(self))
     }
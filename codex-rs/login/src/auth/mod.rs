@@ -5,12 +5,14 @@ mod bedrock_api_key;
 pub mod default_client;
 pub mod error;
 mod personal_access_token;
+mod profiles;
 mod storage;
 mod util;
 
 mod external_bearer;
 mod manager;
 mod revoke;
+mod token_refresh_scheduler;
 
 pub use auth_headers::AuthHeaders;
 pub use bedrock_api_key::BedrockApiKeyAuth;
@@ -18,3 +20,13 @@ pub use bedrock_api_key::login_with_bedrock_api_key;
 pub use error::RefreshTokenFailedError;
 pub use error::RefreshTokenFailedReason;
 pub use manager::*;
+pub use profiles::DEFAULT_AUTH_PROFILE;
+pub use profiles::active_auth_profile;
+pub use profiles::auth_profile_home;
+pub use profiles::ensure_auth_profile_home;
+pub use profiles::list_auth_profiles;
+pub use profiles::resolve_auth_profile;
+pub use profiles::set_active_auth_profile;
+pub use profiles::validate_auth_profile_name;
+pub use storage::migrate_auth_storage;
+pub use token_refresh_scheduler::DEFAULT_CHATGPT_TOKEN_REFRESH_MARGIN;
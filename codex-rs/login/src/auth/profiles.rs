@@ -0,0 +1,149 @@
+//! Named auth profiles.
+//!
+//! The credentials stored directly under `CODEX_HOME` form the `default` profile, so installs
+//! that predate profiles keep their login without any rewrite. Every other profile keeps its
+//! credentials under `CODEX_HOME/auth-profiles/<name>/`, which the configured storage backend
+//! uses in place of `CODEX_HOME`: the file backend writes `auth.json` there and the keyring
+//! backends derive their entry key from that directory. The profile picked with
+//! `codex auth use` is recorded in `CODEX_HOME/auth-profiles/active`; a profile pinned with
+//! `--auth-profile` or the `auth_profile` config key takes precedence over it.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Profile whose credentials live directly under `CODEX_HOME`.
+pub const DEFAULT_AUTH_PROFILE: &str = "default";
+
+const AUTH_PROFILES_DIR: &str = "auth-profiles";
+const ACTIVE_AUTH_PROFILE_FILE: &str = "active";
+const MAX_AUTH_PROFILE_NAME_LEN: usize = 64;
+
+/// Profile names become directory names, so they are limited to ASCII letters, digits, `-`,
+/// and `_`. `active` is reserved for the active-profile record.
+pub fn validate_auth_profile_name(name: &str) -> io::Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_AUTH_PROFILE_NAME_LEN
+        && name
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_'))
+        && name != ACTIVE_AUTH_PROFILE_FILE;
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid auth profile name `{name}`: use up to {MAX_AUTH_PROFILE_NAME_LEN} ASCII letters, digits, `-`, or `_` (`active` is reserved)"
+            ),
+        ))
+    }
+}
+
+/// Returns the directory that holds `profile`'s credentials. Pass it wherever auth storage
+/// expects a Codex home.
+pub fn auth_profile_home(codex_home: &Path, profile: &str) -> PathBuf {
+    if profile == DEFAULT_AUTH_PROFILE {
+        codex_home.to_path_buf()
+    } else {
+        codex_home.join(AUTH_PROFILES_DIR).join(profile)
+    }
+}
+
+/// Like [`auth_profile_home`], but creates a named profile's directory first. Keyring entry
+/// keys are derived from the canonical directory path, so the directory must exist before
+/// credentials are saved or loaded for the key to stay stable.
+pub fn ensure_auth_profile_home(codex_home: &Path, profile: &str) -> io::Result<PathBuf> {
+    validate_auth_profile_name(profile)?;
+    let home = auth_profile_home(codex_home, profile);
+    if profile != DEFAULT_AUTH_PROFILE {
+        fs::create_dir_all(&home)?;
+    }
+    Ok(home)
+}
+
+/// Returns the profile selected with `codex auth use`, or the default profile when none was.
+pub fn active_auth_profile(codex_home: &Path) -> io::Result<String> {
+    let contents = match fs::read_to_string(active_auth_profile_file(codex_home)) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(DEFAULT_AUTH_PROFILE.to_string());
+        }
+        Err(err) => return Err(err),
+    };
+    let profile = contents.trim();
+    if profile.is_empty() {
+        return Ok(DEFAULT_AUTH_PROFILE.to_string());
+    }
+    validate_auth_profile_name(profile)?;
+    Ok(profile.to_string())
+}
+
+/// Makes `profile` the one used when nothing pins another. Selecting the default profile
+/// removes the record, leaving `CODEX_HOME` as it was before profiles existed.
+pub fn set_active_auth_profile(codex_home: &Path, profile: &str) -> io::Result<()> {
+    validate_auth_profile_name(profile)?;
+    let active_file = active_auth_profile_file(codex_home);
+    if profile == DEFAULT_AUTH_PROFILE {
+        return match fs::remove_file(active_file) {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        };
+    }
+    if let Some(parent) = active_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(active_file, format!("{profile}\n"))
+}
+
+/// Resolves the profile to use for this invocation: `pinned` (from `--auth-profile` or the
+/// `auth_profile` config key) wins over the active profile.
+pub fn resolve_auth_profile(codex_home: &Path, pinned: Option<&str>) -> io::Result<String> {
+    match pinned.map(str::trim) {
+        Some(profile) => {
+            validate_auth_profile_name(profile)?;
+            Ok(profile.to_string())
+        }
+        None => active_auth_profile(codex_home),
+    }
+}
+
+/// Lists the default profile followed by every named profile, sorted by name. A named profile
+/// exists once its directory does, whether or not it holds credentials yet.
+pub fn list_auth_profiles(codex_home: &Path) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(codex_home.join(AUTH_PROFILES_DIR)) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(vec![DEFAULT_AUTH_PROFILE.to_string()]);
+        }
+        Err(err) => return Err(err),
+    };
+    let mut profiles = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(name) = entry.file_name().to_str()
+            && name != DEFAULT_AUTH_PROFILE
+            && validate_auth_profile_name(name).is_ok()
+        {
+            profiles.push(name.to_string());
+        }
+    }
+    profiles.sort();
+    profiles.insert(0, DEFAULT_AUTH_PROFILE.to_string());
+    Ok(profiles)
+}
+
+fn active_auth_profile_file(codex_home: &Path) -> PathBuf {
+    codex_home
+        .join(AUTH_PROFILES_DIR)
+        .join(ACTIVE_AUTH_PROFILE_FILE)
+}
+
+#[cfg(test)]
+#[path = "profiles_tests.rs"]
+mod tests;
@@ -0,0 +1,120 @@
+use super::*;
+use crate::auth::AuthKeyringBackendKind;
+use crate::auth::load_auth_dot_json;
+use crate::auth::login_with_api_key;
+use codex_config::types::AuthCredentialsStoreMode;
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+
+fn save_api_key(auth_home: &Path, api_key: &str) -> io::Result<()> {
+    login_with_api_key(
+        auth_home,
+        api_key,
+        AuthCredentialsStoreMode::File,
+        AuthKeyringBackendKind::default(),
+    )
+}
+
+fn stored_api_key(auth_home: &Path) -> io::Result<Option<String>> {
+    Ok(load_auth_dot_json(
+        auth_home,
+        AuthCredentialsStoreMode::File,
+        AuthKeyringBackendKind::default(),
+    )?
+    .and_then(|auth| auth.openai_api_key))
+}
+
+#[test]
+fn single_credential_login_is_the_default_profile() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    save_api_key(codex_home.path(), "sk-existing")?;
+    let auth_json = fs::read(codex_home.path().join("auth.json"))?;
+
+    let profile = resolve_auth_profile(codex_home.path(), /*pinned*/ None)?;
+    let auth_home = ensure_auth_profile_home(codex_home.path(), &profile)?;
+
+    assert_eq!(
+        (
+            profile,
+            stored_api_key(&auth_home)?,
+            list_auth_profiles(codex_home.path())?,
+        ),
+        (
+            DEFAULT_AUTH_PROFILE.to_string(),
+            Some("sk-existing".to_string()),
+            vec![DEFAULT_AUTH_PROFILE.to_string()],
+        )
+    );
+    assert_eq!(fs::read(codex_home.path().join("auth.json"))?, auth_json);
+    Ok(())
+}
+
+#[test]
+fn switching_profiles_keeps_each_profiles_credentials() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    save_api_key(codex_home.path(), "sk-personal")?;
+    save_api_key(
+        &ensure_auth_profile_home(codex_home.path(), "work")?,
+        "sk-work",
+    )?;
+
+    let active_key = |codex_home: &Path| -> io::Result<Option<String>> {
+        let profile = resolve_auth_profile(codex_home, /*pinned*/ None)?;
+        stored_api_key(&auth_profile_home(codex_home, &profile))
+    };
+
+    set_active_auth_profile(codex_home.path(), "work")?;
+    let after_switch = active_key(codex_home.path())?;
+    set_active_auth_profile(codex_home.path(), DEFAULT_AUTH_PROFILE)?;
+    let after_switch_back = active_key(codex_home.path())?;
+
+    assert_eq!(
+        (
+            after_switch,
+            after_switch_back,
+            list_auth_profiles(codex_home.path())?,
+        ),
+        (
+            Some("sk-work".to_string()),
+            Some("sk-personal".to_string()),
+            vec![DEFAULT_AUTH_PROFILE.to_string(), "work".to_string()],
+        )
+    );
+    assert!(!codex_home.path().join("auth-profiles/active").exists());
+    Ok(())
+}
+
+#[test]
+fn pinned_profile_overrides_the_active_profile() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    set_active_auth_profile(codex_home.path(), "work")?;
+
+    assert_eq!(
+        (
+            resolve_auth_profile(codex_home.path(), Some("personal"))?,
+            resolve_auth_profile(codex_home.path(), Some(DEFAULT_AUTH_PROFILE))?,
+            resolve_auth_profile(codex_home.path(), /*pinned*/ None)?,
+        ),
+        (
+            "personal".to_string(),
+            DEFAULT_AUTH_PROFILE.to_string(),
+            "work".to_string(),
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid_profile_names_are_rejected() {
+    assert_eq!(
+        ["", "../work", "my work", "active", "work_2-b"]
+            .map(|name| validate_auth_profile_name(name).map_err(|err| err.kind())),
+        [
+            Err(io::ErrorKind::InvalidInput),
+            Err(io::ErrorKind::InvalidInput),
+            Err(io::ErrorKind::InvalidInput),
+            Err(io::ErrorKind::InvalidInput),
+            Ok(()),
+        ]
+    );
+}
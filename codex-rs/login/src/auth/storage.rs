@@ -163,7 +163,13 @@ pub(super) fn delete_file_if_exists(codex_home: &Path) -> std::io::Result<bool>
 pub(super) trait AuthStorageBackend: Debug + Send + Sync {
     fn load(&self) -> std::io::Result<Option<AuthDotJson>>;
     fn save(&self, auth: &AuthDotJson) -> std::io::Result<()>;
+    /// Like [`Self::save`], but leaves an existing `auth.json` in place so a migration can
+    /// verify the new copy before removing the old one.
+    fn save_keeping_auth_file(&self, auth: &AuthDotJson) -> std::io::Result<()>;
     fn delete(&self) -> std::io::Result<bool>;
+    /// Removes the credentials this backend keeps anywhere other than `auth.json`, so a
+    /// migration can clear its source without touching a target that lives in that file.
+    fn delete_outside_auth_file(&self) -> std::io::Result<bool>;
 }
 
 #[derive(Clone, Debug)]
@@ -218,9 +224,17 @@ impl AuthStorageBackend for FileAuthStorage {
         Ok(())
     }
 
+    fn save_keeping_auth_file(&self, auth: &AuthDotJson) -> std::io::Result<()> {
+        self.save(auth)
+    }
+
     fn delete(&self) -> std::io::Result<bool> {
         delete_file_if_exists(&self.codex_home)
     }
+
+    fn delete_outside_auth_file(&self) -> std::io::Result<bool> {
+        Ok(false)
+    }
 }
 
 static CODEX_AUTH_SECRET_NAME: Lazy<SecretName> =
@@ -295,26 +309,33 @@ impl AuthStorageBackend for DirectKeyringAuthStorage {
     }
 
     fn save(&self, auth: &AuthDotJson) -> std::io::Result<()> {
-        let key = compute_store_key(&self.codex_home)?;
-        // Simpler error mapping per style: prefer method reference over closure
-        let serialized = serde_json::to_string(auth).map_err(std::io::Error::other)?;
-        self.save_to_keyring(&key, &serialized)?;
+        self.save_keeping_auth_file(auth)?;
         if let Err(err) = delete_file_if_exists(&self.codex_home) {
             warn!("failed to remove CLI auth fallback file: {err}");
         }
         Ok(())
     }
 
+    fn save_keeping_auth_file(&self, auth: &AuthDotJson) -> std::io::Result<()> {
+        let key = compute_store_key(&self.codex_home)?;
+        // Simpler error mapping per style: prefer method reference over closure
+        let serialized = serde_json::to_string(auth).map_err(std::io::Error::other)?;
+        self.save_to_keyring(&key, &serialized)
+    }
+
     fn delete(&self) -> std::io::Result<bool> {
+        let keyring_removed = self.delete_outside_auth_file()?;
+        let file_removed = delete_file_if_exists(&self.codex_home)?;
+        Ok(keyring_removed || file_removed)
+    }
+
+    fn delete_outside_auth_file(&self) -> std::io::Result<bool> {
         let key = compute_store_key(&self.codex_home)?;
-        let keyring_removed = self
-            .keyring_store
+        self.keyring_store
             .delete(KEYRING_SERVICE, &key)
             .map_err(|err| {
                 std::io::Error::other(format!("failed to delete auth from keyring: {err}"))
-            })?;
-        let file_removed = delete_file_if_exists(&self.codex_home)?;
-        Ok(keyring_removed || file_removed)
+            })
     }
 }
 
@@ -371,6 +392,14 @@ impl AuthStorageBackend for SecretsKeyringAuthStorage {
     }
 
     fn save(&self, auth: &AuthDotJson) -> std::io::Result<()> {
+        self.save_keeping_auth_file(auth)?;
+        if let Err(err) = delete_file_if_exists(&self.codex_home) {
+            warn!("failed to remove CLI auth fallback file: {err}");
+        }
+        Ok(())
+    }
+
+    fn save_keeping_auth_file(&self, auth: &AuthDotJson) -> std::io::Result<()> {
         let serialized = serde_json::to_string(auth).map_err(std::io::Error::other)?;
         self.secrets_manager
             .set(&SecretScope::Global, &CODEX_AUTH_SECRET_NAME, &serialized)
@@ -379,14 +408,16 @@ impl AuthStorageBackend for SecretsKeyringAuthStorage {
                     format!("failed to write OAuth tokens to encrypted auth storage: {err}");
                 warn!("{message}");
                 std::io::Error::other(message)
-            })?;
-        if let Err(err) = delete_file_if_exists(&self.codex_home) {
-            warn!("failed to remove CLI auth fallback file: {err}");
-        }
-        Ok(())
+            })
     }
 
     fn delete(&self) -> std::io::Result<bool> {
+        let keyring_removed = self.delete_outside_auth_file()?;
+        let file_removed = delete_file_if_exists(&self.codex_home)?;
+        Ok(keyring_removed || file_removed)
+    }
+
+    fn delete_outside_auth_file(&self) -> std::io::Result<bool> {
         let keyring_removed = self
             .secrets_manager
             .delete(&SecretScope::Global, &CODEX_AUTH_SECRET_NAME)
@@ -395,9 +426,8 @@ impl AuthStorageBackend for SecretsKeyringAuthStorage {
                     "failed to delete auth from encrypted auth storage: {err}"
                 ))
             })?;
-        let file_removed = delete_file_if_exists(&self.codex_home)?;
-        let direct_removed = self.direct_storage.delete()?;
-        Ok(keyring_removed || file_removed || direct_removed)
+        let direct_removed = self.direct_storage.delete_outside_auth_file()?;
+        Ok(keyring_removed || direct_removed)
     }
 }
 
@@ -446,10 +476,24 @@ impl AuthStorageBackend for AutoAuthStorage {
         }
     }
 
+    fn save_keeping_auth_file(&self, auth: &AuthDotJson) -> std::io::Result<()> {
+        match self.keyring_storage.save_keeping_auth_file(auth) {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                warn!("failed to save auth to keyring, falling back to file storage: {err}");
+                self.file_storage.save(auth)
+            }
+        }
+    }
+
     fn delete(&self) -> std::io::Result<bool> {
         // Keyring storage will delete from disk as well
         self.keyring_storage.delete()
     }
+
+    fn delete_outside_auth_file(&self) -> std::io::Result<bool> {
+        self.keyring_storage.delete_outside_auth_file()
+    }
 }
 
 // A global in-memory store for mapping codex_home -> AuthDotJson.
@@ -490,9 +534,17 @@ impl AuthStorageBackend for EphemeralAuthStorage {
         })
     }
 
+    fn save_keeping_auth_file(&self, auth: &AuthDotJson) -> std::io::Result<()> {
+        self.save(auth)
+    }
+
     fn delete(&self) -> std::io::Result<bool> {
         self.with_store(|store, key| Ok(store.remove(&key).is_some()))
     }
+
+    fn delete_outside_auth_file(&self) -> std::io::Result<bool> {
+        self.delete()
+    }
 }
 
 pub(super) fn create_auth_storage(
@@ -539,6 +591,90 @@ fn create_keyring_auth_storage(
     }
 }
 
+/// Moves the stored auth payload from the `from` store to the `to` store and removes it from
+/// `from`, so `codex_home` is left with a single copy. Returns `false` when `from` holds nothing.
+///
+/// If `to` rejects the payload (for example, there is no secret service on a headless Linux
+/// machine), the payload is put back in `from` and the error is returned.
+pub fn migrate_auth_storage(
+    codex_home: &Path,
+    from: AuthCredentialsStoreMode,
+    to: AuthCredentialsStoreMode,
+    keyring_backend_kind: AuthKeyringBackendKind,
+) -> std::io::Result<bool> {
+    let keyring_store: Arc<dyn KeyringStore> = Arc::new(DefaultKeyringStore);
+    migrate_auth_storage_with_store(codex_home, from, to, keyring_store, keyring_backend_kind)
+}
+
+fn migrate_auth_storage_with_store(
+    codex_home: &Path,
+    from: AuthCredentialsStoreMode,
+    to: AuthCredentialsStoreMode,
+    keyring_store: Arc<dyn KeyringStore>,
+    keyring_backend_kind: AuthKeyringBackendKind,
+) -> std::io::Result<bool> {
+    if from == to {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "auth credentials are already stored in the requested store",
+        ));
+    }
+    let source = create_auth_storage_with_store(
+        codex_home.to_path_buf(),
+        from,
+        Arc::clone(&keyring_store),
+        keyring_backend_kind,
+    );
+    let target = create_auth_storage_with_store(
+        codex_home.to_path_buf(),
+        to,
+        Arc::clone(&keyring_store),
+        keyring_backend_kind,
+    );
+    let keyring = create_keyring_auth_storage(
+        codex_home.to_path_buf(),
+        keyring_store,
+        keyring_backend_kind,
+    );
+    let Some(auth) = source.load()? else {
+        return Ok(false);
+    };
+
+    // Keep the source, including `auth.json`, until the target has been written and read
+    // back, so a failed migration never leaves the credentials in neither store.
+    target.save_keeping_auth_file(&auth)?;
+    if target.load()?.as_ref() != Some(&auth) {
+        return Err(std::io::Error::other(
+            "auth credentials could not be read back from the requested store; \
+             they were left in the current store",
+        ));
+    }
+
+    // The keyring entry and `auth.json` may also belong to the target, so only clear the
+    // parts of the source the target does not use.
+    match (from, to) {
+        (_, AuthCredentialsStoreMode::File) => {
+            source.delete_outside_auth_file()?;
+        }
+        (AuthCredentialsStoreMode::Ephemeral, _) | (_, AuthCredentialsStoreMode::Ephemeral) => {
+            source.delete()?;
+        }
+        // The keyring entry is shared with the target. `auth.json` is stale once the keyring
+        // holds the credentials; an `auto` target that fell back to the file keeps it.
+        (
+            AuthCredentialsStoreMode::File
+            | AuthCredentialsStoreMode::Keyring
+            | AuthCredentialsStoreMode::Auto,
+            AuthCredentialsStoreMode::Keyring | AuthCredentialsStoreMode::Auto,
+        ) => {
+            if matches!(keyring.load(), Ok(Some(stored)) if stored == auth) {
+                delete_file_if_exists(codex_home)?;
+            }
+        }
+    }
+    Ok(true)
+}
+
 #[cfg(test)]
 #[path = "storage_tests.rs"]
 mod tests;
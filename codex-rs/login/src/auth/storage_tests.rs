@@ -803,3 +803,174 @@ fn auto_auth_storage_delete_removes_keyring_and_file() -> anyhow::Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn migrate_auth_storage_moves_credentials_between_file_and_keyring() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    let mock_keyring = MockKeyringStore::default();
+    let keyring_store: Arc<dyn KeyringStore> = Arc::new(mock_keyring.clone());
+    let auth = auth_with_prefix("migrate");
+    FileAuthStorage::new(codex_home.path().to_path_buf()).save(&auth)?;
+    let key = compute_store_key(codex_home.path())?;
+
+    let migrated_to_keyring = migrate_auth_storage_with_store(
+        codex_home.path(),
+        AuthCredentialsStoreMode::File,
+        AuthCredentialsStoreMode::Keyring,
+        Arc::clone(&keyring_store),
+        AuthKeyringBackendKind::Direct,
+    )?;
+    let in_keyring = mock_keyring.saved_value(&key);
+    let file_after_keyring = get_auth_file(codex_home.path()).exists();
+
+    let migrated_to_file = migrate_auth_storage_with_store(
+        codex_home.path(),
+        AuthCredentialsStoreMode::Keyring,
+        AuthCredentialsStoreMode::File,
+        keyring_store,
+        AuthKeyringBackendKind::Direct,
+    )?;
+
+    assert_eq!(
+        (
+            migrated_to_keyring,
+            in_keyring,
+            file_after_keyring,
+            migrated_to_file,
+            mock_keyring.saved_value(&key),
+            FileAuthStorage::new(codex_home.path().to_path_buf()).load()?,
+        ),
+        (
+            true,
+            Some(serde_json::to_string(&auth)?),
+            false,
+            true,
+            None,
+            Some(auth),
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn migrate_auth_storage_keeps_file_when_keyring_is_unavailable() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    let mock_keyring = MockKeyringStore::default();
+    let auth = auth_with_prefix("keep");
+    FileAuthStorage::new(codex_home.path().to_path_buf()).save(&auth)?;
+    let key = compute_store_key(codex_home.path())?;
+    mock_keyring.set_error(&key, KeyringError::Invalid("error".into(), "save".into()));
+
+    let result = migrate_auth_storage_with_store(
+        codex_home.path(),
+        AuthCredentialsStoreMode::File,
+        AuthCredentialsStoreMode::Keyring,
+        Arc::new(mock_keyring.clone()),
+        AuthKeyringBackendKind::Direct,
+    );
+
+    assert!(result.is_err(), "migration should report the keyring error");
+    assert_eq!(
+        FileAuthStorage::new(codex_home.path().to_path_buf()).load()?,
+        Some(auth)
+    );
+    Ok(())
+}
+
+#[test]
+fn migrate_auth_storage_keeps_keyring_entry_when_file_write_fails() -> anyhow::Result<()> {
+    let codex_home = tempdir()?;
+    let mock_keyring = MockKeyringStore::default();
+    let keyring_store: Arc<dyn KeyringStore> = Arc::new(mock_keyring.clone());
+    let auth = auth_with_prefix("keyring-source");
+    DirectKeyringAuthStorage::new(codex_home.path().to_path_buf(), Arc::clone(&keyring_store))
+        .save(&auth)?;
+    let key = compute_store_key(codex_home.path())?;
+    // A directory where `auth.json` belongs makes the file store unwritable.
+    std::fs::create_dir(get_auth_file(codex_home.path()))?;
+
+    let result = migrate_auth_storage_with_store(
+        codex_home.path(),
+        AuthCredentialsStoreMode::Keyring,
+        AuthCredentialsStoreMode::File,
+        keyring_store,
+        AuthKeyringBackendKind::Direct,
+    );
+
+    assert!(result.is_err(), "migration should report the file error");
+    assert_eq!(
+        mock_keyring.saved_value(&key),
+        Some(serde_json::to_string(&auth)?)
+    );
+    Ok(())
+}
+
+/// Accepts every write but reads back `readback`, or fails to read when it is `None`.
+#[derive(Debug)]
+struct UnverifiableKeyringStore {
+    readback: Option<String>,
+}
+
+impl KeyringStore for UnverifiableKeyringStore {
+    fn load(
+        &self,
+        _service: &str,
+        _account: &str,
+    ) -> Result<Option<String>, codex_keyring_store::CredentialStoreError> {
+        self.readback.clone().map(Some).ok_or_else(|| {
+            codex_keyring_store::CredentialStoreError::new(KeyringError::Invalid(
+                "error".into(),
+                "load".into(),
+            ))
+        })
+    }
+
+    fn save(
+        &self,
+        _service: &str,
+        _account: &str,
+        _value: &str,
+    ) -> Result<(), codex_keyring_store::CredentialStoreError> {
+        Ok(())
+    }
+
+    fn delete(
+        &self,
+        _service: &str,
+        _account: &str,
+    ) -> Result<bool, codex_keyring_store::CredentialStoreError> {
+        Ok(false)
+    }
+}
+
+#[test]
+fn migrate_auth_storage_keeps_auth_file_when_keyring_readback_fails() -> anyhow::Result<()> {
+    let auth = auth_with_prefix("unverified");
+    for readback in [
+        Some(serde_json::to_string(&auth_with_prefix("other"))?),
+        None,
+    ] {
+        let codex_home = tempdir()?;
+        FileAuthStorage::new(codex_home.path().to_path_buf()).save(&auth)?;
+
+        let result = migrate_auth_storage_with_store(
+            codex_home.path(),
+            AuthCredentialsStoreMode::File,
+            AuthCredentialsStoreMode::Keyring,
+            Arc::new(UnverifiableKeyringStore {
+                readback: readback.clone(),
+            }),
+            AuthKeyringBackendKind::Direct,
+        );
+
+        assert!(
+            result.is_err(),
+            "migration should fail when the keyring reads back {readback:?}"
+        );
+        assert_eq!(
+            FileAuthStorage::new(codex_home.path().to_path_buf()).load()?,
+            Some(auth.clone())
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,190 @@
+//! Background refresh of managed ChatGPT access tokens.
+//!
+//! [`AuthManager::auth`] only refreshes a token that is close to expiry when a request asks for
+//! it, so a refresh failure surfaces in the middle of a turn. The scheduler instead refreshes
+//! the cached token a configurable margin before it expires. It goes through
+//! [`AuthManager::refresh_token`], which shares the manager's refresh lock with request-driven
+//! refreshes and persists the new tokens through the configured storage mode. Transient
+//! failures are retried with exponential backoff, and the first failure for a token is reported
+//! as a warning while the token is still valid.
+
+use std::sync::Arc;
+use std::sync::Weak;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use tokio::sync::broadcast;
+use tokio::sync::watch;
+
+use super::manager::AuthManager;
+use super::manager::CodexAuth;
+use super::manager::RefreshTokenError;
+use crate::token_data::parse_jwt_expiration;
+
+/// How long before expiry the scheduler refreshes a managed ChatGPT access token by default.
+pub const DEFAULT_CHATGPT_TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(1);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+const WARNING_CHANNEL_CAPACITY: usize = 16;
+
+pub(super) struct TokenRefreshScheduler {
+    started: AtomicBool,
+    warning_tx: broadcast::Sender<String>,
+}
+
+impl Default for TokenRefreshScheduler {
+    fn default() -> Self {
+        let (warning_tx, _warning_rx) = broadcast::channel(WARNING_CHANNEL_CAPACITY);
+        Self {
+            started: AtomicBool::new(false),
+            warning_tx,
+        }
+    }
+}
+
+impl TokenRefreshScheduler {
+    pub(super) fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.warning_tx.subscribe()
+    }
+
+    /// Spawns the refresh task for `manager` unless one is already running. The task holds a
+    /// weak reference and stops once the manager is dropped.
+    pub(super) fn start(&self, manager: &Arc<AuthManager>, margin: Duration) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            tracing::warn!("not starting background token refresh outside a Tokio runtime");
+            return;
+        };
+        if self.started.swap(true, Ordering::AcqRel) {
+            return;
+        }
+        runtime.spawn(run(
+            Arc::downgrade(manager),
+            manager.auth_change_receiver(),
+            margin,
+            self.warning_tx.clone(),
+        ));
+    }
+}
+
+/// The cached managed ChatGPT access token and its expiry, if the token carries one.
+struct ScheduledToken {
+    access_token: String,
+    expires_at: DateTime<Utc>,
+}
+
+async fn run(
+    manager: Weak<AuthManager>,
+    mut auth_changes: watch::Receiver<u64>,
+    margin: Duration,
+    warning_tx: broadcast::Sender<String>,
+) {
+    let mut retry_delay = INITIAL_RETRY_DELAY;
+    let mut pending_retry: Option<Duration> = None;
+    let mut refreshed_token: Option<String> = None;
+    let mut warned_token: Option<String> = None;
+
+    loop {
+        let Some(scheduled) = manager
+            .upgrade()
+            .and_then(|manager| scheduled_token(&manager))
+        else {
+            // Nothing to refresh until a managed ChatGPT login shows up.
+            if auth_changes.changed().await.is_err() {
+                return;
+            }
+            continue;
+        };
+        let delay = pending_retry.take().unwrap_or_else(|| {
+            refresh_delay(
+                &scheduled,
+                margin,
+                refreshed_token.as_deref() == Some(scheduled.access_token.as_str()),
+                Utc::now(),
+            )
+        });
+        tokio::select! {
+            changed = auth_changes.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+                retry_delay = INITIAL_RETRY_DELAY;
+                continue;
+            }
+            () = tokio::time::sleep(delay) => {}
+        }
+
+        let Some(manager) = manager.upgrade() else {
+            return;
+        };
+        match manager.refresh_token().await {
+            Ok(()) => {
+                retry_delay = INITIAL_RETRY_DELAY;
+                refreshed_token = scheduled_token(&manager).map(|token| token.access_token);
+            }
+            Err(RefreshTokenError::Transient(err)) => {
+                tracing::warn!(
+                    "background token refresh failed, retrying in {retry_delay:?}: {err}"
+                );
+                if warned_token.as_deref() != Some(scheduled.access_token.as_str()) {
+                    warned_token = Some(scheduled.access_token.clone());
+                    let expires_at = scheduled.expires_at.format("%H:%M UTC");
+                    let _ = warning_tx.send(format!(
+                        "Could not refresh your ChatGPT access token, which expires at {expires_at}. Codex will keep retrying; sign in again if requests start failing."
+                    ));
+                }
+                pending_retry = Some(retry_delay);
+                retry_delay = (retry_delay * 2).min(MAX_RETRY_DELAY);
+            }
+            Err(RefreshTokenError::Permanent(err)) => {
+                tracing::warn!("background token refresh failed permanently: {err}");
+                let _ = warning_tx.send(err.message);
+                // Retrying cannot help; wait for the user to sign in again.
+                drop(manager);
+                if auth_changes.changed().await.is_err() {
+                    return;
+                }
+                retry_delay = INITIAL_RETRY_DELAY;
+            }
+        }
+    }
+}
+
+fn scheduled_token(manager: &AuthManager) -> Option<ScheduledToken> {
+    let auth = manager.auth_cached()?;
+    // Tokens from external auth are refreshed by their provider, not by Codex.
+    if !matches!(auth, CodexAuth::Chatgpt(_)) {
+        return None;
+    }
+    let access_token = auth.get_token_data().ok()?.access_token;
+    let expires_at = parse_jwt_expiration(&access_token).ok()??;
+    Some(ScheduledToken {
+        access_token,
+        expires_at,
+    })
+}
+
+/// Returns how long to wait before refreshing `token`: until `margin` before it expires, or
+/// right away once inside the margin. A token this task just obtained that already expires
+/// within the margin is given half of its remaining lifetime instead (but at least a second), so
+/// a short-lived token does not trigger back-to-back refreshes.
+fn refresh_delay(
+    token: &ScheduledToken,
+    margin: Duration,
+    just_refreshed: bool,
+    now: DateTime<Utc>,
+) -> Duration {
+    let until_expiry = (token.expires_at - now).to_std().unwrap_or_default();
+    match until_expiry.checked_sub(margin) {
+        Some(delay) => delay,
+        None if just_refreshed => (until_expiry / 2).max(INITIAL_RETRY_DELAY),
+        None => Duration::ZERO,
+    }
+}
+
+#[cfg(test)]
+#[path = "token_refresh_scheduler_tests.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn token_expiring_in(now: DateTime<Utc>, seconds: i64) -> ScheduledToken {
+    ScheduledToken {
+        access_token: "access-token".to_string(),
+        expires_at: now + chrono::Duration::seconds(seconds),
+    }
+}
+
+#[test]
+fn refresh_delay_waits_until_the_margin_before_expiry() {
+    let now = Utc::now();
+    let margin = Duration::from_secs(300);
+
+    assert_eq!(
+        [
+            refresh_delay(
+                &token_expiring_in(now, 3_600),
+                margin,
+                /*just_refreshed*/ false,
+                now
+            ),
+            refresh_delay(
+                &token_expiring_in(now, 120),
+                margin,
+                /*just_refreshed*/ false,
+                now
+            ),
+            refresh_delay(
+                &token_expiring_in(now, -60),
+                margin,
+                /*just_refreshed*/ false,
+                now
+            ),
+        ],
+        [Duration::from_secs(3_300), Duration::ZERO, Duration::ZERO,]
+    );
+}
+
+#[test]
+fn refresh_delay_spaces_out_tokens_shorter_than_the_margin() {
+    let now = Utc::now();
+    let margin = Duration::from_secs(300);
+
+    assert_eq!(
+        [
+            refresh_delay(
+                &token_expiring_in(now, 120),
+                margin,
+                /*just_refreshed*/ true,
+                now
+            ),
+            refresh_delay(
+                &token_expiring_in(now, 1),
+                margin,
+                /*just_refreshed*/ true,
+                now
+            ),
+        ],
+        [Duration::from_secs(60), INITIAL_RETRY_DELAY]
+    );
+}
@@ -52,6 +52,44 @@ where
     s.trim().parse::<u64>().map_err(de::Error::custom)
 }
 
+/// OAuth error body returned while polling (RFC 8628, section 3.5).
+#[derive(Deserialize)]
+struct TokenPollErrorResp {
+    error: String,
+}
+
+/// What to do after a token poll that did not return an authorization code.
+#[derive(Debug, PartialEq, Eq)]
+enum PollOutcome {
+    /// The user has not finished signing in yet.
+    Pending,
+    /// The server asked the client to poll less often.
+    SlowDown,
+    /// The device code expired before the user signed in.
+    Expired,
+    Failed(String),
+}
+
+/// The device-auth server reports a pending login as 403 or 404 without a body, while
+/// RFC 8628 servers send an `error` code, so both are recognized.
+fn classify_poll_response(status: StatusCode, body: &str) -> PollOutcome {
+    let error = serde_json::from_str::<TokenPollErrorResp>(body)
+        .ok()
+        .map(|resp| resp.error);
+    match error.as_deref() {
+        Some("authorization_pending") => PollOutcome::Pending,
+        Some("slow_down") => PollOutcome::SlowDown,
+        Some("expired_token") => PollOutcome::Expired,
+        Some(error) => {
+            PollOutcome::Failed(format!("device auth failed with status {status}: {error}"))
+        }
+        None if status == StatusCode::FORBIDDEN || status == StatusCode::NOT_FOUND => {
+            PollOutcome::Pending
+        }
+        None => PollOutcome::Failed(format!("device auth failed with status {status}")),
+    }
+}
+
 #[derive(Deserialize)]
 struct CodeSuccessResp {
     authorization_code: String,
@@ -96,13 +134,15 @@ async fn request_user_code(
     serde_json::from_str(&body).map_err(std::io::Error::other)
 }
 
-/// Poll token endpoint until a code is issued or timeout occurs.
+/// Poll token endpoint until a code is issued or timeout occurs. Nothing is stored until a
+/// code is issued, so a timeout, an expired code, or cancelling the login leaves no
+/// credentials behind.
 async fn poll_for_token(
     client: &HttpClient,
     auth_base_url: &str,
     device_auth_id: &str,
     user_code: &str,
-    interval: u64,
+    mut interval: u64,
 ) -> std::io::Result<CodeSuccessResp> {
     let url = format!("{auth_base_url}/deviceauth/token");
     let max_wait = Duration::from_secs(15 * 60);
@@ -128,21 +168,28 @@ async fn poll_for_token(
             return resp.json().await.map_err(std::io::Error::other);
         }
 
-        if status == StatusCode::FORBIDDEN || status == StatusCode::NOT_FOUND {
-            if start.elapsed() >= max_wait {
-                return Err(std::io::Error::other(
-                    "device auth timed out after 15 minutes",
+        let body = resp.text().await.unwrap_or_default();
+        match classify_poll_response(status, &body) {
+            PollOutcome::Pending => {}
+            // RFC 8628 asks clients to add 5 seconds to the interval on every `slow_down`.
+            PollOutcome::SlowDown => interval += 5,
+            PollOutcome::Expired => {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "device code expired before sign-in completed. Run `codex login --device-auth` to get a new code.",
                 ));
             }
-            let sleep_for = Duration::from_secs(interval).min(max_wait - start.elapsed());
-            tokio::time::sleep(sleep_for).await;
-            continue;
+            PollOutcome::Failed(message) => return Err(std::io::Error::other(message)),
         }
 
-        return Err(std::io::Error::other(format!(
-            "device auth failed with status {}",
-            resp.status()
-        )));
+        if start.elapsed() >= max_wait {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "device auth timed out after 15 minutes",
+            ));
+        }
+        let sleep_for = Duration::from_secs(interval).min(max_wait - start.elapsed());
+        tokio::time::sleep(sleep_for).await;
     }
 }
 
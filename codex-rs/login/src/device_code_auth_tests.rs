@@ -1,4 +1,5 @@
 use super::*;
+use pretty_assertions::assert_eq;
 
 #[test]
 fn device_code_prompt_renders_phishing_warning() {
@@ -8,3 +9,35 @@ fn device_code_prompt_renders_phishing_warning() {
         "\x1b[90mContinue only if you started this login in Codex. If a website or another person gave you this code, cancel.\x1b[0m"
     ));
 }
+
+#[test]
+fn classify_poll_response_handles_rfc8628_error_codes() {
+    let outcomes = [
+        (
+            StatusCode::BAD_REQUEST,
+            r#"{"error":"authorization_pending"}"#,
+        ),
+        (StatusCode::BAD_REQUEST, r#"{"error":"slow_down"}"#),
+        (StatusCode::BAD_REQUEST, r#"{"error":"expired_token"}"#),
+        (StatusCode::BAD_REQUEST, r#"{"error":"access_denied"}"#),
+        (StatusCode::NOT_FOUND, ""),
+        (StatusCode::INTERNAL_SERVER_ERROR, ""),
+    ]
+    .map(|(status, body)| classify_poll_response(status, body));
+
+    assert_eq!(
+        outcomes,
+        [
+            PollOutcome::Pending,
+            PollOutcome::SlowDown,
+            PollOutcome::Expired,
+            PollOutcome::Failed(
+                "device auth failed with status 400 Bad Request: access_denied".to_string()
+            ),
+            PollOutcome::Pending,
+            PollOutcome::Failed(
+                "device auth failed with status 500 Internal Server Error".to_string()
+            ),
+        ]
+    );
+}
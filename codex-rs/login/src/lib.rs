@@ -34,6 +34,8 @@ pub use auth::CLIENT_ID_OVERRIDE_ENV_VAR;
 pub use auth::CODEX_ACCESS_TOKEN_ENV_VAR;
 pub use auth::CODEX_API_KEY_ENV_VAR;
 pub use auth::CodexAuth;
+pub use auth::DEFAULT_AUTH_PROFILE;
+pub use auth::DEFAULT_CHATGPT_TOKEN_REFRESH_MARGIN;
 pub use auth::ExternalAuth;
 pub use auth::ExternalAuthFuture;
 pub use auth::ExternalAuthRefreshContext;
@@ -43,18 +45,26 @@ pub use auth::REFRESH_TOKEN_URL_OVERRIDE_ENV_VAR;
 pub use auth::REVOKE_TOKEN_URL_OVERRIDE_ENV_VAR;
 pub use auth::RefreshTokenError;
 pub use auth::UnauthorizedRecovery;
+pub use auth::active_auth_profile;
+pub use auth::auth_profile_home;
 pub use auth::default_client;
 pub use auth::enforce_login_restrictions;
+pub use auth::ensure_auth_profile_home;
+pub use auth::list_auth_profiles;
 pub use auth::load_auth_dot_json;
 pub use auth::login_with_access_token;
 pub use auth::login_with_api_key;
 pub use auth::login_with_bedrock_api_key;
 pub use auth::logout;
 pub use auth::logout_with_revoke;
+pub use auth::migrate_auth_storage;
 pub use auth::oauth_client_id;
 pub use auth::read_codex_access_token_from_env;
 pub use auth::read_openai_api_key_from_env;
+pub use auth::resolve_auth_profile;
 pub use auth::save_auth;
+pub use auth::set_active_auth_profile;
+pub use auth::validate_auth_profile_name;
 pub use auth_env_telemetry::AuthEnvTelemetry;
 pub use auth_env_telemetry::collect_auth_env_telemetry;
 pub use outbound_proxy::AuthRouteConfig;
@@ -14,6 +14,7 @@ use codex_login::load_auth_dot_json;
 use codex_login::save_auth;
 use codex_login::token_data::IdTokenInfo;
 use codex_login::token_data::TokenData;
+use codex_login::token_data::parse_jwt_expiration;
 use codex_protocol::auth::AuthMode;
 use codex_protocol::auth::RefreshTokenFailedReason;
 use core_test_support::skip_if_no_network;
@@ -22,9 +23,12 @@ use serde::Serialize;
 use serde_json::json;
 use std::ffi::OsString;
 use std::sync::Arc;
+use std::time::Duration as StdDuration;
 use tempfile::TempDir;
 use wiremock::Mock;
 use wiremock::MockServer;
+use wiremock::Request;
+use wiremock::Respond;
 use wiremock::ResponseTemplate;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
@@ -1200,6 +1204,155 @@ async fn unauthorized_recovery_requires_chatgpt_auth() -> Result<()> {
     Ok(())
 }
 
+#[serial_test::serial(auth_env)]
+#[tokio::test]
+async fn background_refresh_keeps_short_lived_tokens_valid() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ShortLivedTokenResponder {
+            lifetime: Duration::seconds(3),
+        })
+        .mount(&server)
+        .await;
+
+    let ctx = RefreshTokenTestContext::new(&server).await?;
+    let initial_access_token = access_token_with_expiration(Utc::now() + Duration::seconds(3));
+    ctx.write_auth(&AuthDotJson {
+        auth_mode: Some(AuthMode::Chatgpt),
+        openai_api_key: None,
+        tokens: Some(build_tokens(&initial_access_token, INITIAL_REFRESH_TOKEN)),
+        last_refresh: Some(Utc::now()),
+        agent_identity: None,
+        personal_access_token: None,
+        bedrock_api_key: None,
+    })
+    .await?;
+
+    ctx.auth_manager
+        .start_proactive_token_refresh(StdDuration::from_secs(2));
+
+    // Sample the cached token the way a request would pick it up, well past the point where
+    // the initial token expired.
+    let deadline = Utc::now() + Duration::seconds(5);
+    while Utc::now() < deadline {
+        let access_token = ctx
+            .auth_manager
+            .auth_cached()
+            .context("auth should stay cached")?
+            .get_token_data()?
+            .access_token;
+        let expires_at =
+            parse_jwt_expiration(&access_token)?.context("access token should expire")?;
+        assert!(
+            expires_at > Utc::now(),
+            "cached access token expired at {expires_at}"
+        );
+        tokio::time::sleep(StdDuration::from_millis(50)).await;
+    }
+
+    let refreshes = server.received_requests().await.unwrap_or_default().len();
+    assert!(
+        refreshes >= 2,
+        "expected repeated refreshes, got {refreshes}"
+    );
+    assert_eq!(
+        ctx.load_auth()?.tokens.map(|tokens| tokens.refresh_token),
+        Some("new-refresh-token".to_string())
+    );
+    Ok(())
+}
+
+#[serial_test::serial(auth_env)]
+#[tokio::test]
+async fn background_refresh_retries_and_warns_before_expiry() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+            "error": "temporary-failure"
+        })))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    let refreshed_access_token = access_token_with_expiration(Utc::now() + Duration::hours(1));
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": refreshed_access_token,
+            "refresh_token": "new-refresh-token"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ctx = RefreshTokenTestContext::new(&server).await?;
+    let expires_at = Utc::now() + Duration::seconds(10);
+    let initial_tokens = build_tokens(
+        &access_token_with_expiration(expires_at),
+        INITIAL_REFRESH_TOKEN,
+    );
+    ctx.write_auth(&AuthDotJson {
+        auth_mode: Some(AuthMode::Chatgpt),
+        openai_api_key: None,
+        tokens: Some(initial_tokens.clone()),
+        last_refresh: Some(Utc::now()),
+        agent_identity: None,
+        personal_access_token: None,
+        bedrock_api_key: None,
+    })
+    .await?;
+
+    let mut warnings = ctx.auth_manager.token_refresh_warnings();
+    ctx.auth_manager
+        .start_proactive_token_refresh(StdDuration::from_secs(60));
+
+    let warning = tokio::time::timeout(StdDuration::from_secs(5), warnings.recv()).await??;
+    assert_eq!(
+        warning,
+        format!(
+            "Could not refresh your ChatGPT access token, which expires at {}. Codex will keep retrying; sign in again if requests start failing.",
+            expires_at.format("%H:%M UTC")
+        )
+    );
+
+    let mut auth_changes = ctx.auth_manager.auth_change_receiver();
+    tokio::time::timeout(StdDuration::from_secs(8), auth_changes.changed()).await??;
+    assert!(Utc::now() < expires_at, "refresh should land before expiry");
+    assert_eq!(
+        ctx.load_auth()?.tokens,
+        Some(TokenData {
+            access_token: refreshed_access_token,
+            refresh_token: "new-refresh-token".to_string(),
+            ..initial_tokens
+        })
+    );
+    // One warning per token: the retries after the first failure stay quiet.
+    assert!(warnings.try_recv().is_err());
+
+    server.verify().await;
+    Ok(())
+}
+
+/// Issues a new access token that expires `lifetime` after each refresh request.
+struct ShortLivedTokenResponder {
+    lifetime: Duration,
+}
+
+impl Respond for ShortLivedTokenResponder {
+    fn respond(&self, _request: &Request) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({
+            "access_token": access_token_with_expiration(Utc::now() + self.lifetime),
+            "refresh_token": "new-refresh-token"
+        }))
+    }
+}
+
 struct RefreshTokenTestContext {
     codex_home: TempDir,
     auth_manager: Arc<AuthManager>,
@@ -8,6 +8,7 @@ use codex_login::AuthKeyringBackendKind;
 use codex_login::ServerOptions;
 use codex_login::auth::load_auth_dot_json;
 use codex_login::run_device_code_login;
+use pretty_assertions::assert_eq;
 use serde_json::json;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
@@ -361,3 +362,103 @@ async fn device_code_login_integration_handles_error_payload() -> anyhow::Result
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn device_code_login_waits_through_authorization_pending() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let codex_home = tempdir()?;
+    let mock_server = MockServer::start().await;
+    mock_usercode_success(&mock_server).await;
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counter = attempts.clone();
+    Mock::given(method("POST"))
+        .and(path("/api/accounts/deviceauth/token"))
+        .respond_with(move |_: &Request| {
+            if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                ResponseTemplate::new(400).set_body_json(json!({
+                    "error": "authorization_pending"
+                }))
+            } else {
+                ResponseTemplate::new(200).set_body_json(json!({
+                    "authorization_code": "poll-code-321",
+                    "code_challenge": "code-challenge-321",
+                    "code_verifier": "code-verifier-321"
+                }))
+            }
+        })
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+    let jwt = make_jwt(json!({}));
+    mock_oauth_token_single(&mock_server, jwt.clone()).await;
+
+    let opts = server_opts(
+        &codex_home,
+        mock_server.uri(),
+        AuthCredentialsStoreMode::File,
+    );
+    run_device_code_login(opts).await?;
+
+    let tokens = load_auth_dot_json(
+        codex_home.path(),
+        AuthCredentialsStoreMode::File,
+        AuthKeyringBackendKind::default(),
+    )?
+    .and_then(|auth| auth.tokens)
+    .context("tokens should be persisted")?;
+    assert_eq!(
+        (
+            tokens.access_token.as_str(),
+            tokens.refresh_token.as_str(),
+            attempts.load(Ordering::SeqCst),
+        ),
+        ("access-token-123", "refresh-token-123", 3)
+    );
+    mock_server.verify().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn device_code_login_stops_when_the_code_expires() -> anyhow::Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let codex_home = tempdir()?;
+    let mock_server = MockServer::start().await;
+    mock_usercode_success(&mock_server).await;
+    mock_poll_token_single(
+        &mock_server,
+        "/api/accounts/deviceauth/token",
+        ResponseTemplate::new(400).set_body_json(json!({ "error": "expired_token" })),
+    )
+    .await;
+
+    let opts = server_opts(
+        &codex_home,
+        mock_server.uri(),
+        AuthCredentialsStoreMode::File,
+    );
+    let err = run_device_code_login(opts)
+        .await
+        .err()
+        .context("an expired device code should fail the login")?;
+
+    assert_eq!(
+        (err.kind(), err.to_string()),
+        (
+            std::io::ErrorKind::TimedOut,
+            "device code expired before sign-in completed. Run `codex login --device-auth` to get a new code."
+                .to_string(),
+        )
+    );
+    assert_eq!(
+        load_auth_dot_json(
+            codex_home.path(),
+            AuthCredentialsStoreMode::File,
+            AuthKeyringBackendKind::default(),
+        )?,
+        None
+    );
+    Ok(())
+}
@@ -18,9 +18,12 @@ async fn rate_limits_check(auth_manager: &AuthManager, config: &Config) -> Optio
         return None;
     }
 
-    let client = BackendClient::from_auth(config.chatgpt_base_url.clone(), &auth)
+    let mut client = BackendClient::from_auth(config.chatgpt_base_url.clone(), &auth)
         .map_err(|err| warn!(%err, "failed to construct backend client"))
         .ok()?;
+    if let Some(timeout) = config.rate_limits_timeout {
+        client = client.with_rate_limits_timeout(timeout);
+    }
 
     let snapshots = client
         .get_rate_limits_many()
@@ -8,8 +8,10 @@ fn snapshot(
     RateLimitSnapshot {
         limit_id: Some(crate::guard_limits::CODEX_LIMIT_ID.to_string()),
         limit_name: None,
+        scope: None,
         primary: primary_used_percent.map(window),
         secondary: secondary_used_percent.map(window),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
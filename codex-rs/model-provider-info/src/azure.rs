@@ -0,0 +1,70 @@
+//! Azure OpenAI request conventions for model providers.
+//!
+//! Azure OpenAI deployments are addressed by URL rather than by model name, take the API
+//! version as a query parameter, and accept API keys in an `api-key` header instead of
+//! `Authorization: Bearer`.
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Header Azure OpenAI reads API keys from.
+pub const AZURE_API_KEY_HEADER: &str = "api-key";
+const AZURE_API_VERSION_QUERY_PARAM: &str = "api-version";
+
+/// Azure OpenAI configuration for a model provider.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct ModelProviderAzureInfo {
+    /// Deployment to send requests to. When set, `base_url` is the resource endpoint, such as
+    /// `https://my-resource.openai.azure.com`, and requests go to
+    /// `{base_url}/openai/deployments/{deployment}/...`.
+    pub deployment: Option<String>,
+    /// Value of the `api-version` query parameter. Required when `deployment` is set.
+    pub api_version: Option<String>,
+}
+
+impl ModelProviderAzureInfo {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let Some(deployment) = self.deployment.as_deref() else {
+            return Ok(());
+        };
+        if deployment.trim().is_empty() {
+            return Err("provider azure.deployment must not be empty".to_string());
+        }
+        if self.api_version.is_none() {
+            return Err("provider azure.deployment requires azure.api_version".to_string());
+        }
+        Ok(())
+    }
+
+    /// Returns the URL requests are built on: the deployment's URL under the resource endpoint,
+    /// or `base_url` unchanged when no deployment is configured. A trailing `/openai` on the
+    /// resource endpoint is accepted so existing Azure `base_url` values keep working.
+    pub(crate) fn request_base_url(&self, base_url: &str) -> String {
+        let Some(deployment) = self.deployment.as_deref() else {
+            return base_url.to_string();
+        };
+        let resource = base_url.trim_end_matches('/');
+        let resource = resource.strip_suffix("/openai").unwrap_or(resource);
+        format!("{resource}/openai/deployments/{}", deployment.trim())
+    }
+
+    /// Adds `api-version` to the provider's query parameters. The Azure setting wins over an
+    /// `api-version` entry in `query_params`.
+    pub(crate) fn request_query_params(
+        &self,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> Option<HashMap<String, String>> {
+        let Some(api_version) = self.api_version.as_ref() else {
+            return query_params.cloned();
+        };
+        let mut query_params = query_params.cloned().unwrap_or_default();
+        query_params.insert(
+            AZURE_API_VERSION_QUERY_PARAM.to_string(),
+            api_version.clone(),
+        );
+        Some(query_params)
+    }
+}
@@ -23,6 +23,11 @@ use std::collections::HashMap;
 use std::fmt;
 use std::time::Duration;
 
+mod azure;
+
+pub use azure::AZURE_API_KEY_HEADER;
+pub use azure::ModelProviderAzureInfo;
+
 const DEFAULT_STREAM_IDLE_TIMEOUT_MS: u64 = 300_000;
 const DEFAULT_STREAM_MAX_RETRIES: u64 = 5;
 const DEFAULT_REQUEST_MAX_RETRIES: u64 = 4;
@@ -36,6 +41,7 @@ const OPENAI_PROVIDER_NAME: &str = "OpenAI";
 const OPENAI_ACTOR_AUTHORIZATION_HEADER: &str = "x-openai-actor-authorization";
 pub const OPENAI_PROVIDER_ID: &str = "openai";
 pub const CHATGPT_CODEX_BASE_URL: &str = "https://chatgpt.com/backend-api/codex";
+pub const OPENAI_API_BASE_URL: &str = "https://api.openai.com/v1";
 const AMAZON_BEDROCK_PROVIDER_NAME: &str = "Amazon Bedrock";
 pub const AMAZON_BEDROCK_PROVIDER_ID: &str = "amazon-bedrock";
 pub const AMAZON_BEDROCK_GPT_5_5_MODEL_ID: &str = "openai.gpt-5.5";
@@ -106,6 +112,9 @@ pub struct ModelProviderInfo {
     pub auth: Option<ModelProviderAuthInfo>,
     /// AWS SigV4 auth configuration for this provider.
     pub aws: Option<ModelProviderAwsAuthInfo>,
+    /// Azure OpenAI deployment and API version for this provider. API keys from `env_key` are
+    /// sent in the `api-key` header.
+    pub azure: Option<ModelProviderAzureInfo>,
     /// Which wire protocol this provider expects.
     #[serde(default)]
     pub wire_api: WireApi,
@@ -170,6 +179,9 @@ impl ModelProviderInfo {
             if self.auth.is_some() {
                 conflicts.push("auth");
             }
+            if self.azure.is_some() {
+                conflicts.push("azure");
+            }
             if self.requires_openai_auth {
                 conflicts.push("requires_openai_auth");
             }
@@ -182,6 +194,13 @@ impl ModelProviderInfo {
             }
         }
 
+        if let Some(azure) = self.azure.as_ref() {
+            if self.base_url.is_none() {
+                return Err("provider azure requires base_url".to_string());
+            }
+            azure.validate()?;
+        }
+
         let Some(auth) = self.auth.as_ref() else {
             return Ok(());
         };
@@ -251,12 +270,16 @@ impl ModelProviderInfo {
         ) {
             CHATGPT_CODEX_BASE_URL
         } else {
-            "https://api.openai.com/v1"
+            OPENAI_API_BASE_URL
+        };
+        let base_url = self.base_url.as_deref().unwrap_or(default_base_url);
+        let (base_url, query_params) = match self.azure.as_ref() {
+            Some(azure) => (
+                azure.request_base_url(base_url),
+                azure.request_query_params(self.query_params.as_ref()),
+            ),
+            None => (base_url.to_string(), self.query_params.clone()),
         };
-        let base_url = self
-            .base_url
-            .clone()
-            .unwrap_or_else(|| default_base_url.to_string());
 
         let headers = self.build_header_map()?;
         let retry = ApiRetryConfig {
@@ -270,7 +293,7 @@ impl ModelProviderInfo {
         Ok(ApiProvider {
             name: self.name.clone(),
             base_url,
-            query_params: self.query_params.clone(),
+            query_params,
             headers,
             retry,
             stream_idle_timeout: self.stream_idle_timeout(),
@@ -335,6 +358,7 @@ impl ModelProviderInfo {
             experimental_bearer_token: None,
             auth: None,
             aws: None,
+            azure: None,
             wire_api: WireApi::Responses,
             query_params: None,
             http_headers: Some(
@@ -380,6 +404,7 @@ impl ModelProviderInfo {
                 profile: None,
                 region: None,
             })),
+            azure: None,
             wire_api: WireApi::Responses,
             query_params: None,
             http_headers: Some(HashMap::from([(
@@ -415,7 +440,9 @@ impl ModelProviderInfo {
     }
 
     pub fn supports_remote_compaction(&self) -> bool {
-        self.is_openai() || is_azure_responses_provider(&self.name, self.base_url.as_deref())
+        self.is_openai()
+            || self.azure.is_some()
+            || is_azure_responses_provider(&self.name, self.base_url.as_deref())
     }
 
     pub fn has_command_auth(&self) -> bool {
@@ -530,6 +557,7 @@ pub fn create_oss_provider_with_base_url(base_url: &str, wire_api: WireApi) -> M
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api,
         query_params: None,
         http_headers: None,
@@ -19,6 +19,7 @@ base_url = "http://localhost:11434/v1"
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -86,6 +87,7 @@ env_http_headers = { "X-Example-Env-Header" = "EXAMPLE_ENV_VAR" }
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: Some(maplit::hashmap! {
@@ -167,6 +169,7 @@ fn test_supports_remote_compaction_for_azure_name() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -192,6 +195,7 @@ fn test_supports_remote_compaction_for_non_openai_non_azure_provider() {
         experimental_bearer_token: None,
         auth: None,
         aws: None,
+        azure: None,
         wire_api: WireApi::Responses,
         query_params: None,
         http_headers: None,
@@ -297,6 +301,7 @@ fn test_create_amazon_bedrock_provider() {
                 profile: None,
                 region: None,
             }),
+            azure: None,
             wire_api: WireApi::Responses,
             query_params: None,
             http_headers: Some(maplit::hashmap! {
@@ -555,3 +560,92 @@ refresh_interval_ms = 0
     assert_eq!(auth.refresh_interval_ms, 0);
     assert_eq!(auth.refresh_interval(), None);
 }
+
+#[test]
+fn test_azure_deployment_builds_deployment_urls() {
+    let provider_toml = r#"
+name = "Azure"
+base_url = "https://my-resource.openai.azure.com/openai"
+env_key = "AZURE_OPENAI_API_KEY"
+
+[azure]
+deployment = "gpt-5-codex"
+api_version = "2025-04-01-preview"
+        "#;
+    let provider: ModelProviderInfo =
+        toml::from_str(provider_toml).expect("azure provider should deserialize");
+    let api_provider = provider
+        .to_api_provider(/*auth_mode*/ None)
+        .expect("azure provider should convert");
+
+    assert_eq!(provider.validate(), Ok(()));
+    assert_eq!(
+        (
+            api_provider.url_for_path("responses"),
+            api_provider.url_for_path("audio/transcriptions"),
+        ),
+        (
+            "https://my-resource.openai.azure.com/openai/deployments/gpt-5-codex/responses?api-version=2025-04-01-preview"
+                .to_string(),
+            "https://my-resource.openai.azure.com/openai/deployments/gpt-5-codex/audio/transcriptions?api-version=2025-04-01-preview"
+                .to_string(),
+        )
+    );
+}
+
+#[test]
+fn test_azure_api_version_overrides_query_param_without_deployment() {
+    let provider = ModelProviderInfo {
+        name: "Corp gateway".to_string(),
+        base_url: Some("https://gateway.example.com/openai/v1/".to_string()),
+        query_params: Some(maplit::hashmap! {
+            "api-version".to_string() => "2024-10-21".to_string(),
+        }),
+        azure: Some(ModelProviderAzureInfo {
+            deployment: None,
+            api_version: Some("preview".to_string()),
+        }),
+        ..ModelProviderInfo::default()
+    };
+    let api_provider = provider
+        .to_api_provider(/*auth_mode*/ None)
+        .expect("azure provider should convert");
+
+    assert_eq!(
+        api_provider.url_for_path("responses"),
+        "https://gateway.example.com/openai/v1/responses?api-version=preview"
+    );
+}
+
+#[test]
+fn test_validate_provider_azure_rejects_incomplete_config() {
+    let provider = |base_url: Option<&str>, deployment: &str, api_version: Option<&str>| {
+        ModelProviderInfo {
+            base_url: base_url.map(str::to_string),
+            azure: Some(ModelProviderAzureInfo {
+                deployment: Some(deployment.to_string()),
+                api_version: api_version.map(str::to_string),
+            }),
+            ..ModelProviderInfo::default()
+        }
+        .validate()
+    };
+    let resource = Some("https://my-resource.openai.azure.com");
+
+    assert_eq!(
+        [
+            provider(
+                /*base_url*/ None,
+                "gpt-5-codex",
+                Some("2025-04-01-preview")
+            ),
+            provider(resource, " ", Some("2025-04-01-preview")),
+            provider(resource, "gpt-5-codex", /*api_version*/ None),
+        ],
+        [
+            Err("provider azure requires base_url".to_string()),
+            Err("provider azure.deployment must not be empty".to_string()),
+            Err("provider azure.deployment requires azure.api_version".to_string()),
+        ]
+    );
+}
@@ -7,6 +7,7 @@ use codex_agent_identity::authorization_header_for_agent_task;
 use codex_api::AgentIdentityTelemetry;
 use codex_api::AuthProvider;
 use codex_api::SharedAuthProvider;
+use codex_http_client::register_http_trace_secret;
 use codex_login::AuthHeaders;
 use codex_login::AuthManager;
 use codex_login::CodexAuth;
@@ -19,6 +20,7 @@ use codex_protocol::protocol::SessionSource;
 use http::HeaderMap;
 use http::HeaderValue;
 
+use crate::azure_auth_provider::AzureApiKeyAuthProvider;
 use crate::bearer_auth_provider::BearerAuthProvider;
 
 const BEDROCK_API_KEY_UNSUPPORTED_MESSAGE: &str =
@@ -186,8 +188,8 @@ pub(crate) fn resolve_provider_auth(
         ));
     }
 
-    if let Some(auth) = bearer_auth_for_provider(provider)? {
-        return Ok(Arc::new(auth));
+    if let Some(auth) = api_key_auth_for_provider(provider)? {
+        return Ok(auth);
     }
 
     Ok(match auth {
@@ -264,15 +266,21 @@ fn should_bootstrap_chatgpt_agent_identity(
         && matches!(auth, Some(CodexAuth::Chatgpt(_)))
 }
 
-fn bearer_auth_for_provider(
+fn api_key_auth_for_provider(
     provider: &ModelProviderInfo,
-) -> codex_protocol::error::Result<Option<BearerAuthProvider>> {
+) -> codex_protocol::error::Result<Option<SharedAuthProvider>> {
     if let Some(api_key) = provider.api_key()? {
-        return Ok(Some(BearerAuthProvider::new(api_key)));
+        register_http_trace_secret(&api_key);
+        // Azure OpenAI takes keys in `api-key`; Entra ID tokens from command auth stay bearer.
+        if provider.azure.is_some() {
+            return Ok(Some(Arc::new(AzureApiKeyAuthProvider::new(api_key))));
+        }
+        return Ok(Some(Arc::new(BearerAuthProvider::new(api_key))));
     }
 
     if let Some(token) = provider.experimental_bearer_token.clone() {
-        return Ok(Some(BearerAuthProvider::new(token)));
+        register_http_trace_secret(&token);
+        return Ok(Some(Arc::new(BearerAuthProvider::new(token))));
     }
 
     Ok(None)
@@ -0,0 +1,54 @@
+use codex_api::AuthProvider;
+use codex_model_provider_info::AZURE_API_KEY_HEADER;
+use http::HeaderMap;
+use http::HeaderValue;
+use http::header::HeaderName;
+
+/// API-key auth for Azure OpenAI providers, which read the key from the `api-key` header
+/// instead of `Authorization: Bearer`.
+#[derive(Clone)]
+pub(crate) struct AzureApiKeyAuthProvider {
+    api_key: String,
+}
+
+impl AzureApiKeyAuthProvider {
+    pub(crate) fn new(api_key: String) -> Self {
+        Self { api_key }
+    }
+}
+
+impl AuthProvider for AzureApiKeyAuthProvider {
+    fn add_auth_headers(&self, headers: &mut HeaderMap) {
+        if let Ok(header) = HeaderValue::from_str(&self.api_key) {
+            let _ = headers.insert(HeaderName::from_static(AZURE_API_KEY_HEADER), header);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn azure_api_key_auth_provider_uses_api_key_header() {
+        let auth = AzureApiKeyAuthProvider::new("azure-key".to_string());
+        let mut headers = HeaderMap::new();
+
+        auth.add_auth_headers(&mut headers);
+
+        let mut expected = HeaderMap::new();
+        expected.insert(
+            HeaderName::from_static(AZURE_API_KEY_HEADER),
+            HeaderValue::from_static("azure-key"),
+        );
+        assert_eq!(headers, expected);
+        assert_eq!(
+            codex_api::auth_header_telemetry(&auth),
+            codex_api::AuthHeaderTelemetry {
+                attached: true,
+                name: Some(AZURE_API_KEY_HEADER),
+            }
+        );
+    }
+}
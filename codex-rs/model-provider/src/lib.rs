@@ -1,5 +1,6 @@
 mod amazon_bedrock;
 mod auth;
+mod azure_auth_provider;
 mod bearer_auth_provider;
 mod models_endpoint;
 mod provider;
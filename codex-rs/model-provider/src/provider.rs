@@ -431,6 +431,7 @@ mod tests {
             experimental_bearer_token: None,
             auth: None,
             aws: None,
+            azure: None,
             wire_api: WireApi::Responses,
             query_params: None,
             http_headers: None,
@@ -505,6 +505,9 @@ const DEFAULT_PROVIDER_AUTH_REFRESH_INTERVAL_MS: u64 = 300_000;
 #[schemars(deny_unknown_fields)]
 pub struct ModelProviderAuthInfo {
     /// Command to execute. Bare names are resolved via `PATH`; paths are resolved against `cwd`.
+    /// It prints the token on stdout, or a JSON object such as
+    /// `{"token": "...", "expires_at": "2026-01-01T00:00:00Z"}` to have the token fetched again
+    /// once it expires.
     pub command: String,
 
     /// Command arguments.
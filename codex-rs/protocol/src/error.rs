@@ -404,6 +404,9 @@ fn truncate_text(content: &str, policy: TruncationPolicy) -> String {
 pub struct RetryLimitReachedError {
     pub status: StatusCode,
     pub request_id: Option<String>,
+    /// Delay from the response's `Retry-After` header, when it gave one in
+    /// seconds.
+    pub retry_after: Option<Duration>,
 }
 
 impl std::fmt::Display for RetryLimitReachedError {
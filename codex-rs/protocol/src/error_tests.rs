@@ -24,6 +24,7 @@ fn rate_limit_snapshot() -> RateLimitSnapshot {
     RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 50.0,
             window_minutes: Some(60),
@@ -34,6 +35,7 @@ fn rate_limit_snapshot() -> RateLimitSnapshot {
             window_minutes: Some(120),
             resets_at: Some(secondary_reset_at),
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
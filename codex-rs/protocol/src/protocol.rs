@@ -121,6 +121,8 @@ pub const CONTEXT_WINDOW_OPEN_TAG: &str = "<context_window>";
 pub const CONTEXT_WINDOW_CLOSE_TAG: &str = "</context_window>";
 pub const CONTEXT_WINDOW_GUIDANCE_OPEN_TAG: &str = "<context_window_guidance>";
 pub const CONTEXT_WINDOW_GUIDANCE_CLOSE_TAG: &str = "</context_window_guidance>";
+pub const USER_SHELL_COMMAND_OPEN_TAG: &str = "<user_shell_command>";
+pub const USER_SHELL_COMMAND_CLOSE_TAG: &str = "</user_shell_command>";
 pub const USER_MESSAGE_BEGIN: &str = "## My request for Codex:";
 
 /// Removes the model-context prefix from a user message before displaying it.
@@ -1293,6 +1295,9 @@ pub enum EventMsg {
     /// Warning issued by the guardian automatic approval reviewer.
     GuardianWarning(WarningEvent),
 
+    /// Rate-limit usage crossed a configured `[limits] warn_at` threshold.
+    RateLimitWarning(RateLimitWarningEvent),
+
     /// Realtime conversation lifecycle start event.
     RealtimeConversationStarted(RealtimeConversationStartedEvent),
 
@@ -2149,8 +2154,14 @@ pub struct TokenCountEvent {
 pub struct RateLimitSnapshot {
     pub limit_id: Option<String>,
     pub limit_name: Option<String>,
+    /// Whose usage this limit counts, when the backend says.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<RateLimitScope>,
     pub primary: Option<RateLimitWindow>,
     pub secondary: Option<RateLimitWindow>,
+    /// Additional windows reported for this limit, such as per-model or burst limits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra: Vec<ExtraRateLimitWindow>,
     pub credits: Option<CreditsSnapshot>,
     pub individual_limit: Option<SpendControlLimitSnapshot>,
     /// Backend-reported spend-control state. `None` is unavailable, not a sparse-update recovery.
@@ -2159,6 +2170,30 @@ pub struct RateLimitSnapshot {
     pub rate_limit_reached_type: Option<RateLimitReachedType>,
 }
 
+/// Whose usage a rate limit counts. Accounts with organization or project
+/// budgets report a limit per scope alongside their personal one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(rename_all = "snake_case")]
+pub enum RateLimitScope {
+    Personal,
+    Organization,
+    Project,
+}
+
+impl FromStr for RateLimitScope {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "personal" | "user" | "individual" => Ok(Self::Personal),
+            "organization" | "org" | "workspace" => Ok(Self::Organization),
+            "project" => Ok(Self::Project),
+            other => Err(format!("unknown rate limit scope: {other}")),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema, TS)]
 #[serde(rename_all = "snake_case")]
 #[ts(rename_all = "snake_case")]
@@ -2197,6 +2232,42 @@ pub struct RateLimitWindow {
     pub resets_at: Option<i64>,
 }
 
+/// A rate-limit window beyond `primary`/`secondary`, identified by the label
+/// the backend reported it under.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ExtraRateLimitWindow {
+    pub label: String,
+    /// Percentage (0-100) of the window that has been consumed.
+    pub used_percent: f64,
+    /// Rolling window duration, in minutes.
+    #[ts(type = "number | null")]
+    pub window_minutes: Option<i64>,
+    /// Unix timestamp (seconds since epoch) when the window resets.
+    #[ts(type = "number | null")]
+    pub resets_at: Option<i64>,
+}
+
+/// Emitted the first time usage in a rate-limit window reaches a configured
+/// threshold. Each threshold re-arms once usage drops back below it.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, TS)]
+pub struct RateLimitWarningEvent {
+    pub limit_id: Option<String>,
+    pub limit_name: Option<String>,
+    pub window_kind: RateLimitWindowKind,
+    /// Highest configured threshold, in percent, that usage has reached.
+    pub threshold_percent: u8,
+    /// Window snapshot that triggered the warning, including the projected reset time.
+    pub window: RateLimitWindow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(rename_all = "snake_case")]
+pub enum RateLimitWindowKind {
+    Primary,
+    Secondary,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema, TS)]
 pub struct CreditsSnapshot {
     pub has_credits: bool,
@@ -2379,6 +2450,29 @@ pub fn user_message_preview(user: &UserMessageEvent) -> Option<String> {
     None
 }
 
+/// Returns the preview text for a command the user ran directly, which is
+/// recorded as a user message wrapped in [`USER_SHELL_COMMAND_OPEN_TAG`].
+pub fn user_shell_command_preview(item: &ResponseItem) -> Option<String> {
+    let ResponseItem::Message { role, content, .. } = item else {
+        return None;
+    };
+    if role != "user" {
+        return None;
+    }
+    content.iter().find_map(|content| {
+        let ContentItem::InputText { text } = content else {
+            return None;
+        };
+        let (_, command) = text
+            .trim_start()
+            .strip_prefix(USER_SHELL_COMMAND_OPEN_TAG)?
+            .split_once("<command>")?;
+        let (command, _) = command.split_once("</command>")?;
+        let command = command.trim();
+        (!command.is_empty()).then(|| format!("[Command] {command}"))
+    })
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct AgentReasoningEvent {
     pub text: String,
@@ -2700,6 +2794,7 @@ impl InitialHistory {
                 | RolloutItem::InterAgentCommunication(_)
                 | RolloutItem::InterAgentCommunicationMetadata { .. }
                 | RolloutItem::Compacted(_)
+                | RolloutItem::TurnSummary(_)
                 | RolloutItem::WorldState(_)
                 | RolloutItem::EventMsg(_) => None,
             })
@@ -3036,6 +3131,7 @@ fn multi_agent_version_from_items(
             | RolloutItem::InterAgentCommunication(_)
             | RolloutItem::InterAgentCommunicationMetadata { .. }
             | RolloutItem::Compacted(_)
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_)
             | RolloutItem::EventMsg(_) => None,
         })
@@ -3219,6 +3315,8 @@ pub enum RolloutItem {
     },
     Compacted(CompactedItem),
     TurnContext(TurnContextItem),
+    /// Written once when a turn ends, after its other items.
+    TurnSummary(TurnSummaryItem),
     WorldState(WorldStateItem),
     EventMsg(EventMsg),
 }
@@ -3274,6 +3372,26 @@ impl From<CompactedItem> for ResponseItem {
     }
 }
 
+/// Per-turn metadata persisted when a turn completes or is aborted, so readers
+/// do not have to reconstruct it from the event stream. Rollouts written
+/// before this item existed simply lack it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, TS)]
+pub struct TurnSummaryItem {
+    pub turn_id: String,
+    /// Wall-clock time between turn start and completion in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(type = "number | null", optional)]
+    pub duration_ms: Option<i64>,
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<ReasoningEffortConfig>,
+    /// Number of tool calls dispatched during the turn, keyed by tool name.
+    #[serde(default)]
+    pub tool_calls: BTreeMap<String, u64>,
+    /// Tokens used by this turn alone.
+    pub token_usage: TokenUsage,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, TS)]
 pub struct TurnContextNetworkItem {
     pub allowed_domains: Vec<String>,
@@ -5898,6 +6016,26 @@ mod tests {
         assert_eq!(user_message_preview(&event), Some("[Audio]".to_string()));
     }
 
+    #[test]
+    fn user_shell_command_has_command_preview() {
+        let item = ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: "<user_shell_command>\n<command>\ncargo test\n</command>\n<result>\n\
+                       Exit code: 0\n</result>\n</user_shell_command>"
+                    .to_string(),
+            }],
+            phase: None,
+            internal_chat_message_metadata_passthrough: None,
+        };
+
+        assert_eq!(
+            user_shell_command_preview(&item),
+            Some("[Command] cargo test".to_string())
+        );
+    }
+
     #[test]
     fn turn_aborted_event_deserializes_without_turn_id() -> Result<()> {
         let event: EventMsg = serde_json::from_value(json!({
@@ -5918,6 +6056,81 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn rate_limit_snapshot_round_trips_extra_windows() -> Result<()> {
+        let value = json!({
+            "limit_id": "codex",
+            "limit_name": null,
+            "primary": {"used_percent": 10.5, "window_minutes": 300, "resets_at": 100},
+            "secondary": {"used_percent": 20.0, "window_minutes": 10080, "resets_at": 200},
+            "extra": [
+                {"label": "burst", "used_percent": 55.5, "window_minutes": 1, "resets_at": 60},
+            ],
+            "credits": null,
+            "individual_limit": null,
+            "spend_control_reached": null,
+            "plan_type": null,
+            "rate_limit_reached_type": null,
+        });
+
+        let snapshot: RateLimitSnapshot = serde_json::from_value(value.clone())?;
+        assert_eq!(
+            snapshot.extra,
+            vec![ExtraRateLimitWindow {
+                label: "burst".to_string(),
+                used_percent: 55.5,
+                window_minutes: Some(1),
+                resets_at: Some(60),
+            }]
+        );
+        assert_eq!(serde_json::to_value(&snapshot)?, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rate_limit_snapshot_deserializes_without_extra_windows() -> Result<()> {
+        let value = json!({
+            "limit_id": "codex",
+            "limit_name": null,
+            "primary": {"used_percent": 10.5, "window_minutes": 300, "resets_at": 100},
+            "secondary": null,
+            "credits": null,
+            "individual_limit": null,
+            "spend_control_reached": null,
+            "plan_type": null,
+            "rate_limit_reached_type": null,
+        });
+
+        let snapshot: RateLimitSnapshot = serde_json::from_value(value.clone())?;
+        assert_eq!(snapshot.extra, Vec::new());
+        assert_eq!(serde_json::to_value(&snapshot)?, value);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rate_limit_snapshot_round_trips_scope() -> Result<()> {
+        let value = json!({
+            "limit_id": "codex_project",
+            "limit_name": "Project budget",
+            "scope": "project",
+            "primary": {"used_percent": 40.0, "window_minutes": 43200, "resets_at": 100},
+            "secondary": null,
+            "credits": null,
+            "individual_limit": null,
+            "spend_control_reached": null,
+            "plan_type": null,
+            "rate_limit_reached_type": null,
+        });
+
+        let snapshot: RateLimitSnapshot = serde_json::from_value(value.clone())?;
+        assert_eq!(snapshot.scope, Some(RateLimitScope::Project));
+        assert_eq!(serde_json::to_value(&snapshot)?, value);
+
+        Ok(())
+    }
+
     #[test]
     fn session_meta_defaults_legacy_history_mode() -> Result<()> {
         let session_meta: SessionMeta = serde_json::from_value(json!({
@@ -12,6 +12,7 @@ mod rmcp_client;
 mod startup_error;
 mod stdio_server_launcher;
 mod utils;
+mod websocket_transport;
 
 pub use auth_status::McpAuthState;
 pub use auth_status::McpLoginRequirement;
@@ -77,6 +77,9 @@ use crate::stdio_server_launcher::StdioServerLauncher;
 use crate::stdio_server_launcher::StdioServerProcessHandle;
 use crate::stdio_server_launcher::StdioServerTransport;
 use crate::utils::build_default_headers;
+use crate::websocket_transport::WEBSOCKET_RECONNECT_RETRY_DELAYS_MS;
+use crate::websocket_transport::WebSocketTransport;
+use crate::websocket_transport::connect_websocket_transport;
 use codex_config::types::OAuthCredentialsStoreMode;
 
 #[path = "streamable_http_retry.rs"]
@@ -100,6 +103,9 @@ enum PendingTransport {
         transport: StreamableHttpClientTransport<AuthClient<StreamableHttpClientAdapter>>,
         oauth_persistor: OAuthPersistor,
     },
+    WebSocket {
+        transport: WebSocketTransport,
+    },
 }
 
 enum ClientState {
@@ -134,6 +140,12 @@ enum TransportRecipe {
         http_client: Arc<dyn HttpClient>,
         auth_provider: Option<SharedAuthProvider>,
     },
+    WebSocket {
+        ws_url: String,
+        bearer_token: Option<String>,
+        http_headers: Option<HashMap<String, String>>,
+        env_http_headers: Option<HashMap<String, String>>,
+    },
 }
 
 #[derive(Clone)]
@@ -372,7 +384,8 @@ impl RmcpClient {
             PendingTransport::Stdio { transport } => Some(transport.process_handle()),
             PendingTransport::InProcess { .. }
             | PendingTransport::StreamableHttp { .. }
-            | PendingTransport::StreamableHttpWithOAuth { .. } => None,
+            | PendingTransport::StreamableHttpWithOAuth { .. }
+            | PendingTransport::WebSocket { .. } => None,
         };
 
         Ok(Self {
@@ -424,6 +437,34 @@ impl RmcpClient {
         })
     }
 
+    /// Connects to an MCP server that exchanges JSON-RPC messages over a
+    /// WebSocket. If the connection drops, the next operation reconnects with
+    /// backoff and re-runs the initialize handshake.
+    pub async fn new_websocket_client(
+        ws_url: &str,
+        bearer_token: Option<String>,
+        http_headers: Option<HashMap<String, String>>,
+        env_http_headers: Option<HashMap<String, String>>,
+    ) -> Result<Self> {
+        let transport_recipe = TransportRecipe::WebSocket {
+            ws_url: ws_url.to_string(),
+            bearer_token,
+            http_headers,
+            env_http_headers,
+        };
+        let transport = Self::create_pending_transport(&transport_recipe).await?;
+        Ok(Self {
+            state: Mutex::new(ClientState::Connecting {
+                transport: Some(transport),
+            }),
+            stdio_process: None,
+            transport_recipe,
+            initialize_context: Mutex::new(None),
+            session_recovery_lock: Semaphore::new(/*permits*/ 1),
+            elicitation_pause_state: ElicitationPauseState::new(),
+        })
+    }
+
     /// Perform the initialization handshake with the MCP server.
     /// https://modelcontextprotocol.io/specification/2025-06-18/basic/lifecycle#initialization
     #[instrument(level = "trace", skip_all)]
@@ -910,6 +951,19 @@ impl RmcpClient {
                     Ok(PendingTransport::StreamableHttp { transport })
                 }
             }
+            TransportRecipe::WebSocket {
+                ws_url,
+                bearer_token,
+                http_headers,
+                env_http_headers,
+            } => {
+                let default_headers =
+                    build_default_headers(http_headers.clone(), env_http_headers.clone())?;
+                let transport =
+                    connect_websocket_transport(ws_url, bearer_token.as_deref(), default_headers)
+                        .await?;
+                Ok(PendingTransport::WebSocket { transport })
+            }
         }
     }
 
@@ -941,6 +995,10 @@ impl RmcpClient {
                 service::serve_client(client_service, transport).boxed(),
                 Some(oauth_persistor),
             ),
+            PendingTransport::WebSocket { transport } => (
+                service::serve_client(client_service, (transport.sink, transport.stream)).boxed(),
+                None,
+            ),
         };
 
         let service_result = match timeout {
@@ -994,8 +1052,14 @@ impl RmcpClient {
         .await
         {
             Ok(result) => Ok(result),
-            Err(error) if Self::is_session_expired_404(&error) => {
-                self.reinitialize_after_session_expiry(&service).await?;
+            Err(error)
+                if Self::is_session_expired_404(&error) || self.is_websocket_closed(&error) =>
+            {
+                if self.is_websocket_closed(&error) {
+                    self.reconnect_websocket_with_backoff(&service).await?;
+                } else {
+                    self.reinitialize_after_session_expiry(&service).await?;
+                }
                 let recovered_service = self.service().await?;
                 Self::run_service_operation_with_transient_retries(
                     recovered_service,
@@ -1128,6 +1192,48 @@ impl RmcpClient {
             })
     }
 
+    /// A dropped WebSocket cannot be resumed, so treat a closed transport as a
+    /// signal to reconnect and re-run the initialize handshake.
+    fn is_websocket_closed(&self, error: &ClientOperationError) -> bool {
+        matches!(self.transport_recipe, TransportRecipe::WebSocket { .. })
+            && matches!(
+                error,
+                ClientOperationError::Service(
+                    rmcp::service::ServiceError::TransportClosed
+                        | rmcp::service::ServiceError::TransportSend(_)
+                )
+            )
+    }
+
+    /// Reconnects a dropped WebSocket session and re-runs the initialize
+    /// handshake, backing off between attempts while the server is down.
+    async fn reconnect_websocket_with_backoff(
+        &self,
+        failed_service: &Arc<RunningService<RoleClient, ElicitationClientService>>,
+    ) -> Result<()> {
+        let mut retry_delays = WEBSOCKET_RECONNECT_RETRY_DELAYS_MS.iter().copied();
+        let mut attempt = 1;
+        loop {
+            let error = match self.reinitialize_after_session_expiry(failed_service).await {
+                Ok(()) => return Ok(()),
+                Err(error) => error,
+            };
+            let Some(retry_delay_ms) = retry_delays.next() else {
+                return Err(error.context("failed to reconnect to websocket MCP server"));
+            };
+            let delay = Duration::from_millis(retry_delay_ms);
+            warn!(
+                attempt,
+                max_attempts = WEBSOCKET_RECONNECT_RETRY_DELAYS_MS.len() + 1,
+                delay_ms = delay.as_millis(),
+                error = %error,
+                "websocket MCP reconnect failed; retrying"
+            );
+            time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
     async fn reinitialize_after_session_expiry(
         &self,
         failed_service: &Arc<RunningService<RoleClient, ElicitationClientService>>,
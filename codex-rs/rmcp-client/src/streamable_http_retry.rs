@@ -33,7 +33,9 @@ impl RmcpClient {
         Option<OAuthPersistor>,
     )> {
         let should_retry = match &initial_transport {
-            PendingTransport::InProcess { .. } | PendingTransport::Stdio { .. } => false,
+            PendingTransport::InProcess { .. }
+            | PendingTransport::Stdio { .. }
+            | PendingTransport::WebSocket { .. } => false,
             PendingTransport::StreamableHttp { .. }
             | PendingTransport::StreamableHttpWithOAuth { .. } => true,
         };
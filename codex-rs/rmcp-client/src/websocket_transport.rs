@@ -0,0 +1,145 @@
+//! JSON-RPC over WebSocket transport for MCP servers configured with `ws_url`.
+//!
+//! Each MCP message is sent as a single text frame. `rmcp` owns request/response
+//! correlation, so this module only converts between frames and JSON-RPC
+//! messages and (re)establishes the connection.
+
+use std::pin::Pin;
+use std::time::Duration;
+
+use anyhow::Context;
+use anyhow::Result;
+use futures::Sink;
+use futures::SinkExt;
+use futures::Stream;
+use futures::StreamExt;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
+use rmcp::service::RoleClient;
+use rmcp::service::RxJsonRpcMessage;
+use rmcp::service::TxJsonRpcMessage;
+use tokio::time;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tracing::warn;
+
+/// Delays between failed connection attempts. The connection is attempted
+/// `WEBSOCKET_CONNECT_RETRY_DELAYS_MS.len() + 1` times in total.
+const WEBSOCKET_CONNECT_RETRY_DELAYS_MS: [u64; 3] = [250, 1_000, 4_000];
+
+/// Delays between attempts to restore a session after the connection drops.
+/// Each attempt reconnects (with the connect retries above) and re-runs the
+/// initialize handshake, so a server that restarts is picked up again.
+pub(crate) const WEBSOCKET_RECONNECT_RETRY_DELAYS_MS: [u64; 4] = [500, 2_000, 8_000, 30_000];
+
+pub(crate) type WebSocketSink =
+    Pin<Box<dyn Sink<TxJsonRpcMessage<RoleClient>, Error = WebSocketTransportError> + Send>>;
+pub(crate) type WebSocketStream = Pin<Box<dyn Stream<Item = RxJsonRpcMessage<RoleClient>> + Send>>;
+
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum WebSocketTransportError {
+    #[error("failed to serialize MCP message: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("websocket error: {0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+}
+
+/// Connected WebSocket split into the sink/stream pair `rmcp` accepts as a
+/// transport.
+pub(crate) struct WebSocketTransport {
+    pub(crate) sink: WebSocketSink,
+    pub(crate) stream: WebSocketStream,
+}
+
+/// Connects to `ws_url`, retrying with backoff when the connection cannot be
+/// established.
+pub(crate) async fn connect_websocket_transport(
+    ws_url: &str,
+    bearer_token: Option<&str>,
+    default_headers: HeaderMap,
+) -> Result<WebSocketTransport> {
+    let mut headers = default_headers;
+    if let Some(bearer_token) = bearer_token {
+        let value = HeaderValue::from_str(&format!("Bearer {bearer_token}"))
+            .context("invalid bearer token for websocket MCP server")?;
+        headers.insert(AUTHORIZATION, value);
+    }
+
+    for (attempt, retry_delay_ms) in WEBSOCKET_CONNECT_RETRY_DELAYS_MS
+        .iter()
+        .copied()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .enumerate()
+    {
+        let mut request = ws_url
+            .into_client_request()
+            .with_context(|| format!("invalid websocket MCP url `{ws_url}`"))?;
+        request.headers_mut().extend(headers.clone());
+
+        match connect_async(request).await {
+            Ok((stream, _response)) => return Ok(split_websocket(stream)),
+            Err(error) => {
+                let Some(retry_delay_ms) = retry_delay_ms else {
+                    return Err(anyhow::Error::from(error).context(format!(
+                        "failed to connect to websocket MCP server `{ws_url}`"
+                    )));
+                };
+                let delay = Duration::from_millis(retry_delay_ms);
+                warn!(
+                    attempt = attempt + 1,
+                    max_attempts = WEBSOCKET_CONNECT_RETRY_DELAYS_MS.len() + 1,
+                    delay_ms = delay.as_millis(),
+                    error = %error,
+                    "websocket MCP connection failed; retrying"
+                );
+                time::sleep(delay).await;
+            }
+        }
+    }
+
+    unreachable!("websocket connect loop should return on success or final error")
+}
+
+fn split_websocket<S>(websocket: S) -> WebSocketTransport
+where
+    S: Sink<Message, Error = tokio_tungstenite::tungstenite::Error>
+        + Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>>
+        + Send
+        + 'static,
+{
+    let (sink, stream) = websocket.split();
+    let sink = sink.sink_map_err(WebSocketTransportError::from).with(
+        |message: TxJsonRpcMessage<RoleClient>| async move {
+            let text = serde_json::to_string(&message)?;
+            Ok::<_, WebSocketTransportError>(Message::Text(text.into()))
+        },
+    );
+    let stream = stream.filter_map(|frame| async move {
+        let payload = match frame {
+            Ok(Message::Text(text)) => text.as_bytes().to_vec(),
+            Ok(Message::Binary(bytes)) => bytes.to_vec(),
+            Ok(Message::Ping(_) | Message::Pong(_) | Message::Frame(_) | Message::Close(_)) => {
+                return None;
+            }
+            Err(error) => {
+                warn!("websocket MCP connection failed: {error}");
+                return None;
+            }
+        };
+        match serde_json::from_slice::<RxJsonRpcMessage<RoleClient>>(&payload) {
+            Ok(message) => Some(message),
+            Err(error) => {
+                warn!("ignoring malformed websocket MCP message: {error}");
+                None
+            }
+        }
+    });
+
+    WebSocketTransport {
+        sink: Box::pin(sink),
+        stream: Box::pin(stream),
+    }
+}
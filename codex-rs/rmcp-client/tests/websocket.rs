@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::SendElicitation;
+use futures::FutureExt as _;
+use futures::SinkExt as _;
+use futures::StreamExt as _;
+use pretty_assertions::assert_eq;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::Content;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParams;
+use rmcp::model::ProtocolVersion;
+use serde_json::Value;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio::task::JoinSet;
+use tokio_tungstenite::accept_async;
+use tokio_tungstenite::tungstenite::Message;
+
+fn init_params() -> InitializeRequestParams {
+    InitializeRequestParams::new(
+        ClientCapabilities::default(),
+        Implementation::new("codex-test", "0.0.0-test").with_title("Codex rmcp websocket test"),
+    )
+    .with_protocol_version(ProtocolVersion::V_2025_06_18)
+}
+
+async fn spawn_websocket_mcp_stub() -> anyhow::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    tokio::spawn(serve_websocket_mcp_stub(listener));
+    Ok(format!("ws://{addr}/mcp"))
+}
+
+/// Minimal MCP server that answers `initialize`, `tools/list`, and an `echo`
+/// tool over WebSocket connections. Aborting the task drops every open
+/// connection.
+async fn serve_websocket_mcp_stub(listener: TcpListener) {
+    let mut connections = JoinSet::new();
+    while let Ok((stream, _)) = listener.accept().await {
+        connections.spawn(async move {
+            let Ok(mut websocket) = accept_async(stream).await else {
+                return;
+            };
+            while let Some(Ok(frame)) = websocket.next().await {
+                let Message::Text(text) = frame else {
+                    continue;
+                };
+                let request: Value = serde_json::from_str(&text).expect("valid JSON-RPC");
+                let Some(id) = request.get("id").cloned() else {
+                    // Notifications such as `notifications/initialized`.
+                    continue;
+                };
+                let result = match request["method"].as_str() {
+                    Some("initialize") => json!({
+                        "protocolVersion": "2025-06-18",
+                        "capabilities": { "tools": {} },
+                        "serverInfo": { "name": "websocket-stub", "version": "0.0.0" },
+                    }),
+                    Some("tools/list") => json!({
+                        "tools": [{
+                            "name": "echo",
+                            "description": "Echo a message back.",
+                            "inputSchema": {
+                                "type": "object",
+                                "properties": { "message": { "type": "string" } },
+                            },
+                        }],
+                    }),
+                    Some("tools/call") => {
+                        let message = request["params"]["arguments"]["message"]
+                            .as_str()
+                            .unwrap_or_default();
+                        json!({
+                            "content": [{ "type": "text", "text": format!("echo: {message}") }],
+                        })
+                    }
+                    _ => json!({}),
+                };
+                let response = json!({ "jsonrpc": "2.0", "id": id, "result": result });
+                if websocket
+                    .send(Message::Text(response.to_string().into()))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+        });
+    }
+}
+
+fn accept_elicitation() -> SendElicitation {
+    Box::new(|_, _| {
+        async {
+            Ok(ElicitationResponse {
+                action: ElicitationAction::Accept,
+                content: Some(json!({})),
+                meta: None,
+            })
+        }
+        .boxed()
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn websocket_client_lists_and_calls_tools() -> anyhow::Result<()> {
+    let ws_url = spawn_websocket_mcp_stub().await?;
+    let client = RmcpClient::new_websocket_client(
+        &ws_url, /*bearer_token*/ None, /*http_headers*/ None,
+        /*env_http_headers*/ None,
+    )
+    .await?;
+
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            accept_elicitation(),
+        )
+        .await?;
+
+    let tools = client
+        .list_tools(/*params*/ None, Some(Duration::from_secs(5)))
+        .await?;
+    assert_eq!(
+        tools
+            .tools
+            .iter()
+            .map(|tool| tool.name.to_string())
+            .collect::<Vec<_>>(),
+        vec!["echo".to_string()]
+    );
+
+    let result = client
+        .call_tool(
+            "echo".to_string(),
+            Some(json!({ "message": "hello" })),
+            /*meta*/ None,
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(result.content, vec![Content::text("echo: hello")]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn websocket_client_reconnects_after_server_restart() -> anyhow::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let server = tokio::spawn(serve_websocket_mcp_stub(listener));
+    let client = RmcpClient::new_websocket_client(
+        &format!("ws://{addr}/mcp"),
+        /*bearer_token*/ None,
+        /*http_headers*/ None,
+        /*env_http_headers*/ None,
+    )
+    .await?;
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            accept_elicitation(),
+        )
+        .await?;
+
+    // Kill the server mid-session and bring it back on the same address a
+    // little later, so the client has to back off before reconnecting.
+    server.abort();
+    let _ = server.await;
+    let restarted_server = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let listener = TcpListener::bind(addr)
+            .await
+            .expect("rebind websocket stub");
+        serve_websocket_mcp_stub(listener).await;
+    });
+
+    let result = client
+        .call_tool(
+            "echo".to_string(),
+            Some(json!({ "message": "again" })),
+            /*meta*/ None,
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(result.content, vec![Content::text("echo: again")]);
+
+    restarted_server.abort();
+    Ok(())
+}
@@ -0,0 +1,271 @@
+//! Rewrites plain rollouts without superseded `token_count` events.
+//!
+//! Every turn appends a cumulative `token_count`, so only the newest usage and
+//! the newest rate limits carry information. Compaction keeps one merged
+//! `token_count` in place of the last one and copies every other line
+//! byte-for-byte, so conversational history replays exactly as before.
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::fs::FileTimes;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use serde_json::Value;
+
+use crate::ARCHIVED_SESSIONS_SUBDIR;
+use crate::SESSIONS_SUBDIR;
+use crate::compression;
+use crate::compression::RolloutFile;
+use crate::encryption;
+use crate::lock::RolloutLock;
+use crate::prune::ACTIVE_ROLLOUT_GRACE;
+
+/// Size of one rollout before and after [`compact_rollout`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RolloutCompaction {
+    pub lines_before: usize,
+    pub lines_after: usize,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
+/// Totals from one [`compact_rollouts`] pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutCompactionStats {
+    pub scanned: usize,
+    /// Rollouts that were rewritten because they had superseded records.
+    pub compacted: usize,
+    /// Compressed and encrypted rollouts, rollouts modified within
+    /// [`ACTIVE_ROLLOUT_GRACE`], and rollouts locked by another Codex process.
+    pub skipped: usize,
+    pub failed: usize,
+    pub bytes_saved: u64,
+}
+
+/// Rewrites the plain rollout at `path`, keeping only the final cumulative
+/// token usage and rate limits.
+///
+/// Rollouts that may still be open by a running session or are locked by
+/// another Codex process, and compressed or encrypted rollouts, are rejected. The modification time is carried over so listing
+/// order is unchanged. A rollout with nothing to drop is left untouched.
+pub async fn compact_rollout(path: &Path) -> io::Result<RolloutCompaction> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || compact_rollout_blocking(path.as_path()))
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Compacts every plain rollout under `codex_home`, including archived
+/// sessions. Failures are counted rather than stopping the pass.
+pub async fn compact_rollouts(codex_home: &Path) -> io::Result<RolloutCompactionStats> {
+    let mut stats = RolloutCompactionStats::default();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        for rollout_file in collect_rollout_files(codex_home.join(root).as_path()).await? {
+            stats.scanned += 1;
+            if rollout_file.is_compressed()
+                || rollout_file.is_encrypted()
+                || !is_cold(rollout_file.path()).await?
+            {
+                stats.skipped += 1;
+                continue;
+            }
+            match compact_rollout(rollout_file.path()).await {
+                Ok(compaction) => {
+                    if compaction.lines_after < compaction.lines_before {
+                        stats.compacted += 1;
+                    }
+                    stats.bytes_saved += compaction
+                        .bytes_before
+                        .saturating_sub(compaction.bytes_after);
+                }
+                Err(err) if err.kind() == io::ErrorKind::ResourceBusy => {
+                    tracing::info!("skipping rollout {}: {err}", rollout_file.path().display());
+                    stats.skipped += 1;
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "failed to compact rollout {}: {err}",
+                        rollout_file.path().display()
+                    );
+                    stats.failed += 1;
+                }
+            }
+        }
+    }
+    Ok(stats)
+}
+
+fn compact_rollout_blocking(path: &Path) -> io::Result<RolloutCompaction> {
+    if compression::is_compressed_rollout_path(path) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "compressed rollouts cannot be compacted",
+        ));
+    }
+    if encryption::is_encrypted_rollout_path(path) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "encrypted rollouts cannot be compacted",
+        ));
+    }
+    let metadata = std::fs::metadata(path)?;
+    let modified = metadata.modified()?;
+    if !is_cold_since(modified) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "rollouts modified in the last day may still be in use and cannot be compacted",
+        ));
+    }
+    let Some(_lock) = RolloutLock::try_acquire(path, /*exclusive*/ true)? else {
+        return Err(io::Error::new(
+            io::ErrorKind::ResourceBusy,
+            "in use by another Codex process",
+        ));
+    };
+
+    let lines = BufReader::new(File::open(path)?)
+        .lines()
+        .collect::<io::Result<Vec<_>>>()?;
+    let compacted = compact_lines(&lines)?;
+    let mut compaction = RolloutCompaction {
+        lines_before: lines.len(),
+        lines_after: compacted.len(),
+        bytes_before: metadata.len(),
+        bytes_after: metadata.len(),
+    };
+    if compacted.len() == lines.len() {
+        return Ok(compaction);
+    }
+
+    let temp_path = compression::temp_path_for(path, "compact");
+    let result = (|| {
+        let mut output = compression::create_file_with_permissions(
+            temp_path.as_path(),
+            &metadata.permissions(),
+        )?;
+        for line in &compacted {
+            output.write_all(line.as_bytes())?;
+            output.write_all(b"\n")?;
+        }
+        output.set_times(FileTimes::new().set_modified(modified))?;
+        output.sync_all()?;
+        compaction.bytes_after = output.metadata()?.len();
+
+        let current = std::fs::metadata(path)?;
+        if current.len() != metadata.len() || current.modified()? != modified {
+            return Err(io::Error::other(
+                "rollout changed while it was being compacted",
+            ));
+        }
+        std::fs::rename(temp_path.as_path(), path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(temp_path.as_path());
+    }
+    result?;
+    Ok(compaction)
+}
+
+/// Returns the lines to keep, or the input unchanged when at most one
+/// `token_count` is present.
+fn compact_lines(lines: &[String]) -> io::Result<Vec<String>> {
+    let mut token_counts = Vec::new();
+    let mut info = Value::Null;
+    let mut rate_limits = Value::Null;
+    for (index, line) in lines.iter().enumerate() {
+        let Some(payload) = token_count_payload(line) else {
+            continue;
+        };
+        token_counts.push(index);
+        if let Some(value) = payload.get("info").filter(|value| !value.is_null()) {
+            info = value.clone();
+        }
+        if let Some(value) = payload.get("rate_limits").filter(|value| !value.is_null()) {
+            rate_limits = value.clone();
+        }
+    }
+    let Some((&last, superseded)) = token_counts.split_last() else {
+        return Ok(lines.to_vec());
+    };
+    if superseded.is_empty() {
+        return Ok(lines.to_vec());
+    }
+    let superseded = superseded.iter().copied().collect::<HashSet<_>>();
+
+    let mut merged: Value = serde_json::from_str(&lines[last]).map_err(io::Error::other)?;
+    if let Some(payload) = merged.get_mut("payload").and_then(Value::as_object_mut) {
+        payload.insert("info".to_string(), info);
+        payload.insert("rate_limits".to_string(), rate_limits);
+    }
+    let merged = serde_json::to_string(&merged).map_err(io::Error::other)?;
+
+    Ok(lines
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !superseded.contains(index))
+        .map(|(index, line)| {
+            if index == last {
+                merged.clone()
+            } else {
+                line.clone()
+            }
+        })
+        .collect())
+}
+
+fn token_count_payload(line: &str) -> Option<Value> {
+    // Cheap pre-check so only candidate lines are parsed.
+    if !line.contains("\"token_count\"") {
+        return None;
+    }
+    let value: Value = serde_json::from_str(line).ok()?;
+    if value.get("type").and_then(Value::as_str) != Some("event_msg") {
+        return None;
+    }
+    let payload = value.get("payload")?;
+    (payload.get("type").and_then(Value::as_str) == Some("token_count")).then(|| payload.clone())
+}
+
+fn is_cold_since(modified: SystemTime) -> bool {
+    SystemTime::now()
+        .duration_since(modified)
+        .is_ok_and(|age| age >= ACTIVE_ROLLOUT_GRACE)
+}
+
+pub(crate) async fn is_cold(path: &Path) -> io::Result<bool> {
+    let modified = tokio::fs::metadata(path).await?.modified()?;
+    Ok(is_cold_since(modified))
+}
+
+pub(crate) async fn collect_rollout_files(root: &Path) -> io::Result<Vec<RolloutFile>> {
+    let mut rollout_files = Vec::new();
+    let mut dirs: Vec<PathBuf> = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+            } else if file_type.is_file()
+                && let Some(rollout_file) = RolloutFile::from_path(entry.path())
+            {
+                rollout_files.push(rollout_file);
+            }
+        }
+    }
+    Ok(rollout_files)
+}
+
+#[cfg(test)]
+#[path = "compact_tests.rs"]
+mod tests;
@@ -0,0 +1,224 @@
+use std::fs;
+use std::fs::FileTimes;
+use std::time::Duration;
+
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+
+use super::*;
+use crate::RolloutRecorder;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn usage(total_tokens: i64) -> Value {
+    json!({
+        "input_tokens": total_tokens,
+        "cached_input_tokens": 0,
+        "output_tokens": 0,
+        "reasoning_output_tokens": 0,
+        "total_tokens": total_tokens,
+    })
+}
+
+fn token_count(timestamp: &str, total_tokens: Option<i64>, used_percent: Option<f64>) -> Value {
+    json!({
+        "timestamp": timestamp,
+        "type": "event_msg",
+        "payload": {
+            "type": "token_count",
+            "info": total_tokens.map(|total_tokens| json!({
+                "total_token_usage": usage(total_tokens),
+                "last_token_usage": usage(total_tokens),
+                "model_context_window": null,
+            })),
+            "rate_limits": used_percent.map(|used_percent| json!({
+                "primary": {"used_percent": used_percent},
+            })),
+        },
+    })
+}
+
+fn event(timestamp: &str, payload: Value) -> Value {
+    json!({"timestamp": timestamp, "type": "event_msg", "payload": payload})
+}
+
+fn session_meta() -> Value {
+    json!({
+        "timestamp": "2025-07-01T00:00:00.000Z",
+        "type": "session_meta",
+        "payload": {
+            "session_id": "019a0000-0000-7000-8000-000000000001",
+            "id": "019a0000-0000-7000-8000-000000000001",
+            "timestamp": "2025-07-01T00:00:00.000Z",
+            "cwd": "/workspace",
+            "originator": "codex_cli_rs",
+            "cli_version": "0.0.0",
+            "source": "cli",
+            "model_provider": "openai",
+            "base_instructions": null,
+        },
+    })
+}
+
+fn write_rollout(dir: &Path, lines: &[Value], age: Duration) -> (PathBuf, SystemTime) {
+    let path = dir.join("rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-000000000001.jsonl");
+    let contents = lines
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    fs::write(&path, contents).expect("write rollout");
+    let modified = SystemTime::now() - age;
+    File::options()
+        .write(true)
+        .open(&path)
+        .and_then(|file| file.set_times(FileTimes::new().set_modified(modified)))
+        .expect("set rollout mtime");
+    let modified = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .expect("rollout mtime");
+    (path, modified)
+}
+
+fn read_lines(path: &Path) -> Vec<Value> {
+    fs::read_to_string(path)
+        .expect("read rollout")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("parse line"))
+        .collect()
+}
+
+#[tokio::test]
+async fn compact_keeps_turns_and_merges_the_final_token_count() {
+    let dir = TempDir::new().expect("tempdir");
+    let user = event(
+        "2025-07-01T00:00:01.000Z",
+        json!({"type": "user_message", "message": "hi"}),
+    );
+    let agent = event(
+        "2025-07-01T00:00:03.000Z",
+        json!({"type": "agent_message", "message": "hello"}),
+    );
+    let lines = vec![
+        session_meta(),
+        user.clone(),
+        token_count("2025-07-01T00:00:02.000Z", Some(10), Some(5.0)),
+        agent.clone(),
+        token_count("2025-07-01T00:00:04.000Z", Some(30), Some(7.5)),
+        token_count(
+            "2025-07-01T00:00:05.000Z",
+            Some(40),
+            /*used_percent*/ None,
+        ),
+    ];
+    let (path, modified) = write_rollout(dir.path(), &lines, 2 * DAY);
+    let (items_before, _, _) = RolloutRecorder::load_rollout_items(&path)
+        .await
+        .expect("load before");
+
+    let compaction = RolloutRecorder::compact(&path).await.expect("compact");
+
+    assert_eq!(
+        read_lines(&path),
+        vec![
+            session_meta(),
+            user,
+            agent,
+            token_count("2025-07-01T00:00:05.000Z", Some(40), Some(7.5)),
+        ]
+    );
+    assert_eq!((compaction.lines_before, compaction.lines_after), (6, 4));
+    assert_eq!(
+        compaction.bytes_after,
+        fs::metadata(&path).expect("metadata").len()
+    );
+    assert!(compaction.bytes_after < compaction.bytes_before);
+    assert_eq!(
+        fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .expect("mtime"),
+        modified
+    );
+
+    let (items_after, _, parse_errors) = RolloutRecorder::load_rollout_items(&path)
+        .await
+        .expect("load after");
+    assert_eq!(parse_errors, 0);
+    let conversation = |items: &[RolloutItem]| {
+        items
+            .iter()
+            .filter(|item| !matches!(item, RolloutItem::EventMsg(EventMsg::TokenCount(_))))
+            .map(|item| serde_json::to_value(item).expect("serialize item"))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(conversation(&items_after), conversation(&items_before));
+}
+
+#[tokio::test]
+async fn compact_rejects_rollouts_that_may_still_be_open() {
+    let dir = TempDir::new().expect("tempdir");
+    let lines = vec![
+        session_meta(),
+        token_count(
+            "2025-07-01T00:00:01.000Z",
+            Some(10),
+            /*used_percent*/ None,
+        ),
+        token_count(
+            "2025-07-01T00:00:02.000Z",
+            Some(20),
+            /*used_percent*/ None,
+        ),
+    ];
+    let (path, _) = write_rollout(dir.path(), &lines, Duration::from_secs(60));
+
+    let err = RolloutRecorder::compact(&path)
+        .await
+        .expect_err("recent rollout should be rejected");
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(read_lines(&path), lines);
+}
+
+#[tokio::test]
+async fn compact_rollouts_skips_recent_rollouts() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let lines = vec![
+        session_meta(),
+        token_count(
+            "2025-07-01T00:00:01.000Z",
+            Some(10),
+            /*used_percent*/ None,
+        ),
+        token_count(
+            "2025-07-01T00:00:02.000Z",
+            Some(20),
+            /*used_percent*/ None,
+        ),
+    ];
+    let old_dir = codex_home.path().join("sessions/2025/07/01");
+    let recent_dir = codex_home.path().join("archived_sessions");
+    fs::create_dir_all(&old_dir).expect("create sessions dir");
+    fs::create_dir_all(&recent_dir).expect("create archived dir");
+    let (old, _) = write_rollout(&old_dir, &lines, 2 * DAY);
+    let (recent, _) = write_rollout(&recent_dir, &lines, Duration::from_secs(60));
+    let old_size = fs::metadata(&old).expect("metadata").len();
+
+    let stats = compact_rollouts(codex_home.path()).await.expect("compact");
+
+    let saved = old_size - fs::metadata(&old).expect("metadata").len();
+    assert_eq!(
+        stats,
+        RolloutCompactionStats {
+            scanned: 2,
+            compacted: 1,
+            skipped: 1,
+            failed: 0,
+            bytes_saved: saved,
+        }
+    );
+    assert_eq!(read_lines(&old).len(), 2);
+    assert_eq!(read_lines(&recent), lines);
+}
@@ -6,6 +6,7 @@ use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
@@ -15,8 +16,15 @@ use std::os::unix::fs::OpenOptionsExt;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
+use crate::encryption;
+use crate::encryption::RolloutKey;
+
 const COMPRESSED_SUFFIX: &str = ".zst";
+const GZIP_SUFFIX: &str = ".gz";
 const MAX_NOT_FOUND_RETRIES: usize = 3;
+/// Rollouts still open by a running session must never be compressed; an idle
+/// day is treated as proof the writer is gone.
+const MIN_MANUAL_COMPRESSION_AGE: Duration = Duration::from_secs(24 * 60 * 60);
 const OPEN_ROLLOUT_LINE_READER_RETRY_DELAY: Duration = Duration::from_millis(50);
 const TEMP_SUFFIX: &str = ".tmp";
 static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -30,6 +38,44 @@ pub fn spawn_rollout_compression_worker(codex_home: PathBuf) {
     worker::spawn(codex_home)
 }
 
+/// Encodings a cold rollout can be stored in next to the plain `.jsonl` name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RolloutCompression {
+    /// `.jsonl.zst`, written by the background compression worker.
+    Zstd,
+    /// `.jsonl.gz`, written by `codex sessions compress`.
+    Gzip,
+}
+
+/// Totals from one compression pass over the local rollout directories.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutCompressionStats {
+    pub scanned: usize,
+    pub compressed: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+/// Compresses every plain rollout under `codex_home` that was last modified at
+/// least `min_age` ago.
+///
+/// Modification times are carried over to the compressed file so
+/// `ThreadSortKey::UpdatedAt` ordering is unchanged. Unlike the background
+/// worker, this ignores the run marker and is meant for explicit maintenance.
+pub async fn compress_rollouts_older_than(
+    codex_home: &Path,
+    min_age: Duration,
+    compression: RolloutCompression,
+) -> io::Result<RolloutCompressionStats> {
+    if min_age < MIN_MANUAL_COMPRESSION_AGE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "rollouts must be at least 1 day old to be compressed",
+        ));
+    }
+    worker::compress_roots(codex_home, min_age, compression).await
+}
+
 /// Returns the modified time for the existing plain or compressed rollout file.
 pub(crate) async fn file_modified_time(path: &Path) -> io::Result<Option<time::OffsetDateTime>> {
     let Some(path) = path::existing_rollout_path(path).await else {
@@ -40,7 +86,8 @@ pub(crate) async fn file_modified_time(path: &Path) -> io::Result<Option<time::O
     Ok(modified.map(time::OffsetDateTime::from))
 }
 
-/// Opens a rollout line reader that transparently handles plain `.jsonl` and `.jsonl.zst` files.
+/// Opens a rollout line reader that transparently handles plain `.jsonl`, `.jsonl.zst`,
+/// `.jsonl.gz`, and encrypted `.jsonl.enc` files.
 ///
 /// If the requested path disappears during a representation transition, this briefly retries
 /// resolution so callers do not need to know which representation is on disk.
@@ -57,10 +104,18 @@ pub async fn open_rollout_line_reader(path: &Path) -> io::Result<RolloutLineRead
     reader::open_once(path).await
 }
 
+/// Returns the path a rollout takes when stored with `compression`.
+pub(crate) fn compressed_rollout_path_with(
+    path: &Path,
+    compression: RolloutCompression,
+) -> PathBuf {
+    path::compressed_rollout_path_with(path, compression)
+}
+
 /// Returns the compressed `.jsonl.zst` path for a rollout path.
 #[cfg(test)]
 pub(crate) fn compressed_rollout_path(path: &Path) -> PathBuf {
-    path::compressed_rollout_path(path)
+    compressed_rollout_path_with(path, RolloutCompression::Zstd)
 }
 
 /// Materializes a compressed rollout back to plain `.jsonl` for async append paths.
@@ -78,11 +133,17 @@ pub(crate) fn materialize_rollout_for_append_blocking(path: &Path) -> io::Result
         metrics::materialize("plain_exists");
         return Ok(plain_path);
     }
-    let compressed_path = path::compressed_rollout_path(plain_path.as_path());
-    if !compressed_path.exists() {
+    // Encrypted rollouts are appended to in place.
+    let encrypted_path = encryption::encrypted_rollout_path(plain_path.as_path());
+    if encrypted_path.exists() {
+        return Ok(encrypted_path);
+    }
+    let Some((compressed_path, compression)) =
+        path::existing_compressed_rollout_path_blocking(plain_path.as_path())
+    else {
         metrics::materialize("missing");
         return Ok(plain_path);
-    }
+    };
 
     let temp_path = temp_path_for(plain_path.as_path(), "decompress");
     if let Some(parent) = plain_path.parent() {
@@ -91,8 +152,7 @@ pub(crate) fn materialize_rollout_for_append_blocking(path: &Path) -> io::Result
     let result: io::Result<()> = (|| {
         let permissions = std::fs::metadata(compressed_path.as_path())?.permissions();
         {
-            let input = File::open(compressed_path.as_path())?;
-            let mut decoder = zstd::stream::read::Decoder::new(input)?;
+            let mut decoder = open_decoder(compressed_path.as_path(), compression)?;
             let mut output = create_file_with_permissions(temp_path.as_path(), &permissions)?;
             io::copy(&mut decoder, &mut output)?;
             output.flush()?;
@@ -134,6 +194,11 @@ pub fn plain_rollout_path(path: &Path) -> PathBuf {
     path::plain_rollout_path(path)
 }
 
+/// Returns whether `path` names a compressed `.jsonl.zst` or `.jsonl.gz` rollout.
+pub fn is_compressed_rollout_path(path: &Path) -> bool {
+    path::is_compressed_rollout_path(path)
+}
+
 /// Parses a rollout file name, returning its plain `.jsonl` name when valid.
 pub(crate) fn parse_rollout_file_name(name: &str) -> Option<&str> {
     file_name::parse_rollout_file_name(name)
@@ -142,9 +207,9 @@ pub(crate) fn parse_rollout_file_name(name: &str) -> Option<&str> {
 /// A discovered rollout file, represented by exactly one physical path.
 ///
 /// This keeps directory walkers from reimplementing the plain/compressed
-/// precedence rules. The physical path may point at either `.jsonl` or
-/// `.jsonl.zst`, while `plain_file_name` is always the canonical `.jsonl`
-/// filename used for timestamp and id parsing.
+/// precedence rules. The physical path may point at `.jsonl`, `.jsonl.zst`,
+/// `.jsonl.gz`, or `.jsonl.enc`, while `plain_file_name` is always the
+/// canonical `.jsonl` filename used for timestamp and id parsing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct RolloutFile {
     path: PathBuf,
@@ -184,6 +249,11 @@ impl RolloutFile {
         path::is_compressed_rollout_path(self.path.as_path())
     }
 
+    /// Returns whether the physical path is the encrypted representation.
+    pub(crate) fn is_encrypted(&self) -> bool {
+        encryption::is_encrypted_rollout_path(self.path.as_path())
+    }
+
     /// Consumes the entry and returns the physical path that should be read.
     pub(crate) fn into_path(self) -> PathBuf {
         self.path
@@ -198,6 +268,11 @@ pub struct RolloutLineReader {
 enum RolloutLineReaderInner {
     Plain(tokio::io::Lines<tokio::io::BufReader<tokio::fs::File>>),
     Blocking(Option<BlockingLineReader>),
+    Encrypted {
+        lines: tokio::io::Lines<tokio::io::BufReader<tokio::fs::File>>,
+        key: Arc<RolloutKey>,
+        path: PathBuf,
+    },
 }
 
 impl RolloutLineReader {
@@ -216,12 +291,27 @@ impl RolloutLineReader {
                 *slot = Some(reader);
                 line
             }
+            RolloutLineReaderInner::Encrypted { lines, key, path } => {
+                let Some(line) = lines.next_line().await? else {
+                    return Ok(None);
+                };
+                encryption::decrypt_rollout_line(key, line, path).map(Some)
+            }
         }
     }
 }
 
 type BlockingLineReader = std::io::Lines<std::io::BufReader<Box<dyn Read + Send>>>;
 
+/// Opens `path` and returns a reader over its decompressed bytes.
+fn open_decoder(path: &Path, compression: RolloutCompression) -> io::Result<Box<dyn Read + Send>> {
+    let input = File::open(path)?;
+    Ok(match compression {
+        RolloutCompression::Zstd => Box::new(zstd::stream::read::Decoder::new(input)?),
+        RolloutCompression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(input)),
+    })
+}
+
 mod worker {
     use std::ffi::OsStr;
     use std::fs::File;
@@ -243,9 +333,13 @@ mod worker {
 
     use crate::ARCHIVED_SESSIONS_SUBDIR;
     use crate::SESSIONS_SUBDIR;
+    use crate::lock::RolloutLock;
 
+    use super::RolloutCompression;
+    use super::RolloutCompressionStats;
     use super::RolloutFile;
     use super::metrics;
+    use super::open_decoder;
     use super::path;
 
     const TEMP_SUFFIX: &str = ".tmp";
@@ -257,14 +351,18 @@ mod worker {
     const RUN_MARKER_FILE_NAME: &str = "rollout-compression.lock";
     const MAX_CONCURRENT_COMPRESSION_JOBS: usize = 2;
 
-    #[derive(Default)]
-    struct CompressionStats {
-        scanned: usize,
-        compressed: usize,
-        skipped: usize,
-        failed: usize,
+    /// What a compression pass writes and which rollouts count as cold.
+    #[derive(Clone, Copy)]
+    struct CompressionSettings {
+        min_age: Duration,
+        compression: RolloutCompression,
     }
 
+    const WORKER_SETTINGS: CompressionSettings = CompressionSettings {
+        min_age: MIN_ROLLOUT_AGE,
+        compression: RolloutCompression::Zstd,
+    };
+
     pub(super) struct CompressionRunMarker {
         path: PathBuf,
         remove_on_drop: bool,
@@ -361,17 +459,7 @@ mod worker {
         let started_at = Instant::now();
         let result = async {
             cleanup_stale_temps(codex_home.as_path()).await?;
-            let mut stats = CompressionStats::default();
-            for root in [
-                codex_home.join(ARCHIVED_SESSIONS_SUBDIR),
-                codex_home.join(SESSIONS_SUBDIR),
-            ] {
-                if started_at.elapsed() >= WORKER_MAX_RUNTIME {
-                    break;
-                }
-                compress_rollouts_in_root(root.as_path(), started_at, &mut stats).await?;
-            }
-            Ok::<_, io::Error>(stats)
+            compress_all_roots(codex_home.as_path(), started_at, WORKER_SETTINGS).await
         }
         .await;
         let stats = match result {
@@ -392,6 +480,36 @@ mod worker {
         Ok(())
     }
 
+    pub(super) async fn compress_roots(
+        codex_home: &Path,
+        min_age: Duration,
+        compression: RolloutCompression,
+    ) -> io::Result<RolloutCompressionStats> {
+        let settings = CompressionSettings {
+            min_age,
+            compression,
+        };
+        compress_all_roots(codex_home, Instant::now(), settings).await
+    }
+
+    async fn compress_all_roots(
+        codex_home: &Path,
+        started_at: Instant,
+        settings: CompressionSettings,
+    ) -> io::Result<RolloutCompressionStats> {
+        let mut stats = RolloutCompressionStats::default();
+        for root in [
+            codex_home.join(ARCHIVED_SESSIONS_SUBDIR),
+            codex_home.join(SESSIONS_SUBDIR),
+        ] {
+            if started_at.elapsed() >= WORKER_MAX_RUNTIME {
+                break;
+            }
+            compress_rollouts_in_root(root.as_path(), started_at, settings, &mut stats).await?;
+        }
+        Ok(stats)
+    }
+
     fn create_run_marker_file(path: &Path) -> io::Result<()> {
         let mut file = std::fs::OpenOptions::new()
             .write(true)
@@ -409,7 +527,8 @@ mod worker {
     async fn compress_rollouts_in_root(
         root: &Path,
         started_at: Instant,
-        stats: &mut CompressionStats,
+        settings: CompressionSettings,
+        stats: &mut RolloutCompressionStats,
     ) -> io::Result<()> {
         if !tokio::fs::try_exists(root).await.unwrap_or(false) {
             return Ok(());
@@ -463,7 +582,8 @@ mod worker {
                 let Some(rollout_file) = RolloutFile::from_path(path) else {
                     continue;
                 };
-                if rollout_file.is_compressed() {
+                // Encrypted lines do not compress.
+                if rollout_file.is_compressed() || rollout_file.is_encrypted() {
                     continue;
                 }
                 let path = rollout_file.into_path();
@@ -474,7 +594,7 @@ mod worker {
                 }
                 jobs.spawn_blocking(move || {
                     let started_at = Instant::now();
-                    let result = compress_rollout_if_cold_blocking(path.as_path());
+                    let result = compress_rollout_if_cold_blocking(path.as_path(), settings);
                     let duration = started_at.elapsed();
                     (path, duration, result)
                 });
@@ -492,6 +612,7 @@ mod worker {
         SkippedNotCold,
         SkippedChanged,
         SkippedAlreadyCompressed,
+        SkippedInUse,
     }
 
     impl CompressionOutcome {
@@ -501,6 +622,7 @@ mod worker {
                 CompressionOutcome::SkippedNotCold => "skipped_not_cold",
                 CompressionOutcome::SkippedChanged => "skipped_changed",
                 CompressionOutcome::SkippedAlreadyCompressed => "skipped_already_compressed",
+                CompressionOutcome::SkippedInUse => "skipped_in_use",
             }
         }
     }
@@ -532,7 +654,7 @@ mod worker {
 
     async fn drain_compression_jobs(
         jobs: &mut JoinSet<CompressionJobResult>,
-        stats: &mut CompressionStats,
+        stats: &mut RolloutCompressionStats,
     ) {
         while !jobs.is_empty() {
             collect_next_compression_job(jobs, stats).await;
@@ -541,7 +663,7 @@ mod worker {
 
     async fn collect_next_compression_job(
         jobs: &mut JoinSet<CompressionJobResult>,
-        stats: &mut CompressionStats,
+        stats: &mut RolloutCompressionStats,
     ) {
         let Some(result) = jobs.join_next().await else {
             return;
@@ -555,7 +677,8 @@ mod worker {
                     }
                     CompressionOutcome::SkippedNotCold
                     | CompressionOutcome::SkippedChanged
-                    | CompressionOutcome::SkippedAlreadyCompressed => {
+                    | CompressionOutcome::SkippedAlreadyCompressed
+                    | CompressionOutcome::SkippedInUse => {
                         stats.skipped = stats.skipped.saturating_add(1);
                     }
                 }
@@ -585,8 +708,11 @@ mod worker {
         }
     }
 
-    fn compress_rollout_if_cold_blocking(path: &Path) -> io::Result<CompressionMeasurement> {
-        let before = match cold_file_state(path)? {
+    fn compress_rollout_if_cold_blocking(
+        path: &Path,
+        settings: CompressionSettings,
+    ) -> io::Result<CompressionMeasurement> {
+        let before = match cold_file_state(path, settings.min_age)? {
             ColdFileState::Cold(state) => state,
             ColdFileState::NotCold(state) => {
                 return Ok(CompressionMeasurement::new(
@@ -597,8 +723,19 @@ mod worker {
             }
         };
         let source_bytes = Some(before.len);
-        let compressed_path = path::compressed_rollout_path(path);
-        if compressed_path.exists() {
+        let Some(_lock) = RolloutLock::try_acquire(path, /*exclusive*/ true)? else {
+            info!(
+                "skipping rollout {}: in use by another Codex process",
+                path.display()
+            );
+            return Ok(CompressionMeasurement::new(
+                CompressionOutcome::SkippedInUse,
+                source_bytes,
+                /*compressed_bytes*/ None,
+            ));
+        };
+        let compressed_path = path::compressed_rollout_path_with(path, settings.compression);
+        if path::existing_compressed_rollout_path_blocking(path).is_some() {
             return Ok(CompressionMeasurement::new(
                 CompressionOutcome::SkippedAlreadyCompressed,
                 source_bytes,
@@ -615,9 +752,9 @@ mod worker {
             .prefix("rollout-compress-")
             .suffix(TEMP_SUFFIX)
             .tempfile_in(temp_dir)?;
-        encode_zstd_to_writer(path, temp_file.as_file_mut())?;
+        encode_to_writer(path, temp_file.as_file_mut(), settings.compression)?;
         temp_file.as_file_mut().flush()?;
-        verify_zstd(temp_file.path())?;
+        verify_compressed(temp_file.path(), settings.compression)?;
         if !same_file_state(path, &before)? {
             return Ok(CompressionMeasurement::new(
                 CompressionOutcome::SkippedChanged,
@@ -662,7 +799,7 @@ mod worker {
         permissions: Permissions,
     }
 
-    fn cold_file_state(path: &Path) -> io::Result<ColdFileState> {
+    fn cold_file_state(path: &Path, min_age: Duration) -> io::Result<ColdFileState> {
         let metadata = match std::fs::metadata(path) {
             Ok(metadata) => metadata,
             Err(err) if err.kind() == io::ErrorKind::NotFound => {
@@ -682,7 +819,7 @@ mod worker {
         let age = SystemTime::now()
             .duration_since(modified)
             .unwrap_or(Duration::ZERO);
-        if age < MIN_ROLLOUT_AGE {
+        if age < min_age {
             return Ok(ColdFileState::NotCold(Some(state)));
         }
         Ok(ColdFileState::Cold(state))
@@ -698,17 +835,30 @@ mod worker {
         }
     }
 
-    fn encode_zstd_to_writer(source: &Path, output: impl Write) -> io::Result<()> {
+    fn encode_to_writer(
+        source: &Path,
+        output: impl Write,
+        compression: RolloutCompression,
+    ) -> io::Result<()> {
         let mut input = File::open(source)?;
-        let mut encoder = zstd::stream::write::Encoder::new(output, COMPRESSION_LEVEL)?;
-        io::copy(&mut input, &mut encoder)?;
-        encoder.finish()?;
+        match compression {
+            RolloutCompression::Zstd => {
+                let mut encoder = zstd::stream::write::Encoder::new(output, COMPRESSION_LEVEL)?;
+                io::copy(&mut input, &mut encoder)?;
+                encoder.finish()?;
+            }
+            RolloutCompression::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(output, flate2::Compression::default());
+                io::copy(&mut input, &mut encoder)?;
+                encoder.finish()?;
+            }
+        }
         Ok(())
     }
 
-    fn verify_zstd(path: &Path) -> io::Result<()> {
-        let input = File::open(path)?;
-        let mut decoder = zstd::stream::read::Decoder::new(input)?;
+    fn verify_compressed(path: &Path, compression: RolloutCompression) -> io::Result<()> {
+        let mut decoder = open_decoder(path, compression)?;
         let mut sink = io::sink();
         io::copy(&mut decoder, &mut sink)?;
         Ok(())
@@ -898,7 +1048,7 @@ mod metrics {
 }
 
 /// Returns the existing rollout path, preferring the plain `.jsonl` file over
-/// its `.jsonl.zst` compressed sibling.
+/// its `.jsonl.zst` and then `.jsonl.gz` compressed siblings.
 pub async fn existing_rollout_path(path: &Path) -> Option<PathBuf> {
     path::existing_rollout_path(path).await
 }
@@ -909,37 +1059,83 @@ mod path {
     use std::path::PathBuf;
 
     use super::COMPRESSED_SUFFIX;
-
-    pub(super) fn compressed_rollout_path(path: &Path) -> PathBuf {
-        if is_compressed_rollout_path(path) {
-            return path.to_path_buf();
+    use super::GZIP_SUFFIX;
+    use super::RolloutCompression;
+    use crate::encryption;
+
+    /// Compressed encodings in the order they are preferred when several
+    /// siblings exist.
+    const COMPRESSIONS: [RolloutCompression; 2] =
+        [RolloutCompression::Zstd, RolloutCompression::Gzip];
+
+    fn suffix(compression: RolloutCompression) -> &'static str {
+        match compression {
+            RolloutCompression::Zstd => COMPRESSED_SUFFIX,
+            RolloutCompression::Gzip => GZIP_SUFFIX,
         }
-        let mut file_name = path
+    }
+
+    pub(super) fn compression_of(path: &Path) -> Option<RolloutCompression> {
+        let name = path.file_name().and_then(OsStr::to_str)?;
+        COMPRESSIONS.into_iter().find(|compression| {
+            name.strip_suffix(suffix(*compression))
+                .is_some_and(|plain| plain.ends_with(".jsonl"))
+        })
+    }
+
+    pub(super) fn compressed_rollout_path_with(
+        path: &Path,
+        compression: RolloutCompression,
+    ) -> PathBuf {
+        let plain_path = plain_rollout_path(path);
+        let mut file_name = plain_path
             .file_name()
             .map(OsStr::to_os_string)
             .unwrap_or_else(|| OsStr::new("rollout.jsonl").to_os_string());
-        file_name.push(COMPRESSED_SUFFIX);
-        path.with_file_name(file_name)
+        file_name.push(suffix(compression));
+        plain_path.with_file_name(file_name)
     }
 
     pub(super) fn plain_rollout_path(path: &Path) -> PathBuf {
+        let Some(compression) = compression_of(path) else {
+            return path.to_path_buf();
+        };
         let Some(file_name) = path.file_name().and_then(OsStr::to_str) else {
             return path.to_path_buf();
         };
-        let Some(plain_file_name) = file_name.strip_suffix(COMPRESSED_SUFFIX) else {
+        let Some(plain_file_name) = file_name.strip_suffix(suffix(compression)) else {
             return path.to_path_buf();
         };
         path.with_file_name(plain_file_name)
     }
 
     pub(super) fn is_compressed_rollout_path(path: &Path) -> bool {
-        path.file_name()
-            .and_then(OsStr::to_str)
-            .is_some_and(|name| name.ends_with(".jsonl.zst"))
+        compression_of(path).is_some()
     }
 
+    /// Compressed siblings are hidden by the plain file and by any sibling in a
+    /// more preferred encoding, so each rollout is discovered exactly once.
     pub(super) fn should_skip_compressed_sibling(path: &Path) -> bool {
-        is_compressed_rollout_path(path) && plain_rollout_path(path).exists()
+        let Some(compression) = compression_of(path) else {
+            return false;
+        };
+        let plain_path = plain_rollout_path(path);
+        plain_path.exists()
+            || COMPRESSIONS
+                .into_iter()
+                .take_while(|preferred| *preferred != compression)
+                .any(|preferred| compressed_rollout_path_with(&plain_path, preferred).exists())
+    }
+
+    pub(super) fn existing_compressed_rollout_path_blocking(
+        path: &Path,
+    ) -> Option<(PathBuf, RolloutCompression)> {
+        COMPRESSIONS.into_iter().find_map(|compression| {
+            let compressed_path = compressed_rollout_path_with(path, compression);
+            compressed_path
+                .is_file()
+                .then_some((compressed_path, compression))
+        })
     }
 
     pub(super) async fn existing_rollout_path(path: &Path) -> Option<PathBuf> {
@@ -948,10 +1144,17 @@ mod path {
         {
             return Some(plain_path);
         }
-        let compressed_path = compressed_rollout_path(plain_path.as_path());
-        if matches!(tokio::fs::metadata(compressed_path.as_path()).await, Ok(metadata) if metadata.is_file())
+        for compression in COMPRESSIONS {
+            let compressed_path = compressed_rollout_path_with(plain_path.as_path(), compression);
+            if matches!(tokio::fs::metadata(compressed_path.as_path()).await, Ok(metadata) if metadata.is_file())
+            {
+                return Some(compressed_path);
+            }
+        }
+        let encrypted_path = encryption::encrypted_rollout_path(plain_path.as_path());
+        if matches!(tokio::fs::metadata(encrypted_path.as_path()).await, Ok(metadata) if metadata.is_file())
         {
-            return Some(compressed_path);
+            return Some(encrypted_path);
         }
         None
     }
@@ -959,9 +1162,15 @@ mod path {
 
 mod file_name {
     use super::COMPRESSED_SUFFIX;
+    use super::GZIP_SUFFIX;
+    use crate::encryption::ENCRYPTED_SUFFIX;
 
     pub(super) fn parse_rollout_file_name(name: &str) -> Option<&str> {
-        let name = name.strip_suffix(COMPRESSED_SUFFIX).unwrap_or(name);
+        let name = name
+            .strip_suffix(COMPRESSED_SUFFIX)
+            .or_else(|| name.strip_suffix(GZIP_SUFFIX))
+            .or_else(|| name.strip_suffix(ENCRYPTED_SUFFIX))
+            .unwrap_or(name);
         if name.starts_with("rollout-") && name.ends_with(".jsonl") {
             Some(name)
         } else {
@@ -971,28 +1180,25 @@ mod file_name {
 }
 
 mod reader {
-    use std::fs::File;
     use std::io;
     use std::io::BufRead;
-    use std::io::Read;
     use std::path::Path;
 
     use super::RolloutLineReader;
     use super::RolloutLineReaderInner;
+    use super::open_decoder;
     use super::path;
+    use crate::encryption;
     use tokio::io::AsyncBufReadExt;
 
     pub(super) async fn open_once(path: &Path) -> io::Result<RolloutLineReader> {
         let path = path::existing_rollout_path(path)
             .await
             .unwrap_or_else(|| path.to_path_buf());
-        if path::is_compressed_rollout_path(path.as_path()) {
+        if let Some(compression) = path::compression_of(path.as_path()) {
             let reader = tokio::task::spawn_blocking(move || {
-                let input = File::open(path.as_path())?;
-                let decoder = zstd::stream::read::Decoder::new(input)?;
-                Ok::<_, io::Error>(
-                    io::BufReader::new(Box::new(decoder) as Box<dyn Read + Send>).lines(),
-                )
+                let decoder = open_decoder(path.as_path(), compression)?;
+                Ok::<_, io::Error>(io::BufReader::new(decoder).lines())
             })
             .await
             .map_err(io::Error::other)??;
@@ -1000,6 +1206,17 @@ mod reader {
                 inner: RolloutLineReaderInner::Blocking(Some(reader)),
             });
         }
+        if encryption::is_encrypted_rollout_path(path.as_path()) {
+            let key = encryption::rollout_key_for(path.as_path()).await?;
+            let file = tokio::fs::File::open(path.as_path()).await?;
+            return Ok(RolloutLineReader {
+                inner: RolloutLineReaderInner::Encrypted {
+                    lines: tokio::io::BufReader::new(file).lines(),
+                    key,
+                    path,
+                },
+            });
+        }
         let file = tokio::fs::File::open(path).await?;
         Ok(RolloutLineReader {
             inner: RolloutLineReaderInner::Plain(tokio::io::BufReader::new(file).lines()),
@@ -1008,7 +1225,10 @@ mod reader {
 }
 
 #[cfg(unix)]
-fn create_file_with_permissions(path: &Path, permissions: &Permissions) -> io::Result<File> {
+pub(crate) fn create_file_with_permissions(
+    path: &Path,
+    permissions: &Permissions,
+) -> io::Result<File> {
     let file = std::fs::OpenOptions::new()
         .write(true)
         .create_new(true)
@@ -1019,7 +1239,10 @@ fn create_file_with_permissions(path: &Path, permissions: &Permissions) -> io::R
 }
 
 #[cfg(not(unix))]
-fn create_file_with_permissions(path: &Path, permissions: &Permissions) -> io::Result<File> {
+pub(crate) fn create_file_with_permissions(
+    path: &Path,
+    permissions: &Permissions,
+) -> io::Result<File> {
     let file = std::fs::OpenOptions::new()
         .write(true)
         .create_new(true)
@@ -1028,7 +1251,7 @@ fn create_file_with_permissions(path: &Path, permissions: &Permissions) -> io::R
     Ok(file)
 }
 
-fn temp_path_for(path: &Path, operation: &str) -> PathBuf {
+pub(crate) fn temp_path_for(path: &Path, operation: &str) -> PathBuf {
     let mut file_name = path
         .file_name()
         .map(OsStr::to_os_string)
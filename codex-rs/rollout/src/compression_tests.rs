@@ -572,6 +572,140 @@ async fn find_thread_path_by_id_ignores_compression_temp_matches() -> anyhow::Re
     Ok(())
 }
 
+#[tokio::test]
+async fn listing_and_resume_read_gzip_rollouts_in_mixed_directory() -> anyhow::Result<()> {
+    let home = TempDir::new()?;
+    let config = RolloutConfig {
+        codex_home: home.path().to_path_buf(),
+        sqlite_home: home.path().to_path_buf(),
+        cwd: home.path().to_path_buf(),
+        model_provider_id: "test-provider".to_string(),
+        generate_memories: true,
+    };
+    let mut thread_ids = Vec::new();
+    let mut rollout_paths = Vec::new();
+    for (index, ts) in [
+        "2025-01-03T12-00-00",
+        "2025-01-03T13-00-00",
+        "2025-01-03T14-00-00",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let uuid = Uuid::from_u128(40 + index as u128);
+        let thread_id = ThreadId::from_string(&uuid.to_string())?;
+        let path = rollout_path(home.path(), ts, uuid);
+        write_rollout(&path, thread_id, "mixed directory")?;
+        thread_ids.push(thread_id);
+        rollout_paths.push(path);
+    }
+    // Plain, zstd, and gzip rollouts side by side, plus a stale gzip copy of
+    // the zstd rollout that must not be listed twice.
+    compress_now(&rollout_paths[1])?;
+    write_rollout(&rollout_paths[1], thread_ids[1], "mixed directory")?;
+    gzip_now(&rollout_paths[1])?;
+    gzip_now(&rollout_paths[2])?;
+    let gzip_path = gzip_rollout_path(&rollout_paths[2]);
+
+    let page = crate::get_threads(
+        home.path(),
+        /*page_size*/ 10,
+        /*cursor*/ None,
+        crate::ThreadSortKey::CreatedAt,
+        /*allowed_sources*/ &[],
+        /*model_providers*/ None,
+        /*cwd_filters*/ None,
+        "test-provider",
+    )
+    .await?;
+    assert_eq!(
+        page.items
+            .iter()
+            .map(|item| item.thread_id)
+            .collect::<Vec<_>>(),
+        vec![
+            Some(thread_ids[2]),
+            Some(thread_ids[1]),
+            Some(thread_ids[0]),
+        ]
+    );
+
+    let (items, loaded_thread_id, parse_errors) =
+        RolloutRecorder::load_rollout_items(&rollout_paths[2]).await?;
+    assert_eq!(loaded_thread_id, Some(thread_ids[2]));
+    assert_eq!(parse_errors, 0);
+    assert_eq!(items.len(), 2);
+
+    let recorder =
+        RolloutRecorder::new(&config, RolloutRecorderParams::resume(gzip_path.clone())).await?;
+    assert_eq!(recorder.rollout_path(), rollout_paths[2].as_path());
+    assert!(rollout_paths[2].exists());
+    assert!(!gzip_path.exists());
+    recorder.shutdown().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn compress_rollouts_older_than_gzips_cold_rollouts_and_keeps_mtime() -> anyhow::Result<()> {
+    let home = TempDir::new()?;
+    let old_uuid = Uuid::from_u128(50);
+    let old_path = rollout_path(home.path(), "2025-01-03T12-00-00", old_uuid);
+    write_rollout(
+        &old_path,
+        ThreadId::from_string(&old_uuid.to_string())?,
+        "old",
+    )?;
+    set_old_mtime(&old_path)?;
+    let old_modified = fs::metadata(&old_path)?.modified()?;
+
+    let fresh_uuid = Uuid::from_u128(51);
+    let fresh_path = rollout_path(home.path(), "2025-01-04T12-00-00", fresh_uuid);
+    write_rollout(
+        &fresh_path,
+        ThreadId::from_string(&fresh_uuid.to_string())?,
+        "fresh",
+    )?;
+
+    let stats = compress_rollouts_older_than(
+        home.path(),
+        Duration::from_secs(7 * 24 * 60 * 60),
+        RolloutCompression::Gzip,
+    )
+    .await?;
+
+    assert_eq!(
+        stats,
+        RolloutCompressionStats {
+            scanned: 2,
+            compressed: 1,
+            skipped: 1,
+            failed: 0,
+        }
+    );
+    let gzip_path = gzip_rollout_path(&old_path);
+    assert!(!old_path.exists());
+    assert_eq!(fs::metadata(&gzip_path)?.modified()?, old_modified);
+    assert!(fresh_path.exists());
+    assert!(!gzip_rollout_path(&fresh_path).exists());
+    Ok(())
+}
+
+#[tokio::test]
+async fn compress_rollouts_older_than_rejects_ages_under_a_day() -> anyhow::Result<()> {
+    let home = TempDir::new()?;
+
+    let err = compress_rollouts_older_than(
+        home.path(),
+        Duration::from_secs(60 * 60),
+        RolloutCompression::Gzip,
+    )
+    .await
+    .expect_err("an hour is too recent");
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    Ok(())
+}
+
 fn rollout_path(home: &std::path::Path, ts: &str, uuid: Uuid) -> std::path::PathBuf {
     home.join("sessions/2025/01/03")
         .join(format!("rollout-{ts}-{uuid}.jsonl"))
@@ -649,6 +783,21 @@ fn compress_now(path: &std::path::Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn gzip_rollout_path(path: &std::path::Path) -> std::path::PathBuf {
+    path::compressed_rollout_path_with(path, RolloutCompression::Gzip)
+}
+
+fn gzip_now(path: &std::path::Path) -> anyhow::Result<()> {
+    let input = fs::File::open(path)?;
+    let output = fs::File::create(gzip_rollout_path(path))?;
+    let mut encoder = flate2::write::GzEncoder::new(output, flate2::Compression::default());
+    let mut input = std::io::BufReader::new(input);
+    std::io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?;
+    fs::remove_file(path)?;
+    Ok(())
+}
+
 fn set_old_mtime(path: &std::path::Path) -> anyhow::Result<()> {
     let old = SystemTime::now()
         .checked_sub(Duration::from_secs(8 * 24 * 60 * 60))
@@ -8,6 +8,11 @@ pub trait RolloutConfigView {
     fn cwd(&self) -> &Path;
     fn model_provider_id(&self) -> &str;
     fn generate_memories(&self) -> bool;
+
+    /// Whether new rollouts are encrypted at rest (`history.encrypt`).
+    fn encrypt_history(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -75,6 +80,10 @@ impl<T: RolloutConfigView + ?Sized> RolloutConfigView for &T {
     fn generate_memories(&self) -> bool {
         (*self).generate_memories()
     }
+
+    fn encrypt_history(&self) -> bool {
+        (*self).encrypt_history()
+    }
 }
 
 impl<T: RolloutConfigView + ?Sized> RolloutConfigView for Arc<T> {
@@ -97,4 +106,8 @@ impl<T: RolloutConfigView + ?Sized> RolloutConfigView for Arc<T> {
     fn generate_memories(&self) -> bool {
         self.as_ref().generate_memories()
     }
+
+    fn encrypt_history(&self) -> bool {
+        self.as_ref().encrypt_history()
+    }
 }
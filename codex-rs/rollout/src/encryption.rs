@@ -0,0 +1,421 @@
+//! Optional encryption at rest for rollouts, enabled by `history.encrypt`.
+//!
+//! Encrypted rollouts are stored as `.jsonl.enc`. Every line is encrypted on
+//! its own to an age X25519 key and written as one base64 line, so a session
+//! keeps appending without rewriting the file and an interrupted write only
+//! loses its final line. The key is kept where `cli_auth_credentials_store`
+//! keeps credentials and is loaded once per process.
+
+use std::ffi::OsStr;
+use std::fs::File;
+use std::fs::FileTimes;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use age::DecryptError;
+use age::x25519::Identity;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use codex_keyring_store::KeyringStore;
+use tokio::io::AsyncWriteExt;
+use tracing::info;
+use tracing::warn;
+
+use crate::ARCHIVED_SESSIONS_SUBDIR;
+use crate::SESSIONS_SUBDIR;
+use crate::compact::collect_rollout_files;
+use crate::compact::is_cold;
+use crate::compression;
+use crate::lock::RolloutLock;
+
+#[path = "encryption_key_store.rs"]
+mod key_store;
+
+pub use key_store::RolloutKeyStorage;
+pub use key_store::configure_rollout_key_store;
+
+pub(crate) const ENCRYPTED_SUFFIX: &str = ".enc";
+const MISSING_KEY_MESSAGE: &str = "the history encryption key is missing from its key store";
+
+static ROLLOUT_KEY: RwLock<Option<Arc<RolloutKey>>> = RwLock::new(None);
+
+/// Key that rollout lines are encrypted to and decrypted with.
+pub struct RolloutKey {
+    identity: Identity,
+}
+
+impl std::fmt::Debug for RolloutKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RolloutKey").finish_non_exhaustive()
+    }
+}
+
+impl RolloutKey {
+    pub fn generate() -> Self {
+        Self {
+            identity: Identity::generate(),
+        }
+    }
+
+    /// Loads the key saved in `keyring_store`, or `None` when there is none.
+    pub fn load(keyring_store: &dyn KeyringStore) -> io::Result<Option<Self>> {
+        key_store::load_from_keyring(keyring_store)
+    }
+
+    /// Loads the key saved in `keyring_store`, generating and saving one
+    /// when there is none.
+    pub fn load_or_create(keyring_store: &dyn KeyringStore) -> io::Result<Self> {
+        if let Some(key) = Self::load(keyring_store)? {
+            return Ok(key);
+        }
+        let key = Self::generate();
+        key_store::save_to_keyring(&key, keyring_store)?;
+        Ok(key)
+    }
+
+    /// Encrypts one JSONL record into a single base64 line, without the
+    /// trailing newline.
+    pub fn encrypt_line(&self, line: &str) -> io::Result<String> {
+        let ciphertext =
+            age::encrypt(&self.identity.to_public(), line.as_bytes()).map_err(io::Error::other)?;
+        Ok(BASE64_STANDARD.encode(ciphertext))
+    }
+
+    /// Decrypts one line written by [`Self::encrypt_line`].
+    ///
+    /// A line encrypted to another key fails with
+    /// [`io::ErrorKind::PermissionDenied`], and a damaged line with
+    /// [`io::ErrorKind::InvalidData`].
+    pub fn decrypt_line(&self, line: &str) -> io::Result<String> {
+        let ciphertext = BASE64_STANDARD
+            .decode(line.trim())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let plaintext = age::decrypt(&self.identity, &ciphertext).map_err(|err| match err {
+            DecryptError::NoMatchingKeys => io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "it was encrypted with a different history encryption key",
+            ),
+            err => io::Error::new(io::ErrorKind::InvalidData, err),
+        })?;
+        String::from_utf8(plaintext).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+/// Makes `key` the key this process encrypts and decrypts rollouts with, in
+/// place of the one in the configured key store.
+pub fn install_rollout_key(key: RolloutKey) {
+    *ROLLOUT_KEY.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(key));
+}
+
+/// Returns the process key, loading it from the key store on first use.
+///
+/// A missing key is never replaced here, since a new key could not read the
+/// rollouts encrypted before; it fails with [`io::ErrorKind::PermissionDenied`].
+pub(crate) fn rollout_key() -> io::Result<Arc<RolloutKey>> {
+    cached_or_load(|key_store| {
+        key_store
+            .load()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::PermissionDenied, MISSING_KEY_MESSAGE))
+    })
+}
+
+/// Like [`rollout_key`], but generates and saves a key when the key store
+/// has none, for rollouts that are about to be created.
+pub(crate) fn rollout_key_for_writing() -> io::Result<Arc<RolloutKey>> {
+    cached_or_load(key_store::RolloutKeyStore::load_or_create)
+}
+
+fn cached_or_load(
+    load: impl FnOnce(&key_store::RolloutKeyStore) -> io::Result<RolloutKey>,
+) -> io::Result<Arc<RolloutKey>> {
+    if let Some(key) = ROLLOUT_KEY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+    {
+        return Ok(Arc::clone(key));
+    }
+    let mut guard = ROLLOUT_KEY.write().unwrap_or_else(PoisonError::into_inner);
+    if let Some(key) = guard.as_ref() {
+        return Ok(Arc::clone(key));
+    }
+    let key_store = key_store::configured_rollout_key_store()
+        .unwrap_or_else(key_store::RolloutKeyStore::os_keyring);
+    let key = Arc::new(load(&key_store)?);
+    *guard = Some(Arc::clone(&key));
+    Ok(key)
+}
+
+/// Returns whether `path` names an encrypted `.jsonl.enc` rollout.
+pub fn is_encrypted_rollout_path(path: &Path) -> bool {
+    path.file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| name.strip_suffix(ENCRYPTED_SUFFIX))
+        .is_some_and(|plain| plain.ends_with(".jsonl"))
+}
+
+/// Returns the `.jsonl.enc` path for a plain or compressed rollout path.
+pub(crate) fn encrypted_rollout_path(path: &Path) -> PathBuf {
+    let plain_path = compression::plain_rollout_path(path);
+    let mut file_name = plain_path
+        .file_name()
+        .map(OsStr::to_os_string)
+        .unwrap_or_else(|| OsStr::new("rollout.jsonl").to_os_string());
+    file_name.push(ENCRYPTED_SUFFIX);
+    plain_path.with_file_name(file_name)
+}
+
+/// Returns the plain `.jsonl` path for an encrypted rollout path.
+pub(crate) fn decrypted_rollout_path(path: &Path) -> PathBuf {
+    match path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .and_then(|name| name.strip_suffix(ENCRYPTED_SUFFIX))
+    {
+        Some(plain_file_name) => path.with_file_name(plain_file_name),
+        None => path.to_path_buf(),
+    }
+}
+
+/// Loads the process key for reading the encrypted rollout at `path`.
+pub(crate) async fn rollout_key_for(path: &Path) -> io::Result<Arc<RolloutKey>> {
+    tokio::task::spawn_blocking(rollout_key)
+        .await
+        .map_err(io::Error::other)?
+        .map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("cannot read encrypted rollout {}: {err}", path.display()),
+            )
+        })
+}
+
+/// Decrypts one line read from the encrypted rollout at `path`.
+///
+/// Blank lines are kept, and a line that cannot be decrypted, such as a
+/// partial line left by an interrupted write, is returned unchanged so that
+/// readers skip it as an invalid record. Only a key mismatch is an error.
+pub(crate) fn decrypt_rollout_line(
+    key: &RolloutKey,
+    line: String,
+    path: &Path,
+) -> io::Result<String> {
+    if line.trim().is_empty() {
+        return Ok(line);
+    }
+    match key.decrypt_line(&line) {
+        Ok(plaintext) => Ok(plaintext),
+        Err(err) if err.kind() == io::ErrorKind::PermissionDenied => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("cannot decrypt rollout {}: {err}", path.display()),
+        )),
+        Err(_) => Ok(line),
+    }
+}
+
+/// Reads the whole encrypted rollout in `file` back as plain JSONL, for the
+/// scans that run over a rollout from its end when it is reopened.
+pub(crate) fn decrypt_rollout_file(
+    file: &mut File,
+    key: &RolloutKey,
+    path: &Path,
+) -> io::Result<Vec<u8>> {
+    file.seek(SeekFrom::Start(0))?;
+    let mut plaintext = Vec::new();
+    for line in BufReader::new(file).lines() {
+        plaintext.extend_from_slice(decrypt_rollout_line(key, line?, path)?.as_bytes());
+        plaintext.push(b'\n');
+    }
+    Ok(plaintext)
+}
+
+/// Totals from one [`encrypt_rollouts`] or [`decrypt_rollouts`] pass.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutEncryptionStats {
+    pub scanned: usize,
+    pub converted: usize,
+    /// Rollouts already in the requested form, rollouts modified within
+    /// [`crate::ACTIVE_ROLLOUT_GRACE`], which may still be open, and rollouts
+    /// locked by another Codex process.
+    pub skipped: usize,
+    pub failed: usize,
+    /// Encrypted rollouts the current key cannot decrypt, sorted by path.
+    pub unreadable: Vec<PathBuf>,
+}
+
+/// Encrypts every rollout under `codex_home` that is stored in plain or
+/// compressed form, including archived sessions.
+///
+/// Fails without converting anything when the key is missing from its key
+/// store while encrypted rollouts exist, listing those rollouts, so that a
+/// new key is never mixed with one that may still be recovered.
+pub async fn encrypt_rollouts(codex_home: &Path) -> io::Result<RolloutEncryptionStats> {
+    let Some(key) = migration_key(codex_home, /*create*/ true).await? else {
+        return Ok(RolloutEncryptionStats::default());
+    };
+    convert_rollouts(codex_home, Some(key.as_ref())).await
+}
+
+/// Rewrites every encrypted rollout under `codex_home` as plain `.jsonl`,
+/// including archived sessions.
+///
+/// Fails when the key is missing from its key store, listing the encrypted
+/// rollouts that can no longer be read.
+pub async fn decrypt_rollouts(codex_home: &Path) -> io::Result<RolloutEncryptionStats> {
+    if migration_key(codex_home, /*create*/ false).await?.is_none() {
+        return Ok(RolloutEncryptionStats::default());
+    }
+    convert_rollouts(codex_home, /*encrypt_with*/ None).await
+}
+
+/// Returns every encrypted rollout under `codex_home`, sorted by path.
+pub async fn encrypted_rollouts(codex_home: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        for rollout_file in collect_rollout_files(codex_home.join(root).as_path()).await? {
+            if rollout_file.is_encrypted() {
+                paths.push(rollout_file.into_path());
+            }
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Loads the process key for a migration pass. Returns `None` when there is
+/// no key and nothing needs one.
+async fn migration_key(codex_home: &Path, create: bool) -> io::Result<Option<Arc<RolloutKey>>> {
+    match tokio::task::spawn_blocking(rollout_key)
+        .await
+        .map_err(io::Error::other)?
+    {
+        Ok(key) => return Ok(Some(key)),
+        Err(err) if err.kind() != io::ErrorKind::PermissionDenied => return Err(err),
+        Err(_) => {}
+    }
+    let sessions = encrypted_rollouts(codex_home).await?;
+    if !sessions.is_empty() {
+        return Err(missing_key_error(&sessions));
+    }
+    if !create {
+        return Ok(None);
+    }
+    tokio::task::spawn_blocking(rollout_key_for_writing)
+        .await
+        .map_err(io::Error::other)?
+        .map(Some)
+}
+
+fn missing_key_error(sessions: &[PathBuf]) -> io::Error {
+    let mut message = format!(
+        "{MISSING_KEY_MESSAGE}; {} encrypted sessions cannot be read:",
+        sessions.len()
+    );
+    for path in sessions {
+        message.push_str(&format!("\n  {}", path.display()));
+    }
+    io::Error::new(io::ErrorKind::PermissionDenied, message)
+}
+
+/// Converts each cold rollout to or from the encrypted form. Lines are read
+/// through the usual rollout reader, so decryption uses the process key.
+async fn convert_rollouts(
+    codex_home: &Path,
+    encrypt_with: Option<&RolloutKey>,
+) -> io::Result<RolloutEncryptionStats> {
+    let encrypt = encrypt_with.is_some();
+    let mut stats = RolloutEncryptionStats::default();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        for rollout_file in collect_rollout_files(codex_home.join(root).as_path()).await? {
+            stats.scanned += 1;
+            if rollout_file.is_encrypted() == encrypt || !is_cold(rollout_file.path()).await? {
+                stats.skipped += 1;
+                continue;
+            }
+            let path = rollout_file.into_path();
+            let lock = RolloutLock::try_acquire(path.as_path(), /*exclusive*/ true);
+            if matches!(lock, Ok(None)) {
+                info!(
+                    "skipping rollout {}: in use by another Codex process",
+                    path.display()
+                );
+                stats.skipped += 1;
+                continue;
+            }
+            let result = match lock {
+                Ok(_lock) => convert_rollout(path.as_path(), encrypt_with).await,
+                Err(err) => Err(err),
+            };
+            match result {
+                Ok(()) => stats.converted += 1,
+                Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
+                    stats.unreadable.push(path);
+                }
+                Err(err) => {
+                    warn!(
+                        "failed to {} rollout {}: {err}",
+                        if encrypt { "encrypt" } else { "decrypt" },
+                        path.display()
+                    );
+                    stats.failed += 1;
+                }
+            }
+        }
+    }
+    stats.unreadable.sort();
+    Ok(stats)
+}
+
+/// Rewrites one rollout next to itself in the other form, then removes the
+/// original. The target is never overwritten, and the modification time is
+/// carried over so listing order is unchanged.
+async fn convert_rollout(path: &Path, encrypt_with: Option<&RolloutKey>) -> io::Result<()> {
+    let (target, operation) = match encrypt_with {
+        Some(_) => (encrypted_rollout_path(path), "encrypt"),
+        None => (decrypted_rollout_path(path), "decrypt"),
+    };
+    let metadata = tokio::fs::metadata(path).await?;
+    let temp_path = compression::temp_path_for(target.as_path(), operation);
+    let result = async {
+        let mut reader = compression::open_rollout_line_reader(path).await?;
+        let output = compression::create_file_with_permissions(
+            temp_path.as_path(),
+            &metadata.permissions(),
+        )?;
+        let mut output = tokio::io::BufWriter::new(tokio::fs::File::from_std(output));
+        while let Some(line) = reader.next_line().await? {
+            let line = match encrypt_with {
+                Some(key) if !line.trim().is_empty() => key.encrypt_line(&line)?,
+                Some(_) | None => line,
+            };
+            output.write_all(line.as_bytes()).await?;
+            output.write_all(b"\n").await?;
+        }
+        output.flush().await?;
+        let output = output.into_inner().into_std().await;
+        output.set_times(FileTimes::new().set_modified(metadata.modified()?))?;
+        output.sync_all()?;
+        drop(output);
+        tempfile::TempPath::try_from_path(temp_path.as_path())?
+            .persist_noclobber(target.as_path())
+            .map_err(|err| err.error)
+    }
+    .await;
+    if let Err(err) = result {
+        let _ = tokio::fs::remove_file(temp_path.as_path()).await;
+        return Err(err);
+    }
+    tokio::fs::remove_file(path).await
+}
+
+#[cfg(test)]
+#[path = "encryption_tests.rs"]
+mod tests;
@@ -0,0 +1,210 @@
+//! Where the history encryption key is kept.
+//!
+//! The key follows `cli_auth_credentials_store`: the OS keyring, a `0600`
+//! file under `CODEX_HOME`, or the keyring with the file as a fallback when
+//! the keyring is unavailable.
+
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use age::secrecy::ExposeSecret;
+use age::x25519::Identity;
+use codex_keyring_store::DefaultKeyringStore;
+use codex_keyring_store::KeyringStore;
+use tracing::warn;
+
+use super::RolloutKey;
+
+const KEYRING_SERVICE: &str = "codex";
+const KEYRING_ACCOUNT: &str = "rollout-encryption";
+const KEY_FILE_NAME: &str = "history-encryption.key";
+
+static ROLLOUT_KEY_STORE: RwLock<Option<RolloutKeyStore>> = RwLock::new(None);
+
+/// Backend for the history encryption key, mirroring the
+/// `cli_auth_credentials_store` modes that persist across processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RolloutKeyStorage {
+    /// `CODEX_HOME/history-encryption.key`, readable only by the owner.
+    File,
+    /// The OS keyring. Fails when it is unavailable.
+    Keyring,
+    /// The OS keyring, falling back to the file when the keyring fails.
+    Auto,
+}
+
+/// Key storage resolved for one Codex home.
+#[derive(Clone)]
+pub(crate) struct RolloutKeyStore {
+    storage: RolloutKeyStorage,
+    key_file: PathBuf,
+    keyring_store: Arc<dyn KeyringStore>,
+}
+
+/// Makes this process keep the history encryption key in `storage`, with
+/// file storage under `codex_home`. Until this is called the key lives in
+/// the OS keyring.
+pub fn configure_rollout_key_store(codex_home: &Path, storage: RolloutKeyStorage) {
+    *ROLLOUT_KEY_STORE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = Some(RolloutKeyStore::new(
+        codex_home,
+        storage,
+        Arc::new(DefaultKeyringStore),
+    ));
+}
+
+/// Returns the store configured for this process.
+pub(crate) fn configured_rollout_key_store() -> Option<RolloutKeyStore> {
+    ROLLOUT_KEY_STORE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+impl RolloutKeyStore {
+    pub(crate) fn new(
+        codex_home: &Path,
+        storage: RolloutKeyStorage,
+        keyring_store: Arc<dyn KeyringStore>,
+    ) -> Self {
+        Self {
+            storage,
+            key_file: codex_home.join(KEY_FILE_NAME),
+            keyring_store,
+        }
+    }
+
+    /// The store used before any Codex home is configured.
+    pub(crate) fn os_keyring() -> Self {
+        Self {
+            storage: RolloutKeyStorage::Keyring,
+            key_file: PathBuf::new(),
+            keyring_store: Arc::new(DefaultKeyringStore),
+        }
+    }
+
+    /// Loads the saved key, or `None` when there is none.
+    pub(crate) fn load(&self) -> io::Result<Option<RolloutKey>> {
+        match self.storage {
+            RolloutKeyStorage::File => load_from_file(&self.key_file),
+            RolloutKeyStorage::Keyring => RolloutKey::load(self.keyring_store.as_ref()),
+            RolloutKeyStorage::Auto => match RolloutKey::load(self.keyring_store.as_ref()) {
+                Ok(Some(key)) => Ok(Some(key)),
+                Ok(None) => load_from_file(&self.key_file),
+                Err(err) => {
+                    warn!("{err}; falling back to {}", self.key_file.display());
+                    load_from_file(&self.key_file)
+                }
+            },
+        }
+    }
+
+    /// Loads the saved key, generating and saving one when there is none.
+    pub(crate) fn load_or_create(&self) -> io::Result<RolloutKey> {
+        if let Some(key) = self.load()? {
+            return Ok(key);
+        }
+        let key = RolloutKey::generate();
+        match self.storage {
+            RolloutKeyStorage::File => save_to_file(&key, &self.key_file)?,
+            RolloutKeyStorage::Keyring => save_to_keyring(&key, self.keyring_store.as_ref())?,
+            RolloutKeyStorage::Auto => {
+                if let Err(err) = save_to_keyring(&key, self.keyring_store.as_ref()) {
+                    warn!("{err}; falling back to {}", self.key_file.display());
+                    save_to_file(&key, &self.key_file)?;
+                }
+            }
+        }
+        Ok(key)
+    }
+}
+
+pub(super) fn load_from_keyring(
+    keyring_store: &dyn KeyringStore,
+) -> io::Result<Option<RolloutKey>> {
+    let Some(encoded) = keyring_store
+        .load(KEYRING_SERVICE, KEYRING_ACCOUNT)
+        .map_err(|err| {
+            io::Error::other(format!(
+                "failed to load history encryption key from keyring: {}",
+                err.message()
+            ))
+        })?
+    else {
+        return Ok(None);
+    };
+    parse_key(&encoded, "keyring").map(Some)
+}
+
+pub(super) fn save_to_keyring(
+    key: &RolloutKey,
+    keyring_store: &dyn KeyringStore,
+) -> io::Result<()> {
+    keyring_store
+        .save(
+            KEYRING_SERVICE,
+            KEYRING_ACCOUNT,
+            key.identity.to_string().expose_secret(),
+        )
+        .map_err(|err| {
+            io::Error::other(format!(
+                "failed to save history encryption key in keyring: {}",
+                err.message()
+            ))
+        })
+}
+
+fn load_from_file(path: &Path) -> io::Result<Option<RolloutKey>> {
+    match std::fs::read_to_string(path) {
+        Ok(encoded) => parse_key(encoded.trim(), &path.display().to_string()).map(Some),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(io::Error::new(
+            err.kind(),
+            format!(
+                "failed to load history encryption key from {}: {err}",
+                path.display()
+            ),
+        )),
+    }
+}
+
+fn save_to_file(key: &RolloutKey, path: &Path) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut options = OpenOptions::new();
+    // Never overwrite a key another process saved first.
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(key.identity.to_string().expose_secret().as_bytes())?;
+    file.write_all(b"\n")?;
+    file.flush()
+}
+
+fn parse_key(encoded: &str, location: &str) -> io::Result<RolloutKey> {
+    let identity = Identity::from_str(encoded).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("history encryption key in {location} is invalid: {err}"),
+        )
+    })?;
+    Ok(RolloutKey { identity })
+}
+
+#[cfg(test)]
+#[path = "encryption_key_store_tests.rs"]
+mod tests;
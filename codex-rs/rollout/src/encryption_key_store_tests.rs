@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use codex_keyring_store::tests::MockKeyringStore;
+use keyring::Error as KeyringError;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::*;
+
+const LINE: &str = r#"{"type":"session_meta"}"#;
+
+fn assert_same_key(saved: &RolloutKey, loaded: &RolloutKey) -> io::Result<()> {
+    let encrypted = saved.encrypt_line(LINE)?;
+    assert_eq!(loaded.decrypt_line(&encrypted)?, LINE);
+    Ok(())
+}
+
+#[test]
+fn file_storage_keeps_the_key_in_an_owner_only_file() -> io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let keyring_store = MockKeyringStore::default();
+    let store = RolloutKeyStore::new(
+        codex_home.path(),
+        RolloutKeyStorage::File,
+        Arc::new(keyring_store.clone()),
+    );
+
+    let created = store.load_or_create()?;
+    let loaded = store.load()?.expect("saved key");
+
+    assert_same_key(&created, &loaded)?;
+    assert!(!keyring_store.contains(KEYRING_ACCOUNT));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(codex_home.path().join(KEY_FILE_NAME))?
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    Ok(())
+}
+
+#[test]
+fn keyring_storage_never_writes_a_key_file() -> io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let keyring_store = MockKeyringStore::default();
+    let store = RolloutKeyStore::new(
+        codex_home.path(),
+        RolloutKeyStorage::Keyring,
+        Arc::new(keyring_store.clone()),
+    );
+
+    let created = store.load_or_create()?;
+    let loaded = RolloutKey::load(&keyring_store)?.expect("saved key");
+
+    assert_same_key(&created, &loaded)?;
+    assert!(!codex_home.path().join(KEY_FILE_NAME).exists());
+    Ok(())
+}
+
+#[test]
+fn auto_storage_falls_back_to_the_key_file_when_the_keyring_fails() -> io::Result<()> {
+    let codex_home = TempDir::new()?;
+    let keyring_store = MockKeyringStore::default();
+    keyring_store.set_error(
+        KEYRING_ACCOUNT,
+        KeyringError::Invalid("error".into(), "save".into()),
+    );
+    let store = RolloutKeyStore::new(
+        codex_home.path(),
+        RolloutKeyStorage::Auto,
+        Arc::new(keyring_store.clone()),
+    );
+
+    let created = store.load_or_create()?;
+    let file_store = RolloutKeyStore::new(
+        codex_home.path(),
+        RolloutKeyStorage::File,
+        Arc::new(MockKeyringStore::default()),
+    );
+    let loaded = file_store.load()?.expect("fallback key file");
+
+    assert_same_key(&created, &loaded)?;
+    assert_eq!(keyring_store.saved_value(KEYRING_ACCOUNT), None);
+    Ok(())
+}
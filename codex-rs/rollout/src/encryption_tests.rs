@@ -0,0 +1,272 @@
+use std::fs;
+use std::sync::Once;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use codex_keyring_store::tests::MockKeyringStore;
+use codex_protocol::ThreadId;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::protocol::AgentMessageEvent;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::ThreadHistoryMode;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::*;
+use crate::RolloutRecorder;
+use crate::RolloutRecorderParams;
+use crate::config::RolloutConfig;
+
+const SESSION_META: &str = r#"{"timestamp":"2025-07-01T00:00:00.000Z","type":"session_meta","payload":{"id":"019a0000-0000-7000-8000-000000000001"}}"#;
+const USER_MESSAGE: &str = r#"{"timestamp":"2025-07-01T00:00:01.000Z","type":"event_msg","payload":{"type":"user_message","message":"secret plans"}}"#;
+
+/// Every test in this process shares one key, so that tests running in
+/// parallel never see the process key change under them.
+fn install_test_key() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| install_rollout_key(RolloutKey::generate()));
+}
+
+fn agent_message(message: &str) -> RolloutItem {
+    RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+        message: message.to_string(),
+        phase: None,
+        memory_citation: None,
+    }))
+}
+
+async fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    let mut reader = compression::open_rollout_line_reader(path).await?;
+    let mut lines = Vec::new();
+    while let Some(line) = reader.next_line().await? {
+        lines.push(line);
+    }
+    Ok(lines)
+}
+
+/// Writes a plain rollout last modified two days ago, so migrations treat it
+/// as cold.
+fn write_cold_rollout(codex_home: &Path) -> anyhow::Result<PathBuf> {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir)?;
+    let path = dir.join("rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-000000000001.jsonl");
+    fs::write(&path, format!("{SESSION_META}\n{USER_MESSAGE}\n"))?;
+    File::options().write(true).open(&path)?.set_times(
+        FileTimes::new().set_modified(SystemTime::now() - Duration::from_secs(2 * 24 * 60 * 60)),
+    )?;
+    Ok(path)
+}
+
+#[test]
+fn lines_round_trip_and_fail_with_a_different_key() -> io::Result<()> {
+    let key = RolloutKey::generate();
+    let encrypted = key.encrypt_line(USER_MESSAGE)?;
+
+    assert!(!encrypted.contains("secret plans"));
+    assert_eq!(key.decrypt_line(&encrypted)?, USER_MESSAGE);
+    let err = RolloutKey::generate()
+        .decrypt_line(&encrypted)
+        .expect_err("another key must not decrypt the line");
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    Ok(())
+}
+
+#[test]
+fn load_or_create_saves_the_key_in_the_keyring() -> io::Result<()> {
+    let keyring_store = MockKeyringStore::default();
+    assert!(RolloutKey::load(&keyring_store)?.is_none());
+
+    let created = RolloutKey::load_or_create(&keyring_store)?;
+    let loaded = RolloutKey::load(&keyring_store)?.expect("saved key");
+
+    let encrypted = created.encrypt_line(USER_MESSAGE)?;
+    assert_eq!(loaded.decrypt_line(&encrypted)?, USER_MESSAGE);
+    Ok(())
+}
+
+#[tokio::test]
+async fn reader_fails_clearly_on_a_rollout_encrypted_with_another_key() -> anyhow::Result<()> {
+    install_test_key();
+    let dir = TempDir::new()?;
+    let path = dir
+        .path()
+        .join("rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-000000000001.jsonl.enc");
+    let other_key = RolloutKey::generate();
+    fs::write(
+        &path,
+        format!("{}\n", other_key.encrypt_line(SESSION_META)?),
+    )?;
+
+    let err = read_lines(&path)
+        .await
+        .expect_err("the process key must not decrypt the rollout");
+
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    assert!(err.to_string().contains(&path.display().to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn encrypted_recorder_round_trips_through_resume() -> anyhow::Result<()> {
+    install_test_key();
+    let home = TempDir::new()?;
+    let config = RolloutConfig {
+        codex_home: home.path().to_path_buf(),
+        sqlite_home: home.path().to_path_buf(),
+        cwd: home.path().to_path_buf(),
+        model_provider_id: "test-provider".to_string(),
+        generate_memories: true,
+    };
+    let recorder = RolloutRecorder::new(
+        &config,
+        RolloutRecorderParams::new(
+            ThreadId::new(),
+            /*forked_from_id*/ None,
+            /*parent_thread_id*/ None,
+            SessionSource::Exec,
+            /*thread_source*/ None,
+            "test_originator".to_string(),
+            BaseInstructions::default(),
+            Vec::new(),
+        )
+        .with_history_mode(ThreadHistoryMode::Paginated)
+        .with_encryption(/*encrypted*/ true),
+    )
+    .await?;
+    recorder
+        .record_canonical_items(&[agent_message("first secret")])
+        .await?;
+    recorder.persist().await?;
+    recorder.shutdown().await?;
+    let path = recorder.rollout_path().to_path_buf();
+    assert!(is_encrypted_rollout_path(&path));
+
+    let recorder =
+        RolloutRecorder::new(&config, RolloutRecorderParams::resume(path.clone())).await?;
+    recorder
+        .record_canonical_items(&[agent_message("second secret")])
+        .await?;
+    recorder.flush().await?;
+    recorder.shutdown().await?;
+
+    assert!(!fs::read_to_string(&path)?.contains("secret"));
+    let lines = read_lines(&path)
+        .await?
+        .iter()
+        .map(|line| serde_json::from_str::<RolloutLine>(line))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(
+        lines.iter().map(|line| line.ordinal).collect::<Vec<_>>(),
+        vec![Some(0), Some(1), Some(2)]
+    );
+    let (items, _, parse_errors) = RolloutRecorder::load_rollout_items(&path).await?;
+    assert_eq!(parse_errors, 0);
+    let messages = items
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => Some(event.message.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["first secret", "second secret"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn encrypt_and_decrypt_rollouts_restore_the_original() -> anyhow::Result<()> {
+    install_test_key();
+    let home = TempDir::new()?;
+    let plain_path = write_cold_rollout(home.path())?;
+    let original = fs::read_to_string(&plain_path)?;
+    let modified = fs::metadata(&plain_path)?.modified()?;
+
+    let stats = encrypt_rollouts(home.path()).await?;
+
+    let encrypted_path = encrypted_rollout_path(&plain_path);
+    assert_eq!(
+        stats,
+        RolloutEncryptionStats {
+            scanned: 1,
+            converted: 1,
+            ..Default::default()
+        }
+    );
+    assert!(!plain_path.exists());
+    assert!(!fs::read_to_string(&encrypted_path)?.contains("secret plans"));
+    assert_eq!(fs::metadata(&encrypted_path)?.modified()?, modified);
+    assert_eq!(
+        read_lines(&encrypted_path).await?,
+        vec![SESSION_META.to_string(), USER_MESSAGE.to_string()]
+    );
+    assert_eq!(
+        encrypted_rollouts(home.path()).await?,
+        vec![encrypted_path.clone()]
+    );
+
+    let stats = decrypt_rollouts(home.path()).await?;
+
+    assert_eq!(
+        stats,
+        RolloutEncryptionStats {
+            scanned: 1,
+            converted: 1,
+            ..Default::default()
+        }
+    );
+    assert!(!encrypted_path.exists());
+    assert_eq!(fs::read_to_string(&plain_path)?, original);
+    Ok(())
+}
+
+#[tokio::test]
+async fn decrypt_rollouts_lists_sessions_encrypted_with_another_key() -> anyhow::Result<()> {
+    install_test_key();
+    let home = TempDir::new()?;
+    let plain_path = write_cold_rollout(home.path())?;
+    let encrypted_path = encrypted_rollout_path(&plain_path);
+    let other_key = RolloutKey::generate();
+    fs::write(
+        &encrypted_path,
+        format!("{}\n", other_key.encrypt_line(SESSION_META)?),
+    )?;
+    fs::remove_file(&plain_path)?;
+    File::options()
+        .write(true)
+        .open(&encrypted_path)?
+        .set_times(
+            FileTimes::new()
+                .set_modified(SystemTime::now() - Duration::from_secs(2 * 24 * 60 * 60)),
+        )?;
+
+    let stats = decrypt_rollouts(home.path()).await?;
+
+    assert_eq!(
+        stats,
+        RolloutEncryptionStats {
+            scanned: 1,
+            unreadable: vec![encrypted_path.clone()],
+            ..Default::default()
+        }
+    );
+    assert!(encrypted_path.exists());
+    assert!(!plain_path.exists());
+    Ok(())
+}
+
+#[test]
+fn missing_key_error_lists_every_session() {
+    let err = missing_key_error(&[
+        PathBuf::from("sessions/a.jsonl.enc"),
+        PathBuf::from("archived_sessions/b.jsonl.enc"),
+    ]);
+
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    assert_eq!(
+        err.to_string(),
+        "the history encryption key is missing from its key store; 2 encrypted sessions \
+         cannot be read:\n  sessions/a.jsonl.enc\n  archived_sessions/b.jsonl.enc"
+    );
+}
@@ -0,0 +1,173 @@
+//! Offline forks of saved sessions.
+//!
+//! [`fork_rollout`] copies a prefix of a rollout into a new session file with
+//! a fresh id, the same way a live fork starts from a snapshot of its source.
+//! The copy's `session_meta` records the source in `forked_from_id`, so it
+//! lists, resumes, and reports lineage like any other fork. The source rollout
+//! is only read.
+
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use serde_json::Value;
+use time::OffsetDateTime;
+use time::format_description::FormatItem;
+use time::macros::format_description;
+
+use crate::compression;
+use crate::encryption;
+use crate::recorder::new_log_file_info;
+
+/// The session created by [`fork_rollout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkedRollout {
+    pub thread_id: ThreadId,
+    pub forked_from_id: ThreadId,
+    pub path: PathBuf,
+    /// Rollout lines copied from the source, including `session_meta`.
+    pub lines: usize,
+}
+
+/// Forks the session stored at `source` into a new rollout under
+/// `codex_home`.
+///
+/// `at` is the 1-based rollout line to fork after, as reported by
+/// `codex sessions search --json`; `None` copies the whole session. The fork
+/// point must come after the session's `session_meta` line. Encrypted
+/// sources produce encrypted forks.
+pub async fn fork_rollout(
+    codex_home: &Path,
+    source: &Path,
+    at: Option<usize>,
+) -> io::Result<ForkedRollout> {
+    let mut reader = compression::open_rollout_line_reader(source).await?;
+    let mut lines = Vec::new();
+    while let Some(line) = reader.next_line().await? {
+        if at.is_some_and(|at| lines.len() >= at) {
+            break;
+        }
+        lines.push(line);
+    }
+    if let Some(at) = at
+        && (at == 0 || at > lines.len())
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "fork point {at} is outside the session's {} lines",
+                lines.len()
+            ),
+        ));
+    }
+
+    let thread_id = ThreadId::new();
+    let encrypted = encryption::is_encrypted_rollout_path(source);
+    let log_file_info = new_log_file_info(codex_home, thread_id, encrypted)?;
+    let forked_from_id = rewrite_session_meta(&mut lines, thread_id, log_file_info.timestamp)?;
+
+    let path = log_file_info.path;
+    let permissions = tokio::fs::metadata(source).await?.permissions();
+    let target = path.clone();
+    let line_count = lines.len();
+    tokio::task::spawn_blocking(move || {
+        write_fork(target.as_path(), &permissions, &lines, encrypted)
+    })
+    .await
+    .map_err(io::Error::other)??;
+    Ok(ForkedRollout {
+        thread_id,
+        forked_from_id,
+        path,
+        lines: line_count,
+    })
+}
+
+/// Gives the first `session_meta` record the fork's id and start time and
+/// points `forked_from_id` at the source. Returns the source's id.
+fn rewrite_session_meta(
+    lines: &mut [String],
+    thread_id: ThreadId,
+    started_at: OffsetDateTime,
+) -> io::Result<ThreadId> {
+    let timestamp_format: &[FormatItem] =
+        format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z");
+    let timestamp = started_at
+        .to_offset(time::UtcOffset::UTC)
+        .format(timestamp_format)
+        .map_err(|err| io::Error::other(format!("failed to format timestamp: {err}")))?;
+
+    for line in lines.iter_mut() {
+        let Ok(mut value) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        if value.get("type").and_then(Value::as_str) != Some("session_meta") {
+            continue;
+        }
+        let Some(payload) = value.get_mut("payload").and_then(Value::as_object_mut) else {
+            continue;
+        };
+        let source_id = payload
+            .get("id")
+            .and_then(Value::as_str)
+            .and_then(|id| ThreadId::from_string(id).ok())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "session_meta has no thread id")
+            })?;
+        let source_id_str = source_id.to_string();
+        let thread_id = thread_id.to_string();
+        if payload.get("session_id").and_then(Value::as_str) == Some(source_id_str.as_str()) {
+            payload.insert("session_id".to_string(), Value::String(thread_id.clone()));
+        }
+        payload.insert("id".to_string(), Value::String(thread_id));
+        payload.insert("forked_from_id".to_string(), Value::String(source_id_str));
+        payload.insert("timestamp".to_string(), Value::String(timestamp.clone()));
+        if let Some(object) = value.as_object_mut() {
+            object.insert("timestamp".to_string(), Value::String(timestamp));
+        }
+        *line = serde_json::to_string(&value).map_err(io::Error::other)?;
+        return Ok(source_id);
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "the fork point must come after the session's session_meta line",
+    ))
+}
+
+fn write_fork(
+    path: &Path,
+    permissions: &std::fs::Permissions,
+    lines: &[String],
+    encrypted: bool,
+) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let key = encrypted
+        .then(encryption::rollout_key_for_writing)
+        .transpose()?;
+    let temp_path = compression::temp_path_for(path, "fork");
+    let result = (|| {
+        let mut output =
+            compression::create_file_with_permissions(temp_path.as_path(), permissions)?;
+        for line in lines {
+            match key.as_ref() {
+                Some(key) => output.write_all(key.encrypt_line(line)?.as_bytes())?,
+                None => output.write_all(line.as_bytes())?,
+            }
+            output.write_all(b"\n")?;
+        }
+        output.sync_all()?;
+        std::fs::rename(temp_path.as_path(), path)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(temp_path.as_path());
+    }
+    result
+}
+
+#[cfg(test)]
+#[path = "fork_tests.rs"]
+mod tests;
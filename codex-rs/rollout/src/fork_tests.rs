@@ -0,0 +1,148 @@
+use std::fs;
+
+use codex_protocol::protocol::InitialHistory;
+use codex_protocol::protocol::RolloutItem;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+
+use super::*;
+use crate::RolloutRecorder;
+
+const SOURCE_ID: &str = "019a0000-0000-7000-8000-000000000001";
+
+fn user_message(message: &str) -> Value {
+    json!({
+        "timestamp": "2025-07-01T00:00:01.000Z",
+        "type": "event_msg",
+        "payload": {"type": "user_message", "message": message, "kind": "plain"},
+    })
+}
+
+/// Writes a three-line source rollout and returns its path and contents.
+fn write_source(codex_home: &Path) -> (PathBuf, String) {
+    let lines = [
+        json!({
+            "timestamp": "2025-07-01T00:00:00.000Z",
+            "type": "session_meta",
+            "payload": {
+                "session_id": SOURCE_ID,
+                "id": SOURCE_ID,
+                "timestamp": "2025-07-01T00:00:00.000Z",
+                "cwd": "/workspace",
+                "originator": "codex_cli_rs",
+                "cli_version": "0.0.0",
+                "source": "cli",
+                "model_provider": "openai",
+            },
+        }),
+        user_message("first"),
+        user_message("second"),
+    ];
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir).expect("create sessions dir");
+    let path = dir.join(format!("rollout-2025-07-01T00-00-00-{SOURCE_ID}.jsonl"));
+    let contents = lines
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    fs::write(&path, &contents).expect("write source rollout");
+    (path, contents)
+}
+
+fn resumed_items(history: InitialHistory) -> (ThreadId, Vec<RolloutItem>) {
+    match history {
+        InitialHistory::Resumed(resumed) => (resumed.conversation_id, resumed.history.to_vec()),
+        other => panic!("expected resumed history, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn fork_at_a_line_resumes_as_a_new_session_and_leaves_the_source_alone() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let (source, contents) = write_source(codex_home.path());
+
+    let forked = fork_rollout(codex_home.path(), &source, Some(2))
+        .await
+        .expect("fork");
+
+    let source_id = ThreadId::from_string(SOURCE_ID).expect("source id");
+    assert_eq!(forked.forked_from_id, source_id);
+    assert_eq!(forked.lines, 2);
+    assert_eq!(
+        forked
+            .path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.starts_with("rollout-")
+                && name.ends_with(&format!("-{}.jsonl", forked.thread_id))),
+        Some(true)
+    );
+    assert_eq!(fs::read_to_string(&source).expect("read source"), contents);
+
+    let (thread_id, items) = resumed_items(
+        RolloutRecorder::get_rollout_history(&forked.path)
+            .await
+            .expect("resume fork"),
+    );
+    assert_eq!(thread_id, forked.thread_id);
+    assert_eq!(items.len(), 2);
+    let RolloutItem::SessionMeta(session_meta) = &items[0] else {
+        panic!("expected session meta first, got {:?}", items[0]);
+    };
+    assert_eq!(
+        (
+            session_meta.meta.id,
+            session_meta.meta.session_id.to_string(),
+            session_meta.meta.forked_from_id,
+            session_meta.meta.cwd.clone(),
+        ),
+        (
+            forked.thread_id,
+            forked.thread_id.to_string(),
+            Some(source_id),
+            PathBuf::from("/workspace"),
+        )
+    );
+}
+
+#[tokio::test]
+async fn fork_without_a_point_copies_the_whole_session() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let (source, _) = write_source(codex_home.path());
+
+    let forked = fork_rollout(codex_home.path(), &source, /*at*/ None)
+        .await
+        .expect("fork");
+
+    assert_eq!(forked.lines, 3);
+    let (_, items) = resumed_items(
+        RolloutRecorder::get_rollout_history(&forked.path)
+            .await
+            .expect("resume fork"),
+    );
+    assert_eq!(items.len(), 3);
+}
+
+#[tokio::test]
+async fn fork_points_outside_the_session_are_rejected() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let (source, _) = write_source(codex_home.path());
+
+    let kinds = [
+        fork_rollout(codex_home.path(), &source, Some(0))
+            .await
+            .map_err(|err| err.kind()),
+        fork_rollout(codex_home.path(), &source, Some(4))
+            .await
+            .map_err(|err| err.kind()),
+    ];
+
+    assert_eq!(
+        kinds,
+        [
+            Err(io::ErrorKind::InvalidInput),
+            Err(io::ErrorKind::InvalidInput)
+        ]
+    );
+}
@@ -0,0 +1,420 @@
+//! Streams recorded rollout items to an external sink, configured by
+//! `[history.sink]`.
+//!
+//! Recorders hand each batch of written items to a [`HistorySink`] without
+//! waiting: the batch is queued on a bounded channel and dropped with a
+//! warning when the sink has fallen behind. A background task redacts secrets
+//! from every string in the event, groups events into batches, and delivers
+//! them, retrying failed deliveries with backoff before giving up on a batch.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use codex_http_client::TraceOptions;
+use codex_http_client::build_reqwest_client_with_custom_ca;
+use codex_http_client::execute_traced;
+use codex_protocol::protocol::RolloutItem;
+use codex_secrets::redact_secrets;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderName;
+use reqwest::header::HeaderValue;
+use serde::Serialize;
+use serde_json::Value;
+use time::OffsetDateTime;
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::time::Instant;
+use tracing::warn;
+
+pub const DEFAULT_HISTORY_SINK_BATCH_SIZE: usize = 100;
+pub const DEFAULT_HISTORY_SINK_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Batches of items queued for the sink. Once full, new batches are dropped.
+const CHANNEL_CAPACITY: usize = 256;
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(30);
+
+static SHARED_SINK: LazyLock<Mutex<Option<(HistorySinkConfig, HistorySink)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
+/// Where [`HistorySink`] delivers events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistorySinkConfig {
+    /// POST `{"events": [...]}` to `url` with up to `batch_size` events,
+    /// sending a partial batch once its oldest event is `flush_interval` old.
+    Webhook {
+        url: String,
+        headers: Vec<(String, String)>,
+        batch_size: usize,
+        flush_interval: Duration,
+    },
+    /// Append one JSON line per event to `path`, moving a file that grows past
+    /// `rotation_bytes` to `<path>.1`.
+    File {
+        path: PathBuf,
+        rotation_bytes: Option<u64>,
+    },
+}
+
+/// How often a failed delivery is retried before its batch is dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    pub(crate) max_attempts: usize,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Handle for queueing events to a sink. Clones share one delivery task, which
+/// exits after the last handle is dropped and its queued events are delivered.
+#[derive(Debug, Clone)]
+pub struct HistorySink {
+    tx: mpsc::Sender<SinkBatch>,
+    falling_behind: Arc<AtomicBool>,
+}
+
+#[derive(Debug)]
+struct SinkBatch {
+    thread_id: Arc<str>,
+    timestamp: String,
+    items: Vec<RolloutItem>,
+}
+
+#[derive(Serialize)]
+struct SinkEvent<'a> {
+    thread_id: &'a str,
+    timestamp: &'a str,
+    #[serde(flatten)]
+    item: &'a RolloutItem,
+}
+
+impl HistorySink {
+    /// Returns the process-wide sink for `config`, starting its delivery task
+    /// on the current Tokio runtime when none is running yet.
+    pub fn shared(config: &HistorySinkConfig) -> Option<Self> {
+        let mut shared = SHARED_SINK.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some((shared_config, sink)) = shared.as_ref()
+            && shared_config == config
+            && !sink.tx.is_closed()
+        {
+            return Some(sink.clone());
+        }
+        let sink = Self::spawn(config.clone(), RetryPolicy::default())?;
+        *shared = Some((config.clone(), sink.clone()));
+        Some(sink)
+    }
+
+    pub(crate) fn spawn(config: HistorySinkConfig, retry: RetryPolicy) -> Option<Self> {
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            warn!("failed to start history sink: no Tokio runtime");
+            return None;
+        };
+        let (batch_size, flush_interval, target) = match config {
+            HistorySinkConfig::Webhook {
+                url,
+                headers,
+                batch_size,
+                flush_interval,
+            } => {
+                let headers = match webhook_headers(&headers) {
+                    Ok(headers) => headers,
+                    Err(err) => {
+                        warn!("failed to start history sink for {url}: {err}");
+                        return None;
+                    }
+                };
+                // Built like every other outbound client so `[network]` proxy and CA
+                // settings apply to audit traffic too.
+                let client = match build_reqwest_client_with_custom_ca(
+                    reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT),
+                ) {
+                    Ok(client) => client,
+                    Err(err) => {
+                        warn!("failed to start history sink for {url}: {err}");
+                        return None;
+                    }
+                };
+                let target = SinkTarget::Webhook {
+                    client,
+                    url,
+                    headers,
+                };
+                (batch_size.max(1), flush_interval, target)
+            }
+            // Files are cheap to append to, so every batch is written as soon
+            // as it arrives.
+            HistorySinkConfig::File {
+                path,
+                rotation_bytes,
+            } => (
+                1,
+                Duration::ZERO,
+                SinkTarget::File {
+                    path,
+                    rotation_bytes,
+                },
+            ),
+        };
+        let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+        handle.spawn(run_sink(rx, target, batch_size, flush_interval, retry));
+        Some(Self {
+            tx,
+            falling_behind: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    /// Queues `items`, recorded for `thread_id`, without waiting for delivery.
+    pub(crate) fn send(&self, thread_id: &Arc<str>, items: Vec<RolloutItem>) {
+        if items.is_empty() {
+            return;
+        }
+        let timestamp_format: &[FormatItem] = format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond digits:3]Z"
+        );
+        let timestamp = OffsetDateTime::now_utc()
+            .format(timestamp_format)
+            .unwrap_or_default();
+        let batch = SinkBatch {
+            thread_id: Arc::clone(thread_id),
+            timestamp,
+            items,
+        };
+        match self.tx.try_send(batch) {
+            Ok(()) => self.falling_behind.store(false, Ordering::Relaxed),
+            Err(TrySendError::Full(batch)) => {
+                if !self.falling_behind.swap(true, Ordering::Relaxed) {
+                    warn!(
+                        "history sink is falling behind; dropping {} events",
+                        batch.items.len()
+                    );
+                }
+            }
+            Err(TrySendError::Closed(_)) => {}
+        }
+    }
+}
+
+async fn run_sink(
+    mut rx: mpsc::Receiver<SinkBatch>,
+    target: SinkTarget,
+    batch_size: usize,
+    flush_interval: Duration,
+    retry: RetryPolicy,
+) {
+    let mut pending = Vec::new();
+    let mut deadline = None;
+    loop {
+        let flush_at = deadline.unwrap_or_else(Instant::now);
+        let batch = tokio::select! {
+            received = rx.recv() => received,
+            () = tokio::time::sleep_until(flush_at), if deadline.is_some() => {
+                deliver_with_retry(&target, std::mem::take(&mut pending), retry).await;
+                deadline = None;
+                continue;
+            }
+        };
+        let Some(batch) = batch else {
+            break;
+        };
+        if pending.is_empty() {
+            deadline = Some(Instant::now() + flush_interval);
+        }
+        pending.extend(encode_batch(&batch));
+        while pending.len() >= batch_size {
+            let rest = pending.split_off(batch_size);
+            deliver_with_retry(&target, std::mem::replace(&mut pending, rest), retry).await;
+        }
+        if pending.is_empty() {
+            deadline = None;
+        }
+    }
+    if !pending.is_empty() {
+        deliver_with_retry(&target, pending, retry).await;
+    }
+}
+
+/// Parses configured webhook headers. Config loading rejects invalid ones, so an
+/// error here means the config was built without that validation.
+pub fn webhook_headers(headers: &[(String, String)]) -> Result<HeaderMap, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|err| format!("invalid header name `{name}`: {err}"))?;
+            let header_value = HeaderValue::from_str(value)
+                .map_err(|err| format!("invalid value for header `{name}`: {err}"))?;
+            Ok((header_name, header_value))
+        })
+        .collect()
+}
+
+/// Serializes each item as an event and redacts secrets from its strings.
+fn encode_batch(batch: &SinkBatch) -> Vec<Value> {
+    batch
+        .items
+        .iter()
+        .filter_map(|item| {
+            let event = SinkEvent {
+                thread_id: &batch.thread_id,
+                timestamp: &batch.timestamp,
+                item,
+            };
+            match serde_json::to_value(&event) {
+                Ok(mut value) => {
+                    redact_strings(&mut value);
+                    Some(value)
+                }
+                Err(err) => {
+                    warn!("failed to serialize history sink event: {err}");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+/// Redacts every string in `value`. Redacting the values rather than the
+/// serialized JSON keeps the output valid JSON.
+pub(crate) fn redact_strings(value: &mut Value) {
+    match value {
+        Value::String(text) => *text = redact_secrets(std::mem::take(text)),
+        Value::Array(values) => values.iter_mut().for_each(redact_strings),
+        Value::Object(map) => map.values_mut().for_each(redact_strings),
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+async fn deliver_with_retry(target: &SinkTarget, events: Vec<Value>, retry: RetryPolicy) {
+    if events.is_empty() {
+        return;
+    }
+    let mut backoff = retry.initial_backoff;
+    for attempt in 1..=retry.max_attempts {
+        match target.deliver(&events).await {
+            Ok(()) => return,
+            Err(DeliveryError::Retryable(err)) if attempt < retry.max_attempts => {
+                warn!("history sink delivery failed (attempt {attempt}); retrying: {err}");
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(retry.max_backoff);
+            }
+            Err(DeliveryError::Retryable(err) | DeliveryError::Permanent(err)) => {
+                warn!(
+                    "dropping {} history sink events after {attempt} failed deliveries: {err}",
+                    events.len()
+                );
+                return;
+            }
+        }
+    }
+}
+
+enum DeliveryError {
+    Retryable(String),
+    Permanent(String),
+}
+
+enum SinkTarget {
+    Webhook {
+        client: reqwest::Client,
+        url: String,
+        headers: HeaderMap,
+    },
+    File {
+        path: PathBuf,
+        rotation_bytes: Option<u64>,
+    },
+}
+
+impl SinkTarget {
+    async fn deliver(&self, events: &[Value]) -> Result<(), DeliveryError> {
+        match self {
+            SinkTarget::Webhook {
+                client,
+                url,
+                headers,
+            } => {
+                let body = serde_json::to_vec(&serde_json::json!({ "events": events }))
+                    .map_err(|err| DeliveryError::Permanent(err.to_string()))?;
+                let request = client
+                    .post(url)
+                    .headers(headers.clone())
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body)
+                    .build()
+                    .map_err(|err| DeliveryError::Permanent(err.to_string()))?;
+                let response = execute_traced(client, request, TraceOptions::default())
+                    .await
+                    .map_err(|err| DeliveryError::Retryable(err.to_string()))?;
+                let status = response.status();
+                if status.is_success() {
+                    Ok(())
+                } else if status.is_server_error()
+                    || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                    || status == reqwest::StatusCode::REQUEST_TIMEOUT
+                {
+                    Err(DeliveryError::Retryable(format!("{url} returned {status}")))
+                } else {
+                    Err(DeliveryError::Permanent(format!("{url} returned {status}")))
+                }
+            }
+            SinkTarget::File {
+                path,
+                rotation_bytes,
+            } => append_lines(path, *rotation_bytes, events)
+                .await
+                .map_err(|err| DeliveryError::Retryable(format!("{}: {err}", path.display()))),
+        }
+    }
+}
+
+async fn append_lines(
+    path: &Path,
+    rotation_bytes: Option<u64>,
+    events: &[Value],
+) -> std::io::Result<()> {
+    let mut lines = String::new();
+    for event in events {
+        lines.push_str(&serde_json::to_string(event)?);
+        lines.push('\n');
+    }
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    if let Some(rotation_bytes) = rotation_bytes
+        && let Ok(metadata) = tokio::fs::metadata(path).await
+        && metadata.len() >= rotation_bytes
+    {
+        let mut rotated = path.as_os_str().to_os_string();
+        rotated.push(".1");
+        tokio::fs::rename(path, PathBuf::from(rotated)).await?;
+    }
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(lines.as_bytes()).await?;
+    file.flush().await
+}
+
+#[cfg(test)]
+#[path = "history_sink_tests.rs"]
+mod tests;
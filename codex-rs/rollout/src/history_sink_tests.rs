@@ -0,0 +1,279 @@
+use std::fs;
+
+use codex_protocol::ThreadId;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::protocol::AgentMessageEvent;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::SessionSource;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+use super::*;
+use crate::RolloutRecorder;
+use crate::RolloutRecorderParams;
+use crate::config::RolloutConfig;
+
+const WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn fast_retry() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(10),
+        max_backoff: Duration::from_millis(20),
+    }
+}
+
+fn agent_message(message: &str) -> RolloutItem {
+    RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+        message: message.to_string(),
+        phase: None,
+        memory_citation: None,
+    }))
+}
+
+fn webhook_config(server: &MockServer, batch_size: usize) -> HistorySinkConfig {
+    HistorySinkConfig::Webhook {
+        url: format!("{}/events", server.uri()),
+        headers: vec![("authorization".to_string(), "Bearer sink".to_string())],
+        batch_size,
+        flush_interval: Duration::from_millis(100),
+    }
+}
+
+/// Waits until the server has received `count` requests and returns the
+/// messages in each request body.
+async fn received_messages(server: &MockServer, count: usize) -> Vec<Vec<String>> {
+    let requests = tokio::time::timeout(WAIT_TIMEOUT, async {
+        loop {
+            let requests = server.received_requests().await.unwrap_or_default();
+            if requests.len() >= count {
+                return requests;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("webhook requests");
+    requests
+        .iter()
+        .map(|request| {
+            let body: Value = serde_json::from_slice(&request.body).expect("json body");
+            body["events"]
+                .as_array()
+                .expect("events array")
+                .iter()
+                .map(|event| {
+                    event["payload"]["message"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+async fn read_when(path: &Path, ready: impl Fn(&str) -> bool) -> String {
+    tokio::time::timeout(WAIT_TIMEOUT, async {
+        loop {
+            if let Ok(contents) = fs::read_to_string(path)
+                && ready(&contents)
+            {
+                return contents;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("sink file")
+}
+
+#[tokio::test]
+async fn webhook_sends_full_batches_and_flushes_the_rest() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/events"))
+        .and(header("authorization", "Bearer sink"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let sink =
+        HistorySink::spawn(webhook_config(&server, /*batch_size*/ 2), fast_retry()).expect("sink");
+    let thread_id: Arc<str> = Arc::from("thread-1");
+
+    sink.send(&thread_id, vec![agent_message("one")]);
+    sink.send(
+        &thread_id,
+        vec![agent_message("two"), agent_message("three")],
+    );
+
+    assert_eq!(
+        received_messages(&server, /*count*/ 2).await,
+        vec![
+            vec!["one".to_string(), "two".to_string()],
+            vec!["three".to_string()],
+        ]
+    );
+    let requests = server.received_requests().await.unwrap_or_default();
+    let body: Value = serde_json::from_slice(&requests[0].body).expect("json body");
+    assert_eq!(body["events"][0]["thread_id"], json!("thread-1"));
+    assert_eq!(body["events"][0]["type"], json!("event_msg"));
+}
+
+#[tokio::test]
+async fn webhook_sink_does_not_start_with_invalid_headers() {
+    let server = MockServer::start().await;
+    let config = HistorySinkConfig::Webhook {
+        url: format!("{}/events", server.uri()),
+        headers: vec![("x-audit".to_string(), "line\nbreak".to_string())],
+        batch_size: 1,
+        flush_interval: Duration::from_millis(100),
+    };
+
+    assert!(HistorySink::spawn(config, RetryPolicy::default()).is_none());
+}
+
+#[tokio::test]
+async fn webhook_retries_server_errors_with_the_same_batch() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .with_priority(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+    let sink =
+        HistorySink::spawn(webhook_config(&server, /*batch_size*/ 1), fast_retry()).expect("sink");
+
+    sink.send(&Arc::from("thread-1"), vec![agent_message("retried")]);
+
+    assert_eq!(
+        received_messages(&server, /*count*/ 3).await,
+        vec![vec!["retried".to_string()]; 3]
+    );
+}
+
+#[tokio::test]
+async fn webhook_does_not_retry_client_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&server)
+        .await;
+    let sink =
+        HistorySink::spawn(webhook_config(&server, /*batch_size*/ 1), fast_retry()).expect("sink");
+
+    sink.send(&Arc::from("thread-1"), vec![agent_message("rejected")]);
+    sink.send(&Arc::from("thread-1"), vec![agent_message("next")]);
+
+    assert_eq!(
+        received_messages(&server, /*count*/ 2).await,
+        vec![vec!["rejected".to_string()], vec!["next".to_string()]]
+    );
+}
+
+#[test]
+fn redaction_applies_to_every_string_and_keeps_valid_json() {
+    let mut value = json!({
+        "message": "export OPENAI_API_KEY=sk-abcdefghijklmnopqrstuvwxyz",
+        "nested": [{"output": "password: \"hunter22hunter22\""}],
+        "count": 3,
+    });
+
+    redact_strings(&mut value);
+
+    assert_eq!(
+        value,
+        json!({
+            "message": "export OPENAI_API_KEY=[REDACTED_SECRET]",
+            "nested": [{"output": "password: \"[REDACTED_SECRET]\""}],
+            "count": 3,
+        })
+    );
+}
+
+#[tokio::test]
+async fn file_sink_rotates_once_the_file_is_past_the_limit() {
+    let dir = TempDir::new().expect("tempdir");
+    let path = dir.path().join("audit/events.jsonl");
+    let sink = HistorySink::spawn(
+        HistorySinkConfig::File {
+            path: path.clone(),
+            rotation_bytes: Some(1),
+        },
+        fast_retry(),
+    )
+    .expect("sink");
+    let thread_id: Arc<str> = Arc::from("thread-1");
+
+    sink.send(&thread_id, vec![agent_message("first")]);
+    read_when(&path, |contents| contents.contains("first")).await;
+    sink.send(&thread_id, vec![agent_message("second")]);
+    let current = read_when(&path, |contents| contents.contains("second")).await;
+
+    let rotated = fs::read_to_string(dir.path().join("audit/events.jsonl.1")).expect("rotated");
+    assert_eq!((rotated.lines().count(), current.lines().count()), (1, 1));
+    assert!(rotated.contains("first"));
+}
+
+#[tokio::test]
+async fn recorder_tees_written_items_to_the_sink() -> anyhow::Result<()> {
+    let home = TempDir::new()?;
+    let sink_path = home.path().join("events.jsonl");
+    let sink = HistorySink::spawn(
+        HistorySinkConfig::File {
+            path: sink_path.clone(),
+            rotation_bytes: None,
+        },
+        fast_retry(),
+    );
+    let config = RolloutConfig {
+        codex_home: home.path().to_path_buf(),
+        sqlite_home: home.path().to_path_buf(),
+        cwd: home.path().to_path_buf(),
+        model_provider_id: "test-provider".to_string(),
+        generate_memories: true,
+    };
+    let thread_id = ThreadId::new();
+    let recorder = RolloutRecorder::new(
+        &config,
+        RolloutRecorderParams::new(
+            thread_id,
+            /*forked_from_id*/ None,
+            /*parent_thread_id*/ None,
+            SessionSource::Exec,
+            /*thread_source*/ None,
+            "test_originator".to_string(),
+            BaseInstructions::default(),
+            Vec::new(),
+        )
+        .with_history_sink(sink),
+    )
+    .await?;
+
+    recorder
+        .record_canonical_items(&[agent_message("token=abcdefgh12345678")])
+        .await?;
+    recorder.flush().await?;
+    recorder.shutdown().await?;
+
+    let contents = read_when(&sink_path, |contents| !contents.is_empty()).await;
+    let event: Value = serde_json::from_str(contents.trim())?;
+    assert_eq!(event["thread_id"], json!(thread_id.to_string()));
+    assert_eq!(
+        event["payload"]["message"],
+        json!("token=[REDACTED_SECRET]")
+    );
+    Ok(())
+}
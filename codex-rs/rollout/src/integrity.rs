@@ -0,0 +1,195 @@
+//! Integrity checks for rollout files, used by `codex sessions fsck` and when
+//! a rollout is reopened for resume.
+//!
+//! A crash while appending can leave a rollout ending in a partial line. Such
+//! a line is never newline-terminated, which is what separates it from a
+//! complete but unreadable line in the middle of the file: only the former is
+//! removed by [`repair_rollout`].
+
+use std::fs::File;
+use std::fs::FileTimes;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::ARCHIVED_SESSIONS_SUBDIR;
+use crate::SESSIONS_SUBDIR;
+use crate::compact::collect_rollout_files;
+use crate::compression;
+
+const READ_CHUNK_SIZE: u64 = 8 * 1024;
+
+/// Serde tags of `RolloutItem`. A line with any other `type` was not written
+/// by this version of Codex.
+const ROLLOUT_ITEM_TYPES: &[&str] = &[
+    "session_meta",
+    "response_item",
+    "inter_agent_communication",
+    "inter_agent_communication_metadata",
+    "compacted",
+    "turn_context",
+    "world_state",
+    "event_msg",
+];
+
+/// Result of checking one rollout with [`check_rollout_integrity`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutIntegrityReport {
+    pub valid_lines: usize,
+    /// 1-based numbers of lines that are not JSON objects with a known
+    /// `type`, not counting a truncated final line.
+    pub invalid_lines: Vec<usize>,
+    /// Whether the rollout ends in a partial line left by an interrupted
+    /// write. Only plain rollouts are checked for this.
+    pub truncated_tail: bool,
+}
+
+impl RolloutIntegrityReport {
+    pub fn is_clean(&self) -> bool {
+        self.valid_lines > 0 && self.invalid_lines.is_empty() && !self.truncated_tail
+    }
+}
+
+/// Outcome of checking one rollout during [`check_rollouts`].
+#[derive(Debug)]
+pub struct RolloutCheck {
+    pub path: PathBuf,
+    pub report: io::Result<RolloutIntegrityReport>,
+}
+
+/// Checks that every line of the rollout at `path` is a JSON object with a
+/// known `type`. Works on plain and compressed rollouts.
+pub async fn check_rollout_integrity(path: &Path) -> io::Result<RolloutIntegrityReport> {
+    let mut reader = compression::open_rollout_line_reader(path).await?;
+    let mut report = RolloutIntegrityReport::default();
+    let mut line_number = 0;
+    while let Some(line) = reader.next_line().await? {
+        line_number += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if is_valid_rollout_line(&line) {
+            report.valid_lines += 1;
+        } else {
+            report.invalid_lines.push(line_number);
+        }
+    }
+
+    if !compression::is_compressed_rollout_path(path) {
+        let path = path.to_path_buf();
+        let truncated_tail = tokio::task::spawn_blocking(move || {
+            truncated_tail_start(&mut File::open(path)?).map(|start| start.is_some())
+        })
+        .await
+        .map_err(io::Error::other)??;
+        if truncated_tail {
+            report.invalid_lines.pop();
+            report.truncated_tail = true;
+        }
+    }
+    Ok(report)
+}
+
+/// Checks every rollout under `codex_home`, including archived sessions.
+/// A rollout that cannot be read is reported rather than stopping the pass.
+pub async fn check_rollouts(codex_home: &Path) -> io::Result<Vec<RolloutCheck>> {
+    let mut checks = Vec::new();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        for rollout_file in collect_rollout_files(codex_home.join(root).as_path()).await? {
+            let path = rollout_file.into_path();
+            let report = check_rollout_integrity(path.as_path()).await;
+            checks.push(RolloutCheck { path, report });
+        }
+    }
+    checks.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(checks)
+}
+
+/// Removes a truncated final line from the plain rollout at `path`, first
+/// copying the original next to it with a `.bak` suffix.
+///
+/// Returns the backup path, or `None` when the rollout had nothing to repair.
+/// The modification time is carried over so listing order is unchanged.
+pub async fn repair_rollout(path: &Path) -> io::Result<Option<PathBuf>> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || repair_rollout_blocking(path.as_path()))
+        .await
+        .map_err(io::Error::other)?
+}
+
+fn repair_rollout_blocking(path: &Path) -> io::Result<Option<PathBuf>> {
+    if compression::is_compressed_rollout_path(path) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "compressed rollouts cannot be repaired",
+        ));
+    }
+    let mut file = File::options().read(true).write(true).open(path)?;
+    let Some(tail_start) = truncated_tail_start(&mut file)? else {
+        return Ok(None);
+    };
+
+    let mut backup_path = path.as_os_str().to_owned();
+    backup_path.push(".bak");
+    let backup_path = PathBuf::from(backup_path);
+    if backup_path.try_exists()? {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("backup {} already exists", backup_path.display()),
+        ));
+    }
+    let modified = file.metadata()?.modified()?;
+    std::fs::copy(path, backup_path.as_path())?;
+    file.set_len(tail_start)?;
+    file.set_times(FileTimes::new().set_modified(modified))?;
+    file.sync_all()?;
+    Ok(Some(backup_path))
+}
+
+/// Returns the offset of the final line when it is a partial line: not
+/// newline-terminated and not a valid rollout line. Only the final line is
+/// read, so this is cheap enough to run whenever a rollout is reopened.
+pub(crate) fn truncated_tail_start(file: &mut File) -> io::Result<Option<u64>> {
+    let len = file.metadata()?.len();
+    let mut buf = vec![0; READ_CHUNK_SIZE as usize];
+    let mut chunk_end = len;
+    let mut tail_start = 0;
+    while chunk_end > 0 {
+        let chunk_start = chunk_end.saturating_sub(READ_CHUNK_SIZE);
+        let chunk = &mut buf[..(chunk_end - chunk_start) as usize];
+        file.seek(SeekFrom::Start(chunk_start))?;
+        file.read_exact(chunk)?;
+        if chunk_end == len && chunk.last() == Some(&b'\n') {
+            return Ok(None);
+        }
+        if let Some(newline) = chunk.iter().rposition(|byte| *byte == b'\n') {
+            tail_start = chunk_start + newline as u64 + 1;
+            break;
+        }
+        chunk_end = chunk_start;
+    }
+
+    let mut tail = Vec::new();
+    file.seek(SeekFrom::Start(tail_start))?;
+    file.read_to_end(&mut tail)?;
+    let tail = String::from_utf8_lossy(&tail);
+    Ok((!tail.trim().is_empty() && !is_valid_rollout_line(&tail)).then_some(tail_start))
+}
+
+fn is_valid_rollout_line(line: &str) -> bool {
+    serde_json::from_str::<Value>(line).is_ok_and(|value| {
+        value
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|item_type| ROLLOUT_ITEM_TYPES.contains(&item_type))
+    })
+}
+
+#[cfg(test)]
+#[path = "integrity_tests.rs"]
+mod tests;
@@ -0,0 +1,130 @@
+use std::fs;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::*;
+
+const SESSION_META: &str = r#"{"timestamp":"2025-07-01T00:00:00.000Z","type":"session_meta","payload":{"id":"019a0000-0000-7000-8000-000000000001"}}"#;
+const USER_MESSAGE: &str = r#"{"timestamp":"2025-07-01T00:00:01.000Z","type":"event_msg","payload":{"type":"user_message","message":"hi"}}"#;
+
+fn write_rollout(dir: &Path, contents: &str) -> PathBuf {
+    let path = dir.join("rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-000000000001.jsonl");
+    fs::write(&path, contents).expect("write rollout");
+    path
+}
+
+#[tokio::test]
+async fn check_reports_invalid_lines_and_truncated_tail() {
+    let dir = TempDir::new().expect("tempdir");
+    let path = write_rollout(
+        dir.path(),
+        &format!(
+            "{SESSION_META}\nnot json\n{{\"type\":\"mystery\"}}\n\n{USER_MESSAGE}\n{{\"timestamp\":\"2025-07"
+        ),
+    );
+
+    let report = check_rollout_integrity(&path).await.expect("check");
+
+    assert_eq!(
+        report,
+        RolloutIntegrityReport {
+            valid_lines: 2,
+            invalid_lines: vec![2, 3],
+            truncated_tail: true,
+        }
+    );
+    assert!(!report.is_clean());
+}
+
+#[tokio::test]
+async fn check_treats_terminated_invalid_final_line_as_invalid_not_truncated() {
+    let dir = TempDir::new().expect("tempdir");
+    let path = write_rollout(
+        dir.path(),
+        &format!("{SESSION_META}\n{USER_MESSAGE}\n{{\"ti\n"),
+    );
+
+    let report = check_rollout_integrity(&path).await.expect("check");
+
+    assert_eq!(
+        report,
+        RolloutIntegrityReport {
+            valid_lines: 2,
+            invalid_lines: vec![3],
+            truncated_tail: false,
+        }
+    );
+    assert_eq!(repair_rollout(&path).await.expect("repair"), None);
+}
+
+#[tokio::test]
+async fn repair_truncates_partial_line_and_keeps_a_backup() {
+    let dir = TempDir::new().expect("tempdir");
+    let original = format!("{SESSION_META}\n{USER_MESSAGE}\n{{\"timestamp\":\"2025-07");
+    let path = write_rollout(dir.path(), &original);
+    let modified = SystemTime::now() - Duration::from_secs(60 * 60);
+    File::options()
+        .write(true)
+        .open(&path)
+        .and_then(|file| file.set_times(FileTimes::new().set_modified(modified)))
+        .expect("set rollout mtime");
+    let modified = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .expect("rollout mtime");
+
+    let backup = repair_rollout(&path).await.expect("repair");
+
+    let mut expected_backup = path.as_os_str().to_owned();
+    expected_backup.push(".bak");
+    assert_eq!(backup, Some(PathBuf::from(expected_backup)));
+    assert_eq!(
+        fs::read_to_string(backup.as_deref().expect("backup path")).expect("read backup"),
+        original
+    );
+    assert_eq!(
+        fs::read_to_string(&path).expect("read rollout"),
+        format!("{SESSION_META}\n{USER_MESSAGE}\n")
+    );
+    assert_eq!(
+        fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .expect("mtime"),
+        modified
+    );
+    assert!(
+        check_rollout_integrity(&path)
+            .await
+            .expect("check")
+            .is_clean()
+    );
+
+    // A second partial write must not overwrite the first backup.
+    fs::write(&path, &original).expect("rewrite rollout");
+    let err = repair_rollout(&path)
+        .await
+        .expect_err("existing backup should be kept");
+    assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+}
+
+#[tokio::test]
+async fn check_rollouts_flags_empty_rollouts_under_both_roots() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let sessions = codex_home.path().join("sessions/2025/07/01");
+    let archived = codex_home.path().join("archived_sessions");
+    fs::create_dir_all(&sessions).expect("create sessions dir");
+    fs::create_dir_all(&archived).expect("create archived dir");
+    let healthy = write_rollout(&sessions, &format!("{SESSION_META}\n"));
+    let empty = write_rollout(&archived, "");
+
+    let checks = check_rollouts(codex_home.path())
+        .await
+        .expect("check rollouts")
+        .into_iter()
+        .map(|check| (check.path, check.report.expect("report").is_clean()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(checks, vec![(empty, false), (healthy, true)]);
+}
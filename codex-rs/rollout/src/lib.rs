@@ -4,20 +4,32 @@ use std::sync::LazyLock;
 
 use codex_protocol::protocol::SessionSource;
 
+mod compact;
 pub(crate) mod compression;
 pub(crate) mod config;
+mod encryption;
+mod fork;
+mod history_sink;
+mod integrity;
 pub(crate) mod list;
+mod list_usage;
+mod lock;
 pub(crate) mod metadata;
 mod model_context;
 mod ordinal;
 mod persistence_metrics;
 pub(crate) mod policy;
+mod prune;
 pub(crate) mod recorder;
+mod records;
 mod reverse_jsonl_scanner;
 pub(crate) mod search;
 pub(crate) mod session_index;
+mod session_search;
 mod sqlite_metrics;
 pub mod state_db;
+mod transfer;
+pub mod usage;
 
 pub(crate) use codex_protocol::protocol;
 
@@ -33,16 +45,45 @@ pub static INTERACTIVE_SESSION_SOURCES: LazyLock<Vec<SessionSource>> = LazyLock:
 });
 
 pub use codex_protocol::protocol::SessionMeta;
+pub use compact::RolloutCompaction;
+pub use compact::RolloutCompactionStats;
+pub use compact::compact_rollouts;
+pub use compression::RolloutCompression;
+pub use compression::RolloutCompressionStats;
 pub use compression::RolloutLineReader;
+pub use compression::compress_rollouts_older_than;
 pub use compression::existing_rollout_path;
+pub use compression::is_compressed_rollout_path;
 pub use compression::open_rollout_line_reader;
 pub use compression::plain_rollout_path;
 pub use compression::spawn_rollout_compression_worker;
 pub use config::Config;
 pub use config::RolloutConfig;
 pub use config::RolloutConfigView;
+pub use encryption::RolloutEncryptionStats;
+pub use encryption::RolloutKey;
+pub use encryption::RolloutKeyStorage;
+pub use encryption::configure_rollout_key_store;
+pub use encryption::decrypt_rollouts;
+pub use encryption::encrypt_rollouts;
+pub use encryption::encrypted_rollouts;
+pub use encryption::install_rollout_key;
+pub use encryption::is_encrypted_rollout_path;
+pub use fork::ForkedRollout;
+pub use fork::fork_rollout;
+pub use history_sink::DEFAULT_HISTORY_SINK_BATCH_SIZE;
+pub use history_sink::DEFAULT_HISTORY_SINK_FLUSH_INTERVAL;
+pub use history_sink::HistorySink;
+pub use history_sink::HistorySinkConfig;
+pub use history_sink::webhook_headers;
+pub use integrity::RolloutCheck;
+pub use integrity::RolloutIntegrityReport;
+pub use integrity::check_rollout_integrity;
+pub use integrity::check_rollouts;
+pub use integrity::repair_rollout;
 pub use list::Cursor;
 pub use list::SortDirection;
+pub use list::THREAD_PREVIEW_MAX_CHARS;
 pub use list::ThreadItem;
 pub use list::ThreadListConfig;
 pub use list::ThreadListLayout;
@@ -59,6 +100,7 @@ pub use list::read_head_for_summary;
 pub use list::read_session_meta_line;
 pub use list::read_thread_item_from_rollout;
 pub use list::rollout_date_parts;
+pub use list::single_line_preview;
 pub use metadata::builder_from_items;
 pub use model_context::ModelContextScan;
 pub use model_context::ModelContextScanProgress;
@@ -68,21 +110,40 @@ pub use persistence_metrics::measure_and_filter_rollout_items;
 pub use policy::is_persisted_rollout_item;
 pub use policy::persisted_rollout_items;
 pub use policy::should_persist_response_item_for_memories;
+pub use prune::ACTIVE_ROLLOUT_GRACE;
+pub use prune::PrunedRollout;
+pub use prune::RolloutPrunePolicy;
+pub use prune::RolloutPruneReport;
+pub use prune::delete_rollout;
+pub use prune::prune_rollouts;
+pub use prune::spawn_session_start_prune;
 pub use recorder::RolloutRecorder;
 pub use recorder::RolloutRecorderParams;
 pub use recorder::append_rollout_item_to_path;
+pub use records::RolloutRecord;
+pub use records::RolloutRecordLine;
+pub use records::RolloutRecordStream;
 pub use reverse_jsonl_scanner::ReverseJsonlScanner;
 pub use reverse_jsonl_scanner::ScanOutcome;
 pub use search::first_rollout_content_match_snippet;
 pub use search::search_rollout_matches;
 pub use search::search_rollout_paths;
 pub use session_index::append_thread_name;
+pub use session_index::find_thread_ids_by_name;
 pub use session_index::find_thread_meta_by_name_str;
 pub use session_index::find_thread_name_by_id;
 pub use session_index::find_thread_names_by_ids;
 pub use session_index::remove_thread_name_entries;
+pub use session_search::SessionSearchHit;
+pub use session_search::SessionSearchOptions;
+pub use session_search::SessionSearchQuery;
+pub use session_search::search_sessions;
 pub use state_db::StateDbHandle;
+pub use state_db::reindex_sessions;
 pub use state_db::sqlite_telemetry_recorder;
+pub use transfer::SessionImportStats;
+pub use transfer::archive_sessions;
+pub use transfer::import_sessions;
 
 #[cfg(test)]
 mod tests;
@@ -18,8 +18,14 @@ use uuid::Uuid;
 use super::ARCHIVED_SESSIONS_SUBDIR;
 use super::SESSIONS_SUBDIR;
 use super::compression;
+use crate::list_usage::UsageSortKey;
+use crate::list_usage::traverse_paths_by_usage;
 use crate::protocol::EventMsg;
 use crate::state_db;
+use chrono::DateTime;
+use chrono::Months;
+use chrono::NaiveDate;
+use chrono::Utc;
 use codex_file_search as file_search;
 use codex_protocol::ThreadId;
 use codex_protocol::items::TurnItem;
@@ -29,6 +35,8 @@ use codex_protocol::protocol::SessionMetaLine;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::ThreadHistoryMode;
 use codex_protocol::protocol::user_message_preview;
+use codex_protocol::protocol::user_shell_command_preview;
+use codex_state::ThreadTimeBounds;
 use serde_json::Value;
 
 /// Returned page of thread (thread) summaries.
@@ -44,6 +52,9 @@ pub struct ThreadsPage {
     pub reached_scan_cap: bool,
 }
 
+/// Longest [`ThreadItem::short_preview`], in characters.
+pub const THREAD_PREVIEW_MAX_CHARS: usize = 120;
+
 /// Summary information for a thread rollout file.
 #[derive(Debug, PartialEq, Default)]
 pub struct ThreadItem {
@@ -86,6 +97,32 @@ pub struct ThreadItem {
     pub recency_at: Option<String>,
 }
 
+impl ThreadItem {
+    /// The first user message, or the preview when there is none, on one line
+    /// and cut to [`THREAD_PREVIEW_MAX_CHARS`] for list rows.
+    pub fn short_preview(&self) -> Option<String> {
+        self.first_user_message
+            .as_deref()
+            .or(self.preview.as_deref())
+            .map(|preview| single_line_preview(preview, THREAD_PREVIEW_MAX_CHARS))
+            .filter(|preview| !preview.is_empty())
+    }
+}
+
+/// Collapses whitespace, newlines included, to single spaces and cuts the
+/// result to `max_chars`, ending it with `…` when cut.
+pub fn single_line_preview(text: &str, max_chars: usize) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= max_chars {
+        return line;
+    }
+    let truncated = line
+        .chars()
+        .take(max_chars.saturating_sub(1))
+        .collect::<String>();
+    format!("{truncated}…")
+}
+
 #[allow(dead_code)]
 #[deprecated(note = "use ThreadItem")]
 pub type ConversationItem = ThreadItem;
@@ -115,7 +152,7 @@ struct HeadTailSummary {
 }
 
 /// Hard cap to bound worst‑case work per request.
-const MAX_SCAN_FILES: usize = 10000;
+pub(crate) const MAX_SCAN_FILES: usize = 10000;
 const HEAD_RECORD_LIMIT: usize = 10;
 const USER_EVENT_SCAN_LIMIT: usize = 200;
 
@@ -124,6 +161,10 @@ pub enum ThreadSortKey {
     CreatedAt,
     UpdatedAt,
     RecencyAt,
+    /// Size of the rollout file on disk.
+    FileSize,
+    /// `total_tokens` from the last `token_count` event in the rollout.
+    TotalTokens,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -144,22 +185,49 @@ pub struct ThreadListConfig<'a> {
     pub cwd_filters: Option<&'a [PathBuf]>,
     pub default_provider: &'a str,
     pub layout: ThreadListLayout,
+    /// Bounds on the filename timestamp (created) and file mtime (updated),
+    /// applied before pagination.
+    pub time_bounds: ThreadTimeBounds,
 }
 
 /// Pagination cursor identifying the last item in a page.
+///
+/// Timestamp orders anchor on `ts`; the file size and token orders anchor on
+/// `value` instead and leave `ts` at the Unix epoch.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Cursor {
     ts: OffsetDateTime,
+    value: Option<u64>,
     id: Option<ThreadId>,
 }
 
 impl Cursor {
     pub(crate) fn new(ts: OffsetDateTime) -> Self {
-        Self { ts, id: None }
+        Self {
+            ts,
+            value: None,
+            id: None,
+        }
     }
 
     pub(crate) fn with_thread_id(ts: OffsetDateTime, id: ThreadId) -> Self {
-        Self { ts, id: Some(id) }
+        Self {
+            ts,
+            value: None,
+            id: Some(id),
+        }
+    }
+
+    pub(crate) fn with_value(value: u64, id: ThreadId) -> Self {
+        Self {
+            ts: OffsetDateTime::UNIX_EPOCH,
+            value: Some(value),
+            id: Some(id),
+        }
+    }
+
+    pub(crate) fn value(&self) -> Option<u64> {
+        self.value
     }
 
     pub(crate) fn timestamp(&self) -> OffsetDateTime {
@@ -207,6 +275,37 @@ impl AnchorState {
     }
 }
 
+/// Whether a rollout created at `created_at` (its filename timestamp) and last
+/// modified at `updated_at` satisfies `bounds`. Lower bounds are inclusive and
+/// upper bounds exclusive, matching the state DB query. A missing timestamp
+/// never satisfies a bound on it.
+pub(crate) fn within_time_bounds(
+    bounds: &ThreadTimeBounds,
+    created_at: Option<OffsetDateTime>,
+    updated_at: Option<OffsetDateTime>,
+) -> bool {
+    within_range(created_at, bounds.created_after, bounds.created_before)
+        && within_range(updated_at, bounds.updated_after, bounds.updated_before)
+}
+
+fn within_range(
+    ts: Option<OffsetDateTime>,
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+) -> bool {
+    if after.is_none() && before.is_none() {
+        return true;
+    }
+    let Some(ts) = ts else {
+        return false;
+    };
+    let ts = ts.unix_timestamp_nanos();
+    let nanos = |dt: DateTime<Utc>| {
+        i128::from(dt.timestamp()) * 1_000_000_000 + i128::from(dt.timestamp_subsec_nanos())
+    };
+    after.is_none_or(|after| ts >= nanos(after)) && before.is_none_or(|before| ts < nanos(before))
+}
+
 /// Visitor interface to customize behavior when visiting each rollout file
 /// in `walk_rollout_files`.
 ///
@@ -232,6 +331,7 @@ struct FilesByCreatedAtVisitor<'a> {
     allowed_sources: &'a [SessionSource],
     provider_matcher: Option<&'a ProviderMatcher<'a>>,
     cwd_filters: Option<&'a [PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 }
 
 impl<'a> RolloutFileVisitor for FilesByCreatedAtVisitor<'a> {
@@ -249,14 +349,23 @@ impl<'a> RolloutFileVisitor for FilesByCreatedAtVisitor<'a> {
         if self.anchor_state.should_skip(ts, id) {
             return ControlFlow::Continue(());
         }
+        // Files arrive newest first, so nothing after this one is recent enough.
+        if !within_range(
+            Some(ts),
+            self.time_bounds.created_after,
+            /*before*/ None,
+        ) {
+            return ControlFlow::Break(());
+        }
+        let updated_at = file_modified_time(&path).await.unwrap_or(None);
+        if !within_time_bounds(&self.time_bounds, Some(ts), updated_at) {
+            return ControlFlow::Continue(());
+        }
         if self.items.len() == self.page_size {
             self.more_matches_available = true;
             return ControlFlow::Break(());
         }
-        let updated_at = file_modified_time(&path)
-            .await
-            .unwrap_or(None)
-            .and_then(format_rfc3339);
+        let updated_at = updated_at.and_then(format_rfc3339);
         if let Some(item) = build_thread_item(
             path,
             self.allowed_sources,
@@ -281,7 +390,7 @@ struct FilesByUpdatedAtVisitor<'a> {
 impl<'a> RolloutFileVisitor for FilesByUpdatedAtVisitor<'a> {
     async fn visit(
         &mut self,
-        _ts: OffsetDateTime,
+        ts: OffsetDateTime,
         id: Uuid,
         path: PathBuf,
         _scanned: usize,
@@ -290,6 +399,7 @@ impl<'a> RolloutFileVisitor for FilesByUpdatedAtVisitor<'a> {
         self.candidates.push(ThreadCandidate {
             path,
             id,
+            created_at: ts,
             updated_at,
         });
         ControlFlow::Continue(())
@@ -301,10 +411,13 @@ impl serde::Serialize for Cursor {
     where
         S: serde::Serializer,
     {
-        let ts_str = self
-            .ts
-            .format(&Rfc3339)
-            .map_err(|e| serde::ser::Error::custom(format!("format error: {e}")))?;
+        let ts_str = match self.value {
+            Some(value) => value.to_string(),
+            None => self
+                .ts
+                .format(&Rfc3339)
+                .map_err(|e| serde::ser::Error::custom(format!("format error: {e}")))?,
+        };
         match self.id {
             Some(id) => serializer.serialize_str(&format!("{ts_str}|{id}")),
             None => serializer.serialize_str(&ts_str),
@@ -329,7 +442,11 @@ impl From<codex_state::Anchor> for Cursor {
             .timestamp_nanos_opt()
             .and_then(|nanos| OffsetDateTime::from_unix_timestamp_nanos(nanos as i128).ok())
             .unwrap_or(OffsetDateTime::UNIX_EPOCH);
-        Self { ts, id: anchor.id }
+        Self {
+            ts,
+            value: None,
+            id: anchor.id,
+        }
     }
 }
 
@@ -359,6 +476,7 @@ pub async fn get_threads(
             cwd_filters,
             default_provider,
             layout: ThreadListLayout::NestedByDate,
+            time_bounds: ThreadTimeBounds::default(),
         },
     )
     .await
@@ -396,6 +514,7 @@ pub async fn get_threads_in_root(
                 config.allowed_sources,
                 provider_matcher.as_ref(),
                 config.cwd_filters,
+                config.time_bounds,
             )
             .await?
         }
@@ -408,6 +527,7 @@ pub async fn get_threads_in_root(
                 config.allowed_sources,
                 provider_matcher.as_ref(),
                 config.cwd_filters,
+                config.time_bounds,
             )
             .await?
         }
@@ -427,6 +547,7 @@ async fn traverse_directories_for_paths(
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
     cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 ) -> io::Result<ThreadsPage> {
     match sort_key {
         ThreadSortKey::CreatedAt => {
@@ -437,6 +558,7 @@ async fn traverse_directories_for_paths(
                 allowed_sources,
                 provider_matcher,
                 cwd_filters,
+                time_bounds,
             )
             .await
         }
@@ -448,6 +570,39 @@ async fn traverse_directories_for_paths(
                 allowed_sources,
                 provider_matcher,
                 cwd_filters,
+                time_bounds,
+            )
+            .await
+        }
+        ThreadSortKey::FileSize => {
+            traverse_paths_by_usage(
+                root,
+                ThreadListLayout::NestedByDate,
+                page_size,
+                anchor,
+                UsageSortKey::FileSize,
+                SortDirection::Desc,
+                allowed_sources,
+                provider_matcher,
+                cwd_filters,
+                time_bounds,
+                /*title_search*/ None,
+            )
+            .await
+        }
+        ThreadSortKey::TotalTokens => {
+            traverse_paths_by_usage(
+                root,
+                ThreadListLayout::NestedByDate,
+                page_size,
+                anchor,
+                UsageSortKey::TotalTokens,
+                SortDirection::Desc,
+                allowed_sources,
+                provider_matcher,
+                cwd_filters,
+                time_bounds,
+                /*title_search*/ None,
             )
             .await
         }
@@ -462,6 +617,7 @@ async fn traverse_flat_paths(
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
     cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 ) -> io::Result<ThreadsPage> {
     match sort_key {
         ThreadSortKey::CreatedAt => {
@@ -472,6 +628,7 @@ async fn traverse_flat_paths(
                 allowed_sources,
                 provider_matcher,
                 cwd_filters,
+                time_bounds,
             )
             .await
         }
@@ -483,6 +640,39 @@ async fn traverse_flat_paths(
                 allowed_sources,
                 provider_matcher,
                 cwd_filters,
+                time_bounds,
+            )
+            .await
+        }
+        ThreadSortKey::FileSize => {
+            traverse_paths_by_usage(
+                root,
+                ThreadListLayout::Flat,
+                page_size,
+                anchor,
+                UsageSortKey::FileSize,
+                SortDirection::Desc,
+                allowed_sources,
+                provider_matcher,
+                cwd_filters,
+                time_bounds,
+                /*title_search*/ None,
+            )
+            .await
+        }
+        ThreadSortKey::TotalTokens => {
+            traverse_paths_by_usage(
+                root,
+                ThreadListLayout::Flat,
+                page_size,
+                anchor,
+                UsageSortKey::TotalTokens,
+                SortDirection::Desc,
+                allowed_sources,
+                provider_matcher,
+                cwd_filters,
+                time_bounds,
+                /*title_search*/ None,
             )
             .await
         }
@@ -502,6 +692,7 @@ async fn traverse_directories_for_paths_created(
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
     cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 ) -> io::Result<ThreadsPage> {
     let mut items: Vec<ThreadItem> = Vec::with_capacity(page_size);
     let mut scanned_files = 0usize;
@@ -514,8 +705,9 @@ async fn traverse_directories_for_paths_created(
         allowed_sources,
         provider_matcher,
         cwd_filters,
+        time_bounds,
     };
-    walk_rollout_files(&root, &mut scanned_files, &mut visitor).await?;
+    walk_rollout_files(&root, &time_bounds, &mut scanned_files, &mut visitor).await?;
     more_matches_available = visitor.more_matches_available;
 
     let reached_scan_cap = scanned_files >= MAX_SCAN_FILES;
@@ -551,13 +743,15 @@ async fn traverse_directories_for_paths_updated(
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
     cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 ) -> io::Result<ThreadsPage> {
     let mut items: Vec<ThreadItem> = Vec::with_capacity(page_size);
     let mut scanned_files = 0usize;
     let mut anchor_state = AnchorState::new(anchor);
     let mut more_matches_available = false;
 
-    let mut candidates = collect_files_by_updated_at(&root, &mut scanned_files).await?;
+    let mut candidates =
+        collect_files_by_updated_at(&root, &time_bounds, &mut scanned_files).await?;
     candidates.sort_by_key(|candidate| {
         let ts = candidate.updated_at.unwrap_or(OffsetDateTime::UNIX_EPOCH);
         (Reverse(ts), Reverse(candidate.id))
@@ -568,6 +762,13 @@ async fn traverse_directories_for_paths_updated(
         if anchor_state.should_skip(ts, candidate.id) {
             continue;
         }
+        if !within_time_bounds(
+            &time_bounds,
+            Some(candidate.created_at),
+            candidate.updated_at,
+        ) {
+            continue;
+        }
         if items.len() == page_size {
             more_matches_available = true;
             break;
@@ -612,6 +813,7 @@ async fn traverse_flat_paths_created(
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
     cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 ) -> io::Result<ThreadsPage> {
     let mut items: Vec<ThreadItem> = Vec::with_capacity(page_size);
     let mut scanned_files = 0usize;
@@ -623,14 +825,15 @@ async fn traverse_flat_paths_created(
         if anchor_state.should_skip(ts, id) {
             continue;
         }
+        let updated_at = file_modified_time(&path).await.unwrap_or(None);
+        if !within_time_bounds(&time_bounds, Some(ts), updated_at) {
+            continue;
+        }
         if items.len() == page_size {
             more_matches_available = true;
             break;
         }
-        let updated_at = file_modified_time(&path)
-            .await
-            .unwrap_or(None)
-            .and_then(format_rfc3339);
+        let updated_at = updated_at.and_then(format_rfc3339);
         if let Some(item) = build_thread_item(
             path,
             allowed_sources,
@@ -669,6 +872,7 @@ async fn traverse_flat_paths_updated(
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
     cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
 ) -> io::Result<ThreadsPage> {
     let mut items: Vec<ThreadItem> = Vec::with_capacity(page_size);
     let mut scanned_files = 0usize;
@@ -686,6 +890,13 @@ async fn traverse_flat_paths_updated(
         if anchor_state.should_skip(ts, candidate.id) {
             continue;
         }
+        if !within_time_bounds(
+            &time_bounds,
+            Some(candidate.created_at),
+            candidate.updated_at,
+        ) {
+            continue;
+        }
         if items.len() == page_size {
             more_matches_available = true;
             break;
@@ -724,11 +935,19 @@ async fn traverse_flat_paths_updated(
 }
 
 /// Pagination cursor token format: an RFC3339 timestamp with an optional thread ID tie-breaker.
+/// File size and token cursors use an integer value in place of the timestamp.
 pub fn parse_cursor(token: &str) -> Option<Cursor> {
     let (timestamp, id) = match token.rsplit_once('|') {
         Some((timestamp, id)) => (timestamp, Some(ThreadId::from_string(id).ok()?)),
         None => (token, None),
     };
+    if let Ok(value) = timestamp.parse::<u64>() {
+        return Some(Cursor {
+            ts: OffsetDateTime::UNIX_EPOCH,
+            value: Some(value),
+            id,
+        });
+    }
 
     let ts = OffsetDateTime::parse(timestamp, &Rfc3339)
         .ok()
@@ -740,7 +959,11 @@ pub fn parse_cursor(token: &str) -> Option<Cursor> {
                 .map(PrimitiveDateTime::assume_utc)
         })?;
 
-    Some(Cursor { ts, id })
+    Some(Cursor {
+        ts,
+        value: None,
+        id,
+    })
 }
 
 fn build_next_cursor(items: &[ThreadItem], sort_key: ThreadSortKey) -> Option<Cursor> {
@@ -757,17 +980,22 @@ fn build_next_cursor(items: &[ThreadItem], sort_key: ThreadSortKey) -> Option<Cu
             let recency_at = last.recency_at.as_deref().or(last.updated_at.as_deref())?;
             OffsetDateTime::parse(recency_at, &Rfc3339).ok()?
         }
+        // Usage orders build their cursors from the sort value in `list_usage`.
+        ThreadSortKey::FileSize | ThreadSortKey::TotalTokens => return None,
     };
     match sort_key {
         ThreadSortKey::RecencyAt => Some(Cursor::with_thread_id(
             ts,
             ThreadId::from_string(&id.to_string()).ok()?,
         )),
-        ThreadSortKey::CreatedAt | ThreadSortKey::UpdatedAt => Some(Cursor::new(ts)),
+        ThreadSortKey::CreatedAt
+        | ThreadSortKey::UpdatedAt
+        | ThreadSortKey::FileSize
+        | ThreadSortKey::TotalTokens => Some(Cursor::new(ts)),
     }
 }
 
-async fn build_thread_item(
+pub(crate) async fn build_thread_item(
     path: PathBuf,
     allowed_sources: &[SessionSource],
     provider_matcher: Option<&ProviderMatcher<'_>>,
@@ -962,7 +1190,7 @@ async fn collect_rollout_day_files(
 }
 
 pub(crate) fn parse_timestamp_uuid_from_filename(name: &str) -> Option<(OffsetDateTime, Uuid)> {
-    // Expected: rollout-YYYY-MM-DDThh-mm-ss-<uuid>.jsonl[.zst]
+    // Expected: rollout-YYYY-MM-DDThh-mm-ss-<uuid>.jsonl[.zst|.gz]
     let name = compression::parse_rollout_file_name(name)?;
     let core = name.strip_prefix("rollout-")?.strip_suffix(".jsonl")?;
 
@@ -979,26 +1207,28 @@ pub(crate) fn parse_timestamp_uuid_from_filename(name: &str) -> Option<(OffsetDa
     Some((ts, uuid))
 }
 
-struct ThreadCandidate {
-    path: PathBuf,
-    id: Uuid,
-    updated_at: Option<OffsetDateTime>,
+pub(crate) struct ThreadCandidate {
+    pub(crate) path: PathBuf,
+    pub(crate) id: Uuid,
+    pub(crate) created_at: OffsetDateTime,
+    pub(crate) updated_at: Option<OffsetDateTime>,
 }
 
-async fn collect_files_by_updated_at(
+pub(crate) async fn collect_files_by_updated_at(
     root: &Path,
+    time_bounds: &ThreadTimeBounds,
     scanned_files: &mut usize,
 ) -> io::Result<Vec<ThreadCandidate>> {
     let mut candidates = Vec::new();
     let mut visitor = FilesByUpdatedAtVisitor {
         candidates: &mut candidates,
     };
-    walk_rollout_files(root, scanned_files, &mut visitor).await?;
+    walk_rollout_files(root, time_bounds, scanned_files, &mut visitor).await?;
 
     Ok(candidates)
 }
 
-async fn collect_flat_files_by_updated_at(
+pub(crate) async fn collect_flat_files_by_updated_at(
     root: &Path,
     scanned_files: &mut usize,
 ) -> io::Result<Vec<ThreadCandidate>> {
@@ -1019,7 +1249,8 @@ async fn collect_flat_files_by_updated_at(
         let Some(rollout_file) = compression::RolloutFile::from_path(entry.path()) else {
             continue;
         };
-        let Some((_ts, id)) = parse_timestamp_uuid_from_filename(rollout_file.plain_file_name())
+        let Some((created_at, id)) =
+            parse_timestamp_uuid_from_filename(rollout_file.plain_file_name())
         else {
             continue;
         };
@@ -1033,6 +1264,7 @@ async fn collect_flat_files_by_updated_at(
         candidates.push(ThreadCandidate {
             path: rollout_file.into_path(),
             id,
+            created_at,
             updated_at,
         });
     }
@@ -1040,27 +1272,39 @@ async fn collect_flat_files_by_updated_at(
     Ok(candidates)
 }
 
+/// Walks the `YYYY/MM/DD` tree newest first, skipping directories that cannot
+/// hold rollouts created within `time_bounds` without reading them.
 async fn walk_rollout_files(
     root: &Path,
+    time_bounds: &ThreadTimeBounds,
     scanned_files: &mut usize,
     visitor: &mut impl RolloutFileVisitor,
 ) -> io::Result<()> {
     let year_dirs = collect_dirs_desc(root, |s| s.parse::<u16>().ok()).await?;
 
-    'outer: for (_year, year_path) in year_dirs.iter() {
+    'outer: for (year, year_path) in year_dirs.iter() {
         if *scanned_files >= MAX_SCAN_FILES {
             break;
         }
+        if !date_dir_may_match(time_bounds, *year, /*month*/ None, /*day*/ None) {
+            continue;
+        }
         let month_dirs = collect_dirs_desc(year_path, |s| s.parse::<u8>().ok()).await?;
-        for (_month, month_path) in month_dirs.iter() {
+        for (month, month_path) in month_dirs.iter() {
             if *scanned_files >= MAX_SCAN_FILES {
                 break 'outer;
             }
+            if !date_dir_may_match(time_bounds, *year, Some(*month), /*day*/ None) {
+                continue;
+            }
             let day_dirs = collect_dirs_desc(month_path, |s| s.parse::<u8>().ok()).await?;
-            for (_day, day_path) in day_dirs.iter() {
+            for (day, day_path) in day_dirs.iter() {
                 if *scanned_files >= MAX_SCAN_FILES {
                     break 'outer;
                 }
+                if !date_dir_may_match(time_bounds, *year, Some(*month), Some(*day)) {
+                    continue;
+                }
                 let day_files = collect_rollout_day_files(day_path).await?;
                 for (ts, id, path) in day_files.into_iter() {
                     *scanned_files += 1;
@@ -1080,13 +1324,43 @@ async fn walk_rollout_files(
     Ok(())
 }
 
-struct ProviderMatcher<'a> {
+/// Whether the date directory for `year`, `month`, and `day` can hold rollouts
+/// created within `time_bounds`. Directory dates and filename timestamps come
+/// from the same clock, so comparing the directory's date range with the
+/// created bounds agrees with [`within_time_bounds`]. Directories whose names
+/// are not a valid date are always walked.
+fn date_dir_may_match(
+    time_bounds: &ThreadTimeBounds,
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+) -> bool {
+    let range = match (month, day) {
+        (Some(month), Some(day)) => NaiveDate::from_ymd_opt(year.into(), month.into(), day.into())
+            .and_then(|start| Some((start, start.succ_opt()?))),
+        (Some(month), None) => NaiveDate::from_ymd_opt(year.into(), month.into(), 1)
+            .and_then(|start| Some((start, start.checked_add_months(Months::new(1))?))),
+        (None, _) => NaiveDate::from_ymd_opt(year.into(), 1, 1)
+            .and_then(|start| Some((start, start.checked_add_months(Months::new(12))?))),
+    };
+    let Some((start, end)) = range else {
+        return true;
+    };
+    let start = start.and_time(chrono::NaiveTime::MIN).and_utc();
+    let end = end.and_time(chrono::NaiveTime::MIN).and_utc();
+    time_bounds
+        .created_before
+        .is_none_or(|before| start < before)
+        && time_bounds.created_after.is_none_or(|after| end > after)
+}
+
+pub(crate) struct ProviderMatcher<'a> {
     filters: &'a [String],
     matches_default_provider: bool,
 }
 
 impl<'a> ProviderMatcher<'a> {
-    fn new(filters: &'a [String], default_provider: &'a str) -> Option<Self> {
+    pub(crate) fn new(filters: &'a [String], default_provider: &'a str) -> Option<Self> {
         if filters.is_empty() {
             return None;
         }
@@ -1168,7 +1442,15 @@ async fn read_head_summary(path: &Path, head_limit: usize) -> io::Result<HeadTai
                     summary.saw_session_meta = true;
                 }
             }
-            RolloutItem::ResponseItem(_) | RolloutItem::InterAgentCommunication(_) => {
+            RolloutItem::ResponseItem(item) => {
+                summary
+                    .created_at
+                    .get_or_insert_with(|| rollout_line.timestamp.clone());
+                if summary.preview.is_none() {
+                    summary.preview = user_shell_command_preview(&item);
+                }
+            }
+            RolloutItem::InterAgentCommunication(_) => {
                 summary
                     .created_at
                     .get_or_insert_with(|| rollout_line.timestamp.clone());
@@ -1177,6 +1459,9 @@ async fn read_head_summary(path: &Path, head_limit: usize) -> io::Result<HeadTai
             RolloutItem::TurnContext(_) => {
                 // Not included in `head`; skip.
             }
+            RolloutItem::TurnSummary(_) => {
+                // Not included in `head`; skip.
+            }
             RolloutItem::WorldState(_) => {
                 // Not included in `head`; skip.
             }
@@ -1249,6 +1534,7 @@ pub async fn read_head_for_summary(path: &Path) -> io::Result<Vec<serde_json::Va
                 RolloutItem::InterAgentCommunicationMetadata { .. }
                 | RolloutItem::Compacted(_)
                 | RolloutItem::TurnContext(_)
+                | RolloutItem::TurnSummary(_)
                 | RolloutItem::WorldState(_)
                 | RolloutItem::EventMsg(_) => {}
             }
@@ -1301,6 +1587,7 @@ pub async fn read_session_meta_line(path: &Path) -> io::Result<SessionMetaLine>
             RolloutItem::InterAgentCommunicationMetadata { .. }
             | RolloutItem::Compacted(_)
             | RolloutItem::TurnContext(_)
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_)
             | RolloutItem::EventMsg(_) => {}
         }
@@ -1317,7 +1604,7 @@ async fn file_modified_time(path: &Path) -> io::Result<Option<OffsetDateTime>> {
         .and_then(truncate_to_millis))
 }
 
-fn format_rfc3339(dt: OffsetDateTime) -> Option<String> {
+pub(crate) fn format_rfc3339(dt: OffsetDateTime) -> Option<String> {
     dt.format(&Rfc3339).ok()
 }
 
@@ -0,0 +1,253 @@
+//! Thread listings ordered by rollout usage: file size or total tokens.
+//!
+//! Neither value is indexed in the state DB, so these orders always come from
+//! a scan of the rollout files. Candidates are sorted in memory by
+//! `(value, id)`, and cursors carry both so threads that tie on the value page
+//! without being skipped or repeated.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SessionSource;
+use codex_state::ThreadTimeBounds;
+use uuid::Uuid;
+
+use crate::compression;
+use crate::encryption;
+use crate::list::Cursor;
+use crate::list::MAX_SCAN_FILES;
+use crate::list::ProviderMatcher;
+use crate::list::SortDirection;
+use crate::list::ThreadCandidate;
+use crate::list::ThreadListLayout;
+use crate::list::ThreadsPage;
+use crate::list::build_thread_item;
+use crate::list::collect_files_by_updated_at;
+use crate::list::collect_flat_files_by_updated_at;
+use crate::list::format_rfc3339;
+use crate::list::within_time_bounds;
+use crate::reverse_jsonl_scanner::ReverseJsonlScanner;
+use crate::reverse_jsonl_scanner::ScanOutcome;
+use crate::session_index::find_thread_names_by_ids;
+
+/// Records read from the end of a plain rollout while looking for the last
+/// `token_count` event. Token counts are emitted after every turn, so a
+/// missing count this close to the tail means the thread never reported one.
+const TOKEN_COUNT_SCAN_LIMIT: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UsageSortKey {
+    /// Size of the rollout file on disk, in bytes.
+    FileSize,
+    /// `total_tokens` from the last `token_count` event in the rollout.
+    TotalTokens,
+}
+
+/// Thread title filter applied before pagination, backed by the session index
+/// in `codex_home`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TitleSearch<'a> {
+    pub(crate) codex_home: &'a Path,
+    pub(crate) term: &'a str,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn traverse_paths_by_usage(
+    root: PathBuf,
+    layout: ThreadListLayout,
+    page_size: usize,
+    anchor: Option<Cursor>,
+    sort_key: UsageSortKey,
+    sort_direction: SortDirection,
+    allowed_sources: &[SessionSource],
+    provider_matcher: Option<&ProviderMatcher<'_>>,
+    cwd_filters: Option<&[PathBuf]>,
+    time_bounds: ThreadTimeBounds,
+    title_search: Option<TitleSearch<'_>>,
+) -> io::Result<ThreadsPage> {
+    if !root.exists() {
+        return Ok(ThreadsPage::default());
+    }
+
+    let mut scanned_files = 0usize;
+    let mut candidates = match layout {
+        ThreadListLayout::NestedByDate => {
+            collect_files_by_updated_at(&root, &time_bounds, &mut scanned_files).await?
+        }
+        ThreadListLayout::Flat => {
+            collect_flat_files_by_updated_at(&root, &mut scanned_files).await?
+        }
+    };
+    candidates.retain(|candidate| {
+        within_time_bounds(
+            &time_bounds,
+            Some(candidate.created_at),
+            candidate.updated_at,
+        )
+    });
+    if let Some(title_search) = title_search {
+        retain_title_matches(&mut candidates, title_search).await?;
+    }
+
+    let mut keyed_candidates = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        let value = match sort_key {
+            UsageSortKey::FileSize => rollout_file_size(&candidate.path).await,
+            UsageSortKey::TotalTokens => last_total_tokens(&candidate.path).await,
+        };
+        keyed_candidates.push(((value, candidate.id), candidate));
+    }
+    match sort_direction {
+        SortDirection::Asc => keyed_candidates.sort_by_key(|(key, _)| *key),
+        SortDirection::Desc => keyed_candidates.sort_by_key(|(key, _)| Reverse(*key)),
+    }
+
+    let anchor = anchor.and_then(|cursor| {
+        let id = Uuid::parse_str(&cursor.thread_id()?.to_string()).ok()?;
+        Some((cursor.value()?, id))
+    });
+    let mut items = Vec::with_capacity(page_size);
+    let mut last_key = None;
+    let mut more_matches_available = false;
+    for (key, candidate) in keyed_candidates {
+        let past_anchor = anchor.is_none_or(|anchor| match sort_direction {
+            SortDirection::Asc => key > anchor,
+            SortDirection::Desc => key < anchor,
+        });
+        if !past_anchor {
+            continue;
+        }
+        if items.len() == page_size {
+            more_matches_available = true;
+            break;
+        }
+        let updated_at_fallback = candidate.updated_at.and_then(format_rfc3339);
+        if let Some(item) = build_thread_item(
+            candidate.path,
+            allowed_sources,
+            provider_matcher,
+            cwd_filters,
+            updated_at_fallback,
+        )
+        .await
+        {
+            items.push(item);
+            last_key = Some(key);
+        }
+    }
+
+    let reached_scan_cap = scanned_files >= MAX_SCAN_FILES;
+    if reached_scan_cap && !items.is_empty() {
+        more_matches_available = true;
+    }
+    let next_cursor = if more_matches_available {
+        last_key.and_then(|(value, id)| {
+            let thread_id = ThreadId::from_string(&id.to_string()).ok()?;
+            Some(Cursor::with_value(value, thread_id))
+        })
+    } else {
+        None
+    };
+    Ok(ThreadsPage {
+        items,
+        next_cursor,
+        num_scanned_files: scanned_files,
+        reached_scan_cap,
+    })
+}
+
+async fn retain_title_matches(
+    candidates: &mut Vec<ThreadCandidate>,
+    title_search: TitleSearch<'_>,
+) -> io::Result<()> {
+    let thread_ids = candidates
+        .iter()
+        .filter_map(|candidate| ThreadId::from_string(&candidate.id.to_string()).ok())
+        .collect::<HashSet<_>>();
+    let thread_names = find_thread_names_by_ids(title_search.codex_home, &thread_ids).await?;
+    candidates.retain(|candidate| {
+        ThreadId::from_string(&candidate.id.to_string())
+            .ok()
+            .and_then(|thread_id| thread_names.get(&thread_id))
+            .is_some_and(|title| title.contains(title_search.term))
+    });
+    Ok(())
+}
+
+/// Size of the rollout as stored on disk. Compressed rollouts report their
+/// compressed size. Unreadable files sort as empty.
+async fn rollout_file_size(path: &Path) -> u64 {
+    tokio::fs::metadata(path)
+        .await
+        .map_or(0, |metadata| metadata.len())
+}
+
+/// `total_tokens` from the last `token_count` event, or 0 when the rollout
+/// never reported one.
+async fn last_total_tokens(path: &Path) -> u64 {
+    let total_tokens = if compression::is_compressed_rollout_path(path)
+        || encryption::is_encrypted_rollout_path(path)
+    {
+        last_total_tokens_from_start(path).await
+    } else {
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || last_total_tokens_from_tail(&path))
+            .await
+            .map_err(io::Error::other)
+            .and_then(|result| result)
+    };
+    total_tokens
+        .ok()
+        .flatten()
+        .and_then(|total_tokens| u64::try_from(total_tokens).ok())
+        .unwrap_or(0)
+}
+
+fn last_total_tokens_from_tail(path: &Path) -> io::Result<Option<i64>> {
+    let mut scanner = ReverseJsonlScanner::new(File::open(path)?)?;
+    for _ in 0..TOKEN_COUNT_SCAN_LIMIT {
+        match scanner.scan_next::<RolloutLine>()? {
+            Some(ScanOutcome::Parsed(line)) => {
+                if let Some(total_tokens) = total_tokens(&line.item) {
+                    return Ok(Some(total_tokens));
+                }
+            }
+            Some(ScanOutcome::Rejected(_)) => {}
+            None => break,
+        }
+    }
+    Ok(None)
+}
+
+/// Compressed and encrypted rollouts cannot be read backwards, so they are
+/// streamed from the start and the last count wins.
+async fn last_total_tokens_from_start(path: &Path) -> io::Result<Option<i64>> {
+    let mut lines = compression::open_rollout_line_reader(path).await?;
+    let mut last = None;
+    while let Some(line) = lines.next_line().await? {
+        if let Ok(line) = serde_json::from_str::<RolloutLine>(&line)
+            && let Some(total_tokens) = total_tokens(&line.item)
+        {
+            last = Some(total_tokens);
+        }
+    }
+    Ok(last)
+}
+
+fn total_tokens(item: &RolloutItem) -> Option<i64> {
+    match item {
+        RolloutItem::EventMsg(EventMsg::TokenCount(event)) => event
+            .info
+            .as_ref()
+            .map(|info| info.total_token_usage.total_tokens),
+        _ => None,
+    }
+}
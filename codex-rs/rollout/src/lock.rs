@@ -0,0 +1,179 @@
+//! Advisory locks that keep concurrent Codex processes from writing or
+//! rewriting the same rollout.
+//!
+//! Each rollout has a sidecar `rollout-<timestamp>-<id>.lock` file next to it,
+//! shared by its plain, compressed, and encrypted forms. The process appending
+//! to a session holds an exclusive lock and records its PID in the lock file.
+//! Maintenance passes that rewrite or delete a rollout (prune, compression,
+//! compaction, encryption) also take an exclusive lock first and skip
+//! rollouts whose lock is held, so they never rewrite a file that is being
+//! appended to or that another pass is already replacing. Read-only consumers
+//! may take a shared lock, which only keeps writers and rewrites out.
+//!
+//! The locks are advisory: they only coordinate Codex processes with each
+//! other. On Windows the lock file cannot be read while it is locked, so the
+//! holder's PID is not reported there.
+
+use std::fs::File;
+use std::fs::TryLockError;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::compression;
+
+const LOCK_SUFFIX: &str = ".lock";
+const JSONL_SUFFIX: &str = ".jsonl";
+/// Attempts to lock a lock file that keeps being replaced under us before
+/// giving up.
+const MAX_LOCK_ATTEMPTS: usize = 3;
+
+/// A held advisory lock on one rollout. The lock is released on drop.
+#[derive(Debug)]
+pub(crate) struct RolloutLock {
+    file: File,
+    path: PathBuf,
+    exclusive: bool,
+}
+
+impl RolloutLock {
+    /// Takes the exclusive writer lock for `rollout_path`, failing with
+    /// [`io::ErrorKind::ResourceBusy`] when another process holds a lock on
+    /// the same rollout.
+    pub(crate) fn acquire_for_writing(rollout_path: &Path) -> io::Result<Self> {
+        let lock_path = lock_path_for(rollout_path);
+        match Self::try_acquire_at(lock_path.clone(), /*exclusive*/ true)? {
+            Some(mut lock) => {
+                lock.record_pid()?;
+                Ok(lock)
+            }
+            None => Err(in_use_error(&lock_path, rollout_path)),
+        }
+    }
+
+    /// Tries to lock `rollout_path` without blocking, returning `None` when a
+    /// conflicting lock is held. Passes that rewrite or replace the rollout
+    /// need `exclusive`; a shared lock only suits readers, which may run
+    /// alongside each other but never alongside a writer or a rewrite.
+    pub(crate) fn try_acquire(rollout_path: &Path, exclusive: bool) -> io::Result<Option<Self>> {
+        Self::try_acquire_at(lock_path_for(rollout_path), exclusive)
+    }
+
+    fn try_acquire_at(lock_path: PathBuf, exclusive: bool) -> io::Result<Option<Self>> {
+        if let Some(parent) = lock_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        for _ in 0..MAX_LOCK_ATTEMPTS {
+            let file = File::options()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&lock_path)?;
+            let locked = if exclusive {
+                file.try_lock()
+            } else {
+                file.try_lock_shared()
+            };
+            match locked {
+                Ok(()) => {}
+                Err(TryLockError::WouldBlock) => return Ok(None),
+                Err(TryLockError::Error(err)) => return Err(err),
+            }
+            // A previous holder removes the lock file when it releases it. If
+            // that happened between our open and lock, we hold a lock on an
+            // unlinked file and must retry with a fresh one.
+            if is_linked_at(&file, &lock_path)? {
+                return Ok(Some(Self {
+                    file,
+                    path: lock_path,
+                    exclusive,
+                }));
+            }
+        }
+        Err(io::Error::other(format!(
+            "lock file keeps changing: {}",
+            lock_path.display()
+        )))
+    }
+
+    fn record_pid(&mut self) -> io::Result<()> {
+        self.file.set_len(0)?;
+        write!(self.file, "{}", std::process::id())?;
+        self.file.flush()
+    }
+}
+
+impl Drop for RolloutLock {
+    fn drop(&mut self) {
+        // Only a holder that can take the lock exclusively may remove the
+        // file; otherwise another shared holder still relies on it.
+        if self.exclusive || self.file.try_lock().is_ok() {
+            let _ = self.file.set_len(0);
+            let _ = std::fs::remove_file(&self.path);
+        }
+        let _ = self.file.unlock();
+    }
+}
+
+/// Returns the lock file path for a rollout in any of its on-disk forms.
+pub(crate) fn lock_path_for(rollout_path: &Path) -> PathBuf {
+    let file_name = rollout_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(compression::parse_rollout_file_name)
+        .and_then(|name| name.strip_suffix(JSONL_SUFFIX));
+    match file_name {
+        Some(stem) => rollout_path.with_file_name(format!("{stem}{LOCK_SUFFIX}")),
+        None => {
+            let mut path = rollout_path.as_os_str().to_os_string();
+            path.push(LOCK_SUFFIX);
+            PathBuf::from(path)
+        }
+    }
+}
+
+/// Builds the error returned when a session is locked by another process.
+fn in_use_error(lock_path: &Path, rollout_path: &Path) -> io::Error {
+    let holder = match holder_pid(lock_path) {
+        Some(pid) => format!("PID {pid}"),
+        None => "another Codex process".to_string(),
+    };
+    io::Error::new(
+        io::ErrorKind::ResourceBusy,
+        format!("session is in use by {holder} ({})", rollout_path.display()),
+    )
+}
+
+fn holder_pid(lock_path: &Path) -> Option<u32> {
+    let mut contents = String::new();
+    File::open(lock_path)
+        .and_then(|mut file| file.read_to_string(&mut contents))
+        .ok()?;
+    contents.trim().parse().ok()
+}
+
+#[cfg(unix)]
+fn is_linked_at(file: &File, path: &Path) -> io::Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+
+    let held = file.metadata()?;
+    match std::fs::metadata(path) {
+        Ok(current) => Ok(held.dev() == current.dev() && held.ino() == current.ino()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// On Windows a removed file lingers until its last handle closes and cannot
+/// be opened meanwhile, so the file we locked is the one at `path`.
+#[cfg(not(unix))]
+fn is_linked_at(_file: &File, _path: &Path) -> io::Result<bool> {
+    Ok(true)
+}
+
+#[cfg(test)]
+#[path = "lock_tests.rs"]
+mod tests;
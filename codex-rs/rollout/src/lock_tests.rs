@@ -0,0 +1,124 @@
+use std::fs;
+use std::sync::mpsc;
+use std::thread;
+
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::*;
+use crate::encryption;
+
+const ROLLOUT_NAME: &str = "rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-000000000001";
+
+fn rollout_path(dir: &TempDir) -> PathBuf {
+    dir.path().join(format!("{ROLLOUT_NAME}.jsonl"))
+}
+
+#[test]
+fn every_form_of_a_rollout_shares_one_lock_file() {
+    let dir = TempDir::new().expect("tempdir");
+    let expected = dir.path().join(format!("{ROLLOUT_NAME}.lock"));
+
+    let lock_paths = ["jsonl", "jsonl.zst", "jsonl.gz", "jsonl.enc"]
+        .into_iter()
+        .map(|extension| lock_path_for(&dir.path().join(format!("{ROLLOUT_NAME}.{extension}"))))
+        .collect::<Vec<_>>();
+
+    assert_eq!(lock_paths, vec![expected; 4]);
+}
+
+#[test]
+fn a_second_writer_is_rejected_with_the_holder_pid() -> io::Result<()> {
+    let dir = TempDir::new()?;
+    let path = rollout_path(&dir);
+    let (locked_tx, locked_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let holder = {
+        let path = path.clone();
+        thread::spawn(move || -> io::Result<()> {
+            let _lock = RolloutLock::acquire_for_writing(path.as_path())?;
+            let _ = locked_tx.send(());
+            let _ = release_rx.recv();
+            Ok(())
+        })
+    };
+    locked_rx.recv().map_err(io::Error::other)?;
+
+    let err = RolloutLock::acquire_for_writing(path.as_path())
+        .expect_err("the rollout is locked by another writer");
+
+    assert_eq!(err.kind(), io::ErrorKind::ResourceBusy);
+    #[cfg(unix)]
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "session is in use by PID {} ({})",
+            std::process::id(),
+            path.display()
+        )
+    );
+    release_tx.send(()).map_err(io::Error::other)?;
+    holder
+        .join()
+        .map_err(|_| io::Error::other("lock holder panicked"))??;
+    assert!(!lock_path_for(path.as_path()).exists());
+    let _lock = RolloutLock::acquire_for_writing(path.as_path())?;
+    Ok(())
+}
+
+#[test]
+fn shared_locks_coexist_but_keep_writers_out() -> io::Result<()> {
+    let dir = TempDir::new()?;
+    let path = rollout_path(&dir);
+
+    let first = RolloutLock::try_acquire(path.as_path(), /*exclusive*/ false)?;
+    let second = RolloutLock::try_acquire(path.as_path(), /*exclusive*/ false)?;
+    assert!(first.is_some());
+    assert!(second.is_some());
+    let err =
+        RolloutLock::acquire_for_writing(path.as_path()).expect_err("readers hold the rollout");
+    assert_eq!(err.kind(), io::ErrorKind::ResourceBusy);
+
+    drop(first);
+    assert!(lock_path_for(path.as_path()).exists());
+    drop(second);
+    assert!(!lock_path_for(path.as_path()).exists());
+    Ok(())
+}
+
+#[test]
+fn maintenance_skips_a_rollout_held_by_a_writer() -> io::Result<()> {
+    let dir = TempDir::new()?;
+    let path = rollout_path(&dir);
+    fs::write(&path, "{}\n")?;
+    let _writer = RolloutLock::acquire_for_writing(path.as_path())?;
+
+    let shared = RolloutLock::try_acquire(path.as_path(), /*exclusive*/ false)?;
+    let exclusive = RolloutLock::try_acquire(
+        encryption::encrypted_rollout_path(path.as_path()).as_path(),
+        /*exclusive*/ true,
+    )?;
+
+    assert!(shared.is_none());
+    assert!(exclusive.is_none());
+    Ok(())
+}
+
+#[test]
+fn rewriting_passes_exclude_each_other_and_readers() -> io::Result<()> {
+    let dir = TempDir::new()?;
+    let path = rollout_path(&dir);
+    fs::write(&path, "{}\n")?;
+    let rewrite = RolloutLock::try_acquire(path.as_path(), /*exclusive*/ true)?;
+    assert!(rewrite.is_some());
+
+    let second_rewrite = RolloutLock::try_acquire(
+        encryption::encrypted_rollout_path(path.as_path()).as_path(),
+        /*exclusive*/ true,
+    )?;
+    let reader = RolloutLock::try_acquire(path.as_path(), /*exclusive*/ false)?;
+
+    assert!(second_rewrite.is_none());
+    assert!(reader.is_none());
+    Ok(())
+}
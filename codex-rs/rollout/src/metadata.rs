@@ -74,6 +74,7 @@ pub fn builder_from_items(
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::Compacted(_)
         | RolloutItem::TurnContext(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_)
         | RolloutItem::EventMsg(_) => None,
     }) && let Some(builder) = builder_from_session_meta(session_meta, rollout_path)
@@ -130,6 +131,7 @@ pub async fn extract_metadata_from_rollout(
             | RolloutItem::InterAgentCommunicationMetadata { .. }
             | RolloutItem::Compacted(_)
             | RolloutItem::TurnContext(_)
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_)
             | RolloutItem::EventMsg(_) => None,
         }),
@@ -137,18 +139,22 @@ pub async fn extract_metadata_from_rollout(
     })
 }
 
+/// Runs the rollout metadata backfill unless it is already complete.
+///
+/// Returns `None` when there was nothing to do or another worker holds the
+/// backfill lease.
 pub(crate) async fn backfill_sessions(
     runtime: &codex_state::StateRuntime,
     codex_home: &Path,
     default_provider: &str,
-) {
+) -> Option<BackfillStats> {
     backfill_sessions_with_lease(
         runtime,
         codex_home,
         default_provider,
         BACKFILL_LEASE_SECONDS,
     )
-    .await;
+    .await
 }
 
 pub(crate) async fn backfill_sessions_with_lease(
@@ -156,7 +162,7 @@ pub(crate) async fn backfill_sessions_with_lease(
     codex_home: &Path,
     default_provider: &str,
     backfill_lease_seconds: i64,
-) {
+) -> Option<BackfillStats> {
     let metric_client = codex_otel::global();
     let timer = metric_client
         .as_ref()
@@ -172,7 +178,7 @@ pub(crate) async fn backfill_sessions_with_lease(
         }
     };
     if backfill_state.status == BackfillStatus::Complete {
-        return;
+        return None;
     }
     let claimed = match runtime.try_claim_backfill(backfill_lease_seconds).await {
         Ok(claimed) => claimed,
@@ -181,7 +187,7 @@ pub(crate) async fn backfill_sessions_with_lease(
                 "failed to claim backfill worker at {}: {err}",
                 codex_home.display()
             );
-            return;
+            return None;
         }
     };
     if !claimed {
@@ -189,7 +195,7 @@ pub(crate) async fn backfill_sessions_with_lease(
             "state db backfill already running at {}; skipping duplicate worker",
             codex_home.display()
         );
-        return;
+        return None;
     }
     let mut backfill_state = match runtime.get_backfill_state().await {
         Ok(state) => state,
@@ -360,6 +366,7 @@ pub(crate) async fn backfill_sessions_with_lease(
         };
         let _ = timer.record(&[("status", status)]);
     }
+    Some(stats)
 }
 
 #[derive(Debug, Clone)]
@@ -152,6 +152,7 @@ impl ModelContextScan {
             RolloutItem::EventMsg(_)
             | RolloutItem::SessionMeta(_)
             | RolloutItem::InterAgentCommunicationMetadata { .. }
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_) => {}
         }
 
@@ -1,7 +1,7 @@
-use std::fs::File;
 use std::io;
 use std::io::BufRead;
 use std::io::BufReader;
+use std::io::Read;
 use std::io::Seek;
 use std::io::SeekFrom;
 use std::path::Path;
@@ -47,8 +47,8 @@ impl RolloutOrdinalState {
     }
 }
 
-pub(crate) fn ordinal_state_for_rollout(
-    file: &mut File,
+pub(crate) fn ordinal_state_for_rollout<R: Read + Seek>(
+    file: &mut R,
     path: &Path,
 ) -> io::Result<RolloutOrdinalState> {
     let Some((history_mode, subagent_history_start_ordinal)) = read_history_metadata(file, path)?
@@ -94,8 +94,8 @@ pub(crate) fn ordinal_state_for_rollout(
     })
 }
 
-fn read_history_metadata(
-    file: &mut File,
+fn read_history_metadata<R: Read + Seek>(
+    file: &mut R,
     path: &Path,
 ) -> io::Result<Option<(ThreadHistoryMode, Option<u64>)>> {
     file.seek(SeekFrom::Start(0))?;
@@ -0,0 +1,359 @@
+//! Retention for local session rollouts.
+//!
+//! Pruning deletes whole rollouts under `sessions`, oldest first. Archived
+//! sessions are left alone because archiving is an explicit request to keep
+//! them. Rollouts that may still be open by a running session are never
+//! removed, rollouts locked by another Codex process are skipped, and files
+//! that cannot be deleted are reported instead of stopping the pass. A removed
+//! rollout's session index and thread history rows are deleted with it.
+
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use codex_protocol::ThreadId;
+use tracing::info;
+use tracing::warn;
+
+use crate::SESSIONS_SUBDIR;
+use crate::compression;
+use crate::compression::RolloutCompression;
+use crate::compression::RolloutFile;
+use crate::config::RolloutConfig;
+use crate::config::RolloutConfigView;
+use crate::encryption;
+use crate::list::parse_timestamp_uuid_from_filename;
+use crate::lock::RolloutLock;
+use crate::session_index::remove_thread_name_entries;
+use crate::state_db;
+use crate::state_db::StateDbHandle;
+
+/// Rollouts modified this recently may still be open by a running session,
+/// so they are never pruned. Matches the minimum age for manual compression.
+pub const ACTIVE_ROLLOUT_GRACE: Duration = Duration::from_secs(24 * 60 * 60);
+
+static SESSION_START_PRUNE_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Which rollouts to remove. An empty policy removes nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutPrunePolicy {
+    /// Remove rollouts last modified longer ago than this.
+    pub older_than: Option<Duration>,
+    /// Keep the newest `n` rollouts. On its own this removes everything else;
+    /// combined with another limit it only shields the newest `n` from it.
+    pub keep_last: Option<usize>,
+    /// Remove the oldest rollouts until the rest fit in this many bytes.
+    pub max_total_bytes: Option<u64>,
+}
+
+impl RolloutPrunePolicy {
+    pub fn is_empty(&self) -> bool {
+        self.older_than.is_none() && self.keep_last.is_none() && self.max_total_bytes.is_none()
+    }
+}
+
+/// A rollout selected for removal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrunedRollout {
+    /// Physical path of the rollout, which may be compressed.
+    pub path: PathBuf,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+/// Outcome of [`prune_rollouts`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RolloutPruneReport {
+    /// Number of rollouts found under `sessions`.
+    pub scanned: usize,
+    /// Rollouts that were deleted, or would be deleted in a dry run, oldest
+    /// first.
+    pub removed: Vec<PrunedRollout>,
+    /// Rollouts that matched the policy but could not be deleted.
+    pub failed: Vec<(PrunedRollout, String)>,
+    /// Rollouts that matched the policy but were kept because another Codex
+    /// process holds their lock. Always empty in a dry run.
+    pub in_use: Vec<PrunedRollout>,
+}
+
+/// Applies `policy` to the rollouts under `codex_home/sessions`.
+///
+/// Rollouts modified within [`ACTIVE_ROLLOUT_GRACE`] are kept but still count
+/// toward `max_total_bytes`. With `dry_run` set nothing is deleted and
+/// `removed` lists what would have been.
+pub async fn prune_rollouts(
+    config: &impl RolloutConfigView,
+    policy: &RolloutPrunePolicy,
+    dry_run: bool,
+) -> io::Result<RolloutPruneReport> {
+    let codex_home = config.codex_home();
+    let mut rollouts = collect_rollouts(codex_home.join(SESSIONS_SUBDIR).as_path()).await?;
+    let scanned = rollouts.len();
+    if policy.is_empty() {
+        return Ok(RolloutPruneReport {
+            scanned,
+            ..Default::default()
+        });
+    }
+    rollouts.sort_by(|left, right| {
+        right
+            .modified
+            .cmp(&left.modified)
+            .then_with(|| right.path.cmp(&left.path))
+    });
+
+    let now = SystemTime::now();
+    let active_since = now.checked_sub(ACTIVE_ROLLOUT_GRACE);
+    let age_cutoff = policy.older_than.and_then(|age| now.checked_sub(age));
+
+    let mut kept = Vec::new();
+    let mut selected = Vec::new();
+    for (index, rollout) in rollouts.into_iter().enumerate() {
+        let pinned = policy.keep_last.is_some_and(|keep_last| index < keep_last)
+            || active_since.is_none_or(|active_since| rollout.modified >= active_since);
+        let expired = match age_cutoff {
+            Some(cutoff) => rollout.modified < cutoff,
+            None => policy.older_than.is_none() && policy.max_total_bytes.is_none(),
+        };
+        if !pinned && expired {
+            selected.push(rollout);
+        } else {
+            kept.push((rollout, pinned));
+        }
+    }
+
+    if let Some(max_total_bytes) = policy.max_total_bytes {
+        let mut total = kept.iter().map(|(rollout, _)| rollout.size).sum::<u64>();
+        let mut index = kept.len();
+        while total > max_total_bytes && index > 0 {
+            index -= 1;
+            if kept[index].1 {
+                continue;
+            }
+            let (rollout, _) = kept.remove(index);
+            total -= rollout.size;
+            selected.push(rollout);
+        }
+    }
+    selected.sort_by(|left, right| {
+        left.modified
+            .cmp(&right.modified)
+            .then_with(|| left.path.cmp(&right.path))
+    });
+
+    if dry_run {
+        return Ok(RolloutPruneReport {
+            scanned,
+            removed: selected,
+            failed: Vec::new(),
+            in_use: Vec::new(),
+        });
+    }
+    let mut report = RolloutPruneReport {
+        scanned,
+        ..Default::default()
+    };
+    let state_db = if selected.is_empty() {
+        None
+    } else {
+        state_db::get_state_db(config).await
+    };
+    for rollout in selected {
+        // Hold the lock while deleting so no session can resume the rollout
+        // halfway through. Dropping it removes the lock file as well.
+        let _lock = match RolloutLock::try_acquire(rollout.path.as_path(), /*exclusive*/ true) {
+            Ok(Some(lock)) => lock,
+            Ok(None) => {
+                info!(
+                    "skipping rollout {}: in use by another Codex process",
+                    rollout.path.display()
+                );
+                report.in_use.push(rollout);
+                continue;
+            }
+            Err(err) => {
+                report.failed.push((rollout, err.to_string()));
+                continue;
+            }
+        };
+        match remove_rollout(codex_home, rollout.path.as_path(), /*wipe*/ false).await {
+            Ok(()) => {
+                remove_thread_state(state_db.as_ref(), config.sqlite_home(), &rollout.path).await;
+                report.removed.push(rollout);
+            }
+            Err(err) => report.failed.push((rollout, err.to_string())),
+        }
+    }
+    Ok(report)
+}
+
+/// Prunes rollouts in the background once per process, for retention limits
+/// configured to run at session start.
+///
+/// Failures are logged and never block startup.
+pub fn spawn_session_start_prune(config: RolloutConfig, policy: RolloutPrunePolicy) {
+    if policy.is_empty() || SESSION_START_PRUNE_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let codex_home = config.codex_home.clone();
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+        warn!(
+            "failed to start rollout pruning for {}: no Tokio runtime",
+            codex_home.display()
+        );
+        return;
+    };
+    handle.spawn(async move {
+        match prune_rollouts(&config, &policy, /*dry_run*/ false).await {
+            Ok(report) => {
+                info!(
+                    "rollout pruning finished: scanned={}, removed={}, failed={}, in_use={}",
+                    report.scanned,
+                    report.removed.len(),
+                    report.failed.len(),
+                    report.in_use.len()
+                );
+                for (rollout, err) in report.failed {
+                    warn!("failed to prune rollout {}: {err}", rollout.path.display());
+                }
+            }
+            Err(err) => warn!("rollout pruning failed for {}: {err}", codex_home.display()),
+        }
+    });
+}
+
+async fn collect_rollouts(root: &Path) -> io::Result<Vec<PrunedRollout>> {
+    let mut rollouts = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(entry.path());
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+            let Some(rollout_file) = RolloutFile::from_path(entry.path()) else {
+                continue;
+            };
+            let metadata = match entry.metadata().await {
+                Ok(metadata) => metadata,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+            rollouts.push(PrunedRollout {
+                path: rollout_file.into_path(),
+                size: metadata.len(),
+                modified: metadata.modified()?,
+            });
+        }
+    }
+    Ok(rollouts)
+}
+
+/// Deletes one session's rollout, active or archived, along with its thread
+/// name entries.
+///
+/// Fails with [`io::ErrorKind::ResourceBusy`] when a running session holds the
+/// rollout's lock. With `wipe`, every representation is overwritten with zeros
+/// before it is unlinked.
+pub async fn delete_rollout(codex_home: &Path, path: &Path, wipe: bool) -> io::Result<()> {
+    let _lock = RolloutLock::acquire_for_writing(path)?;
+    remove_rollout(codex_home, path, wipe).await
+}
+
+/// Deletes every representation of a rollout, then its thread name entries.
+/// A file that is already gone counts as deleted.
+async fn remove_rollout(codex_home: &Path, path: &Path, wipe: bool) -> io::Result<()> {
+    let plain_path = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(compression::parse_rollout_file_name)
+        .map(|name| path.with_file_name(name))
+        .unwrap_or_else(|| compression::plain_rollout_path(path));
+    let representations = [
+        compression::compressed_rollout_path_with(&plain_path, RolloutCompression::Zstd),
+        compression::compressed_rollout_path_with(&plain_path, RolloutCompression::Gzip),
+        encryption::encrypted_rollout_path(&plain_path),
+        plain_path.clone(),
+    ];
+    for representation in representations {
+        if wipe {
+            let target = representation.clone();
+            match tokio::task::spawn_blocking(move || overwrite_with_zeros(target.as_path()))
+                .await
+                .map_err(io::Error::other)?
+            {
+                Ok(()) => {}
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            }
+        }
+        match tokio::fs::remove_file(representation.as_path()).await {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    if let Some(thread_id) = rollout_thread_id(&plain_path)
+        && let Err(err) = remove_thread_name_entries(codex_home, thread_id).await
+    {
+        warn!("failed to remove thread name entries for deleted thread {thread_id}: {err}");
+    }
+    Ok(())
+}
+
+/// Deletes a pruned rollout's session index row and thread history, as
+/// `codex sessions delete` does. Failures are logged, since the rollout
+/// itself is already gone.
+async fn remove_thread_state(state_db: Option<&StateDbHandle>, sqlite_home: &Path, path: &Path) {
+    let Some(thread_id) = rollout_thread_id(path) else {
+        return;
+    };
+    if let Some(state_db) = state_db
+        && let Err(err) = state_db.delete_thread(thread_id).await
+    {
+        warn!("failed to remove pruned thread {thread_id} from the session index: {err}");
+    }
+    if let Err(err) = codex_state::delete_thread_history(sqlite_home, thread_id).await {
+        warn!("failed to remove thread history for pruned thread {thread_id}: {err}");
+    }
+}
+
+fn rollout_thread_id(path: &Path) -> Option<ThreadId> {
+    let (_, uuid) = parse_timestamp_uuid_from_filename(path.file_name()?.to_str()?)?;
+    ThreadId::from_string(&uuid.to_string()).ok()
+}
+
+/// Overwrites the file in place so its content does not survive the unlink on
+/// filesystems that write in place. Copy-on-write filesystems and SSD wear
+/// leveling can still keep old blocks around.
+fn overwrite_with_zeros(path: &Path) -> io::Result<()> {
+    const CHUNK: [u8; 64 * 1024] = [0; 64 * 1024];
+
+    let mut file = std::fs::OpenOptions::new().write(true).open(path)?;
+    let mut remaining = file.metadata()?.len();
+    while remaining > 0 {
+        let len = remaining.min(CHUNK.len() as u64) as usize;
+        file.write_all(&CHUNK[..len])?;
+        remaining -= len as u64;
+    }
+    file.sync_all()
+}
+
+#[cfg(test)]
+#[path = "prune_tests.rs"]
+mod tests;
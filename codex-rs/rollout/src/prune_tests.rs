@@ -0,0 +1,293 @@
+use std::fs;
+use std::fs::FileTimes;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::protocol::SessionSource;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+use super::*;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Writes a `size`-byte rollout for thread `index` last modified `age` ago.
+fn write_rollout(codex_home: &Path, index: u64, size: usize, age: Duration) -> PrunedRollout {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir).expect("create sessions dir");
+    let path = dir.join(format!(
+        "rollout-2025-07-01T00-00-00-019a0000-0000-7000-8000-{index:012}.jsonl"
+    ));
+    fs::write(&path, "x".repeat(size)).expect("write rollout");
+    let modified = SystemTime::now() - age;
+    fs::File::options()
+        .write(true)
+        .open(&path)
+        .and_then(|file| file.set_times(FileTimes::new().set_modified(modified)))
+        .expect("set rollout mtime");
+    let modified = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .expect("rollout mtime");
+    PrunedRollout {
+        path,
+        size: size as u64,
+        modified,
+    }
+}
+
+fn test_config(codex_home: &Path) -> RolloutConfig {
+    RolloutConfig {
+        codex_home: codex_home.to_path_buf(),
+        sqlite_home: codex_home.to_path_buf(),
+        cwd: codex_home.to_path_buf(),
+        model_provider_id: "test-provider".to_string(),
+        generate_memories: false,
+    }
+}
+
+fn existing(rollouts: &[&PrunedRollout]) -> Vec<PathBuf> {
+    rollouts
+        .iter()
+        .map(|rollout| rollout.path.clone())
+        .filter(|path| path.exists())
+        .collect()
+}
+
+#[tokio::test]
+async fn dry_run_lists_expired_rollouts_past_keep_last_without_deleting() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let active = write_rollout(codex_home.path(), 1, 10, Duration::from_secs(60));
+    let recent = write_rollout(codex_home.path(), 2, 10, 10 * DAY);
+    let old = write_rollout(codex_home.path(), 3, 10, 40 * DAY);
+    let oldest = write_rollout(codex_home.path(), 4, 10, 50 * DAY);
+
+    let report = prune_rollouts(
+        &test_config(codex_home.path()),
+        &RolloutPrunePolicy {
+            older_than: Some(30 * DAY),
+            keep_last: Some(3),
+            max_total_bytes: None,
+        },
+        /*dry_run*/ true,
+    )
+    .await
+    .expect("prune");
+
+    assert_eq!(
+        report,
+        RolloutPruneReport {
+            scanned: 4,
+            removed: vec![oldest.clone()],
+            failed: Vec::new(),
+            in_use: Vec::new(),
+        }
+    );
+    assert_eq!(
+        existing(&[&active, &recent, &old, &oldest]),
+        vec![
+            active.path.clone(),
+            recent.path.clone(),
+            old.path.clone(),
+            oldest.path.clone(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn max_total_bytes_evicts_oldest_first_and_spares_active_rollouts() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let active = write_rollout(codex_home.path(), 1, 400, Duration::from_secs(60));
+    let recent = write_rollout(codex_home.path(), 2, 100, 2 * DAY);
+    let old = write_rollout(codex_home.path(), 3, 100, 3 * DAY);
+    let oldest = write_rollout(codex_home.path(), 4, 100, 4 * DAY);
+    let hidden_sibling =
+        compression::compressed_rollout_path_with(oldest.path.as_path(), RolloutCompression::Gzip);
+    fs::write(&hidden_sibling, b"stale copy").expect("write hidden sibling");
+
+    let report = prune_rollouts(
+        &test_config(codex_home.path()),
+        &RolloutPrunePolicy {
+            max_total_bytes: Some(500),
+            ..Default::default()
+        },
+        /*dry_run*/ false,
+    )
+    .await
+    .expect("prune");
+
+    assert_eq!(
+        report,
+        RolloutPruneReport {
+            scanned: 4,
+            removed: vec![oldest.clone(), old.clone()],
+            failed: Vec::new(),
+            in_use: Vec::new(),
+        }
+    );
+    assert_eq!(
+        existing(&[&active, &recent, &old, &oldest]),
+        vec![active.path.clone(), recent.path.clone()]
+    );
+    assert!(!hidden_sibling.exists());
+}
+
+#[tokio::test]
+async fn keep_last_alone_removes_everything_but_the_newest() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let newest = write_rollout(codex_home.path(), 1, 10, 2 * DAY);
+    let older = write_rollout(codex_home.path(), 2, 10, 3 * DAY);
+    let oldest = write_rollout(codex_home.path(), 3, 10, 4 * DAY);
+
+    let report = prune_rollouts(
+        &test_config(codex_home.path()),
+        &RolloutPrunePolicy {
+            keep_last: Some(1),
+            ..Default::default()
+        },
+        /*dry_run*/ false,
+    )
+    .await
+    .expect("prune");
+
+    assert_eq!(
+        report,
+        RolloutPruneReport {
+            scanned: 3,
+            removed: vec![oldest.clone(), older.clone()],
+            failed: Vec::new(),
+            in_use: Vec::new(),
+        }
+    );
+    assert_eq!(
+        existing(&[&newest, &older, &oldest]),
+        vec![newest.path.clone()]
+    );
+}
+
+#[tokio::test]
+async fn pruning_removes_the_session_index_row() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let old = write_rollout(codex_home.path(), 1, 10, 3 * DAY);
+    let thread_id = ThreadId::from_string("019a0000-0000-7000-8000-000000000001")?;
+    let runtime = codex_state::StateRuntime::init(
+        codex_home.path().to_path_buf(),
+        "test-provider".to_string(),
+    )
+    .await?;
+    runtime
+        .mark_backfill_complete(/*last_watermark*/ None)
+        .await?;
+    let metadata = codex_state::ThreadMetadataBuilder::new(
+        thread_id,
+        old.path.clone(),
+        chrono::Utc::now(),
+        SessionSource::Cli,
+    )
+    .build("test-provider");
+    runtime.upsert_thread(&metadata).await?;
+
+    let report = prune_rollouts(
+        &test_config(codex_home.path()),
+        &RolloutPrunePolicy {
+            older_than: Some(DAY),
+            ..Default::default()
+        },
+        /*dry_run*/ false,
+    )
+    .await?;
+
+    assert_eq!(report.removed, vec![old]);
+    assert_eq!(runtime.get_thread(thread_id).await?, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rollouts_locked_by_a_writer_are_skipped() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let locked = write_rollout(codex_home.path(), 1, 10, 2 * DAY);
+    let unlocked = write_rollout(codex_home.path(), 2, 10, 3 * DAY);
+    let writer_lock =
+        RolloutLock::acquire_for_writing(locked.path.as_path()).expect("lock rollout");
+
+    let report = prune_rollouts(
+        &test_config(codex_home.path()),
+        &RolloutPrunePolicy {
+            older_than: Some(DAY),
+            ..Default::default()
+        },
+        /*dry_run*/ false,
+    )
+    .await
+    .expect("prune");
+
+    assert_eq!(
+        report,
+        RolloutPruneReport {
+            scanned: 2,
+            removed: vec![unlocked.clone()],
+            failed: Vec::new(),
+            in_use: vec![locked.clone()],
+        }
+    );
+    assert_eq!(existing(&[&locked, &unlocked]), vec![locked.path.clone()]);
+    assert!(!crate::lock::lock_path_for(unlocked.path.as_path()).exists());
+    drop(writer_lock);
+    assert!(!crate::lock::lock_path_for(locked.path.as_path()).exists());
+}
+
+#[tokio::test]
+async fn delete_rollout_refuses_a_session_locked_by_a_writer() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let locked = write_rollout(codex_home.path(), 1, 10, 2 * DAY);
+    let writer_lock =
+        RolloutLock::acquire_for_writing(locked.path.as_path()).expect("lock rollout");
+
+    let err = delete_rollout(
+        codex_home.path(),
+        locked.path.as_path(),
+        /*wipe*/ false,
+    )
+    .await
+    .expect_err("locked rollout");
+
+    assert_eq!(err.kind(), io::ErrorKind::ResourceBusy);
+    assert_eq!(existing(&[&locked]), vec![locked.path.clone()]);
+    drop(writer_lock);
+}
+
+#[tokio::test]
+async fn delete_rollout_removes_every_representation_and_its_name() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let rollout = write_rollout(codex_home.path(), 1, 10, 2 * DAY);
+    let compressed =
+        compression::compressed_rollout_path_with(&rollout.path, RolloutCompression::Gzip);
+    fs::write(&compressed, "stale copy").expect("write compressed sibling");
+    let thread_id =
+        ThreadId::from_string("019a0000-0000-7000-8000-000000000001").expect("thread id");
+    crate::session_index::append_thread_name(codex_home.path(), thread_id, "secret")
+        .await
+        .expect("name thread");
+
+    delete_rollout(
+        codex_home.path(),
+        rollout.path.as_path(),
+        /*wipe*/ true,
+    )
+    .await
+    .expect("delete rollout");
+
+    assert_eq!(
+        [rollout.path.as_path(), compressed.as_path()]
+            .iter()
+            .filter(|path| path.exists())
+            .count(),
+        0
+    );
+    assert_eq!(
+        crate::session_index::find_thread_name_by_id(codex_home.path(), &thread_id)
+            .await
+            .expect("read names"),
+        None
+    );
+    assert!(!crate::lock::lock_path_for(rollout.path.as_path()).exists());
+}
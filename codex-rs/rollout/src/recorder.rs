@@ -38,6 +38,7 @@ use super::ARCHIVED_SESSIONS_SUBDIR;
 use super::SESSIONS_SUBDIR;
 use super::compression;
 use super::list::Cursor;
+use super::list::ProviderMatcher;
 use super::list::SortDirection;
 use super::list::ThreadItem;
 use super::list::ThreadListConfig;
@@ -48,11 +49,22 @@ use super::list::get_threads;
 use super::list::get_threads_in_root;
 use super::list::parse_cursor;
 use super::list::parse_timestamp_uuid_from_filename;
+use super::list_usage::TitleSearch;
+use super::list_usage::UsageSortKey;
+use super::list_usage::traverse_paths_by_usage;
 use super::metadata;
 use super::ordinal::RolloutOrdinalState;
 use super::ordinal::ordinal_state_for_rollout;
 use super::session_index::find_thread_names_by_ids;
+use crate::compact;
+use crate::compact::RolloutCompaction;
 use crate::config::RolloutConfigView;
+use crate::encryption;
+use crate::encryption::RolloutKey;
+use crate::history_sink::HistorySink;
+use crate::integrity::truncated_tail_start;
+use crate::lock::RolloutLock;
+use crate::records::RolloutRecordStream;
 use crate::state_db;
 use crate::state_db::StateDbHandle;
 use codex_git_utils::collect_git_info;
@@ -70,6 +82,7 @@ use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::ThreadHistoryMode;
 use codex_protocol::protocol::ThreadSource;
 use codex_state::StateRuntime;
+use codex_state::ThreadTimeBounds;
 use codex_utils_path as path_utils;
 
 /// Writes canonical session rollout items to JSONL.
@@ -105,9 +118,12 @@ pub enum RolloutRecorderParams {
         history_mode: ThreadHistoryMode,
         subagent_history_start_ordinal: Option<u64>,
         initial_window_id: Option<String>,
+        encrypted: bool,
+        history_sink: Option<HistorySink>,
     },
     Resume {
         path: PathBuf,
+        history_sink: Option<HistorySink>,
     },
 }
 
@@ -199,6 +215,8 @@ impl RolloutRecorderParams {
             history_mode: Default::default(),
             subagent_history_start_ordinal: None,
             initial_window_id: None,
+            encrypted: false,
+            history_sink: None,
         }
     }
 
@@ -272,8 +290,36 @@ impl RolloutRecorderParams {
         self
     }
 
+    /// Stores the new rollout as `.jsonl.enc`, encrypted with the history
+    /// encryption key.
+    pub fn with_encryption(mut self, encrypted: bool) -> Self {
+        if let Self::Create {
+            encrypted: encrypt, ..
+        } = &mut self
+        {
+            *encrypt = encrypted;
+        }
+        self
+    }
+
+    /// Also streams every item written to the rollout to `history_sink`.
+    pub fn with_history_sink(mut self, history_sink: Option<HistorySink>) -> Self {
+        match &mut self {
+            Self::Create {
+                history_sink: sink, ..
+            }
+            | Self::Resume {
+                history_sink: sink, ..
+            } => *sink = history_sink,
+        }
+        self
+    }
+
     pub fn resume(path: PathBuf) -> Self {
-        Self::Resume { path }
+        Self::Resume {
+            path,
+            history_sink: None,
+        }
     }
 }
 
@@ -291,6 +337,9 @@ enum ThreadListRepairMode {
 
 impl RolloutRecorder {
     /// List threads (rollout files) under the provided Codex home directory.
+    ///
+    /// `time_bounds` is applied before pagination, so cursors stay valid
+    /// across pages of a bounded listing.
     #[allow(clippy::too_many_arguments)]
     pub async fn list_threads(
         state_db_ctx: Option<StateDbHandle>,
@@ -304,6 +353,7 @@ impl RolloutRecorder {
         cwd_filters: Option<&[PathBuf]>,
         default_provider: &str,
         search_term: Option<&str>,
+        time_bounds: ThreadTimeBounds,
     ) -> std::io::Result<ThreadsPage> {
         Self::list_threads_with_db_fallback(
             state_db_ctx,
@@ -319,6 +369,7 @@ impl RolloutRecorder {
             ThreadListArchiveFilter::Active,
             ThreadListRepairMode::ScanAndRepair,
             search_term,
+            time_bounds,
         )
         .await
     }
@@ -351,6 +402,7 @@ impl RolloutRecorder {
             ThreadListArchiveFilter::Active,
             ThreadListRepairMode::StateDbOnly,
             search_term,
+            ThreadTimeBounds::default(),
         )
         .await
     }
@@ -384,6 +436,7 @@ impl RolloutRecorder {
             ThreadListArchiveFilter::Archived,
             ThreadListRepairMode::ScanAndRepair,
             search_term,
+            ThreadTimeBounds::default(),
         )
         .await
     }
@@ -416,6 +469,7 @@ impl RolloutRecorder {
             ThreadListArchiveFilter::Archived,
             ThreadListRepairMode::StateDbOnly,
             search_term,
+            ThreadTimeBounds::default(),
         )
         .await
     }
@@ -435,6 +489,7 @@ impl RolloutRecorder {
         archive_filter: ThreadListArchiveFilter,
         repair_mode: ThreadListRepairMode,
         search_term: Option<&str>,
+        time_bounds: ThreadTimeBounds,
     ) -> std::io::Result<ThreadsPage> {
         let codex_home = config.codex_home();
         let archived = match archive_filter {
@@ -445,6 +500,38 @@ impl RolloutRecorder {
             return Ok(ThreadsPage::default());
         }
 
+        if let Some(usage_sort_key) = usage_sort_key(sort_key) {
+            // Rollout size and token totals are not stored in SQLite, so these orders always
+            // come from the rollout files, even for state-DB-only listings.
+            let (root, layout) = if archived {
+                (
+                    codex_home.join(ARCHIVED_SESSIONS_SUBDIR),
+                    ThreadListLayout::Flat,
+                )
+            } else {
+                (
+                    codex_home.join(SESSIONS_SUBDIR),
+                    ThreadListLayout::NestedByDate,
+                )
+            };
+            let provider_matcher =
+                model_providers.and_then(|filters| ProviderMatcher::new(filters, default_provider));
+            return traverse_paths_by_usage(
+                root,
+                layout,
+                page_size,
+                cursor.cloned(),
+                usage_sort_key,
+                sort_direction,
+                allowed_sources,
+                provider_matcher.as_ref(),
+                cwd_filters,
+                time_bounds,
+                search_term.map(|term| TitleSearch { codex_home, term }),
+            )
+            .await;
+        }
+
         if matches!(repair_mode, ThreadListRepairMode::StateDbOnly) {
             return Ok(state_db::list_threads_db(
                 state_db_ctx.as_deref(),
@@ -459,6 +546,7 @@ impl RolloutRecorder {
                 /*relation_filter*/ None,
                 archived,
                 search_term,
+                time_bounds,
             )
             .await
             .map(Into::into)
@@ -468,7 +556,8 @@ impl RolloutRecorder {
         let listing_has_metadata_filters = !allowed_sources.is_empty()
             || model_providers.is_some()
             || cwd_filters.is_some()
-            || search_term.is_some();
+            || search_term.is_some()
+            || time_bounds != ThreadTimeBounds::default();
         // Filesystem-first listing intentionally overfetches so we can repair stale/missing
         // SQLite rows before returning the scan page for filtered listings or the DB page for
         // unfiltered listings.
@@ -485,6 +574,7 @@ impl RolloutRecorder {
                     default_provider,
                     archived,
                     search_term,
+                    time_bounds,
                 )
                 .await?
             }
@@ -500,6 +590,7 @@ impl RolloutRecorder {
                     default_provider,
                     archived,
                     search_term,
+                    time_bounds,
                 )
                 .await?
             }
@@ -568,6 +659,7 @@ impl RolloutRecorder {
             /*relation_filter*/ None,
             archived,
             search_term,
+            time_bounds,
         )
         .await;
         if let Some(db_page) = db_page {
@@ -597,6 +689,7 @@ impl RolloutRecorder {
                     /*relation_filter*/ None,
                     archived,
                     search_term,
+                    time_bounds,
                 )
                 .await
                 {
@@ -637,6 +730,7 @@ impl RolloutRecorder {
                         /*relation_filter*/ None,
                         archived,
                         search_term,
+                        time_bounds,
                     )
                     .await
                     {
@@ -715,6 +809,7 @@ impl RolloutRecorder {
                     /*relation_filter*/ None,
                     /*archived*/ false,
                     /*search_term*/ None,
+                    ThreadTimeBounds::default(),
                 )
                 .await
                 else {
@@ -792,9 +887,11 @@ impl RolloutRecorder {
                 history_mode,
                 subagent_history_start_ordinal,
                 initial_window_id,
+                encrypted,
+                history_sink,
             } => {
                 let ordinal_state = RolloutOrdinalState::for_new_rollout(history_mode);
-                let log_file_info = precompute_log_file_info(config, conversation_id)?;
+                let log_file_info = precompute_log_file_info(config, conversation_id, encrypted)?;
                 let path = log_file_info.path.clone();
                 let thread_id = log_file_info.conversation_id;
                 let started_at = log_file_info.timestamp;
@@ -839,6 +936,9 @@ impl RolloutRecorder {
 
                 RolloutWriterState {
                     writer: None,
+                    lock: None,
+                    history_sink: history_sink
+                        .map(|sink| (sink, Arc::from(thread_id.to_string().as_str()))),
                     deferred_log_file_info: Some(log_file_info),
                     pending_items: Vec::new(),
                     meta: Some(session_meta),
@@ -848,10 +948,22 @@ impl RolloutRecorder {
                     last_logged_error: None,
                 }
             }
-            RolloutRecorderParams::Resume { path } => {
-                let (path, file, ordinal_state) = open_rollout_for_append(path.as_path()).await?;
+            RolloutRecorderParams::Resume { path, history_sink } => {
+                let lock = RolloutLock::acquire_for_writing(path.as_path())?;
+                let (path, writer, ordinal_state) = open_rollout_for_append(path.as_path()).await?;
+                let history_sink = history_sink.map(|sink| {
+                    let thread_id = path
+                        .file_name()
+                        .and_then(|name| name.to_str())
+                        .and_then(parse_timestamp_uuid_from_filename)
+                        .map(|(_, uuid)| uuid.to_string())
+                        .unwrap_or_default();
+                    (sink, Arc::from(thread_id.as_str()))
+                });
                 RolloutWriterState {
-                    writer: Some(JsonlWriter { file }),
+                    writer: Some(writer),
+                    lock: Some(lock),
+                    history_sink,
                     deferred_log_file_info: None,
                     pending_items: Vec::new(),
                     meta: None,
@@ -1022,6 +1134,19 @@ impl RolloutRecorder {
         Ok((items, thread_id, parse_errors))
     }
 
+    /// Rewrites a cold plain rollout without superseded `token_count` events,
+    /// keeping only the final cumulative usage and rate limits. Every other
+    /// line is copied unchanged.
+    pub async fn compact(path: &Path) -> std::io::Result<RolloutCompaction> {
+        compact::compact_rollout(path).await
+    }
+
+    /// Opens `path` as a stream of typed records, without loading the whole
+    /// rollout into memory.
+    pub async fn stream_records(path: &Path) -> std::io::Result<RolloutRecordStream> {
+        RolloutRecordStream::open(path).await
+    }
+
     pub async fn get_rollout_history(path: &Path) -> std::io::Result<InitialHistory> {
         let (items, thread_id, _parse_errors) = Self::load_rollout_items(path).await?;
         let conversation_id = thread_id
@@ -1082,7 +1207,7 @@ pub(crate) fn reject_unknown_thread_history_mode(value: &Value) -> std::io::Resu
         .map_err(|err| IoError::other(format!("invalid session metadata history_mode: {err}")))
 }
 
-fn strip_legacy_ghost_snapshot_rollout_line(value: &mut Value) -> bool {
+pub(crate) fn strip_legacy_ghost_snapshot_rollout_line(value: &mut Value) -> bool {
     match value.get("type").and_then(Value::as_str) {
         Some("response_item") => value
             .get("payload")
@@ -1125,6 +1250,7 @@ fn truncate_fs_page(
                 .as_deref()
                 .or(item.updated_at.as_deref())?
                 .to_string(),
+            ThreadSortKey::FileSize | ThreadSortKey::TotalTokens => return None,
         };
         parse_cursor(cursor_token.as_str())
     });
@@ -1255,6 +1381,7 @@ async fn list_threads_from_files_desc(
     default_provider: &str,
     archived: bool,
     search_term: Option<&str>,
+    time_bounds: ThreadTimeBounds,
 ) -> std::io::Result<ThreadsPage> {
     if let Some(search_term) = search_term {
         let mut matching_items = Vec::new();
@@ -1274,6 +1401,7 @@ async fn list_threads_from_files_desc(
                 cwd_filters,
                 default_provider,
                 archived,
+                time_bounds,
             )
             .await?;
             scanned_files = scanned_files.saturating_add(page.num_scanned_files);
@@ -1316,6 +1444,7 @@ async fn list_threads_from_files_desc(
         cwd_filters,
         default_provider,
         archived,
+        time_bounds,
     )
     .await
 }
@@ -1331,36 +1460,34 @@ async fn list_threads_from_files_desc_unfiltered(
     cwd_filters: Option<&[PathBuf]>,
     default_provider: &str,
     archived: bool,
+    time_bounds: ThreadTimeBounds,
 ) -> std::io::Result<ThreadsPage> {
-    if archived {
-        let root = codex_home.join(ARCHIVED_SESSIONS_SUBDIR);
-        get_threads_in_root(
-            root,
-            page_size,
-            cursor,
-            sort_key,
-            ThreadListConfig {
-                allowed_sources,
-                model_providers,
-                cwd_filters,
-                default_provider,
-                layout: ThreadListLayout::Flat,
-            },
+    let (root, layout) = if archived {
+        (
+            codex_home.join(ARCHIVED_SESSIONS_SUBDIR),
+            ThreadListLayout::Flat,
         )
-        .await
     } else {
-        get_threads(
-            codex_home,
-            page_size,
-            cursor,
-            sort_key,
+        (
+            codex_home.join(SESSIONS_SUBDIR),
+            ThreadListLayout::NestedByDate,
+        )
+    };
+    get_threads_in_root(
+        root,
+        page_size,
+        cursor,
+        sort_key,
+        ThreadListConfig {
             allowed_sources,
             model_providers,
             cwd_filters,
             default_provider,
-        )
-        .await
-    }
+            layout,
+            time_bounds,
+        },
+    )
+    .await
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1375,6 +1502,7 @@ async fn list_threads_from_files_asc(
     default_provider: &str,
     archived: bool,
     search_term: Option<&str>,
+    time_bounds: ThreadTimeBounds,
 ) -> std::io::Result<ThreadsPage> {
     let mut all_items = Vec::new();
     let mut scanned_files = 0usize;
@@ -1393,6 +1521,7 @@ async fn list_threads_from_files_asc(
             default_provider,
             archived,
             /*search_term*/ None,
+            time_bounds,
         )
         .await?;
         scanned_files = scanned_files.saturating_add(page.num_scanned_files);
@@ -1496,6 +1625,7 @@ fn thread_item_sort_key(
                 .or(item.created_at.as_deref())?;
             OffsetDateTime::parse(recency_at, &Rfc3339).ok()?
         }
+        ThreadSortKey::FileSize | ThreadSortKey::TotalTokens => return None,
     };
     Some((timestamp, id))
 }
@@ -1507,29 +1637,50 @@ fn cursor_from_thread_item(item: &ThreadItem, sort_key: ThreadSortKey) -> Option
             timestamp,
             ThreadId::from_string(&id.to_string()).ok()?,
         )),
-        ThreadSortKey::CreatedAt | ThreadSortKey::UpdatedAt => Some(Cursor::new(timestamp)),
+        ThreadSortKey::CreatedAt
+        | ThreadSortKey::UpdatedAt
+        | ThreadSortKey::FileSize
+        | ThreadSortKey::TotalTokens => Some(Cursor::new(timestamp)),
     }
 }
 
-struct LogFileInfo {
+fn usage_sort_key(sort_key: ThreadSortKey) -> Option<UsageSortKey> {
+    match sort_key {
+        ThreadSortKey::FileSize => Some(UsageSortKey::FileSize),
+        ThreadSortKey::TotalTokens => Some(UsageSortKey::TotalTokens),
+        ThreadSortKey::CreatedAt | ThreadSortKey::UpdatedAt | ThreadSortKey::RecencyAt => None,
+    }
+}
+
+pub(crate) struct LogFileInfo {
     /// Full path to the rollout file.
-    path: PathBuf,
+    pub(crate) path: PathBuf,
 
     /// Session ID (also embedded in filename).
-    conversation_id: ThreadId,
+    pub(crate) conversation_id: ThreadId,
 
     /// Timestamp for the start of the session.
-    timestamp: OffsetDateTime,
+    pub(crate) timestamp: OffsetDateTime,
 }
 
 fn precompute_log_file_info(
     config: &impl RolloutConfigView,
     conversation_id: ThreadId,
+    encrypted: bool,
+) -> std::io::Result<LogFileInfo> {
+    new_log_file_info(config.codex_home(), conversation_id, encrypted)
+}
+
+/// Picks the dated path under `sessions` for a rollout started now.
+pub(crate) fn new_log_file_info(
+    codex_home: &Path,
+    conversation_id: ThreadId,
+    encrypted: bool,
 ) -> std::io::Result<LogFileInfo> {
     // Resolve ~/.codex/sessions/YYYY/MM/DD path.
     let timestamp = OffsetDateTime::now_local()
         .map_err(|e| IoError::other(format!("failed to get local time: {e}")))?;
-    let mut dir = config.codex_home().to_path_buf();
+    let mut dir = codex_home.to_path_buf();
     dir.push(SESSIONS_SUBDIR);
     dir.push(timestamp.year().to_string());
     dir.push(format!("{:02}", u8::from(timestamp.month())));
@@ -1545,7 +1696,10 @@ fn precompute_log_file_info(
 
     let filename = format!("rollout-{date_str}-{conversation_id}.jsonl");
 
-    let path = dir.join(filename);
+    let mut path = dir.join(filename);
+    if encrypted {
+        path = encryption::encrypted_rollout_path(path.as_path());
+    }
 
     Ok(LogFileInfo {
         path,
@@ -1579,6 +1733,12 @@ fn open_log_file(path: &Path) -> std::io::Result<File> {
 /// unwritten suffix so the next barrier can reopen the file and retry.
 struct RolloutWriterState {
     writer: Option<JsonlWriter>,
+    /// Exclusive lock held from the first write until the writer task ends,
+    /// so other Codex processes neither append to nor rewrite this rollout.
+    lock: Option<RolloutLock>,
+    /// Sink that receives every written item, with the thread id to tag the
+    /// items with.
+    history_sink: Option<(HistorySink, Arc<str>)>,
     deferred_log_file_info: Option<LogFileInfo>,
     pending_items: Vec<RolloutItem>,
     meta: Option<SessionMeta>,
@@ -1676,9 +1836,18 @@ impl RolloutWriterState {
             .as_ref()
             .map(|info| info.path.as_path())
             .unwrap_or(self.rollout_path.as_path());
+        if self.lock.is_none() {
+            self.lock = Some(RolloutLock::acquire_for_writing(path)?);
+        }
+        let key = if encryption::is_encrypted_rollout_path(path) {
+            Some(encryption::rollout_key_for_writing()?)
+        } else {
+            None
+        };
         let file = open_log_file(path)?;
         self.writer = Some(JsonlWriter {
             file: tokio::fs::File::from_std(file),
+            key,
         });
         self.deferred_log_file_info = None;
         Ok(())
@@ -1736,7 +1905,10 @@ impl RolloutWriterState {
         }
 
         if written_count > 0 {
-            self.pending_items.drain(..written_count);
+            let written = self.pending_items.drain(..written_count);
+            if let Some((sink, thread_id)) = self.history_sink.as_ref() {
+                sink.send(thread_id, written.collect());
+            }
         }
 
         write_result
@@ -1762,6 +1934,9 @@ async fn rollout_writer(
             }
             RolloutCmd::Shutdown { ack } => match state.shutdown().await {
                 Ok(()) => {
+                    // Release the lock before acknowledging, so the session can
+                    // be resumed as soon as `shutdown` returns.
+                    state.lock = None;
                     let _ = ack.send(Ok(()));
                     break;
                 }
@@ -1808,34 +1983,60 @@ async fn write_session_meta(
 ///
 /// This is for metadata updates to unloaded threads. Live sessions should use
 /// `RolloutRecorder::record_canonical_items` so rollout writes remain ordered
-/// with the rest of the session stream.
+/// with the rest of the session stream. It does not take the rollout lock,
+/// since the thread may be loaded by this very process.
 pub async fn append_rollout_item_to_path(
     rollout_path: &Path,
     item: &RolloutItem,
 ) -> std::io::Result<()> {
-    let (_rollout_path, file, ordinal_state) = open_rollout_for_append(rollout_path).await?;
+    let (_rollout_path, mut writer, ordinal_state) = open_rollout_for_append(rollout_path).await?;
     let ordinal = ordinal_state.current()?;
-    let mut writer = JsonlWriter { file };
     writer.write_rollout_item(item, ordinal).await
 }
 
 async fn open_rollout_for_append(
     path: &Path,
-) -> std::io::Result<(PathBuf, tokio::fs::File, RolloutOrdinalState)> {
+) -> std::io::Result<(PathBuf, JsonlWriter, RolloutOrdinalState)> {
     let path = compression::materialize_rollout_for_append(path).await?;
+    let key = if encryption::is_encrypted_rollout_path(path.as_path()) {
+        Some(encryption::rollout_key_for(path.as_path()).await?)
+    } else {
+        None
+    };
     let path_for_open = path.clone();
+    let key_for_open = key.clone();
     let (file, ordinal_state) = tokio::task::spawn_blocking(move || {
         let mut file = File::options()
             .read(true)
             .append(true)
             .open(path_for_open.as_path())?;
+        if truncated_tail_start(&mut file)?.is_some() {
+            warn!(
+                "rollout {} ends in a partial line from an interrupted write; readers will skip it",
+                path_for_open.display()
+            );
+        }
         ensure_rollout_is_newline_terminated(&mut file)?;
-        let ordinal_state = ordinal_state_for_rollout(&mut file, path_for_open.as_path())?;
+        let ordinal_state = match key_for_open {
+            Some(key) => {
+                let plaintext =
+                    encryption::decrypt_rollout_file(&mut file, &key, path_for_open.as_path())?;
+                ordinal_state_for_rollout(
+                    &mut std::io::Cursor::new(plaintext),
+                    path_for_open.as_path(),
+                )?
+            }
+            None => ordinal_state_for_rollout(&mut file, path_for_open.as_path())?,
+        };
         Ok::<_, std::io::Error>((file, ordinal_state))
     })
     .await
     .map_err(IoError::other)??;
-    Ok((path, tokio::fs::File::from_std(file), ordinal_state))
+    let writer = JsonlWriter {
+        file: tokio::fs::File::from_std(file),
+        key,
+    };
+    Ok((path, writer, ordinal_state))
 }
 
 fn ensure_rollout_is_newline_terminated(file: &mut File) -> std::io::Result<()> {
@@ -1855,6 +2056,8 @@ fn ensure_rollout_is_newline_terminated(file: &mut File) -> std::io::Result<()>
 
 struct JsonlWriter {
     file: tokio::fs::File,
+    /// Set for `.jsonl.enc` rollouts, whose lines are written encrypted.
+    key: Option<Arc<RolloutKey>>,
 }
 
 #[derive(serde::Serialize)]
@@ -1888,6 +2091,9 @@ impl JsonlWriter {
     }
     async fn write_line(&mut self, item: &impl serde::Serialize) -> std::io::Result<()> {
         let mut json = serde_json::to_string(item)?;
+        if let Some(key) = self.key.as_ref() {
+            json = key.encrypt_line(&json)?;
+        }
         json.push('\n');
         self.file.write_all(json.as_bytes()).await?;
         self.file.flush().await?;
@@ -1992,6 +2198,7 @@ async fn resume_candidate_matches_cwd(
             | RolloutItem::InterAgentCommunication(_)
             | RolloutItem::InterAgentCommunicationMetadata { .. }
             | RolloutItem::Compacted(_)
+            | RolloutItem::TurnSummary(_)
             | RolloutItem::WorldState(_)
             | RolloutItem::EventMsg(_) => None,
         })
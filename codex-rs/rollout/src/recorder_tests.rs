@@ -710,7 +710,10 @@ async fn writer_state_retries_write_error_before_reporting_flush_success() -> st
     let mut state = RolloutWriterState {
         writer: Some(JsonlWriter {
             file: tokio::fs::File::from_std(read_only_file),
+            key: None,
         }),
+        lock: None,
+        history_sink: None,
         deferred_log_file_info: None,
         pending_items: Vec::new(),
         meta: None,
@@ -871,6 +874,42 @@ async fn resumed_paginated_subagent_rollout_rejects_incomplete_prefix() -> std::
     Ok(())
 }
 
+#[tokio::test]
+async fn resume_fails_while_another_recorder_holds_the_rollout() -> std::io::Result<()> {
+    let home = TempDir::new().expect("temp dir");
+    let config = test_config(home.path());
+    let rollout_path = write_session_file(home.path(), "2025-01-03T12-00-00", Uuid::new_v4())?;
+    let recorder =
+        RolloutRecorder::new(&config, RolloutRecorderParams::resume(rollout_path.clone())).await?;
+
+    let contender = {
+        let config = config.clone();
+        let rollout_path = rollout_path.clone();
+        tokio::spawn(async move {
+            RolloutRecorder::new(&config, RolloutRecorderParams::resume(rollout_path)).await
+        })
+    };
+    let err = match contender.await.map_err(std::io::Error::other)? {
+        Ok(_) => panic!("a locked session should not resume"),
+        Err(err) => err,
+    };
+    assert_eq!(err.kind(), std::io::ErrorKind::ResourceBusy);
+    #[cfg(unix)]
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "session is in use by PID {} ({})",
+            std::process::id(),
+            rollout_path.display()
+        )
+    );
+
+    recorder.shutdown().await?;
+    let resumed =
+        RolloutRecorder::new(&config, RolloutRecorderParams::resume(rollout_path)).await?;
+    resumed.shutdown().await
+}
+
 #[tokio::test]
 async fn append_rollout_item_to_path_assigns_next_paginated_ordinal() -> std::io::Result<()> {
     let home = TempDir::new().expect("temp dir");
@@ -906,6 +945,7 @@ async fn list_threads_db_disabled_does_not_skip_paginated_items() -> std::io::Re
         /*cwd_filters*/ None,
         default_provider.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(page1.items.len(), 1);
@@ -924,6 +964,7 @@ async fn list_threads_db_disabled_does_not_skip_paginated_items() -> std::io::Re
         /*cwd_filters*/ None,
         default_provider.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(page2.items.len(), 1);
@@ -931,6 +972,93 @@ async fn list_threads_db_disabled_does_not_skip_paginated_items() -> std::io::Re
     Ok(())
 }
 
+#[tokio::test]
+async fn list_threads_time_bounds_include_start_and_exclude_end() -> std::io::Result<()> {
+    let home = TempDir::new().expect("temp dir");
+    let config = test_config(home.path());
+
+    let paths = [
+        write_session_file(home.path(), "2025-01-04T12-00-00", Uuid::from_u128(9021))?,
+        write_session_file(home.path(), "2025-01-03T12-00-00", Uuid::from_u128(9022))?,
+        write_session_file(home.path(), "2025-01-02T12-00-00", Uuid::from_u128(9023))?,
+        write_session_file(home.path(), "2025-01-01T12-00-00", Uuid::from_u128(9024))?,
+    ];
+    let day = |day| {
+        chrono::Utc
+            .with_ymd_and_hms(2025, 1, day, 12, 0, 0)
+            .single()
+            .expect("valid datetime")
+    };
+    // Each rollout was last written exactly one day after it was created.
+    for (path, created_day) in paths.iter().zip([4, 3, 2, 1]) {
+        let modified = std::time::SystemTime::from(day(created_day + 1));
+        File::options()
+            .write(true)
+            .open(path)?
+            .set_times(std::fs::FileTimes::new().set_modified(modified))?;
+    }
+
+    let default_provider = config.model_provider_id.clone();
+    let list = |cursor: Option<Cursor>, sort_key, time_bounds| {
+        let config = &config;
+        let default_provider = default_provider.as_str();
+        async move {
+            RolloutRecorder::list_threads(
+                /*state_db_ctx*/ None,
+                config,
+                /*page_size*/ 1,
+                cursor.as_ref(),
+                sort_key,
+                SortDirection::Desc,
+                &[],
+                /*model_providers*/ None,
+                /*cwd_filters*/ None,
+                default_provider,
+                /*search_term*/ None,
+                time_bounds,
+            )
+            .await
+        }
+    };
+
+    let created_bounds = ThreadTimeBounds {
+        created_after: Some(day(2)),
+        created_before: Some(day(4)),
+        ..Default::default()
+    };
+    let page1 = list(None, ThreadSortKey::CreatedAt, created_bounds).await?;
+    assert_eq!(
+        page1
+            .items
+            .iter()
+            .map(|item| &item.path)
+            .collect::<Vec<_>>(),
+        vec![&paths[1]]
+    );
+    let page2 = list(page1.next_cursor, ThreadSortKey::CreatedAt, created_bounds).await?;
+    assert_eq!(
+        page2
+            .items
+            .iter()
+            .map(|item| &item.path)
+            .collect::<Vec<_>>(),
+        vec![&paths[2]]
+    );
+    assert_eq!(page2.next_cursor, None);
+
+    let updated_bounds = ThreadTimeBounds {
+        updated_after: Some(day(5)),
+        ..Default::default()
+    };
+    let page = list(None, ThreadSortKey::UpdatedAt, updated_bounds).await?;
+    assert_eq!(
+        page.items.iter().map(|item| &item.path).collect::<Vec<_>>(),
+        vec![&paths[0]]
+    );
+    assert_eq!(page.next_cursor, None);
+    Ok(())
+}
+
 #[tokio::test]
 async fn list_threads_db_enabled_drops_missing_rollout_paths() -> std::io::Result<()> {
     let home = TempDir::new().expect("temp dir");
@@ -985,6 +1113,7 @@ async fn list_threads_db_enabled_drops_missing_rollout_paths() -> std::io::Resul
         /*cwd_filters*/ None,
         default_provider.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(page.items.len(), 0);
@@ -1051,6 +1180,7 @@ async fn list_threads_db_enabled_repairs_stale_rollout_paths() -> std::io::Resul
         /*cwd_filters*/ None,
         default_provider.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(page.items.len(), 1);
@@ -1141,6 +1271,7 @@ async fn list_threads_state_db_only_skips_jsonl_repair_scan() -> std::io::Result
         /*cwd_filters*/ Some(cwd_filters.as_slice()),
         config.model_provider_id.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(repaired_page.items.len(), 1);
@@ -1232,6 +1363,7 @@ async fn list_threads_default_filter_returns_filesystem_scan_results() -> std::i
         /*cwd_filters*/ Some(cwd_filters.as_slice()),
         config.model_provider_id.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(scanned_page.items.len(), 0);
@@ -1308,6 +1440,7 @@ async fn list_threads_metadata_filter_overlays_state_db_list_metadata() -> std::
         /*cwd_filters*/ None,
         config.model_provider_id.as_str(),
         /*search_term*/ None,
+        ThreadTimeBounds::default(),
     )
     .await?;
 
@@ -1462,6 +1595,7 @@ async fn list_threads_search_repairs_stale_state_db_hits_before_returning() -> s
         /*cwd_filters*/ None,
         config.model_provider_id.as_str(),
         Some("needle"),
+        ThreadTimeBounds::default(),
     )
     .await?;
     assert_eq!(scanned_page.items.len(), 0);
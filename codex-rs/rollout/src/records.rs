@@ -0,0 +1,214 @@
+//! Typed, streaming view of a rollout for tools outside the core.
+//!
+//! Readers that only want messages, tool calls, and usage should not have to
+//! know which events each history mode persists, so both legacy and paginated
+//! message shapes map to the same records here. Anything without a dedicated
+//! record is passed through unchanged as [`RolloutRecord::Other`].
+
+use std::io;
+use std::path::Path;
+
+use codex_protocol::items::AgentMessageContent;
+use codex_protocol::items::TurnItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RateLimitSnapshot;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SessionMetaLine;
+use codex_protocol::protocol::TokenUsageInfo;
+use codex_protocol::protocol::TurnSummaryItem;
+use serde_json::Value;
+use tracing::trace;
+
+use crate::compression;
+use crate::compression::RolloutLineReader;
+use crate::recorder::strip_legacy_ghost_snapshot_rollout_line;
+
+/// One record from a rollout, with the timestamp of the line it came from.
+#[derive(Debug, Clone)]
+pub struct RolloutRecordLine {
+    pub timestamp: String,
+    pub record: RolloutRecord,
+}
+
+#[derive(Debug, Clone)]
+pub enum RolloutRecord {
+    SessionMeta(SessionMetaLine),
+    UserTurn {
+        message: String,
+        images: Vec<String>,
+    },
+    AssistantTurn {
+        message: String,
+    },
+    /// A function or freeform tool call made by the model. `arguments` is the
+    /// raw argument string exactly as the model produced it.
+    ToolCall {
+        call_id: String,
+        name: String,
+        arguments: String,
+    },
+    TokenCount(TokenUsageInfo),
+    RateLimits(RateLimitSnapshot),
+    /// Duration, model, tool calls, and token usage of a finished turn. Only
+    /// rollouts recorded since these summaries were added contain them.
+    TurnSummary(TurnSummaryItem),
+    /// Any other persisted item.
+    Other(RolloutItem),
+}
+
+/// Reads [`RolloutRecordLine`]s from a plain or compressed rollout one at a
+/// time, so large rollouts are never held in memory.
+///
+/// Lines that fail to parse are skipped and counted in
+/// [`RolloutRecordStream::parse_errors`].
+pub struct RolloutRecordStream {
+    reader: RolloutLineReader,
+    pending: Option<RolloutRecordLine>,
+    parse_errors: usize,
+}
+
+impl RolloutRecordStream {
+    pub(crate) async fn open(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            reader: compression::open_rollout_line_reader(path).await?,
+            pending: None,
+            parse_errors: 0,
+        })
+    }
+
+    /// Returns the next record, or `None` at the end of the rollout.
+    pub async fn next_record(&mut self) -> io::Result<Option<RolloutRecordLine>> {
+        if let Some(record) = self.pending.take() {
+            return Ok(Some(record));
+        }
+        while let Some(line) = self.reader.next_line().await? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Some(rollout_line) = self.parse_line(&line) else {
+                continue;
+            };
+            let timestamp = rollout_line.timestamp;
+            let mut records = records_from_item(rollout_line.item)
+                .into_iter()
+                .map(|record| RolloutRecordLine {
+                    timestamp: timestamp.clone(),
+                    record,
+                });
+            if let Some(first) = records.next() {
+                self.pending = records.next();
+                return Ok(Some(first));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Number of lines skipped so far because they were not valid rollout
+    /// lines.
+    pub fn parse_errors(&self) -> usize {
+        self.parse_errors
+    }
+
+    fn parse_line(&mut self, line: &str) -> Option<RolloutLine> {
+        let mut value = match serde_json::from_str::<Value>(line) {
+            Ok(value) => value,
+            Err(err) => {
+                trace!("failed to parse rollout line as JSON: {err}");
+                self.parse_errors = self.parse_errors.saturating_add(1);
+                return None;
+            }
+        };
+        if strip_legacy_ghost_snapshot_rollout_line(&mut value) {
+            return None;
+        }
+        match serde_json::from_value::<RolloutLine>(value) {
+            Ok(rollout_line) => Some(rollout_line),
+            Err(err) => {
+                trace!("failed to parse rollout line: {err}");
+                self.parse_errors = self.parse_errors.saturating_add(1);
+                None
+            }
+        }
+    }
+}
+
+/// Maps one persisted item to its records. A `token_count` event carrying
+/// both usage and rate limits yields two records; every other item yields
+/// exactly one.
+fn records_from_item(item: RolloutItem) -> Vec<RolloutRecord> {
+    let record = match item {
+        RolloutItem::SessionMeta(meta) => RolloutRecord::SessionMeta(meta),
+        RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+            name,
+            arguments,
+            call_id,
+            ..
+        }) => RolloutRecord::ToolCall {
+            call_id,
+            name,
+            arguments,
+        },
+        RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+            call_id,
+            name,
+            input,
+            ..
+        }) => RolloutRecord::ToolCall {
+            call_id,
+            name,
+            arguments: input,
+        },
+        RolloutItem::EventMsg(EventMsg::UserMessage(event)) => RolloutRecord::UserTurn {
+            message: event.message,
+            images: event.images.unwrap_or_default(),
+        },
+        RolloutItem::EventMsg(EventMsg::AgentMessage(event)) => RolloutRecord::AssistantTurn {
+            message: event.message,
+        },
+        RolloutItem::EventMsg(EventMsg::ItemCompleted(event)) => message_record(&event.item)
+            .unwrap_or_else(|| {
+                RolloutRecord::Other(RolloutItem::EventMsg(EventMsg::ItemCompleted(event)))
+            }),
+        RolloutItem::EventMsg(EventMsg::TokenCount(event)) => {
+            return event
+                .info
+                .map(RolloutRecord::TokenCount)
+                .into_iter()
+                .chain(event.rate_limits.map(RolloutRecord::RateLimits))
+                .collect();
+        }
+        RolloutItem::TurnSummary(summary) => RolloutRecord::TurnSummary(summary),
+        item => RolloutRecord::Other(item),
+    };
+    vec![record]
+}
+
+/// Paginated rollouts persist messages as completed turn items rather than
+/// legacy message events.
+fn message_record(item: &TurnItem) -> Option<RolloutRecord> {
+    match item {
+        TurnItem::UserMessage(user) => {
+            let event = user.as_legacy_user_message_event();
+            Some(RolloutRecord::UserTurn {
+                message: event.message,
+                images: event.images.unwrap_or_default(),
+            })
+        }
+        TurnItem::AgentMessage(agent) => Some(RolloutRecord::AssistantTurn {
+            message: agent
+                .content
+                .iter()
+                .map(|content| match content {
+                    AgentMessageContent::Text { text } => text.as_str(),
+                })
+                .collect(),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "records_tests.rs"]
+mod tests;
@@ -0,0 +1,172 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+
+use crate::RolloutRecorder;
+
+use super::*;
+
+const SESSION_META: &str = r#"{"timestamp":"2025-07-01T00:00:00.000Z","type":"session_meta","payload":{"session_id":"019a0000-0000-7000-8000-000000000001","id":"019a0000-0000-7000-8000-000000000001","timestamp":"2025-07-01T00:00:00.000Z","cwd":"/workspace","originator":"codex_cli_rs","cli_version":"0.0.0","source":"cli","model_provider":"openai","base_instructions":null}}"#;
+
+/// Flattens a record into something comparable, since session metadata and
+/// pass-through items do not implement `PartialEq`.
+fn describe(line: &RolloutRecordLine) -> (String, String) {
+    let record = match &line.record {
+        RolloutRecord::SessionMeta(meta) => format!("session_meta {}", meta.meta.id),
+        RolloutRecord::UserTurn { message, images } => format!("user {message} {images:?}"),
+        RolloutRecord::AssistantTurn { message } => format!("assistant {message}"),
+        RolloutRecord::ToolCall {
+            call_id,
+            name,
+            arguments,
+        } => format!("tool_call {call_id} {name} {arguments}"),
+        RolloutRecord::TokenCount(info) => {
+            format!("token_count {}", info.total_token_usage.total_tokens)
+        }
+        RolloutRecord::RateLimits(snapshot) => format!(
+            "rate_limits {:?}",
+            snapshot.primary.as_ref().map(|window| window.used_percent)
+        ),
+        RolloutRecord::TurnSummary(summary) => format!(
+            "turn_summary {} {} {:?} {}",
+            summary.turn_id, summary.model, summary.tool_calls, summary.token_usage.total_tokens
+        ),
+        RolloutRecord::Other(item) => format!(
+            "other {}",
+            serde_json::to_value(item).expect("serialize item")["type"]
+        ),
+    };
+    (line.timestamp.clone(), record)
+}
+
+async fn collect(lines: &[String]) -> (Vec<(String, String)>, usize) {
+    let dir = TempDir::new().expect("tempdir");
+    let path = dir.path().join("rollout.jsonl");
+    fs::write(&path, lines.join("\n")).expect("write rollout");
+
+    let mut stream = RolloutRecorder::stream_records(&path)
+        .await
+        .expect("open stream");
+    let mut records = Vec::new();
+    while let Some(line) = stream.next_record().await.expect("read record") {
+        records.push(describe(&line));
+    }
+    (records, stream.parse_errors())
+}
+
+fn event(timestamp: &str, payload: serde_json::Value) -> String {
+    json!({"timestamp": timestamp, "type": "event_msg", "payload": payload}).to_string()
+}
+
+#[tokio::test]
+async fn stream_records_maps_legacy_and_paginated_lines() {
+    let lines = vec![
+        SESSION_META.to_string(),
+        event(
+            "2025-07-01T00:00:01.000Z",
+            json!({"type": "user_message", "message": "list files", "images": ["a.png"]}),
+        ),
+        json!({
+            "timestamp": "2025-07-01T00:00:02.000Z",
+            "type": "response_item",
+            "payload": {
+                "type": "function_call",
+                "name": "shell",
+                "arguments": "{\"command\":[\"ls\"]}",
+                "call_id": "call-1",
+            },
+        })
+        .to_string(),
+        event(
+            "2025-07-01T00:00:03.000Z",
+            json!({"type": "agent_message", "message": "Two files."}),
+        ),
+        "not json".to_string(),
+        event(
+            "2025-07-01T00:00:04.000Z",
+            json!({
+                "type": "token_count",
+                "info": {
+                    "total_token_usage": {
+                        "input_tokens": 100,
+                        "cached_input_tokens": 0,
+                        "output_tokens": 20,
+                        "reasoning_output_tokens": 0,
+                        "total_tokens": 120,
+                    },
+                    "last_token_usage": {
+                        "input_tokens": 100,
+                        "cached_input_tokens": 0,
+                        "output_tokens": 20,
+                        "reasoning_output_tokens": 0,
+                        "total_tokens": 120,
+                    },
+                    "model_context_window": null,
+                },
+                "rate_limits": {"primary": {"used_percent": 12.5}},
+            }),
+        ),
+        event(
+            "2025-07-01T00:00:05.000Z",
+            json!({
+                "type": "item_completed",
+                "thread_id": "019a0000-0000-7000-8000-000000000001",
+                "turn_id": "turn-2",
+                "item": {"type": "AgentMessage", "id": "msg-1", "content": [
+                    {"type": "Text", "text": "Paginated "},
+                    {"type": "Text", "text": "reply."},
+                ]},
+            }),
+        ),
+        event(
+            "2025-07-01T00:00:06.000Z",
+            json!({"type": "task_complete", "turn_id": "turn-2", "last_agent_message": null}),
+        ),
+        json!({
+            "timestamp": "2025-07-01T00:00:06.000Z",
+            "type": "turn_summary",
+            "payload": {
+                "turn_id": "turn-2",
+                "duration_ms": 5000,
+                "model": "gpt-5",
+                "effort": "high",
+                "tool_calls": {"shell": 1},
+                "token_usage": {
+                    "input_tokens": 100,
+                    "cached_input_tokens": 0,
+                    "output_tokens": 20,
+                    "reasoning_output_tokens": 0,
+                    "total_tokens": 120,
+                },
+            },
+        })
+        .to_string(),
+    ];
+
+    let (records, parse_errors) = collect(&lines).await;
+
+    let expected = [
+        (
+            "2025-07-01T00:00:00.000Z",
+            "session_meta 019a0000-0000-7000-8000-000000000001",
+        ),
+        ("2025-07-01T00:00:01.000Z", r#"user list files ["a.png"]"#),
+        (
+            "2025-07-01T00:00:02.000Z",
+            r#"tool_call call-1 shell {"command":["ls"]}"#,
+        ),
+        ("2025-07-01T00:00:03.000Z", "assistant Two files."),
+        ("2025-07-01T00:00:04.000Z", "token_count 120"),
+        ("2025-07-01T00:00:04.000Z", "rate_limits Some(12.5)"),
+        ("2025-07-01T00:00:05.000Z", "assistant Paginated reply."),
+        ("2025-07-01T00:00:06.000Z", r#"other "event_msg""#),
+        (
+            "2025-07-01T00:00:06.000Z",
+            r#"turn_summary turn-2 gpt-5 {"shell": 1} 120"#,
+        ),
+    ]
+    .map(|(timestamp, record)| (timestamp.to_string(), record.to_string()));
+    assert_eq!((records, parse_errors), (expected.to_vec(), 1));
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::io;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -215,7 +216,8 @@ async fn scan_compressed_rollout_matches(
             let Some(rollout_file) = compression::RolloutFile::from_path(path) else {
                 continue;
             };
-            if !rollout_file.is_compressed() {
+            // ripgrep only sees plain rollouts.
+            if !rollout_file.is_compressed() && !rollout_file.is_encrypted() {
                 continue;
             }
             if let Some(snippet) =
@@ -232,12 +234,12 @@ async fn scan_compressed_rollout_matches(
     Ok(matches)
 }
 
-fn json_escaped_search_term(search_term: &str) -> io::Result<String> {
+pub(crate) fn json_escaped_search_term(search_term: &str) -> io::Result<String> {
     let serialized = serde_json::to_string(search_term).map_err(io::Error::other)?;
     Ok(serialized[1..serialized.len() - 1].to_string())
 }
 
-fn case_insensitive_literal_regex(search_term: impl AsRef<str>) -> io::Result<Regex> {
+pub(crate) fn case_insensitive_literal_regex(search_term: impl AsRef<str>) -> io::Result<Regex> {
     RegexBuilder::new(regex::escape(search_term.as_ref()).as_str())
         .case_insensitive(true)
         .build()
@@ -250,7 +252,7 @@ fn content_match_snippet(jsonl_line: &str, search_term: &Regex) -> Option<String
     excerpt_around_match(text.as_str(), search_term)
 }
 
-fn conversation_text_from_item(item: &RolloutItem) -> Option<String> {
+pub(crate) fn conversation_text_from_item(item: &RolloutItem) -> Option<String> {
     match item {
         RolloutItem::EventMsg(EventMsg::UserMessage(user)) => {
             let text = strip_user_message_prefix(user.message.as_str());
@@ -286,6 +288,7 @@ fn conversation_text_from_item(item: &RolloutItem) -> Option<String> {
         | RolloutItem::InterAgentCommunication(_)
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::Compacted(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_) => None,
     }
 }
@@ -298,6 +301,15 @@ fn content_item_text(item: &ContentItem) -> Option<&str> {
 }
 
 fn excerpt_around_match(text: &str, search_term: &Regex) -> Option<String> {
+    excerpt_with_match_range(text, search_term).map(|(snippet, _)| snippet)
+}
+
+/// Like [`excerpt_around_match`], but also returns the byte range of the match
+/// within the returned snippet so callers can highlight it.
+pub(crate) fn excerpt_with_match_range(
+    text: &str,
+    search_term: &Regex,
+) -> Option<(String, Range<usize>)> {
     let normalized = normalize_preview_text(text);
     let matched = search_term.find(normalized.as_str())?;
     let match_start = matched.start();
@@ -305,20 +317,24 @@ fn excerpt_around_match(text: &str, search_term: &Regex) -> Option<String> {
     let excerpt_start =
         char_start_before(normalized.as_str(), match_start, MATCH_CONTEXT_BEFORE_CHARS);
     let excerpt_end = char_end_after(normalized.as_str(), match_end, MATCH_CONTEXT_AFTER_CHARS);
-    let excerpt = normalized[excerpt_start..excerpt_end].trim();
+    let untrimmed = &normalized[excerpt_start..excerpt_end];
+    let excerpt = untrimmed.trim();
     if excerpt.is_empty() {
         return None;
     }
+    let excerpt_offset = excerpt_start + (untrimmed.len() - untrimmed.trim_start().len());
 
     let mut snippet = String::new();
     if excerpt_start > 0 {
         snippet.push_str("... ");
     }
+    let highlight_start = snippet.len() + match_start.saturating_sub(excerpt_offset);
     snippet.push_str(excerpt);
+    let highlight_end = (highlight_start + (match_end - match_start)).min(snippet.len());
     if excerpt_end < normalized.len() {
         snippet.push_str(" ...");
     }
-    Some(snippet)
+    Some((snippet, highlight_start.min(highlight_end)..highlight_end))
 }
 
 fn normalize_preview_text(text: &str) -> String {
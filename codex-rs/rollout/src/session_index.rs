@@ -141,8 +141,14 @@ pub async fn find_thread_names_by_ids(
         let Ok(entry) = serde_json::from_str::<SessionIndexEntry>(trimmed) else {
             continue;
         };
+        if !thread_ids.contains(&entry.id) {
+            continue;
+        }
+        // An empty name clears whatever the thread was called before.
         let name = entry.thread_name.trim();
-        if !name.is_empty() && thread_ids.contains(&entry.id) {
+        if name.is_empty() {
+            names.remove(&entry.id);
+        } else {
             names.insert(entry.id, name.to_string());
         }
     }
@@ -150,6 +156,38 @@ pub async fn find_thread_names_by_ids(
     Ok(names)
 }
 
+/// Find every thread whose current name is exactly `name`.
+///
+/// Only the latest entry for each thread counts, so renamed or cleared threads
+/// do not match their old names.
+pub async fn find_thread_ids_by_name(
+    codex_home: &Path,
+    name: &str,
+) -> std::io::Result<Vec<ThreadId>> {
+    let path = session_index_path(codex_home);
+    let file = match tokio::fs::File::open(&path).await {
+        Ok(file) => file,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut lines = tokio::io::BufReader::new(file).lines();
+    let mut latest_names = HashMap::new();
+    let mut order = Vec::new();
+    while let Some(line) = lines.next_line().await? {
+        let Ok(entry) = serde_json::from_str::<SessionIndexEntry>(line.trim()) else {
+            continue;
+        };
+        if latest_names.insert(entry.id, entry.thread_name).is_none() {
+            order.push(entry.id);
+        }
+    }
+
+    Ok(order
+        .into_iter()
+        .filter(|id| latest_names.get(id).is_some_and(|latest| latest == name))
+        .collect())
+}
+
 /// Locate a recorded thread rollout and read its session metadata by thread name.
 /// Returns the newest indexed name that still has a readable rollout header.
 pub async fn find_thread_meta_by_name_str(
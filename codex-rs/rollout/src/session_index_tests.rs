@@ -309,6 +309,62 @@ async fn find_thread_names_by_ids_prefers_latest_entry() -> std::io::Result<()>
     Ok(())
 }
 
+#[tokio::test]
+async fn find_thread_names_by_ids_honors_cleared_names() -> std::io::Result<()> {
+    let temp = TempDir::new()?;
+    let path = session_index_path(temp.path());
+    let id = ThreadId::new();
+    let lines = vec![
+        SessionIndexEntry {
+            id,
+            thread_name: "release".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+        },
+        SessionIndexEntry {
+            id,
+            thread_name: String::new(),
+            updated_at: "2024-01-02T00:00:00Z".to_string(),
+        },
+    ];
+    write_index(&path, &lines)?;
+
+    let found = find_thread_names_by_ids(temp.path(), &HashSet::from([id])).await?;
+    assert_eq!(found, HashMap::new());
+    Ok(())
+}
+
+#[tokio::test]
+async fn find_thread_ids_by_name_ignores_renamed_and_cleared_threads() -> std::io::Result<()> {
+    let temp = TempDir::new()?;
+    let path = session_index_path(temp.path());
+    let renamed = ThreadId::new();
+    let cleared = ThreadId::new();
+    let current = ThreadId::new();
+    let entry = |id, name: &str| SessionIndexEntry {
+        id,
+        thread_name: name.to_string(),
+        updated_at: "2024-01-01T00:00:00Z".to_string(),
+    };
+    let lines = vec![
+        entry(renamed, "release"),
+        entry(cleared, "release"),
+        entry(current, "release"),
+        entry(renamed, "hotfix"),
+        entry(cleared, ""),
+    ];
+    write_index(&path, &lines)?;
+
+    assert_eq!(
+        find_thread_ids_by_name(temp.path(), "release").await?,
+        vec![current]
+    );
+    assert_eq!(
+        find_thread_ids_by_name(temp.path(), "missing").await?,
+        Vec::<ThreadId>::new()
+    );
+    Ok(())
+}
+
 #[test]
 fn scan_index_finds_latest_match_among_mixed_entries() -> std::io::Result<()> {
     let temp = TempDir::new()?;
@@ -0,0 +1,280 @@
+//! Full-text search over the user and assistant messages stored in rollouts.
+//!
+//! Unlike [`crate::search`], which only answers "which rollouts mention this
+//! term", this scan returns every matching message with its timestamp and a
+//! snippet. Files are streamed line by line on a worker pool sized to the
+//! number of CPUs. Lines that cannot be decoded or parsed are skipped so one
+//! damaged rollout does not hide results from the rest.
+
+use std::io;
+use std::num::NonZeroUsize;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::RolloutLine;
+use regex::Regex;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::task::JoinSet;
+use tracing::warn;
+
+use crate::ARCHIVED_SESSIONS_SUBDIR;
+use crate::SESSIONS_SUBDIR;
+use crate::compression;
+use crate::compression::RolloutFile;
+use crate::list::parse_timestamp_uuid_from_filename;
+use crate::search::case_insensitive_literal_regex;
+use crate::search::conversation_text_from_item;
+use crate::search::excerpt_with_match_range;
+use crate::search::json_escaped_search_term;
+
+/// Serialized `type` tags of the only rollout records that carry
+/// conversation text. Any other line is skipped without being parsed.
+const MESSAGE_TYPE_MARKERS: [&str; 3] = [
+    r#""type":"message""#,
+    r#""type":"user_message""#,
+    r#""type":"agent_message""#,
+];
+
+/// What to look for in message text.
+#[derive(Debug, Clone)]
+pub struct SessionSearchQuery {
+    pattern: Regex,
+    /// Literal queries can also be checked against the raw JSONL line, which
+    /// rules out most message lines before they are parsed.
+    raw_line_filter: Option<Regex>,
+}
+
+impl SessionSearchQuery {
+    /// Case-insensitive substring match.
+    pub fn literal(term: &str) -> io::Result<Self> {
+        Ok(Self {
+            pattern: case_insensitive_literal_regex(term)?,
+            raw_line_filter: Some(case_insensitive_literal_regex(json_escaped_search_term(
+                term,
+            )?)?),
+        })
+    }
+
+    /// Regular expression match against the message text, using the syntax of
+    /// the `regex` crate.
+    pub fn regex(pattern: &str) -> io::Result<Self> {
+        let pattern =
+            Regex::new(pattern).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        Ok(Self {
+            pattern,
+            raw_line_filter: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SessionSearchOptions {
+    pub query: SessionSearchQuery,
+    /// Search `archived_sessions` instead of `sessions`.
+    pub archived: bool,
+    /// Ignore messages recorded before this time.
+    pub since: Option<SystemTime>,
+    /// Maximum number of hits to return.
+    pub limit: usize,
+}
+
+/// One message that matched a [`SessionSearchQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionSearchHit {
+    pub thread_id: ThreadId,
+    /// Physical path of the rollout, which may be compressed.
+    pub path: PathBuf,
+    /// 1-based line of the matching record within the rollout.
+    pub line: usize,
+    /// Timestamp recorded with the message.
+    pub timestamp: String,
+    /// Whitespace-normalized excerpt around the first match in the message.
+    pub snippet: String,
+    /// Byte range of the match within `snippet`.
+    pub highlight: Range<usize>,
+}
+
+/// Searches every rollout under `codex_home` and returns the newest matching
+/// messages first, up to `options.limit`.
+pub async fn search_sessions(
+    codex_home: &Path,
+    options: &SessionSearchOptions,
+) -> io::Result<Vec<SessionSearchHit>> {
+    if options.limit == 0 {
+        return Ok(Vec::new());
+    }
+    let root = codex_home.join(if options.archived {
+        ARCHIVED_SESSIONS_SUBDIR
+    } else {
+        SESSIONS_SUBDIR
+    });
+    let since = options.since.map(OffsetDateTime::from);
+    let files = collect_rollout_files(root.as_path(), since).await?;
+    let max_workers = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+    let mut hits = Vec::new();
+    let mut jobs = JoinSet::new();
+    for file in files {
+        while jobs.len() >= max_workers {
+            collect_next_search_job(&mut jobs, &mut hits).await?;
+        }
+        let query = options.query.clone();
+        let limit = options.limit;
+        jobs.spawn(async move { search_rollout_file(file, &query, since, limit).await });
+    }
+    while !jobs.is_empty() {
+        collect_next_search_job(&mut jobs, &mut hits).await?;
+    }
+
+    hits.sort_by(|left, right| {
+        right
+            .timestamp
+            .cmp(&left.timestamp)
+            .then_with(|| left.path.cmp(&right.path))
+            .then_with(|| left.line.cmp(&right.line))
+    });
+    hits.truncate(options.limit);
+    Ok(hits)
+}
+
+async fn collect_next_search_job(
+    jobs: &mut JoinSet<io::Result<Vec<SessionSearchHit>>>,
+    hits: &mut Vec<SessionSearchHit>,
+) -> io::Result<()> {
+    let Some(result) = jobs.join_next().await else {
+        return Ok(());
+    };
+    match result.map_err(io::Error::other)? {
+        Ok(file_hits) => hits.extend(file_hits),
+        Err(err) => warn!("skipping unreadable rollout during session search: {err}"),
+    }
+    Ok(())
+}
+
+/// Walks `root` for rollout files, dropping those last modified before
+/// `since` since none of their messages can qualify.
+async fn collect_rollout_files(
+    root: &Path,
+    since: Option<OffsetDateTime>,
+) -> io::Result<Vec<RolloutFile>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                dirs.push(path);
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+            let Some(rollout_file) = RolloutFile::from_path(path) else {
+                continue;
+            };
+            if let Some(since) = since
+                && let Ok(Some(modified)) =
+                    compression::file_modified_time(rollout_file.path()).await
+                && modified < since
+            {
+                continue;
+            }
+            files.push(rollout_file);
+        }
+    }
+    Ok(files)
+}
+
+async fn search_rollout_file(
+    rollout_file: RolloutFile,
+    query: &SessionSearchQuery,
+    since: Option<OffsetDateTime>,
+    limit: usize,
+) -> io::Result<Vec<SessionSearchHit>> {
+    let Some((_, uuid)) = parse_timestamp_uuid_from_filename(rollout_file.plain_file_name()) else {
+        return Ok(Vec::new());
+    };
+    let thread_id = ThreadId::from_string(&uuid.to_string()).map_err(io::Error::other)?;
+
+    let mut lines = compression::open_rollout_line_reader(rollout_file.path()).await?;
+    let mut hits = Vec::new();
+    let mut line_number = 0usize;
+    // Messages are usually recorded twice in a row, once as a response item
+    // and once as an event, so a repeat of the previous hit is dropped.
+    let mut previous_snippet: Option<String> = None;
+    loop {
+        let line = match lines.next_line().await {
+            Ok(Some(line)) => line,
+            Ok(None) => break,
+            // Plain readers consume an undecodable line before reporting it,
+            // so the scan can move on. Compressed streams cannot resync.
+            Err(err)
+                if err.kind() == io::ErrorKind::InvalidData && !rollout_file.is_compressed() =>
+            {
+                line_number += 1;
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        line_number += 1;
+
+        if !MESSAGE_TYPE_MARKERS
+            .iter()
+            .any(|marker| line.contains(marker))
+        {
+            continue;
+        }
+        if let Some(filter) = &query.raw_line_filter
+            && !filter.is_match(line.as_str())
+        {
+            continue;
+        }
+        let Ok(rollout_line) = serde_json::from_str::<RolloutLine>(line.trim()) else {
+            continue;
+        };
+        if let Some(since) = since
+            && OffsetDateTime::parse(rollout_line.timestamp.as_str(), &Rfc3339)
+                .is_ok_and(|timestamp| timestamp < since)
+        {
+            continue;
+        }
+        let Some(text) = conversation_text_from_item(&rollout_line.item) else {
+            continue;
+        };
+        let Some((snippet, highlight)) = excerpt_with_match_range(text.as_str(), &query.pattern)
+        else {
+            continue;
+        };
+        if previous_snippet.as_deref() == Some(snippet.as_str()) {
+            continue;
+        }
+        previous_snippet = Some(snippet.clone());
+        hits.push(SessionSearchHit {
+            thread_id,
+            path: rollout_file.path().to_path_buf(),
+            line: line_number,
+            timestamp: rollout_line.timestamp,
+            snippet,
+            highlight,
+        });
+    }
+
+    // Lines are chronological, so the newest hits are at the end.
+    let overflow = hits.len().saturating_sub(limit);
+    hits.drain(..overflow);
+    Ok(hits)
+}
+
+#[cfg(test)]
+#[path = "session_search_tests.rs"]
+mod tests;
@@ -0,0 +1,203 @@
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use tempfile::TempDir;
+use time::macros::datetime;
+
+use super::*;
+
+const THREAD_A: &str = "019a0000-0000-7000-8000-00000000000a";
+const THREAD_B: &str = "019a0000-0000-7000-8000-00000000000b";
+
+fn user_event(timestamp: &str, message: &str) -> Value {
+    json!({
+        "timestamp": timestamp,
+        "type": "event_msg",
+        "payload": {"type": "user_message", "message": message, "kind": "plain"},
+    })
+}
+
+fn agent_event(timestamp: &str, message: &str) -> Value {
+    json!({
+        "timestamp": timestamp,
+        "type": "event_msg",
+        "payload": {"type": "agent_message", "message": message},
+    })
+}
+
+fn user_response_item(timestamp: &str, text: &str) -> Value {
+    json!({
+        "timestamp": timestamp,
+        "type": "response_item",
+        "payload": {
+            "type": "message",
+            "role": "user",
+            "content": [{"type": "input_text", "text": text}],
+        },
+    })
+}
+
+/// Writes raw JSONL lines, plus any extra raw bytes, to a rollout for
+/// `thread_id` under `sessions/2025/07/01`.
+fn write_rollout(codex_home: &Path, thread_id: &str, lines: &[Value], extra: &[u8]) -> PathBuf {
+    let dir = codex_home.join("sessions/2025/07/01");
+    fs::create_dir_all(&dir).expect("create sessions dir");
+    let path = dir.join(format!("rollout-2025-07-01T00-00-00-{thread_id}.jsonl"));
+    let mut file = fs::File::create(&path).expect("create rollout");
+    for line in lines {
+        writeln!(file, "{line}").expect("write rollout line");
+    }
+    file.write_all(extra).expect("write raw bytes");
+    path
+}
+
+fn hit(
+    thread_id: &str,
+    path: &Path,
+    line: usize,
+    timestamp: &str,
+    snippet: &str,
+    highlight: Range<usize>,
+) -> SessionSearchHit {
+    SessionSearchHit {
+        thread_id: ThreadId::from_string(thread_id).expect("thread id"),
+        path: path.to_path_buf(),
+        line,
+        timestamp: timestamp.to_string(),
+        snippet: snippet.to_string(),
+        highlight,
+    }
+}
+
+fn options(query: SessionSearchQuery) -> SessionSearchOptions {
+    SessionSearchOptions {
+        query,
+        archived: false,
+        since: None,
+        limit: 10,
+    }
+}
+
+#[tokio::test]
+async fn literal_search_returns_newest_messages_and_skips_corrupt_lines() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let path_a = write_rollout(
+        codex_home.path(),
+        THREAD_A,
+        &[
+            user_response_item("2025-07-01T00:00:01.000Z", "How do I run the Migration?"),
+            user_event("2025-07-01T00:00:01.000Z", "How do I run the Migration?"),
+            json!({
+                "timestamp": "2025-07-01T00:00:02.000Z",
+                "type": "response_item",
+                "payload": {
+                    "type": "function_call_output",
+                    "call_id": "call-1",
+                    "output": "migration log",
+                },
+            }),
+        ],
+        b"{not json migration}\n\xff\xfe migration\n",
+    );
+    let path_b = write_rollout(
+        codex_home.path(),
+        THREAD_B,
+        &[
+            user_event("2025-07-02T00:00:00.000Z", "unrelated"),
+            agent_event(
+                "2025-07-02T00:00:05.000Z",
+                "Run the migration with   `just migrate`.",
+            ),
+        ],
+        b"",
+    );
+
+    let hits = search_sessions(
+        codex_home.path(),
+        &options(SessionSearchQuery::literal("migration").expect("query")),
+    )
+    .await
+    .expect("search");
+
+    assert_eq!(
+        hits,
+        vec![
+            hit(
+                THREAD_B,
+                &path_b,
+                2,
+                "2025-07-02T00:00:05.000Z",
+                "Run the migration with `just migrate`.",
+                8..17,
+            ),
+            hit(
+                THREAD_A,
+                &path_a,
+                1,
+                "2025-07-01T00:00:01.000Z",
+                "How do I run the Migration?",
+                17..26,
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn regex_search_honors_since_and_limit() {
+    let codex_home = TempDir::new().expect("tempdir");
+    let path = write_rollout(
+        codex_home.path(),
+        THREAD_A,
+        &[
+            user_event("2025-06-01T00:00:00.000Z", "deploy v1"),
+            user_event("2025-07-01T00:00:00.000Z", "deploy v2"),
+            user_event("2025-07-02T00:00:00.000Z", "deploy v3"),
+            user_event("2025-07-03T00:00:00.000Z", "deploy v4"),
+        ],
+        b"",
+    );
+
+    let hits = search_sessions(
+        codex_home.path(),
+        &SessionSearchOptions {
+            since: Some(datetime!(2025-06-15 00:00 UTC).into()),
+            limit: 2,
+            ..options(SessionSearchQuery::regex(r"v[0-9]").expect("query"))
+        },
+    )
+    .await
+    .expect("search");
+
+    assert_eq!(
+        hits,
+        vec![
+            hit(
+                THREAD_A,
+                &path,
+                4,
+                "2025-07-03T00:00:00.000Z",
+                "deploy v4",
+                7..9
+            ),
+            hit(
+                THREAD_A,
+                &path,
+                3,
+                "2025-07-02T00:00:00.000Z",
+                "deploy v3",
+                7..9
+            ),
+        ]
+    );
+}
+
+#[test]
+fn invalid_regex_is_rejected() {
+    let err = SessionSearchQuery::regex("(").expect_err("invalid regex");
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
@@ -201,6 +201,57 @@ async fn wait_for_backfill_gate(
     }
 }
 
+/// Rebuild thread metadata in the state DB from every rollout under
+/// `codex_home`, including archived sessions.
+///
+/// Rollout files are only read, so a stale or damaged index can always be
+/// recovered this way without losing sessions.
+pub async fn reindex_sessions(
+    config: &impl RolloutConfigView,
+) -> anyhow::Result<codex_state::BackfillStats> {
+    let config = RolloutConfig::from_view(config);
+    reindex_sessions_with_roots(
+        config.codex_home,
+        config.sqlite_home,
+        config.model_provider_id,
+    )
+    .await
+}
+
+async fn reindex_sessions_with_roots(
+    codex_home: PathBuf,
+    sqlite_home: PathBuf,
+    default_model_provider_id: String,
+) -> anyhow::Result<codex_state::BackfillStats> {
+    let runtime = try_init_with_roots(
+        codex_home.clone(),
+        sqlite_home,
+        default_model_provider_id.clone(),
+    )
+    .await?;
+    let result = async {
+        runtime
+            .reset_backfill()
+            .await
+            .context("failed to reset state db backfill")?;
+        metadata::backfill_sessions(
+            runtime.as_ref(),
+            codex_home.as_path(),
+            default_model_provider_id.as_str(),
+        )
+        .await
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "another process is already rebuilding the session index at {}",
+                codex_home.display()
+            )
+        })
+    }
+    .await;
+    runtime.close().await;
+    result
+}
+
 fn emit_startup_warning(message: &str) {
     warn!("{message}");
     if !tracing::dispatcher::has_been_set() {
@@ -301,6 +352,17 @@ pub fn normalize_cwd_for_state_db(cwd: &Path) -> PathBuf {
     normalize_for_path_comparison(cwd).unwrap_or_else(|_| cwd.to_path_buf())
 }
 
+/// SQLite only indexes timestamps; file size and token orders are listed from
+/// the rollout files.
+fn state_sort_key(sort_key: ThreadSortKey) -> Option<codex_state::SortKey> {
+    match sort_key {
+        ThreadSortKey::CreatedAt => Some(codex_state::SortKey::CreatedAt),
+        ThreadSortKey::UpdatedAt => Some(codex_state::SortKey::UpdatedAt),
+        ThreadSortKey::RecencyAt => Some(codex_state::SortKey::RecencyAt),
+        ThreadSortKey::FileSize | ThreadSortKey::TotalTokens => None,
+    }
+}
+
 /// List thread ids from SQLite for parity checks without rollout scanning.
 #[allow(clippy::too_many_arguments)]
 pub async fn list_thread_ids_db(
@@ -337,11 +399,7 @@ pub async fn list_thread_ids_db(
         .list_thread_ids(
             page_size,
             anchor.as_ref(),
-            match sort_key {
-                ThreadSortKey::CreatedAt => codex_state::SortKey::CreatedAt,
-                ThreadSortKey::UpdatedAt => codex_state::SortKey::UpdatedAt,
-                ThreadSortKey::RecencyAt => codex_state::SortKey::RecencyAt,
-            },
+            state_sort_key(sort_key)?,
             allowed_sources.as_slice(),
             model_providers.as_deref(),
             archived_only,
@@ -371,6 +429,7 @@ pub async fn list_threads_db(
     relation_filter: Option<codex_state::ThreadRelationFilter>,
     archived: bool,
     search_term: Option<&str>,
+    time_bounds: codex_state::ThreadTimeBounds,
 ) -> Option<codex_state::ThreadsPage> {
     let ctx = context?;
     if ctx.codex_home() != codex_home {
@@ -403,16 +462,13 @@ pub async fn list_threads_db(
         model_providers: model_providers.as_deref(),
         cwd_filters: normalized_cwd_filters.as_deref(),
         anchor: anchor.as_ref(),
-        sort_key: match sort_key {
-            ThreadSortKey::CreatedAt => codex_state::SortKey::CreatedAt,
-            ThreadSortKey::UpdatedAt => codex_state::SortKey::UpdatedAt,
-            ThreadSortKey::RecencyAt => codex_state::SortKey::RecencyAt,
-        },
+        sort_key: state_sort_key(sort_key)?,
         sort_direction: match sort_direction {
             SortDirection::Asc => codex_state::SortDirection::Asc,
             SortDirection::Desc => codex_state::SortDirection::Desc,
         },
         search_term,
+        time_bounds,
     };
     let page = match relation_filter {
         Some(relation_filter) => {
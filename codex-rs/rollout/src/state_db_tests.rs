@@ -195,6 +195,39 @@ async fn reconcile_rollout_preserves_existing_paginated_memory_mode() -> anyhow:
     Ok(())
 }
 
+#[tokio::test]
+async fn reindex_sessions_restores_missing_thread_rows() -> anyhow::Result<()> {
+    let home = TempDir::new().expect("temp dir");
+    let thread_id = ThreadId::new();
+    write_rollout_with_user_message(home.path(), thread_id, "Hey", ThreadHistoryMode::Legacy)?;
+    let runtime =
+        codex_state::StateRuntime::init(home.path().to_path_buf(), "test-provider".to_string())
+            .await?;
+    runtime
+        .mark_backfill_complete(/*last_watermark*/ None)
+        .await?;
+    assert_eq!(runtime.get_thread(thread_id).await?, None);
+
+    let stats = reindex_sessions_with_roots(
+        home.path().to_path_buf(),
+        home.path().to_path_buf(),
+        "test-provider".to_string(),
+    )
+    .await?;
+
+    assert_eq!((stats.scanned, stats.upserted, stats.failed), (1, 1, 0));
+    let persisted = runtime
+        .get_thread(thread_id)
+        .await?
+        .expect("thread should be reindexed");
+    assert_eq!(persisted.first_user_message.as_deref(), Some("Hey"));
+    assert_eq!(
+        runtime.get_backfill_state().await?.status,
+        codex_state::BackfillStatus::Complete
+    );
+    Ok(())
+}
+
 fn write_rollout_with_user_message(
     home: &Path,
     thread_id: ThreadId,
@@ -19,12 +19,16 @@ use time::macros::format_description;
 use uuid::Uuid;
 
 use crate::INTERACTIVE_SESSION_SOURCES;
+use crate::THREAD_PREVIEW_MAX_CHARS;
 use crate::find_thread_path_by_id_str;
 use crate::list::Cursor;
 use crate::list::ThreadItem;
+use crate::list::ThreadListConfig;
+use crate::list::ThreadListLayout;
 use crate::list::ThreadSortKey;
 use crate::list::ThreadsPage;
 use crate::list::get_threads;
+use crate::list::get_threads_in_root;
 use crate::list::read_head_for_summary;
 use crate::rollout_date_parts;
 use anyhow::Result;
@@ -40,7 +44,11 @@ use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::ThreadGoal;
 use codex_protocol::protocol::ThreadGoalStatus;
 use codex_protocol::protocol::ThreadGoalUpdatedEvent;
+use codex_protocol::protocol::TokenCountEvent;
+use codex_protocol::protocol::TokenUsage;
+use codex_protocol::protocol::TokenUsageInfo;
 use codex_protocol::protocol::UserMessageEvent;
+use codex_state::ThreadTimeBounds;
 
 const NO_SOURCE_FILTER: &[SessionSource] = &[];
 const TEST_PROVIDER: &str = "test-provider";
@@ -560,6 +568,56 @@ fn write_session_file_with_meta_payload(
     Ok(())
 }
 
+/// Writes a session whose meta line is followed by `lines`, each a rollout
+/// line payload of the given `type`.
+fn write_session_file_with_lines(
+    root: &Path,
+    ts_str: &str,
+    uuid: Uuid,
+    lines: &[(&str, serde_json::Value)],
+) -> std::io::Result<()> {
+    let format: &[FormatItem] =
+        format_description!("[year]-[month]-[day]T[hour]-[minute]-[second]");
+    let dt = PrimitiveDateTime::parse(ts_str, format)
+        .expect("valid timestamp")
+        .assume_utc();
+    let dir = root
+        .join("sessions")
+        .join(format!("{:04}", dt.year()))
+        .join(format!("{:02}", u8::from(dt.month())))
+        .join(format!("{:02}", dt.day()));
+    fs::create_dir_all(&dir)?;
+
+    let mut file = File::create(dir.join(format!("rollout-{ts_str}-{uuid}.jsonl")))?;
+    let meta = serde_json::json!({
+        "timestamp": ts_str,
+        "type": "session_meta",
+        "payload": {
+            "session_id": uuid,
+            "id": uuid,
+            "timestamp": ts_str,
+            "cwd": ".",
+            "originator": "test_originator",
+            "cli_version": "test_version",
+            "source": "vscode",
+            "model_provider": TEST_PROVIDER,
+            "base_instructions": null,
+        },
+    });
+    writeln!(file, "{meta}")?;
+    for (line_type, payload) in lines {
+        let line = serde_json::json!({
+            "timestamp": ts_str,
+            "type": line_type,
+            "payload": payload,
+        });
+        writeln!(file, "{line}")?;
+    }
+
+    file.set_times(FileTimes::new().set_modified(dt.into()))?;
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_list_conversations_latest_first() {
     let temp = TempDir::new().unwrap();
@@ -1064,6 +1122,140 @@ async fn test_goal_first_thread_reads_later_user_message() {
     );
 }
 
+#[test]
+fn short_preview_is_one_line_of_at_most_120_chars() {
+    let item = ThreadItem {
+        first_user_message: Some(format!("fix the\n\n  build {}", "x".repeat(200))),
+        preview: Some("optimize the benchmark".to_string()),
+        ..Default::default()
+    };
+
+    let preview = item.short_preview().expect("preview");
+
+    assert_eq!(preview.chars().count(), THREAD_PREVIEW_MAX_CHARS);
+    assert_eq!(
+        preview,
+        format!(
+            "fix the build {}…",
+            "x".repeat(THREAD_PREVIEW_MAX_CHARS - 15)
+        )
+    );
+    assert_eq!(
+        ThreadItem {
+            preview: Some("optimize the benchmark".to_string()),
+            ..Default::default()
+        }
+        .short_preview(),
+        Some("optimize the benchmark".to_string())
+    );
+    assert_eq!(ThreadItem::default().short_preview(), None);
+}
+
+async fn list_single_thread(home: &Path) -> Result<Option<ThreadItem>> {
+    let provider_filter = provider_vec(&[TEST_PROVIDER]);
+    let mut page = get_threads(
+        home,
+        /*page_size*/ 10,
+        /*cursor*/ None,
+        ThreadSortKey::CreatedAt,
+        INTERACTIVE_SESSION_SOURCES.as_slice(),
+        Some(provider_filter.as_slice()),
+        /*cwd_filters*/ None,
+        TEST_PROVIDER,
+    )
+    .await?;
+    assert!(page.items.len() <= 1, "expected at most one thread");
+    Ok(page.items.pop())
+}
+
+#[tokio::test]
+async fn short_preview_skips_instructions_and_shows_image_placeholder() -> Result<()> {
+    let temp = TempDir::new()?;
+    let uuid = Uuid::from_u128(102);
+    write_session_file_with_lines(
+        temp.path(),
+        "2025-05-02T10-30-00",
+        uuid,
+        &[
+            (
+                "response_item",
+                serde_json::json!({
+                    "type": "message",
+                    "role": "user",
+                    "content": [{"type": "input_text", "text": "<user_instructions>be terse</user_instructions>"}],
+                }),
+            ),
+            (
+                "event_msg",
+                serde_json::json!({
+                    "type": "user_message",
+                    "message": "",
+                    "images": ["https://example.com/screenshot.png"],
+                    "kind": "plain",
+                }),
+            ),
+            (
+                "event_msg",
+                serde_json::json!({"type": "user_message", "message": "what is this?", "kind": "plain"}),
+            ),
+        ],
+    )?;
+
+    let item = list_single_thread(temp.path())
+        .await?
+        .expect("listed thread");
+
+    assert_eq!(item.thread_id, Some(thread_id_from_uuid(uuid)));
+    assert_eq!(item.short_preview(), Some("[Image]".to_string()));
+    Ok(())
+}
+
+#[tokio::test]
+async fn short_preview_shows_command_when_session_starts_with_one() -> Result<()> {
+    let temp = TempDir::new()?;
+    write_session_file_with_lines(
+        temp.path(),
+        "2025-05-02T10-30-00",
+        Uuid::from_u128(103),
+        &[(
+            "response_item",
+            serde_json::json!({
+                "type": "message",
+                "role": "user",
+                "content": [{
+                    "type": "input_text",
+                    "text": "<user_shell_command>\n<command>\ngit status\n</command>\n<result>\nExit code: 0\n</result>\n</user_shell_command>",
+                }],
+            }),
+        )],
+    )?;
+
+    let item = list_single_thread(temp.path())
+        .await?
+        .expect("listed thread");
+
+    assert_eq!(item.first_user_message, None);
+    assert_eq!(
+        item.short_preview(),
+        Some("[Command] git status".to_string())
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn empty_sessions_have_no_preview_and_are_not_listed() -> Result<()> {
+    let temp = TempDir::new()?;
+    write_session_file_with_lines(
+        temp.path(),
+        "2025-05-02T10-30-00",
+        Uuid::from_u128(104),
+        &[],
+    )?;
+
+    assert_eq!(list_single_thread(temp.path()).await?, None);
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_get_thread_contents() {
     let temp = TempDir::new().unwrap();
@@ -1542,6 +1734,150 @@ async fn test_timestamp_only_cursor_skips_same_second_filesystem_ties() {
     assert_eq!(page2, expected_page2);
 }
 
+fn append_token_count(home: &Path, ts_str: &str, uuid: Uuid, total_tokens: i64) {
+    let path = home
+        .join("sessions")
+        .join("2025")
+        .join("07")
+        .join("01")
+        .join(format!("rollout-{ts_str}-{uuid}.jsonl"));
+    let line = RolloutLine {
+        timestamp: ts_str.to_string(),
+        ordinal: None,
+        item: RolloutItem::EventMsg(EventMsg::TokenCount(TokenCountEvent {
+            info: Some(TokenUsageInfo {
+                total_token_usage: TokenUsage {
+                    total_tokens,
+                    ..Default::default()
+                },
+                last_token_usage: TokenUsage::default(),
+                model_context_window: None,
+            }),
+            rate_limits: None,
+        })),
+    };
+    let mut file = fs::OpenOptions::new().append(true).open(path).unwrap();
+    writeln!(file, "{}", serde_json::to_string(&line).unwrap()).unwrap();
+}
+
+#[tokio::test]
+async fn test_total_tokens_sort_pages_through_ties() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+
+    let u1 = Uuid::from_u128(1);
+    let u2 = Uuid::from_u128(2);
+    let u3 = Uuid::from_u128(3);
+    let u4 = Uuid::from_u128(4);
+    for (ts, uuid, total_tokens) in [
+        ("2025-07-01T00-00-01", u1, Some(500)),
+        ("2025-07-01T00-00-02", u2, Some(200)),
+        ("2025-07-01T00-00-03", u3, Some(200)),
+        ("2025-07-01T00-00-04", u4, None),
+    ] {
+        write_session_file(
+            home,
+            ts,
+            uuid,
+            /*num_records*/ 0,
+            Some(SessionSource::VSCode),
+        )
+        .unwrap();
+        if let Some(total_tokens) = total_tokens {
+            // An earlier count is superseded by the last one in the file.
+            append_token_count(home, ts, uuid, /*total_tokens*/ 1);
+            append_token_count(home, ts, uuid, total_tokens);
+        }
+    }
+
+    let mut thread_ids = Vec::new();
+    let mut cursors = Vec::new();
+    let mut cursor: Option<Cursor> = None;
+    loop {
+        let page = get_threads(
+            home,
+            /*page_size*/ 1,
+            cursor.as_ref(),
+            ThreadSortKey::TotalTokens,
+            INTERACTIVE_SESSION_SOURCES.as_slice(),
+            /*model_providers*/ None,
+            /*cwd_filters*/ None,
+            TEST_PROVIDER,
+        )
+        .await
+        .unwrap();
+        thread_ids.extend(page.items.iter().map(|item| item.thread_id));
+        cursor = page.next_cursor;
+        match &cursor {
+            Some(cursor) => cursors.push(serde_json::to_value(cursor).unwrap()),
+            None => break,
+        }
+    }
+
+    assert_eq!(
+        thread_ids,
+        vec![
+            Some(thread_id_from_uuid(u1)),
+            Some(thread_id_from_uuid(u3)),
+            Some(thread_id_from_uuid(u2)),
+            Some(thread_id_from_uuid(u4)),
+        ]
+    );
+    assert_eq!(
+        cursors,
+        vec![
+            serde_json::json!(format!("500|{u1}")),
+            serde_json::json!(format!("200|{u3}")),
+            serde_json::json!(format!("200|{u2}")),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_file_size_sort_orders_largest_first() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+
+    let u1 = Uuid::from_u128(1);
+    let u2 = Uuid::from_u128(2);
+    let u3 = Uuid::from_u128(3);
+    for (ts, uuid, num_records) in [
+        ("2025-07-01T00-00-01", u1, 5),
+        ("2025-07-01T00-00-02", u2, 20),
+        ("2025-07-01T00-00-03", u3, 0),
+    ] {
+        write_session_file(home, ts, uuid, num_records, Some(SessionSource::VSCode)).unwrap();
+    }
+
+    let page = get_threads(
+        home,
+        /*page_size*/ 10,
+        /*cursor*/ None,
+        ThreadSortKey::FileSize,
+        INTERACTIVE_SESSION_SOURCES.as_slice(),
+        /*model_providers*/ None,
+        /*cwd_filters*/ None,
+        TEST_PROVIDER,
+    )
+    .await
+    .unwrap();
+
+    let thread_ids = page
+        .items
+        .iter()
+        .map(|item| item.thread_id)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        thread_ids,
+        vec![
+            Some(thread_id_from_uuid(u2)),
+            Some(thread_id_from_uuid(u1)),
+            Some(thread_id_from_uuid(u3)),
+        ]
+    );
+    assert_eq!(page.next_cursor, None);
+}
+
 #[tokio::test]
 async fn test_source_filter_excludes_non_matching_sessions() {
     let temp = TempDir::new().unwrap();
@@ -1723,3 +2059,75 @@ async fn test_model_provider_filter_selects_only_matching_sessions() -> Result<(
 
     Ok(())
 }
+
+#[tokio::test]
+async fn created_time_bounds_skip_date_directories_outside_the_range() -> Result<()> {
+    let temp = TempDir::new()?;
+    let home = temp.path();
+    for (index, ts) in [
+        "2024-12-31T12-00-00",
+        "2025-01-01T12-00-00",
+        "2025-01-02T00-00-00",
+        "2025-01-02T12-00-00",
+        "2025-01-03T00-00-00",
+        "2025-02-01T12-00-00",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        write_session_file(
+            home,
+            ts,
+            Uuid::from_u128(7000 + index as u128),
+            /*num_records*/ 1,
+            Some(SessionSource::Cli),
+        )?;
+    }
+    let time_bounds = ThreadTimeBounds {
+        created_after: Some(chrono::Utc.with_ymd_and_hms(2025, 1, 2, 0, 0, 0).unwrap()),
+        created_before: Some(chrono::Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap()),
+        ..Default::default()
+    };
+
+    for sort_key in [ThreadSortKey::CreatedAt, ThreadSortKey::UpdatedAt] {
+        let page = get_threads_in_root(
+            home.join("sessions"),
+            /*page_size*/ 10,
+            /*cursor*/ None,
+            sort_key,
+            ThreadListConfig {
+                allowed_sources: NO_SOURCE_FILTER,
+                model_providers: None,
+                cwd_filters: None,
+                default_provider: TEST_PROVIDER,
+                layout: ThreadListLayout::NestedByDate,
+                time_bounds,
+            },
+        )
+        .await?;
+
+        let mut names = page
+            .items
+            .iter()
+            .filter_map(|item| item.path.file_name()?.to_str().map(str::to_string))
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                format!(
+                    "rollout-2025-01-02T00-00-00-{}.jsonl",
+                    Uuid::from_u128(7002)
+                ),
+                format!(
+                    "rollout-2025-01-02T12-00-00-{}.jsonl",
+                    Uuid::from_u128(7003)
+                ),
+            ]
+        );
+        // Only the 2025/01/02 directory is read.
+        assert_eq!(page.num_scanned_files, 2);
+        assert_eq!(page.next_cursor, None);
+    }
+    Ok(())
+}
@@ -0,0 +1,424 @@
+//! Moves saved sessions between Codex homes.
+//!
+//! [`archive_sessions`] bundles every rollout and the session name index into
+//! a `.tar.zst`. [`import_sessions`] merges such a bundle, or another Codex
+//! home, into this one. Each rollout lands with a single rename, and an
+//! incoming rollout that collides with a different local one is re-id'd from
+//! its content, so an interrupted import can simply be run again.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fs::File;
+use std::fs::FileTimes;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use serde_json::Value;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::ARCHIVED_SESSIONS_SUBDIR;
+use crate::SESSIONS_SUBDIR;
+use crate::compact::collect_rollout_files;
+use crate::compression;
+use crate::compression::RolloutFile;
+use crate::session_index;
+
+const SESSION_INDEX_FILE: &str = "session_index.jsonl";
+const ARCHIVE_COMPRESSION_LEVEL: i32 = 3;
+/// Length of the hyphenated thread id that ends every rollout file stem.
+const THREAD_ID_LEN: usize = 36;
+
+/// Totals from one [`import_sessions`] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionImportStats {
+    pub scanned: usize,
+    /// Rollouts copied in under their original name.
+    pub imported: usize,
+    /// Rollouts already present with the same content, including ones placed
+    /// by an earlier, interrupted import.
+    pub skipped: usize,
+    /// Rollouts whose id was already used by a different local session and
+    /// that were copied in under a new id.
+    pub renamed: usize,
+    pub failed: usize,
+}
+
+/// Where one incoming rollout ended up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportOutcome {
+    Imported,
+    Skipped,
+    Renamed,
+}
+
+/// Writes every rollout under `codex_home`, including archived sessions, and
+/// the session name index to a zstd-compressed tarball at `out`. Rollouts are
+/// stored as they are on disk. Returns the number of rollouts archived.
+pub async fn archive_sessions(codex_home: &Path, out: &Path) -> io::Result<usize> {
+    let mut entries = Vec::new();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        for rollout_file in collect_rollout_files(codex_home.join(root).as_path()).await? {
+            entries.push(rollout_file.into_path());
+        }
+    }
+    let count = entries.len();
+    let index_path = codex_home.join(SESSION_INDEX_FILE);
+    if tokio::fs::metadata(index_path.as_path())
+        .await
+        .is_ok_and(|metadata| metadata.is_file())
+    {
+        entries.push(index_path);
+    }
+    entries.sort();
+
+    let codex_home = codex_home.to_path_buf();
+    let out = out.to_path_buf();
+    tokio::task::spawn_blocking(move || write_archive(&codex_home, &entries, &out))
+        .await
+        .map_err(io::Error::other)??;
+    Ok(count)
+}
+
+/// Copies the rollouts and session names from `source` into `codex_home`.
+///
+/// `source` is either another Codex home or a bundle written by
+/// [`archive_sessions`]. The session index is not touched; callers reindex
+/// afterwards so imported sessions show up in listings.
+pub async fn import_sessions(codex_home: &Path, source: &Path) -> io::Result<SessionImportStats> {
+    if tokio::fs::metadata(source).await?.is_dir() {
+        if same_path(codex_home, source).await {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot import sessions from the current Codex home",
+            ));
+        }
+        return import_from_home(codex_home, source).await;
+    }
+
+    let extracted = tempfile::tempdir()?;
+    let archive = source.to_path_buf();
+    let destination = extracted.path().to_path_buf();
+    tokio::task::spawn_blocking(move || extract_archive(&archive, &destination))
+        .await
+        .map_err(io::Error::other)??;
+    import_from_home(codex_home, extracted.path()).await
+}
+
+async fn import_from_home(codex_home: &Path, source: &Path) -> io::Result<SessionImportStats> {
+    let mut local = HashMap::new();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        for rollout_file in collect_rollout_files(codex_home.join(root).as_path()).await? {
+            local.insert(
+                rollout_file.plain_file_name().to_string(),
+                rollout_file.into_path(),
+            );
+        }
+    }
+
+    let mut stats = SessionImportStats::default();
+    let mut thread_ids = HashMap::new();
+    for root in [SESSIONS_SUBDIR, ARCHIVED_SESSIONS_SUBDIR] {
+        let source_root = source.join(root);
+        for rollout_file in collect_rollout_files(source_root.as_path()).await? {
+            stats.scanned += 1;
+            let destination_dir = rollout_file
+                .path()
+                .parent()
+                .and_then(|parent| parent.strip_prefix(source_root.as_path()).ok())
+                .map(|relative| codex_home.join(root).join(relative))
+                .unwrap_or_else(|| codex_home.join(root));
+            match import_rollout(&rollout_file, destination_dir.as_path(), &mut local).await {
+                Ok((outcome, source_id, imported_id)) => {
+                    match outcome {
+                        ImportOutcome::Imported => stats.imported += 1,
+                        ImportOutcome::Skipped => stats.skipped += 1,
+                        ImportOutcome::Renamed => stats.renamed += 1,
+                    }
+                    if let (Some(source_id), Some(imported_id)) = (source_id, imported_id) {
+                        thread_ids.insert(source_id, imported_id);
+                    }
+                }
+                Err(err) => {
+                    warn!(
+                        "failed to import rollout {}: {err}",
+                        rollout_file.path().display()
+                    );
+                    stats.failed += 1;
+                }
+            }
+        }
+    }
+
+    import_thread_names(codex_home, source, &thread_ids).await?;
+    Ok(stats)
+}
+
+/// Places one incoming rollout, returning what happened along with its
+/// thread id before and after the import.
+async fn import_rollout(
+    rollout_file: &RolloutFile,
+    destination_dir: &Path,
+    local: &mut HashMap<String, PathBuf>,
+) -> io::Result<(ImportOutcome, Option<ThreadId>, Option<ThreadId>)> {
+    let plain_file_name = rollout_file.plain_file_name().to_string();
+    let source_id = thread_id_from_file_name(&plain_file_name);
+    let Some(existing) = local.get(&plain_file_name) else {
+        let file_name = rollout_file
+            .path()
+            .file_name()
+            .ok_or_else(|| io::Error::other("rollout path has no file name"))?;
+        let destination = destination_dir.join(file_name);
+        copy_atomically(rollout_file.path(), destination.as_path()).await?;
+        local.insert(plain_file_name, destination);
+        return Ok((ImportOutcome::Imported, source_id, source_id));
+    };
+
+    let incoming = read_lines(rollout_file.path()).await?;
+    if read_lines(existing).await? == incoming {
+        return Ok((ImportOutcome::Skipped, source_id, source_id));
+    }
+
+    let Some((renamed_file_name, renamed_id)) = renamed_file_name(&plain_file_name, &incoming)
+    else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "rollout file name does not end in a thread id",
+        ));
+    };
+    let renamed_lines = rewrite_session_meta(&incoming, &plain_file_name, &renamed_id)?;
+    if let Some(existing) = local.get(&renamed_file_name) {
+        // Placed by an earlier run of the same import.
+        if read_lines(existing).await? == renamed_lines {
+            return Ok((ImportOutcome::Skipped, source_id, Some(renamed_id)));
+        }
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{renamed_file_name} already exists with different content"),
+        ));
+    }
+
+    let destination = destination_dir.join(renamed_file_name.as_str());
+    let source = rollout_file.path().to_path_buf();
+    let target = destination.clone();
+    tokio::task::spawn_blocking(move || {
+        let metadata = std::fs::metadata(source.as_path())?;
+        write_atomically(
+            target.as_path(),
+            Some(&metadata.permissions()),
+            metadata.modified()?,
+            |output| {
+                for line in &renamed_lines {
+                    output.write_all(line.as_bytes())?;
+                    output.write_all(b"\n")?;
+                }
+                Ok(())
+            },
+        )
+    })
+    .await
+    .map_err(io::Error::other)??;
+    local.insert(renamed_file_name, destination);
+    Ok((ImportOutcome::Renamed, source_id, Some(renamed_id)))
+}
+
+/// Records the source home's names for imported sessions, unless the name is
+/// already in use here.
+async fn import_thread_names(
+    codex_home: &Path,
+    source: &Path,
+    thread_ids: &HashMap<ThreadId, ThreadId>,
+) -> io::Result<()> {
+    let source_ids = thread_ids.keys().copied().collect::<HashSet<_>>();
+    let names = session_index::find_thread_names_by_ids(source, &source_ids).await?;
+    let mut names = names.into_iter().collect::<Vec<_>>();
+    names.sort_by(|(left, _), (right, _)| left.to_string().cmp(&right.to_string()));
+    for (source_id, name) in names {
+        let Some(imported_id) = thread_ids.get(&source_id).copied() else {
+            continue;
+        };
+        if !session_index::find_thread_ids_by_name(codex_home, &name)
+            .await?
+            .is_empty()
+        {
+            continue;
+        }
+        session_index::append_thread_name(codex_home, imported_id, &name).await?;
+    }
+    Ok(())
+}
+
+/// The file name and id an incoming rollout gets when its own id is taken.
+///
+/// The id is derived from the original id and the content, so re-running an
+/// import picks the same name and finds the copy it already made.
+fn renamed_file_name(plain_file_name: &str, lines: &[String]) -> Option<(String, ThreadId)> {
+    let stem = plain_file_name.strip_suffix(".jsonl")?;
+    let prefix = stem.get(..stem.len().checked_sub(THREAD_ID_LEN)?)?;
+    let original = Uuid::parse_str(stem.get(prefix.len()..)?).ok()?;
+    let renamed = Uuid::new_v5(&original, lines.join("\n").as_bytes()).to_string();
+    let thread_id = ThreadId::from_string(&renamed).ok()?;
+    Some((format!("{prefix}{renamed}.jsonl"), thread_id))
+}
+
+fn thread_id_from_file_name(plain_file_name: &str) -> Option<ThreadId> {
+    let stem = plain_file_name.strip_suffix(".jsonl")?;
+    let id = stem.get(stem.len().checked_sub(THREAD_ID_LEN)?..)?;
+    ThreadId::from_string(id).ok()
+}
+
+/// Swaps the thread id in `session_meta` records, leaving everything else in
+/// the rollout byte-for-byte intact.
+fn rewrite_session_meta(
+    lines: &[String],
+    plain_file_name: &str,
+    renamed_id: &ThreadId,
+) -> io::Result<Vec<String>> {
+    let Some(original_id) = thread_id_from_file_name(plain_file_name).map(|id| id.to_string())
+    else {
+        return Ok(lines.to_vec());
+    };
+    let renamed_id = renamed_id.to_string();
+    lines
+        .iter()
+        .map(|line| {
+            // Cheap pre-check so only candidate lines are parsed.
+            if !line.contains("\"session_meta\"") {
+                return Ok(line.clone());
+            }
+            let Ok(mut value) = serde_json::from_str::<Value>(line) else {
+                return Ok(line.clone());
+            };
+            if value.get("type").and_then(Value::as_str) != Some("session_meta") {
+                return Ok(line.clone());
+            }
+            let Some(payload) = value.get_mut("payload").and_then(Value::as_object_mut) else {
+                return Ok(line.clone());
+            };
+            for key in ["id", "session_id"] {
+                if payload.get(key).and_then(Value::as_str) == Some(original_id.as_str()) {
+                    payload.insert(key.to_string(), Value::String(renamed_id.clone()));
+                }
+            }
+            serde_json::to_string(&value).map_err(io::Error::other)
+        })
+        .collect()
+}
+
+async fn read_lines(path: &Path) -> io::Result<Vec<String>> {
+    let mut reader = compression::open_rollout_line_reader(path).await?;
+    let mut lines = Vec::new();
+    while let Some(line) = reader.next_line().await? {
+        lines.push(line);
+    }
+    Ok(lines)
+}
+
+async fn copy_atomically(source: &Path, destination: &Path) -> io::Result<()> {
+    let source = source.to_path_buf();
+    let destination = destination.to_path_buf();
+    tokio::task::spawn_blocking(move || {
+        let metadata = std::fs::metadata(source.as_path())?;
+        let mut input = File::open(source.as_path())?;
+        write_atomically(
+            destination.as_path(),
+            Some(&metadata.permissions()),
+            metadata.modified()?,
+            |output| io::copy(&mut input, output).map(|_| ()),
+        )
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+/// Writes `destination` through a temporary sibling so it either appears
+/// complete or not at all. Imported rollouts keep the source's permissions and
+/// modification time so listing order is unchanged.
+fn write_atomically(
+    destination: &Path,
+    permissions: Option<&std::fs::Permissions>,
+    modified: std::time::SystemTime,
+    write: impl FnOnce(&mut File) -> io::Result<()>,
+) -> io::Result<()> {
+    if let Some(parent) = destination.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let temp_path = compression::temp_path_for(destination, "import");
+    let result = (|| {
+        let mut output = match permissions {
+            Some(permissions) => {
+                compression::create_file_with_permissions(temp_path.as_path(), permissions)?
+            }
+            None => File::create_new(temp_path.as_path())?,
+        };
+        write(&mut output)?;
+        output.set_times(FileTimes::new().set_modified(modified))?;
+        output.sync_all()?;
+        if destination.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", destination.display()),
+            ));
+        }
+        std::fs::rename(temp_path.as_path(), destination)
+    })();
+    if result.is_err() {
+        let _ = std::fs::remove_file(temp_path.as_path());
+    }
+    result
+}
+
+fn write_archive(codex_home: &Path, entries: &[PathBuf], out: &Path) -> io::Result<()> {
+    if out.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", out.display()),
+        ));
+    }
+    write_atomically(
+        out,
+        /*permissions*/ None,
+        std::time::SystemTime::now(),
+        |output| {
+            let encoder = zstd::Encoder::new(output, ARCHIVE_COMPRESSION_LEVEL)?;
+            let mut archive = tar::Builder::new(encoder);
+            for path in entries {
+                let relative = path.strip_prefix(codex_home).map_err(io::Error::other)?;
+                archive.append_path_with_name(path, relative)?;
+            }
+            archive.into_inner()?.finish()?;
+            Ok(())
+        },
+    )
+}
+
+fn extract_archive(archive: &Path, destination: &Path) -> io::Result<()> {
+    let decoder = zstd::Decoder::new(File::open(archive)?)?;
+    let mut archive = tar::Archive::new(decoder);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        // `unpack_in` refuses paths that would escape `destination`.
+        entry.unpack_in(destination)?;
+    }
+    Ok(())
+}
+
+async fn same_path(left: &Path, right: &Path) -> bool {
+    match (
+        tokio::fs::canonicalize(left).await,
+        tokio::fs::canonicalize(right).await,
+    ) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+#[path = "transfer_tests.rs"]
+mod tests;
@@ -0,0 +1,237 @@
+use std::fs;
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+
+use super::*;
+
+const DAY_DIR: &str = "sessions/2025/07/01";
+
+fn thread_id(index: u64) -> String {
+    format!("019a0000-0000-7000-8000-{index:012}")
+}
+
+fn rollout_name(index: u64) -> String {
+    format!("rollout-2025-07-01T00-00-00-{}.jsonl", thread_id(index))
+}
+
+/// Writes a rollout for thread `index` whose only message is `message`.
+fn write_rollout(codex_home: &Path, index: u64, message: &str) -> PathBuf {
+    let id = thread_id(index);
+    let lines = [
+        json!({
+            "timestamp": "2025-07-01T00:00:00.000Z",
+            "type": "session_meta",
+            "payload": {
+                "session_id": id,
+                "id": id,
+                "timestamp": "2025-07-01T00:00:00.000Z",
+                "cwd": "/workspace",
+                "originator": "codex_cli_rs",
+                "cli_version": "0.0.0",
+                "source": "cli",
+                "model_provider": "openai",
+            },
+        }),
+        json!({
+            "timestamp": "2025-07-01T00:00:01.000Z",
+            "type": "event_msg",
+            "payload": {"type": "user_message", "message": message},
+        }),
+    ];
+    let dir = codex_home.join(DAY_DIR);
+    fs::create_dir_all(&dir).expect("create sessions dir");
+    let path = dir.join(rollout_name(index));
+    let contents = lines
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect::<String>();
+    fs::write(&path, contents).expect("write rollout");
+    path
+}
+
+fn rollout_names(codex_home: &Path) -> Vec<String> {
+    let mut names = fs::read_dir(codex_home.join(DAY_DIR))
+        .expect("read sessions dir")
+        .map(|entry| {
+            entry
+                .expect("dir entry")
+                .file_name()
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+fn session_meta_ids(path: &Path) -> (String, String, String) {
+    let contents = fs::read_to_string(path).expect("read rollout");
+    let first_line = contents.lines().next().unwrap_or_default();
+    let value: Value = serde_json::from_str(first_line).expect("session meta line");
+    let field = |key: &str| {
+        value["payload"][key]
+            .as_str()
+            .unwrap_or_default()
+            .to_string()
+    };
+    (field("id"), field("session_id"), field("cwd"))
+}
+
+/// A source home with a new session, one already imported, and one whose id
+/// is used locally by a different session.
+async fn source_and_local_homes() -> (TempDir, TempDir) {
+    let source = TempDir::new().expect("source home");
+    let local = TempDir::new().expect("local home");
+    write_rollout(source.path(), 1, "new on the old machine");
+    write_rollout(source.path(), 2, "already copied");
+    write_rollout(source.path(), 3, "from the old machine");
+    write_rollout(local.path(), 2, "already copied");
+    write_rollout(local.path(), 3, "from the new machine");
+    let source_id = ThreadId::from_string(&thread_id(1)).expect("thread id");
+    session_index::append_thread_name(source.path(), source_id, "old-work")
+        .await
+        .expect("name source session");
+    (source, local)
+}
+
+#[tokio::test]
+async fn import_copies_new_sessions_and_renames_collisions() {
+    let (source, local) = source_and_local_homes().await;
+
+    let stats = import_sessions(local.path(), source.path())
+        .await
+        .expect("import");
+
+    assert_eq!(
+        stats,
+        SessionImportStats {
+            scanned: 3,
+            imported: 1,
+            skipped: 1,
+            renamed: 1,
+            failed: 0,
+        }
+    );
+    let names = rollout_names(local.path());
+    let renamed = names
+        .iter()
+        .find(|name| ![rollout_name(1), rollout_name(2), rollout_name(3)].contains(name))
+        .expect("renamed rollout")
+        .clone();
+    let renamed_id = thread_id_from_file_name(&renamed)
+        .expect("renamed id")
+        .to_string();
+    let mut expected = vec![
+        rollout_name(1),
+        rollout_name(2),
+        rollout_name(3),
+        renamed.clone(),
+    ];
+    expected.sort();
+    assert_eq!(names, expected);
+    assert_eq!(
+        session_meta_ids(&local.path().join(DAY_DIR).join(&renamed)),
+        (renamed_id.clone(), renamed_id, "/workspace".to_string())
+    );
+    assert_eq!(
+        session_meta_ids(&local.path().join(DAY_DIR).join(rollout_name(3))),
+        (thread_id(3), thread_id(3), "/workspace".to_string())
+    );
+    assert_eq!(
+        session_index::find_thread_ids_by_name(local.path(), "old-work")
+            .await
+            .expect("find name"),
+        vec![ThreadId::from_string(&thread_id(1)).expect("thread id")]
+    );
+}
+
+#[tokio::test]
+async fn rerunning_an_import_skips_everything_already_placed() {
+    let (source, local) = source_and_local_homes().await;
+    import_sessions(local.path(), source.path())
+        .await
+        .expect("first import");
+    let names = rollout_names(local.path());
+
+    let stats = import_sessions(local.path(), source.path())
+        .await
+        .expect("second import");
+
+    assert_eq!(
+        stats,
+        SessionImportStats {
+            scanned: 3,
+            imported: 0,
+            skipped: 3,
+            renamed: 0,
+            failed: 0,
+        }
+    );
+    assert_eq!(rollout_names(local.path()), names);
+    assert_eq!(
+        session_index::find_thread_ids_by_name(local.path(), "old-work")
+            .await
+            .expect("find name")
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn archive_imports_into_a_fresh_home() {
+    let (source, _local) = source_and_local_homes().await;
+    let bundle_dir = TempDir::new().expect("bundle dir");
+    let bundle = bundle_dir.path().join("sessions.tar.zst");
+    let fresh = TempDir::new().expect("fresh home");
+
+    let archived = archive_sessions(source.path(), &bundle)
+        .await
+        .expect("archive");
+    let stats = import_sessions(fresh.path(), &bundle)
+        .await
+        .expect("import archive");
+
+    assert_eq!(archived, 3);
+    assert_eq!(
+        stats,
+        SessionImportStats {
+            scanned: 3,
+            imported: 3,
+            skipped: 0,
+            renamed: 0,
+            failed: 0,
+        }
+    );
+    assert_eq!(
+        rollout_names(fresh.path()),
+        vec![rollout_name(1), rollout_name(2), rollout_name(3)]
+    );
+    assert_eq!(
+        session_index::find_thread_name_by_id(
+            fresh.path(),
+            &ThreadId::from_string(&thread_id(1)).expect("thread id")
+        )
+        .await
+        .expect("find name"),
+        Some("old-work".to_string())
+    );
+    assert_eq!(
+        archive_sessions(source.path(), &bundle)
+            .await
+            .map_err(|err| err.kind()),
+        Err(io::ErrorKind::AlreadyExists)
+    );
+}
+
+#[tokio::test]
+async fn importing_the_current_home_is_rejected() {
+    let (_source, local) = source_and_local_homes().await;
+
+    let err = import_sessions(local.path(), local.path())
+        .await
+        .expect_err("self import");
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
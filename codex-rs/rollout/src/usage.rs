@@ -0,0 +1,349 @@
+//! Token usage totals across saved sessions, for `codex status` style
+//! summaries and usage reports.
+//!
+//! Rollouts only record a cumulative `total_token_usage` on each
+//! `token_count` event, and the same total is re-emitted whenever rate limits
+//! change. Usage is therefore counted as the growth of that total between
+//! consecutive events, attributed to the model of the latest turn context and
+//! to the UTC day of the event. Time spent in turns is summed from the
+//! durations on terminal turn events. [`for_each_session`] reports the same
+//! counts one session at a time.
+
+use std::collections::BTreeMap;
+use std::io;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::TokenUsage;
+use codex_state::ThreadTimeBounds;
+use serde::Serialize;
+
+use crate::RolloutRecorder;
+use crate::config::RolloutConfigView;
+use crate::list::Cursor;
+use crate::list::SortDirection;
+use crate::list::ThreadItem;
+use crate::list::ThreadSortKey;
+use crate::list::ThreadsPage;
+use crate::records::RolloutRecord;
+use crate::state_db::StateDbHandle;
+
+const LIST_PAGE_SIZE: usize = 100;
+
+/// Model name used for usage reported before the first turn context.
+pub const UNKNOWN_MODEL: &str = "unknown";
+
+/// Which sessions and `token_count` events [`aggregate`] counts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageFilter {
+    /// Only count usage reported at or after this time.
+    pub since: Option<DateTime<Utc>>,
+    /// Only count usage reported before this time.
+    pub until: Option<DateTime<Utc>>,
+    /// Only count sessions that used one of these model providers.
+    pub model_providers: Option<Vec<String>>,
+    /// Only count sessions from these sources. Empty counts every source.
+    pub allowed_sources: Vec<SessionSource>,
+}
+
+/// Token usage summed over the sessions matching a [`UsageFilter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UsageReport {
+    pub total: TokenUsage,
+    /// Usage per provider and model, ordered by provider then model.
+    pub by_model: Vec<ModelUsage>,
+    /// Usage per UTC day, oldest first.
+    pub by_day: Vec<DailyUsage>,
+    /// Sessions that reported usage within the filter's time range.
+    pub sessions_with_usage: usize,
+    /// Matching sessions without any counted usage, such as sessions that
+    /// never recorded a `token_count` event.
+    pub sessions_without_usage: usize,
+    /// Wall-clock time of turns that ended within the filter's time range.
+    pub turn_duration_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModelUsage {
+    pub model_provider: String,
+    pub model: String,
+    pub usage: TokenUsage,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyUsage {
+    pub date: NaiveDate,
+    pub usage: TokenUsage,
+}
+
+/// Usage counted for one session by [`for_each_session`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SessionUsage {
+    pub thread_id: Option<ThreadId>,
+    /// RFC3339 creation time, when known.
+    pub created_at: Option<String>,
+    /// RFC3339 time of the last update, when known.
+    pub updated_at: Option<String>,
+    pub model_provider: String,
+    /// Model of the session's latest turn context, or [`UNKNOWN_MODEL`].
+    pub model: String,
+    pub usage: TokenUsage,
+    pub turn_duration_ms: i64,
+}
+
+/// Sums token usage over the saved, unarchived sessions matching `filter`.
+///
+/// Sessions are listed through the state DB when available and streamed one
+/// record at a time, so memory use grows with the number of models and days
+/// in the report rather than with the size of the rollouts.
+pub async fn aggregate(
+    state_db_ctx: Option<StateDbHandle>,
+    config: &impl RolloutConfigView,
+    filter: &UsageFilter,
+) -> io::Result<UsageReport> {
+    let mut totals = UsageTotals::default();
+    let mut cursor = None;
+    loop {
+        let page =
+            list_sessions_page(state_db_ctx.clone(), config, filter, cursor.as_ref()).await?;
+        for item in &page.items {
+            let model_provider = session_model_provider(item, config);
+            let mut stream = RolloutRecorder::stream_records(item.path.as_path()).await?;
+            totals
+                .add_session(&mut stream, model_provider, filter)
+                .await?;
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(totals.into_report())
+}
+
+/// Counts usage like [`aggregate`] but hands each matching session to `visit`
+/// as soon as its rollout has been read, most recently updated first.
+///
+/// Only one session's counts are held at a time, so callers can stream rows
+/// for any number of sessions.
+pub async fn for_each_session(
+    state_db_ctx: Option<StateDbHandle>,
+    config: &impl RolloutConfigView,
+    filter: &UsageFilter,
+    mut visit: impl FnMut(SessionUsage) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut cursor = None;
+    loop {
+        let page =
+            list_sessions_page(state_db_ctx.clone(), config, filter, cursor.as_ref()).await?;
+        for item in page.items {
+            let model_provider = session_model_provider(&item, config).to_string();
+            let mut stream = RolloutRecorder::stream_records(item.path.as_path()).await?;
+            let mut totals = UsageTotals::default();
+            let model = totals
+                .add_session(&mut stream, &model_provider, filter)
+                .await?;
+            visit(SessionUsage {
+                thread_id: item.thread_id,
+                created_at: item.created_at,
+                updated_at: item.updated_at,
+                model_provider,
+                model,
+                usage: totals.total,
+                turn_duration_ms: totals.turn_duration_ms,
+            })?;
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+async fn list_sessions_page(
+    state_db_ctx: Option<StateDbHandle>,
+    config: &impl RolloutConfigView,
+    filter: &UsageFilter,
+    cursor: Option<&Cursor>,
+) -> io::Result<ThreadsPage> {
+    // A session last updated before `since` or created at or after `until`
+    // cannot hold usage reported inside the range.
+    let time_bounds = ThreadTimeBounds {
+        updated_after: filter.since,
+        created_before: filter.until,
+        ..Default::default()
+    };
+    RolloutRecorder::list_threads(
+        state_db_ctx,
+        config,
+        LIST_PAGE_SIZE,
+        cursor,
+        ThreadSortKey::UpdatedAt,
+        SortDirection::Desc,
+        &filter.allowed_sources,
+        filter.model_providers.as_deref(),
+        /*cwd_filters*/ None,
+        config.model_provider_id(),
+        /*search_term*/ None,
+        time_bounds,
+    )
+    .await
+}
+
+fn session_model_provider<'a>(item: &'a ThreadItem, config: &'a impl RolloutConfigView) -> &'a str {
+    item.model_provider
+        .as_deref()
+        .unwrap_or(config.model_provider_id())
+}
+
+#[derive(Default)]
+struct UsageTotals {
+    total: TokenUsage,
+    by_model: BTreeMap<(String, String), TokenUsage>,
+    by_day: BTreeMap<NaiveDate, TokenUsage>,
+    sessions_with_usage: usize,
+    sessions_without_usage: usize,
+    turn_duration_ms: i64,
+}
+
+impl UsageTotals {
+    /// Adds one session's usage and returns the model of its latest turn
+    /// context.
+    async fn add_session(
+        &mut self,
+        stream: &mut crate::RolloutRecordStream,
+        model_provider: &str,
+        filter: &UsageFilter,
+    ) -> io::Result<String> {
+        let mut model = UNKNOWN_MODEL.to_string();
+        let mut previous_total = TokenUsage::default();
+        let mut counted = false;
+        while let Some(line) = stream.next_record().await? {
+            let info = match line.record {
+                RolloutRecord::Other(RolloutItem::TurnContext(turn_context)) => {
+                    model = turn_context.model;
+                    continue;
+                }
+                RolloutRecord::TokenCount(info) => info,
+                RolloutRecord::Other(RolloutItem::EventMsg(EventMsg::TurnComplete(event))) => {
+                    self.add_turn_duration(&line.timestamp, event.duration_ms, filter);
+                    continue;
+                }
+                RolloutRecord::Other(RolloutItem::EventMsg(EventMsg::TurnAborted(event))) => {
+                    self.add_turn_duration(&line.timestamp, event.duration_ms, filter);
+                    continue;
+                }
+                RolloutRecord::SessionMeta(_)
+                | RolloutRecord::UserTurn { .. }
+                | RolloutRecord::AssistantTurn { .. }
+                | RolloutRecord::ToolCall { .. }
+                | RolloutRecord::RateLimits(_)
+                | RolloutRecord::TurnSummary(_)
+                | RolloutRecord::Other(_) => continue,
+            };
+            let delta = usage_growth(&previous_total, &info.total_token_usage);
+            previous_total = info.total_token_usage;
+            let Some(timestamp) = parse_timestamp(&line.timestamp) else {
+                continue;
+            };
+            if !filter.contains(timestamp) || delta.is_zero() {
+                continue;
+            }
+            counted = true;
+            self.total.add_assign(&delta);
+            self.by_model
+                .entry((model_provider.to_string(), model.clone()))
+                .or_default()
+                .add_assign(&delta);
+            self.by_day
+                .entry(timestamp.date_naive())
+                .or_default()
+                .add_assign(&delta);
+        }
+        if counted {
+            self.sessions_with_usage += 1;
+        } else {
+            self.sessions_without_usage += 1;
+        }
+        Ok(model)
+    }
+
+    fn add_turn_duration(
+        &mut self,
+        timestamp: &str,
+        duration_ms: Option<i64>,
+        filter: &UsageFilter,
+    ) {
+        if let Some(duration_ms) = duration_ms
+            && parse_timestamp(timestamp).is_some_and(|timestamp| filter.contains(timestamp))
+        {
+            self.turn_duration_ms = self.turn_duration_ms.saturating_add(duration_ms.max(0));
+        }
+    }
+
+    fn into_report(self) -> UsageReport {
+        UsageReport {
+            total: self.total,
+            by_model: self
+                .by_model
+                .into_iter()
+                .map(|((model_provider, model), usage)| ModelUsage {
+                    model_provider,
+                    model,
+                    usage,
+                })
+                .collect(),
+            by_day: self
+                .by_day
+                .into_iter()
+                .map(|(date, usage)| DailyUsage { date, usage })
+                .collect(),
+            sessions_with_usage: self.sessions_with_usage,
+            sessions_without_usage: self.sessions_without_usage,
+            turn_duration_ms: self.turn_duration_ms,
+        }
+    }
+}
+
+impl UsageFilter {
+    fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        self.since.is_none_or(|since| timestamp >= since)
+            && self.until.is_none_or(|until| timestamp < until)
+    }
+}
+
+/// Growth from `previous` to `current`, per field. A field that shrank, as
+/// when a session's total is reset to its context window, contributes nothing.
+fn usage_growth(previous: &TokenUsage, current: &TokenUsage) -> TokenUsage {
+    let growth = |previous: i64, current: i64| (current - previous).max(0);
+    TokenUsage {
+        input_tokens: growth(previous.input_tokens, current.input_tokens),
+        cached_input_tokens: growth(previous.cached_input_tokens, current.cached_input_tokens),
+        cache_write_input_tokens: growth(
+            previous.cache_write_input_tokens,
+            current.cache_write_input_tokens,
+        ),
+        output_tokens: growth(previous.output_tokens, current.output_tokens),
+        reasoning_output_tokens: growth(
+            previous.reasoning_output_tokens,
+            current.reasoning_output_tokens,
+        ),
+        total_tokens: growth(previous.total_tokens, current.total_tokens),
+    }
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+#[path = "usage_tests.rs"]
+mod tests;
@@ -0,0 +1,367 @@
+use std::fs;
+use std::path::Path;
+
+use chrono::TimeZone;
+use codex_protocol::config_types::ReasoningSummary;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::TokenCountEvent;
+use codex_protocol::protocol::TokenUsageInfo;
+use codex_protocol::protocol::TurnCompleteEvent;
+use codex_protocol::protocol::TurnContextItem;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tempfile::TempDir;
+
+use super::*;
+use crate::INTERACTIVE_SESSION_SOURCES;
+use crate::config::RolloutConfig;
+
+fn test_config(codex_home: &Path) -> RolloutConfig {
+    RolloutConfig {
+        codex_home: codex_home.to_path_buf(),
+        sqlite_home: codex_home.to_path_buf(),
+        cwd: codex_home.to_path_buf(),
+        model_provider_id: "openai".to_string(),
+        generate_memories: false,
+    }
+}
+
+fn usage(input_tokens: i64, output_tokens: i64) -> TokenUsage {
+    TokenUsage {
+        input_tokens,
+        output_tokens,
+        total_tokens: input_tokens + output_tokens,
+        ..Default::default()
+    }
+}
+
+fn turn_context(codex_home: &Path, timestamp: &str, model: &str) -> RolloutLine {
+    RolloutLine {
+        timestamp: timestamp.to_string(),
+        ordinal: None,
+        item: RolloutItem::TurnContext(TurnContextItem {
+            turn_id: None,
+            cwd: serde_json::from_value(json!(codex_home)).expect("absolute cwd"),
+            workspace_roots: None,
+            current_date: None,
+            timezone: None,
+            approval_policy: AskForApproval::Never,
+            approvals_reviewer: None,
+            sandbox_policy: SandboxPolicy::new_read_only_policy(),
+            permission_profile: None,
+            network: None,
+            file_system_sandbox_policy: None,
+            model: model.to_string(),
+            comp_hash: None,
+            personality: None,
+            collaboration_mode: None,
+            multi_agent_version: None,
+            multi_agent_mode: None,
+            realtime_active: None,
+            effort: None,
+            summary: ReasoningSummary::Auto,
+        }),
+    }
+}
+
+fn token_count(timestamp: &str, total_token_usage: TokenUsage) -> RolloutLine {
+    RolloutLine {
+        timestamp: timestamp.to_string(),
+        ordinal: None,
+        item: RolloutItem::EventMsg(EventMsg::TokenCount(TokenCountEvent {
+            info: Some(TokenUsageInfo {
+                last_token_usage: total_token_usage.clone(),
+                total_token_usage,
+                model_context_window: None,
+            }),
+            rate_limits: None,
+        })),
+    }
+}
+
+fn turn_complete(timestamp: &str, duration_ms: i64) -> RolloutLine {
+    RolloutLine {
+        timestamp: timestamp.to_string(),
+        ordinal: None,
+        item: RolloutItem::EventMsg(EventMsg::TurnComplete(TurnCompleteEvent {
+            turn_id: "turn-1".to_string(),
+            last_agent_message: None,
+            error: None,
+            started_at: None,
+            completed_at: None,
+            duration_ms: Some(duration_ms),
+            time_to_first_token_ms: None,
+        })),
+    }
+}
+
+/// Writes a CLI rollout created at `ts` (`YYYY-MM-DDThh-mm-ss`) by
+/// `model_provider`, followed by `lines`.
+fn write_rollout(
+    codex_home: &Path,
+    ts: &str,
+    id: u128,
+    model_provider: &str,
+    lines: &[RolloutLine],
+) -> anyhow::Result<()> {
+    write_rollout_from_source(codex_home, ts, id, model_provider, "cli", lines)
+}
+
+fn write_rollout_from_source(
+    codex_home: &Path,
+    ts: &str,
+    id: u128,
+    model_provider: &str,
+    source: &str,
+    lines: &[RolloutLine],
+) -> anyhow::Result<()> {
+    let id = uuid::Uuid::from_u128(id);
+    let dir = codex_home.join(format!(
+        "sessions/{}/{}/{}",
+        &ts[..4],
+        &ts[5..7],
+        &ts[8..10]
+    ));
+    fs::create_dir_all(&dir)?;
+    let meta = json!({
+        "timestamp": ts,
+        "type": "session_meta",
+        "payload": {
+            "session_id": id,
+            "id": id,
+            "timestamp": ts,
+            "cwd": codex_home,
+            "originator": "test_originator",
+            "cli_version": "0.0.0",
+            "source": source,
+            "model_provider": model_provider,
+        },
+    });
+    let user_message = json!({
+        "timestamp": ts,
+        "type": "event_msg",
+        "payload": {"type": "user_message", "message": "hello", "kind": "plain"},
+    });
+    let mut contents = format!("{meta}\n{user_message}\n");
+    for line in lines {
+        contents.push_str(&serde_json::to_string(line)?);
+        contents.push('\n');
+    }
+    fs::write(dir.join(format!("rollout-{ts}-{id}.jsonl")), contents)?;
+    Ok(())
+}
+
+fn write_fixture(codex_home: &Path) -> anyhow::Result<()> {
+    write_rollout(
+        codex_home,
+        "2025-07-01T10-00-00",
+        /*id*/ 1,
+        "openai",
+        &[
+            turn_context(codex_home, "2025-07-01T10:00:01Z", "gpt-5"),
+            token_count("2025-07-01T10:00:02Z", usage(80, 20)),
+            // Re-emitted with a rate limit update; must not count twice.
+            token_count("2025-07-01T10:00:03Z", usage(80, 20)),
+            turn_context(codex_home, "2025-07-02T09:00:00Z", "gpt-5-mini"),
+            token_count("2025-07-02T09:00:01Z", usage(110, 40)),
+        ],
+    )?;
+    write_rollout(
+        codex_home,
+        "2025-07-02T12-00-00",
+        /*id*/ 2,
+        "azure",
+        &[
+            turn_context(codex_home, "2025-07-02T12:00:01Z", "gpt-5"),
+            token_count("2025-07-02T12:00:02Z", usage(10, 0)),
+        ],
+    )?;
+    write_rollout(
+        codex_home,
+        "2025-07-02T13-00-00",
+        /*id*/ 3,
+        "openai",
+        &[],
+    )
+}
+
+fn model_usage(model_provider: &str, model: &str, usage: TokenUsage) -> ModelUsage {
+    ModelUsage {
+        model_provider: model_provider.to_string(),
+        model: model.to_string(),
+        usage,
+    }
+}
+
+fn daily_usage(day: u32, usage: TokenUsage) -> DailyUsage {
+    DailyUsage {
+        date: NaiveDate::from_ymd_opt(2025, 7, day).expect("valid date"),
+        usage,
+    }
+}
+
+#[tokio::test]
+async fn aggregate_buckets_usage_per_model_and_day() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    let report = aggregate(
+        /*state_db_ctx*/ None,
+        &test_config(codex_home.path()),
+        &UsageFilter::default(),
+    )
+    .await?;
+
+    assert_eq!(
+        report,
+        UsageReport {
+            total: usage(120, 40),
+            by_model: vec![
+                model_usage("azure", "gpt-5", usage(10, 0)),
+                model_usage("openai", "gpt-5", usage(80, 20)),
+                model_usage("openai", "gpt-5-mini", usage(30, 20)),
+            ],
+            by_day: vec![daily_usage(1, usage(80, 20)), daily_usage(2, usage(40, 20))],
+            sessions_with_usage: 2,
+            sessions_without_usage: 1,
+            turn_duration_ms: 0,
+        }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn aggregate_counts_only_usage_inside_the_time_range() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    let report = aggregate(
+        /*state_db_ctx*/ None,
+        &test_config(codex_home.path()),
+        &UsageFilter {
+            since: Utc.with_ymd_and_hms(2025, 7, 2, 0, 0, 0).single(),
+            until: Utc.with_ymd_and_hms(2025, 7, 2, 12, 0, 2).single(),
+            model_providers: None,
+            allowed_sources: Vec::new(),
+        },
+    )
+    .await?;
+
+    // The first session's usage on July 2nd is measured from its July 1st
+    // total, and the second session's event at exactly `until` is excluded.
+    assert_eq!(
+        report,
+        UsageReport {
+            total: usage(30, 20),
+            by_model: vec![model_usage("openai", "gpt-5-mini", usage(30, 20))],
+            by_day: vec![daily_usage(2, usage(30, 20))],
+            sessions_with_usage: 1,
+            sessions_without_usage: 1,
+            turn_duration_ms: 0,
+        }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn aggregate_sums_turn_durations_from_allowed_sources() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    write_rollout(
+        codex_home.path(),
+        "2025-07-01T10-00-00",
+        /*id*/ 1,
+        "openai",
+        &[
+            token_count("2025-07-01T10:00:02Z", usage(80, 20)),
+            turn_complete("2025-07-01T10:00:03Z", /*duration_ms*/ 1_500),
+            turn_complete("2025-07-01T10:05:00Z", /*duration_ms*/ 2_000),
+        ],
+    )?;
+    write_rollout_from_source(
+        codex_home.path(),
+        "2025-07-01T11-00-00",
+        /*id*/ 2,
+        "openai",
+        "exec",
+        &[
+            token_count("2025-07-01T11:00:02Z", usage(500, 0)),
+            turn_complete("2025-07-01T11:00:03Z", /*duration_ms*/ 9_000),
+        ],
+    )?;
+
+    let report = aggregate(
+        /*state_db_ctx*/ None,
+        &test_config(codex_home.path()),
+        &UsageFilter {
+            allowed_sources: INTERACTIVE_SESSION_SOURCES.clone(),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    assert_eq!(
+        report,
+        UsageReport {
+            total: usage(80, 20),
+            by_model: vec![model_usage("openai", UNKNOWN_MODEL, usage(80, 20))],
+            by_day: vec![daily_usage(1, usage(80, 20))],
+            sessions_with_usage: 1,
+            sessions_without_usage: 0,
+            turn_duration_ms: 3_500,
+        }
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn for_each_session_reports_usage_per_session() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    write_fixture(codex_home.path())?;
+
+    let mut sessions = Vec::new();
+    for_each_session(
+        /*state_db_ctx*/ None,
+        &test_config(codex_home.path()),
+        &UsageFilter::default(),
+        |session| {
+            sessions.push((
+                session.thread_id.map(|thread_id| thread_id.to_string()),
+                session.model_provider,
+                session.model,
+                session.usage,
+            ));
+            Ok(())
+        },
+    )
+    .await?;
+    sessions.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let thread_id = |id: u128| Some(uuid::Uuid::from_u128(id).to_string());
+    assert_eq!(
+        sessions,
+        vec![
+            (
+                thread_id(1),
+                "openai".to_string(),
+                "gpt-5-mini".to_string(),
+                usage(110, 40),
+            ),
+            (
+                thread_id(2),
+                "azure".to_string(),
+                "gpt-5".to_string(),
+                usage(10, 0),
+            ),
+            (
+                thread_id(3),
+                "openai".to_string(),
+                UNKNOWN_MODEL.to_string(),
+                TokenUsage::default(),
+            ),
+        ]
+    );
+    Ok(())
+}
@@ -8,6 +8,7 @@ use codex_protocol::protocol::TurnContextItem;
 use codex_protocol::protocol::UserMessageEvent;
 use codex_protocol::protocol::strip_user_message_prefix;
 use codex_protocol::protocol::user_message_preview;
+use codex_protocol::protocol::user_shell_command_preview;
 use serde::Serialize;
 use serde_json::Value;
 
@@ -25,6 +26,7 @@ pub fn apply_rollout_item(
         RolloutItem::InterAgentCommunication(_)
         | RolloutItem::InterAgentCommunicationMetadata { .. } => {}
         RolloutItem::Compacted(_) => {}
+        RolloutItem::TurnSummary(_) => {}
         RolloutItem::WorldState(_) => {}
     }
     if metadata.model_provider.is_empty() {
@@ -47,11 +49,12 @@ pub fn rollout_item_affects_thread_metadata(item: &RolloutItem) -> bool {
         {
             true
         }
+        RolloutItem::ResponseItem(item) => user_shell_command_preview(item).is_some(),
         RolloutItem::EventMsg(_)
-        | RolloutItem::ResponseItem(_)
         | RolloutItem::InterAgentCommunication(_)
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::Compacted(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_) => false,
     }
 }
@@ -131,7 +134,13 @@ fn apply_event_msg(metadata: &mut ThreadMetadata, event: &EventMsg) {
     }
 }
 
-fn apply_response_item(_metadata: &mut ThreadMetadata, _item: &ResponseItem) {}
+fn apply_response_item(metadata: &mut ThreadMetadata, item: &ResponseItem) {
+    // A command the user ran directly stands in for a user message in the
+    // preview, but is not one.
+    if let Some(preview) = user_shell_command_preview(item) {
+        set_preview_if_empty(metadata, Some(preview));
+    }
+}
 
 fn apply_user_message(metadata: &mut ThreadMetadata, user: &UserMessageEvent) {
     let preview = user_message_preview(user);
@@ -223,6 +232,29 @@ mod tests {
         assert_eq!(metadata.title, "");
     }
 
+    #[test]
+    fn user_shell_commands_set_preview_but_not_first_user_message() {
+        let mut metadata = metadata_for_test();
+        let item = RolloutItem::ResponseItem(ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: "<user_shell_command>\n<command>\ngit status\n</command>\n\
+                       <result>\nExit code: 0\n</result>\n</user_shell_command>"
+                    .to_string(),
+            }],
+            phase: None,
+            internal_chat_message_metadata_passthrough: None,
+        });
+
+        assert!(rollout_item_affects_thread_metadata(&item));
+        apply_rollout_item(&mut metadata, &item, "test-provider");
+
+        assert_eq!(metadata.first_user_message, None);
+        assert_eq!(metadata.preview.as_deref(), Some("[Command] git status"));
+        assert_eq!(metadata.title, "");
+    }
+
     #[test]
     fn event_msg_user_messages_set_title_and_first_user_message() {
         let mut metadata = metadata_for_test();
@@ -51,6 +51,7 @@ pub use model::ThreadGoalStatus;
 pub use model::ThreadMetadata;
 pub use model::ThreadMetadataBuilder;
 pub use model::ThreadRelationFilter;
+pub use model::ThreadTimeBounds;
 pub use model::ThreadsPage;
 pub use runtime::ExternalAgentConfigImportDetailsRecord;
 pub use runtime::ExternalAgentConfigImportFailureRecord;
@@ -66,6 +67,7 @@ pub use runtime::RuntimeDbBackup;
 pub use runtime::RuntimeDbPath;
 pub use runtime::ThreadFilterOptions;
 pub use runtime::backup_runtime_db_for_fresh_start;
+pub use runtime::delete_thread_history;
 pub use runtime::goals_db_filename;
 pub use runtime::goals_db_path;
 pub use runtime::is_sqlite_corruption_error;
@@ -26,6 +26,7 @@ pub use thread_metadata::SortKey;
 pub use thread_metadata::ThreadMetadata;
 pub use thread_metadata::ThreadMetadataBuilder;
 pub use thread_metadata::ThreadRelationFilter;
+pub use thread_metadata::ThreadTimeBounds;
 pub use thread_metadata::ThreadsPage;
 
 pub(crate) use thread_goal::ThreadGoalRow;
@@ -49,6 +49,16 @@ pub struct Anchor {
     pub id: Option<ThreadId>,
 }
 
+/// Optional bounds on thread timestamps. The `*_after` bounds are inclusive
+/// and the `*_before` bounds are exclusive.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThreadTimeBounds {
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+}
+
 /// A single page of thread metadata results.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ThreadsPage {
@@ -425,6 +425,30 @@ pub async fn open_thread_history_db(sqlite_home: &Path) -> anyhow::Result<Sqlite
     .await
 }
 
+/// Deletes the projected history rows for `thread_id`. Does nothing when the
+/// thread history database has never been created.
+pub async fn delete_thread_history(sqlite_home: &Path, thread_id: ThreadId) -> anyhow::Result<()> {
+    if !tokio::fs::try_exists(thread_history_db_path(sqlite_home)).await? {
+        return Ok(());
+    }
+    let pool = open_thread_history_db(sqlite_home).await?;
+    let thread_id = thread_id.to_string();
+    let mut transaction = pool.begin_with("BEGIN IMMEDIATE").await?;
+    for statement in [
+        "DELETE FROM thread_items WHERE thread_id = ?",
+        "DELETE FROM thread_turns WHERE thread_id = ?",
+        "DELETE FROM thread_history_projection_state WHERE thread_id = ?",
+    ] {
+        sqlx::query(statement)
+            .bind(thread_id.as_str())
+            .execute(&mut *transaction)
+            .await?;
+    }
+    transaction.commit().await?;
+    pool.close().await;
+    Ok(())
+}
+
 async fn open_sqlite(
     sqlite: &SqliteConfig,
     path: &Path,
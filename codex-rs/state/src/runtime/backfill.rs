@@ -102,6 +102,26 @@ WHERE id = 1
         Ok(())
     }
 
+    /// Forget backfill progress so the next backfill rescans every rollout.
+    ///
+    /// Existing thread rows are kept and overwritten as the rescan reaches
+    /// them, so listings keep working while the rebuild runs.
+    pub async fn reset_backfill(&self) -> anyhow::Result<()> {
+        self.ensure_backfill_state_row().await?;
+        sqlx::query(
+            r#"
+UPDATE backfill_state
+SET status = ?, last_watermark = NULL, updated_at = ?
+WHERE id = 1
+            "#,
+        )
+        .bind(crate::BackfillStatus::Pending.as_str())
+        .bind(Utc::now().timestamp())
+        .execute(self.pool.as_ref())
+        .await?;
+        Ok(())
+    }
+
     async fn ensure_backfill_state_row(&self) -> anyhow::Result<()> {
         ensure_backfill_state_row_in_pool(self.pool.as_ref()).await
     }
@@ -169,6 +189,39 @@ mod tests {
         let _ = tokio::fs::remove_dir_all(codex_home).await;
     }
 
+    #[tokio::test]
+    async fn reset_backfill_clears_progress_but_keeps_last_success() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+            .await
+            .expect("initialize runtime");
+        runtime
+            .mark_backfill_complete(Some("sessions/2026/01/28/rollout-b.jsonl"))
+            .await
+            .expect("mark backfill complete");
+        let completed = runtime
+            .get_backfill_state()
+            .await
+            .expect("get completed backfill state");
+
+        runtime.reset_backfill().await.expect("reset backfill");
+
+        let reset = runtime
+            .get_backfill_state()
+            .await
+            .expect("get reset backfill state");
+        assert_eq!(
+            reset,
+            crate::BackfillState {
+                status: crate::BackfillStatus::Pending,
+                last_watermark: None,
+                last_success_at: completed.last_success_at,
+            }
+        );
+
+        let _ = tokio::fs::remove_dir_all(codex_home).await;
+    }
+
     #[tokio::test]
     async fn get_backfill_state_succeeds_while_another_connection_holds_writer_slot() {
         let codex_home = unique_temp_dir();
@@ -2,6 +2,7 @@ use super::threads::ThreadFilterOptions;
 use super::threads::push_thread_filters;
 use super::*;
 use crate::SortDirection;
+use crate::ThreadTimeBounds;
 use crate::model::Phase2JobClaimOutcome;
 use crate::model::Stage1JobClaim;
 use crate::model::Stage1JobClaimOutcome;
@@ -212,6 +213,7 @@ FROM threads
                 sort_key: SortKey::UpdatedAt,
                 sort_direction: SortDirection::Desc,
                 search_term: None,
+                time_bounds: ThreadTimeBounds::default(),
             },
             /*include_thread_id_tiebreaker*/ false,
         );
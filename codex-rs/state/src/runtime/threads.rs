@@ -1,5 +1,6 @@
 use super::*;
 use crate::SortDirection;
+use crate::ThreadTimeBounds;
 use codex_protocol::protocol::SessionSource;
 use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
@@ -376,6 +377,7 @@ ON CONFLICT(child_thread_id) DO NOTHING
                 sort_key: crate::SortKey::UpdatedAt,
                 sort_direction: SortDirection::Desc,
                 search_term: None,
+                time_bounds: ThreadTimeBounds::default(),
             },
             /*include_thread_id_tiebreaker*/ false,
         );
@@ -500,6 +502,7 @@ ON CONFLICT(child_thread_id) DO NOTHING
                 sort_key,
                 sort_direction: SortDirection::Desc,
                 search_term: None,
+                time_bounds: ThreadTimeBounds::default(),
             },
             sort_key == crate::SortKey::RecencyAt,
         );
@@ -1205,6 +1208,7 @@ pub(super) fn extract_memory_mode(items: &[RolloutItem]) -> Option<String> {
         | RolloutItem::InterAgentCommunicationMetadata { .. }
         | RolloutItem::Compacted(_)
         | RolloutItem::TurnContext(_)
+        | RolloutItem::TurnSummary(_)
         | RolloutItem::WorldState(_)
         | RolloutItem::EventMsg(_) => None,
     })
@@ -1226,6 +1230,7 @@ pub struct ThreadFilterOptions<'a> {
     pub sort_key: SortKey,
     pub sort_direction: SortDirection,
     pub search_term: Option<&'a str>,
+    pub time_bounds: ThreadTimeBounds,
 }
 
 pub(super) fn push_thread_filters<'a>(
@@ -1242,6 +1247,7 @@ pub(super) fn push_thread_filters<'a>(
         sort_key,
         sort_direction,
         search_term,
+        time_bounds,
     } = options;
     builder.push(" WHERE 1 = 1");
     if archived_only {
@@ -1291,6 +1297,18 @@ pub(super) fn push_thread_filters<'a>(
         builder.push_bind(search_term);
         builder.push(") > 0)");
     }
+    push_time_bounds(
+        builder,
+        "threads.created_at_ms",
+        time_bounds.created_after,
+        time_bounds.created_before,
+    );
+    push_time_bounds(
+        builder,
+        "threads.updated_at_ms",
+        time_bounds.updated_after,
+        time_bounds.updated_before,
+    );
     if let Some(anchor) = anchor {
         let anchor_ts = datetime_to_epoch_millis(anchor.ts);
         let column = match sort_key {
@@ -1323,6 +1341,26 @@ pub(super) fn push_thread_filters<'a>(
     }
 }
 
+fn push_time_bounds(
+    builder: &mut QueryBuilder<Sqlite>,
+    column: &str,
+    after: Option<DateTime<Utc>>,
+    before: Option<DateTime<Utc>>,
+) {
+    if let Some(after) = after {
+        builder.push(" AND ");
+        builder.push(column);
+        builder.push(" >= ");
+        builder.push_bind(datetime_to_epoch_millis(after));
+    }
+    if let Some(before) = before {
+        builder.push(" AND ");
+        builder.push(column);
+        builder.push(" < ");
+        builder.push_bind(datetime_to_epoch_millis(before));
+    }
+}
+
 /// Controls whether SQLite may use the ordered column to satisfy `ORDER BY` from an index.
 ///
 /// Disabling it adds a unary `+` to the ordered column. This preserves the sort semantics while
@@ -1632,6 +1670,7 @@ mod tests {
                     sort_key: SortKey::UpdatedAt,
                     sort_direction: SortDirection::Asc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -1660,6 +1699,7 @@ mod tests {
                     sort_key: SortKey::UpdatedAt,
                     sort_direction: SortDirection::Asc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -1713,6 +1753,7 @@ mod tests {
                     sort_key: SortKey::UpdatedAt,
                     sort_direction: SortDirection::Desc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -1745,6 +1786,7 @@ mod tests {
                     sort_key: SortKey::UpdatedAt,
                     sort_direction: SortDirection::Desc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -1770,6 +1812,7 @@ mod tests {
                     sort_key: SortKey::UpdatedAt,
                     sort_direction: SortDirection::Desc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -1834,6 +1877,7 @@ mod tests {
                         sort_key,
                         sort_direction: SortDirection::Desc,
                         search_term: None,
+                        time_bounds: ThreadTimeBounds::default(),
                     },
                     /*relation_filter*/ None,
                     /*limit*/ 201,
@@ -1927,6 +1971,7 @@ mod tests {
                 sort_key: SortKey::CreatedAt,
                 sort_direction: SortDirection::Desc,
                 search_term: None,
+                time_bounds: ThreadTimeBounds::default(),
             },
             Some(crate::ThreadRelationFilter::DescendantsOf(parent_id)),
             /*limit*/ 10,
@@ -1955,6 +2000,7 @@ mod tests {
             sort_key: SortKey::CreatedAt,
             sort_direction: SortDirection::Desc,
             search_term: None,
+            time_bounds: ThreadTimeBounds::default(),
         };
         let first_page = runtime
             .list_threads_by_parent(/*page_size*/ 1, parent_id, filters(None))
@@ -2599,6 +2645,7 @@ mod tests {
                     sort_key: SortKey::RecencyAt,
                     sort_direction: SortDirection::Desc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -2631,6 +2678,7 @@ mod tests {
                     sort_key: SortKey::RecencyAt,
                     sort_direction: SortDirection::Desc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -2663,6 +2711,7 @@ mod tests {
                     sort_key: SortKey::RecencyAt,
                     sort_direction: SortDirection::Desc,
                     search_term: None,
+                    time_bounds: ThreadTimeBounds::default(),
                 },
             )
             .await
@@ -2678,6 +2727,87 @@ mod tests {
         assert_eq!(third_page.next_anchor, None);
     }
 
+    #[tokio::test]
+    async fn list_threads_time_bounds_include_start_and_exclude_end() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+            .await
+            .expect("state db should initialize");
+        let base_ms = 1_700_003_000_000;
+        let thread_ids = [
+            ThreadId::from_string("00000000-0000-0000-0000-000000000811").expect("valid thread id"),
+            ThreadId::from_string("00000000-0000-0000-0000-000000000812").expect("valid thread id"),
+            ThreadId::from_string("00000000-0000-0000-0000-000000000813").expect("valid thread id"),
+        ];
+        let timestamp = |offset_ms: i64| {
+            DateTime::<Utc>::from_timestamp_millis(base_ms + offset_ms).expect("timestamp")
+        };
+        for (index, thread_id) in thread_ids.into_iter().enumerate() {
+            let offset_ms = index as i64 * 1_000;
+            let mut metadata = test_thread_metadata(&codex_home, thread_id, codex_home.clone());
+            metadata.created_at = timestamp(offset_ms);
+            metadata.updated_at = timestamp(10_000 + offset_ms);
+            metadata.recency_at = metadata.updated_at;
+            runtime
+                .upsert_thread(&metadata)
+                .await
+                .expect("thread insert should succeed");
+        }
+
+        let list = |time_bounds| {
+            let runtime = &runtime;
+            async move {
+                runtime
+                    .list_threads(
+                        /*page_size*/ 10,
+                        ThreadFilterOptions {
+                            archived_only: false,
+                            allowed_sources: &[],
+                            model_providers: None,
+                            cwd_filters: None,
+                            anchor: None,
+                            sort_key: SortKey::CreatedAt,
+                            sort_direction: SortDirection::Asc,
+                            search_term: None,
+                            time_bounds,
+                        },
+                    )
+                    .await
+                    .expect("list should succeed")
+                    .items
+                    .into_iter()
+                    .map(|item| item.id)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(
+            list(ThreadTimeBounds {
+                created_after: Some(timestamp(1_000)),
+                created_before: Some(timestamp(2_000)),
+                ..Default::default()
+            })
+            .await,
+            vec![thread_ids[1]]
+        );
+        assert_eq!(
+            list(ThreadTimeBounds {
+                updated_after: Some(timestamp(11_000)),
+                ..Default::default()
+            })
+            .await,
+            vec![thread_ids[1], thread_ids[2]]
+        );
+        assert_eq!(
+            list(ThreadTimeBounds {
+                updated_before: Some(timestamp(11_000)),
+                ..Default::default()
+            })
+            .await,
+            vec![thread_ids[0]]
+        );
+    }
+
     #[tokio::test]
     async fn thread_updated_at_uses_unique_epoch_millis_and_reads_legacy_seconds() {
         let codex_home = unique_temp_dir();
@@ -4,6 +4,7 @@ use std::io::IsTerminal;
 use std::io::Read;
 use std::io::Write;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Context;
 use anyhow::bail;
@@ -233,12 +234,15 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         workspace_roots: vec![cwd],
         workspace_roots_explicit: false,
         cli_auth_credentials_store_mode: AuthCredentialsStoreMode::File,
+        auth_profile: "default".to_string(),
+        chatgpt_token_refresh_margin: Duration::from_secs(5 * 60),
         mcp_servers: Constrained::allow_any(HashMap::new()),
         mcp_oauth_credentials_store_mode: OAuthCredentialsStoreMode::File,
         mcp_oauth_callback_port: None,
         mcp_oauth_callback_url: None,
         model_providers,
         project_doc_max_bytes: 32 * 1024,
+        project_doc_max_depth: 64,
         project_doc_fallback_filenames: Vec::new(),
         tool_output_token_limit: None,
         agents_enabled: true,
@@ -270,6 +274,7 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         model_catalog: None,
         model_verbosity: None,
         chatgpt_base_url: "https://chatgpt.com/backend-api/".to_string(),
+        rate_limits_timeout: None,
         respect_system_proxy: false,
         apps_mcp_product_sku: None,
         realtime_audio: RealtimeAudioConfig::default(),
@@ -287,6 +292,8 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         web_search_mode: Constrained::allow_any(WebSearchMode::Disabled),
         web_search_config: None,
         experimental_request_user_input_enabled: true,
+        disabled_builtin_tools: Vec::new(),
+        builtin_tool_timeouts: Default::default(),
         code_mode: Default::default(),
         use_experimental_unified_exec_tool: false,
         background_terminal_max_timeout: 300_000,
@@ -305,6 +312,9 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         feedback_enabled: false,
         tool_suggest: ToolSuggestConfig::default(),
         otel: OtelConfig::default(),
+        http_network: Default::default(),
+        rate_limit_warn_at: Vec::new(),
+        rate_limit_auto_throttle: false,
     };
     config
         .features
@@ -42,7 +42,9 @@ pub(super) async fn create_thread(
         .with_multi_agent_version(params.multi_agent_version)
         .with_history_mode(params.history_mode)
         .with_subagent_history_start_ordinal(params.subagent_history_start_ordinal)
-        .with_initial_window_id(params.initial_window_id),
+        .with_initial_window_id(params.initial_window_id)
+        .with_encryption(store.config.encrypt_history)
+        .with_history_sink(store.history_sink.clone()),
     )
     .await
     .map_err(|err| ThreadStoreError::Internal {
@@ -92,10 +92,12 @@ fn delete_rollout_file(
     thread_id: codex_protocol::ThreadId,
 ) -> ThreadStoreResult<bool> {
     let plain_path = codex_rollout::plain_rollout_path(rollout_path);
-    let compressed_path = plain_path.with_extension("jsonl.zst");
-    let deleted_plain = delete_rollout_path(store, plain_path.as_path(), thread_id)?;
-    let deleted_compressed = delete_rollout_path(store, compressed_path.as_path(), thread_id)?;
-    Ok(deleted_plain || deleted_compressed)
+    let mut deleted = delete_rollout_path(store, plain_path.as_path(), thread_id)?;
+    for extension in ["jsonl.zst", "jsonl.gz"] {
+        let compressed_path = plain_path.with_extension(extension);
+        deleted |= delete_rollout_path(store, compressed_path.as_path(), thread_id)?;
+    }
+    Ok(deleted)
 }
 
 fn delete_rollout_path(
@@ -33,6 +33,8 @@ pub(super) async fn list_threads(
         ThreadSortKey::CreatedAt => codex_rollout::ThreadSortKey::CreatedAt,
         ThreadSortKey::UpdatedAt => codex_rollout::ThreadSortKey::UpdatedAt,
         ThreadSortKey::RecencyAt => codex_rollout::ThreadSortKey::RecencyAt,
+        ThreadSortKey::FileSize => codex_rollout::ThreadSortKey::FileSize,
+        ThreadSortKey::TotalTokens => codex_rollout::ThreadSortKey::TotalTokens,
     };
     let sort_direction = match params.sort_direction {
         SortDirection::Asc => codex_rollout::SortDirection::Asc,
@@ -119,6 +121,7 @@ pub(super) async fn list_rollout_threads(
             Some(relation_filter),
             params.archived,
             params.search_term.as_deref(),
+            codex_state::ThreadTimeBounds::default(),
         )
         .await
         .ok_or_else(|| ThreadStoreError::Internal {
@@ -185,6 +188,7 @@ pub(super) async fn list_rollout_threads(
             params.cwd_filters.as_deref(),
             default_model_provider_id,
             params.search_term.as_deref(),
+            codex_state::ThreadTimeBounds::default(),
         )
         .await
     };
@@ -20,6 +20,7 @@ mod test_support;
 
 use codex_protocol::ThreadId;
 use codex_protocol::protocol::ThreadHistoryMode;
+use codex_rollout::HistorySink;
 use codex_rollout::RolloutRecorder;
 use codex_rollout::StateDbHandle;
 use std::collections::HashMap;
@@ -77,6 +78,7 @@ pub struct LocalThreadStore {
     live_writer_locks: Arc<LiveWriterLocks>,
     state_db: Option<StateDbHandle>,
     thread_history_db: Arc<OnceCell<sqlx::SqlitePool>>,
+    history_sink: Option<HistorySink>,
 }
 
 struct LiveRecorderEntry {
@@ -117,6 +119,8 @@ pub struct LocalThreadStoreConfig {
     pub sqlite_home: PathBuf,
     /// Provider used only when older local metadata does not contain one.
     pub default_model_provider_id: String,
+    /// Whether new rollouts are encrypted at rest (`history.encrypt`).
+    pub encrypt_history: bool,
 }
 
 impl LocalThreadStoreConfig {
@@ -125,6 +129,7 @@ impl LocalThreadStoreConfig {
             codex_home: config.codex_home().to_path_buf(),
             sqlite_home: config.sqlite_home().to_path_buf(),
             default_model_provider_id: config.model_provider_id().to_string(),
+            encrypt_history: config.encrypt_history(),
         }
     }
 }
@@ -146,9 +151,17 @@ impl LocalThreadStore {
             live_writer_locks: Arc::new(LiveWriterLocks::default()),
             state_db,
             thread_history_db: Arc::new(OnceCell::new()),
+            history_sink: None,
         }
     }
 
+    /// Streams every item written by this store's recorders to `history_sink`
+    /// (`history.sink`).
+    pub fn with_history_sink(mut self, history_sink: Option<HistorySink>) -> Self {
+        self.history_sink = history_sink;
+        self
+    }
+
     /// Return the state DB handle used by local rollout writers.
     pub async fn state_db(&self) -> Option<StateDbHandle> {
         self.state_db.clone()
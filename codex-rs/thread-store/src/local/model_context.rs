@@ -59,10 +59,8 @@ pub(super) async fn load_latest_model_context(
     }
 
     let items = if matches!(session_meta.meta.history_mode, ThreadHistoryMode::Paginated)
-        && !path
-            .file_name()
-            .and_then(|file_name| file_name.to_str())
-            .is_some_and(|file_name| file_name.ends_with(".jsonl.zst"))
+        && !codex_rollout::is_compressed_rollout_path(path.as_path())
+        && !codex_rollout::is_encrypted_rollout_path(path.as_path())
     {
         scan_model_context_from_end(path, session_meta).await?
     } else {
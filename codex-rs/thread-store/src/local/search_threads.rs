@@ -52,6 +52,11 @@ pub(super) async fn search_threads(
         ThreadSortKey::CreatedAt => codex_rollout::ThreadSortKey::CreatedAt,
         ThreadSortKey::UpdatedAt => codex_rollout::ThreadSortKey::UpdatedAt,
         ThreadSortKey::RecencyAt => codex_rollout::ThreadSortKey::RecencyAt,
+        ThreadSortKey::FileSize | ThreadSortKey::TotalTokens => {
+            return Err(ThreadStoreError::InvalidRequest {
+                message: "thread/search only supports timestamp sort keys".to_string(),
+            });
+        }
     };
     let sort_direction = match params.sort_direction {
         SortDirection::Asc => codex_rollout::SortDirection::Asc,
@@ -187,10 +192,14 @@ fn cursor_from_thread_search_item(
             .as_deref()
             .or(item.item.updated_at.as_deref())
             .or(item.item.created_at.as_deref())?,
+        ThreadSortKey::FileSize | ThreadSortKey::TotalTokens => return None,
     };
     match sort_key {
         ThreadSortKey::RecencyAt => parse_cursor(&format!("{timestamp}|{}", item.item.thread_id?)),
-        ThreadSortKey::CreatedAt | ThreadSortKey::UpdatedAt => parse_cursor(timestamp),
+        ThreadSortKey::CreatedAt
+        | ThreadSortKey::UpdatedAt
+        | ThreadSortKey::FileSize
+        | ThreadSortKey::TotalTokens => parse_cursor(timestamp),
     }
 }
 
@@ -297,6 +297,7 @@ impl ThreadMetadataSync {
                 | RolloutItem::InterAgentCommunication(_)
                 | RolloutItem::InterAgentCommunicationMetadata { .. }
                 | RolloutItem::Compacted(_)
+                | RolloutItem::TurnSummary(_)
                 | RolloutItem::WorldState(_) => {}
             }
         }
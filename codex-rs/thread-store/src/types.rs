@@ -207,6 +207,10 @@ pub enum ThreadSortKey {
     UpdatedAt,
     /// Sort by the thread's product recency timestamp.
     RecencyAt,
+    /// Sort by the size of the thread's rollout file.
+    FileSize,
+    /// Sort by the thread's total token usage.
+    TotalTokens,
 }
 
 /// The direction to use when listing stored threads.
@@ -27,6 +27,7 @@ pub(super) fn event_is_notice(event: &ThreadBufferedEvent) -> bool {
         ThreadBufferedEvent::Notification(
             ServerNotification::Warning(_)
                 | ServerNotification::GuardianWarning(_)
+                | ServerNotification::AccountRateLimitWarning(_)
                 | ServerNotification::ConfigWarning(_)
         )
     )
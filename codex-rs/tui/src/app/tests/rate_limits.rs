@@ -20,12 +20,14 @@ fn rate_limit_snapshot(
     RateLimitSnapshot {
         limit_id: Some("codex".to_string()),
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent,
             window_duration_mins: Some(300),
             resets_at: None,
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: Some(CreditsSnapshot {
             has_credits: true,
             unlimited: false,
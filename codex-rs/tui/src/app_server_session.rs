@@ -1932,12 +1932,14 @@ mod tests {
         RateLimitSnapshot {
             limit_id: Some(limit_id.to_string()),
             limit_name: None,
+            scope: None,
             primary: Some(codex_app_server_protocol::RateLimitWindow {
                 used_percent: 0,
                 window_duration_mins: Some(10_080),
                 resets_at: None,
             }),
             secondary: None,
+            extra: Vec::new(),
             credits: None,
             individual_limit: None,
             spend_control_reached: None,
@@ -154,6 +154,9 @@ impl ChatWidget {
             ServerNotification::GuardianWarning(notification) => {
                 self.on_warning(notification.message)
             }
+            ServerNotification::AccountRateLimitWarning(notification) => {
+                self.on_rate_limit_threshold_warning(notification)
+            }
             ServerNotification::DeprecationNotice(notification) => {
                 self.on_deprecation_notice(notification.summary, notification.details)
             }
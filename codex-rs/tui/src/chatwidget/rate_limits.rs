@@ -249,7 +249,11 @@ impl ChatWidget {
                     RateLimitSwitchPromptState::Idle => {}
                 }
             }
-            let should_warn_about_rate_limit_usage = is_codex_limit && !has_workspace_credits;
+            // Configured `[limits] warn_at` thresholds are evaluated by core and arrive as
+            // dedicated warning notifications, so skip the built-in thresholds.
+            let should_warn_about_rate_limit_usage = is_codex_limit
+                && !has_workspace_credits
+                && self.config.rate_limit_warn_at.is_empty();
             let warnings = if should_warn_about_rate_limit_usage {
                 self.rate_limit_warnings.take_warnings(
                     snapshot
@@ -319,6 +323,30 @@ impl ChatWidget {
         self.refresh_status_line();
     }
 
+    pub(super) fn on_rate_limit_threshold_warning(
+        &mut self,
+        notification: AccountRateLimitWarningNotification,
+    ) {
+        let is_secondary = matches!(notification.window_kind, RateLimitWindowKind::Secondary);
+        let limit_label =
+            limit_label_for_window(notification.window.window_duration_mins, is_secondary);
+        let used_percent = notification.window.used_percent;
+        let threshold_percent = notification.threshold_percent;
+        let resets = notification
+            .window
+            .resets_at
+            .and_then(|seconds| DateTime::<Utc>::from_timestamp(seconds, 0))
+            .map(|dt| {
+                let reset = format_reset_timestamp(dt.with_timezone(&Local), Local::now());
+                format!(" Resets at {reset}.")
+            })
+            .unwrap_or_default();
+        self.add_to_history(history_cell::new_warning_event(format!(
+            "Rate limit warning: {used_percent}% of your {limit_label} limit used (warn_at {threshold_percent}%).{resets} Run /status for a breakdown."
+        )));
+        self.request_redraw();
+    }
+
     pub(super) fn stop_rate_limit_poller(&mut self) {}
 
     #[cfg_attr(not(test), allow(dead_code))]
@@ -93,9 +93,11 @@ pub(super) use codex_app_server_protocol::PluginMarketplaceEntry;
 pub(super) use codex_app_server_protocol::PluginReadResponse;
 pub(super) use codex_app_server_protocol::PluginSource;
 pub(super) use codex_app_server_protocol::PluginSummary;
+pub(super) use codex_app_server_protocol::AccountRateLimitWarningNotification;
 pub(super) use codex_app_server_protocol::RateLimitReachedType;
 pub(super) use codex_app_server_protocol::RateLimitSnapshot;
 pub(super) use codex_app_server_protocol::RateLimitWindow;
+pub(super) use codex_app_server_protocol::RateLimitWindowKind;
 pub(super) use codex_app_server_protocol::ReasoningSummaryTextDeltaNotification;
 pub(super) use codex_app_server_protocol::ReviewTarget;
 pub(super) use codex_app_server_protocol::ServerNotification;
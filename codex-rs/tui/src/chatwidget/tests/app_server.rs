@@ -731,6 +731,36 @@ async fn live_app_server_guardian_warning_notification_renders_message() {
     );
 }
 
+#[tokio::test]
+async fn live_app_server_rate_limit_warning_renders_threshold_and_window() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    chat.handle_server_notification(
+        ServerNotification::AccountRateLimitWarning(AccountRateLimitWarningNotification {
+            thread_id: "thread-1".to_string(),
+            limit_id: Some("codex".to_string()),
+            limit_name: None,
+            window_kind: RateLimitWindowKind::Primary,
+            threshold_percent: 90,
+            window: RateLimitWindow {
+                used_percent: 91,
+                window_duration_mins: Some(300),
+                resets_at: None,
+            },
+        }),
+        /*replay_kind*/ None,
+    );
+
+    let cells = drain_insert_history(&mut rx);
+    assert_eq!(cells.len(), 1, "expected one warning history cell");
+    let rendered = lines_to_single_string(&cells[0]);
+    let normalized = rendered.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        normalized.contains("Rate limit warning: 91% of your 5h limit used (warn_at 90%)."),
+        "expected rate limit warning message, got {rendered}"
+    );
+}
+
 #[tokio::test]
 async fn live_app_server_config_warning_prefixes_summary() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
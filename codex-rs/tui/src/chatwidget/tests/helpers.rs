@@ -105,12 +105,14 @@ pub(super) fn snapshot(percent: f64) -> RateLimitSnapshot {
     RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: percent.round() as i32,
             window_duration_mins: Some(60),
             resets_at: None,
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
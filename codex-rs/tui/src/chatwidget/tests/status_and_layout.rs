@@ -584,12 +584,14 @@ async fn status_line_uses_secondary_fallback_for_unsupported_window() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: None,
         secondary: Some(RateLimitWindow {
             used_percent: 50,
             window_duration_mins: Some(2 * 60),
             resets_at: None,
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -610,6 +612,7 @@ async fn status_line_legacy_limit_items_prefer_matching_windows() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 94,
             window_duration_mins: Some(7 * 24 * 60),
@@ -620,6 +623,7 @@ async fn status_line_legacy_limit_items_prefer_matching_windows() {
             window_duration_mins: Some(5 * 60),
             resets_at: None,
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -644,6 +648,7 @@ async fn status_line_shows_secondary_non_weekly_when_primary_is_weekly() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 94,
             window_duration_mins: Some(7 * 24 * 60),
@@ -654,6 +659,7 @@ async fn status_line_shows_secondary_non_weekly_when_primary_is_weekly() {
             window_duration_mins: Some(30 * 24 * 60),
             resets_at: None,
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -678,12 +684,14 @@ async fn status_line_five_hour_item_omits_weekly_only_limit() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 9,
             window_duration_mins: Some(7 * 24 * 60),
             resets_at: None,
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -708,12 +716,14 @@ async fn status_line_single_monthly_primary_omits_weekly_limit_item() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 35,
             window_duration_mins: Some(30 * 24 * 60),
             resets_at: None,
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -738,12 +748,14 @@ async fn status_line_secondary_only_non_weekly_limit_omits_primary_limit_item()
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: None,
         secondary: Some(RateLimitWindow {
             used_percent: 35,
             window_duration_mins: Some(30 * 24 * 60),
             resets_at: None,
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -768,8 +780,10 @@ async fn rate_limit_snapshot_keeps_prior_credits_when_missing_from_headers() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: None,
         secondary: None,
+        extra: Vec::new(),
         credits: Some(CreditsSnapshot {
             has_credits: true,
             unlimited: false,
@@ -790,12 +804,14 @@ async fn rate_limit_snapshot_keeps_prior_credits_when_missing_from_headers() {
     chat.on_rolling_rate_limit_snapshot(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 80,
             window_duration_mins: Some(60),
             resets_at: Some(123),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -861,6 +877,7 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 10,
             window_duration_mins: Some(60),
@@ -871,6 +888,7 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
             window_duration_mins: Some(300),
             resets_at: None,
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: Some(PlanType::Plus),
@@ -882,6 +900,7 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 25,
             window_duration_mins: Some(30),
@@ -892,6 +911,7 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
             window_duration_mins: Some(300),
             resets_at: Some(234),
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: Some(PlanType::Pro),
@@ -903,6 +923,7 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 30,
             window_duration_mins: Some(60),
@@ -913,6 +934,7 @@ async fn rate_limit_snapshot_updates_and_retains_plan_type() {
             window_duration_mins: Some(300),
             resets_at: Some(567),
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -929,12 +951,14 @@ async fn rate_limit_snapshots_keep_separate_entries_per_limit_id() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: Some("codex".to_string()),
         limit_name: Some("codex".to_string()),
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 20,
             window_duration_mins: Some(300),
             resets_at: Some(100),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: Some(CreditsSnapshot {
             has_credits: true,
             unlimited: false,
@@ -949,12 +973,14 @@ async fn rate_limit_snapshots_keep_separate_entries_per_limit_id() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: Some("codex_other".to_string()),
         limit_name: Some("codex_other".to_string()),
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 90,
             window_duration_mins: Some(60),
             resets_at: Some(200),
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: Some(PlanType::Pro),
@@ -1004,12 +1030,14 @@ async fn rate_limit_switch_prompt_skips_non_codex_limit() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: Some("codex_other".to_string()),
         limit_name: Some("codex_other".to_string()),
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 95,
             window_duration_mins: Some(60),
             resets_at: None,
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         plan_type: None,
@@ -65,6 +65,7 @@ fn cache_rate_limit_snapshot(chat: &mut ChatWidget) {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 35,
             window_duration_mins: Some(30 * 24 * 60),
@@ -75,6 +76,7 @@ fn cache_rate_limit_snapshot(chat: &mut ChatWidget) {
             window_duration_mins: Some(7 * 24 * 60),
             resets_at: None,
         }),
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
@@ -240,12 +242,14 @@ async fn status_surface_preview_omits_unavailable_rate_limit_items() {
     chat.on_rate_limit_snapshot(Some(RateLimitSnapshot {
         limit_id: None,
         limit_name: None,
+        scope: None,
         primary: Some(RateLimitWindow {
             used_percent: 9,
             window_duration_mins: Some(7 * 24 * 60),
             resets_at: None,
         }),
         secondary: None,
+        extra: Vec::new(),
         credits: None,
         individual_limit: None,
         spend_control_reached: None,
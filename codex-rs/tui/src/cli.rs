@@ -71,6 +71,12 @@ pub struct Cli {
     #[arg(long = "no-alt-screen", default_value_t = false)]
     pub no_alt_screen: bool,
 
+    /// Draw usage bars with ASCII `#` and `-` instead of block characters.
+    ///
+    /// Implied when the locale does not advertise UTF-8.
+    #[arg(long = "ascii", visible_alias = "plain", default_value_t = false)]
+    pub ascii: bool,
+
     #[clap(skip)]
     pub config_overrides: CliConfigOverrides,
 }
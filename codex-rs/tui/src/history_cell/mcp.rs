@@ -426,6 +426,12 @@ pub(crate) fn new_mcp_tools_output(
                 http_headers,
                 env_http_headers,
                 ..
+            }
+            | McpServerTransportConfig::WebSocket {
+                ws_url: url,
+                http_headers,
+                env_http_headers,
+                ..
             } => {
                 lines.push(vec!["    • URL: ".into(), url.clone().into()].into());
                 if let Some(headers) = http_headers.as_ref()
@@ -8,6 +8,7 @@ use crate::legacy_core::config::ConfigBuilder;
 use crate::legacy_core::config::ConfigOverrides;
 use crate::legacy_core::config::ConfigTomlLoadResult;
 use crate::legacy_core::config::load_config_toml_with_layer_stack;
+use crate::legacy_core::config::resolve_bootstrap_auth_home;
 use crate::legacy_core::config::resolve_bootstrap_auth_keyring_backend_kind;
 use crate::legacy_core::config::resolve_bootstrap_auth_route_config;
 use crate::legacy_core::config::resolve_oss_provider;
@@ -46,6 +47,7 @@ use codex_config::format_config_error_with_source;
 use codex_config::types::ResumeCwdMode;
 use codex_exec_server::EnvironmentManager;
 use codex_exec_server::ExecServerRuntimePaths;
+use codex_http_client::set_http_network_settings;
 use codex_login::AuthConfig;
 use codex_login::default_client::originator;
 use codex_login::default_client::set_default_client_residency_requirement;
@@ -1030,6 +1032,7 @@ pub async fn run_main(
     )?;
     let cloud_config_bundle = cloud_config_bundle_loader_for_storage(
         codex_home.to_path_buf(),
+        resolve_bootstrap_auth_home(&codex_home, &bootstrap_config)?,
         /*enable_codex_api_key_env*/ false,
         bootstrap_config_toml
             .cli_auth_credentials_store
@@ -1169,6 +1172,7 @@ pub async fn run_main(
         .is_some_and(|table| table.contains_key("log_dir"));
 
     set_default_client_residency_requirement(config.enforce_residency.value());
+    set_http_network_settings(config.http_network.clone());
 
     if let Some(warning) = add_dir_warning_message(
         &cli.add_dir,
@@ -1186,7 +1190,7 @@ pub async fn run_main(
         let auth_route_config = config.auth_route_config();
         #[allow(clippy::print_stderr)]
         if let Err(err) = enforce_login_restrictions(&AuthConfig {
-            codex_home: config.codex_home.to_path_buf(),
+            codex_home: config.auth_home(),
             auth_credentials_store_mode: config.cli_auth_credentials_store_mode,
             keyring_backend_kind: config.auth_keyring_backend_kind(),
             forced_login_method: config.forced_login_method,
@@ -1456,6 +1460,7 @@ async fn run_ratatui_app(
         if show_login_screen && !uses_remote_workspace {
             cloud_config_bundle = cloud_config_bundle_loader_for_storage(
                 initial_config.codex_home.to_path_buf(),
+                initial_config.auth_home(),
                 /*enable_codex_api_key_env*/ false,
                 initial_config.cli_auth_credentials_store_mode,
                 initial_config.auth_keyring_backend_kind(),
@@ -1694,6 +1699,7 @@ async fn run_ratatui_app(
     }
 
     set_default_client_residency_requirement(config.enforce_residency.value());
+    set_http_network_settings(config.http_network.clone());
     let should_show_trust_screen = should_show_trust_screen(&config);
     #[cfg(target_os = "windows")]
     let windows_sandbox_level = crate::windows_sandbox::level_from_config(&config);
@@ -1718,9 +1724,11 @@ async fn run_ratatui_app(
         prompt,
         shared,
         no_alt_screen,
+        ascii,
         ..
     } = cli;
     let images = shared.into_inner().images;
+    crate::status::init_progress_bar_glyphs(ascii);
 
     let use_alt_screen = determine_alt_screen_mode(no_alt_screen, config.tui_alternate_screen);
     tui.set_alt_screen_enabled(use_alt_screen);
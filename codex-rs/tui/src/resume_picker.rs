@@ -616,6 +616,8 @@ fn sort_key_label(sort_key: ThreadSortKey) -> &'static str {
     match sort_key {
         ThreadSortKey::CreatedAt => "Created",
         ThreadSortKey::UpdatedAt | ThreadSortKey::RecencyAt => "Updated",
+        ThreadSortKey::FileSize => "Size",
+        ThreadSortKey::TotalTokens => "Tokens",
     }
 }
 
@@ -1644,7 +1646,10 @@ impl PickerState {
     fn toggle_sort_key(&mut self) {
         self.sort_key = match self.sort_key {
             ThreadSortKey::CreatedAt => ThreadSortKey::UpdatedAt,
-            ThreadSortKey::UpdatedAt | ThreadSortKey::RecencyAt => ThreadSortKey::CreatedAt,
+            ThreadSortKey::UpdatedAt
+            | ThreadSortKey::RecencyAt
+            | ThreadSortKey::FileSize
+            | ThreadSortKey::TotalTokens => ThreadSortKey::CreatedAt,
         };
         self.start_initial_load();
     }
@@ -2644,7 +2649,10 @@ fn render_dense_session_lines(
     let updated = format_relative_time(reference, row.updated_at.or(row.created_at));
     let date = match state.sort_key {
         ThreadSortKey::CreatedAt => created,
-        ThreadSortKey::UpdatedAt | ThreadSortKey::RecencyAt => updated,
+        ThreadSortKey::UpdatedAt
+        | ThreadSortKey::RecencyAt
+        | ThreadSortKey::FileSize
+        | ThreadSortKey::TotalTokens => updated,
     };
     let mut lines = vec![dense_summary_line(DenseSummaryInput {
         marker,
@@ -2773,7 +2781,10 @@ fn render_footer_lines(
 ) -> Vec<Line<'static>> {
     let date = match sort_key {
         ThreadSortKey::CreatedAt => created,
-        ThreadSortKey::UpdatedAt | ThreadSortKey::RecencyAt => updated,
+        ThreadSortKey::UpdatedAt
+        | ThreadSortKey::RecencyAt
+        | ThreadSortKey::FileSize
+        | ThreadSortKey::TotalTokens => updated,
     };
     let mut parts = vec![FooterPart::Date(date.to_string())];
     if show_cwd {
@@ -27,17 +27,21 @@ use ratatui::style::Stylize;
 use std::collections::BTreeSet;
 use std::path::PathBuf;
 use unicode_width::UnicodeWidthStr;
-use url::Url;
 
 use super::account::StatusAccountDisplay;
 use super::format::FieldFormatter;
 use super::format::line_display_width;
 use super::format::push_label;
 use super::format::truncate_line_to_width;
+use super::helpers::ConfigSummaryKey;
 use super::helpers::compose_account_display;
 use super::helpers::compose_model_display;
+use super::helpers::config_summary_value;
 use super::helpers::format_directory_display;
 use super::helpers::format_tokens_compact;
+use super::model_provider::ModelProviderDisplay;
+use super::model_provider::format_model_provider;
+use super::progress_bar::render_status_limit_progress_bar;
 use super::rate_limits::RateLimitSnapshotDisplay;
 use super::rate_limits::StatusRateLimitData;
 use super::rate_limits::StatusRateLimitRow;
@@ -45,7 +49,6 @@ use super::rate_limits::StatusRateLimitValue;
 use super::rate_limits::compose_rate_limit_data;
 use super::rate_limits::compose_rate_limit_data_many;
 use super::rate_limits::format_status_limit_summary;
-use super::rate_limits::render_status_limit_progress_bar;
 use super::remote_connection::RemoteConnectionStatus;
 use crate::wrapping::RtOptions;
 use crate::wrapping::adaptive_wrap_lines;
@@ -110,7 +113,7 @@ struct StatusHistoryCell {
     permissions: String,
     agents_summary: Arc<RwLock<String>>,
     collaboration_mode: Option<String>,
-    model_provider: Option<String>,
+    model_provider: Option<ModelProviderDisplay>,
     remote_connection: Option<RemoteConnectionStatus>,
     show_chatgpt_usage_link: bool,
     account: Option<StatusAccountDisplay>,
@@ -269,15 +272,15 @@ impl StatusHistoryCell {
         let permission_profile = config.permissions.effective_permission_profile();
         let workspace_roots = config.effective_workspace_roots();
         let mut config_entries = vec![
-            ("workdir", config.cwd.display().to_string()),
-            ("model", model_name.to_string()),
-            ("provider", config.model_provider_id.clone()),
+            (ConfigSummaryKey::Workdir, config.cwd.display().to_string()),
+            (ConfigSummaryKey::Model, model_name.to_string()),
+            (ConfigSummaryKey::Provider, config.model_provider_id.clone()),
             (
-                "approval",
+                ConfigSummaryKey::Approval,
                 config.permissions.approval_policy.value().to_string(),
             ),
             (
-                "sandbox",
+                ConfigSummaryKey::Sandbox,
                 summarize_permission_profile(
                     &permission_profile,
                     &config.cwd,
@@ -290,9 +293,9 @@ impl StatusHistoryCell {
                 .unwrap_or_else(|| config.model_reasoning_effort.clone())
                 .map(|effort| effort.to_string())
                 .unwrap_or_else(|| "none".to_string());
-            config_entries.push(("reasoning effort", effort_value));
+            config_entries.push((ConfigSummaryKey::ReasoningEffort, effort_value));
             config_entries.push((
-                "reasoning summaries",
+                ConfigSummaryKey::ReasoningSummaries,
                 config
                     .model_reasoning_summary
                     .map(|summary| summary.to_string())
@@ -300,11 +303,9 @@ impl StatusHistoryCell {
             ));
         }
         let (model_name, model_details) = compose_model_display(model_name, &config_entries);
-        let approval = config_entries
-            .iter()
-            .find(|(k, _)| *k == "approval")
-            .map(|(_, v)| v.clone())
-            .unwrap_or_else(|| "<unknown>".to_string());
+        let approval = config_summary_value(&config_entries, ConfigSummaryKey::Approval)
+            .unwrap_or("<unknown>")
+            .to_string();
         let active_permission_profile = config.permissions.active_permission_profile();
         let sandbox =
             status_permission_summary(&permission_profile, &config.cwd, workspace_roots.as_slice());
@@ -826,7 +827,10 @@ impl HistoryCell for StatusHistoryCell {
 
         lines.push(formatter.line("Model", model_spans));
         if let Some(model_provider) = self.model_provider.as_ref() {
-            lines.push(formatter.line("Model provider", vec![Span::from(model_provider.clone())]));
+            lines.push(formatter.line(
+                "Model provider",
+                vec![Span::from(model_provider.to_string())],
+            ));
         }
         lines.push(formatter.line("Directory", vec![Span::from(directory_value)]));
         lines.push(formatter.line("Permissions", vec![Span::from(self.permissions.clone())]));
@@ -909,39 +913,3 @@ impl HistoryCell for StatusHistoryCell {
         self.display_hyperlink_lines(width)
     }
 }
-
-fn format_model_provider(config: &Config, runtime_base_url: Option<&str>) -> Option<String> {
-    let provider = &config.model_provider;
-    let name = provider.name.trim();
-    let provider_name = if name.is_empty() {
-        config.model_provider_id.as_str()
-    } else {
-        name
-    };
-    let base_url = runtime_base_url.and_then(sanitize_base_url);
-    let is_default_openai = provider.is_openai() && base_url.is_none();
-    if is_default_openai {
-        return None;
-    }
-
-    Some(match base_url {
-        Some(base_url) => format!("{provider_name} - {base_url}"),
-        None => provider_name.to_string(),
-    })
-}
-
-fn sanitize_base_url(raw: &str) -> Option<String> {
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-
-    let Ok(mut url) = Url::parse(trimmed) else {
-        return None;
-    };
-    let _ = url.set_username("");
-    let _ = url.set_password(None);
-    url.set_query(None);
-    url.set_fragment(None);
-    Some(url.to_string().trim_end_matches('/').to_string()).filter(|value| !value.is_empty())
-}
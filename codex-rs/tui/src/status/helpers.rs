@@ -7,6 +7,8 @@ use chrono::Local;
 use codex_protocol::account::PlanType;
 use codex_utils_path_uri::PathConvention;
 use codex_utils_path_uri::PathUri;
+use serde::Serialize;
+use std::fmt;
 use std::path::Path;
 use unicode_width::UnicodeWidthStr;
 
@@ -14,15 +16,38 @@ fn normalize_agents_display_path(path: &Path) -> String {
     dunce::simplified(path).display().to_string()
 }
 
+/// Identifies an entry in the `/status` config summary, so consumers pick
+/// entries by variant rather than by their display label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ConfigSummaryKey {
+    Workdir,
+    Model,
+    Provider,
+    Approval,
+    Sandbox,
+    ReasoningEffort,
+    ReasoningSummaries,
+}
+
+pub(crate) fn config_summary_value(
+    entries: &[(ConfigSummaryKey, String)],
+    key: ConfigSummaryKey,
+) -> Option<&str> {
+    entries
+        .iter()
+        .find(|(entry_key, _)| *entry_key == key)
+        .map(|(_, value)| value.as_str())
+}
+
 pub(crate) fn compose_model_display(
     model_name: &str,
-    entries: &[(&str, String)],
+    entries: &[(ConfigSummaryKey, String)],
 ) -> (String, Vec<String>) {
     let mut details: Vec<String> = Vec::new();
-    if let Some((_, effort)) = entries.iter().find(|(k, _)| *k == "reasoning effort") {
-        details.push(format!("reasoning {}", effort.to_ascii_lowercase()));
+    if let Some(effort) = config_summary_value(entries, ConfigSummaryKey::ReasoningEffort) {
+        details.push(format!("reasoning {}", normalize_reasoning_effort(effort)));
     }
-    if let Some((_, summary)) = entries.iter().find(|(k, _)| *k == "reasoning summaries") {
+    if let Some(summary) = config_summary_value(entries, ConfigSummaryKey::ReasoningSummaries) {
         let summary = summary.trim();
         if summary.eq_ignore_ascii_case("none") || summary.eq_ignore_ascii_case("off") {
             details.push("summaries off".to_string());
@@ -34,59 +59,130 @@ pub(crate) fn compose_model_display(
     (model_name.to_string(), details)
 }
 
-pub(crate) fn compose_agents_summary(config: &Config, paths: &[PathUri]) -> String {
-    let mut rels: Vec<String> = Vec::new();
+/// Maps the effort spellings other providers use, such as numeric levels, onto
+/// the names Codex shows. Values it does not recognize are shown as given.
+fn normalize_reasoning_effort(effort: &str) -> String {
+    let effort = effort.trim();
+    let normalized = match effort
+        .to_ascii_lowercase()
+        .replace(['-', '_', ' '], "")
+        .as_str()
+    {
+        "0" | "off" | "disabled" | "none" => "none",
+        "min" | "minimal" => "minimal",
+        "1" | "lo" | "low" => "low",
+        "2" | "med" | "mid" | "medium" => "medium",
+        "3" | "hi" | "high" => "high",
+        "xhigh" | "extrahigh" | "veryhigh" => "xhigh",
+        "max" => "max",
+        "ultra" => "ultra",
+        _ => return effort.to_string(),
+    };
+    normalized.to_string()
+}
+
+/// An instruction file listed by `/status`, in the order core loaded it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct AgentsDoc {
+    /// Full path of the file, spelled natively even when it came from another
+    /// environment's path convention.
+    pub(crate) absolute: String,
+    /// The path relative to the cwd when that is shorter, otherwise the full
+    /// path.
+    pub(crate) display: String,
+    /// Whether core cut the file short to fit `project_doc_max_bytes`.
+    pub(crate) is_truncated: bool,
+}
+
+impl fmt::Display for AgentsDoc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_truncated {
+            write!(f, "{} (truncated)", self.display)
+        } else {
+            f.write_str(&self.display)
+        }
+    }
+}
+
+pub(crate) fn agents_docs(config: &Config, paths: &[PathUri]) -> Vec<AgentsDoc> {
+    let mut docs = Vec::with_capacity(paths.len());
+    // Project docs share the `project_doc_max_bytes` budget in load order, so
+    // replay it to flag the files core truncated.
+    let mut remaining_project_doc_bytes = config.project_doc_max_bytes as u64;
 
     for path in paths {
         // TODO(anp): Rationalize instruction-source summaries with the TUI's broader foreign-path
         // display strategy once other status surfaces can retain environment-native paths.
-        if path.infer_path_convention() != Some(PathConvention::native()) {
-            rels.push(path.inferred_native_path_string());
-            continue;
-        }
-        let Ok(p) = path.to_abs_path() else {
-            rels.push(path.inferred_native_path_string());
+        let native_path = (path.infer_path_convention() == Some(PathConvention::native()))
+            .then(|| path.to_abs_path().ok())
+            .flatten();
+        let Some(p) = native_path else {
+            let absolute = path.inferred_native_path_string();
+            docs.push(AgentsDoc {
+                display: absolute.clone(),
+                absolute,
+                is_truncated: false,
+            });
             continue;
         };
         let p = p.as_path();
-        let file_name = p
-            .file_name()
-            .map(|name| name.to_string_lossy().to_string())
-            .unwrap_or_else(|| "<unknown>".to_string());
-        let display = if let Some(parent) = p.parent() {
-            if parent == config.cwd.as_path() {
-                file_name.clone()
-            } else {
-                let mut cur = config.cwd.as_path();
-                let mut ups = 0usize;
-                let mut reached = false;
-                while let Some(c) = cur.parent() {
-                    if cur == parent {
-                        reached = true;
-                        break;
-                    }
-                    cur = c;
-                    ups += 1;
-                }
-                if reached {
-                    let up = format!("..{}", std::path::MAIN_SEPARATOR);
-                    format!("{}{}", up.repeat(ups), file_name)
-                } else if let Ok(stripped) = p.strip_prefix(&config.cwd) {
-                    normalize_agents_display_path(stripped)
-                } else {
-                    normalize_agents_display_path(p)
-                }
-            }
-        } else {
-            normalize_agents_display_path(p)
+        let is_truncated = !p.starts_with(config.codex_home.as_path()) && {
+            let size = std::fs::metadata(p).map_or(0, |metadata| metadata.len());
+            let truncated = size > remaining_project_doc_bytes;
+            remaining_project_doc_bytes = remaining_project_doc_bytes.saturating_sub(size);
+            truncated
         };
-        rels.push(display);
+        docs.push(AgentsDoc {
+            absolute: normalize_agents_display_path(p),
+            display: relativize_for_display(config.cwd.as_path(), p),
+            is_truncated,
+        });
+    }
+
+    docs
+}
+
+/// Shows `path` relative to `cwd`: a file in `cwd` or one of its subdirectories
+/// by its relative path, a file in an ancestor of `cwd` as `../` steps followed
+/// by its name, and anything else as the full path. The walk up from `cwd` stops
+/// below the filesystem root, so files directly under the root are shown in full.
+pub(crate) fn relativize_for_display(cwd: &Path, path: &Path) -> String {
+    let Some(parent) = path.parent() else {
+        return normalize_agents_display_path(path);
+    };
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "<unknown>".to_string());
+    if parent == cwd {
+        return file_name;
+    }
+
+    let mut cur = cwd;
+    let mut ups = 0usize;
+    while let Some(next) = cur.parent() {
+        if cur == parent {
+            let up = format!("..{}", std::path::MAIN_SEPARATOR);
+            return format!("{}{file_name}", up.repeat(ups));
+        }
+        cur = next;
+        ups += 1;
     }
+    match path.strip_prefix(cwd) {
+        Ok(stripped) => normalize_agents_display_path(stripped),
+        Err(_) => normalize_agents_display_path(path),
+    }
+}
 
-    if rels.is_empty() {
+pub(crate) fn compose_agents_summary(config: &Config, paths: &[PathUri]) -> String {
+    let docs = agents_docs(config, paths);
+    if docs.is_empty() {
         "<none>".to_string()
     } else {
-        rels.join(", ")
+        docs.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 }
 
@@ -198,7 +294,9 @@ mod tests {
     use super::*;
     use crate::legacy_core::config::ConfigBuilder;
     use codex_utils_absolute_path::test_support::PathBufExt;
+    use codex_utils_absolute_path::test_support::test_path_buf;
     use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
     use tempfile::TempDir;
 
     async fn test_config(codex_home: &TempDir, cwd: &TempDir) -> Config {
@@ -283,6 +381,98 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn compose_agents_summary_marks_truncated_project_docs() {
+        let codex_home = TempDir::new().expect("temp codex home");
+        let cwd = TempDir::new().expect("temp cwd");
+        let mut config = test_config(&codex_home, &cwd).await;
+        config.project_doc_max_bytes = 4;
+        std::fs::write(cwd.path().join("AGENTS.md"), "abcdefgh").expect("write AGENTS.md");
+
+        assert_eq!(
+            compose_agents_summary(
+                &config,
+                &[PathUri::from_abs_path(&config.cwd.join("AGENTS.md"))]
+            ),
+            "AGENTS.md (truncated)"
+        );
+    }
+
+    #[test]
+    fn relativize_for_display_handles_children_ancestors_and_other_roots() {
+        let cwd = test_path_buf("/repo/app/src");
+        let up = format!("..{}", std::path::MAIN_SEPARATOR);
+        let (other_root, other_root_display) = if cfg!(windows) {
+            (PathBuf::from(r"D:\notes\AGENTS.md"), r"D:\notes\AGENTS.md")
+        } else {
+            (PathBuf::from("/AGENTS.md"), "/AGENTS.md")
+        };
+        let cases = [
+            (
+                test_path_buf("/repo/app/src/AGENTS.md"),
+                "AGENTS.md".to_string(),
+            ),
+            (
+                test_path_buf("/repo/app/src/nested/AGENTS.md"),
+                format!("nested{}AGENTS.md", std::path::MAIN_SEPARATOR),
+            ),
+            (
+                test_path_buf("/repo/app/AGENTS.md"),
+                format!("{up}AGENTS.md"),
+            ),
+            (
+                test_path_buf("/repo/AGENTS.md"),
+                format!("{up}{up}AGENTS.md"),
+            ),
+            (
+                test_path_buf("/repo/lib/AGENTS.md"),
+                test_path_buf("/repo/lib/AGENTS.md").display().to_string(),
+            ),
+            (other_root, other_root_display.to_string()),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(relativize_for_display(&cwd, &path), expected, "{path:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn agents_docs_describes_each_instruction_file() {
+        let codex_home = TempDir::new().expect("temp codex home");
+        let cwd = TempDir::new().expect("temp cwd");
+        let mut config = test_config(&codex_home, &cwd).await;
+        config.project_doc_max_bytes = 4;
+        let parent_doc = config
+            .cwd
+            .parent()
+            .expect("cwd has a parent")
+            .join("AGENTS.md");
+        let cwd_doc = config.cwd.join("AGENTS.md");
+        std::fs::write(cwd_doc.as_path(), "abcdefgh").expect("write AGENTS.md");
+
+        assert_eq!(
+            agents_docs(
+                &config,
+                &[
+                    PathUri::from_abs_path(&parent_doc),
+                    PathUri::from_abs_path(&cwd_doc),
+                ]
+            ),
+            vec![
+                AgentsDoc {
+                    absolute: normalize_agents_display_path(parent_doc.as_path()),
+                    display: format!("..{}AGENTS.md", std::path::MAIN_SEPARATOR),
+                    is_truncated: false,
+                },
+                AgentsDoc {
+                    absolute: normalize_agents_display_path(cwd_doc.as_path()),
+                    display: "AGENTS.md".to_string(),
+                    is_truncated: true,
+                },
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn compose_agents_summary_orders_global_before_project_agents() {
         let codex_home = TempDir::new().expect("temp codex home");
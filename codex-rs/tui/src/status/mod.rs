@@ -10,6 +10,8 @@ mod account;
 mod card;
 mod format;
 mod helpers;
+mod model_provider;
+mod progress_bar;
 mod rate_limits;
 pub(crate) mod remote_connection;
 
@@ -22,8 +24,10 @@ pub(crate) use card::new_status_output_with_rate_limits;
 pub(crate) use card::new_status_output_with_rate_limits_handle;
 pub(crate) use helpers::compose_agents_summary;
 pub(crate) use helpers::format_directory_display;
+pub(crate) use helpers::format_reset_timestamp;
 pub(crate) use helpers::format_tokens_compact;
 pub(crate) use helpers::plan_type_display_name;
+pub(crate) use progress_bar::init_progress_bar_glyphs;
 pub(crate) use rate_limits::RateLimitSnapshotDisplay;
 pub(crate) use rate_limits::RateLimitWindowDisplay;
 #[cfg(test)]
@@ -0,0 +1,86 @@
+//! Model provider details shown by `/status`.
+
+use std::fmt;
+
+use codex_model_provider_info::CHATGPT_CODEX_BASE_URL;
+use codex_model_provider_info::OPENAI_API_BASE_URL;
+use serde::Serialize;
+use url::Url;
+
+use crate::legacy_core::config::Config;
+
+/// The model provider a session talks to, with its base URL stripped of
+/// credentials, query, and fragment. The runtime base URL wins over the
+/// configured one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct ModelProviderDisplay {
+    /// Key of the provider in `model_providers`, such as `openai`.
+    pub(crate) id: String,
+    /// Provider name, falling back to `id` when the name is blank.
+    pub(crate) name: String,
+    pub(crate) base_url: Option<String>,
+    pub(crate) is_openai: bool,
+}
+
+impl ModelProviderDisplay {
+    pub(crate) fn from_config(config: &Config, runtime_base_url: Option<&str>) -> Self {
+        let provider = &config.model_provider;
+        let name = provider.name.trim();
+        Self {
+            id: config.model_provider_id.clone(),
+            name: if name.is_empty() {
+                config.model_provider_id.clone()
+            } else {
+                name.to_string()
+            },
+            base_url: runtime_base_url
+                .or(provider.base_url.as_deref())
+                .and_then(sanitize_base_url),
+            is_openai: provider.is_openai(),
+        }
+    }
+
+    /// Whether this is OpenAI at one of its default endpoints, which `/status`
+    /// does not call out. Any other base URL, such as a proxy, is shown.
+    pub(crate) fn is_default_openai(&self) -> bool {
+        self.is_openai
+            && self.base_url.as_deref().is_none_or(|base_url| {
+                base_url == OPENAI_API_BASE_URL || base_url == CHATGPT_CODEX_BASE_URL
+            })
+    }
+}
+
+impl fmt::Display for ModelProviderDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.base_url.as_deref() {
+            Some(base_url) => write!(f, "{} - {base_url}", self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+/// Returns the provider to show in `/status`, or `None` for the default
+/// OpenAI provider.
+pub(crate) fn format_model_provider(
+    config: &Config,
+    runtime_base_url: Option<&str>,
+) -> Option<ModelProviderDisplay> {
+    Some(ModelProviderDisplay::from_config(config, runtime_base_url))
+        .filter(|provider| !provider.is_default_openai())
+}
+
+fn sanitize_base_url(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let Ok(mut url) = Url::parse(trimmed) else {
+        return None;
+    };
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+    url.set_query(None);
+    url.set_fragment(None);
+    Some(url.to_string().trim_end_matches('/').to_string()).filter(|value| !value.is_empty())
+}
@@ -0,0 +1,107 @@
+//! Fixed-width usage bars for `/status`.
+//!
+//! Bars are drawn with Unicode block characters unless `--ascii` is passed or
+//! the locale does not advertise UTF-8, in which case they fall back to `#`
+//! and `-` so they stay legible in terminals and logs without those glyphs.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+const STATUS_LIMIT_BAR_SEGMENTS: usize = 20;
+const STATUS_LIMIT_BAR_FILLED: &str = "█";
+const STATUS_LIMIT_BAR_EMPTY: &str = "░";
+const STATUS_LIMIT_BAR_FILLED_ASCII: &str = "#";
+const STATUS_LIMIT_BAR_EMPTY_ASCII: &str = "-";
+
+static ASCII_PROGRESS_BARS: AtomicBool = AtomicBool::new(false);
+
+/// Picks the glyphs for every bar drawn afterwards. ASCII is used when `ascii`
+/// is set or the locale does not advertise UTF-8.
+pub(crate) fn init_progress_bar_glyphs(ascii: bool) {
+    let ascii = ascii || !locale_advertises_utf8(|name| std::env::var(name).ok());
+    ASCII_PROGRESS_BARS.store(ascii, Ordering::Relaxed);
+}
+
+/// Renders a fixed-width progress bar from remaining percentage.
+///
+/// This function expects a remaining value in the `0..=100` range and clamps out-of-range input.
+/// Passing a used percentage by mistake will invert the bar and mislead users.
+pub(crate) fn render_status_limit_progress_bar(percent_remaining: f64) -> String {
+    render_progress_bar(
+        percent_remaining,
+        ASCII_PROGRESS_BARS.load(Ordering::Relaxed),
+    )
+}
+
+fn render_progress_bar(percent_remaining: f64, ascii: bool) -> String {
+    let (filled_glyph, empty_glyph) = if ascii {
+        (STATUS_LIMIT_BAR_FILLED_ASCII, STATUS_LIMIT_BAR_EMPTY_ASCII)
+    } else {
+        (STATUS_LIMIT_BAR_FILLED, STATUS_LIMIT_BAR_EMPTY)
+    };
+    let ratio = (percent_remaining / 100.0).clamp(0.0, 1.0);
+    let filled = (ratio * STATUS_LIMIT_BAR_SEGMENTS as f64).round() as usize;
+    let filled = filled.min(STATUS_LIMIT_BAR_SEGMENTS);
+    let empty = STATUS_LIMIT_BAR_SEGMENTS.saturating_sub(filled);
+    format!(
+        "[{}{}]",
+        filled_glyph.repeat(filled),
+        empty_glyph.repeat(empty)
+    )
+}
+
+/// Whether the locale names a UTF-8 character set. As in POSIX, the first
+/// non-empty of `LC_ALL`, `LC_CTYPE`, and `LANG` decides. Windows consoles do
+/// not set these, so an unset locale only means ASCII elsewhere.
+fn locale_advertises_utf8(var: impl Fn(&str) -> Option<String>) -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .into_iter()
+        .find_map(|name| var(name).filter(|value| !value.is_empty()));
+    match locale {
+        Some(locale) => {
+            let locale = locale.to_ascii_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        }
+        None => cfg!(windows),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::locale_advertises_utf8;
+    use super::render_progress_bar;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ascii_bar_matches_the_unicode_bar_width() {
+        assert_eq!(
+            render_progress_bar(/*percent_remaining*/ 50.0, /*ascii*/ true),
+            "[##########----------]"
+        );
+        assert_eq!(
+            render_progress_bar(/*percent_remaining*/ 50.0, /*ascii*/ false),
+            "[██████████░░░░░░░░░░]"
+        );
+    }
+
+    #[test]
+    fn first_non_empty_locale_variable_decides_utf8() {
+        let locale = |vars: &'static [(&'static str, &'static str)]| {
+            locale_advertises_utf8(move |name| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| (*value).to_string())
+            })
+        };
+
+        assert!(locale(&[("LANG", "en_US.UTF-8")]));
+        assert!(locale(&[
+            ("LC_ALL", ""),
+            ("LC_CTYPE", "C.utf8"),
+            ("LANG", "C")
+        ]));
+        assert!(!locale(&[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")]));
+        assert!(!locale(&[("LANG", "POSIX")]));
+        assert_eq!(locale(&[]), cfg!(windows));
+    }
+}
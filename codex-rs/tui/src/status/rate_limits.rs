@@ -15,15 +15,12 @@ use chrono::Duration as ChronoDuration;
 use chrono::Local;
 use chrono::Utc;
 use codex_app_server_protocol::CreditsSnapshot as CoreCreditsSnapshot;
+use codex_app_server_protocol::RateLimitScope;
 use codex_app_server_protocol::RateLimitSnapshot;
 use codex_app_server_protocol::RateLimitWindow;
 use codex_app_server_protocol::SpendControlLimitSnapshot as CoreSpendControlLimitSnapshot;
 use codex_protocol::num_format::format_with_separators;
 
-const STATUS_LIMIT_BAR_SEGMENTS: usize = 20;
-const STATUS_LIMIT_BAR_FILLED: &str = "█";
-const STATUS_LIMIT_BAR_EMPTY: &str = "░";
-
 #[derive(Debug, Clone)]
 pub(crate) struct StatusRateLimitRow {
     /// Human-readable row label, such as `"5h limit"`, `"Monthly limit"`, or `"Credits"`.
@@ -95,12 +92,16 @@ impl RateLimitWindowDisplay {
 pub(crate) struct RateLimitSnapshotDisplay {
     /// Canonical limit identifier (for example: `codex` or `codex_other`).
     pub limit_name: String,
+    /// Whose usage the limit counts, when the backend reported it.
+    pub scope: Option<RateLimitScope>,
     /// Local timestamp representing when this display snapshot was captured.
     pub captured_at: DateTime<Local>,
     /// Primary usage window.
     pub primary: Option<RateLimitWindowDisplay>,
     /// Secondary usage window.
     pub secondary: Option<RateLimitWindowDisplay>,
+    /// Additional usage windows, paired with the label the backend reported them under.
+    pub extra: Vec<(String, RateLimitWindowDisplay)>,
     /// Optional credits metadata when available.
     pub credits: Option<CreditsSnapshotDisplay>,
     /// Optional effective monthly credit limit from workspace spend controls.
@@ -148,6 +149,7 @@ pub(crate) fn rate_limit_snapshot_display_for_limit(
 ) -> RateLimitSnapshotDisplay {
     RateLimitSnapshotDisplay {
         limit_name,
+        scope: snapshot.scope,
         captured_at,
         primary: snapshot
             .primary
@@ -157,6 +159,21 @@ pub(crate) fn rate_limit_snapshot_display_for_limit(
             .secondary
             .as_ref()
             .map(|window| RateLimitWindowDisplay::from_window(window, captured_at)),
+        extra: snapshot
+            .extra
+            .iter()
+            .map(|window| {
+                let display = RateLimitWindowDisplay::from_window(
+                    &RateLimitWindow {
+                        used_percent: window.used_percent,
+                        window_duration_mins: window.window_duration_mins,
+                        resets_at: window.resets_at,
+                    },
+                    captured_at,
+                );
+                (window.label.clone(), display)
+            })
+            .collect(),
         credits: snapshot.credits.as_ref().map(CreditsSnapshotDisplay::from),
         individual_limit: snapshot
             .individual_limit
@@ -228,8 +245,7 @@ pub(crate) fn compose_rate_limit_data_many(
             })
             .unwrap_or(false);
 
-        let limit_bucket_label = snapshot.limit_name.clone();
-        let show_limit_prefix = !limit_bucket_label.eq_ignore_ascii_case("codex");
+        let section_label = limit_section_label(snapshot);
         let primary_label = snapshot
             .primary
             .as_ref()
@@ -242,22 +258,27 @@ pub(crate) fn compose_rate_limit_data_many(
             .as_ref()
             .map(|window| limit_label_for_window(window.window_minutes, /*is_secondary*/ true))
             .map(|label| capitalize_first(&label));
-        let window_count =
-            usize::from(snapshot.primary.is_some()) + usize::from(snapshot.secondary.is_some());
-        let combine_non_codex_single_limit = show_limit_prefix && window_count == 1;
+        let window_count = usize::from(snapshot.primary.is_some())
+            + usize::from(snapshot.secondary.is_some())
+            + snapshot.extra.len();
+        let combine_non_codex_single_limit = section_label.is_some() && window_count == 1;
 
-        if show_limit_prefix && !combine_non_codex_single_limit {
+        if let Some(section_label) = section_label.as_deref()
+            && !combine_non_codex_single_limit
+        {
             rows.push(StatusRateLimitRow {
-                label: format!("{limit_bucket_label} limit"),
+                label: format!("{section_label} limit"),
                 value: StatusRateLimitValue::Text(String::new()),
             });
         }
 
         if let Some(primary) = snapshot.primary.as_ref() {
-            let label = if combine_non_codex_single_limit {
+            let label = if let Some(section_label) = section_label.as_deref()
+                && combine_non_codex_single_limit
+            {
                 format!(
                     "{} {} limit",
-                    limit_bucket_label,
+                    section_label,
                     primary_label.clone().unwrap_or_else(|| capitalize_first(
                         fallback_limit_label(/*is_secondary*/ false)
                     ))
@@ -281,10 +302,12 @@ pub(crate) fn compose_rate_limit_data_many(
         }
 
         if let Some(secondary) = snapshot.secondary.as_ref() {
-            let label = if combine_non_codex_single_limit {
+            let label = if let Some(section_label) = section_label.as_deref()
+                && combine_non_codex_single_limit
+            {
                 format!(
                     "{} {} limit",
-                    limit_bucket_label,
+                    section_label,
                     secondary_label.clone().unwrap_or_else(|| capitalize_first(
                         fallback_limit_label(/*is_secondary*/ true)
                     ))
@@ -307,6 +330,25 @@ pub(crate) fn compose_rate_limit_data_many(
             });
         }
 
+        for (extra_label, window) in &snapshot.extra {
+            let extra_label = capitalize_first(extra_label);
+            let label = if let Some(section_label) = section_label.as_deref()
+                && combine_non_codex_single_limit
+            {
+                format!("{section_label} {extra_label} limit")
+            } else {
+                format!("{extra_label} limit")
+            };
+            rows.push(StatusRateLimitRow {
+                label,
+                value: StatusRateLimitValue::Window {
+                    percent_used: window.used_percent,
+                    resets_at: window.resets_at.clone(),
+                    details: None,
+                },
+            });
+        }
+
         if let Some(credits) = snapshot.credits.as_ref()
             && let Some(row) = credit_status_row(credits)
         {
@@ -336,20 +378,27 @@ pub(crate) fn compose_rate_limit_data_many(
     }
 }
 
-/// Renders a fixed-width progress bar from remaining percentage.
+/// Returns the heading for a snapshot's rows, or `None` for the unscoped default `codex` bucket.
 ///
-/// This function expects a remaining value in the `0..=100` range and clamps out-of-range input.
-/// Passing a used percentage by mistake will invert the bar and mislead users.
-pub(crate) fn render_status_limit_progress_bar(percent_remaining: f64) -> String {
-    let ratio = (percent_remaining / 100.0).clamp(0.0, 1.0);
-    let filled = (ratio * STATUS_LIMIT_BAR_SEGMENTS as f64).round() as usize;
-    let filled = filled.min(STATUS_LIMIT_BAR_SEGMENTS);
-    let empty = STATUS_LIMIT_BAR_SEGMENTS.saturating_sub(filled);
-    format!(
-        "[{}{}]",
-        STATUS_LIMIT_BAR_FILLED.repeat(filled),
-        STATUS_LIMIT_BAR_EMPTY.repeat(empty)
-    )
+/// Scoped limits lead with their scope so personal, organization, and project budgets for the
+/// same bucket stay distinguishable.
+fn limit_section_label(snapshot: &RateLimitSnapshotDisplay) -> Option<String> {
+    let bucket = snapshot.limit_name.as_str();
+    let is_default_bucket = bucket.eq_ignore_ascii_case("codex");
+    match snapshot.scope.map(rate_limit_scope_label) {
+        Some(scope) if is_default_bucket => Some(scope.to_string()),
+        Some(scope) => Some(format!("{scope}: {bucket}")),
+        None if is_default_bucket => None,
+        None => Some(bucket.to_string()),
+    }
+}
+
+fn rate_limit_scope_label(scope: RateLimitScope) -> &'static str {
+    match scope {
+        RateLimitScope::Personal => "Personal",
+        RateLimitScope::Organization => "Organization",
+        RateLimitScope::Project => "Project",
+    }
 }
 
 /// Formats a compact textual summary from remaining percentage.
@@ -357,9 +406,10 @@ pub(crate) fn format_status_limit_summary(percent_remaining: f64) -> String {
     format!("{percent_remaining:.0}% left")
 }
 
-/// Builds a single `StatusRateLimitRow` when workspace credits are available.
+/// Builds a single `StatusRateLimitRow` when workspace credits are known.
 /// Unlimited credits are shown explicitly; finite credits show their rounded
-/// balance or `Available` when the balance is hidden.
+/// balance or `Available` when the balance is hidden. Accounts without credits
+/// still show a reported balance so a depleted account reads `0 credits`.
 fn credit_status_row(credits: &CreditsSnapshotDisplay) -> Option<StatusRateLimitRow> {
     if credits.unlimited {
         return Some(StatusRateLimitRow {
@@ -368,7 +418,11 @@ fn credit_status_row(credits: &CreditsSnapshotDisplay) -> Option<StatusRateLimit
         });
     }
     if !credits.has_credits {
-        return None;
+        let balance = credits.balance.as_deref().and_then(format_credit_amount)?;
+        return Some(StatusRateLimitRow {
+            label: "Credits".to_string(),
+            value: StatusRateLimitValue::Text(format!("{balance} credits")),
+        });
     }
     let value = credits
         .balance
@@ -418,10 +472,13 @@ fn format_credit_amount(raw: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::CreditsSnapshotDisplay;
+    use super::RateLimitScope;
     use super::RateLimitSnapshotDisplay;
     use super::RateLimitWindowDisplay;
     use super::StatusRateLimitData;
+    use super::StatusRateLimitValue;
     use super::compose_rate_limit_data_many;
+    use super::credit_status_row;
     use chrono::Local;
     use pretty_assertions::assert_eq;
 
@@ -438,9 +495,11 @@ mod tests {
         let now = Local::now();
         let codex = RateLimitSnapshotDisplay {
             limit_name: "codex".to_string(),
+            scope: None,
             captured_at: now,
             primary: Some(window(/*used_percent*/ 10.0)),
             secondary: None,
+            extra: Vec::new(),
             credits: Some(CreditsSnapshotDisplay {
                 has_credits: true,
                 unlimited: false,
@@ -450,9 +509,11 @@ mod tests {
         };
         let other = RateLimitSnapshotDisplay {
             limit_name: "codex-other".to_string(),
+            scope: None,
             captured_at: now,
             primary: Some(window(/*used_percent*/ 20.0)),
             secondary: None,
+            extra: Vec::new(),
             credits: Some(CreditsSnapshotDisplay {
                 has_credits: true,
                 unlimited: false,
@@ -484,6 +545,7 @@ mod tests {
         let now = Local::now();
         let other = RateLimitSnapshotDisplay {
             limit_name: "codex-other".to_string(),
+            scope: None,
             captured_at: now,
             primary: Some(RateLimitWindowDisplay {
                 used_percent: 20.0,
@@ -495,6 +557,7 @@ mod tests {
                 resets_at: Some("later".to_string()),
                 window_minutes: Some(2 * 60),
             }),
+            extra: Vec::new(),
             credits: None,
             individual_limit: None,
         };
@@ -513,4 +576,109 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn extra_windows_render_after_secondary() {
+        let now = Local::now();
+        let codex = RateLimitSnapshotDisplay {
+            limit_name: "codex".to_string(),
+            scope: None,
+            captured_at: now,
+            primary: Some(window(/*used_percent*/ 10.0)),
+            secondary: None,
+            extra: vec![("burst".to_string(), window(/*used_percent*/ 55.0))],
+            credits: None,
+            individual_limit: None,
+        };
+        let other = RateLimitSnapshotDisplay {
+            limit_name: "codex-other".to_string(),
+            scope: None,
+            captured_at: now,
+            primary: None,
+            secondary: None,
+            extra: vec![("daily".to_string(), window(/*used_percent*/ 5.0))],
+            credits: None,
+            individual_limit: None,
+        };
+
+        let rows = match compose_rate_limit_data_many(&[codex, other], now) {
+            StatusRateLimitData::Available(rows) => rows,
+            other => panic!("unexpected status: {other:?}"),
+        };
+        let labels: Vec<String> = rows.iter().map(|row| row.label.clone()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "5h limit".to_string(),
+                "Burst limit".to_string(),
+                "codex-other Daily limit".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn scoped_limits_lead_with_their_scope() {
+        let now = Local::now();
+        let snapshot = |limit_name: &str, scope| RateLimitSnapshotDisplay {
+            limit_name: limit_name.to_string(),
+            scope,
+            captured_at: now,
+            primary: Some(window(/*used_percent*/ 10.0)),
+            secondary: Some(window(/*used_percent*/ 30.0)),
+            extra: Vec::new(),
+            credits: None,
+            individual_limit: None,
+        };
+        let project = RateLimitSnapshotDisplay {
+            secondary: None,
+            ..snapshot("codex-other", Some(RateLimitScope::Project))
+        };
+
+        let rows = match compose_rate_limit_data_many(
+            &[
+                snapshot("codex", /*scope*/ None),
+                snapshot("codex", Some(RateLimitScope::Organization)),
+                project,
+            ],
+            now,
+        ) {
+            StatusRateLimitData::Available(rows) => rows,
+            other => panic!("unexpected status: {other:?}"),
+        };
+        let labels: Vec<String> = rows.iter().map(|row| row.label.clone()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "5h limit".to_string(),
+                "5h limit".to_string(),
+                "Organization limit".to_string(),
+                "5h limit".to_string(),
+                "5h limit".to_string(),
+                "Project: codex-other 5h limit".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn credit_row_shows_balance_without_credits_flag() {
+        let row_text = |balance: Option<&str>| {
+            credit_status_row(&CreditsSnapshotDisplay {
+                has_credits: false,
+                unlimited: false,
+                balance: balance.map(str::to_string),
+            })
+            .map(|row| match row.value {
+                StatusRateLimitValue::Text(text) => format!("{}: {text}", row.label),
+                other => panic!("unexpected credits value: {other:?}"),
+            })
+        };
+
+        assert_eq!(row_text(Some("0")), Some("Credits: 0 credits".to_string()));
+        assert_eq!(
+            row_text(Some("1234.6")),
+            Some("Credits: 1,235 credits".to_string())
+        );
+        assert_eq!(row_text(None), None);
+        assert_eq!(row_text(Some("not-a-number")), None);
+    }
 }
@@ -5,10 +5,22 @@
 //! of `-c key=value` (or `--config key=value`) will be collected as a raw
 //! string. Helper methods are provided to convert the raw strings into
 //! key/value pairs as well as to apply them onto a mutable
-//! `serde_json::Value` representing the configuration tree.
+//! `serde_json::Value` representing the configuration tree. `-c @file.toml`
+//! (or `-c @-` for stdin) expands every key in a TOML fragment into overrides;
+//! `.yaml`, `.yml`, and `.json` files are accepted as well.
+
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+use std::sync::OnceLock;
 
 use clap::ArgAction;
 use clap::Parser;
+use codex_config::ConfigFileFormat;
+use codex_config::ConfigOverrideIssue;
+use codex_config::parse_config_document;
+use codex_config::quote_override_path_segment;
+use codex_config::validate_config_override;
 use serde::de::Error as SerdeError;
 use toml::Value;
 
@@ -20,12 +32,15 @@ pub struct CliConfigOverrides {
     /// Override a configuration value that would otherwise be loaded from
     /// `~/.codex/config.toml`. Use a dotted path (`foo.bar.baz`) to override
     /// nested values. The `value` portion is parsed as TOML. If it fails to
-    /// parse as TOML, the raw string is used as a literal.
+    /// parse as TOML, the raw string is used as a literal. `@path` loads
+    /// every key from a TOML, YAML, or JSON file (by extension), and `@-`
+    /// reads a TOML file from stdin.
     ///
     /// Examples:
     ///   - `-c model="o3"`
     ///   - `-c 'sandbox_permissions=["disk-full-read-access"]'`
     ///   - `-c shell_environment_policy.inherit=all`
+    ///   - `-c @overrides.toml`
     #[arg(
         short = 'c',
         long = "config",
@@ -45,42 +60,186 @@ impl CliConfigOverrides {
     }
 
     /// Parse the raw strings captured from the CLI into a list of `(path,
-    /// value)` tuples where `value` is a `serde_json::Value`.
+    /// value)` tuples where `value` is a `serde_json::Value`. `@file`
+    /// overrides expand in place, so later flags still win. Every rejected
+    /// flag is reported, one per line.
     pub fn parse_overrides(&self) -> Result<Vec<(String, Value)>, String> {
-        self.raw_overrides
-            .iter()
-            .map(|s| {
-                // Only split on the *first* '=' so values are free to contain
-                // the character.
-                let mut parts = s.splitn(2, '=');
-                let key = match parts.next() {
-                    Some(k) => k.trim(),
-                    None => return Err("Override missing key".to_string()),
-                };
-                let value_str = parts
-                    .next()
-                    .ok_or_else(|| format!("Invalid override (missing '='): {s}"))?
-                    .trim();
-
-                if key.is_empty() {
-                    return Err(format!("Empty key in override: {s}"));
+        self.parse_overrides_detailed().map_err(|errors| {
+            errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    /// Like [`Self::parse_overrides`], but returns a typed error for every
+    /// flag that fails to parse or does not match the config schema.
+    pub fn parse_overrides_detailed(
+        &self,
+    ) -> Result<Vec<(String, Value)>, Vec<ConfigOverrideError>> {
+        let mut overrides = Vec::new();
+        let mut errors = Vec::new();
+        for flag in &self.raw_overrides {
+            let parsed = match parse_override_flag(flag) {
+                Ok(parsed) => parsed,
+                Err(kind) => {
+                    errors.push(ConfigOverrideError {
+                        flag: flag.clone(),
+                        kind,
+                    });
+                    continue;
+                }
+            };
+            for (key, value) in parsed {
+                match validate_config_override(&key, &value) {
+                    // Keys with no plausible match stay lenient so callers can
+                    // pass through keys this build does not know about;
+                    // `--strict-config` rejects them when the config loads.
+                    Ok(())
+                    | Err(ConfigOverrideIssue::UnknownKey {
+                        suggestion: None, ..
+                    }) => overrides.push((key, value)),
+                    Err(issue) => errors.push(ConfigOverrideError {
+                        flag: flag.clone(),
+                        kind: ConfigOverrideErrorKind::Schema(issue),
+                    }),
                 }
+            }
+        }
+        if errors.is_empty() {
+            Ok(overrides)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// A `-c` flag that could not be turned into config overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigOverrideError {
+    /// The flag exactly as it was passed, without the `-c`.
+    pub flag: String,
+    pub kind: ConfigOverrideErrorKind,
+}
 
-                // Attempt to parse as TOML. If that fails, treat it as a raw
-                // string. This allows convenient usage such as
-                // `-c model=o3` without the quotes.
-                let value: Value = match parse_toml_value(value_str) {
-                    Ok(v) => v,
-                    Err(_) => {
-                        // Strip leading/trailing quotes if present
-                        let trimmed = value_str.trim().trim_matches(|c| c == '"' || c == '\'');
-                        Value::String(trimmed.to_string())
-                    }
-                };
-
-                Ok((canonicalize_override_key(key), value))
-            })
-            .collect()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOverrideErrorKind {
+    /// The flag is not `key=value` or `@path`.
+    Syntax(String),
+    /// An `@path` file could not be read or parsed.
+    File(String),
+    /// The override does not match the `config.toml` schema.
+    Schema(ConfigOverrideIssue),
+}
+
+impl fmt::Display for ConfigOverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flag = &self.flag;
+        match &self.kind {
+            ConfigOverrideErrorKind::Syntax(message) | ConfigOverrideErrorKind::File(message) => {
+                write!(f, "-c {flag}: {message}")
+            }
+            ConfigOverrideErrorKind::Schema(issue) => write!(f, "-c {flag}: {issue}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigOverrideError {}
+
+fn parse_override_flag(flag: &str) -> Result<Vec<(String, Value)>, ConfigOverrideErrorKind> {
+    if let Some(source) = flag.strip_prefix('@') {
+        let contents = if source == "-" {
+            read_stdin_once()
+        } else {
+            std::fs::read_to_string(source).map_err(|err| format!("failed to read file: {err}"))
+        }
+        .map_err(ConfigOverrideErrorKind::File)?;
+        // stdin is always TOML; files are parsed by extension.
+        let format = match source {
+            "-" => ConfigFileFormat::Toml,
+            path => ConfigFileFormat::from_path(Path::new(path)),
+        };
+        let table: toml::Table = match format {
+            ConfigFileFormat::Toml => toml::from_str(&contents).map_err(|err| {
+                ConfigOverrideErrorKind::File(format!("failed to parse TOML: {err}"))
+            })?,
+            ConfigFileFormat::Yaml | ConfigFileFormat::Json => {
+                parse_config_document(Path::new(source), format, &contents).map_err(|err| {
+                    ConfigOverrideErrorKind::File(format!(
+                        "failed to parse {}: line {}, column {}: {}",
+                        format.label(),
+                        err.range.start.line,
+                        err.range.start.column,
+                        err.message
+                    ))
+                })?
+            }
+        };
+        let mut overrides = Vec::new();
+        flatten_override_table(/*prefix*/ None, table, &mut overrides);
+        return Ok(overrides);
+    }
+
+    // Only split on the *first* '=' so values are free to contain the
+    // character.
+    let (key, value_str) = flag.split_once('=').ok_or_else(|| {
+        ConfigOverrideErrorKind::Syntax("missing '=' (expected key=value)".to_string())
+    })?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(ConfigOverrideErrorKind::Syntax("empty key".to_string()));
+    }
+    let value_str = value_str.trim();
+
+    // Attempt to parse as TOML. If that fails, treat it as a raw string. This
+    // allows convenient usage such as `-c model=o3` without the quotes.
+    let value: Value = match parse_toml_value(value_str) {
+        Ok(v) => v,
+        Err(_) => {
+            // Strip leading/trailing quotes if present
+            let trimmed = value_str.trim().trim_matches(|c| c == '"' || c == '\'');
+            Value::String(trimmed.to_string())
+        }
+    };
+    Ok(vec![(canonicalize_override_key(key), value)])
+}
+
+/// Reads stdin for `-c @-` once per process, since several call sites may
+/// parse the same overrides.
+fn read_stdin_once() -> Result<String, String> {
+    static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+    STDIN
+        .get_or_init(|| {
+            let mut contents = String::new();
+            std::io::stdin()
+                .read_to_string(&mut contents)
+                .map(|_| contents)
+                .map_err(|err| format!("failed to read stdin: {err}"))
+        })
+        .clone()
+}
+
+/// Expands a TOML fragment into dotted-path overrides, one per non-table
+/// value, so file overrides merge with existing config like `-c` flags do.
+/// Keys containing dots are quoted so they stay a single path segment.
+fn flatten_override_table(
+    prefix: Option<&str>,
+    table: toml::Table,
+    overrides: &mut Vec<(String, Value)>,
+) {
+    for (key, value) in table {
+        let key = quote_override_path_segment(&key);
+        let path = match prefix {
+            Some(prefix) => format!("{prefix}.{key}"),
+            None => key,
+        };
+        match value {
+            Value::Table(table) if !table.is_empty() => {
+                flatten_override_table(Some(&path), table, overrides);
+            }
+            value => overrides.push((canonicalize_override_key(&path), value)),
+        }
     }
 }
 
@@ -104,6 +263,7 @@ fn parse_toml_value(raw: &str) -> Result<Value, toml::de::Error> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
 
     #[test]
     fn parses_basic_scalar() {
@@ -160,6 +320,250 @@ mod tests {
         );
     }
 
+    #[test]
+    fn file_overrides_expand_in_flag_order() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("overrides.toml");
+        std::fs::write(
+            &path,
+            r#"mcp_servers.docs.args = ["--stdio"]
+mcp_servers.docs.command = "docs-server"
+model = "from-file"
+use_legacy_landlock = true
+"#,
+        )
+        .expect("write overrides file");
+        let overrides = CliConfigOverrides {
+            raw_overrides: vec![
+                r#"model="inline-before""#.to_string(),
+                format!("@{}", path.display()),
+                r#"model="inline-after""#.to_string(),
+            ],
+        };
+
+        assert_eq!(
+            overrides.parse_overrides().expect("parse_overrides"),
+            vec![
+                ("model".to_string(), Value::from("inline-before")),
+                (
+                    "mcp_servers.docs.args".to_string(),
+                    Value::Array(vec![Value::from("--stdio")]),
+                ),
+                (
+                    "mcp_servers.docs.command".to_string(),
+                    Value::from("docs-server"),
+                ),
+                ("model".to_string(), Value::from("from-file")),
+                (
+                    "features.use_legacy_landlock".to_string(),
+                    Value::Boolean(true),
+                ),
+                ("model".to_string(), Value::from("inline-after")),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_overrides_quote_keys_containing_dots() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("overrides.toml");
+        std::fs::write(&path, "[projects.\"/a/b.c\"]\ntrust_level = \"trusted\"\n")
+            .expect("write overrides file");
+        let overrides = CliConfigOverrides {
+            raw_overrides: vec![format!("@{}", path.display())],
+        };
+
+        let parsed = overrides.parse_overrides().expect("parse_overrides");
+
+        assert_eq!(
+            parsed,
+            vec![(
+                r#"projects."/a/b.c".trust_level"#.to_string(),
+                Value::from("trusted"),
+            )]
+        );
+        assert_eq!(
+            codex_config::build_cli_overrides_layer(&parsed),
+            toml::from_str::<Value>("[projects.\"/a/b.c\"]\ntrust_level = \"trusted\"\n")
+                .expect("parse expected layer"),
+        );
+    }
+
+    #[test]
+    fn file_override_errors_name_file_and_line() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("broken.toml");
+        std::fs::write(&path, "model = \"ok\"\nsandbox_mode = \n").expect("write overrides file");
+        let overrides = CliConfigOverrides {
+            raw_overrides: vec![format!("@{}", path.display())],
+        };
+
+        let err = overrides
+            .parse_overrides()
+            .expect_err("invalid TOML should fail");
+        assert!(
+            err.starts_with(&format!("-c @{}: failed to parse TOML: ", path.display())),
+            "unexpected error: {err}"
+        );
+        assert!(err.contains("line 2"), "unexpected error: {err}");
+
+        let missing_path = dir.path().join("missing.toml");
+        let missing = CliConfigOverrides {
+            raw_overrides: vec![format!("@{}", missing_path.display())],
+        };
+        assert!(
+            missing
+                .parse_overrides()
+                .expect_err("missing file should fail")
+                .starts_with(&format!(
+                    "-c @{}: failed to read file: ",
+                    missing_path.display()
+                ))
+        );
+    }
+
+    #[test]
+    fn yaml_and_json_file_overrides_match_toml() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let files = [
+            (
+                "overrides.toml",
+                "model = \"from-file\"\n[mcp_servers.docs]\nargs = [\"--stdio\"]\n",
+            ),
+            (
+                "overrides.yaml",
+                "model: from-file\nmcp_servers:\n  docs:\n    args: [\"--stdio\"]\n",
+            ),
+            (
+                "overrides.json",
+                r#"{"model": "from-file", "mcp_servers": {"docs": {"args": ["--stdio"]}}}"#,
+            ),
+        ];
+
+        let parsed = files.map(|(name, contents)| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, contents).expect("write overrides file");
+            CliConfigOverrides {
+                raw_overrides: vec![format!("@{}", path.display())],
+            }
+            .parse_overrides()
+            .expect("parse_overrides")
+        });
+
+        let expected = vec![
+            ("model".to_string(), Value::from("from-file")),
+            (
+                "mcp_servers.docs.args".to_string(),
+                Value::Array(vec![Value::from("--stdio")]),
+            ),
+        ];
+        assert_eq!(parsed, [expected.clone(), expected.clone(), expected]);
+    }
+
+    #[test]
+    fn json_file_override_errors_name_line_and_column() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("broken.json");
+        std::fs::write(&path, "{\"model\": \"ok\",\n\"sandbox_mode\": }\n")
+            .expect("write overrides file");
+        let overrides = CliConfigOverrides {
+            raw_overrides: vec![format!("@{}", path.display())],
+        };
+
+        assert_eq!(
+            overrides
+                .parse_overrides()
+                .expect_err("invalid JSON should fail"),
+            format!(
+                "-c @{}: failed to parse JSON: line 2, column 17: expected value",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn reports_every_invalid_flag_in_one_pass() {
+        let overrides = CliConfigOverrides {
+            raw_overrides: vec![
+                "sandbox=workspace_write".to_string(),
+                r#"model="o3""#.to_string(),
+                "approval_policy=never_ask".to_string(),
+                "mcp_servers.foo.enabled=yes".to_string(),
+                "no_equals_sign".to_string(),
+            ],
+        };
+
+        assert_eq!(
+            overrides.parse_overrides_detailed(),
+            Err(vec![
+                ConfigOverrideError {
+                    flag: "sandbox=workspace_write".to_string(),
+                    kind: ConfigOverrideErrorKind::Schema(ConfigOverrideIssue::UnknownKey {
+                        key: "sandbox".to_string(),
+                        suggestion: Some("sandbox_mode".to_string()),
+                    }),
+                },
+                ConfigOverrideError {
+                    flag: "approval_policy=never_ask".to_string(),
+                    kind: ConfigOverrideErrorKind::Schema(ConfigOverrideIssue::InvalidValue {
+                        key: "approval_policy".to_string(),
+                        value: "never_ask".to_string(),
+                        allowed: vec![
+                            "untrusted".to_string(),
+                            "on-request".to_string(),
+                            "never".to_string(),
+                        ],
+                        suggestion: Some("never".to_string()),
+                    }),
+                },
+                ConfigOverrideError {
+                    flag: "mcp_servers.foo.enabled=yes".to_string(),
+                    kind: ConfigOverrideErrorKind::Schema(ConfigOverrideIssue::WrongType {
+                        key: "mcp_servers.foo.enabled".to_string(),
+                        expected: vec!["boolean".to_string()],
+                        actual: "string".to_string(),
+                    }),
+                },
+                ConfigOverrideError {
+                    flag: "no_equals_sign".to_string(),
+                    kind: ConfigOverrideErrorKind::Syntax(
+                        "missing '=' (expected key=value)".to_string()
+                    ),
+                },
+            ])
+        );
+        assert_eq!(
+            overrides
+                .parse_overrides()
+                .expect_err("invalid overrides should fail")
+                .lines()
+                .next(),
+            Some(
+                "-c sandbox=workspace_write: unknown configuration field `sandbox`; did you mean `sandbox_mode`?"
+            )
+        );
+    }
+
+    #[test]
+    fn accepts_valid_deep_paths_and_unrecognized_keys() {
+        let overrides = CliConfigOverrides {
+            raw_overrides: vec![
+                "mcp_servers.foo.enabled=true".to_string(),
+                "sandbox_mode=workspace-write".to_string(),
+                "reasoning_level=xhigh".to_string(),
+            ],
+        };
+
+        assert_eq!(
+            overrides.parse_overrides_detailed(),
+            Ok(vec![
+                ("mcp_servers.foo.enabled".to_string(), Value::Boolean(true)),
+                ("sandbox_mode".to_string(), Value::from("workspace-write")),
+                ("reasoning_level".to_string(), Value::from("xhigh")),
+            ])
+        );
+    }
+
     #[test]
     fn parses_inline_table() {
         let v = parse_toml_value("{a = 1, b = 2}").expect("parse");
@@ -8,6 +8,8 @@ mod shared_options;
 pub use approval_mode_cli_arg::ApprovalModeCliArg;
 pub use codex_protocol::config_types::ProfileV2Name;
 pub use config_override::CliConfigOverrides;
+pub use config_override::ConfigOverrideError;
+pub use config_override::ConfigOverrideErrorKind;
 pub use format_env_display::format_env_display;
 pub use resume_command::resume_command;
 pub use resume_command::resume_hint;